@@ -2,12 +2,16 @@
 //! 
 //! This provides `cargo udonsharp` command for seamless integration
 //! with Rust development workflow.
+//!
+//! `build` and `check` exit with 0 on success, 1 when there are errors and 2
+//! when `--deny-warnings` is set and warnings were reported, so CI pipelines
+//! can tell a failed build from a warning-only one.
 
-use clap::{Parser, Subcommand};
-use udonsharp_core::{UdonSharpResult, error::CompilationContext};
-use udonsharp_compiler::{CompilationPipeline, UdonSharpConfig};
+use clap::{Args, Parser, Subcommand};
+use udonsharp_core::{UdonSharpResult, error::{CompilationContext, ExitStatus}};
+use udonsharp_compiler::{expand_method, load_package_name, ConfigError, load_project_lint_levels, verify_examples, AssertionMode, CompilationPipeline, UdonSharpConfig, UdonBehaviourStruct, UnityPackage, BudgetStats, DEFAULT_PACKAGE_ASSET_ROOT};
 use udonsharp_bindings::UniversalBindingPipeline;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::env;
 use log::{info, warn, error};
 
@@ -40,26 +44,7 @@ struct UdonSharpArgs {
 #[derive(Subcommand)]
 enum UdonSharpCommand {
     /// Build the current project
-    Build {
-        /// Build in release mode
-        #[arg(long)]
-        release: bool,
-        /// Generate debug information
-        #[arg(long)]
-        debug: bool,
-        /// Target directory for output
-        #[arg(long)]
-        target_dir: Option<String>,
-        /// Show compilation progress
-        #[arg(long)]
-        progress: bool,
-        /// Build all workspace members
-        #[arg(long)]
-        workspace: bool,
-        /// Package to build (for workspace)
-        #[arg(short, long)]
-        package: Option<String>,
-    },
+    Build(BuildArgs),
     /// Generate API bindings from .asmdef files
     Bindings {
         /// Directory to scan for .asmdef files
@@ -92,6 +77,30 @@ enum UdonSharpCommand {
         /// Package to check (for workspace)
         #[arg(short, long)]
         package: Option<String>,
+        /// Fail with exit code 2 when warnings are reported
+        #[arg(long)]
+        deny_warnings: bool,
+        /// Compile every example crate under `examples/` instead of the project
+        #[arg(long)]
+        examples: bool,
+    },
+    /// Print the generated C# of a single method
+    Expand {
+        /// Method to expand, as `method` or `Behavior::method`
+        #[arg(long = "fn", value_name = "BEHAVIOR::METHOD")]
+        function: String,
+    },
+    /// Bundle the generated scripts, prefabs and manifests into a .unitypackage
+    Package {
+        /// Directory holding the generated output
+        #[arg(long)]
+        target_dir: Option<String>,
+        /// Path of the package to write
+        #[arg(short, long, value_name = "PATH", default_value = "UdonSharpGenerated.unitypackage")]
+        output: PathBuf,
+        /// Folder of the Unity project the assets are imported into
+        #[arg(long, value_name = "ASSET_PATH", default_value = DEFAULT_PACKAGE_ASSET_ROOT)]
+        asset_path: String,
     },
     /// Clean build artifacts
     Clean {
@@ -133,6 +142,17 @@ enum UdonSharpCommand {
         /// Custom features to include
         #[arg(long)]
         features: Vec<String>,
+        /// Detect an enclosing Unity project and VRChat SDK and write their paths into build.rs
+        #[arg(long)]
+        open: bool,
+    },
+    /// Append a new behavior skeleton to the current crate's lib.rs
+    AddBehavior {
+        /// Behavior name in PascalCase
+        name: String,
+        /// Unity events to stub, e.g. Start,Update,OnPlayerJoined
+        #[arg(long, value_delimiter = ',', default_value = "Start")]
+        events: Vec<String>,
     },
     /// Initialize UdonSharp in an existing Rust project
     Init {
@@ -150,6 +170,47 @@ enum UdonSharpCommand {
     Templates,
 }
 
+/// Options of the build command
+#[derive(Args, Default)]
+struct BuildArgs {
+    /// Build in release mode
+    #[arg(long)]
+    release: bool,
+    /// Generate debug information
+    #[arg(long)]
+    debug: bool,
+    /// Target directory for output
+    #[arg(long)]
+    target_dir: Option<String>,
+    /// Show compilation progress
+    #[arg(long)]
+    progress: bool,
+    /// Build all workspace members
+    #[arg(long)]
+    workspace: bool,
+    /// Package to build (for workspace)
+    #[arg(short, long)]
+    package: Option<String>,
+    /// Write the intermediate WASM module to this path for inspection
+    #[arg(long, value_name = "PATH")]
+    emit_wasm: Option<PathBuf>,
+    /// Fail with exit code 2 when warnings are reported
+    #[arg(long)]
+    deny_warnings: bool,
+    /// Compile and emit only this behavior (repeat to select several)
+    #[arg(long = "behavior", value_name = "NAME")]
+    behaviors: Vec<String>,
+    /// Emit a behavior showing the fields marked #[udon_debug] on an in-world text element
+    #[arg(long)]
+    emit_debug_overlay: bool,
+    /// Write a JSON Schema of each behavior's #[udon_public] fields next to its script
+    #[arg(long)]
+    emit_field_schemas: bool,
+    /// Print each behavior's synced-variable, public-variable, event and instruction budget usage
+    #[arg(long)]
+    stats: bool,
+}
+
 #[tokio::main]
 async fn main() -> UdonSharpResult<()> {
     let CargoCli::UdonSharp(args) = CargoCli::parse();
@@ -158,38 +219,56 @@ async fn main() -> UdonSharpResult<()> {
     init_logging(&args)?;
     
     // If no subcommand is provided, default to build
-    let command = args.command.unwrap_or(UdonSharpCommand::Build {
-        release: false,
-        debug: false,
-        target_dir: None,
-        progress: false,
-        workspace: false,
-        package: None,
-    });
-    
-    match command {
-        UdonSharpCommand::Build { release, debug, target_dir, progress, workspace, package } => {
-            handle_build_command(release, debug, target_dir, progress, workspace, package).await
-        }
+    let command = args.command.unwrap_or(UdonSharpCommand::Build(BuildArgs::default()));
+    
+    let status = match command {
+        UdonSharpCommand::Build(build) => handle_build_command(build).await?,
         UdonSharpCommand::Bindings { scan_dir, output, force, progress, workspace } => {
-            handle_bindings_command(scan_dir, output, force, progress, workspace).await
+            handle_bindings_command(scan_dir, output, force, progress, workspace).await?;
+            ExitStatus::Success
+        }
+        UdonSharpCommand::Check { examples: true, .. } => {
+            handle_check_examples_command().await?;
+            ExitStatus::Success
+        }
+        UdonSharpCommand::Check { release, detailed, workspace, package, deny_warnings, examples: false } => {
+            handle_check_command(release, detailed, workspace, package, deny_warnings).await?
+        }
+        UdonSharpCommand::Expand { function } => {
+            handle_expand_command(function).await?;
+            ExitStatus::Success
         }
-        UdonSharpCommand::Check { release, detailed, workspace, package } => {
-            handle_check_command(release, detailed, workspace, package).await
+        UdonSharpCommand::Package { target_dir, output, asset_path } => {
+            handle_package_command(target_dir, output, asset_path).await?;
+            ExitStatus::Success
         }
         UdonSharpCommand::Clean { target_dir, all, workspace } => {
-            handle_clean_command(target_dir, all, workspace).await
+            handle_clean_command(target_dir, all, workspace).await?;
+            ExitStatus::Success
         }
-        UdonSharpCommand::New { name, template, workspace, examples, tests, docs, vrc_sdk_path, unity_project_path, features } => {
-            handle_new_command(name, template, workspace, examples, tests, docs, vrc_sdk_path, unity_project_path, features).await
+        UdonSharpCommand::New { name, template, workspace, examples, tests, docs, vrc_sdk_path, unity_project_path, features, open } => {
+            handle_new_command(name, template, workspace, examples, tests, docs, vrc_sdk_path, unity_project_path, features, open).await?;
+            ExitStatus::Success
+        }
+        UdonSharpCommand::AddBehavior { name, events } => {
+            handle_add_behavior_command(name, events).await?;
+            ExitStatus::Success
         }
         UdonSharpCommand::Init { template, vrc_sdk_path, unity_project_path } => {
-            handle_init_command(template, vrc_sdk_path, unity_project_path).await
+            handle_init_command(template, vrc_sdk_path, unity_project_path).await?;
+            ExitStatus::Success
         }
         UdonSharpCommand::Templates => {
-            handle_templates_command().await
+            handle_templates_command().await?;
+            ExitStatus::Success
         }
+    };
+    
+    // Errors propagate out of main, which exits with code 1
+    if status != ExitStatus::Success {
+        std::process::exit(status.code());
     }
+    Ok(())
 }
 
 fn init_logging(args: &UdonSharpArgs) -> UdonSharpResult<()> {
@@ -209,14 +288,11 @@ fn init_logging(args: &UdonSharpArgs) -> UdonSharpResult<()> {
     Ok(())
 }
 
-async fn handle_build_command(
-    release: bool,
-    debug: bool,
-    target_dir: Option<String>,
-    progress: bool,
-    workspace: bool,
-    package: Option<String>,
-) -> UdonSharpResult<()> {
+async fn handle_build_command(build: BuildArgs) -> UdonSharpResult<ExitStatus> {
+    let BuildArgs {
+        release, debug, target_dir, progress, workspace, package, emit_wasm, deny_warnings,
+        behaviors, emit_debug_overlay, emit_field_schemas, stats,
+    } = build;
     info!("Building UdonSharp project...");
     
     // Detect if we're in a Cargo workspace
@@ -224,7 +300,22 @@ async fn handle_build_command(
     let project_dir = manifest_path.parent().unwrap();
     
     if workspace {
-        return handle_workspace_build(project_dir, release, debug, target_dir, progress, package).await;
+        if emit_wasm.is_some() {
+            warn!("--emit-wasm is ignored for workspace builds; build a single package to inspect its WASM");
+        }
+        if !behaviors.is_empty() {
+            warn!("--behavior is ignored for workspace builds; build a single package to select behaviors");
+        }
+        if emit_debug_overlay {
+            warn!("--emit-debug-overlay is ignored for workspace builds; build a single package to add the overlay");
+        }
+        if emit_field_schemas {
+            warn!("--emit-field-schemas is ignored for workspace builds; build a single package to export its schemas");
+        }
+        if stats {
+            warn!("--stats is ignored for workspace builds; build a single package to see its budget usage");
+        }
+        return handle_workspace_build(project_dir, release, debug, target_dir, progress, package, deny_warnings).await;
     }
     
     if progress {
@@ -242,7 +333,17 @@ async fn handle_build_command(
     // Create configuration
     let mut config = UdonSharpConfig::default();
     config.optimize_for_performance = release;
+    config.strip_debug_logs = release;
+    if release {
+        config.assertions = AssertionMode::Strip;
+    }
     config.generate_debug_info = debug;
+    config.emit_wasm = emit_wasm;
+    config.selected_behaviors = behaviors;
+    config.emit_debug_overlay = emit_debug_overlay;
+    config.emit_field_schemas = emit_field_schemas;
+    config.lints = project_lint_levels(project_dir)?;
+    config.package_name = project_package_name(project_dir)?;
     
     if let Some(target) = target_dir {
         config.output_directory = Some(target);
@@ -269,7 +370,62 @@ async fn handle_build_command(
         return Err(udonsharp_core::UdonSharpError::compilation("Build failed"));
     }
     
-    Ok(())
+    if stats {
+        print_budget_stats(&result.behaviors, pipeline.config());
+    }
+    
+    Ok(denied_warnings_status(pipeline.context(), deny_warnings))
+}
+
+/// Print the budget table of the behaviors a build analyzed
+fn print_budget_stats(behaviors: &[UdonBehaviourStruct], config: &UdonSharpConfig) {
+    use std::io::IsTerminal;
+    
+    let stats = BudgetStats::with_config(behaviors, config);
+    println!("📊 Budget usage:");
+    println!("{}", stats.format_table(std::io::stdout().is_terminal()));
+}
+
+/// Read the crate root of a project, `src/lib.rs` or else `src/main.rs`
+fn read_project_source(project_dir: &Path) -> UdonSharpResult<(PathBuf, String)> {
+    let source_path = ["src/lib.rs", "src/main.rs"].iter()
+        .map(|path| project_dir.join(path))
+        .find(|path| path.exists())
+        .ok_or_else(|| udonsharp_core::UdonSharpError::configuration(
+            format!("Could not find src/lib.rs or src/main.rs in {}", project_dir.display())
+        ))?;
+    let source = std::fs::read_to_string(&source_path).map_err(|e| {
+        udonsharp_core::UdonSharpError::configuration(format!("Failed to read {}: {}", source_path.display(), e))
+    })?;
+    Ok((source_path, source))
+}
+
+/// Lint levels a project sets in its Cargo.toml metadata and `udonsharp.toml`
+fn project_lint_levels(project_dir: &Path) -> UdonSharpResult<std::collections::BTreeMap<String, udonsharp_compiler::LintLevel>> {
+    load_project_lint_levels(project_dir)
+        .map_err(|e| udonsharp_core::UdonSharpError::configuration(e.to_string()))
+}
+
+/// Name of the package a project's Cargo.toml declares, or the directory
+/// name of a workspace root that declares none
+fn project_package_name(project_dir: &Path) -> UdonSharpResult<String> {
+    match load_package_name(project_dir) {
+        Ok(name) => Ok(name),
+        Err(ConfigError::MissingField(_)) => Ok(project_dir.canonicalize()
+            .ok()
+            .and_then(|dir| dir.file_name().map(|name| name.to_string_lossy().into_owned()))
+            .unwrap_or_default()),
+        Err(e) => Err(udonsharp_core::UdonSharpError::configuration(e.to_string())),
+    }
+}
+
+/// Exit status of a successful build or check, reporting warnings that `--deny-warnings` rejects
+fn denied_warnings_status(context: &CompilationContext, deny_warnings: bool) -> ExitStatus {
+    let status = context.exit_status(deny_warnings);
+    if status == ExitStatus::DeniedWarnings {
+        error!("{} warning(s) reported and --deny-warnings is set", context.warning_count());
+    }
+    status
 }
 
 async fn handle_bindings_command(
@@ -334,7 +490,13 @@ async fn handle_bindings_command(
     Ok(())
 }
 
-async fn handle_check_command(release: bool, detailed: bool, workspace: bool, package: Option<String>) -> UdonSharpResult<()> {
+async fn handle_check_command(
+    release: bool,
+    detailed: bool,
+    _workspace: bool,
+    _package: Option<String>,
+    deny_warnings: bool,
+) -> UdonSharpResult<ExitStatus> {
     info!("Checking project for errors...");
     
     let manifest_path = find_cargo_manifest()?;
@@ -344,6 +506,8 @@ async fn handle_check_command(release: bool, detailed: bool, workspace: bool, pa
     let mut config = UdonSharpConfig::default();
     config.optimize_for_performance = release;
     config.check_only = true;
+    config.lints = project_lint_levels(project_dir)?;
+    config.package_name = project_package_name(project_dir)?;
     
     let pipeline = CompilationPipeline::with_context(config, context);
     let result = pipeline.check_project(project_dir).await?;
@@ -361,6 +525,80 @@ async fn handle_check_command(release: bool, detailed: bool, workspace: bool, pa
         return Err(udonsharp_core::UdonSharpError::compilation("Check failed"));
     }
     
+    Ok(denied_warnings_status(pipeline.context(), deny_warnings))
+}
+
+async fn handle_check_examples_command() -> UdonSharpResult<()> {
+    let manifest_path = find_cargo_manifest()?;
+    let examples_dir = manifest_path.parent().unwrap().join("examples");
+    info!("Compiling examples in {}...", examples_dir.display());
+    
+    let reports = verify_examples(&examples_dir).await.map_err(|e| {
+        udonsharp_core::UdonSharpError::configuration(format!("Failed to read {}: {}", examples_dir.display(), e))
+    })?;
+    for report in &reports {
+        println!("{}", report);
+    }
+    
+    let failed = reports.iter().filter(|report| !report.success()).count();
+    if failed > 0 {
+        error!("{} of {} example(s) failed to compile", failed, reports.len());
+        return Err(udonsharp_core::UdonSharpError::compilation("Example check failed"));
+    }
+    
+    println!("✅ All {} example(s) compile", reports.len());
+    Ok(())
+}
+
+async fn handle_expand_command(function: String) -> UdonSharpResult<()> {
+    let manifest_path = find_cargo_manifest()?;
+    let project_dir = manifest_path.parent().unwrap();
+    
+    let (_, source) = read_project_source(project_dir)?;
+    
+    let expanded = expand_method(&source, &function)
+        .map_err(|e| udonsharp_core::UdonSharpError::compilation(e.to_string()))?;
+    println!("{}", expanded);
+    
+    Ok(())
+}
+
+async fn handle_add_behavior_command(name: String, events: Vec<String>) -> UdonSharpResult<()> {
+    let manifest_path = find_cargo_manifest()?;
+    let project_dir = manifest_path.parent().unwrap();
+    
+    let lib_path = udonsharp_build::add_behavior_skeleton(project_dir, &name, &events)
+        .map_err(|e| udonsharp_core::UdonSharpError::configuration(e.to_string()))?;
+    
+    println!("✅ Added behavior {} to {}", name, lib_path.display());
+    
+    Ok(())
+}
+
+async fn handle_package_command(target_dir: Option<String>, output: PathBuf, asset_path: String) -> UdonSharpResult<()> {
+    let manifest_path = find_cargo_manifest()?;
+    let package_name = project_package_name(manifest_path.parent().unwrap())?;
+    let output_dir = PathBuf::from(target_dir.unwrap_or_else(|| ".".to_string()));
+    info!("Collecting generated assets in {}...", output_dir.display());
+    
+    let package = UnityPackage::collect(&output_dir, &asset_path, &package_name).map_err(|e| {
+        udonsharp_core::UdonSharpError::configuration(format!("Failed to read {}: {}", output_dir.display(), e))
+    })?;
+    if package.assets.is_empty() {
+        return Err(udonsharp_core::UdonSharpError::configuration(
+            format!("No generated assets found in {}; run `cargo udonsharp build` first", output_dir.display())
+        ));
+    }
+    
+    package.write_file(&output).map_err(|e| {
+        udonsharp_core::UdonSharpError::compilation(format!("Failed to write {}: {}", output.display(), e))
+    })?;
+    
+    println!("📦 Packaged {} asset(s) into {}", package.assets.len(), output.display());
+    for asset in &package.assets {
+        println!("   {}", asset.pathname);
+    }
+    
     Ok(())
 }
 
@@ -456,7 +694,8 @@ async fn handle_workspace_build(
     target_dir: Option<String>,
     progress: bool,
     package: Option<String>,
-) -> UdonSharpResult<()> {
+    deny_warnings: bool,
+) -> UdonSharpResult<ExitStatus> {
     info!("Building UdonSharp workspace...");
     
     let workspace_members = discover_workspace_members(workspace_dir)?;
@@ -477,6 +716,7 @@ async fn handle_workspace_build(
     
     let mut all_success = true;
     let mut total_files = Vec::new();
+    let mut status = ExitStatus::Success;
     
     for member in members_to_build {
         println!("🔨 Building workspace member: {}", member);
@@ -496,6 +736,8 @@ async fn handle_workspace_build(
         let mut config = UdonSharpConfig::default();
         config.optimize_for_performance = release;
         config.generate_debug_info = debug;
+        config.lints = project_lint_levels(&member_dir)?;
+        config.package_name = project_package_name(&member_dir)?;
         
         if let Some(ref target) = target_dir {
             config.output_directory = Some(format!("{}/{}", target, member));
@@ -508,6 +750,9 @@ async fn handle_workspace_build(
                 if result.success {
                     println!("✅ {} built successfully", member);
                     total_files.extend(result.output_files);
+                    if denied_warnings_status(pipeline.context(), deny_warnings) == ExitStatus::DeniedWarnings {
+                        status = ExitStatus::DeniedWarnings;
+                    }
                 } else {
                     println!("❌ {} build failed", member);
                     all_success = false;
@@ -529,7 +774,7 @@ async fn handle_workspace_build(
         return Err(udonsharp_core::UdonSharpError::compilation("Some workspace members failed to build"));
     }
     
-    Ok(())
+    Ok(status)
 }
 
 async fn handle_new_command(
@@ -542,6 +787,7 @@ async fn handle_new_command(
     vrc_sdk_path: Option<String>,
     unity_project_path: Option<String>,
     features: Vec<String>,
+    open: bool,
 ) -> UdonSharpResult<()> {
     info!("Creating new UdonSharp project: {}", name);
     
@@ -562,7 +808,7 @@ async fn handle_new_command(
         udonsharp_core::UdonSharpError::configuration(format!("Invalid template: {}", e))
     })?;
     
-    let config = udonsharp_build::ScaffoldingConfig {
+    let mut config = udonsharp_build::ScaffoldingConfig {
         template: template_type,
         project_name: name.clone(),
         workspace,
@@ -574,6 +820,16 @@ async fn handle_new_command(
         custom_features: features,
     };
     
+    // Resolve the project path so detection can walk its parents
+    let detected = if open {
+        let absolute_dir = project_dir.canonicalize().map_err(|e| {
+            udonsharp_core::UdonSharpError::configuration(format!("Failed to resolve project directory: {}", e))
+        })?;
+        config.apply_unity_project_detection(&absolute_dir)
+    } else {
+        None
+    };
+    
     // Create project with configuration
     udonsharp_build::create_project_with_config(project_dir, &config).map_err(|e| {
         udonsharp_core::UdonSharpError::configuration(format!("Failed to create project: {}", e))
@@ -602,9 +858,26 @@ async fn handle_new_command(
         println!("📖 Documentation included in docs/ directory");
     }
     
+    if open {
+        match &detected {
+            Some(detected) => {
+                println!("🔍 Detected Unity project: {}", detected.unity_project_path);
+                match &detected.vrc_sdk_path {
+                    Some(sdk) => println!("🔍 Detected VRChat SDK: {}", sdk),
+                    None => println!("⚠️  No VRChat SDK found in the Unity project; install it with the VRChat Creator Companion"),
+                }
+                println!("   Scan directories written to build.rs");
+            }
+            None => println!("⚠️  No enclosing Unity project found; set --unity-project-path or edit build.rs"),
+        }
+    }
+    
     println!("🚀 To get started:");
     println!("   cd {}", name);
     println!("   cargo udonsharp build");
+    if open && detected.is_some() {
+        println!("   Open the Unity project and add the generated C# from the output directory to a scene");
+    }
     
     Ok(())
 }
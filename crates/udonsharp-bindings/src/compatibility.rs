@@ -280,8 +280,8 @@ impl UdonSharpCompatibilityChecker {
             }
         }
         
-        // Check return type
-        if !self.is_parameter_type_compatible(&method.return_type) {
+        // Check return type; `void` methods bind to functions returning `()`
+        if method.return_type != "System.Void" && !self.is_parameter_type_compatible(&method.return_type) {
             result.is_compatible = false;
             result.reasons.push(format!("Return type '{}' is not compatible", method.return_type));
         }
@@ -6,6 +6,7 @@
 use crate::asmdef::{AsmdefFile, AsmdefDiscovery, AssemblyInfo, TypeInfo, MethodInfo, PropertyInfo, FieldInfo};
 use crate::analyzer::AssemblyAnalyzer;
 use crate::compatibility::UdonSharpCompatibilityChecker;
+use crate::tree_shake::{prune_assembly, BindingUsage};
 use anyhow::{Result, Context};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -26,6 +27,8 @@ pub struct BindingConfig {
     pub include_internal_types: bool,
     pub module_prefix: Option<String>,
     pub custom_type_mappings: HashMap<String, String>,
+    /// Generate only the types and members these sources refer to; `None` generates everything
+    pub used_apis: Option<BindingUsage>,
 }
 
 /// Generated binding information
@@ -134,6 +137,18 @@ impl UniversalBindingPipeline {
         let assembly_info = analyzer.extract_api_information()
             .with_context(|| format!("Failed to extract API information from assembly: {}", asmdef.name))?;
         
+        // Drop the types and members the project never refers to
+        let assembly_info = match &config.used_apis {
+            Some(usage) => match prune_assembly(&assembly_info, usage) {
+                Some(pruned) => pruned,
+                None => {
+                    log::info!("Skipping unused assembly '{}'", asmdef.name);
+                    return Ok(None);
+                }
+            },
+            None => assembly_info,
+        };
+        
         // Generate Rust bindings
         let binding_code = self.generate_binding_for_assembly(&assembly_info, config)
             .with_context(|| format!("Failed to generate bindings for assembly: {}", asmdef.name))?;
@@ -385,23 +400,7 @@ impl UniversalBindingPipeline {
     
     /// Convert C# method name to Rust naming convention
     fn convert_to_rust_naming(&self, name: &str) -> String {
-        // Convert PascalCase to snake_case
-        let mut result = String::new();
-        let mut chars = name.chars().peekable();
-        
-        while let Some(ch) = chars.next() {
-            if ch.is_uppercase() && !result.is_empty() {
-                // Add underscore before uppercase letters (except at start)
-                if let Some(&next_ch) = chars.peek() {
-                    if next_ch.is_lowercase() {
-                        result.push('_');
-                    }
-                }
-            }
-            result.push(ch.to_lowercase().next().unwrap_or(ch));
-        }
-        
-        result
+        rust_binding_name(name)
     }
     
     /// Convert method parameters to Rust syntax
@@ -505,8 +504,29 @@ impl Default for BindingConfig {
             include_internal_types: false,
             module_prefix: None,
             custom_type_mappings: HashMap::new(),
+            used_apis: None,
+        }
+    }
+}
+
+/// Convert a C# member name to the `snake_case` name of its Rust binding
+pub fn rust_binding_name(name: &str) -> String {
+    let mut result = String::new();
+    let mut chars = name.chars().peekable();
+    
+    while let Some(ch) = chars.next() {
+        if ch.is_uppercase() && !result.is_empty() {
+            // Add underscore before uppercase letters (except at start)
+            if let Some(&next_ch) = chars.peek() {
+                if next_ch.is_lowercase() {
+                    result.push('_');
+                }
+            }
         }
+        result.push(ch.to_lowercase().next().unwrap_or(ch));
     }
+    
+    result
 }
 
 /// Placeholder for object handle (to be implemented with actual UdonSharp integration)
@@ -519,4 +539,73 @@ impl ObjectHandle {
     pub fn new() -> Self {
         Self {}
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debug_log_method(name: &str) -> MethodInfo {
+        MethodInfo {
+            name: name.to_string(),
+            declaring_type: "UnityEngine.Debug".to_string(),
+            is_public: true,
+            is_static: true,
+            is_virtual: false,
+            is_abstract: false,
+            is_generic: false,
+            parameters: Vec::new(),
+            return_type: "System.Void".to_string(),
+            has_ref_parameters: false,
+            has_out_parameters: false,
+        }
+    }
+
+    fn unity_type(name: &str, methods: Vec<MethodInfo>) -> TypeInfo {
+        TypeInfo {
+            name: name.to_string(),
+            namespace: Some("UnityEngine".to_string()),
+            full_name: format!("UnityEngine.{}", name),
+            is_public: true,
+            is_static: false,
+            is_abstract: false,
+            is_sealed: false,
+            is_generic: false,
+            generic_constraints: Vec::new(),
+            base_type: None,
+            interfaces: Vec::new(),
+            methods,
+            properties: Vec::new(),
+            fields: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_debug_log_only_project_generates_only_debug_log() {
+        let assembly = AssemblyInfo {
+            name: "UnityEngine".to_string(),
+            version: "1.0.0".to_string(),
+            types: vec![
+                unity_type("Debug", vec![debug_log_method("Log"), debug_log_method("LogWarning")]),
+                unity_type("Transform", vec![debug_log_method("DetachChildren")]),
+                unity_type("Rigidbody", Vec::new()),
+            ],
+            dependencies: Vec::new(),
+            asmdef_path: PathBuf::new(),
+        };
+        let usage = BindingUsage::from_rust_source("fn start(&mut self) { Debug::log(\"ready\"); }").unwrap();
+        let pruned = prune_assembly(&assembly, &usage).unwrap();
+
+        let pipeline = UniversalBindingPipeline::new("generated".to_string());
+        let code = pipeline.generate_binding_for_assembly(&pruned, &BindingConfig::default()).unwrap();
+        assert!(code.contains("pub struct Debug {"));
+        assert!(code.contains("#[udon_binding(\"UnityEngine.Debug.Log\")]"));
+        assert!(code.contains("pub fn log() -> ()"));
+        assert!(!code.contains("log_warning"));
+        assert!(!code.contains("Transform"));
+        assert!(!code.contains("Rigidbody"));
+
+        let unused = BindingUsage::from_rust_source("fn start(&mut self) { self.count += 1; }").unwrap();
+        assert!(prune_assembly(&assembly, &unused).is_none());
+    }
+}
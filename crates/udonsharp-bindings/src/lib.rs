@@ -9,6 +9,7 @@ pub mod asmdef;
 pub mod analyzer;
 pub mod generator;
 pub mod compatibility;
+pub mod tree_shake;
 
 // Re-export core types
 pub use udonsharp_core::*;
@@ -26,6 +27,7 @@ pub mod csharp;
 pub use generator::*;
 pub use compatibility::*;
 pub use asmdef::*;
+pub use tree_shake::*;
 
 // Re-export API bindings for easy access
 pub use vrchat::*;
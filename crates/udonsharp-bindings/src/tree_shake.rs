@@ -0,0 +1,144 @@
+//! Tree-shaking of generated bindings down to the APIs a project uses
+//!
+//! Generated binding modules cover whole assemblies, while a world typically
+//! calls a handful of Unity and VRChat APIs. `BindingUsage` records the
+//! identifiers a project's Rust sources mention, and `prune_assembly` drops
+//! the types and members no source refers to before bindings are generated.
+//! A member is kept when its Rust binding name is mentioned anywhere, since
+//! the receiver type of a method call is not known without type checking.
+
+use crate::asmdef::{AssemblyInfo, TypeInfo};
+use crate::generator::rust_binding_name;
+use anyhow::Context;
+use proc_macro2::{TokenStream, TokenTree};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Identifiers referenced by a project's sources
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BindingUsage {
+    identifiers: HashSet<String>,
+}
+
+impl BindingUsage {
+    /// Create an empty usage set, which prunes every binding
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect the identifiers of Rust source code, skipping comments and literals
+    pub fn from_rust_source(source: &str) -> anyhow::Result<Self> {
+        let mut usage = Self::new();
+        usage.add_rust_source(source)?;
+        Ok(usage)
+    }
+
+    /// Collect the identifiers of every `.rs` file below `dir`
+    pub fn from_source_dir<P: AsRef<Path>>(dir: P) -> anyhow::Result<Self> {
+        let mut usage = Self::new();
+        for entry in walkdir::WalkDir::new(dir) {
+            let entry = entry?;
+            if entry.path().extension().is_some_and(|ext| ext == "rs") {
+                usage.add_rust_source(&fs::read_to_string(entry.path())?)
+                    .with_context(|| format!("failed to tokenize {}", entry.path().display()))?;
+            }
+        }
+        Ok(usage)
+    }
+
+    /// Add the identifiers of more Rust source code
+    ///
+    /// The source is tokenized as Rust, so comments and every form of
+    /// literal, including raw strings and character literals, are skipped.
+    pub fn add_rust_source(&mut self, source: &str) -> anyhow::Result<()> {
+        let tokens = TokenStream::from_str(source).map_err(|e| anyhow::anyhow!("{}", e))?;
+        self.add_tokens(tokens);
+        Ok(())
+    }
+
+    /// Record the identifiers of a token stream and of the groups within it
+    fn add_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => {
+                    let ident = ident.to_string();
+                    let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+                    self.identifiers.insert(ident.to_string());
+                }
+                TokenTree::Group(group) => self.add_tokens(group.stream()),
+                TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+            }
+        }
+    }
+
+    /// Whether the sources mention `identifier`
+    pub fn mentions(&self, identifier: &str) -> bool {
+        self.identifiers.contains(identifier)
+    }
+
+    /// Whether the sources refer to a type by its Rust binding name
+    pub fn uses_type(&self, type_info: &TypeInfo) -> bool {
+        self.mentions(&type_info.name)
+    }
+
+    /// Whether the sources may call a member with the given C# name, as a
+    /// method or property getter (`snake_case`) or a property setter (`set_snake_case`)
+    pub fn uses_member(&self, csharp_name: &str) -> bool {
+        let rust_name = rust_binding_name(csharp_name);
+        self.mentions(&rust_name) || self.mentions(&format!("set_{}", rust_name))
+    }
+}
+
+/// Keep only the types and members of an assembly that `usage` refers to;
+/// `None` when no type of the assembly is used
+pub fn prune_assembly(assembly: &AssemblyInfo, usage: &BindingUsage) -> Option<AssemblyInfo> {
+    let types: Vec<TypeInfo> = assembly.types.iter()
+        .filter(|type_info| usage.uses_type(type_info))
+        .map(|type_info| TypeInfo {
+            methods: type_info.methods.iter().filter(|m| usage.uses_member(&m.name)).cloned().collect(),
+            properties: type_info.properties.iter().filter(|p| usage.uses_member(&p.name)).cloned().collect(),
+            fields: type_info.fields.iter().filter(|f| usage.uses_member(&f.name)).cloned().collect(),
+            events: type_info.events.iter().filter(|e| usage.uses_member(&e.name)).cloned().collect(),
+            ..type_info.clone()
+        })
+        .collect();
+
+    if types.is_empty() {
+        return None;
+    }
+    Some(AssemblyInfo { types, ..assembly.clone() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_skips_comments_strings_and_numbers() {
+        let usage = BindingUsage::from_rust_source(
+            "// Transform is unused\nfn start(&mut self) { /* nor GameObject */ Debug::log(\"Transform \\\" moved\"); let x = 3f32; }",
+        ).unwrap();
+        assert!(usage.mentions("Debug"));
+        assert!(usage.mentions("log"));
+        assert!(!usage.mentions("Transform"));
+        assert!(!usage.mentions("GameObject"));
+        assert!(!usage.mentions("3f32"));
+        assert!(usage.uses_member("Log"));
+        assert!(!usage.uses_member("LogWarning"));
+    }
+
+    #[test]
+    fn test_usage_skips_char_and_raw_string_literals() {
+        let usage = BindingUsage::from_rust_source(
+            "fn start(&mut self) { let quote = '\"'; Debug::log(r#\"Transform \" moved\"#); let r#type = GameObject::find(\"Spawn\"); }",
+        ).unwrap();
+        assert!(usage.mentions("Debug"));
+        assert!(usage.mentions("GameObject"));
+        assert!(usage.mentions("find"));
+        assert!(usage.mentions("type"));
+        assert!(!usage.mentions("Transform"));
+        assert!(!usage.mentions("moved"));
+    }
+}
@@ -49,6 +49,21 @@ pub mod core {
         pub fn send_custom_event(&self, event_name: &str) {
             todo!("Implement SendCustomEvent binding")
         }
+        
+        /// Check whether this GameObject has the given tag
+        pub fn compare_tag(&self, _tag: &str) -> bool {
+            todo!("Implement CompareTag binding")
+        }
+        
+        /// Get the layer number
+        pub fn layer(&self) -> i32 {
+            todo!("Implement get layer binding")
+        }
+        
+        /// Set the layer number
+        pub fn set_layer(&mut self, _layer: i32) {
+            todo!("Implement set layer binding")
+        }
     }
     
     /// Unity Transform component
@@ -137,6 +152,11 @@ pub mod time {
         pub fn delta_time() -> f32 {
             todo!("Implement Time.deltaTime binding")
         }
+        
+        /// Get the physics step interval
+        pub fn fixed_delta_time() -> f32 {
+            todo!("Implement Time.fixedDeltaTime binding")
+        }
     }
 }
 
@@ -25,7 +25,15 @@ pub mod networking {
             todo!("Implement GetPlayers binding")
         }
         
-        /// Check if the local player is the owner of an object
+        /// Get the player who owns an object.
+        ///
+        /// Ownership is per object; an object nobody took ownership of belongs
+        /// to the instance master.
+        pub fn get_owner(target: &GameObject) -> super::player::VRCPlayerApi {
+            todo!("Implement GetOwner binding")
+        }
+        
+        /// Check if a player is the owner of an object
         pub fn is_owner(player: &super::player::VRCPlayerApi, target: &GameObject) -> bool {
             todo!("Implement IsOwner binding")
         }
@@ -466,7 +474,377 @@ pub mod video {
     }
 }
 
+/// VRChat data containers (`VRC.SDK3.Data`)
+pub mod data {
+    /// Type of the value held by a [`DataToken`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum TokenType {
+        Null,
+        Boolean,
+        Int,
+        Float,
+        Double,
+        String,
+        DataList,
+        DataDictionary,
+    }
+    
+    /// A dynamically typed value stored in a [`DataList`] or [`DataDictionary`]
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub enum DataToken {
+        #[default]
+        Null,
+        Boolean(bool),
+        Int(i32),
+        Float(f32),
+        Double(f64),
+        String(String),
+        DataList(DataList),
+        DataDictionary(DataDictionary),
+    }
+    
+    impl DataToken {
+        /// Get the type of the held value
+        pub fn token_type(&self) -> TokenType {
+            match self {
+                DataToken::Null => TokenType::Null,
+                DataToken::Boolean(_) => TokenType::Boolean,
+                DataToken::Int(_) => TokenType::Int,
+                DataToken::Float(_) => TokenType::Float,
+                DataToken::Double(_) => TokenType::Double,
+                DataToken::String(_) => TokenType::String,
+                DataToken::DataList(_) => TokenType::DataList,
+                DataToken::DataDictionary(_) => TokenType::DataDictionary,
+            }
+        }
+        
+        /// Get the value as a bool (`token.Boolean`)
+        pub fn as_bool(&self) -> Option<bool> {
+            match self {
+                DataToken::Boolean(value) => Some(*value),
+                _ => None,
+            }
+        }
+        
+        /// Get the value as an int (`token.Int`)
+        pub fn as_int(&self) -> Option<i32> {
+            match self {
+                DataToken::Int(value) => Some(*value),
+                _ => None,
+            }
+        }
+        
+        /// Get the value as a float (`token.Float`)
+        pub fn as_float(&self) -> Option<f32> {
+            match self {
+                DataToken::Float(value) => Some(*value),
+                _ => None,
+            }
+        }
+        
+        /// Get the value as a double (`token.Double`)
+        pub fn as_double(&self) -> Option<f64> {
+            match self {
+                DataToken::Double(value) => Some(*value),
+                _ => None,
+            }
+        }
+        
+        /// Get the value as a string (`token.String`)
+        pub fn as_string(&self) -> Option<&str> {
+            match self {
+                DataToken::String(value) => Some(value),
+                _ => None,
+            }
+        }
+        
+        /// Get the value as a nested list (`token.DataList`)
+        pub fn as_data_list(&self) -> Option<&DataList> {
+            match self {
+                DataToken::DataList(value) => Some(value),
+                _ => None,
+            }
+        }
+        
+        /// Get the value as a nested dictionary (`token.DataDictionary`)
+        pub fn as_data_dictionary(&self) -> Option<&DataDictionary> {
+            match self {
+                DataToken::DataDictionary(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+    
+    impl From<bool> for DataToken {
+        fn from(value: bool) -> Self {
+            DataToken::Boolean(value)
+        }
+    }
+    
+    impl From<i32> for DataToken {
+        fn from(value: i32) -> Self {
+            DataToken::Int(value)
+        }
+    }
+    
+    impl From<f32> for DataToken {
+        fn from(value: f32) -> Self {
+            DataToken::Float(value)
+        }
+    }
+    
+    impl From<f64> for DataToken {
+        fn from(value: f64) -> Self {
+            DataToken::Double(value)
+        }
+    }
+    
+    impl From<&str> for DataToken {
+        fn from(value: &str) -> Self {
+            DataToken::String(value.to_string())
+        }
+    }
+    
+    impl From<String> for DataToken {
+        fn from(value: String) -> Self {
+            DataToken::String(value)
+        }
+    }
+    
+    impl From<DataList> for DataToken {
+        fn from(value: DataList) -> Self {
+            DataToken::DataList(value)
+        }
+    }
+    
+    impl From<DataDictionary> for DataToken {
+        fn from(value: DataDictionary) -> Self {
+            DataToken::DataDictionary(value)
+        }
+    }
+    
+    /// An ordered list of [`DataToken`] values (`VRC.SDK3.Data.DataList`)
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct DataList {
+        tokens: Vec<DataToken>,
+    }
+    
+    impl DataList {
+        /// Create an empty list
+        pub fn new() -> Self {
+            Self::default()
+        }
+        
+        /// Append a value (`list.Add`)
+        pub fn add(&mut self, token: impl Into<DataToken>) {
+            self.tokens.push(token.into());
+        }
+        
+        /// Get the value at `index` (`list[index]`).
+        ///
+        /// Panics if `index` is out of range, like the SDK indexer throws.
+        pub fn get(&self, index: i32) -> DataToken {
+            self.tokens[index as usize].clone()
+        }
+        
+        /// Get the value at `index` if it exists (`list.TryGetValue`)
+        pub fn try_get_value(&self, index: i32) -> Option<DataToken> {
+            usize::try_from(index).ok().and_then(|index| self.tokens.get(index).cloned())
+        }
+        
+        /// Number of values in the list (`list.Count`)
+        pub fn count(&self) -> i32 {
+            self.tokens.len() as i32
+        }
+        
+        /// Remove the value at `index` (`list.RemoveAt`)
+        pub fn remove_at(&mut self, index: i32) {
+            self.tokens.remove(index as usize);
+        }
+        
+        /// Remove every value (`list.Clear`)
+        pub fn clear(&mut self) {
+            self.tokens.clear();
+        }
+    }
+    
+    /// String-keyed [`DataToken`] values in insertion order (`VRC.SDK3.Data.DataDictionary`)
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct DataDictionary {
+        entries: Vec<(String, DataToken)>,
+    }
+    
+    impl DataDictionary {
+        /// Create an empty dictionary
+        pub fn new() -> Self {
+            Self::default()
+        }
+        
+        /// Set the value stored under `key` (`dictionary.SetValue`)
+        pub fn set_value(&mut self, key: &str, token: impl Into<DataToken>) {
+            let token = token.into();
+            match self.entries.iter_mut().find(|(k, _)| k == key) {
+                Some(entry) => entry.1 = token,
+                None => self.entries.push((key.to_string(), token)),
+            }
+        }
+        
+        /// Get the value stored under `key` if it exists (`dictionary.TryGetValue`)
+        pub fn try_get_value(&self, key: &str) -> Option<DataToken> {
+            self.entries.iter().find(|(k, _)| k == key).map(|(_, token)| token.clone())
+        }
+        
+        /// Whether a value is stored under `key` (`dictionary.ContainsKey`)
+        pub fn contains_key(&self, key: &str) -> bool {
+            self.entries.iter().any(|(k, _)| k == key)
+        }
+        
+        /// Remove the value stored under `key` (`dictionary.Remove`)
+        pub fn remove(&mut self, key: &str) -> bool {
+            let before = self.entries.len();
+            self.entries.retain(|(k, _)| k != key);
+            self.entries.len() != before
+        }
+        
+        /// Number of entries (`dictionary.Count`)
+        pub fn count(&self) -> i32 {
+            self.entries.len() as i32
+        }
+    }
+    
+    /// Output formatting of [`VRCJson::try_serialize_to_json`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum JsonExportType {
+        #[default]
+        Minify,
+        Beautify,
+    }
+    
+    /// VRChat's native JSON support (`VRC.SDK3.Data.VRCJson`).
+    ///
+    /// Like the SDK, deserialized numbers are always `Double` tokens and only
+    /// lists and dictionaries can be serialized.
+    pub struct VRCJson;
+    
+    impl VRCJson {
+        /// Serialize a list or dictionary token into a `String` token (`VRCJson.TrySerializeToJson`)
+        pub fn try_serialize_to_json(input: &DataToken, export_type: JsonExportType, result: &mut DataToken) -> bool {
+            if !matches!(input, DataToken::DataList(_) | DataToken::DataDictionary(_)) {
+                *result = DataToken::String("Only DataList and DataDictionary can be serialized".to_string());
+                return false;
+            }
+            let value = token_to_json(input);
+            let json = match export_type {
+                JsonExportType::Minify => serde_json::to_string(&value),
+                JsonExportType::Beautify => serde_json::to_string_pretty(&value),
+            };
+            match json {
+                Ok(json) => {
+                    *result = DataToken::String(json);
+                    true
+                }
+                Err(error) => {
+                    *result = DataToken::String(error.to_string());
+                    false
+                }
+            }
+        }
+        
+        /// Parse JSON into a list or dictionary token (`VRCJson.TryDeserializeFromJson`)
+        pub fn try_deserialize_from_json(json: &str, result: &mut DataToken) -> bool {
+            match serde_json::from_str::<serde_json::Value>(json) {
+                Ok(value @ (serde_json::Value::Array(_) | serde_json::Value::Object(_))) => {
+                    *result = json_to_token(value);
+                    true
+                }
+                Ok(_) => {
+                    *result = DataToken::String("JSON root must be an array or object".to_string());
+                    false
+                }
+                Err(error) => {
+                    *result = DataToken::String(error.to_string());
+                    false
+                }
+            }
+        }
+    }
+    
+    fn token_to_json(token: &DataToken) -> serde_json::Value {
+        use serde_json::Value;
+        match token {
+            DataToken::Null => Value::Null,
+            DataToken::Boolean(value) => Value::Bool(*value),
+            DataToken::Int(value) => Value::from(*value),
+            DataToken::Float(value) => Value::from(*value as f64),
+            DataToken::Double(value) => Value::from(*value),
+            DataToken::String(value) => Value::String(value.clone()),
+            DataToken::DataList(list) => Value::Array(list.tokens.iter().map(token_to_json).collect()),
+            DataToken::DataDictionary(dictionary) => Value::Object(
+                dictionary.entries.iter().map(|(k, v)| (k.clone(), token_to_json(v))).collect(),
+            ),
+        }
+    }
+    
+    fn json_to_token(value: serde_json::Value) -> DataToken {
+        use serde_json::Value;
+        match value {
+            Value::Null => DataToken::Null,
+            Value::Bool(value) => DataToken::Boolean(value),
+            Value::Number(number) => DataToken::Double(number.as_f64().unwrap_or_default()),
+            Value::String(value) => DataToken::String(value),
+            Value::Array(values) => DataToken::DataList(DataList {
+                tokens: values.into_iter().map(json_to_token).collect(),
+            }),
+            Value::Object(entries) => DataToken::DataDictionary(DataDictionary {
+                entries: entries.into_iter().map(|(k, v)| (k, json_to_token(v))).collect(),
+            }),
+        }
+    }
+    
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        
+        #[test]
+        fn test_data_list_round_trip() {
+            let mut list = DataList::new();
+            list.add(5);
+            list.add("spawn");
+            list.add(1.5f32);
+            
+            assert_eq!(list.count(), 3);
+            assert_eq!(list.get(0).as_int(), Some(5));
+            assert_eq!(list.get(1).as_string(), Some("spawn"));
+            assert_eq!(list.get(2).token_type(), TokenType::Float);
+            
+            list.remove_at(0);
+            assert_eq!(list.get(0).as_string(), Some("spawn"));
+            assert_eq!(list.try_get_value(5), None);
+        }
+        
+        #[test]
+        fn test_vrc_json_round_trip() {
+            let mut state = DataDictionary::new();
+            state.set_value("leader", "Avi");
+            state.set_value("round", 3);
+            
+            let mut json = DataToken::Null;
+            assert!(VRCJson::try_serialize_to_json(&state.into(), JsonExportType::Minify, &mut json));
+            assert_eq!(json.as_string(), Some(r#"{"leader":"Avi","round":3}"#));
+            
+            let mut parsed = DataToken::Null;
+            assert!(VRCJson::try_deserialize_from_json(json.as_string().unwrap(), &mut parsed));
+            let parsed = parsed.as_data_dictionary().unwrap();
+            assert_eq!(parsed.try_get_value("round"), Some(DataToken::Double(3.0)));
+            assert_eq!(parsed.try_get_value("leader").unwrap().as_string(), Some("Avi"));
+            
+            assert!(!VRCJson::try_deserialize_from_json("42", &mut DataToken::Null));
+        }
+    }
+}
+
 // Re-export commonly used types
+pub use data::*;
 pub use networking::*;
 pub use player::*;
 pub use world::*;
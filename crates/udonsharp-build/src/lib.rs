@@ -7,7 +7,7 @@ use anyhow::{Result, Context};
 use std::path::{Path, PathBuf};
 use std::env;
 use std::fs;
-use udonsharp_bindings::UniversalBindingPipeline;
+use udonsharp_bindings::{BindingConfig, BindingUsage, UniversalBindingPipeline};
 
 /// Configuration for UdonSharp build integration
 #[derive(Debug, Clone)]
@@ -24,6 +24,8 @@ pub struct UdonSharpBuild {
     pub is_workspace_member: bool,
     /// Workspace root directory (if applicable)
     pub workspace_root: Option<PathBuf>,
+    /// Whether to generate only the binding types and members the crate's sources refer to
+    pub strip_unused_bindings: bool,
 }
 
 impl Default for UdonSharpBuild {
@@ -35,6 +37,7 @@ impl Default for UdonSharpBuild {
             watch_asmdef_files: true,
             is_workspace_member: false,
             workspace_root: None,
+            strip_unused_bindings: false,
         }
     }
 }
@@ -69,6 +72,12 @@ impl UdonSharpBuild {
         self
     }
     
+    /// Enable or disable generating only the bindings the crate's sources refer to
+    pub fn strip_unused_bindings(mut self, enabled: bool) -> Self {
+        self.strip_unused_bindings = enabled;
+        self
+    }
+    
     /// Detect Unity project paths automatically
     pub fn with_unity_project_detection(mut self) -> Self {
         let unity_paths = detect_unity_paths();
@@ -145,8 +154,17 @@ impl UdonSharpBuild {
             }
         }
         
+        let mut config = BindingConfig::default();
+        if self.strip_unused_bindings {
+            if let Some(src_dir) = get_manifest_dir().map(|dir| dir.join("src")) {
+                println!("cargo:rerun-if-changed={}", src_dir.display());
+                config.used_apis = Some(BindingUsage::from_source_dir(&src_dir)
+                    .with_context(|| format!("Failed to scan {:?} for used APIs", src_dir))?);
+            }
+        }
+        
         // Generate bindings
-        match pipeline.scan_and_generate_all_bindings() {
+        match pipeline.scan_and_generate_bindings_with_config(&config) {
             Ok(_) => {
                 println!("cargo:warning=Successfully generated UdonSharp API bindings");
             }
@@ -563,6 +581,69 @@ impl Default for ScaffoldingConfig {
     }
 }
 
+/// Parent directories searched for a Unity project around a new Rust project
+const UNITY_PROJECT_SEARCH_DEPTH: usize = 4;
+
+/// VRChat SDK packages, in the order they are preferred as the SDK scan directory
+const VRC_SDK_PACKAGES: &[&str] = &[
+    "Packages/com.vrchat.worlds",
+    "Packages/com.vrchat.base",
+    "Assets/VRCSDK",
+];
+
+/// Unity project and VRChat SDK found next to a new Rust project
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedUnityProject {
+    /// Unity project root, relative to the Rust project
+    pub unity_project_path: String,
+    /// VRChat SDK directory, relative to the Rust project
+    pub vrc_sdk_path: Option<String>,
+}
+
+impl ScaffoldingConfig {
+    /// Fill in unset Unity and VRChat SDK paths from a Unity project enclosing `project_dir`
+    ///
+    /// Paths are written relative to the Rust project, since build scripts run
+    /// from the manifest directory, so the generated build.rs keeps working when
+    /// the Unity project is moved or cloned elsewhere.
+    pub fn apply_unity_project_detection(&mut self, project_dir: &Path) -> Option<DetectedUnityProject> {
+        let detected = detect_unity_project(project_dir)?;
+        if self.unity_project_path.is_none() {
+            self.unity_project_path = Some(detected.unity_project_path.clone());
+        }
+        if self.vrc_sdk_path.is_none() {
+            self.vrc_sdk_path = detected.vrc_sdk_path.clone();
+        }
+        Some(detected)
+    }
+}
+
+/// Find the Unity project enclosing `project_dir` and the VRChat SDK inside it
+///
+/// A Unity project is a directory with both `Assets` and `ProjectSettings`.
+/// `project_dir` itself is not searched since it is the new Rust project.
+pub fn detect_unity_project(project_dir: &Path) -> Option<DetectedUnityProject> {
+    let mut relative = PathBuf::new();
+    let mut candidate = project_dir.to_path_buf();
+
+    for _ in 0..UNITY_PROJECT_SEARCH_DEPTH {
+        candidate = candidate.parent()?.to_path_buf();
+        relative.push("..");
+
+        if candidate.join("Assets").is_dir() && candidate.join("ProjectSettings").is_dir() {
+            let vrc_sdk_path = VRC_SDK_PACKAGES.iter()
+                .find(|package| candidate.join(package).is_dir())
+                .map(|package| relative.join(package).to_string_lossy().replace('\\', "/"));
+            return Some(DetectedUnityProject {
+                unity_project_path: relative.to_string_lossy().replace('\\', "/"),
+                vrc_sdk_path,
+            });
+        }
+    }
+
+    None
+}
+
 /// Create a new UdonSharp project template
 pub fn create_project_template(
     project_dir: &Path,
@@ -2434,4 +2515,146 @@ impl CustomExample {
     
     fs::write(examples_dir.join("custom_example.rs"), custom_example)?;
     Ok(())
-}
\ No newline at end of file
+}
+/// Unity events a behavior skeleton can stub, as `(event, Rust method, parameters)`
+const SKELETON_EVENTS: &[(&str, &str, &str)] = &[
+    ("Awake", "awake", ""),
+    ("Start", "start", ""),
+    ("Update", "update", ""),
+    ("FixedUpdate", "fixed_update", ""),
+    ("LateUpdate", "late_update", ""),
+    ("OnEnable", "on_enable", ""),
+    ("OnDisable", "on_disable", ""),
+    ("OnDestroy", "on_destroy", ""),
+    ("OnPlayerJoined", "on_player_joined", "_player: VRCPlayerApi"),
+    ("OnPlayerLeft", "on_player_left", "_player: VRCPlayerApi"),
+    ("OnOwnershipTransferred", "on_ownership_transferred", "_player: VRCPlayerApi"),
+    ("OnBecameVisible", "on_became_visible", ""),
+    ("OnBecameInvisible", "on_became_invisible", ""),
+];
+
+/// Generate a `#[derive(UdonBehaviour)]` struct with the given events stubbed
+///
+/// Events are given by their Unity names, e.g. `Start` or `OnPlayerJoined`.
+/// `Start` is always stubbed because every behavior must implement it.
+pub fn behavior_skeleton(name: &str, events: &[String]) -> Result<String> {
+    let is_pascal_case = name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric());
+    if !is_pascal_case {
+        anyhow::bail!("Behavior name '{}' must be a PascalCase identifier", name);
+    }
+
+    let mut requested = vec!["Start".to_string()];
+    for event in events.iter().map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        if !SKELETON_EVENTS.iter().any(|(known, _, _)| known.eq_ignore_ascii_case(event)) {
+            let known: Vec<&str> = SKELETON_EVENTS.iter().map(|(known, _, _)| *known).collect();
+            anyhow::bail!("Unknown event '{}'; expected one of: {}", event, known.join(", "));
+        }
+        requested.push(event.to_string());
+    }
+
+    let overrides: Vec<String> = SKELETON_EVENTS.iter()
+        .filter(|(event, _, _)| requested.iter().any(|r| r.eq_ignore_ascii_case(event)))
+        .map(|(_, method, params)| {
+            let params = if params.is_empty() { String::new() } else { format!(", {}", params) };
+            format!("    fn {}(&mut self{}) {{\n    }}", method, params)
+        })
+        .collect();
+
+    Ok(format!(
+        "\n/// {name} behaviour\n#[derive(UdonBehaviour)]\npub struct {name} {{\n}}\n\n\
+         impl UdonBehaviour for {name} {{\n{overrides}\n}}\n\n\
+         impl {name} {{\n    pub fn new() -> Self {{\n        Self {{}}\n    }}\n}}\n",
+        name = name,
+        overrides = overrides.join("\n\n"),
+    ))
+}
+
+/// Append a behavior skeleton to the crate root of a project
+///
+/// The skeleton goes into `src/lib.rs` because the compiler discovers
+/// behaviors in the crate root. Returns the path of the file written.
+pub fn add_behavior_skeleton(project_dir: &Path, name: &str, events: &[String]) -> Result<PathBuf> {
+    let lib_path = project_dir.join("src").join("lib.rs");
+    let mut source = fs::read_to_string(&lib_path)
+        .with_context(|| format!("Failed to read {:?}", lib_path))?;
+
+    let declared = [" ", "{", ";", "("].iter()
+        .any(|next| source.contains(&format!("struct {}{}", name, next)));
+    if declared {
+        anyhow::bail!("{:?} already declares '{}'", lib_path, name);
+    }
+
+    let skeleton = behavior_skeleton(name, events)?;
+    if !source.ends_with('\n') {
+        source.push('\n');
+    }
+    source.push_str(&skeleton);
+    fs::write(&lib_path, source).with_context(|| format!("Failed to write {:?}", lib_path))?;
+
+    Ok(lib_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_behavior_skeleton_appends_compilable_struct() {
+        let project_dir = env::temp_dir().join(format!("udonsharp-add-behavior-{}", std::process::id()));
+        fs::create_dir_all(project_dir.join("src")).unwrap();
+        fs::write(project_dir.join("src/lib.rs"), "use udonsharp_core::prelude::*;\n").unwrap();
+
+        let events = vec!["Update".to_string(), "OnPlayerJoined".to_string()];
+        let lib_path = add_behavior_skeleton(&project_dir, "Scoreboard", &events).unwrap();
+        let source = fs::read_to_string(&lib_path).unwrap();
+        assert!(source.starts_with("use udonsharp_core::prelude::*;\n"));
+        assert!(source.contains("#[derive(UdonBehaviour)]\npub struct Scoreboard {"));
+
+        // The skeleton analyzes and generates C# with the requested overrides
+        let joined = udonsharp_compiler::expand_method(&source, "Scoreboard::on_player_joined").unwrap();
+        assert!(joined.contains("OnPlayerJoined("));
+        assert!(udonsharp_compiler::expand_method(&source, "Scoreboard::update").is_ok());
+        assert!(udonsharp_compiler::expand_method(&source, "Scoreboard::start").is_ok());
+
+        assert!(add_behavior_skeleton(&project_dir, "Scoreboard", &[]).is_err());
+        assert!(behavior_skeleton("Scoreboard", &["OnJump".to_string()]).is_err());
+        fs::remove_dir_all(&project_dir).unwrap();
+    }
+
+    #[test]
+    fn test_new_project_inside_unity_layout_writes_detected_scan_dirs() {
+        let unity_dir = env::temp_dir().join(format!("udonsharp-unity-detection-{}", std::process::id()));
+        for dir in ["Assets", "ProjectSettings", "Packages/com.vrchat.worlds", "Packages/com.vrchat.base"] {
+            fs::create_dir_all(unity_dir.join(dir)).unwrap();
+        }
+        let project_dir = unity_dir.join("Rust").join("arena");
+
+        let mut config = ScaffoldingConfig {
+            project_name: "arena".to_string(),
+            include_examples: false,
+            include_documentation: false,
+            ..Default::default()
+        };
+        let detected = config.apply_unity_project_detection(&project_dir).unwrap();
+        assert_eq!(detected, DetectedUnityProject {
+            unity_project_path: "../..".to_string(),
+            vrc_sdk_path: Some("../../Packages/com.vrchat.worlds".to_string()),
+        });
+
+        create_project_with_config(&project_dir, &config).unwrap();
+        let build_rs = fs::read_to_string(project_dir.join("build.rs")).unwrap();
+        assert!(build_rs.contains("config.add_asmdef_scan_dir(\"../../Packages/com.vrchat.worlds\");"), "{}", build_rs);
+        assert!(build_rs.contains("config.add_asmdef_scan_dir(\"../../Library/PackageCache\");"));
+        assert!(build_rs.contains("config.add_asmdef_scan_dir(\"../../Assets\");"));
+
+        // Paths given on the command line win over detected ones
+        let mut explicit = ScaffoldingConfig { vrc_sdk_path: Some("/sdk".to_string()), ..Default::default() };
+        explicit.apply_unity_project_detection(&project_dir);
+        assert_eq!(explicit.vrc_sdk_path.as_deref(), Some("/sdk"));
+        assert_eq!(explicit.unity_project_path.as_deref(), Some("../.."));
+
+        assert!(detect_unity_project(&env::temp_dir().join("udonsharp-no-unity").join("arena")).is_none());
+        fs::remove_dir_all(&unity_dir).unwrap();
+    }
+}
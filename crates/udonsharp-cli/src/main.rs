@@ -2,10 +2,11 @@
 
 use clap::{Parser, Subcommand, ValueEnum};
 use udonsharp_core::{UdonSharpResult, error::CompilationContext};
-use udonsharp_compiler::{CompilationPipeline, UdonSharpConfig};
+use udonsharp_compiler::{load_package_name, CompilationPipeline, ScriptSnapshot, UdonSharpConfig};
 use udonsharp_bindings::UniversalBindingPipeline;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::fs;
+use std::time::{Duration, SystemTime};
 use log::{info, warn, error};
 
 mod analyze_command;
@@ -72,7 +73,7 @@ enum Commands {
         /// Target directory for output
         #[arg(long)]
         target_dir: Option<String>,
-        /// Watch for changes and rebuild automatically
+        /// Watch for changes and rebuild automatically, writing a reload.signal for the Unity editor
         #[arg(long)]
         watch: bool,
         /// Number of parallel jobs
@@ -290,14 +291,6 @@ async fn handle_build_command(
     jobs: Option<usize>,
     progress: bool,
 ) -> UdonSharpResult<()> {
-    if watch {
-        info!("Starting build in watch mode...");
-        // TODO: Implement watch mode
-        return Err(udonsharp_core::UdonSharpError::configuration(
-            "Watch mode not yet implemented"
-        ));
-    }
-    
     let context = if debug {
         CompilationContext::debug()
     } else {
@@ -314,6 +307,7 @@ async fn handle_build_command(
     let mut config = UdonSharpConfig::default();
     config.optimize_for_performance = release;
     config.generate_debug_info = debug;
+    config.package_name = load_package_name(Path::new(".")).unwrap_or_default();
     
     if let Some(target) = target_dir {
         config.output_directory = Some(target);
@@ -323,6 +317,11 @@ async fn handle_build_command(
         config.parallel_jobs = Some(job_count);
     }
     
+    if watch {
+        info!("Starting build in watch mode...");
+        return run_watch_build(config, debug).await;
+    }
+    
     // Create compilation pipeline
     let pipeline = CompilationPipeline::with_context(config, context);
     
@@ -347,6 +346,69 @@ async fn handle_build_command(
     Ok(())
 }
 
+/// How often watch mode checks the sources for changes
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Rebuild whenever a file below `src/` changes, writing a `reload.signal`
+/// naming the rebuilt behaviors after each successful build so a Unity
+/// editor script can re-import only their scripts
+async fn run_watch_build(config: UdonSharpConfig, debug: bool) -> UdonSharpResult<()> {
+    let output_dir = PathBuf::from(config.output_directory.clone().unwrap_or_else(|| ".".to_string()));
+    let mut previous = ScriptSnapshot::default();
+    let mut last_change = None;
+    
+    println!("👀 Watching src/ for changes (Ctrl+C to stop)...");
+    loop {
+        let change = latest_modification(Path::new("src"));
+        if change != last_change {
+            last_change = change;
+            
+            let context = if debug {
+                CompilationContext::debug()
+            } else {
+                CompilationContext::new()
+            };
+            let pipeline = CompilationPipeline::with_context(config.clone(), context);
+            match pipeline.compile_project(".").await {
+                Ok(result) if result.success => {
+                    let snapshot = ScriptSnapshot::capture(&output_dir, &result.behavior_files);
+                    match snapshot.reload_signal(&previous) {
+                        Some(signal) => {
+                            signal.write_to(&output_dir).map_err(|e| {
+                                udonsharp_core::UdonSharpError::compilation(format!("Failed to write reload signal: {}", e))
+                            })?;
+                            println!("🔁 Rebuilt {}", signal.behaviors.join(", "));
+                        }
+                        None => println!("✅ Rebuilt, no behavior changed"),
+                    }
+                    previous = snapshot;
+                }
+                Ok(_) => {
+                    pipeline.context().print_summary();
+                    error!("Build failed; waiting for changes");
+                }
+                Err(e) => error!("Build failed: {}; waiting for changes", e),
+            }
+        }
+        tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+    }
+}
+
+/// Latest modification time of a directory and the entries below it;
+/// directories count too, so deleting or renaming a file is a change
+fn latest_modification(dir: &Path) -> Option<SystemTime> {
+    let mut latest = Some(fs::metadata(dir).ok()?.modified().ok()?);
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            latest = latest.max(latest_modification(&path));
+        } else {
+            latest = latest.max(entry.metadata().and_then(|metadata| metadata.modified()).ok());
+        }
+    }
+    latest
+}
+
 async fn handle_bindings_command(
     scan_dirs: Vec<String>,
     output: String,
@@ -1367,4 +1429,22 @@ fn print_yaml_info(info: &ProjectInfo) -> UdonSharpResult<()> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deleting_a_top_level_file_is_a_modification() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("lib.rs"), "pub struct Counter;").unwrap();
+        fs::write(dir.path().join("scores.rs"), "pub struct Scores;").unwrap();
+        let before = latest_modification(dir.path()).unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+        fs::remove_file(dir.path().join("scores.rs")).unwrap();
+
+        assert!(latest_modification(dir.path()).unwrap() > before);
+    }
+}
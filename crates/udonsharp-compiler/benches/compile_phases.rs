@@ -0,0 +1,176 @@
+//! Compile-time benchmarks for the compilation pipeline
+//!
+//! Measures end-to-end compile time of the fixture projects under
+//! `benches/fixtures` as well as the individual phases, so regressions show
+//! up as a change in a single phase rather than in the total only.
+//!
+//! Run all benchmarks with:
+//!
+//! ```text
+//! cargo bench -p udonsharp-compiler --bench compile_phases
+//! ```
+//!
+//! Pass a filter to run a subset, e.g. `-- wasm_phases` or `-- game-manager`.
+//! Criterion stores its baseline under `target/criterion`, so running the
+//! command on two revisions reports the change between them.
+//!
+//! WASM pipeline phases are timed through the `CompilationProfiler`, which
+//! folds the pipeline's phase log into profiled operations. Phases the
+//! pipeline does not run for a fixture are skipped.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use udonsharp_compiler::{
+    BehaviorDependencyAnalyzer, CodeGenerator, CompilationPipeline, PipelinePhase,
+    StandardMultiBehaviorIntegration, StructAnalyzer, UdonBehaviourStruct, UdonSharpConfig,
+};
+use udonsharp_core::error::CompilationContext;
+use udonsharp_performance::CompilationProfiler;
+
+/// Single behavior project compiled through the WASM pipeline
+const HELLO_WORLD: &str = "hello-world";
+
+/// Multi-behavior project compiled through the standard multi-behavior pattern
+const GAME_MANAGER: &str = "game-manager";
+
+/// WASM pipeline phases benchmarked individually
+const WASM_PHASES: [PipelinePhase; 4] = [
+    PipelinePhase::ParseWasm,
+    PipelinePhase::AnalyzeOop,
+    PipelinePhase::SplitBehaviors,
+    PipelinePhase::EmitCSharp,
+];
+
+fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/fixtures").join(name)
+}
+
+fn fixture_source(name: &str) -> String {
+    std::fs::read_to_string(fixture_path(name).join("src/lib.rs"))
+        .unwrap_or_else(|e| panic!("Failed to read fixture '{}': {}", name, e))
+}
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread().build().expect("Failed to build runtime")
+}
+
+/// Pipeline that writes its output to a scratch directory and always takes the WASM path
+fn wasm_pipeline(output: &Path) -> CompilationPipeline {
+    let mut config = UdonSharpConfig {
+        output_directory: Some(output.to_string_lossy().into_owned()),
+        ..UdonSharpConfig::default()
+    };
+    config.multi_behavior.enabled = false;
+    CompilationPipeline::new(config)
+}
+
+fn analyze(source: &str) -> Vec<UdonBehaviourStruct> {
+    let file: syn::File = syn::parse_str(source).expect("Fixture does not parse");
+    StructAnalyzer::new().analyze_module(&file.items).expect("Fixture analysis failed")
+}
+
+fn bench_end_to_end(c: &mut Criterion) {
+    let runtime = runtime();
+    let output = tempfile::tempdir().expect("Failed to create output directory");
+    let mut group = c.benchmark_group("end_to_end");
+
+    let pipeline = wasm_pipeline(output.path());
+    let hello_world = fixture_path(HELLO_WORLD);
+    group.bench_function(HELLO_WORLD, |b| {
+        b.iter(|| runtime.block_on(pipeline.compile_project(&hello_world)).expect("Compilation failed"))
+    });
+
+    // The integration is driven directly so the generated files stay in memory
+    let game_manager = fixture_source(GAME_MANAGER);
+    group.bench_function(GAME_MANAGER, |b| {
+        b.iter_batched(
+            || {
+                let mut config = UdonSharpConfig::default();
+                config.multi_behavior.generate_shared_runtime = false;
+                StandardMultiBehaviorIntegration::new(config, CompilationContext::new())
+            },
+            |mut integration| runtime.block_on(integration.compile_multi_behavior(&game_manager)).expect("Compilation failed"),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_wasm_phases(c: &mut Criterion) {
+    let runtime = runtime();
+    let output = tempfile::tempdir().expect("Failed to create output directory");
+    let pipeline = wasm_pipeline(output.path());
+    let mut group = c.benchmark_group("wasm_phases");
+
+    for fixture in [HELLO_WORLD, GAME_MANAGER] {
+        let project = fixture_path(fixture);
+
+        // Find out which phases this fixture goes through
+        runtime.block_on(pipeline.compile_project(&project)).expect("Compilation failed");
+        let mut profiler = CompilationProfiler::new();
+        profiler.record_phase_log(&pipeline.phase_log());
+
+        for phase in WASM_PHASES {
+            if profiler.get_operation_results(phase.name()).is_none() {
+                eprintln!("Skipping '{}' for {}: the pipeline does not run it", phase, fixture);
+                continue;
+            }
+
+            group.bench_function(BenchmarkId::new(phase.name(), fixture), |b| {
+                b.iter_custom(|iterations| {
+                    let mut total = Duration::ZERO;
+                    for _ in 0..iterations {
+                        runtime.block_on(pipeline.compile_project(&project)).expect("Compilation failed");
+                        let mut profiler = CompilationProfiler::new();
+                        profiler.record_phase_log(&pipeline.phase_log());
+                        total += profiler.get_operation_results(phase.name())
+                            .map(|operation| operation.duration)
+                            .unwrap_or_default();
+                    }
+                    total
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_source_phases(c: &mut Criterion) {
+    let mut group = c.benchmark_group("source_phases");
+
+    for fixture in [HELLO_WORLD, GAME_MANAGER] {
+        let source = fixture_source(fixture);
+        let behaviors = analyze(&source);
+
+        group.bench_function(BenchmarkId::new("analyze", fixture), |b| b.iter(|| analyze(&source)));
+
+        group.bench_function(BenchmarkId::new("split", fixture), |b| {
+            b.iter(|| {
+                BehaviorDependencyAnalyzer::new()
+                    .analyze_dependencies(behaviors.clone())
+                    .expect("Dependency analysis failed")
+            })
+        });
+
+        group.bench_function(BenchmarkId::new("codegen", fixture), |b| {
+            b.iter_batched(
+                CodeGenerator::new,
+                |mut generator| {
+                    generator.register_behaviors(&behaviors);
+                    for behavior in &behaviors {
+                        generator.generate_behavior_class(behavior).expect("Code generation failed");
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_end_to_end, bench_wasm_phases, bench_source_phases);
+criterion_main!(benches);
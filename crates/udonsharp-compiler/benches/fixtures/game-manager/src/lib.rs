@@ -0,0 +1,105 @@
+//! Game-manager benchmark fixture: three cooperating behaviors with synced
+//! state, cross-behavior references and custom events
+
+use udonsharp_core::prelude::*;
+use udonsharp_bindings::*;
+
+#[derive(UdonBehaviour)]
+#[udon_sync_mode(Manual)]
+pub struct GameManager {
+    #[udon_public]
+    round_duration: f32,
+    #[udon_sync]
+    pub current_round: i32,
+    #[udon_sync]
+    pub time_remaining: f32,
+    score_board: Option<unity::GameObject>,
+    round_timer: f32,
+}
+
+impl UdonBehaviour for GameManager {
+    fn start(&mut self) {
+        self.round_duration = 120.0;
+        self.current_round = 0;
+        self.score_board = unity::GameObject::find("ScoreBoard");
+    }
+
+    fn update(&mut self) {
+        if !networking::is_master() {
+            return;
+        }
+        self.round_timer += unity::time::delta_time();
+        self.time_remaining = self.round_duration - self.round_timer;
+        if self.time_remaining <= 0.0 {
+            self.end_round();
+        }
+    }
+}
+
+impl GameManager {
+    pub fn start_round(&mut self) {
+        if !networking::is_master() {
+            return;
+        }
+        self.current_round += 1;
+        self.round_timer = 0.0;
+        networking::request_serialization();
+    }
+
+    pub fn end_round(&mut self) {
+        if let Some(board) = &self.score_board {
+            board.send_custom_event("ShowResults");
+        }
+        self.start_round();
+    }
+}
+
+#[derive(UdonBehaviour)]
+pub struct ScoreBoard {
+    #[udon_sync]
+    pub high_score: i32,
+    last_score: i32,
+    score_label: Option<unity::GameObject>,
+}
+
+impl UdonBehaviour for ScoreBoard {
+    fn start(&mut self) {
+        self.high_score = 0;
+        self.score_label = unity::GameObject::find("ScoreLabel");
+    }
+}
+
+impl ScoreBoard {
+    pub fn add_score(&mut self, points: i32) {
+        self.last_score = points;
+        if points > self.high_score {
+            self.high_score = points;
+        }
+    }
+
+    #[udon_event("ShowResults")]
+    pub fn show_results(&mut self) {
+        log_info(&format!("High score: {}", self.high_score));
+    }
+}
+
+#[derive(UdonBehaviour)]
+pub struct PlayerTracker {
+    player_count: i32,
+    game_manager: Option<unity::GameObject>,
+}
+
+impl UdonBehaviour for PlayerTracker {
+    fn start(&mut self) {
+        self.game_manager = unity::GameObject::find("GameManager");
+    }
+
+    fn on_player_joined(&mut self, player: VRCPlayerApi) {
+        self.player_count += 1;
+        log_info(&format!("{} joined", player.display_name()));
+    }
+
+    fn on_player_left(&mut self, _player: VRCPlayerApi) {
+        self.player_count -= 1;
+    }
+}
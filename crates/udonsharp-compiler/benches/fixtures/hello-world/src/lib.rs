@@ -0,0 +1,16 @@
+//! Hello-world benchmark fixture: a single behavior that logs on start
+
+use udonsharp_core::prelude::*;
+
+#[derive(UdonBehaviour)]
+pub struct HelloWorld {
+    #[udon_public]
+    greeting: String,
+}
+
+impl UdonBehaviour for HelloWorld {
+    fn start(&mut self) {
+        self.greeting = "Hello, VRChat!".to_string();
+        log_info(&self.greeting);
+    }
+}
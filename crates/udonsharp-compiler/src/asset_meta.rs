@@ -0,0 +1,186 @@
+//! Unity `.meta` file generation for emitted assets
+//!
+//! Unity identifies every asset by the GUID stored in its sibling `.meta`
+//! file. Without one, Unity invents a fresh GUID on import and any prefab or
+//! scene reference to the asset breaks on the next regeneration. This module
+//! derives a stable GUID for each generated asset and writes the matching
+//! `.meta` file, keeping the GUID of a `.meta` that already exists on disk.
+//!
+//! GUIDs are derived from the package name together with the asset's path
+//! below the output directory. Every project generates a `SharedRuntime.cs`
+//! and a `behaviors.manifest.json`, so a GUID of the file name alone would
+//! collide as soon as two generated crates are imported into one Unity project.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Namespace used to derive asset GUIDs from asset paths
+const ASSET_GUID_NAMESPACE: Uuid = Uuid::from_bytes([
+    0x5d, 0x1c, 0x9a, 0x4e, 0x27, 0xb3, 0x4f, 0x81,
+    0x9c, 0x62, 0x0e, 0x7a, 0xd4, 0x35, 0x18, 0xf0,
+]);
+
+/// Kind of Unity importer an asset is handled by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetMetaKind {
+    /// C# scripts (`.cs`)
+    MonoScript,
+    /// Prefabs (`.prefab`)
+    Prefab,
+    /// Any other asset, including scenes
+    Default,
+}
+
+impl AssetMetaKind {
+    /// Determine the importer kind from an asset path
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("cs") => AssetMetaKind::MonoScript,
+            Some("prefab") => AssetMetaKind::Prefab,
+            _ => AssetMetaKind::Default,
+        }
+    }
+}
+
+/// Derive the stable GUID of an asset from the package that generates it and
+/// its path relative to the output directory, with `/` separators.
+///
+/// The GUID only depends on these, so regenerating a project without its
+/// `.meta` files still reproduces the same GUIDs.
+pub fn stable_guid(package_name: &str, relative_path: &str) -> String {
+    Uuid::new_v5(&ASSET_GUID_NAMESPACE, format!("{}/{}", package_name, relative_path).as_bytes())
+        .simple()
+        .to_string()
+}
+
+/// Package and output directory the GUIDs of generated assets are derived from
+#[derive(Debug, Clone, Copy)]
+pub struct AssetGuidSeed<'a> {
+    /// Name of the package whose assets are generated
+    pub package_name: &'a str,
+    /// Directory the assets are written below
+    pub output_dir: &'a Path,
+}
+
+impl<'a> AssetGuidSeed<'a> {
+    /// Create a seed for the assets a package writes below `output_dir`
+    pub fn new(package_name: &'a str, output_dir: &'a Path) -> Self {
+        Self { package_name, output_dir }
+    }
+
+    /// Derive the stable GUID of an asset written below the output directory
+    pub fn guid(&self, asset_path: &Path) -> String {
+        let relative = asset_path.strip_prefix(self.output_dir).unwrap_or(asset_path);
+        let relative: Vec<String> = relative.components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        stable_guid(self.package_name, &relative.join("/"))
+    }
+}
+
+/// Get the path of the `.meta` file that belongs to an asset
+pub fn meta_path(asset_path: &Path) -> PathBuf {
+    let mut path = asset_path.as_os_str().to_owned();
+    path.push(".meta");
+    PathBuf::from(path)
+}
+
+/// Read the GUID recorded in an existing `.meta` file
+pub fn read_meta_guid(meta_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(meta_path).ok()?;
+    content.lines()
+        .find_map(|line| line.strip_prefix("guid:"))
+        .map(|guid| guid.trim().to_string())
+        .filter(|guid| guid.len() == 32 && guid.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Generate the content of a `.meta` file
+pub fn meta_file_content(kind: AssetMetaKind, guid: &str) -> String {
+    meta_file_content_with_execution_order(kind, guid, 0)
+}
+
+/// Generate the content of a `.meta` file, recording the script execution
+/// order of a `.cs` asset
+pub fn meta_file_content_with_execution_order(kind: AssetMetaKind, guid: &str, execution_order: i32) -> String {
+    let importer = match kind {
+        AssetMetaKind::MonoScript => format!(
+            concat!(
+                "MonoImporter:\n",
+                "  externalObjects: {{}}\n",
+                "  serializedVersion: 2\n",
+                "  defaultReferences: []\n",
+                "  executionOrder: {}\n",
+                "  icon: {{instanceID: 0}}\n",
+            ),
+            execution_order
+        ),
+        AssetMetaKind::Prefab => "PrefabImporter:\n  externalObjects: {}\n".to_string(),
+        AssetMetaKind::Default => "DefaultImporter:\n  externalObjects: {}\n".to_string(),
+    };
+
+    format!(
+        "fileFormatVersion: 2\nguid: {}\n{}  userData: \n  assetBundleName: \n  assetBundleVariant: \n",
+        guid, importer
+    )
+}
+
+/// Write the `.meta` file for an asset and return the GUID it records.
+///
+/// A GUID already persisted in an existing `.meta` file is kept so Unity
+/// references to the asset survive regeneration.
+pub fn write_asset_meta(asset_path: &Path, seed: &AssetGuidSeed) -> io::Result<String> {
+    write_asset_meta_with_execution_order(asset_path, seed, 0)
+}
+
+/// Write the `.meta` file for a script that runs at the given execution order
+/// and return the GUID it records
+pub fn write_asset_meta_with_execution_order(asset_path: &Path, seed: &AssetGuidSeed, execution_order: i32) -> io::Result<String> {
+    let meta_path = meta_path(asset_path);
+    let guid = read_meta_guid(&meta_path).unwrap_or_else(|| seed.guid(asset_path));
+
+    let content = meta_file_content_with_execution_order(AssetMetaKind::from_path(asset_path), &guid, execution_order);
+    fs::write(&meta_path, content)?;
+    Ok(guid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_guid_is_deterministic() {
+        let guid = stable_guid("world", "GameManager.cs");
+        assert_eq!(guid, stable_guid("world", "GameManager.cs"));
+        assert_ne!(guid, stable_guid("world", "UIController.cs"));
+        assert_ne!(guid, stable_guid("world", "Scripts/GameManager.cs"));
+        assert_eq!(guid.len(), 32);
+    }
+
+    #[test]
+    fn test_stable_guid_differs_between_packages() {
+        assert_ne!(stable_guid("lobby_world", "SharedRuntime.cs"), stable_guid("arena_world", "SharedRuntime.cs"));
+
+        let output = Path::new("Assets/Generated");
+        let lobby = AssetGuidSeed::new("lobby_world", output);
+        let arena = AssetGuidSeed::new("arena_world", output);
+        let asset = output.join("SharedRuntime.cs");
+        assert_eq!(lobby.guid(&asset), stable_guid("lobby_world", "SharedRuntime.cs"));
+        assert_ne!(lobby.guid(&asset), arena.guid(&asset));
+    }
+
+    #[test]
+    fn test_existing_meta_guid_is_preserved() {
+        let dir = tempfile::tempdir().unwrap();
+        let asset = dir.path().join("GameManager.cs");
+        fs::write(&asset, "public class GameManager {}").unwrap();
+
+        let persisted = "0123456789abcdef0123456789abcdef";
+        fs::write(meta_path(&asset), meta_file_content(AssetMetaKind::MonoScript, persisted)).unwrap();
+
+        let seed = AssetGuidSeed::new("world", dir.path());
+        assert_eq!(write_asset_meta(&asset, &seed).unwrap(), persisted);
+        assert_eq!(read_meta_guid(&meta_path(&asset)).as_deref(), Some(persisted));
+    }
+}
@@ -0,0 +1,304 @@
+//! Machine-readable manifest of the generated behaviors
+//!
+//! Unity-side tooling that sets up a world from the pipeline's output, such
+//! as an editor importer creating one GameObject per behavior, needs to know
+//! what was generated without parsing C#. Every build writes a
+//! `behaviors.manifest.json` next to the scripts listing each behavior's
+//! class, script file, script GUID, execution order, public fields and
+//! events, plus hints for wiring UI buttons to those events. The manifest is the contract with that tooling: keys are
+//! camelCase so Unity's `JsonUtility` reads them directly, and `version` is
+//! bumped whenever an existing key changes meaning.
+
+use serde::{Deserialize, Serialize};
+
+use crate::asset_meta::stable_guid;
+use crate::code_generator::{to_camel_case, to_pascal_case};
+use crate::multi_behavior::{FieldAttribute, MethodAttribute, RustToCSharpTypeMapper, UdonBehaviourStruct};
+
+/// File name of the manifest
+pub const BEHAVIOR_MANIFEST_FILE: &str = "behaviors.manifest.json";
+
+/// Version of the manifest format
+pub const BEHAVIOR_MANIFEST_VERSION: u32 = 1;
+
+/// Every behavior generated by a build
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BehaviorManifest {
+    pub version: u32,
+    /// Behaviors ordered by class name
+    pub behaviors: Vec<ManifestBehavior>,
+}
+
+/// One generated behavior
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestBehavior {
+    pub class_name: String,
+    /// Script path relative to the output directory
+    pub file: String,
+    /// GUID recorded in the script's `.meta` file
+    pub guid: String,
+    /// Script execution order; 0 when the behavior does not set one
+    pub execution_order: i32,
+    /// `#[udon_public]` fields, in declaration order
+    pub public_fields: Vec<ManifestField>,
+    /// Custom and network events the behavior handles
+    pub events: Vec<ManifestEvent>,
+    /// Buttons to wire to the behavior's click events
+    #[serde(default)]
+    pub ui_wiring: Vec<UiWiringHint>,
+}
+
+/// A field shown in the inspector
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestField {
+    /// C# name Unity serializes the value under
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub synced: bool,
+}
+
+/// An event other objects can send to the behavior
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEvent {
+    /// Name passed to `SendCustomEvent`/`SendCustomNetworkEvent`
+    pub name: String,
+    /// `"custom"` or `"network"`
+    pub kind: String,
+}
+
+/// A Button whose OnClick should send one of the behavior's custom events
+///
+/// Hints are inferred from names: `OnStartButtonClick` is paired with a
+/// public `start_button: Button` field. A click event no Button field
+/// matches still gets a hint, without a field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiWiringHint {
+    /// Event the Button's OnClick sends through `SendCustomEvent`
+    pub event: String,
+    /// C# name of the public Button field the event belongs to
+    pub button_field: Option<String>,
+    /// The wiring step, to show the creator
+    pub hint: String,
+}
+
+impl BehaviorManifest {
+    /// Describe the behaviors `package_name` writes as `{ClassName}.cs`, each
+    /// with the stable GUID its `.meta` file gets when none exists yet
+    pub fn new(behaviors: &[UdonBehaviourStruct], package_name: &str) -> Self {
+        Self::with_extension(behaviors, "cs", package_name)
+    }
+
+    /// Describe the behaviors `package_name` writes as `{ClassName}.{extension}`
+    pub fn with_extension(behaviors: &[UdonBehaviourStruct], extension: &str, package_name: &str) -> Self {
+        let mut behaviors: Vec<ManifestBehavior> = behaviors.iter()
+            .map(|behavior| ManifestBehavior::new(behavior, extension, package_name))
+            .collect();
+        behaviors.sort_by(|a, b| a.class_name.cmp(&b.class_name));
+        Self { version: BEHAVIOR_MANIFEST_VERSION, behaviors }
+    }
+
+    /// Record the GUID actually written for a script, which differs from the
+    /// stable one when an existing `.meta` file was kept
+    pub fn set_guid(&mut self, file: &str, guid: &str) {
+        if let Some(behavior) = self.behaviors.iter_mut().find(|behavior| behavior.file == file) {
+            behavior.guid = guid.to_string();
+        }
+    }
+
+    /// Serialize the manifest as written to disk
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl ManifestBehavior {
+    fn new(udon_struct: &UdonBehaviourStruct, extension: &str, package_name: &str) -> Self {
+        let type_mapper = RustToCSharpTypeMapper::new();
+        let file = format!("{}.{}", udon_struct.name, extension);
+
+        let public_fields = udon_struct.fields.iter()
+            .filter(|field| field.attributes.contains(&FieldAttribute::UdonPublic))
+            .map(|field| ManifestField {
+                name: to_camel_case(&field.name),
+                field_type: type_mapper.map_type(&field.field_type).unwrap_or_else(|_| "object".to_string()),
+                synced: field.has_sync_attribute(),
+            })
+            .collect::<Vec<_>>();
+
+        let events = udon_struct.methods.iter()
+            .flat_map(|method| {
+                let custom = method.attributes.iter().filter_map(|attribute| match attribute {
+                    MethodAttribute::UdonEvent(name) => Some(ManifestEvent {
+                        name: name.clone(),
+                        kind: "custom".to_string(),
+                    }),
+                    _ => None,
+                });
+                let network = method.network_event().map(|(name, _)| ManifestEvent {
+                    name: name.to_string(),
+                    kind: "network".to_string(),
+                });
+                custom.chain(network).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let ui_wiring = ui_wiring_hints(&udon_struct.name, &public_fields, &events);
+
+        Self {
+            class_name: udon_struct.name.clone(),
+            guid: stable_guid(package_name, &file),
+            file,
+            execution_order: udon_struct.execution_order().unwrap_or(0),
+            public_fields,
+            events,
+            ui_wiring,
+        }
+    }
+}
+
+/// Pair the custom events named after a click with the public Button fields
+/// they belong to
+fn ui_wiring_hints(class_name: &str, public_fields: &[ManifestField], events: &[ManifestEvent]) -> Vec<UiWiringHint> {
+    let buttons: Vec<(&ManifestField, String)> = public_fields.iter()
+        .filter(|field| field.field_type == "Button")
+        .map(|field| (field, to_pascal_case(&field.name)))
+        .collect();
+
+    events.iter()
+        .filter(|event| event.kind == "custom")
+        .filter_map(|event| {
+            let target = click_target(&event.name)?;
+            let button = buttons.iter().find(|(_, pascal)| pascal.strip_suffix("Button").unwrap_or(pascal) == target);
+            let hint = match button {
+                Some((_, pascal)) => format!("Add SendCustomEvent '{}' to Button '{}' on {}", event.name, pascal, class_name),
+                None => format!(
+                    "Add SendCustomEvent '{}' to the OnClick of the Button that triggers it, targeting {}",
+                    event.name, class_name
+                ),
+            };
+            Some(UiWiringHint {
+                event: event.name.clone(),
+                button_field: button.map(|(field, _)| field.name.clone()),
+                hint,
+            })
+        })
+        .collect()
+}
+
+/// What a click event is named after: `OnStartButtonClick` and
+/// `StartClicked` both give `Start`; `None` when the name is not a click
+fn click_target(event: &str) -> Option<&str> {
+    let name = event.strip_prefix("On").filter(|rest| rest.starts_with(char::is_uppercase)).unwrap_or(event);
+    let name = ["Clicked", "Click", "Pressed"].iter().find_map(|suffix| name.strip_suffix(suffix))?;
+    let name = name.strip_suffix("Button").unwrap_or(name);
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_lists_public_fields_and_events() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                #[udon_execution_order(-10)]
+                pub struct GameManager {
+                    #[udon_public]
+                    pub round_duration: f32,
+                    #[udon_public]
+                    #[udon_sync]
+                    pub current_round: i32,
+                    round_timer: f32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for GameManager {
+                    fn start(&mut self) {}
+                }
+            },
+            syn::parse_quote! {
+                impl GameManager {
+                    #[udon_event("ReturnToLobby")]
+                    pub fn return_to_lobby(&mut self) {}
+
+                    #[udon_network_event(target = "All")]
+                    pub fn start_round(&mut self) {}
+                }
+            },
+        ];
+        let behaviors = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&items).unwrap();
+        let manifest = BehaviorManifest::new(&behaviors, "world");
+
+        let game_manager = &manifest.behaviors[0];
+        assert_eq!(game_manager.execution_order, -10);
+        assert_eq!(game_manager.public_fields, vec![
+            ManifestField { name: "roundDuration".to_string(), field_type: "float".to_string(), synced: false },
+            ManifestField { name: "currentRound".to_string(), field_type: "int".to_string(), synced: true },
+        ]);
+        assert_eq!(game_manager.events, vec![
+            ManifestEvent { name: "ReturnToLobby".to_string(), kind: "custom".to_string() },
+            ManifestEvent { name: "StartRound".to_string(), kind: "network".to_string() },
+        ]);
+
+        assert!(game_manager.ui_wiring.is_empty());
+
+        let json: serde_json::Value = serde_json::from_str(&manifest.to_json().unwrap()).unwrap();
+        assert_eq!(json["behaviors"][0]["className"], "GameManager");
+        assert_eq!(json["behaviors"][0]["publicFields"][0]["type"], "float");
+    }
+
+    #[test]
+    fn test_click_event_with_button_field_gets_a_wiring_hint() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct LobbyMenu {
+                    #[udon_public]
+                    pub start_button: Option<Button>,
+                    #[udon_public]
+                    pub title: Option<Text>,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for LobbyMenu {
+                    fn start(&mut self) {}
+                }
+            },
+            syn::parse_quote! {
+                impl LobbyMenu {
+                    #[udon_event("OnStartButtonClick")]
+                    pub fn on_start_button_click(&mut self) {}
+
+                    #[udon_event("OnQuitClicked")]
+                    pub fn on_quit_clicked(&mut self) {}
+
+                    #[udon_event("ResetRound")]
+                    pub fn reset_round(&mut self) {}
+                }
+            },
+        ];
+        let behaviors = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&items).unwrap();
+        let manifest = BehaviorManifest::new(&behaviors, "world");
+
+        assert_eq!(manifest.behaviors[0].ui_wiring, vec![
+            UiWiringHint {
+                event: "OnStartButtonClick".to_string(),
+                button_field: Some("startButton".to_string()),
+                hint: "Add SendCustomEvent 'OnStartButtonClick' to Button 'StartButton' on LobbyMenu".to_string(),
+            },
+            UiWiringHint {
+                event: "OnQuitClicked".to_string(),
+                button_field: None,
+                hint: "Add SendCustomEvent 'OnQuitClicked' to the OnClick of the Button that triggers it, targeting LobbyMenu".to_string(),
+            },
+        ]);
+    }
+}
@@ -0,0 +1,5239 @@
+//! Lowering of Rust method bodies into UdonSharp C# statements
+//!
+//! This module walks the `syn` AST of a method body and emits equivalent C#
+//! source for the generated UdonSharpBehaviour class. Field accesses and calls
+//! through `self` are rewritten to target the generated class members,
+//! including members of embedded structs that were flattened into the behavior.
+
+use crate::multi_behavior::{is_data_container, EmbeddedStruct, IntEnum, MethodParameter, UdonBehaviourStruct, RustType, RustToCSharpTypeMapper, ELAPSED_TIMER};
+use crate::struct_analyzer::is_int_enum_constructor;
+use crate::serde_compat::is_self_to_json_call;
+use crate::config::AssertionMode;
+use crate::record_pool::RecordPool;
+use crate::const_tables::ConstTable;
+use crate::payload_enum::{payload_tag, read_payload_field, PayloadEnum, PayloadVariant, PAYLOAD_ENUM_RECORD_TYPE};
+use crate::code_generator::{to_camel_case, to_pascal_case};
+use crate::helper_inlining::ParameterSubstitution;
+use syn::{Block, Expr, Lit, Member, Pat, Stmt, Type, BinOp, UnOp, RangeLimits};
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+
+/// Result type for body lowering operations
+pub type LoweringResult<T> = Result<T, LoweringError>;
+
+/// Errors that can occur while lowering a method body
+#[derive(Debug, Clone)]
+pub enum LoweringError {
+    /// The body source could not be parsed
+    ParseError { message: String },
+    /// Expression has no UdonSharp equivalent
+    UnsupportedExpression { expression: String, reason: String },
+    /// Statement has no UdonSharp equivalent
+    UnsupportedStatement { statement: String, reason: String },
+    /// A `match` over an enum misses variants and has no wildcard arm
+    NonExhaustiveMatch { enum_name: String, missing: Vec<String> },
+    /// A `loop` has no `break`, `return` or `?` to leave it
+    UnboundedLoop,
+}
+
+impl std::fmt::Display for LoweringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoweringError::ParseError { message } => {
+                write!(f, "Failed to parse method body: {}", message)
+            }
+            LoweringError::UnsupportedExpression { expression, reason } => {
+                write!(f, "Unsupported expression '{}': {}", expression, reason)
+            }
+            LoweringError::UnsupportedStatement { statement, reason } => {
+                write!(f, "Unsupported statement '{}': {}", statement, reason)
+            }
+            LoweringError::NonExhaustiveMatch { enum_name, missing } => {
+                write!(
+                    f,
+                    "Non-exhaustive match over '{}': variant(s) {} not handled and there is no `_` arm",
+                    enum_name,
+                    missing.iter().map(|v| format!("'{}'", v)).collect::<Vec<_>>().join(", ")
+                )
+            }
+            LoweringError::UnboundedLoop => {
+                write!(f, "`loop` has no `break`, `return` or `?` to leave it and would hang the Udon VM")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoweringError {}
+
+/// Context describing the behavior a body is lowered for
+#[derive(Debug, Clone, Default)]
+pub struct LoweringContext {
+    /// Embedded structs keyed by the behavior field that holds them
+    embedded_structs: HashMap<String, EmbeddedStruct>,
+    /// Embedded field whose method is being lowered, if any
+    self_prefix: Option<String>,
+    /// Other behaviors reachable through `get_component::<T>()`, keyed by name
+    behaviors: HashMap<String, BehaviorInterface>,
+    /// Behavior fields holding a `DataList`
+    data_list_fields: HashSet<String>,
+    /// Behavior fields holding an `ElapsedTimer`, emitted as the `float` time it started at
+    elapsed_timer_fields: HashSet<String>,
+    /// Behavior fields holding a `Vec<T>`, emitted as arrays, mapped to the C# element type
+    array_fields: HashMap<String, String>,
+    /// Parameters of the method being lowered taking a `&[T]` slice or a `Vec<T>`,
+    /// passed as arrays, mapped to the C# element type
+    array_parameters: HashMap<String, String>,
+    /// Behavior fields holding an `Option` of a reference type, with whether
+    /// they are public and so assigned in the inspector
+    option_ref_fields: HashMap<String, bool>,
+    /// Name of the behavior the bodies belong to
+    behavior_name: String,
+    /// Whether unwraps of public `Option` references are guarded by a null check
+    safe_refs: bool,
+    /// Whether the behavior is `#[udon_json]` and so has a VRCJson `ToJson` method
+    json_serializable: bool,
+    /// Behavior fields holding an `UdonRef<T>`, mapped to the behavior `T`
+    udon_ref_fields: HashMap<String, String>,
+    /// Behavior fields of integer types, used to pick overflow bounds
+    integer_fields: HashMap<String, IntegerType>,
+    /// Behavior fields mapped to their C# type, used to type bindings of computed values
+    field_types: HashMap<String, String>,
+    /// Parameters of the method being lowered mapped to their C# type
+    parameter_types: HashMap<String, String>,
+    /// Fieldless enums converted from integers through a generated switch
+    int_enums: HashMap<String, IntEnum>,
+    /// Every known fieldless enum, used to check `match` exhaustiveness
+    enums: HashMap<String, IntEnum>,
+    /// Enums with data, held as tagged `DataDictionary` records
+    payload_enums: HashMap<String, PayloadEnum>,
+    /// Whether `log::debug!` and `log::trace!` calls are dropped
+    strip_debug_logs: bool,
+    /// How `assert!`-family macros are lowered
+    assertion_mode: AssertionMode,
+    /// Declared return type of the method being lowered, if known
+    return_type: Option<RustType>,
+    /// Pool that allocations of its record type draw from, for `#[udon_pool]` behaviors
+    record_pool: Option<RecordPool>,
+    /// Module constant tables, mapped to the static array they are read from
+    const_tables: HashMap<String, String>,
+}
+
+/// The surface of another behavior that lowered code can reach through Udon
+#[derive(Debug, Clone, Default)]
+pub struct BehaviorInterface {
+    /// Fields keyed by Rust name
+    pub fields: HashMap<String, BehaviorFieldInfo>,
+    /// Methods keyed by Rust name, with whether they return a value
+    pub methods: HashMap<String, bool>,
+}
+
+/// A field of another behavior as seen from lowered code
+#[derive(Debug, Clone)]
+pub struct BehaviorFieldInfo {
+    /// C# type used to cast `GetProgramVariable` results
+    pub csharp_type: String,
+    /// Whether the field is a public Udon variable
+    pub is_public: bool,
+    /// Whether the field is synced, which makes it readable from other behaviors
+    pub is_synced: bool,
+}
+
+impl BehaviorFieldInfo {
+    /// Whether other behaviors may read the field through `GetProgramVariable`
+    pub fn is_readable(&self) -> bool {
+        self.is_public || self.is_synced
+    }
+}
+
+impl BehaviorInterface {
+    /// Build the interface of a behavior struct
+    pub fn from_behavior(udon_struct: &UdonBehaviourStruct) -> Self {
+        let type_mapper = RustToCSharpTypeMapper::new();
+        let mut interface = Self::default();
+
+        for field in &udon_struct.fields {
+            let csharp_type = type_mapper.map_type(&field.field_type)
+                .unwrap_or_else(|_| "object".to_string());
+            interface.fields.insert(field.name.clone(), BehaviorFieldInfo {
+                csharp_type,
+                is_public: field.is_public(),
+                is_synced: field.has_sync_attribute(),
+            });
+        }
+
+        for method in &udon_struct.methods {
+            interface.methods.insert(method.name.clone(), method.return_type != RustType::Unit);
+        }
+
+        interface
+    }
+}
+
+impl LoweringContext {
+    /// Create an empty lowering context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a lowering context for a behavior
+    pub fn for_behavior(udon_struct: &UdonBehaviourStruct) -> Self {
+        let mut context = Self::new();
+        context.behavior_name = udon_struct.name.clone();
+        context.json_serializable = udon_struct.is_json_serializable();
+        context.record_pool = RecordPool::for_behavior(udon_struct);
+        for embedded in &udon_struct.embedded_structs {
+            context.add_embedded_struct(embedded.clone());
+        }
+        for field in &udon_struct.fields {
+            if field.field_type == RustType::Custom("DataList".to_string()) {
+                context.add_data_list_field(field.name.clone());
+            }
+            if field.field_type == RustType::Custom(ELAPSED_TIMER.to_string()) {
+                context.add_elapsed_timer_field(field.name.clone());
+            }
+            if let RustType::UdonRef(target) = &field.field_type {
+                context.add_udon_ref_field(field.name.clone(), target.clone());
+            }
+            if let RustType::Option(inner) = &field.field_type {
+                if is_reference_type(inner) {
+                    context.option_ref_fields.insert(field.name.clone(), field.is_public());
+                }
+            }
+            if let RustType::Vec(element) = &field.field_type {
+                if let Ok(element_type) = RustToCSharpTypeMapper::new().map_type(element) {
+                    context.add_array_field(field.name.clone(), element_type);
+                }
+            }
+            if let Some(integer) = IntegerType::from_rust_type(&field.field_type) {
+                context.integer_fields.insert(field.name.clone(), integer);
+            }
+            if let Ok(csharp_type) = RustToCSharpTypeMapper::new().map_type(&field.field_type) {
+                context.field_types.insert(field.name.clone(), csharp_type);
+            }
+        }
+        for int_enum in &udon_struct.int_enums {
+            context.add_int_enum(int_enum.clone());
+        }
+        for matched_enum in &udon_struct.matched_enums {
+            context.add_enum(matched_enum.clone());
+        }
+        for payload_enum in &udon_struct.payload_enums {
+            context.add_payload_enum(payload_enum.clone());
+        }
+        for table in &udon_struct.const_tables {
+            context.add_const_table(table);
+        }
+        context
+    }
+
+    /// Register a module constant table, read from its SharedRuntime array
+    pub fn add_const_table(&mut self, table: &ConstTable) {
+        self.const_tables.insert(table.name.clone(), table.reference());
+    }
+
+    /// Register an enum that integers convert to through its `{Enum}FromInt` method
+    pub fn add_int_enum(&mut self, int_enum: IntEnum) {
+        self.add_enum(int_enum.clone());
+        self.int_enums.insert(int_enum.name.clone(), int_enum);
+    }
+
+    /// Register a fieldless enum whose matches are checked for exhaustiveness
+    pub fn add_enum(&mut self, fieldless_enum: IntEnum) {
+        self.enums.insert(fieldless_enum.name.clone(), fieldless_enum);
+    }
+
+    /// Register an enum with data, constructed through its `New{Enum}{Variant}` methods
+    pub fn add_payload_enum(&mut self, payload_enum: PayloadEnum) {
+        self.payload_enums.insert(payload_enum.name.clone(), payload_enum);
+    }
+
+    /// Register a behavior field that holds a `DataList`
+    pub fn add_data_list_field(&mut self, field_name: String) {
+        self.data_list_fields.insert(field_name);
+    }
+
+    /// Register a behavior field that holds an `ElapsedTimer`
+    pub fn add_elapsed_timer_field(&mut self, field_name: String) {
+        self.elapsed_timer_fields.insert(field_name);
+    }
+
+    /// Register a behavior field that holds a `Vec`, emitted as an array of `element_type`
+    pub fn add_array_field(&mut self, field_name: String, element_type: String) {
+        self.array_fields.insert(field_name, element_type);
+    }
+
+    /// Register a behavior field that holds an `UdonRef` to the `target` behavior
+    pub fn add_udon_ref_field(&mut self, field_name: String, target: String) {
+        self.udon_ref_fields.insert(field_name, target);
+    }
+
+    /// Register an embedded struct
+    pub fn add_embedded_struct(&mut self, embedded: EmbeddedStruct) {
+        self.embedded_structs.insert(embedded.field_name.clone(), embedded);
+    }
+
+    /// Register another behavior that bodies may reach through `get_component::<T>()`
+    pub fn add_behavior(&mut self, udon_struct: &UdonBehaviourStruct) {
+        self.behaviors.insert(udon_struct.name.clone(), BehaviorInterface::from_behavior(udon_struct));
+    }
+
+    /// Drop `log::debug!` and `log::trace!` calls, as release builds do
+    pub fn set_strip_debug_logs(&mut self, strip: bool) {
+        self.strip_debug_logs = strip;
+    }
+
+    /// Choose how `assert!`, `assert_eq!`, `assert_ne!` and their `debug_` forms are lowered
+    pub fn set_assertion_mode(&mut self, mode: AssertionMode) {
+        self.assertion_mode = mode;
+    }
+
+    /// Guard unwraps of public `Option` reference fields with a null check that
+    /// logs the unassigned reference once and leaves the method
+    pub fn set_safe_refs(&mut self, safe_refs: bool) {
+        self.safe_refs = safe_refs;
+    }
+
+    /// Lower bodies as methods of the embedded struct stored in `field_name`
+    pub fn with_self_prefix(mut self, field_name: Option<String>) -> Self {
+        self.self_prefix = field_name;
+        self
+    }
+
+    /// Lower bodies of a method taking `parameters`, so that slice and `Vec`
+    /// parameters are indexed, searched and measured as arrays
+    pub fn with_parameters(mut self, parameters: &[MethodParameter]) -> Self {
+        for parameter in parameters {
+            if let Ok(csharp_type) = RustToCSharpTypeMapper::new().map_type(&parameter.param_type) {
+                self.parameter_types.insert(parameter.name.clone(), csharp_type);
+            }
+            if let RustType::Vec(element) = &parameter.param_type {
+                if let Ok(element_type) = RustToCSharpTypeMapper::new().map_type(element) {
+                    self.array_parameters.insert(parameter.name.clone(), element_type);
+                }
+            }
+        }
+        self
+    }
+
+    /// Lower bodies of a method declared to return `return_type`
+    pub fn with_return_type(mut self, return_type: RustType) -> Self {
+        self.return_type = Some(return_type);
+        self
+    }
+
+    /// Resolve `self.<field>` to the generated C# field name
+    fn self_field(&self, field: &str) -> String {
+        match &self.self_prefix {
+            Some(prefix) => to_camel_case(&format!("{}_{}", prefix, field)),
+            None => to_camel_case(field),
+        }
+    }
+
+    /// Resolve `self.<method>()` to the generated C# method name
+    fn self_method(&self, method: &str) -> String {
+        match &self.self_prefix {
+            Some(prefix) => to_pascal_case(&format!("{}_{}", prefix, method)),
+            None => to_pascal_case(method),
+        }
+    }
+
+    /// Get the embedded struct stored in a behavior field
+    fn embedded(&self, field: &str) -> Option<&EmbeddedStruct> {
+        if self.self_prefix.is_some() {
+            return None;
+        }
+        self.embedded_structs.get(field)
+    }
+
+    /// Get a registered behavior by name
+    fn behavior(&self, name: &str) -> Option<&BehaviorInterface> {
+        self.behaviors.get(name)
+    }
+}
+
+/// Lowers Rust method bodies to UdonSharp C# source
+pub struct BodyLowerer {
+    /// Context for the behavior being lowered
+    context: LoweringContext,
+    /// Locals bound to another behavior, mapped to that behavior's name
+    behavior_locals: RefCell<HashMap<String, String>>,
+    /// Locals holding a `DataList`
+    data_list_locals: RefCell<HashSet<String>>,
+    /// Locals holding a `DataDictionary`
+    data_dictionary_locals: RefCell<HashSet<String>>,
+    /// Locals holding a `DataToken`
+    data_token_locals: RefCell<HashSet<String>>,
+    /// Whether the lowered code names a `VRC.SDK3.Data` container type
+    emits_data_containers: Cell<bool>,
+}
+
+impl BodyLowerer {
+    /// Create a new body lowerer
+    pub fn new(context: LoweringContext) -> Self {
+        Self {
+            context,
+            behavior_locals: RefCell::new(HashMap::new()),
+            data_list_locals: RefCell::new(HashSet::new()),
+            data_dictionary_locals: RefCell::new(HashSet::new()),
+            data_token_locals: RefCell::new(HashSet::new()),
+            emits_data_containers: Cell::new(false),
+        }
+    }
+
+    /// Whether the code lowered so far names a `VRC.SDK3.Data` container type,
+    /// so the class needs `using VRC.SDK3.Data;`
+    pub fn emits_data_containers(&self) -> bool {
+        self.emits_data_containers.get()
+    }
+
+    /// Remember that lowered code names the given C# type
+    fn note_emitted_type(&self, csharp_type: &str) {
+        if is_data_container(csharp_type) {
+            self.emits_data_containers.set(true);
+        }
+    }
+
+    /// Lower the Rust source of a method body to indented C# statements
+    pub fn lower_method_body(&self, source: &str, returns_value: bool) -> LoweringResult<String> {
+        let block: Block = syn::parse_str(source)
+            .map_err(|e| LoweringError::ParseError { message: e.to_string() })?;
+        let lines = self.lower_block(&block, 2, returns_value)?;
+        Ok(lines.join("\n"))
+    }
+
+    /// Lower a block to C# lines at the given indentation depth
+    pub fn lower_block(&self, block: &Block, depth: usize, returns_value: bool) -> LoweringResult<Vec<String>> {
+        let mut lines = Vec::new();
+        let last_index = block.stmts.len().saturating_sub(1);
+
+        for (index, stmt) in block.stmts.iter().enumerate() {
+            let is_tail = returns_value && index == last_index;
+            lines.extend(self.null_reference_guards(stmt, depth));
+            lines.extend(self.lower_stmt(stmt, depth, is_tail)?);
+        }
+
+        Ok(lines)
+    }
+
+    /// Lower a binding of `x.find_child(name)`. `Transform.Find` returns null
+    /// for a missing child, so its `gameObject` is only read when it was found.
+    fn lower_find_child_binding(&self, name: &str, declared_type: &str, expr: &Expr, depth: usize) -> LoweringResult<Option<Vec<String>>> {
+        let Expr::MethodCall(call) = expr else {
+            return Ok(None);
+        };
+        if call.method != "find_child" || call.args.len() != 1 {
+            return Ok(None);
+        }
+        let indent = indent(depth);
+        let transform = format!("{}Transform", name);
+        Ok(Some(vec![
+            format!(
+                "{}var {} = {}.transform.Find({});",
+                indent, transform, self.lower_operand(&call.receiver)?, self.lower_args(call.args.iter())?
+            ),
+            format!("{}{} {} = {} != null ? {}.gameObject : null;", indent, declared_type, name, transform, transform),
+        ]))
+    }
+
+    /// Lower a single statement
+    fn lower_stmt(&self, stmt: &Stmt, depth: usize, is_tail: bool) -> LoweringResult<Vec<String>> {
+        let indent = indent(depth);
+
+        match stmt {
+            Stmt::Local(local) => {
+                let (name, declared_type) = self.lower_local_pattern(&local.pat)?;
+                let init = local.init.as_ref().ok_or_else(|| LoweringError::UnsupportedStatement {
+                    statement: format!("let {}", name),
+                    reason: "local variables must be initialized".to_string(),
+                })?;
+                if init.diverge.is_some() {
+                    return Err(LoweringError::UnsupportedStatement {
+                        statement: format!("let {} = ... else", name),
+                        reason: "let-else is not supported".to_string(),
+                    });
+                }
+                let (negated, lookup_expr) = match &*init.expr {
+                    Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => (true, &*unary.expr),
+                    expr => (false, expr),
+                };
+                if let Some((array, lookup)) = self.array_lookup(lookup_expr) {
+                    return self.lower_bound_lookup(&name, &declared_type, array, &lookup, negated, depth);
+                }
+                if let Some(lines) = self.lower_find_child_binding(&name, &declared_type, &init.expr, depth)? {
+                    return Ok(lines);
+                }
+                let value = match &*init.expr {
+                    Expr::If(expr_if) => match self.lower_if_ternary(expr_if)? {
+                        Some(value) => value,
+                        None => return self.lower_if_binding(&local.pat, &name, &declared_type, expr_if, depth),
+                    },
+                    Expr::Loop(expr_loop) => return self.lower_loop_binding(&local.pat, &name, &declared_type, expr_loop, depth),
+                    expr => self.lower_expr(expr)?,
+                };
+                self.bind_behavior_local(&local.pat, &init.expr);
+                self.bind_data_local(&local.pat, &init.expr);
+                Ok(vec![format!("{}{} {} = {};", indent, declared_type, name, value)])
+            }
+            Stmt::Expr(Expr::Macro(expr_macro), _) if self.is_stripped_log(&expr_macro.mac) => Ok(Vec::new()),
+            Stmt::Macro(stmt_macro) if self.is_stripped_log(&stmt_macro.mac) => Ok(Vec::new()),
+            Stmt::Expr(Expr::Macro(expr_macro), _) if is_assertion(&expr_macro.mac) => self.lower_assertion(&expr_macro.mac, depth),
+            Stmt::Macro(stmt_macro) if is_assertion(&stmt_macro.mac) => self.lower_assertion(&stmt_macro.mac, depth),
+            Stmt::Expr(expr, semi) => {
+                if let Expr::Match(expr_match) = expr {
+                    return self.lower_match(expr_match, depth, is_tail && semi.is_none());
+                }
+                if let Expr::MethodCall(call) = expr {
+                    if let Some(lines) = self.lower_array_mutation(call, depth)? {
+                        return Ok(lines);
+                    }
+                }
+                if let Expr::Call(call) = expr {
+                    if let Some(lines) = self.lower_shuffle(call, depth)? {
+                        return Ok(lines);
+                    }
+                }
+                if let Expr::If(expr_if) = expr {
+                    if is_tail && semi.is_none() && expr_if.else_branch.is_some() {
+                        return self.lower_if(&sink_branch_values(expr_if, &|value| syn::parse_quote!(return #value)), depth);
+                    }
+                }
+                if let Expr::Loop(expr_loop) = expr {
+                    if is_tail && semi.is_none() {
+                        return self.lower_loop(&sink_break_values(expr_loop, &|value| vec![syn::parse_quote!(return #value;)]), depth);
+                    }
+                }
+                if let Expr::Assign(assign) = expr {
+                    let left = &assign.left;
+                    if let Expr::If(expr_if) = &*assign.right {
+                        if expr_if.else_branch.is_some() && self.lower_if_ternary(expr_if)?.is_none() {
+                            return self.lower_if(&sink_branch_values(expr_if, &|value| syn::parse_quote!(#left = #value)), depth);
+                        }
+                    }
+                    if let Expr::Loop(expr_loop) = &*assign.right {
+                        let assign_and_break = |value: Expr| vec![syn::parse_quote!(#left = #value;), syn::parse_quote!(break;)];
+                        return self.lower_loop(&sink_break_values(expr_loop, &assign_and_break), depth);
+                    }
+                }
+                if let Some(lines) = self.lower_control_flow(expr, depth)? {
+                    return Ok(lines);
+                }
+
+                match expr {
+                    Expr::Return(_) | Expr::Break(_) | Expr::Continue(_) => {
+                        Ok(vec![format!("{}{};", indent, self.lower_expr(expr)?)])
+                    }
+                    _ if is_tail && semi.is_none() => {
+                        Ok(vec![format!("{}return {};", indent, self.lower_return_value(expr)?)])
+                    }
+                    _ => Ok(vec![format!("{}{};", indent, self.lower_expr(expr)?)]),
+                }
+            }
+            Stmt::Macro(stmt_macro) => {
+                let expr = Expr::Macro(syn::ExprMacro {
+                    attrs: stmt_macro.attrs.clone(),
+                    mac: stmt_macro.mac.clone(),
+                });
+                Ok(vec![format!("{}{};", indent, self.lower_expr(&expr)?)])
+            }
+            Stmt::Item(_) => Err(LoweringError::UnsupportedStatement {
+                statement: quote::quote!(#stmt).to_string(),
+                reason: "nested items are not supported inside UdonSharp methods".to_string(),
+            }),
+        }
+    }
+
+    /// Lower the pattern of a `let` binding to a C# name and declared type
+    fn lower_local_pattern(&self, pat: &Pat) -> LoweringResult<(String, String)> {
+        match pat {
+            Pat::Ident(pat_ident) => Ok((local_name(&pat_ident.ident.to_string()), "var".to_string())),
+            Pat::Type(pat_type) => {
+                let (name, _) = self.lower_local_pattern(&pat_type.pat)?;
+                Ok((name, self.lower_type(&pat_type.ty)?))
+            }
+            _ => Err(LoweringError::UnsupportedStatement {
+                statement: quote::quote!(#pat).to_string(),
+                reason: "only simple identifier bindings are supported".to_string(),
+            }),
+        }
+    }
+
+    /// Lower `let name = if ... { ...; a } else { ...; b };` to a declaration
+    /// followed by an `if` assigning the value of whichever branch runs
+    fn lower_if_binding(
+        &self,
+        pat: &Pat,
+        name: &str,
+        declared_type: &str,
+        expr_if: &syn::ExprIf,
+        depth: usize,
+    ) -> LoweringResult<Vec<String>> {
+        let unsupported_binding = |reason: &str| LoweringError::UnsupportedStatement {
+            statement: format!("let {} = if ...", name),
+            reason: reason.to_string(),
+        };
+        if expr_if.else_branch.is_none() {
+            return Err(unsupported_binding("an `if` without `else` has no value to bind"));
+        }
+        let declared_type = match declared_type {
+            "var" => self.if_value_type(expr_if).ok_or_else(|| {
+                unsupported_binding("no branch ends in a literal to infer the type from; annotate the binding, e.g. `let x: i32 = if ...`")
+            })?,
+            declared_type => declared_type.to_string(),
+        };
+        let binding = binding_ident(pat).ok_or_else(|| unsupported_binding("only simple identifier bindings are supported"))?;
+
+        let mut lines = vec![format!("{}{} {};", indent(depth), declared_type, name)];
+        lines.extend(self.lower_if(&sink_branch_values(expr_if, &|value| syn::parse_quote!(#binding = #value)), depth)?);
+        Ok(lines)
+    }
+
+    /// Lower `let name = loop { ... break value; };` to a declaration followed
+    /// by a `while (true)` that assigns the value before each `break`
+    fn lower_loop_binding(
+        &self,
+        pat: &Pat,
+        name: &str,
+        declared_type: &str,
+        expr_loop: &syn::ExprLoop,
+        depth: usize,
+    ) -> LoweringResult<Vec<String>> {
+        let unsupported_binding = |reason: &str| LoweringError::UnsupportedStatement {
+            statement: format!("let {} = loop ...", name),
+            reason: reason.to_string(),
+        };
+        let declared_type = match declared_type {
+            "var" => {
+                let counters = range_counters(&expr_loop.body);
+                loop_exits(expr_loop).values.into_iter().find_map(|value| self.value_type(value, &counters)).ok_or_else(|| {
+                    unsupported_binding("the type of no `break` value can be inferred; annotate the binding, e.g. `let x: i32 = loop ...`")
+                })?
+            }
+            declared_type => declared_type.to_string(),
+        };
+        let binding = binding_ident(pat).ok_or_else(|| unsupported_binding("only simple identifier bindings are supported"))?;
+
+        let assign_and_break = |value: Expr| vec![syn::parse_quote!(#binding = #value;), syn::parse_quote!(break;)];
+        let mut lines = vec![format!("{}{} {};", indent(depth), declared_type, name)];
+        lines.extend(self.lower_loop(&sink_break_values(expr_loop, &assign_and_break), depth)?);
+        Ok(lines)
+    }
+
+    /// Lower `loop { .. }` to `while (true)`.
+    ///
+    /// Udon has no way to interrupt a script that never yields, so a loop the
+    /// body can never leave through `break`, `return` or `?` would hang the VM
+    /// and is rejected.
+    fn lower_loop(&self, expr_loop: &syn::ExprLoop, depth: usize) -> LoweringResult<Vec<String>> {
+        if !loop_exits(expr_loop).exits {
+            return Err(LoweringError::UnboundedLoop);
+        }
+        let mut lines = vec![format!("{}while (true)", indent(depth))];
+        lines.extend(self.lower_braced_block(&expr_loop.body, depth)?);
+        Ok(lines)
+    }
+
+    /// Lower a value-producing `if`/`else` whose branches are single
+    /// expressions to a conditional expression; `None` when a branch has
+    /// statements or the condition binds a pattern
+    fn lower_if_ternary(&self, expr_if: &syn::ExprIf) -> LoweringResult<Option<String>> {
+        if matches!(*expr_if.cond, Expr::Let(_)) {
+            return Ok(None);
+        }
+        let Some((_, else_branch)) = &expr_if.else_branch else {
+            return Ok(None);
+        };
+        let Some(then_value) = self.lower_branch_value(&expr_if.then_branch)? else {
+            return Ok(None);
+        };
+        let else_value = match &**else_branch {
+            Expr::If(nested) => self.lower_if_ternary(nested)?,
+            Expr::Block(expr_block) => self.lower_branch_value(&expr_block.block)?,
+            _ => None,
+        };
+        let Some(else_value) = else_value else {
+            return Ok(None);
+        };
+        Ok(Some(format!("({} ? {} : {})", self.lower_expr(&expr_if.cond)?, then_value, else_value)))
+    }
+
+    /// Lower the value of an `if` branch consisting of a single expression
+    fn lower_branch_value(&self, block: &Block) -> LoweringResult<Option<String>> {
+        match block.stmts.as_slice() {
+            [Stmt::Expr(Expr::If(nested), None)] => self.lower_if_ternary(nested),
+            [Stmt::Expr(value, None)] => self.lower_expr(value).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// C# type of a value-producing `if`, read off the first branch value
+    /// whose type can be inferred
+    fn if_value_type(&self, expr_if: &syn::ExprIf) -> Option<String> {
+        let mut values = Vec::new();
+        collect_branch_values(expr_if, &mut values);
+        values.into_iter().find_map(|value| self.value_type(value, &HashMap::new()))
+    }
+
+    /// C# type of a value built from literals, casts and operators over
+    /// fields, parameters and the given locals; `None` for anything else,
+    /// such as method calls
+    fn value_type(&self, value: &Expr, locals: &HashMap<String, String>) -> Option<String> {
+        if let Some(literal_type) = self.literal_type(value) {
+            return Some(literal_type);
+        }
+        match value {
+            Expr::Cast(cast) => self.lower_type(&cast.ty).ok(),
+            Expr::Paren(paren) => self.value_type(&paren.expr, locals),
+            Expr::Unary(unary) if !matches!(unary.op, UnOp::Deref(_)) => self.value_type(&unary.expr, locals),
+            Expr::Binary(binary) => match binary.op {
+                BinOp::Eq(_) | BinOp::Ne(_) | BinOp::Lt(_) | BinOp::Le(_) | BinOp::Gt(_) | BinOp::Ge(_)
+                | BinOp::And(_) | BinOp::Or(_) => Some("bool".to_string()),
+                // An unsuffixed literal takes the type of the other operand
+                _ => {
+                    let (first, second) = match &*binary.left {
+                        Expr::Lit(syn::ExprLit { lit: Lit::Int(int), .. }) if int.suffix().is_empty() => (&binary.right, &binary.left),
+                        Expr::Lit(syn::ExprLit { lit: Lit::Float(float), .. }) if float.suffix().is_empty() => (&binary.right, &binary.left),
+                        _ => (&binary.left, &binary.right),
+                    };
+                    self.value_type(first, locals).or_else(|| self.value_type(second, locals))
+                }
+            },
+            Expr::Path(path) => {
+                let name = path.path.get_ident()?.to_string();
+                locals.get(&name).or_else(|| self.context.parameter_types.get(&name)).cloned()
+            }
+            Expr::Field(field) if matches!(&*field.base, Expr::Path(base) if base.path.is_ident("self")) => match &field.member {
+                Member::Named(name) => self.context.field_types.get(&name.to_string()).cloned(),
+                Member::Unnamed(_) => None,
+            },
+            Expr::MethodCall(call) if call.method == "len" && call.args.is_empty() => Some("int".to_string()),
+            _ => None,
+        }
+    }
+
+    /// C# type of a literal value, or of a value that is evidently a string
+    fn literal_type(&self, value: &Expr) -> Option<String> {
+        match value {
+            Expr::Lit(expr_lit) => match &expr_lit.lit {
+                Lit::Str(_) => Some("string".to_string()),
+                Lit::Bool(_) => Some("bool".to_string()),
+                Lit::Int(int) if int.suffix().is_empty() => Some("int".to_string()),
+                Lit::Float(float) if float.suffix().is_empty() => Some("float".to_string()),
+                Lit::Int(int) => self.lower_type(&syn::parse_str(int.suffix()).ok()?).ok(),
+                Lit::Float(float) => self.lower_type(&syn::parse_str(float.suffix()).ok()?).ok(),
+                _ => None,
+            },
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => self.literal_type(&unary.expr),
+            Expr::Paren(paren) => self.literal_type(&paren.expr),
+            Expr::Macro(expr_macro) if expr_macro.mac.path.is_ident("format") => Some("string".to_string()),
+            Expr::MethodCall(call) if call.method == "to_string" => Some("string".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Lower control-flow expressions used in statement position
+    fn lower_control_flow(&self, expr: &Expr, depth: usize) -> LoweringResult<Option<Vec<String>>> {
+        let indent = indent(depth);
+
+        let lines = match expr {
+            Expr::If(expr_if) => self.lower_if(expr_if, depth)?,
+            Expr::While(expr_while) => {
+                if let Expr::Let(expr_let) = &*expr_while.cond {
+                    return self.lower_while_let(expr_while, expr_let, depth).map(Some);
+                }
+                let mut lines = vec![format!("{}while ({})", indent, self.lower_expr(&expr_while.cond)?)];
+                lines.extend(self.lower_braced_block(&expr_while.body, depth)?);
+                lines
+            }
+            Expr::Loop(expr_loop) => self.lower_loop(expr_loop, depth)?,
+            Expr::ForLoop(for_loop) => {
+                let binding = match &*for_loop.pat {
+                    Pat::Ident(pat_ident) => local_name(&pat_ident.ident.to_string()),
+                    Pat::Wild(_) => "_".to_string(),
+                    _ => return Err(unsupported(expr, "only simple loop bindings are supported")),
+                };
+                let header = match &*for_loop.expr {
+                    Expr::Range(range) => {
+                        let start = match &range.start {
+                            Some(start) => self.lower_expr(start)?,
+                            None => "0".to_string(),
+                        };
+                        let end = range.end.as_ref()
+                            .ok_or_else(|| unsupported(expr, "open-ended ranges cannot be iterated"))?;
+                        let comparison = match range.limits {
+                            RangeLimits::HalfOpen(_) => "<",
+                            RangeLimits::Closed(_) => "<=",
+                        };
+                        format!(
+                            "{}for (int {} = {}; {} {} {}; {}++)",
+                            indent, binding, start, binding, comparison, self.lower_expr(end)?, binding
+                        )
+                    }
+                    iterable => {
+                        format!("{}foreach (var {} in {})", indent, binding, self.lower_iterable(iterable)?)
+                    }
+                };
+                let mut lines = vec![header];
+                lines.extend(self.lower_braced_block(&for_loop.body, depth)?);
+                lines
+            }
+            Expr::Block(expr_block) => self.lower_braced_block(&expr_block.block, depth)?,
+            Expr::Match(expr_match) => self.lower_match(expr_match, depth, false)?,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(lines))
+    }
+
+    /// Lower `while let PAT = EXPR { .. }` to a C# loop that re-evaluates `EXPR`
+    /// on every iteration and leaves once the pattern no longer matches.
+    ///
+    /// Draining a `Vec`/`VecDeque` field with `pop_front()` or `pop()` becomes a
+    /// length check with the element removed at the top of the body; any other
+    /// `Option` value is bound, null-checked and tested against the pattern.
+    fn lower_while_let(&self, expr_while: &syn::ExprWhile, expr_let: &syn::ExprLet, depth: usize) -> LoweringResult<Vec<String>> {
+        let indent = indent(depth);
+        let body_indent = self::indent(depth + 1);
+        let binding = match &*expr_let.pat {
+            Pat::TupleStruct(tuple) if tuple.path.is_ident("Some") && tuple.elems.len() == 1 => {
+                tuple.elems.first().and_then(pattern_ident)
+            }
+            _ => None,
+        };
+
+        if let (Some(binding), Expr::MethodCall(call)) = (&binding, &*expr_let.expr) {
+            if let (Some((array, _)), Some(lines)) = (
+                self.array_field(&call.receiver),
+                self.lower_array_pop(call, &local_name(binding), depth + 1)?,
+            ) {
+                let mut loop_lines = vec![format!("{}while ({}.Length > 0)", indent, array)];
+                let mut body = self.lower_braced_block(&expr_while.body, depth)?;
+                body.splice(1..1, lines);
+                loop_lines.extend(body);
+                return Ok(loop_lines);
+            }
+        }
+
+        let mut prelude = Vec::new();
+        let scrutinee = if is_place_expr(&expr_let.expr) && component_type_name(&expr_let.expr).is_none() {
+            self.lower_operand(&expr_let.expr)?
+        } else {
+            let binding = binding.as_ref().ok_or_else(|| {
+                unsupported(&expr_let.expr, "`while let` over a computed value needs a plain `Some(x)` pattern")
+            })?;
+            let name = local_name(binding);
+            prelude.push(format!("{}var {} = {};", body_indent, name, self.lower_expr(&expr_let.expr)?));
+            self.bind_behavior_name(binding, &expr_let.expr);
+            name
+        };
+
+        let mut conditions = Vec::new();
+        let mut bindings = Vec::new();
+        self.lower_let_pattern(&scrutinee, &expr_let.pat, &mut conditions, &mut bindings)?;
+        bindings.retain(|(name, _)| *name != scrutinee);
+        if !conditions.is_empty() {
+            prelude.push(format!("{}if (!({}))", body_indent, conditions.join(" && ")));
+            prelude.push(format!("{}{{", body_indent));
+            prelude.push(format!("{}break;", self::indent(depth + 2)));
+            prelude.push(format!("{}}}", body_indent));
+        }
+        for (name, value) in bindings {
+            prelude.push(format!("{}var {} = {};", body_indent, name, value));
+        }
+
+        let mut lines = vec![format!("{}while (true)", indent)];
+        let mut body = self.lower_braced_block(&expr_while.body, depth)?;
+        body.splice(1..1, prelude);
+        lines.extend(body);
+        Ok(lines)
+    }
+
+    /// Lower an `if`/`else if`/`else` chain in statement position
+    fn lower_if(&self, expr_if: &syn::ExprIf, depth: usize) -> LoweringResult<Vec<String>> {
+        if let Some(lines) = self.lower_lookup_if(expr_if, depth)? {
+            return Ok(lines);
+        }
+        let indent = indent(depth);
+
+        let mut lines = Vec::new();
+        let mut arm_bindings = Vec::new();
+        let condition = match &*expr_if.cond {
+            Expr::Let(expr_let) => {
+                let unsupported_let = |reason: &str| LoweringError::UnsupportedExpression {
+                    expression: quote::quote!(#expr_if).to_string(),
+                    reason: reason.to_string(),
+                };
+                let simple_binding = match &*expr_let.pat {
+                    Pat::TupleStruct(tuple) if (tuple.path.is_ident("Some") || tuple.path.is_ident("Err")) && tuple.elems.len() == 1 => {
+                        tuple.elems.first().and_then(pattern_ident)
+                    }
+                    _ => None,
+                };
+
+                // `checked_*` yields no value to null-check: test for overflow
+                // and compute the result only in the `Some` arm
+                if let (Some(binding), Some(checked)) = (&simple_binding, checked_arithmetic(&expr_let.expr)) {
+                    let (ok, value) = self.lower_checked_arithmetic(checked)?;
+                    arm_bindings.push((local_name(binding), value));
+                    ok
+                } else {
+                    // A place is tested directly and its bindings are declared in
+                    // the `Some` arm; any other value is evaluated once into the
+                    // binding of a plain `Some(x)` pattern ahead of the check.
+                    let (scrutinee, hoisted) = if is_place_expr(&expr_let.expr) && component_type_name(&expr_let.expr).is_none() {
+                        (self.lower_operand(&expr_let.expr)?, None)
+                    } else {
+                        let binding = simple_binding.ok_or_else(|| {
+                            unsupported_let("nested `if let` patterns need a field or local; bind the value to a local first")
+                        })?;
+                        let name = local_name(&binding);
+                        match self.lower_find_child_binding(&name, "var", &expr_let.expr, depth)? {
+                            Some(binding_lines) => lines.extend(binding_lines),
+                            None => lines.push(format!("{}var {} = {};", indent, name, self.lower_expr(&expr_let.expr)?)),
+                        }
+                        self.bind_behavior_name(&binding, &expr_let.expr);
+                        (name.clone(), Some(name))
+                    };
+
+                    let mut conditions = Vec::new();
+                    self.lower_let_pattern(&scrutinee, &expr_let.pat, &mut conditions, &mut arm_bindings)?;
+                    arm_bindings.retain(|(name, _)| Some(name) != hoisted.as_ref());
+                    if conditions.is_empty() {
+                        "true".to_string()
+                    } else {
+                        conditions.join(" && ")
+                    }
+                }
+            }
+            cond => self.lower_expr(cond)?,
+        };
+
+        lines.push(format!("{}if ({})", indent, condition));
+        let mut then_lines = self.lower_braced_block(&expr_if.then_branch, depth)?;
+        let body_indent = self::indent(depth + 1);
+        for (offset, (name, value)) in arm_bindings.iter().enumerate() {
+            then_lines.insert(1 + offset, format!("{}var {} = {};", body_indent, name, value));
+        }
+        lines.extend(then_lines);
+
+        if let Some((_, else_branch)) = &expr_if.else_branch {
+            match &**else_branch {
+                Expr::If(nested) if matches!(*nested.cond, Expr::Let(_)) || self.is_lookup_condition(&nested.cond) => {
+                    // The binding declaration needs its own scope inside the else branch
+                    lines.push(format!("{}else", indent));
+                    lines.push(format!("{}{{", indent));
+                    lines.extend(self.lower_if(nested, depth + 1)?);
+                    lines.push(format!("{}}}", indent));
+                }
+                Expr::If(nested) => {
+                    let mut nested_lines = self.lower_if(nested, depth)?;
+                    nested_lines[0] = format!("{}else {}", indent, nested_lines[0].trim_start());
+                    lines.extend(nested_lines);
+                }
+                Expr::Block(expr_block) => {
+                    lines.push(format!("{}else", indent));
+                    lines.extend(self.lower_braced_block(&expr_block.block, depth)?);
+                }
+                other => return Err(unsupported(other, "unexpected else branch")),
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Lower an `if let` pattern over an already-lowered value into the
+    /// conditions it tests and the `(name, value)` bindings it introduces.
+    ///
+    /// Options map to nullable references in C#, so `Some(p)` is a null check
+    /// followed by matching `p` against the same value.
+    fn lower_let_pattern(
+        &self,
+        scrutinee: &str,
+        pat: &Pat,
+        conditions: &mut Vec<String>,
+        bindings: &mut Vec<(String, String)>,
+    ) -> LoweringResult<()> {
+        match pat {
+            Pat::TupleStruct(tuple) if tuple.path.is_ident("Some") && tuple.elems.len() == 1 => {
+                // Nested options flatten onto the same C# value, so test it once
+                let null_check = format!("{} != null", scrutinee);
+                if !conditions.contains(&null_check) {
+                    conditions.push(null_check);
+                }
+                self.lower_let_pattern(scrutinee, &tuple.elems[0], conditions, bindings)
+            }
+            // A `Result<(), E>` is its error, null on success
+            Pat::TupleStruct(tuple) if tuple.path.is_ident("Err") && tuple.elems.len() == 1 => {
+                conditions.push(format!("{} != null", scrutinee));
+                self.lower_let_pattern(scrutinee, &tuple.elems[0], conditions, bindings)
+            }
+            Pat::TupleStruct(tuple) if tuple.path.is_ident("Ok") && tuple.elems.len() == 1 => {
+                conditions.push(format!("{} == null", scrutinee));
+                Ok(())
+            }
+            Pat::Ident(ident) if !ident.ident.to_string().starts_with(|c: char| c.is_uppercase()) => {
+                bindings.push((local_name(&ident.ident.to_string()), scrutinee.to_string()));
+                match &ident.subpat {
+                    Some((_, subpat)) => self.lower_let_pattern(scrutinee, subpat, conditions, bindings),
+                    None => Ok(()),
+                }
+            }
+            Pat::Paren(paren) => self.lower_let_pattern(scrutinee, &paren.pat, conditions, bindings),
+            Pat::Type(pat_type) => self.lower_let_pattern(scrutinee, &pat_type.pat, conditions, bindings),
+            pat => {
+                if let Some(condition) = self.lower_pattern_test(scrutinee, pat)? {
+                    conditions.push(condition);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Lower a `match` in statement position to a C# `switch`
+    ///
+    /// Each arm becomes a braced case section ending in `break`. A match over a
+    /// known fieldless enum without a `_` arm must name every variant, since a
+    /// missing case would silently do nothing in C#. When `is_tail` is set the
+    /// match produces the method's return value and every arm returns; C#
+    /// does not consider a switch exhaustive, so without a `_` arm the last
+    /// arm also takes the `default:` label.
+    fn lower_match(&self, expr_match: &syn::ExprMatch, depth: usize, is_tail: bool) -> LoweringResult<Vec<String>> {
+        if let Some(payload_enum) = self.matched_payload_enum(expr_match) {
+            return self.lower_payload_match(expr_match, payload_enum, depth, is_tail);
+        }
+        if self.needs_match_chain(expr_match) {
+            return self.lower_match_chain(expr_match, depth, is_tail);
+        }
+        let indent = indent(depth);
+        let match_expr = Expr::Match(expr_match.clone());
+
+        let mut lines = vec![
+            format!("{}switch ({})", indent, self.lower_expr(&expr_match.expr)?),
+            format!("{}{{", indent),
+        ];
+        let mut matched_enum: Option<&IntEnum> = None;
+        let mut covered = HashSet::new();
+        let mut has_default = false;
+
+        for (index, arm) in expr_match.arms.iter().enumerate() {
+            if contains_loop_break(&arm.body) {
+                return Err(unsupported(&match_expr, "`break` inside a `match` arm would only leave the C# switch"));
+            }
+
+            let alternatives: Vec<&Pat> = match &arm.pat {
+                Pat::Or(or) => or.cases.iter().collect(),
+                pat => vec![pat],
+            };
+            for pat in alternatives {
+                let label = if matches!(pat, Pat::Wild(_)) {
+                    has_default = true;
+                    "default:".to_string()
+                } else if let Some((fieldless_enum, variant)) = enum_variant_pattern(pat, &self.context.enums) {
+                    matched_enum = Some(fieldless_enum);
+                    let label = format!("case {}.{}:", fieldless_enum.name, variant);
+                    covered.insert(variant);
+                    label
+                } else {
+                    match pat {
+                        Pat::Lit(lit) => format!("case {}:", self.lower_expr(&Expr::Lit(lit.clone()))?),
+                        Pat::Path(path) => format!("case {}:", self.lower_path(&path.path)),
+                        _ => return Err(unsupported(
+                            &match_expr,
+                            "only enum variants, literals and `_` are supported in `match` arms",
+                        )),
+                    }
+                };
+                lines.push(format!("{}    {}", indent, label));
+            }
+            if is_tail && !has_default && index + 1 == expr_match.arms.len() {
+                lines.push(format!("{}    default:", indent));
+            }
+
+            lines.push(format!("{}    {{", indent));
+            lines.extend(self.lower_arm_body(&arm.body, depth + 2, is_tail)?);
+            if !is_tail && !arm_diverges(&arm.body) {
+                lines.push(format!("{}        break;", indent));
+            }
+            lines.push(format!("{}    }}", indent));
+        }
+        lines.push(format!("{}}}", indent));
+
+        if let (Some(fieldless_enum), false) = (matched_enum, has_default) {
+            let missing: Vec<String> = fieldless_enum.variants.iter()
+                .map(|(variant, _)| variant.clone())
+                .filter(|variant| !covered.contains(variant))
+                .collect();
+            if !missing.is_empty() {
+                return Err(LoweringError::NonExhaustiveMatch { enum_name: fieldless_enum.name.clone(), missing });
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Lower a `match` over an enum with data to a `switch` on the record's tag
+    ///
+    /// Each arm reads the fields it binds out of the record into locals
+    /// before its body. As with fieldless enums, a match without a `_` arm
+    /// must name every variant, and in tail position its last arm also takes
+    /// the `default:` label.
+    fn lower_payload_match(
+        &self,
+        expr_match: &syn::ExprMatch,
+        payload_enum: &PayloadEnum,
+        depth: usize,
+        is_tail: bool,
+    ) -> LoweringResult<Vec<String>> {
+        let indent = indent(depth);
+        let match_expr = Expr::Match(expr_match.clone());
+        if !is_place_expr(&expr_match.expr) {
+            return Err(unsupported(&expr_match.expr, "a `match` over an enum with data needs a field or local; bind the value to a local first"));
+        }
+        let record = self.lower_operand(&expr_match.expr)?;
+
+        let mut lines = vec![
+            format!("{}switch ({})", indent, payload_tag(&record)),
+            format!("{}{{", indent),
+        ];
+        let mut covered = HashSet::new();
+        let mut has_default = false;
+
+        for (index, arm) in expr_match.arms.iter().enumerate() {
+            if contains_loop_break(&arm.body) {
+                return Err(unsupported(&match_expr, "`break` inside a `match` arm would only leave the C# switch"));
+            }
+            if arm.guard.is_some() {
+                return Err(unsupported(&match_expr, "guards on variants with data are not supported; test the bound fields inside the arm"));
+            }
+
+            let alternatives: Vec<&Pat> = match &arm.pat {
+                Pat::Or(or) => or.cases.iter().collect(),
+                pat => vec![pat],
+            };
+            // Each binding with the payload field it reads
+            let mut bindings = Vec::new();
+            for pat in &alternatives {
+                if matches!(pat, Pat::Wild(_)) {
+                    has_default = true;
+                    lines.push(format!("{}    default:", indent));
+                    continue;
+                }
+                let Some((_, variant)) = self.payload_variant_pattern(pat).filter(|(matched, _)| matched.name == payload_enum.name) else {
+                    return Err(unsupported(
+                        &match_expr,
+                        &format!("only variants of '{}' and `_` are supported in this `match`", payload_enum.name),
+                    ));
+                };
+                let bound = payload_bindings(pat, variant).map_err(|reason| unsupported(&match_expr, &reason))?;
+                if alternatives.len() > 1 && !bound.is_empty() {
+                    return Err(unsupported(&match_expr, "bindings in `|` alternatives are not supported"));
+                }
+                bindings.extend(bound);
+                lines.push(format!("{}    case \"{}\":", indent, variant.name));
+                covered.insert(variant.name.clone());
+            }
+            if is_tail && !has_default && index + 1 == expr_match.arms.len() {
+                lines.push(format!("{}    default:", indent));
+            }
+
+            lines.push(format!("{}    {{", indent));
+            for (name, field, field_type) in &bindings {
+                lines.push(format!(
+                    "{}        var {} = {};",
+                    indent, local_name(name), read_payload_field(&record, field, field_type)
+                ));
+            }
+            lines.extend(self.lower_arm_body(&arm.body, depth + 2, is_tail)?);
+            if !is_tail && !arm_diverges(&arm.body) {
+                lines.push(format!("{}        break;", indent));
+            }
+            lines.push(format!("{}    }}", indent));
+        }
+        lines.push(format!("{}}}", indent));
+
+        if !has_default {
+            let missing: Vec<String> = payload_enum.variants.iter()
+                .map(|variant| variant.name.clone())
+                .filter(|variant| !covered.contains(variant))
+                .collect();
+            if !missing.is_empty() {
+                return Err(LoweringError::NonExhaustiveMatch { enum_name: payload_enum.name.clone(), missing });
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// The enum with data whose variants the arms of a `match` test, if any
+    fn matched_payload_enum(&self, expr_match: &syn::ExprMatch) -> Option<&PayloadEnum> {
+        expr_match.arms.iter()
+            .flat_map(|arm| -> Vec<&Pat> {
+                match &arm.pat {
+                    Pat::Or(or) => or.cases.iter().collect(),
+                    pat => vec![pat],
+                }
+            })
+            .find_map(|pat| self.payload_variant_pattern(pat))
+            .map(|(payload_enum, _)| payload_enum)
+    }
+
+    /// Resolve `Enum::Variant { .. }`, `Enum::Variant(..)` or a unit
+    /// `Enum::Variant` pattern to a variant of a known enum with data
+    fn payload_variant_pattern(&self, pat: &Pat) -> Option<(&PayloadEnum, &PayloadVariant)> {
+        match pat {
+            Pat::Paren(paren) => self.payload_variant_pattern(&paren.pat),
+            Pat::Struct(pat_struct) => self.payload_variant(&pat_struct.path),
+            Pat::TupleStruct(pat_tuple) => self.payload_variant(&pat_tuple.path),
+            Pat::Path(pat_path) => self.payload_variant(&pat_path.path),
+            _ => None,
+        }
+    }
+
+    /// Resolve an `Enum::Variant` path to a variant of a known enum with data
+    fn payload_variant(&self, path: &syn::Path) -> Option<(&PayloadEnum, &PayloadVariant)> {
+        let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+        let [.., enum_name, variant] = segments.as_slice() else {
+            return None;
+        };
+        let payload_enum = self.context.payload_enums.get(enum_name)?;
+        Some((payload_enum, payload_enum.variant(variant)?))
+    }
+
+    /// Lower `Enum::Variant { field: value, .. }` to the variant's generated
+    /// constructor, passing the values in declaration order
+    fn lower_payload_struct(&self, expr: &Expr, expr_struct: &syn::ExprStruct) -> LoweringResult<String> {
+        let Some((payload_enum, variant)) = self.payload_variant(&expr_struct.path) else {
+            return Err(unsupported(expr, "expression has no UdonSharp lowering"));
+        };
+        if expr_struct.rest.is_some() {
+            return Err(unsupported(expr, "`..` is not supported when constructing a variant; name every field"));
+        }
+        let args = variant.fields.iter()
+            .map(|(field, _)| {
+                let value = expr_struct.fields.iter()
+                    .find(|value| payload_field_key(&value.member) == *field)
+                    .ok_or_else(|| unsupported(expr, &format!("missing field `{}` of {}::{}", field, payload_enum.name, variant.name)))?;
+                self.lower_expr(&value.expr)
+            })
+            .collect::<LoweringResult<Vec<_>>>()?;
+        Ok(format!("{}({})", payload_enum.constructor_name(&variant.name), args.join(", ")))
+    }
+
+    /// Lower the body of a `match` arm
+    fn lower_arm_body(&self, body: &Expr, depth: usize, is_tail: bool) -> LoweringResult<Vec<String>> {
+        match body {
+            Expr::Block(expr_block) if expr_block.label.is_none() => self.lower_block(&expr_block.block, depth, is_tail),
+            Expr::Tuple(tuple) if tuple.elems.is_empty() => Ok(Vec::new()),
+            body => {
+                let stmt = Stmt::Expr(body.clone(), if is_tail { None } else { Some(Default::default()) });
+                self.lower_stmt(&stmt, depth, is_tail)
+            }
+        }
+    }
+
+    /// Whether a `match` needs more than a C# switch: a tuple of values,
+    /// bindings or guards
+    fn needs_match_chain(&self, expr_match: &syn::ExprMatch) -> bool {
+        let binds_or_destructures = |pat: &Pat| match pat {
+            Pat::Tuple(_) => true,
+            Pat::Ident(pat_ident) => {
+                enum_variant_pattern(pat, &self.context.enums).is_none() && is_binding_name(&pat_ident.ident.to_string())
+            }
+            _ => false,
+        };
+        matches!(*expr_match.expr, Expr::Tuple(_))
+            || expr_match.arms.iter().any(|arm| {
+                arm.guard.is_some()
+                    || match &arm.pat {
+                        Pat::Or(or) => or.cases.iter().any(binds_or_destructures),
+                        pat => binds_or_destructures(pat),
+                    }
+            })
+    }
+
+    /// Lower a `match` over a tuple of values, or with bindings or guards, to
+    /// an `if`/`else if` chain testing the arms in order.
+    ///
+    /// Bindings become locals of their arm and are replaced by the value they
+    /// bind inside guards, which C# evaluates before the arm's locals exist.
+    /// Rust only accepts exhaustive matches, so an unguarded last arm becomes
+    /// the final `else`.
+    fn lower_match_chain(&self, expr_match: &syn::ExprMatch, depth: usize, is_tail: bool) -> LoweringResult<Vec<String>> {
+        let indent = indent(depth);
+        let match_expr = Expr::Match(expr_match.clone());
+        let scrutinees: Vec<&Expr> = match &*expr_match.expr {
+            Expr::Tuple(tuple) => tuple.elems.iter().collect(),
+            expr => vec![expr],
+        };
+        if let Some(value) = scrutinees.iter().find(|value| !is_place_expr(value)) {
+            return Err(unsupported(value, "a `match` with bindings, guards or tuples needs fields or locals; bind the value to a local first"));
+        }
+        let values = scrutinees.iter().map(|value| self.lower_operand(value)).collect::<LoweringResult<Vec<_>>>()?;
+        if let Some((enum_name, missing)) = self.missing_tuple_variants(expr_match, scrutinees.len()) {
+            return Err(LoweringError::NonExhaustiveMatch { enum_name, missing });
+        }
+
+        let mut lines = Vec::new();
+        for (index, arm) in expr_match.arms.iter().enumerate() {
+            let alternatives: Vec<&Pat> = match &arm.pat {
+                Pat::Or(or) => or.cases.iter().collect(),
+                pat => vec![pat],
+            };
+            let mut tests = Vec::new();
+            // Each binding with the position of the value it binds
+            let mut bindings: Vec<(String, usize)> = Vec::new();
+            for alternative in &alternatives {
+                let elements = tuple_pattern_elements(alternative, scrutinees.len())
+                    .ok_or_else(|| unsupported(&match_expr, "tuple patterns must have one element per matched value"))?;
+                let mut conditions = Vec::new();
+                let bound = bindings.len();
+                for (position, pat) in elements.into_iter().enumerate() {
+                    self.lower_match_element(position, &values[position], pat, &mut conditions, &mut bindings)?;
+                }
+                if alternatives.len() > 1 && bindings.len() > bound {
+                    return Err(unsupported(&match_expr, "bindings in `|` alternatives are not supported"));
+                }
+                tests.push(conditions);
+            }
+
+            let mut condition = if tests.iter().any(Vec::is_empty) {
+                None
+            } else if tests.len() == 1 {
+                Some(tests[0].join(" && "))
+            } else {
+                let alternatives: Vec<String> = tests.iter()
+                    .map(|conditions| match conditions.len() {
+                        1 => conditions[0].clone(),
+                        _ => format!("({})", conditions.join(" && ")),
+                    })
+                    .collect();
+                Some(alternatives.join(" || "))
+            };
+            if let Some((_, guard)) = &arm.guard {
+                let mut guard = (**guard).clone();
+                let arguments: HashMap<&str, &Expr> = bindings.iter()
+                    .map(|(name, position)| (name.as_str(), scrutinees[*position]))
+                    .collect();
+                ParameterSubstitution { arguments: &arguments }.visit_expr_mut(&mut guard);
+                let guard = match &guard {
+                    Expr::Binary(binary) if matches!(binary.op, BinOp::Or(_)) => format!("({})", self.lower_expr(&guard)?),
+                    _ => self.lower_expr(&guard)?,
+                };
+                condition = Some(match condition {
+                    None => guard,
+                    Some(condition) if tests.len() > 1 => format!("({}) && {}", condition, guard),
+                    Some(condition) => format!("{} && {}", condition, guard),
+                });
+            }
+
+            let is_last = index + 1 == expr_match.arms.len();
+            let catches_rest = condition.is_none() || (is_last && arm.guard.is_none());
+            match condition {
+                _ if catches_rest => {
+                    if !lines.is_empty() {
+                        lines.push(format!("{}else", indent));
+                    }
+                }
+                Some(condition) if lines.is_empty() => lines.push(format!("{}if ({})", indent, condition)),
+                Some(condition) => lines.push(format!("{}else if ({})", indent, condition)),
+                None => {}
+            }
+            lines.push(format!("{}{{", indent));
+            let body_indent = self::indent(depth + 1);
+            for (name, position) in &bindings {
+                lines.push(format!("{}var {} = {};", body_indent, local_name(name), values[*position]));
+            }
+            lines.extend(self.lower_arm_body(&arm.body, depth + 1, is_tail)?);
+            lines.push(format!("{}}}", indent));
+
+            // Later arms are unreachable
+            if catches_rest {
+                break;
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Lower the pattern matched against one value of a `match` into the
+    /// conditions it tests and the bindings it introduces
+    fn lower_match_element(
+        &self,
+        position: usize,
+        value: &str,
+        pat: &Pat,
+        conditions: &mut Vec<String>,
+        bindings: &mut Vec<(String, usize)>,
+    ) -> LoweringResult<()> {
+        if let Some((fieldless_enum, variant)) = enum_variant_pattern(pat, &self.context.enums) {
+            conditions.push(format!("{} == {}.{}", value, fieldless_enum.name, variant));
+            return Ok(());
+        }
+        match pat {
+            Pat::Paren(paren) => self.lower_match_element(position, value, &paren.pat, conditions, bindings),
+            Pat::Ident(pat_ident) if is_binding_name(&pat_ident.ident.to_string()) => {
+                bindings.push((pat_ident.ident.to_string(), position));
+                if let Some((_, subpat)) = &pat_ident.subpat {
+                    self.lower_match_element(position, value, subpat, conditions, bindings)?;
+                }
+                Ok(())
+            }
+            Pat::Or(or) => {
+                let mut alternatives = Vec::new();
+                for case in &or.cases {
+                    let mut case_conditions = Vec::new();
+                    let bound = bindings.len();
+                    self.lower_match_element(position, value, case, &mut case_conditions, bindings)?;
+                    if bindings.len() > bound {
+                        return Err(LoweringError::UnsupportedExpression {
+                            expression: quote::quote!(#pat).to_string(),
+                            reason: "bindings in `|` alternatives are not supported".to_string(),
+                        });
+                    }
+                    // A wildcard alternative matches every value
+                    if case_conditions.is_empty() {
+                        return Ok(());
+                    }
+                    alternatives.push(case_conditions.join(" && "));
+                }
+                conditions.push(format!("({})", alternatives.join(" || ")));
+                Ok(())
+            }
+            pat => {
+                conditions.extend(self.lower_pattern_test(value, pat)?);
+                Ok(())
+            }
+        }
+    }
+
+    /// Variant combinations of a `match` over a tuple of enums that no
+    /// unguarded arm handles, with the enum names; `None` when the match has
+    /// a catch-all arm or tests values other than enum variants
+    fn missing_tuple_variants(&self, expr_match: &syn::ExprMatch, width: usize) -> Option<(String, Vec<String>)> {
+        if width < 2 {
+            return None;
+        }
+        let mut enums: Vec<Option<&IntEnum>> = vec![None; width];
+        // Variant tested at each position of each unguarded alternative; `None` matches anything
+        let mut covered: Vec<Vec<Option<String>>> = Vec::new();
+        for arm in expr_match.arms.iter().filter(|arm| arm.guard.is_none()) {
+            let alternatives: Vec<&Pat> = match &arm.pat {
+                Pat::Or(or) => or.cases.iter().collect(),
+                pat => vec![pat],
+            };
+            for alternative in alternatives {
+                let mut key = Vec::new();
+                for (position, pat) in tuple_pattern_elements(alternative, width)?.into_iter().enumerate() {
+                    if let Some((fieldless_enum, variant)) = enum_variant_pattern(pat, &self.context.enums) {
+                        enums[position] = Some(fieldless_enum);
+                        key.push(Some(variant));
+                    } else if matches!(pat, Pat::Wild(_))
+                        || matches!(pat, Pat::Ident(ident) if ident.subpat.is_none() && is_binding_name(&ident.ident.to_string()))
+                    {
+                        key.push(None);
+                    } else {
+                        return None;
+                    }
+                }
+                if key.iter().all(Option::is_none) {
+                    return None;
+                }
+                covered.push(key);
+            }
+        }
+
+        let mut combinations: Vec<Vec<Option<String>>> = vec![Vec::new()];
+        for fieldless_enum in &enums {
+            let variants: Vec<Option<String>> = match fieldless_enum {
+                Some(fieldless_enum) => fieldless_enum.variants.iter().map(|(variant, _)| Some(variant.clone())).collect(),
+                None => vec![None],
+            };
+            combinations = combinations.into_iter()
+                .flat_map(|prefix| variants.iter().map(move |variant| {
+                    let mut combination = prefix.clone();
+                    combination.push(variant.clone());
+                    combination
+                }))
+                .collect();
+        }
+        let missing: Vec<String> = combinations.into_iter()
+            .filter(|combination| !covered.iter().any(|key| {
+                key.iter().zip(combination).all(|(tested, variant)| tested.is_none() || tested == variant)
+            }))
+            .map(|combination| {
+                let variants: Vec<&str> = combination.iter().map(|variant| variant.as_deref().unwrap_or("_")).collect();
+                format!("({})", variants.join(", "))
+            })
+            .collect();
+        if missing.is_empty() {
+            return None;
+        }
+        let names: Vec<&str> = enums.iter().map(|e| e.map_or("_", |e| e.name.as_str())).collect();
+        Some((format!("({})", names.join(", ")), missing))
+    }
+
+    /// Lower a block wrapped in braces at the given depth
+    fn lower_braced_block(&self, block: &Block, depth: usize) -> LoweringResult<Vec<String>> {
+        let indent = indent(depth);
+        let mut lines = vec![format!("{}{{", indent)];
+        lines.extend(self.lower_block(block, depth + 1, false)?);
+        lines.push(format!("{}}}", indent));
+        Ok(lines)
+    }
+
+    /// Lower the iterable of a `for` loop, dropping iterator adapters C# does not need
+    fn lower_iterable(&self, expr: &Expr) -> LoweringResult<String> {
+        match expr {
+            Expr::MethodCall(call) if call.args.is_empty()
+                && matches!(call.method.to_string().as_str(), "iter" | "iter_mut" | "into_iter") => {
+                self.lower_expr(&call.receiver)
+            }
+            Expr::Reference(reference) => self.lower_iterable(&reference.expr),
+            _ => self.lower_expr(expr),
+        }
+    }
+
+    /// Lower an expression to C#
+    pub fn lower_expr(&self, expr: &Expr) -> LoweringResult<String> {
+        match expr {
+            Expr::Lit(expr_lit) => self.lower_lit(&expr_lit.lit),
+            Expr::Path(expr_path) => match self.payload_variant(&expr_path.path) {
+                Some((payload_enum, variant)) if variant.fields.is_empty() => {
+                    Ok(format!("{}()", payload_enum.constructor_name(&variant.name)))
+                }
+                _ => Ok(self.lower_path(&expr_path.path)),
+            },
+            Expr::Struct(expr_struct) => self.lower_payload_struct(expr, expr_struct),
+            Expr::Field(field) => self.lower_field(field),
+            Expr::MethodCall(call) => self.lower_method_call(call),
+            Expr::Call(call) => self.lower_call(call),
+            Expr::Binary(binary) => {
+                if let Some(op) = compound_assign_op(&binary.op) {
+                    if let Some((local, behavior)) = self.behavior_field_base(&binary.left) {
+                        let read = self.lower_expr(&binary.left)?;
+                        let value = format!("{} {} {}", read, op, self.lower_operand(&binary.right)?);
+                        return self.lower_behavior_field_write(&local, &behavior, &binary.left, value);
+                    }
+                }
+                if matches!(binary.op, BinOp::And(_) | BinOp::Or(_)) {
+                    return self.lower_logical(binary);
+                }
+                let left = self.lower_expr(&binary.left)?;
+                let right = self.lower_expr(&binary.right)?;
+                Ok(format!("{} {} {}", left, lower_bin_op(&binary.op), right))
+            }
+            Expr::Unary(unary) => {
+                let operand = self.lower_expr(&unary.expr)?;
+                match unary.op {
+                    UnOp::Not(_) if matches_macro(&unary.expr).is_some() => {
+                        Ok(format!("!{}", self.lower_operand(&unary.expr)?))
+                    }
+                    UnOp::Not(_) => Ok(format!("!{}", operand)),
+                    UnOp::Neg(_) => Ok(format!("-{}", operand)),
+                    UnOp::Deref(_) => Ok(operand),
+                    _ => Err(unsupported(expr, "unknown unary operator")),
+                }
+            }
+            Expr::Assign(assign) => {
+                if let Some((array, element_type)) = self.array_field(&assign.left).filter(|_| is_empty_vec(&assign.right)) {
+                    return Ok(format!("{} = new {}[0]", array, element_type));
+                }
+                if let Some((local, behavior)) = self.behavior_field_base(&assign.left) {
+                    let value = self.lower_expr(&assign.right)?;
+                    return self.lower_behavior_field_write(&local, &behavior, &assign.left, value);
+                }
+                Ok(format!("{} = {}", self.lower_expr(&assign.left)?, self.lower_expr(&assign.right)?))
+            }
+            Expr::Paren(paren) => Ok(format!("({})", self.lower_expr(&paren.expr)?)),
+            Expr::Reference(reference) => self.lower_expr(&reference.expr),
+            Expr::Cast(cast) => {
+                let target = self.lower_type(&cast.ty)?;
+                if let Some(int_enum) = self.context.int_enums.get(&target) {
+                    return Ok(format!("{}({})", int_enum.conversion_method_name(), self.lower_expr(&cast.expr)?));
+                }
+                // A C# cast truncates floats toward zero like `as` does; rounding
+                // is spelled `Mathf::round_to_int`/`floor_to_int`/`ceil_to_int`
+                Ok(format!("({}){}", target, self.lower_operand(&cast.expr)?))
+            }
+            Expr::Index(index) => {
+                // C# indexes arrays with an `int`, so `as usize` index casts are dropped
+                let position = match &*index.index {
+                    Expr::Cast(cast) if type_name(&cast.ty).as_deref() == Some("usize") => &*cast.expr,
+                    position => position,
+                };
+                Ok(format!("{}[{}]", self.lower_expr(&index.expr)?, self.lower_expr(position)?))
+            }
+            Expr::Return(ret) => match &ret.expr {
+                Some(value) => Ok(format!("return {}", self.lower_return_value(value)?)),
+                None => Ok("return".to_string()),
+            },
+            Expr::Break(brk) => {
+                if brk.expr.is_some() {
+                    return Err(unsupported(expr, "`break` with a value is only supported in a `loop` that initializes a `let`, is assigned or is returned"));
+                }
+                Ok("break".to_string())
+            }
+            Expr::Continue(_) => Ok("continue".to_string()),
+            Expr::Macro(expr_macro) => self.lower_macro(expr_macro),
+            Expr::If(expr_if) => self.lower_if_ternary(expr_if)?.ok_or_else(|| {
+                unsupported(expr, "an `if` with statements in its branches can only initialize a `let`, be assigned or be returned")
+            }),
+            _ => Err(unsupported(expr, "expression has no UdonSharp lowering")),
+        }
+    }
+
+    /// Lower a short-circuiting `&&`/`||` chain.
+    ///
+    /// Both operands stay inline in the C# `&&`/`||` expression, so the right
+    /// operand is only evaluated when the left one does not decide the result,
+    /// exactly as in Rust. Operands are never hoisted into temporaries ahead of
+    /// the condition, and a nested chain of the other operator is parenthesized
+    /// to keep its grouping explicit.
+    fn lower_logical(&self, binary: &syn::ExprBinary) -> LoweringResult<String> {
+        let lower_side = |operand: &Expr| -> LoweringResult<String> {
+            if let Some(expr_macro) = matches_macro(operand) {
+                let (condition, compound) = self.lower_matches(expr_macro)?;
+                return Ok(if compound { format!("({})", condition) } else { condition });
+            }
+            let lowered = self.lower_expr(operand)?;
+            match operand {
+                Expr::Binary(inner) if matches!(inner.op, BinOp::And(_) | BinOp::Or(_))
+                    && std::mem::discriminant(&inner.op) != std::mem::discriminant(&binary.op) =>
+                {
+                    Ok(format!("({})", lowered))
+                }
+                _ => Ok(lowered),
+            }
+        };
+
+        let left = lower_side(&binary.left)?;
+        let right = lower_side(&binary.right)?;
+        Ok(format!("{} {} {}", left, lower_bin_op(&binary.op), right))
+    }
+
+    /// Lower an expression that is used as the operand of a tighter-binding C# operator
+    fn lower_operand(&self, expr: &Expr) -> LoweringResult<String> {
+        let lowered = self.lower_expr(expr)?;
+        match expr {
+            Expr::Binary(_) | Expr::Cast(_) | Expr::Unary(_) => Ok(format!("({})", lowered)),
+            Expr::Macro(_) if matches_macro(expr).is_some() => Ok(format!("({})", lowered)),
+            _ => Ok(lowered),
+        }
+    }
+
+    /// Lower a literal
+    fn lower_lit(&self, lit: &Lit) -> LoweringResult<String> {
+        match lit {
+            Lit::Int(int) => Ok(int.base10_digits().to_string()),
+            Lit::Float(float) => {
+                let digits = float.base10_digits();
+                if float.suffix() == "f64" {
+                    Ok(digits.to_string())
+                } else {
+                    Ok(format!("{}f", digits))
+                }
+            }
+            Lit::Bool(b) => Ok(b.value.to_string()),
+            Lit::Str(s) => Ok(csharp_string_literal(&s.value())),
+            Lit::Char(c) => Ok(csharp_char_literal(c.value())),
+            _ => Err(LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#lit).to_string(),
+                reason: "literal type is not supported".to_string(),
+            }),
+        }
+    }
+
+    /// Lower a path expression (locals, constants, associated items)
+    fn lower_path(&self, path: &syn::Path) -> String {
+        let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+
+        if segments.len() == 1 {
+            let name = &segments[0];
+            if let Some(table) = self.context.const_tables.get(name) {
+                return table.clone();
+            }
+            return match name.as_str() {
+                "self" => "this".to_string(),
+                "None" => "null".to_string(),
+                _ if is_constant_name(name) => name.clone(),
+                _ => local_name(name),
+            };
+        }
+
+        if let Some(known) = lower_known_path(&segments.join("::")) {
+            return known.to_string();
+        }
+
+        // Drop leading lowercase module segments such as `unity::` or `networking::`
+        let type_start = segments.iter()
+            .position(|s| s.chars().next().is_some_and(|c| c.is_uppercase()))
+            .unwrap_or(segments.len() - 1);
+        segments[type_start..].join(".")
+    }
+
+    /// Lower a field access, rewriting `self` and embedded struct members
+    fn lower_field(&self, field: &syn::ExprField) -> LoweringResult<String> {
+        let member = member_name(&field.member)
+            .ok_or_else(|| unsupported_field(field, "tuple fields are not supported"))?;
+
+        if is_self(&field.base) {
+            return Ok(self.context.self_field(&member));
+        }
+
+        if let Some(embedded) = self.embedded_base(&field.base) {
+            if embedded.has_field(&member) {
+                return Ok(to_camel_case(&embedded.mangle(&member)));
+            }
+            return Err(unsupported_field(field, "embedded struct has no such field"));
+        }
+
+        if let Some((local, behavior)) = self.behavior_local(&field.base) {
+            let info = self.behavior_field(&behavior, &member, field)?;
+            return Ok(format!(
+                "({}){}.GetProgramVariable(\"{}\")",
+                info.csharp_type, local_name(&local), to_camel_case(&member)
+            ));
+        }
+
+        Ok(format!("{}.{}", self.lower_operand(&field.base)?, to_camel_case(&member)))
+    }
+
+    /// Lower a method call, rewriting calls on `self` and on embedded structs
+    fn lower_method_call(&self, call: &syn::ExprMethodCall) -> LoweringResult<String> {
+        let method = call.method.to_string();
+        if self.array_lookup(&Expr::MethodCall(call.clone())).is_some() {
+            return Err(unsupported(
+                &Expr::MethodCall(call.clone()),
+                "array searches can only be bound with `let`, tested by an `if` of their own or matched with `if let Some(..)`",
+            ));
+        }
+        if let Some(lowered) = self.lower_integer_intrinsic(call)? {
+            return Ok(lowered);
+        }
+        // The generated `ToJson` logs a failed serialization and returns null instead of an error
+        if let Expr::Call(inner) = &*call.receiver {
+            if matches!(method.as_str(), "unwrap" | "expect" | "unwrap_or_default")
+                && self.context.json_serializable
+                && is_self_to_json_call(inner)
+            {
+                return self.lower_call(inner);
+            }
+        }
+        if let Some(field) = self.option_ref_field(&call.receiver) {
+            let field = self.context.self_field(&field);
+            match method.as_str() {
+                "is_some" => return Ok(format!("({} != null)", field)),
+                "is_none" => return Ok(format!("({} == null)", field)),
+                "unwrap" | "expect" | "as_ref" | "as_mut" => return Ok(field),
+                _ => {}
+            }
+        }
+        if let Some(lowered) = self.lower_elapsed_timer_call(call)? {
+            return Ok(lowered);
+        }
+        let args = self.lower_args(call.args.iter())?;
+
+        if is_self(&call.receiver) && method == "disable_behavior" && call.args.is_empty() {
+            return Ok("enabled = false".to_string());
+        }
+        if is_self(&call.receiver) {
+            return Ok(format!("{}({})", self.context.self_method(&method), args));
+        }
+
+        if let Some(embedded) = self.embedded_base(&call.receiver) {
+            if embedded.has_method(&method) {
+                return Ok(format!("{}({})", to_pascal_case(&embedded.mangle(&method)), args));
+            }
+            return Err(LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#call).to_string(),
+                reason: format!("embedded struct '{}' has no method '{}'", embedded.type_name, method),
+            });
+        }
+
+        if let Some((local, behavior)) = self.behavior_local(&call.receiver) {
+            return self.lower_behavior_method_call(call, &local, &behavior);
+        }
+
+        if let Some((field, behavior)) = self.udon_ref_field(&call.receiver) {
+            return self.lower_udon_ref_call(call, &field, &behavior);
+        }
+
+        if let Some(type_name) = component_type_name(&Expr::MethodCall(call.clone())) {
+            return self.lower_get_component(call, &type_name);
+        }
+
+        let receiver = self.lower_operand(&call.receiver)?;
+        if self.is_data_list(&call.receiver) {
+            return Ok(match method.as_str() {
+                "get" => format!("{}[{}]", receiver, args),
+                "count" | "len" => format!("{}.Count", receiver),
+                _ => format!("{}.{}({})", receiver, to_pascal_case(&method), args),
+            });
+        }
+        if self.is_data_dictionary(&call.receiver) {
+            return Ok(match method.as_str() {
+                "get" => format!("{}[{}]", receiver, args),
+                "count" | "len" => format!("{}.Count", receiver),
+                _ => format!("{}.{}({})", receiver, to_pascal_case(&method), args),
+            });
+        }
+        if let Some(property) = data_token_accessor(&method).filter(|_| self.is_data_token(&call.receiver)) {
+            return Ok(format!("{}.{}", receiver, property));
+        }
+        if self.array_field(&call.receiver).is_some() {
+            match method.as_str() {
+                "is_empty" => return Ok(format!("({}.Length == 0)", receiver)),
+                "push" | "push_back" | "clear" | "retain" | "retain_mut" => return Err(unsupported(
+                    &Expr::MethodCall(call.clone()),
+                    "arrays can only be grown, filtered or cleared in a statement of their own",
+                )),
+                "pop" | "pop_front" => return Err(unsupported(
+                    &Expr::MethodCall(call.clone()),
+                    "popped elements can only be bound by `while let Some(x) = ..`",
+                )),
+                _ => {}
+            }
+        }
+        match method.as_str() {
+            "clone" | "to_owned" | "as_str" => Ok(receiver),
+            "to_string" | "into" if matches!(&*call.receiver, Expr::Lit(syn::ExprLit { lit: Lit::Str(_), .. })) => Ok(receiver),
+            "is_err" if call.args.is_empty() => Ok(format!("({} != null)", receiver)),
+            "is_ok" if call.args.is_empty() => Ok(format!("({} == null)", receiver)),
+            "len" => Ok(format!("{}.Length", receiver)),
+            "layer" if call.args.is_empty() => Ok(format!("{}.layer", receiver)),
+            "active_self" if call.args.is_empty() => Ok(format!("{}.activeSelf", receiver)),
+            "set_layer" => Ok(format!("{}.layer = {}", receiver, args)),
+            "find_child" => Err(unsupported(
+                &Expr::MethodCall(call.clone()),
+                "bind `find_child` to a local with `let` or `if let` so a missing child can be null-checked",
+            )),
+            "display_name" if call.args.is_empty() => Ok(format!("{}.displayName", receiver)),
+            "set_text" => Ok(format!("{}.text = {}", receiver, args)),
+            "set_interactable" => Ok(format!("{}.interactable = {}", receiver, args)),
+            "abs" | "sqrt" if call.args.is_empty() => Ok(format!("Mathf.{}({})", to_pascal_case(&method), receiver)),
+            "min" | "max" if call.args.len() == 1 => Ok(format!("Mathf.{}({}, {})", to_pascal_case(&method), receiver, args)),
+            "is_some" if call.args.is_empty() => Ok(format!("({} != null)", receiver)),
+            "is_none" if call.args.is_empty() => Ok(format!("({} == null)", receiver)),
+            // Events are sent through the object's behavior, as GameObject has no such methods
+            "send_custom_event" | "send_custom_event_delayed_seconds" | "send_custom_event_delayed_frames" => Ok(format!(
+                "((UdonBehaviour){}.GetComponent(typeof(UdonBehaviour))).{}({})",
+                receiver, to_pascal_case(&method), args
+            )),
+            _ if is_passthrough_method(&method) => Ok(format!("{}.{}({})", receiver, to_pascal_case(&method), args)),
+            _ => Err(unsupported(&Expr::MethodCall(call.clone()), "no C# binding is known for this method")),
+        }
+    }
+
+    /// Lower a call on an `ElapsedTimer` field to arithmetic on the start time it holds
+    fn lower_elapsed_timer_call(&self, call: &syn::ExprMethodCall) -> LoweringResult<Option<String>> {
+        let Expr::Field(field) = &*call.receiver else {
+            return Ok(None);
+        };
+        let Some(name) = member_name(&field.member).filter(|name| is_self(&field.base) && self.context.elapsed_timer_fields.contains(name)) else {
+            return Ok(None);
+        };
+        let timer = self.context.self_field(&name);
+        let lowered = match (call.method.to_string().as_str(), call.args.first()) {
+            ("start", None) => format!("{} = Time.time", timer),
+            ("elapsed", None) => format!("(Time.time - {})", timer),
+            ("has_elapsed", Some(seconds)) if call.args.len() == 1 => {
+                format!("(Time.time - {} >= {})", timer, self.lower_operand(seconds)?)
+            }
+            _ => return Err(unsupported(
+                &Expr::MethodCall(call.clone()),
+                "an ElapsedTimer supports `start()`, `elapsed()` and `has_elapsed(seconds)`",
+            )),
+        };
+        Ok(Some(lowered))
+    }
+
+    /// Lower `get_component::<T>()`, looking through `unwrap`/`expect`.
+    ///
+    /// Behaviors are fetched as plain `UdonBehaviour` components because Udon
+    /// only exposes their variables and events through that type.
+    fn lower_get_component(&self, call: &syn::ExprMethodCall, type_name: &str) -> LoweringResult<String> {
+        if call.method != "get_component" {
+            // `unwrap`/`expect` on a component lookup: the null check is left to the caller
+            if let Expr::MethodCall(inner) = &*call.receiver {
+                return self.lower_get_component(inner, type_name);
+            }
+            return Err(unsupported(&call.receiver, "expected a `get_component` call"));
+        }
+
+        let receiver = self.lower_operand(&call.receiver)?;
+        if self.context.behavior(type_name).is_some() {
+            Ok(format!("(UdonBehaviour){}.GetComponent(typeof(UdonBehaviour))", receiver))
+        } else {
+            Ok(format!("{}.GetComponent<{}>()", receiver, type_name))
+        }
+    }
+
+    /// Lower a method call on another behavior to `SendCustomEvent`
+    fn lower_behavior_method_call(&self, call: &syn::ExprMethodCall, local: &str, behavior: &str) -> LoweringResult<String> {
+        let method = call.method.to_string();
+        let returns_value = self.context.behavior(behavior)
+            .and_then(|b| b.methods.get(&method).copied())
+            .ok_or_else(|| LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#call).to_string(),
+                reason: format!("behavior '{}' has no method '{}'", behavior, method),
+            })?;
+
+        if !call.args.is_empty() {
+            return Err(LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#call).to_string(),
+                reason: "custom events on other behaviors cannot take arguments; set a public field first".to_string(),
+            });
+        }
+        if returns_value {
+            return Err(LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#call).to_string(),
+                reason: "custom events on other behaviors cannot return values; read a public field instead".to_string(),
+            });
+        }
+
+        Ok(format!("{}.SendCustomEvent(\"{}\")", local_name(local), to_pascal_case(&method)))
+    }
+
+    /// Lower `saturating_*`, `wrapping_*` and `checked_*` integer arithmetic.
+    ///
+    /// C# integer arithmetic wraps silently, so wrapping operations are plain
+    /// operators (cast back for types narrower than `int`), saturating ones
+    /// test the operands against the type's bounds before operating, and
+    /// `checked_*` results are only usable through `if let Some(x)` or
+    /// `unwrap_or`, which turn the overflow test into a branch.
+    fn lower_integer_intrinsic(&self, call: &syn::ExprMethodCall) -> LoweringResult<Option<String>> {
+        let method = call.method.to_string();
+        if method == "unwrap_or" && call.args.len() == 1 {
+            if let Some(checked) = checked_arithmetic(&call.receiver) {
+                let (ok, value) = self.lower_checked_arithmetic(checked)?;
+                return Ok(Some(format!("({} ? {} : {})", ok, value, self.lower_expr(&call.args[0])?)));
+            }
+            return Ok(None);
+        }
+
+        let Some((kind, op)) = method.split_once('_') else {
+            return Ok(None);
+        };
+        let op = match (kind, op) {
+            ("wrapping" | "saturating" | "checked", "add") => "+",
+            ("wrapping" | "saturating" | "checked", "sub") => "-",
+            ("wrapping", "mul") => "*",
+            _ => return Ok(None),
+        };
+        if call.args.len() != 1 {
+            return Ok(None);
+        }
+
+        let integer = self.integer_type(&call.receiver, &call.args[0]);
+        let lhs = self.lower_operand(&call.receiver)?;
+        let rhs = self.lower_operand(&call.args[0])?;
+        match kind {
+            "wrapping" => Ok(Some(integer.narrow(format!("{} {} {}", lhs, op, rhs)))),
+            "saturating" => {
+                self.require_pure_operands(call)?;
+                let (bound, _) = self.overflow_test(integer, &lhs, op, &rhs, &call.args[0]);
+                let result = integer.narrow(format!("{} {} {}", lhs, op, rhs));
+                Ok(Some(match bound {
+                    OverflowBound::Never => result,
+                    OverflowBound::Single(test, limit) => format!("({} ? {} : {})", test, limit, result),
+                    OverflowBound::BySign { positive, negative } => format!(
+                        "({} > 0 ? ({} ? {} : {}) : ({} ? {} : {}))",
+                        rhs, positive.0, positive.1, result, negative.0, negative.1, result
+                    ),
+                }))
+            }
+            _ => Err(LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#call).to_string(),
+                reason: format!("`{}` results must be unpacked with `if let Some(x)` or `unwrap_or`", method),
+            }),
+        }
+    }
+
+    /// Lower a `checked_add`/`checked_sub` call to `(no-overflow test, value)`
+    fn lower_checked_arithmetic(&self, call: &syn::ExprMethodCall) -> LoweringResult<(String, String)> {
+        self.require_pure_operands(call)?;
+        let op = if call.method == "checked_add" { "+" } else { "-" };
+        let integer = self.integer_type(&call.receiver, &call.args[0]);
+        let lhs = self.lower_operand(&call.receiver)?;
+        let rhs = self.lower_operand(&call.args[0])?;
+        let (_, ok) = self.overflow_test(integer, &lhs, op, &rhs, &call.args[0]);
+        Ok((ok, integer.narrow(format!("{} {} {}", lhs, op, rhs))))
+    }
+
+    /// Bounds tests for `lhs op rhs`, as the saturating form and as a
+    /// condition that holds when the operation does not overflow
+    fn overflow_test(&self, integer: IntegerType, lhs: &str, op: &str, rhs: &str, rhs_expr: &Expr) -> (OverflowBound, String) {
+        let (min, max) = (integer.min_value(), integer.max_value());
+        let rhs_sign = literal_sign(rhs_expr);
+
+        // Unsigned values only overflow in the direction of the operation
+        let (positive, negative) = match (op, integer.signed) {
+            ("+", false) => ((format!("{} > {} - {}", lhs, max, rhs), max.clone()), None),
+            ("-", false) => ((format!("{} < {}", lhs, rhs), min.clone()), None),
+            ("+", true) => (
+                (format!("{} > {} - {}", lhs, max, rhs), max.clone()),
+                Some((format!("{} < {} - {}", lhs, min, rhs), min.clone())),
+            ),
+            _ => (
+                (format!("{} < {} + {}", lhs, min, rhs), min.clone()),
+                Some((format!("{} > {} + {}", lhs, max, rhs), max.clone())),
+            ),
+        };
+
+        match (negative, rhs_sign) {
+            (_, Some(0)) => (OverflowBound::Never, "true".to_string()),
+            (None, _) => {
+                let ok = negate_comparison(&positive.0);
+                (OverflowBound::Single(positive.0, positive.1), ok)
+            }
+            (Some(_), Some(1)) => {
+                let ok = negate_comparison(&positive.0);
+                (OverflowBound::Single(positive.0, positive.1), ok)
+            }
+            (Some(negative), Some(_)) => {
+                let ok = negate_comparison(&negative.0);
+                (OverflowBound::Single(negative.0, negative.1), ok)
+            }
+            (Some(negative), None) => {
+                let ok = format!(
+                    "({} > 0 ? {} : {})",
+                    rhs, negate_comparison(&positive.0), negate_comparison(&negative.0)
+                );
+                (OverflowBound::BySign { positive, negative }, ok)
+            }
+        }
+    }
+
+    /// Reject operands that would be evaluated more than once with side effects
+    fn require_pure_operands(&self, call: &syn::ExprMethodCall) -> LoweringResult<()> {
+        if is_pure_operand(&call.receiver) && call.args.iter().all(is_pure_operand) {
+            return Ok(());
+        }
+        Err(LoweringError::UnsupportedExpression {
+            expression: quote::quote!(#call).to_string(),
+            reason: "operands of saturating and checked arithmetic are evaluated more than once; bind them to locals first".to_string(),
+        })
+    }
+
+    /// Integer type of an arithmetic intrinsic, from either operand; `i32` if unknown
+    fn integer_type(&self, receiver: &Expr, arg: &Expr) -> IntegerType {
+        self.operand_integer_type(receiver)
+            .or_else(|| self.operand_integer_type(arg))
+            .unwrap_or(IntegerType::I32)
+    }
+
+    fn operand_integer_type(&self, expr: &Expr) -> Option<IntegerType> {
+        match expr {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => IntegerType::from_suffix(int.suffix()),
+                _ => None,
+            },
+            Expr::Cast(cast) => IntegerType::from_suffix(&type_name(&cast.ty)?),
+            Expr::Paren(paren) => self.operand_integer_type(&paren.expr),
+            Expr::Unary(unary) => self.operand_integer_type(&unary.expr),
+            Expr::Field(field) if is_self(&field.base) => {
+                let member = member_name(&field.member)?;
+                self.context.integer_fields.get(&member).copied()
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve `self.<field>` holding an `UdonRef` to `(C# field, behavior)`
+    fn udon_ref_field(&self, expr: &Expr) -> Option<(String, String)> {
+        let Expr::Field(field) = expr else {
+            return None;
+        };
+        let member = member_name(&field.member)?;
+        if !is_self(&field.base) {
+            return None;
+        }
+        let behavior = self.context.udon_ref_fields.get(&member)?;
+        Some((self.context.self_field(&member), behavior.clone()))
+    }
+
+    /// Lower `call`, `get`, `set` and `is_valid` on an `UdonRef` field.
+    ///
+    /// Event and variable names may be given as the Rust method or field
+    /// name; when the target behavior is known they are checked against it.
+    /// `get` casts the `GetProgramVariable` result to the field's type, or to
+    /// the type given with `get::<T>(..)` when the target is not known.
+    fn lower_udon_ref_call(&self, call: &syn::ExprMethodCall, field: &str, behavior: &str) -> LoweringResult<String> {
+        let unsupported_call = |reason: String| LoweringError::UnsupportedExpression {
+            expression: quote::quote!(#call).to_string(),
+            reason,
+        };
+        let name_arg = || match call.args.first() {
+            Some(Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(name), .. })) => Ok(name.value()),
+            _ => Err(unsupported_call("the event or variable name must be a string literal".to_string())),
+        };
+        let target = self.context.behavior(behavior);
+
+        match call.method.to_string().as_str() {
+            "is_valid" if call.args.is_empty() => Ok(format!("{} != null", field)),
+            "call" if call.args.len() == 1 => {
+                let event = name_arg()?;
+                if let Some(target) = target {
+                    match target.methods.get(&event) {
+                        Some(false) => {}
+                        Some(true) => {
+                            return Err(unsupported_call(format!(
+                                "'{}::{}' returns a value, which custom events cannot pass back", behavior, event
+                            )));
+                        }
+                        None => return Err(unsupported_call(format!("behavior '{}' has no method '{}'", behavior, event))),
+                    }
+                }
+                Ok(format!("{}.SendCustomEvent(\"{}\")", field, to_pascal_case(&event)))
+            }
+            "get" if call.args.len() == 1 => {
+                let variable = name_arg()?;
+                let declared = call.turbofish.as_ref()
+                    .and_then(|turbofish| match turbofish.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) => Some(self.lower_type(ty)),
+                        _ => None,
+                    })
+                    .transpose()?;
+                let csharp_type = match target.map(|t| t.fields.get(&variable)) {
+                    Some(Some(info)) if info.is_readable() => info.csharp_type.clone(),
+                    Some(Some(_)) => {
+                        return Err(unsupported_call(format!(
+                            "field '{}' of behavior '{}' is neither public nor synced and cannot be read from another behavior", variable, behavior
+                        )));
+                    }
+                    Some(None) => return Err(unsupported_call(format!("behavior '{}' has no field '{}'", behavior, variable))),
+                    None => declared.ok_or_else(|| unsupported_call(format!(
+                        "behavior '{}' is not known; give the value type with `get::<T>(\"{}\")`", behavior, variable
+                    )))?,
+                };
+                Ok(format!("({}){}.GetProgramVariable(\"{}\")", csharp_type, field, to_camel_case(&variable)))
+            }
+            "set" if call.args.len() == 2 => {
+                let variable = name_arg()?;
+                if let Some(info) = target.map(|t| t.fields.get(&variable)) {
+                    match info {
+                        Some(info) if info.is_public => {}
+                        Some(_) => {
+                            return Err(unsupported_call(format!(
+                                "field '{}' of behavior '{}' is private and cannot be set from another behavior", variable, behavior
+                            )));
+                        }
+                        None => return Err(unsupported_call(format!("behavior '{}' has no field '{}'", behavior, variable))),
+                    }
+                }
+                Ok(format!(
+                    "{}.SetProgramVariable(\"{}\", {})",
+                    field, to_camel_case(&variable), self.lower_expr(&call.args[1])?
+                ))
+            }
+            method => Err(unsupported_call(format!(
+                "`UdonRef` supports `call(event)`, `get(variable)`, `set(variable, value)` and `is_valid()`, not `{}`", method
+            ))),
+        }
+    }
+
+    /// Lower a write to a field of another behavior to `SetProgramVariable`
+    fn lower_behavior_field_write(&self, local: &str, behavior: &str, target: &Expr, value: String) -> LoweringResult<String> {
+        let field = match target {
+            Expr::Field(field) => field,
+            _ => return Err(unsupported(target, "expected a field access")),
+        };
+        let member = member_name(&field.member)
+            .ok_or_else(|| unsupported_field(field, "tuple fields are not supported"))?;
+        if !self.behavior_field(behavior, &member, field)?.is_public {
+            return Err(unsupported_field(
+                field,
+                &format!("field '{}' of behavior '{}' is not public and cannot be set from another behavior", member, behavior),
+            ));
+        }
+
+        Ok(format!(
+            "{}.SetProgramVariable(\"{}\", {})",
+            local_name(local), to_camel_case(&member), value
+        ))
+    }
+
+    /// Look up a field of another behavior, rejecting fields that are neither public nor synced
+    fn behavior_field(&self, behavior: &str, member: &str, field: &syn::ExprField) -> LoweringResult<BehaviorFieldInfo> {
+        let info = self.context.behavior(behavior)
+            .and_then(|b| b.fields.get(member))
+            .ok_or_else(|| unsupported_field(field, &format!("behavior '{}' has no field '{}'", behavior, member)))?;
+
+        if !info.is_readable() {
+            return Err(unsupported_field(
+                field,
+                &format!("field '{}' of behavior '{}' is private and cannot be read from another behavior", member, behavior),
+            ));
+        }
+
+        Ok(info.clone())
+    }
+
+    /// Remember a `let` binding that holds another behavior
+    fn bind_behavior_local(&self, pat: &Pat, init: &Expr) {
+        if let Some(name) = pattern_ident(pat) {
+            self.bind_behavior_name(&name, init);
+        }
+    }
+
+    /// Remember a binding named `name` if `init` looks up another behavior
+    fn bind_behavior_name(&self, name: &str, init: &Expr) {
+        if let Some(type_name) = component_type_name(init) {
+            if self.context.behavior(&type_name).is_some() {
+                self.behavior_locals.borrow_mut().insert(name.to_string(), type_name);
+            }
+        }
+    }
+
+    /// Resolve a local that holds another behavior to `(local, behavior)`
+    fn behavior_local(&self, expr: &Expr) -> Option<(String, String)> {
+        match expr {
+            Expr::Path(expr_path) => {
+                let name = expr_path.path.get_ident()?.to_string();
+                let behavior = self.behavior_locals.borrow().get(&name)?.clone();
+                Some((name, behavior))
+            }
+            Expr::Paren(paren) => self.behavior_local(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Remember a `let` binding that holds a `DataList`, `DataDictionary` or `DataToken`
+    fn bind_data_local(&self, pat: &Pat, init: &Expr) {
+        let Some(name) = pattern_ident(pat) else {
+            return;
+        };
+        let annotated = match pat {
+            Pat::Type(pat_type) => type_name(&pat_type.ty),
+            _ => None,
+        };
+        let constructed = match init {
+            Expr::Call(call) => match &*call.func {
+                Expr::Path(func) => {
+                    let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+                    (segments.len() >= 2).then(|| segments[segments.len() - 2].clone())
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        match annotated.or(constructed).as_deref() {
+            Some("DataList") => {
+                self.data_list_locals.borrow_mut().insert(name);
+            }
+            Some("DataDictionary") => {
+                self.data_dictionary_locals.borrow_mut().insert(name);
+            }
+            Some("DataToken") => {
+                self.data_token_locals.borrow_mut().insert(name);
+            }
+            _ if self.is_data_token(init) => {
+                self.data_token_locals.borrow_mut().insert(name);
+            }
+            _ => {}
+        }
+    }
+
+    /// Get the `Option` reference field named by `self.<field>`, looking through `as_ref`/`as_mut`
+    fn option_ref_field(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Field(field) if is_self(&field.base) => {
+                let member = member_name(&field.member)?;
+                self.context.option_ref_fields.contains_key(&member).then_some(member)
+            }
+            Expr::MethodCall(call) if call.args.is_empty() && (call.method == "as_ref" || call.method == "as_mut") => {
+                self.option_ref_field(&call.receiver)
+            }
+            Expr::Paren(paren) => self.option_ref_field(&paren.expr),
+            Expr::Reference(reference) => self.option_ref_field(&reference.expr),
+            _ => None,
+        }
+    }
+
+    /// Null checks to run before a statement that unwraps public `Option` references.
+    ///
+    /// An unassigned inspector reference would otherwise throw on every call
+    /// and halt the behavior. The guard logs which reference is missing the
+    /// first time, through a `{field}UnassignedLogged` flag the code generator
+    /// declares, and leaves the method instead.
+    fn null_reference_guards(&self, stmt: &Stmt, depth: usize) -> Vec<String> {
+        if !self.context.safe_refs {
+            return Vec::new();
+        }
+        let mut finder = UnwrapFinder { lowerer: self, fields: Vec::new() };
+        finder.visit_stmt(stmt);
+
+        let indent = indent(depth);
+        let return_stmt = self.default_return();
+        let mut lines = Vec::new();
+        for field in finder.fields {
+            let name = self.context.self_field(&field);
+            let flag = unassigned_flag_name(&name);
+            lines.extend([
+                format!("{}if ({} == null)", indent, name),
+                format!("{}{{", indent),
+                format!("{}    if (!{})", indent, flag),
+                format!("{}    {{", indent),
+                format!(
+                    "{}        Debug.LogError(\"Unassigned reference '{}' on {}\");",
+                    indent, field, self.context.behavior_name
+                ),
+                format!("{}        {} = true;", indent, flag),
+                format!("{}    }}", indent),
+                format!("{}    {}", indent, return_stmt),
+                format!("{}}}", indent),
+            ]);
+        }
+        lines
+    }
+
+    /// `return` statement leaving the method early with the default of its return type
+    fn default_return(&self) -> String {
+        match &self.context.return_type {
+            Some(return_type) if *return_type != RustType::Unit => {
+                format!("return {};", RustToCSharpTypeMapper::new().get_default_value(return_type))
+            }
+            _ => "return;".to_string(),
+        }
+    }
+
+    /// Lower an `assert!`-family macro to a check that logs the failure.
+    ///
+    /// `assert_eq!`/`assert_ne!` report both operands; a custom message is
+    /// formatted like a log call. The operands are lowered again in the
+    /// message, so they are evaluated a second time when the check fails.
+    fn lower_assertion(&self, mac: &syn::Macro, depth: usize) -> LoweringResult<Vec<String>> {
+        if self.context.assertion_mode == AssertionMode::Strip {
+            return Ok(Vec::new());
+        }
+        let name = mac.path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+        let expr_macro = syn::ExprMacro { attrs: Vec::new(), mac: mac.clone() };
+        let args: Vec<Expr> = mac
+            .parse_body_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)
+            .map_err(|e| LoweringError::ParseError { message: e.to_string() })?
+            .into_iter()
+            .collect();
+
+        let compares = name.ends_with("_eq") || name.ends_with("_ne");
+        let operand_count = if compares { 2 } else { 1 };
+        if args.len() < operand_count {
+            return Err(unsupported_macro(&expr_macro, &format!("{}! is missing its operands", name)));
+        }
+        let (failure, description, operands) = if compares {
+            let (left, right) = (self.lower_operand(&args[0])?, self.lower_operand(&args[1])?);
+            let (holds, fails) = if name.ends_with("_eq") { ("==", "!=") } else { ("!=", "==") };
+            (
+                format!("{} {} {}", left, fails, right),
+                format!("{} {} {}", left, holds, right),
+                Some((left, right)),
+            )
+        } else {
+            let condition = self.lower_expr(&args[0])?;
+            (format!("!({})", condition), condition, None)
+        };
+
+        let prefix = format!("[{}] assertion failed: ", self.context.behavior_name);
+        let message = if args.len() > operand_count {
+            let custom = syn::ExprMacro {
+                attrs: Vec::new(),
+                mac: syn::Macro {
+                    path: mac.path.clone(),
+                    bang_token: mac.bang_token,
+                    delimiter: mac.delimiter.clone(),
+                    tokens: args[operand_count..].iter().map(|arg| quote::quote!(#arg,)).collect(),
+                },
+            };
+            format!("{} + {}", csharp_string_literal(&prefix), self.lower_format_args(&custom)?)
+        } else {
+            csharp_string_literal(&format!("{}{}", prefix, description))
+        };
+        let message = match operands {
+            Some((left, right)) => format!("{} + \" (left: \" + {} + \", right: \" + {} + \")\"", message, left, right),
+            None => message,
+        };
+
+        let indent = indent(depth);
+        let mut lines = vec![
+            format!("{}if ({})", indent, failure),
+            format!("{}{{", indent),
+            format!("{}    Debug.LogError({});", indent, message),
+        ];
+        if self.context.assertion_mode == AssertionMode::LogAndDisable {
+            lines.push(format!("{}    enabled = false;", indent));
+            lines.push(format!("{}    {}", indent, self.default_return()));
+        }
+        lines.push(format!("{}}}", indent));
+        Ok(lines)
+    }
+
+    /// Get the name and C# element type of a `Vec` behavior field named by
+    /// `self.<field>`, or of an array parameter
+    fn array_field(&self, expr: &Expr) -> Option<(String, String)> {
+        match expr {
+            Expr::Field(field) if is_self(&field.base) => {
+                let member = member_name(&field.member)?;
+                let element_type = self.context.array_fields.get(&member)?;
+                Some((self.context.self_field(&member), element_type.clone()))
+            }
+            Expr::Path(path) => {
+                let name = path.path.get_ident()?.to_string();
+                let element_type = self.context.array_parameters.get(&name)?;
+                Some((local_name(&name), element_type.clone()))
+            }
+            Expr::Paren(paren) => self.array_field(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Lower `push`, `pop` and `clear` on a `Vec` field, and their `VecDeque`
+    /// counterparts, to operations on its backing array.
+    ///
+    /// Udon arrays have a fixed length, so pushing copies the array into one
+    /// a slot longer. The copy is made in a block of its own so that several
+    /// pushes in one method do not declare the same temporary twice.
+    fn lower_array_mutation(&self, call: &syn::ExprMethodCall, depth: usize) -> LoweringResult<Option<Vec<String>>> {
+        let Some((array, element_type)) = self.array_field(&call.receiver) else {
+            return Ok(None);
+        };
+        let resizes = matches!(call.method.to_string().as_str(), "clear" | "pop" | "pop_front" | "retain" | "retain_mut" | "push" | "push_back");
+        if resizes && matches!(&*call.receiver, Expr::Path(_)) {
+            return Err(unsupported(
+                &Expr::MethodCall(call.clone()),
+                "an array parameter cannot be resized, as the caller keeps its own array; resize a `Vec` field instead",
+            ));
+        }
+        let inner = indent(depth + 1);
+        let indent = indent(depth);
+        match (call.method.to_string().as_str(), call.args.len()) {
+            ("clear", 0) => Ok(Some(vec![format!("{}{} = new {}[0];", indent, array, element_type)])),
+            ("pop" | "pop_front", 0) => Ok(Some(vec![
+                format!("{}if ({}.Length > 0)", indent, array),
+                format!("{}{{", indent),
+                format!("{}{}", inner, self.array_shrink(&array, &element_type, call.method == "pop_front")),
+                format!("{}}}", indent),
+            ])),
+            ("retain" | "retain_mut", 1) => self.lower_array_retain(call, &array, &element_type, depth).map(Some),
+            ("push" | "push_back", 1) => {
+                let value = self.lower_expr(&call.args[0])?;
+                let grown = format!("{}Grown", array);
+                Ok(Some(vec![
+                    format!("{}{{", indent),
+                    format!("{}var {} = new {}[{}.Length + 1];", inner, grown, element_type, array),
+                    format!("{}System.Array.Copy({}, {}, {}.Length);", inner, array, grown, array),
+                    format!("{}{}[{}.Length] = {};", inner, grown, array, value),
+                    format!("{}{} = {};", inner, array, grown),
+                    format!("{}}}", indent),
+                ]))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Lower `pop_front()`/`pop()` on a non-empty `Vec` field to a `var binding = ..;`
+    /// of the removed element followed by the array shrinking around it
+    fn lower_array_pop(&self, call: &syn::ExprMethodCall, binding: &str, depth: usize) -> LoweringResult<Option<Vec<String>>> {
+        let Some((array, element_type)) = self.array_field(&call.receiver) else {
+            return Ok(None);
+        };
+        if !call.args.is_empty() {
+            return Ok(None);
+        }
+        let (front, index) = match call.method.to_string().as_str() {
+            "pop_front" => (true, "0".to_string()),
+            "pop" => (false, format!("{}.Length - 1", array)),
+            _ => return Ok(None),
+        };
+        let indent = indent(depth);
+        Ok(Some(vec![
+            format!("{}var {} = {}[{}];", indent, binding, array, index),
+            format!("{}{}", indent, self.array_shrink(&array, &element_type, front)),
+        ]))
+    }
+
+    /// One-line block copying a non-empty array into one a slot shorter,
+    /// dropping its first element when `front` is set and its last otherwise
+    fn array_shrink(&self, array: &str, element_type: &str, front: bool) -> String {
+        let shrunk = format!("{}Shrunk", array);
+        let source_start = if front { "1" } else { "0" };
+        format!(
+            "{{ var {shrunk} = new {element_type}[{array}.Length - 1]; System.Array.Copy({array}, {source_start}, {shrunk}, 0, {shrunk}.Length); {array} = {shrunk}; }}"
+        )
+    }
+
+    /// Lower `retain(|x| ..)` on a `Vec` field to a pass moving the kept
+    /// elements to the front of the backing array, in order, followed by a copy
+    /// into an array of the kept length when anything was removed
+    fn lower_array_retain(&self, call: &syn::ExprMethodCall, array: &str, element_type: &str, depth: usize) -> LoweringResult<Vec<String>> {
+        let Expr::Closure(closure) = &call.args[0] else {
+            return Err(unsupported(&call.args[0], "`retain` needs a closure predicate"));
+        };
+        let (binding, condition) = self.lower_predicate(closure)?;
+        let (outer, inner, body) = (indent(depth), indent(depth + 1), indent(depth + 2));
+        let kept = format!("{}Kept", array);
+        let index = format!("{}Index", array);
+        let shrunk = format!("{}Shrunk", array);
+
+        let mut lines = vec![
+            format!("{}{{", outer),
+            format!("{}var {} = 0;", inner, kept),
+            format!("{}for (int {} = 0; {} < {}.Length; {}++)", inner, index, index, array, index),
+            format!("{}{{", inner),
+        ];
+        if let Some(binding) = binding {
+            lines.push(format!("{}var {} = {}[{}];", body, binding, array, index));
+        }
+        lines.extend([
+            format!("{}if ({})", body, condition),
+            format!("{}{{", body),
+            format!("{}    {}[{}] = {}[{}];", body, array, kept, array, index),
+            format!("{}    {}++;", body, kept),
+            format!("{}}}", body),
+            format!("{}}}", inner),
+            format!("{}if ({} < {}.Length)", inner, kept, array),
+            format!("{}{{", inner),
+            format!("{}var {} = new {}[{}];", body, shrunk, element_type, kept),
+            format!("{}System.Array.Copy({}, {}, {});", body, array, shrunk, kept),
+            format!("{}{} = {};", body, array, shrunk),
+            format!("{}}}", inner),
+            format!("{}}}", outer),
+        ]);
+        Ok(lines)
+    }
+
+    /// Lower `UdonSharpUtility::shuffle(&mut self.field, seed)` to a
+    /// Fisher–Yates shuffle of the field's backing array. Unity's generator is
+    /// reseeded first so every client shuffling with the same seed swaps the
+    /// same elements.
+    fn lower_shuffle(&self, call: &syn::ExprCall, depth: usize) -> LoweringResult<Option<Vec<String>>> {
+        let Expr::Path(func) = &*call.func else {
+            return Ok(None);
+        };
+        let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+        if !segments.ends_with(&["UdonSharpUtility".to_string(), "shuffle".to_string()]) || call.args.len() != 2 {
+            return Ok(None);
+        }
+        let items = match &call.args[0] {
+            Expr::Reference(reference) => &*reference.expr,
+            other => other,
+        };
+        let Some((array, _)) = self.array_field(items) else {
+            return Err(unsupported(&call.args[0], "only `Vec` fields can be shuffled"));
+        };
+        let seed = self.lower_expr(&call.args[1])?;
+        let (outer, inner, body) = (indent(depth), indent(depth + 1), indent(depth + 2));
+        let index = format!("{}Index", array);
+        let swap = format!("{}Swap", array);
+        let swapped = format!("{}Swapped", array);
+
+        Ok(Some(vec![
+            format!("{}{{", outer),
+            format!("{}UnityEngine.Random.InitState({});", inner, seed),
+            format!("{}for (int {} = {}.Length - 1; {} > 0; {}--)", inner, index, array, index, index),
+            format!("{}{{", inner),
+            format!("{}var {} = UnityEngine.Random.Range(0, {} + 1);", body, swap, index),
+            format!("{}var {} = {}[{}];", body, swapped, array, index),
+            format!("{}{}[{}] = {}[{}];", body, array, index, array, swap),
+            format!("{}{}[{}] = {};", body, array, swap, swapped),
+            format!("{}}}", inner),
+            format!("{}}}", outer),
+        ]))
+    }
+
+    /// Resolve a search over a `Vec` field, `contains(&value)`,
+    /// `iter().position(|x| ..)` or `iter().find(|x| ..)`, to the searched
+    /// field expression and the kind of search
+    fn array_lookup<'a>(&self, expr: &'a Expr) -> Option<(&'a Expr, ArrayLookup<'a>)> {
+        let call = match expr {
+            Expr::Paren(paren) => return self.array_lookup(&paren.expr),
+            Expr::MethodCall(call) if call.args.len() == 1 => call,
+            _ => return None,
+        };
+        let method = call.method.to_string();
+        if method == "contains" {
+            return self.array_field(&call.receiver).map(|_| (&*call.receiver, ArrayLookup::Contains(&call.args[0])));
+        }
+        let (Expr::MethodCall(iter), Expr::Closure(closure)) = (&*call.receiver, &call.args[0]) else {
+            return None;
+        };
+        if !iter.args.is_empty()
+            || !matches!(iter.method.to_string().as_str(), "iter" | "iter_mut" | "into_iter")
+            || self.array_field(&iter.receiver).is_none()
+        {
+            return None;
+        }
+        match method.as_str() {
+            "position" => Some((&*iter.receiver, ArrayLookup::Position(closure))),
+            "find" => Some((&*iter.receiver, ArrayLookup::Find(closure))),
+            _ => None,
+        }
+    }
+
+    /// Whether an `if` condition is lowered by `lower_lookup_if`
+    fn is_lookup_condition(&self, cond: &Expr) -> bool {
+        match cond {
+            Expr::Let(expr_let) => self.array_lookup(&expr_let.expr).is_some(),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => self.array_lookup(&unary.expr).is_some(),
+            cond => self.array_lookup(cond).is_some(),
+        }
+    }
+
+    /// Lower a search over a `Vec` field to a loop that leaves the index of the
+    /// first matching element in a new `found` local, or -1 when none matches.
+    ///
+    /// The loop stops at the first match, like the Rust iterator methods, and
+    /// the value passed to `contains` is evaluated once ahead of it.
+    fn lower_array_search(&self, array_expr: &Expr, lookup: &ArrayLookup, found: &str, depth: usize) -> LoweringResult<Vec<String>> {
+        let (array, _) = self.array_field(array_expr)
+            .ok_or_else(|| unsupported(array_expr, "only `Vec` fields can be searched"))?;
+        let (outer, inner, body) = (indent(depth), indent(depth + 1), indent(depth + 2));
+        let index = format!("{}Index", array);
+        let element = format!("{}[{}]", array, index);
+
+        let mut lines = Vec::new();
+        let (binding, condition) = match lookup {
+            ArrayLookup::Contains(value_expr) => {
+                let mut value = self.lower_expr(value_expr)?;
+                let plain = match *value_expr {
+                    Expr::Reference(reference) => &*reference.expr,
+                    other => other,
+                };
+                if !is_place_expr(plain) && !matches!(plain, Expr::Lit(_)) {
+                    let target = format!("{}Target", found);
+                    lines.push(format!("{}var {} = {};", outer, target, value));
+                    value = target;
+                }
+                (None, format!("{} == {}", element, value))
+            }
+            ArrayLookup::Position(closure) | ArrayLookup::Find(closure) => self.lower_predicate(closure)?,
+        };
+
+        lines.extend([
+            format!("{}var {} = -1;", outer, found),
+            format!("{}for (int {} = 0; {} < {}.Length; {}++)", outer, index, index, array, index),
+            format!("{}{{", outer),
+        ]);
+        if let Some(binding) = binding {
+            lines.push(format!("{}var {} = {};", inner, binding, element));
+        }
+        lines.extend([
+            format!("{}if ({})", inner, condition),
+            format!("{}{{", inner),
+            format!("{}{} = {};", body, found, index),
+            format!("{}break;", body),
+            format!("{}}}", inner),
+            format!("{}}}", outer),
+        ]);
+        Ok(lines)
+    }
+
+    /// Lower `let name = self.field.contains(&value);` to the search loop and a
+    /// `bool` of whether it found the value (or, when negated, whether it did not)
+    fn lower_bound_lookup(&self, name: &str, declared_type: &str, array_expr: &Expr, lookup: &ArrayLookup, negated: bool, depth: usize) -> LoweringResult<Vec<String>> {
+        if !matches!(lookup, ArrayLookup::Contains(_)) {
+            return Err(unsupported(array_expr, "`position`/`find` results can only be matched with `if let Some(..)`"));
+        }
+        let found = format!("{}At", name);
+        let mut lines = self.lower_array_search(array_expr, lookup, &found, depth)?;
+        let test = if negated { "<" } else { ">=" };
+        lines.push(format!("{}{} {} = {} {} 0;", indent(depth), declared_type, name, found, test));
+        Ok(lines)
+    }
+
+    /// Lower an `if` testing `contains` on a `Vec` field, or matching
+    /// `if let Some(x)` against its `iter().position(..)`/`iter().find(..)`, to
+    /// the search loop followed by an `if` on the found index. Both go in a
+    /// block of their own so that a later search in the same method does not
+    /// declare the same locals twice.
+    fn lower_lookup_if(&self, expr_if: &syn::ExprIf, depth: usize) -> LoweringResult<Option<Vec<String>>> {
+        let (negated, cond) = match &*expr_if.cond {
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => (true, &*unary.expr),
+            cond => (false, cond),
+        };
+        let mut rewritten = expr_if.clone();
+        let (array_expr, lookup, found) = match cond {
+            Expr::Let(expr_let) => {
+                let Some((array_expr, lookup)) = self.array_lookup(&expr_let.expr) else {
+                    return Ok(None);
+                };
+                let binding = match &*expr_let.pat {
+                    Pat::TupleStruct(tuple) if tuple.path.is_ident("Some") && tuple.elems.len() == 1 => {
+                        pattern_ident(strip_pattern_reference(&tuple.elems[0]))
+                    }
+                    _ => None,
+                }.ok_or_else(|| unsupported(&expr_let.expr, "searches are matched with a plain `Some(x)` pattern"))?;
+                let found = match lookup {
+                    ArrayLookup::Position(_) => binding,
+                    ArrayLookup::Find(_) => {
+                        let found = format!("{}Index", binding);
+                        let item = syn::Ident::new(&binding, proc_macro2::Span::call_site());
+                        let index = syn::Ident::new(&found, proc_macro2::Span::call_site());
+                        rewritten.then_branch.stmts.insert(0, syn::parse_quote!(let #item = #array_expr[#index];));
+                        found
+                    }
+                    ArrayLookup::Contains(_) => {
+                        return Err(unsupported(&expr_let.expr, "`contains` returns a bool; test it with a plain `if`"));
+                    }
+                };
+                (array_expr, lookup, found)
+            }
+            cond => match self.array_lookup(cond) {
+                Some((array_expr, lookup @ ArrayLookup::Contains(_))) => {
+                    let (array, _) = self.array_field(array_expr)
+                        .ok_or_else(|| unsupported(array_expr, "only `Vec` fields can be searched"))?;
+                    (array_expr, lookup, format!("{}Found", array))
+                }
+                Some(_) => return Err(unsupported(cond, "`position`/`find` results can only be matched with `if let Some(..)`")),
+                None => return Ok(None),
+            },
+        };
+
+        let found_ident = syn::Ident::new(&found, proc_macro2::Span::call_site());
+        *rewritten.cond = if negated {
+            syn::parse_quote!(#found_ident < 0)
+        } else {
+            syn::parse_quote!(#found_ident >= 0)
+        };
+        let mut lines = vec![format!("{}{{", indent(depth))];
+        lines.extend(self.lower_array_search(array_expr, &lookup, &local_name(&found), depth + 1)?);
+        lines.extend(self.lower_if(&rewritten, depth + 1)?);
+        lines.push(format!("{}}}", indent(depth)));
+        Ok(Some(lines))
+    }
+
+    /// Lower a one-parameter closure used as a predicate over array elements
+    /// to the element binding it declares, if any, and its condition
+    fn lower_predicate(&self, closure: &syn::ExprClosure) -> LoweringResult<(Option<String>, String)> {
+        let closure_expr = || Expr::Closure(closure.clone());
+        if closure.inputs.len() != 1 {
+            return Err(unsupported(&closure_expr(), "predicates take exactly one parameter"));
+        }
+        let binding = match strip_pattern_reference(&closure.inputs[0]) {
+            Pat::Wild(_) => None,
+            pat => Some(pattern_ident(pat)
+                .ok_or_else(|| unsupported(&closure_expr(), "predicate parameters must be a plain binding"))?),
+        };
+        let body = match &*closure.body {
+            Expr::Block(block) => match block.block.stmts.as_slice() {
+                [Stmt::Expr(body, None)] => body,
+                _ => return Err(unsupported(&closure_expr(), "predicate bodies must be a single expression")),
+            },
+            body => body,
+        };
+        Ok((binding.map(|binding| local_name(&binding)), self.lower_expr(body)?))
+    }
+
+    /// Whether an expression is a `DataList` local or behavior field
+    fn is_data_list(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Path(expr_path) => expr_path.path.get_ident()
+                .is_some_and(|ident| self.data_list_locals.borrow().contains(&ident.to_string())),
+            Expr::Field(field) if is_self(&field.base) => member_name(&field.member)
+                .is_some_and(|member| self.context.data_list_fields.contains(&member)),
+            Expr::Paren(paren) => self.is_data_list(&paren.expr),
+            Expr::Reference(reference) => self.is_data_list(&reference.expr),
+            _ => false,
+        }
+    }
+
+    /// Whether an expression is a `DataDictionary` local
+    fn is_data_dictionary(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Path(expr_path) => expr_path.path.get_ident()
+                .is_some_and(|ident| self.data_dictionary_locals.borrow().contains(&ident.to_string())),
+            Expr::Paren(paren) => self.is_data_dictionary(&paren.expr),
+            Expr::Reference(reference) => self.is_data_dictionary(&reference.expr),
+            _ => false,
+        }
+    }
+
+    /// Whether an expression is a `DataToken`: a `DataToken` local or an
+    /// element read from a `DataList` or `DataDictionary`
+    fn is_data_token(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Path(expr_path) => expr_path.path.get_ident()
+                .is_some_and(|ident| self.data_token_locals.borrow().contains(&ident.to_string())),
+            Expr::MethodCall(call) => call.method == "get"
+                && call.args.len() == 1
+                && (self.is_data_list(&call.receiver) || self.is_data_dictionary(&call.receiver)),
+            Expr::Paren(paren) => self.is_data_token(&paren.expr),
+            Expr::Reference(reference) => self.is_data_token(&reference.expr),
+            _ => false,
+        }
+    }
+
+    /// Resolve `local.<field>` on another behavior to `(local, behavior)`
+    fn behavior_field_base(&self, expr: &Expr) -> Option<(String, String)> {
+        match expr {
+            Expr::Field(field) => self.behavior_local(&field.base),
+            _ => None,
+        }
+    }
+
+    /// Lower a free function or associated function call
+    fn lower_call(&self, call: &syn::ExprCall) -> LoweringResult<String> {
+        let args = || self.lower_args(call.args.iter());
+
+        if self.context.json_serializable && is_self_to_json_call(call) {
+            return Ok("ToJson()".to_string());
+        }
+
+        if let Expr::Path(expr_path) = &*call.func {
+            let joined = expr_path.path.segments.iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
+
+            // A `Result<(), E>` is lowered to its error, null on success
+            if let [value] = call.args.iter().collect::<Vec<_>>().as_slice() {
+                match joined.as_str() {
+                    "Ok" if matches!(value, Expr::Tuple(unit) if unit.elems.is_empty()) => return Ok("null".to_string()),
+                    "Ok" => return Err(unsupported(value, "only `Result<(), E>` is supported; return the value through a field")),
+                    "Err" => return self.lower_expr(value),
+                    _ => {}
+                }
+            }
+
+            if let Some((payload_enum, variant)) = self.payload_variant(&expr_path.path) {
+                if call.args.len() != variant.fields.len() {
+                    return Err(unsupported(
+                        &Expr::Call(call.clone()),
+                        &format!("{}::{} takes {} value(s)", payload_enum.name, variant.name, variant.fields.len()),
+                    ));
+                }
+                return Ok(format!("{}({})", payload_enum.constructor_name(&variant.name), args()?));
+            }
+
+            if let [enum_name, constructor] = expr_path.path.segments.iter().collect::<Vec<_>>().as_slice() {
+                if let Some(int_enum) = self.context.int_enums.get(&enum_name.ident.to_string()) {
+                    if is_int_enum_constructor(&constructor.ident.to_string()) {
+                        return Ok(format!("{}({})", int_enum.conversion_method_name(), args()?));
+                    }
+                }
+            }
+
+            if let Some(pool) = &self.context.record_pool {
+                if matches!(lower_known_call(&joined), Some(KnownCall::Method(constructor)) if pool.allocates(constructor)) {
+                    return Ok(format!("{}()", pool.acquire_method()));
+                }
+                if joined == "drop" || joined == "std::mem::drop" {
+                    return Ok(format!("{}({})", pool.release_method(), args()?));
+                }
+            }
+
+            if let Some(known) = lower_known_call(&joined) {
+                return Ok(match known {
+                    KnownCall::Property(property) => property.to_string(),
+                    KnownCall::Method(method) => {
+                        if let Some(constructed) = method.strip_prefix("new ") {
+                            self.note_emitted_type(constructed);
+                        }
+                        format!("{}({})", method, args()?)
+                    }
+                    KnownCall::OutMethod(method) => self.lower_out_call(method, call)?,
+                    KnownCall::SpreadMethod(method) => format!("{}({})", method, self.lower_spread_args(call)?),
+                    KnownCall::Template(template) => {
+                        call.args.iter().enumerate().try_fold(template.to_string(), |lowered, (i, arg)| {
+                            Ok(lowered.replace(&format!("{{{}}}", i), &self.lower_operand(arg)?))
+                        })?
+                    }
+                });
+            }
+
+            return Err(unsupported(&Expr::Call(call.clone()), "no C# binding is known for this function"));
+        }
+
+        Err(unsupported(&call.func, "only named functions can be called"))
+    }
+
+    /// Lower a call to an SDK method that reports its result through a trailing `out` parameter
+    fn lower_out_call(&self, method: &str, call: &syn::ExprCall) -> LoweringResult<String> {
+        let args: Vec<&Expr> = call.args.iter().collect();
+        let Some((result, inputs)) = args.split_last() else {
+            return Err(unsupported(&call.func, "expected a trailing `&mut` result argument"));
+        };
+        let Expr::Reference(reference) = result else {
+            return Err(unsupported(result, "the result argument must be borrowed with `&mut`"));
+        };
+        if reference.mutability.is_none() {
+            return Err(unsupported(result, "the result argument must be borrowed with `&mut`"));
+        }
+
+        let mut lowered = inputs.iter()
+            .map(|arg| self.lower_expr(arg))
+            .collect::<LoweringResult<Vec<_>>>()?;
+        lowered.push(format!("out {}", self.lower_expr(&reference.expr)?));
+        Ok(format!("{}({})", method, lowered.join(", ")))
+    }
+
+    /// Lower the arguments of a `params` call, spreading a slice literal into separate arguments
+    fn lower_spread_args(&self, call: &syn::ExprCall) -> LoweringResult<String> {
+        let spread = call.args.iter().flat_map(|arg| {
+            let inner = match arg {
+                Expr::Reference(reference) => &*reference.expr,
+                other => other,
+            };
+            match inner {
+                Expr::Array(array) => array.elems.iter().collect::<Vec<_>>(),
+                other => vec![other],
+            }
+        });
+        self.lower_args(spread)
+    }
+
+    /// Lower a comma-separated argument list
+    fn lower_args<'a>(&self, args: impl Iterator<Item = &'a Expr>) -> LoweringResult<String> {
+        let lowered = args.map(|arg| self.lower_expr(arg)).collect::<LoweringResult<Vec<_>>>()?;
+        Ok(lowered.join(", "))
+    }
+
+    /// Lower a macro invocation
+    fn lower_macro(&self, expr_macro: &syn::ExprMacro) -> LoweringResult<String> {
+        let name = expr_macro.mac.path.segments.last()
+            .map(|s| s.ident.to_string())
+            .unwrap_or_default();
+
+        if let Some(method) = log_macro_method(&expr_macro.mac) {
+            return Ok(format!("{}({})", method, self.lower_format_args(expr_macro)?));
+        }
+
+        match name.as_str() {
+            "format" => self.lower_format_args(expr_macro),
+            "matches" => self.lower_matches(expr_macro).map(|(condition, _)| condition),
+            _ => Err(unsupported_macro(expr_macro, "macro has no UdonSharp lowering")),
+        }
+    }
+
+    /// Lower a returned value. Methods returning a `Vec` return a C# array,
+    /// so collections built in the return itself (`Vec::new()`, `vec![]`,
+    /// `vec![a, b]`) become arrays of the declared element type.
+    fn lower_return_value(&self, value: &Expr) -> LoweringResult<String> {
+        let Some(RustType::Vec(element)) = &self.context.return_type else {
+            return self.lower_expr(value);
+        };
+        let element_type = RustToCSharpTypeMapper::new().map_type(element)
+            .map_err(|reason| unsupported(value, &reason))?;
+
+        if let Expr::Macro(expr_macro) = value {
+            if expr_macro.mac.path.is_ident("vec") {
+                let elements = expr_macro.mac
+                    .parse_body_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)
+                    .map_err(|_| unsupported(value, "only `vec![a, b, ...]` lists can be returned as an array"))?;
+                if elements.is_empty() {
+                    return Ok(format!("new {}[0]", element_type));
+                }
+                let elements = elements.iter()
+                    .map(|element| self.lower_expr(element))
+                    .collect::<LoweringResult<Vec<_>>>()?;
+                return Ok(format!("new {}[] {{ {} }}", element_type, elements.join(", ")));
+            }
+        }
+        if is_empty_vec_constructor(value) {
+            return Ok(format!("new {}[0]", element_type));
+        }
+        self.lower_expr(value)
+    }
+
+    /// Lower `matches!(value, pattern if guard)` to a boolean C# expression.
+    ///
+    /// Each alternative of an or-pattern becomes a comparison of the value, and
+    /// the alternatives are joined with `||`. The value is repeated in every
+    /// comparison, so it must be free of side effects when there are several.
+    /// Also returns whether the condition joins several comparisons and needs
+    /// parentheses as the operand of another logical operator.
+    fn lower_matches(&self, expr_macro: &syn::ExprMacro) -> LoweringResult<(String, bool)> {
+        let (value, pat, guard) = expr_macro.mac
+            .parse_body_with(|input: syn::parse::ParseStream| {
+                let value: Expr = input.parse()?;
+                input.parse::<syn::Token![,]>()?;
+                let pat = Pat::parse_multi_with_leading_vert(input)?;
+                let guard = if input.peek(syn::Token![if]) {
+                    input.parse::<syn::Token![if]>()?;
+                    Some(input.parse::<Expr>()?)
+                } else {
+                    None
+                };
+                input.parse::<Option<syn::Token![,]>>()?;
+                Ok((value, pat, guard))
+            })
+            .map_err(|e| LoweringError::ParseError { message: e.to_string() })?;
+
+        let alternatives: Vec<&Pat> = match &pat {
+            Pat::Or(or) => or.cases.iter().collect(),
+            pat => vec![pat],
+        };
+        if alternatives.len() > 1 && !is_place_expr(&value) {
+            return Err(unsupported_macro(
+                expr_macro,
+                "`matches!` with several patterns needs a field or local; bind the value to a local first",
+            ));
+        }
+
+        let scrutinee = self.lower_operand(&value)?;
+        let mut comparisons = Vec::new();
+        for alternative in alternatives {
+            match self.lower_pattern_test(&scrutinee, alternative)? {
+                Some(comparison) => comparisons.push(comparison),
+                // A wildcard alternative matches everything
+                None => {
+                    comparisons.clear();
+                    break;
+                }
+            }
+        }
+
+        let mut condition = comparisons.join(" || ");
+        let mut compound = comparisons.len() > 1;
+        if let Some(guard) = guard {
+            let guard = match &guard {
+                Expr::Binary(binary) if matches!(binary.op, BinOp::Or(_)) => format!("({})", self.lower_expr(&guard)?),
+                _ => self.lower_expr(&guard)?,
+            };
+            condition = match comparisons.len() {
+                0 => guard,
+                1 => format!("{} && {}", condition, guard),
+                _ => format!("({}) && {}", condition, guard),
+            };
+            compound = !comparisons.is_empty();
+        } else if comparisons.is_empty() {
+            condition = "true".to_string();
+        }
+
+        Ok((condition, compound))
+    }
+
+    /// Lower a pattern to a C# test of an already-lowered value; `None` for a wildcard
+    fn lower_pattern_test(&self, scrutinee: &str, pat: &Pat) -> LoweringResult<Option<String>> {
+        let unsupported_pattern = |reason: &str| LoweringError::UnsupportedExpression {
+            expression: quote::quote!(#pat).to_string(),
+            reason: reason.to_string(),
+        };
+
+        match pat {
+            Pat::Wild(_) => Ok(None),
+            Pat::Paren(paren) => self.lower_pattern_test(scrutinee, &paren.pat),
+            Pat::Lit(lit) => Ok(Some(format!("{} == {}", scrutinee, self.lower_expr(&Expr::Lit(lit.clone()))?))),
+            Pat::Path(path) => Ok(Some(format!("{} == {}", scrutinee, self.lower_path(&path.path)))),
+            Pat::Ident(ident) if ident.subpat.is_none() => {
+                let name = ident.ident.to_string();
+                if name == "None" || name.starts_with(|c: char| c.is_uppercase()) {
+                    Ok(Some(format!("{} == {}", scrutinee, self.lower_path(&ident.ident.clone().into()))))
+                } else {
+                    Err(unsupported_pattern("bindings are not supported in `matches!` patterns"))
+                }
+            }
+            Pat::TupleStruct(tuple) if tuple.path.is_ident("Some")
+                && tuple.elems.len() == 1
+                && matches!(tuple.elems.first(), Some(Pat::Wild(_))) =>
+            {
+                Ok(Some(format!("{} != null", scrutinee)))
+            }
+            Pat::Range(range) => {
+                let mut bounds = Vec::new();
+                if let Some(start) = &range.start {
+                    bounds.push(format!("{} >= {}", scrutinee, self.lower_expr(start)?));
+                }
+                if let Some(end) = &range.end {
+                    let op = match range.limits {
+                        RangeLimits::HalfOpen(_) => "<",
+                        RangeLimits::Closed(_) => "<=",
+                    };
+                    bounds.push(format!("{} {} {}", scrutinee, op, self.lower_expr(end)?));
+                }
+                Ok(match bounds.len() {
+                    0 => None,
+                    1 => Some(bounds.remove(0)),
+                    _ => Some(format!("({})", bounds.join(" && "))),
+                })
+            }
+            _ => Err(unsupported_pattern("pattern has no UdonSharp lowering")),
+        }
+    }
+
+    /// Lower the `format!`-style arguments of a macro to a C# string expression
+    fn lower_format_args(&self, expr_macro: &syn::ExprMacro) -> LoweringResult<String> {
+        let name = expr_macro.mac.path.segments.last()
+            .map(|s| s.ident.to_string())
+            .unwrap_or_default();
+        let args: syn::punctuated::Punctuated<Expr, syn::Token![,]> = expr_macro.mac
+            .parse_body_with(syn::punctuated::Punctuated::parse_terminated)
+            .map_err(|e| LoweringError::ParseError { message: e.to_string() })?;
+        let mut args = args.into_iter();
+        let template = match args.next() {
+            Some(Expr::Lit(syn::ExprLit { lit: Lit::Str(s), .. })) => s.value(),
+            _ => return Err(unsupported_macro(expr_macro, &format!("{}! requires a string literal template", name))),
+        };
+        // Named arguments (`name = expr`) follow the positional ones
+        let mut named_args = Vec::new();
+        let mut values = Vec::new();
+        for arg in args {
+            match arg {
+                Expr::Assign(assign) => match &*assign.left {
+                    Expr::Path(path) if path.path.get_ident().is_some() => {
+                        named_args.push((path.path.segments[0].ident.to_string(), *assign.right));
+                    }
+                    _ => return Err(unsupported_macro(expr_macro, "named format arguments must be identifiers")),
+                },
+                arg => values.push(self.lower_expr(&arg)?),
+            }
+        }
+        let positional_count = values.len();
+
+        // Translate Rust placeholders to C# composite format items
+        let mut named_indices: HashMap<String, usize> = HashMap::new();
+        let mut next_positional = 0;
+        let mut csharp_template = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    csharp_template.push_str("{{");
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    csharp_template.push_str("}}");
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(unsupported_macro(expr_macro, "format string has an unclosed `{`")),
+                        }
+                    }
+                    let (argument, spec) = placeholder.split_once(':').unwrap_or((placeholder.as_str(), ""));
+                    let argument = argument.trim();
+                    let positional = if argument.is_empty() {
+                        next_positional += 1;
+                        Some(next_positional - 1)
+                    } else {
+                        argument.parse::<usize>().ok()
+                    };
+                    let index = if let Some(index) = positional {
+                        if index >= positional_count {
+                            return Err(unsupported_macro(expr_macro, "format string has more placeholders than arguments"));
+                        }
+                        index
+                    } else if let Some(&index) = named_indices.get(argument) {
+                        index
+                    } else {
+                        // Inline `{name}` captures a local unless a named argument supplies it
+                        let value = match named_args.iter().find(|(name, _)| name == argument) {
+                            Some((_, expr)) => self.lower_expr(expr)?,
+                            None => {
+                                let ident: syn::Ident = syn::parse_str(argument).map_err(|_| unsupported_macro(
+                                    expr_macro,
+                                    &format!("format argument `{}` is not supported", argument),
+                                ))?;
+                                self.lower_expr(&ident_expr(ident))?
+                            }
+                        };
+                        values.push(value);
+                        named_indices.insert(argument.to_string(), values.len() - 1);
+                        values.len() - 1
+                    };
+                    let format = csharp_format_spec(spec).ok_or_else(|| unsupported_macro(
+                        expr_macro,
+                        &format!("format spec `{{:{}}}` has no C# equivalent", spec),
+                    ))?;
+                    csharp_template.push_str(&format!("{{{}{}}}", index, format));
+                }
+                '}' => return Err(unsupported_macro(expr_macro, "format string has an unmatched `}`")),
+                c => csharp_template.push(c),
+            }
+        }
+
+        if values.is_empty() {
+            Ok(csharp_string_literal(&csharp_template.replace("{{", "{").replace("}}", "}")))
+        } else {
+            Ok(format!("string.Format({}, {})", csharp_string_literal(&csharp_template), values.join(", ")))
+        }
+    }
+
+    /// Whether a macro is a debug-level log call that the context strips
+    fn is_stripped_log(&self, mac: &syn::Macro) -> bool {
+        self.context.strip_debug_logs
+            && log_macro_method(mac).is_some()
+            && mac.path.segments.last().is_some_and(|s| s.ident == "debug" || s.ident == "trace")
+    }
+
+    /// Lower a Rust type to its C# spelling
+    fn lower_type(&self, ty: &Type) -> LoweringResult<String> {
+        match ty {
+            Type::Path(type_path) => {
+                let ident = type_path.path.segments.last()
+                    .map(|s| s.ident.to_string())
+                    .unwrap_or_default();
+                let lowered = match ident.as_str() {
+                    "bool" => "bool",
+                    "i8" => "sbyte",
+                    "i16" => "short",
+                    "i32" => "int",
+                    "i64" => "long",
+                    "u8" => "byte",
+                    "u16" => "ushort",
+                    "u32" => "uint",
+                    "u64" => "ulong",
+                    "f32" => "float",
+                    "f64" => "double",
+                    "char" => "char",
+                    "String" | "str" => "string",
+                    other if self.context.payload_enums.contains_key(other) => PAYLOAD_ENUM_RECORD_TYPE,
+                    other => other,
+                }.to_string();
+                self.note_emitted_type(&lowered);
+                Ok(lowered)
+            }
+            Type::Reference(reference) => self.lower_type(&reference.elem),
+            _ => Err(LoweringError::UnsupportedExpression {
+                expression: quote::quote!(#ty).to_string(),
+                reason: "type has no UdonSharp equivalent".to_string(),
+            }),
+        }
+    }
+
+    /// Resolve `self.<field>` to an embedded struct if the field holds one
+    fn embedded_base(&self, expr: &Expr) -> Option<&EmbeddedStruct> {
+        if let Expr::Field(field) = expr {
+            if is_self(&field.base) {
+                if let Some(name) = member_name(&field.member) {
+                    return self.context.embedded(&name);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Default for BodyLowerer {
+    fn default() -> Self {
+        Self::new(LoweringContext::new())
+    }
+}
+
+/// A search over a `Vec` field, lowered to a loop by `lower_array_search`
+enum ArrayLookup<'a> {
+    /// `contains(&value)`
+    Contains(&'a Expr),
+    /// `iter().position(|x| ..)`
+    Position(&'a syn::ExprClosure),
+    /// `iter().find(|x| ..)`
+    Find(&'a syn::ExprClosure),
+}
+
+/// Known free functions from the Rust bindings and their C# lowering
+enum KnownCall {
+    /// Lowered to a property access; arguments are dropped
+    Property(&'static str),
+    /// Lowered to a call of the given C# method
+    Method(&'static str),
+    /// Lowered to a method call whose trailing `&mut` argument is an `out` parameter
+    OutMethod(&'static str),
+    /// Lowered to a `params` method call; a slice literal argument is spread into arguments
+    SpreadMethod(&'static str),
+    /// Lowered to a C# expression with the arguments substituted for `{0}`, `{1}`, ...
+    Template(&'static str),
+}
+
+/// Look up the C# lowering of a well-known binding function
+fn lower_known_call(path: &str) -> Option<KnownCall> {
+    let path = path.strip_prefix("unity::").unwrap_or(path);
+    let path = path.strip_prefix("vrchat::").unwrap_or(path);
+    // `Networking::x` and `networking::Networking::x` name the same binding as `networking::x`,
+    // and likewise for `Time::x`
+    let normalized;
+    let path = if let Some(rest) = path.strip_prefix("networking::Networking::").or_else(|| path.strip_prefix("Networking::")) {
+        normalized = format!("networking::{}", rest);
+        normalized.as_str()
+    } else if let Some(rest) = path.strip_prefix("time::Time::").or_else(|| path.strip_prefix("Time::")) {
+        normalized = format!("time::{}", rest);
+        normalized.as_str()
+    } else {
+        path
+    };
+    match path {
+        "DataList::new" | "DataList::default" => Some(KnownCall::Method("new DataList")),
+        "DataToken::from" | "DataToken::new" => Some(KnownCall::Method("new DataToken")),
+        "DataDictionary::new" | "DataDictionary::default" | "HashMap::new" | "HashMap::default" => {
+            Some(KnownCall::Method("new DataDictionary"))
+        }
+        "VRCJson::try_serialize_to_json" => Some(KnownCall::OutMethod("VRCJson.TrySerializeToJson")),
+        "VRCJson::try_deserialize_from_json" => Some(KnownCall::OutMethod("VRCJson.TryDeserializeFromJson")),
+        "log_info" | "debug_log" => Some(KnownCall::Method("Debug.Log")),
+        "log_warning" | "log_warn" => Some(KnownCall::Method("Debug.LogWarning")),
+        "log_error" => Some(KnownCall::Method("Debug.LogError")),
+        "networking::is_master" => Some(KnownCall::Property("Networking.IsMaster")),
+        "networking::get_local_player" | "networking::local_player" => Some(KnownCall::Property("Networking.LocalPlayer")),
+        "networking::request_serialization" => Some(KnownCall::Method("RequestSerialization")),
+        "networking::get_owner" => Some(KnownCall::Method("Networking.GetOwner")),
+        "networking::is_owner" => Some(KnownCall::Method("Networking.IsOwner")),
+        "networking::set_owner" => Some(KnownCall::Method("Networking.SetOwner")),
+        "time::delta_time" => Some(KnownCall::Property("Time.deltaTime")),
+        "time::fixed_delta_time" => Some(KnownCall::Property("Time.fixedDeltaTime")),
+        "time::time" => Some(KnownCall::Property("Time.time")),
+        "time::fixed_time" => Some(KnownCall::Property("Time.fixedTime")),
+        "GameObject::find" => Some(KnownCall::Method("GameObject.Find")),
+        "LayerMask::name_to_layer" => Some(KnownCall::Method("LayerMask.NameToLayer")),
+        "LayerMask::layer_to_name" => Some(KnownCall::Method("LayerMask.LayerToName")),
+        "LayerMask::get_mask" => Some(KnownCall::SpreadMethod("LayerMask.GetMask")),
+        "LayerMask::contains" => Some(KnownCall::Template("(({0} & (1 << {1})) != 0)")),
+        "Mathf::round_to_int" => Some(KnownCall::Method("Mathf.RoundToInt")),
+        "Mathf::floor_to_int" => Some(KnownCall::Method("Mathf.FloorToInt")),
+        "Mathf::ceil_to_int" => Some(KnownCall::Method("Mathf.CeilToInt")),
+        "networking::get_player_count" => Some(KnownCall::Method("VRCPlayerApi.GetPlayerCount")),
+        "String::new" | "String::default" => Some(KnownCall::Property("\"\"")),
+        "String::from" => Some(KnownCall::Template("{0}")),
+        _ => None,
+    }
+}
+
+/// Whether a method on an SDK value is spelled the same in C# once converted
+/// to PascalCase, such as `player.get_position()` for `player.GetPosition()`
+fn is_passthrough_method(method: &str) -> bool {
+    matches!(
+        method,
+        "to_string" | "get_position" | "get_rotation" | "get_velocity" | "set_velocity" | "teleport_to"
+            | "set_active" | "compare_tag" | "contains"
+    )
+}
+
+/// Map a `log` crate macro, written as `log::info!` or `info!`, to the `Debug` method it lowers to
+fn log_macro_method(mac: &syn::Macro) -> Option<&'static str> {
+    let segments: Vec<String> = mac.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let level = match segments.as_slice() {
+        [level] => level,
+        [krate, level] if krate == "log" => level,
+        _ => return None,
+    };
+    match level.as_str() {
+        "info" | "debug" | "trace" => Some("Debug.Log"),
+        "warn" => Some("Debug.LogWarning"),
+        "error" => Some("Debug.LogError"),
+        _ => None,
+    }
+}
+
+/// Map a `DataToken` accessor to the SDK property it reads
+fn data_token_accessor(method: &str) -> Option<&'static str> {
+    match method {
+        "as_bool" => Some("Boolean"),
+        "as_int" => Some("Int"),
+        "as_float" => Some("Float"),
+        "as_double" => Some("Double"),
+        "as_string" => Some("String"),
+        "as_data_list" => Some("DataList"),
+        _ => None,
+    }
+}
+
+/// Get the last path segment of a type, if it is a path
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.last().map(|s| s.ident.to_string()),
+        Type::Reference(reference) => type_name(&reference.elem),
+        _ => None,
+    }
+}
+
+/// Look up the C# lowering of a well-known constant path
+pub fn lower_known_path(path: &str) -> Option<&'static str> {
+    match path {
+        "Vector3::ZERO" => Some("Vector3.zero"),
+        "Vector3::ONE" => Some("Vector3.one"),
+        "Vector3::UP" => Some("Vector3.up"),
+        "Vector2::ZERO" => Some("Vector2.zero"),
+        "Vector2::ONE" => Some("Vector2.one"),
+        "Quaternion::IDENTITY" => Some("Quaternion.identity"),
+        _ => None,
+    }
+}
+
+/// Map a Rust binary operator to C#
+fn lower_bin_op(op: &BinOp) -> &'static str {
+    match op {
+        BinOp::Add(_) => "+",
+        BinOp::Sub(_) => "-",
+        BinOp::Mul(_) => "*",
+        BinOp::Div(_) => "/",
+        BinOp::Rem(_) => "%",
+        BinOp::And(_) => "&&",
+        BinOp::Or(_) => "||",
+        BinOp::BitXor(_) => "^",
+        BinOp::BitAnd(_) => "&",
+        BinOp::BitOr(_) => "|",
+        BinOp::Shl(_) => "<<",
+        BinOp::Shr(_) => ">>",
+        BinOp::Eq(_) => "==",
+        BinOp::Lt(_) => "<",
+        BinOp::Le(_) => "<=",
+        BinOp::Ne(_) => "!=",
+        BinOp::Ge(_) => ">=",
+        BinOp::Gt(_) => ">",
+        BinOp::AddAssign(_) => "+=",
+        BinOp::SubAssign(_) => "-=",
+        BinOp::MulAssign(_) => "*=",
+        BinOp::DivAssign(_) => "/=",
+        BinOp::RemAssign(_) => "%=",
+        BinOp::BitXorAssign(_) => "^=",
+        BinOp::BitAndAssign(_) => "&=",
+        BinOp::BitOrAssign(_) => "|=",
+        BinOp::ShlAssign(_) => "<<=",
+        BinOp::ShrAssign(_) => ">>=",
+        _ => "?",
+    }
+}
+
+/// Get the target type of a `get_component::<T>()` call, looking through
+/// `unwrap`/`expect` and references
+pub fn component_type_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::MethodCall(call) if call.method == "get_component" => {
+            let turbofish = call.turbofish.as_ref()?;
+            match turbofish.args.first()? {
+                syn::GenericArgument::Type(Type::Path(type_path)) => {
+                    type_path.path.segments.last().map(|s| s.ident.to_string())
+                }
+                _ => None,
+            }
+        }
+        Expr::MethodCall(call) if matches!(call.method.to_string().as_str(), "unwrap" | "expect") => {
+            component_type_name(&call.receiver)
+        }
+        Expr::Reference(reference) => component_type_name(&reference.expr),
+        Expr::Paren(paren) => component_type_name(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Locals of a method body bound to another behavior by `get_component::<T>()`,
+/// for the visitors that follow values of other behaviors through a body
+#[derive(Debug, Clone, Default)]
+pub struct BehaviorLocals {
+    /// Local names mapped to the behavior they hold
+    locals: HashMap<String, String>,
+}
+
+impl BehaviorLocals {
+    /// Remember a binding, including `Some(x)` in `if let`, if its initializer
+    /// looks up a behavior accepted by `is_behavior`
+    pub fn bind(&mut self, pat: &Pat, init: &Expr, is_behavior: impl Fn(&str) -> bool) {
+        let pat = match pat {
+            Pat::TupleStruct(tuple) if tuple.path.is_ident("Some") && tuple.elems.len() == 1 => &tuple.elems[0],
+            Pat::Type(pat_type) => &*pat_type.pat,
+            other => other,
+        };
+        if let (Pat::Ident(pat_ident), Some(type_name)) = (pat, component_type_name(init)) {
+            if is_behavior(&type_name) {
+                self.locals.insert(pat_ident.ident.to_string(), type_name);
+            }
+        }
+    }
+
+    /// Get the behavior local an expression names
+    pub fn local(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Path(path) => path.path.get_ident()
+                .map(|ident| ident.to_string())
+                .filter(|name| self.locals.contains_key(name)),
+            Expr::Paren(paren) => self.local(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// Get the behavior a local holds
+    pub fn behavior(&self, local: &str) -> Option<&str> {
+        self.locals.get(local).map(String::as_str)
+    }
+}
+
+/// Resolve an `Enum::Variant` or bare `Variant` pattern against known fieldless enums
+pub fn enum_variant_pattern<'a>(pat: &Pat, enums: &'a HashMap<String, IntEnum>) -> Option<(&'a IntEnum, String)> {
+    match pat {
+        Pat::Paren(paren) => enum_variant_pattern(&paren.pat, enums),
+        Pat::Path(path) => {
+            let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            match segments.as_slice() {
+                [.., enum_name, variant] => enums.get(enum_name)
+                    .filter(|e| e.has_variant(variant))
+                    .map(|e| (e, variant.clone())),
+                [variant] => unique_enum_with_variant(variant, enums).map(|e| (e, variant.clone())),
+                [] => None,
+            }
+        }
+        // A bare variant brought into scope with `use Enum::*`
+        Pat::Ident(pat_ident) if pat_ident.subpat.is_none() && pat_ident.by_ref.is_none() => {
+            let variant = pat_ident.ident.to_string();
+            unique_enum_with_variant(&variant, enums).map(|e| (e, variant))
+        }
+        _ => None,
+    }
+}
+
+/// The only known enum declaring a variant
+fn unique_enum_with_variant<'a>(variant: &str, enums: &'a HashMap<String, IntEnum>) -> Option<&'a IntEnum> {
+    let mut candidates = enums.values().filter(|e| e.has_variant(variant));
+    let found = candidates.next()?;
+    candidates.next().is_none().then_some(found)
+}
+
+/// Copy of an `if`/`else` with the value of every branch passed to `sink`,
+/// turning a value-producing `if` into one that assigns or returns the value
+fn sink_branch_values(expr_if: &syn::ExprIf, sink: &dyn Fn(Expr) -> Expr) -> syn::ExprIf {
+    let mut expr_if = expr_if.clone();
+    sink_block_value(&mut expr_if.then_branch, sink);
+    if let Some((_, else_branch)) = &mut expr_if.else_branch {
+        match &mut **else_branch {
+            Expr::If(nested) => *nested = sink_branch_values(nested, sink),
+            Expr::Block(expr_block) => sink_block_value(&mut expr_block.block, sink),
+            _ => {}
+        }
+    }
+    expr_if
+}
+
+/// Replace the tail expression of a block with a statement sinking its value
+fn sink_block_value(block: &mut Block, sink: &dyn Fn(Expr) -> Expr) {
+    let Some(Stmt::Expr(value, None)) = block.stmts.last_mut() else {
+        return;
+    };
+    match value {
+        Expr::If(nested) if nested.else_branch.is_some() => *nested = sink_branch_values(nested, sink),
+        Expr::Block(expr_block) => sink_block_value(&mut expr_block.block, sink),
+        // Branches leaving the method or loop, or an `if` with no value, keep their tail
+        Expr::Return(_) | Expr::Break(_) | Expr::Continue(_) | Expr::If(_) => {}
+        _ => {
+            let value = std::mem::replace(value, Expr::Verbatim(Default::default()));
+            if let Some(tail) = block.stmts.last_mut() {
+                *tail = Stmt::Expr(sink(value), Some(Default::default()));
+            }
+        }
+    }
+}
+
+/// The loops nested in the body of a `loop` while walking it, to tell the
+/// `break`s leaving the `loop` from those leaving a nested loop
+struct LoopNesting {
+    label: Option<String>,
+    inner_labels: Vec<Option<String>>,
+}
+
+impl LoopNesting {
+    fn new(expr_loop: &syn::ExprLoop) -> Self {
+        Self {
+            label: expr_loop.label.as_ref().map(|label| label.name.ident.to_string()),
+            inner_labels: Vec::new(),
+        }
+    }
+
+    /// The label of a loop expression, `None` for anything else
+    fn nested_loop(expr: &Expr) -> Option<Option<String>> {
+        let label = match expr {
+            Expr::While(expr_while) => &expr_while.label,
+            Expr::Loop(expr_loop) => &expr_loop.label,
+            Expr::ForLoop(for_loop) => &for_loop.label,
+            _ => return None,
+        };
+        Some(label.as_ref().map(|label| label.name.ident.to_string()))
+    }
+
+    /// Whether a `break` ends the `loop` itself
+    fn breaks_loop(&self, brk: &syn::ExprBreak) -> bool {
+        match &brk.label {
+            Some(label) => self.label.as_deref() == Some(label.ident.to_string().as_str()),
+            None => self.inner_labels.is_empty(),
+        }
+    }
+
+    /// Whether a `break` leaves the `loop`, ending it or an enclosing loop
+    fn leaves_loop(&self, brk: &syn::ExprBreak) -> bool {
+        match &brk.label {
+            Some(label) => {
+                let label = label.ident.to_string();
+                !self.inner_labels.iter().any(|inner| inner.as_deref() == Some(label.as_str()))
+            }
+            None => self.inner_labels.is_empty(),
+        }
+    }
+}
+
+/// The ways out of a `loop` and the values its `break`s give
+struct LoopExits<'a> {
+    nesting: LoopNesting,
+    values: Vec<&'a Expr>,
+    exits: bool,
+}
+
+impl<'a> syn::visit::Visit<'a> for LoopExits<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        match expr {
+            Expr::Break(brk) => {
+                if self.nesting.breaks_loop(brk) {
+                    self.values.extend(brk.expr.as_deref());
+                }
+                self.exits |= self.nesting.leaves_loop(brk);
+                syn::visit::visit_expr(self, expr);
+            }
+            Expr::Return(_) | Expr::Try(_) => {
+                self.exits = true;
+                syn::visit::visit_expr(self, expr);
+            }
+            Expr::Closure(_) => {}
+            _ => match LoopNesting::nested_loop(expr) {
+                Some(label) => {
+                    self.nesting.inner_labels.push(label);
+                    syn::visit::visit_expr(self, expr);
+                    self.nesting.inner_labels.pop();
+                }
+                None => syn::visit::visit_expr(self, expr),
+            },
+        }
+    }
+}
+
+/// Counters of the `for` loops over ranges in a block, mapped to the C# type
+/// of their range: the type of a suffixed bound, else `int`
+fn range_counters(block: &Block) -> HashMap<String, String> {
+    struct RangeCounters(HashMap<String, String>);
+
+    impl<'ast> Visit<'ast> for RangeCounters {
+        fn visit_expr_for_loop(&mut self, for_loop: &'ast syn::ExprForLoop) {
+            if let (Pat::Ident(pat_ident), Expr::Range(range)) = (&*for_loop.pat, &*for_loop.expr) {
+                let suffix = [&range.start, &range.end].into_iter().flatten().find_map(|bound| match &**bound {
+                    Expr::Lit(syn::ExprLit { lit: Lit::Int(int), .. }) if !int.suffix().is_empty() => IntegerType::from_suffix(int.suffix()),
+                    _ => None,
+                });
+                let csharp_type = suffix.unwrap_or(IntegerType::I32).csharp;
+                self.0.insert(pat_ident.ident.to_string(), csharp_type.to_string());
+            }
+            syn::visit::visit_expr_for_loop(self, for_loop);
+        }
+    }
+
+    let mut counters = RangeCounters(HashMap::new());
+    counters.visit_block(block);
+    counters.0
+}
+
+fn loop_exits(expr_loop: &syn::ExprLoop) -> LoopExits<'_> {
+    let mut exits = LoopExits { nesting: LoopNesting::new(expr_loop), values: Vec::new(), exits: false };
+    exits.visit_block(&expr_loop.body);
+    exits
+}
+
+/// Replace every `break value` ending a `loop` with the statements `sink`
+/// makes of the value
+fn sink_break_values(expr_loop: &syn::ExprLoop, sink: &dyn Fn(Expr) -> Vec<Stmt>) -> syn::ExprLoop {
+    struct BreakSink<'a> {
+        nesting: LoopNesting,
+        sink: &'a dyn Fn(Expr) -> Vec<Stmt>,
+    }
+
+    impl BreakSink<'_> {
+        fn break_value(&self, expr: &Expr) -> Option<Expr> {
+            match expr {
+                Expr::Break(brk) if self.nesting.breaks_loop(brk) => brk.expr.as_deref().cloned(),
+                _ => None,
+            }
+        }
+    }
+
+    impl VisitMut for BreakSink<'_> {
+        fn visit_block_mut(&mut self, block: &mut Block) {
+            block.stmts = std::mem::take(&mut block.stmts).into_iter()
+                .flat_map(|stmt| match &stmt {
+                    Stmt::Expr(expr, _) => match self.break_value(expr) {
+                        Some(value) => (self.sink)(value),
+                        None => vec![stmt],
+                    },
+                    _ => vec![stmt],
+                })
+                .collect();
+            syn::visit_mut::visit_block_mut(self, block);
+        }
+
+        fn visit_expr_mut(&mut self, expr: &mut Expr) {
+            if let Some(value) = self.break_value(expr) {
+                *expr = syn::parse_quote!({});
+                if let Expr::Block(expr_block) = expr {
+                    expr_block.block.stmts = (self.sink)(value);
+                }
+                return;
+            }
+            match expr {
+                Expr::Closure(_) => {}
+                _ => match LoopNesting::nested_loop(expr) {
+                    Some(label) => {
+                        self.nesting.inner_labels.push(label);
+                        syn::visit_mut::visit_expr_mut(self, expr);
+                        self.nesting.inner_labels.pop();
+                    }
+                    None => syn::visit_mut::visit_expr_mut(self, expr),
+                },
+            }
+        }
+    }
+
+    let mut expr_loop = expr_loop.clone();
+    let mut break_sink = BreakSink { nesting: LoopNesting::new(&expr_loop), sink };
+    break_sink.visit_block_mut(&mut expr_loop.body);
+    expr_loop
+}
+
+/// Collect the value of every branch of an `if`/`else`, nested ones included
+fn collect_branch_values<'a>(expr_if: &'a syn::ExprIf, values: &mut Vec<&'a Expr>) {
+    fn block_values<'a>(block: &'a Block, values: &mut Vec<&'a Expr>) {
+        match block.stmts.last() {
+            Some(Stmt::Expr(Expr::If(nested), None)) => collect_branch_values(nested, values),
+            Some(Stmt::Expr(Expr::Block(expr_block), None)) => block_values(&expr_block.block, values),
+            Some(Stmt::Expr(value, None)) => values.push(value),
+            _ => {}
+        }
+    }
+
+    block_values(&expr_if.then_branch, values);
+    match expr_if.else_branch.as_ref().map(|(_, else_branch)| &**else_branch) {
+        Some(Expr::If(nested)) => collect_branch_values(nested, values),
+        Some(Expr::Block(expr_block)) => block_values(&expr_block.block, values),
+        _ => {}
+    }
+}
+
+/// The pattern matched against each of `width` values: the elements of a
+/// tuple pattern, or a wildcard or binding standing for all of them
+fn tuple_pattern_elements(pat: &Pat, width: usize) -> Option<Vec<&Pat>> {
+    match pat {
+        Pat::Tuple(tuple) if width > 1 => (tuple.elems.len() == width).then(|| tuple.elems.iter().collect()),
+        Pat::Paren(paren) => tuple_pattern_elements(&paren.pat, width),
+        _ if width == 1 => Some(vec![pat]),
+        Pat::Wild(_) => Some(vec![pat; width]),
+        _ => None,
+    }
+}
+
+/// Whether an identifier pattern binds a value rather than naming a constant or `None`
+fn is_binding_name(name: &str) -> bool {
+    name != "None" && !name.starts_with(|c: char| c.is_uppercase())
+}
+
+/// The identifier a simple `let` pattern binds
+fn binding_ident(pat: &Pat) -> Option<&syn::Ident> {
+    match pat {
+        Pat::Ident(pat_ident) => Some(&pat_ident.ident),
+        Pat::Type(pat_type) => binding_ident(&pat_type.pat),
+        _ => None,
+    }
+}
+
+/// Whether an arm body always leaves the C# case section through `return` or `continue`
+fn arm_diverges(body: &Expr) -> bool {
+    match body {
+        Expr::Return(_) | Expr::Continue(_) => true,
+        Expr::Block(expr_block) => matches!(
+            expr_block.block.stmts.last(),
+            Some(Stmt::Expr(Expr::Return(_) | Expr::Continue(_), _))
+        ),
+        _ => false,
+    }
+}
+
+/// Whether an expression contains a `break` of an enclosing loop, ignoring nested loops and closures
+fn contains_loop_break(expr: &Expr) -> bool {
+    struct BreakFinder {
+        found: bool,
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for BreakFinder {
+        fn visit_expr(&mut self, expr: &'ast Expr) {
+            match expr {
+                Expr::Break(_) => self.found = true,
+                Expr::While(_) | Expr::Loop(_) | Expr::ForLoop(_) | Expr::Closure(_) => {}
+                _ => syn::visit::visit_expr(self, expr),
+            }
+        }
+    }
+
+    let mut finder = BreakFinder { found: false };
+    syn::visit::Visit::visit_expr(&mut finder, expr);
+    finder.found
+}
+
+/// Get the identifier bound by a simple pattern
+fn pattern_ident(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+        Pat::Type(pat_type) => pattern_ident(&pat_type.pat),
+        _ => None,
+    }
+}
+
+/// Strip `&` from a pattern such as the `&x` parameter of a closure
+fn strip_pattern_reference(pat: &Pat) -> &Pat {
+    match pat {
+        Pat::Reference(reference) => strip_pattern_reference(&reference.pat),
+        pat => pat,
+    }
+}
+
+/// Get the plain operator of a compound assignment operator
+fn compound_assign_op(op: &BinOp) -> Option<&'static str> {
+    match op {
+        BinOp::AddAssign(_) => Some("+"),
+        BinOp::SubAssign(_) => Some("-"),
+        BinOp::MulAssign(_) => Some("*"),
+        BinOp::DivAssign(_) => Some("/"),
+        BinOp::RemAssign(_) => Some("%"),
+        _ => None,
+    }
+}
+
+/// Get the `matches!` invocation an expression consists of
+fn matches_macro(expr: &Expr) -> Option<&syn::ExprMacro> {
+    match expr {
+        Expr::Macro(expr_macro) if expr_macro.mac.path.segments.last().is_some_and(|s| s.ident == "matches") => {
+            Some(expr_macro)
+        }
+        _ => None,
+    }
+}
+
+/// Check if an expression reads a local or field without side effects
+fn is_place_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(_) => true,
+        Expr::Field(field) => is_place_expr(&field.base),
+        Expr::Paren(paren) => is_place_expr(&paren.expr),
+        Expr::Reference(reference) => is_place_expr(&reference.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Deref(_)) => is_place_expr(&unary.expr),
+        _ => false,
+    }
+}
+
+/// A Rust integer type as seen by overflow-aware lowering
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct IntegerType {
+    csharp: &'static str,
+    signed: bool,
+}
+
+impl IntegerType {
+    const I32: Self = Self { csharp: "int", signed: true };
+
+    fn from_suffix(suffix: &str) -> Option<Self> {
+        let (csharp, signed) = match suffix {
+            "i8" => ("sbyte", true),
+            "i16" => ("short", true),
+            "i32" => ("int", true),
+            "i64" => ("long", true),
+            "u8" => ("byte", false),
+            "u16" => ("ushort", false),
+            "u32" => ("uint", false),
+            "u64" => ("ulong", false),
+            _ => return None,
+        };
+        Some(Self { csharp, signed })
+    }
+
+    fn from_rust_type(rust_type: &RustType) -> Option<Self> {
+        let suffix = match rust_type {
+            RustType::I8 => "i8",
+            RustType::I16 => "i16",
+            RustType::I32 => "i32",
+            RustType::I64 => "i64",
+            RustType::U8 => "u8",
+            RustType::U16 => "u16",
+            RustType::U32 => "u32",
+            RustType::U64 => "u64",
+            _ => return None,
+        };
+        Self::from_suffix(suffix)
+    }
+
+    fn min_value(&self) -> String {
+        if self.signed {
+            format!("{}.MinValue", self.csharp)
+        } else {
+            "0".to_string()
+        }
+    }
+
+    fn max_value(&self) -> String {
+        format!("{}.MaxValue", self.csharp)
+    }
+
+    /// C# promotes types narrower than `int` to `int`; cast the result back
+    fn narrow(&self, expr: String) -> String {
+        match self.csharp {
+            "sbyte" | "short" | "byte" | "ushort" => format!("({})({})", self.csharp, expr),
+            _ => expr,
+        }
+    }
+}
+
+/// Where an addition or subtraction can leave the range of its type
+enum OverflowBound {
+    /// The operation cannot overflow
+    Never,
+    /// One `(test, limit)` bound applies
+    Single(String, String),
+    /// The bound depends on the sign of the right-hand side
+    BySign { positive: (String, String), negative: (String, String) },
+}
+
+/// Get the `checked_add`/`checked_sub` call an expression consists of
+fn checked_arithmetic(expr: &Expr) -> Option<&syn::ExprMethodCall> {
+    match expr {
+        Expr::MethodCall(call) if (call.method == "checked_add" || call.method == "checked_sub") && call.args.len() == 1 => {
+            Some(call)
+        }
+        Expr::Paren(paren) => checked_arithmetic(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Check if an operand can be evaluated repeatedly without side effects
+fn is_pure_operand(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Cast(cast) => is_pure_operand(&cast.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => is_pure_operand(&unary.expr),
+        expr => is_place_expr(expr),
+    }
+}
+
+/// Sign of an integer literal operand: `Some(1)`, `Some(0)`, `Some(-1)`, or `None` if not a literal
+fn literal_sign(expr: &Expr) -> Option<i8> {
+    match expr {
+        Expr::Lit(syn::ExprLit { lit: Lit::Int(int), .. }) => {
+            Some(if int.base10_digits().trim_start_matches('0').is_empty() { 0 } else { 1 })
+        }
+        Expr::Paren(paren) => literal_sign(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => literal_sign(&unary.expr).map(|sign| -sign),
+        _ => None,
+    }
+}
+
+/// Negate a single `a < b`/`a > b` comparison
+fn negate_comparison(comparison: &str) -> String {
+    if comparison.contains(" > ") {
+        comparison.replacen(" > ", " <= ", 1)
+    } else {
+        comparison.replacen(" < ", " >= ", 1)
+    }
+}
+
+/// Name of the flag recording that an unassigned reference was already logged
+pub fn unassigned_flag_name(csharp_field: &str) -> String {
+    format!("{}UnassignedLogged", csharp_field)
+}
+
+/// Whether values of a type are C# references that can be null
+fn is_reference_type(rust_type: &RustType) -> bool {
+    matches!(
+        rust_type,
+        RustType::GameObject | RustType::Transform | RustType::VRCPlayerApi | RustType::UdonRef(_) | RustType::Custom(_)
+    )
+}
+
+/// Collects the public `Option` reference fields a statement unwraps, without
+/// looking into nested blocks, whose statements get guards of their own
+struct UnwrapFinder<'a> {
+    lowerer: &'a BodyLowerer,
+    fields: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for UnwrapFinder<'_> {
+    fn visit_block(&mut self, _block: &'ast Block) {}
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if call.method == "unwrap" || call.method == "expect" {
+            if let Some(field) = self.lowerer.option_ref_field(&call.receiver) {
+                let is_public = self.lowerer.context.option_ref_fields.get(&field).copied().unwrap_or(false);
+                if is_public && !self.fields.contains(&field) {
+                    self.fields.push(field);
+                }
+            }
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Indentation for a nesting depth
+fn indent(depth: usize) -> String {
+    "    ".repeat(depth)
+}
+
+/// Whether an expression is `Vec::new()`, `VecDeque::new()` or their `default()`
+fn is_empty_vec(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    let Expr::Path(func) = &*call.func else {
+        return false;
+    };
+    let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    call.args.is_empty()
+        && matches!(segments.as_slice(), [owner, constructor]
+            if matches!(owner.as_str(), "Vec" | "VecDeque") && matches!(constructor.as_str(), "new" | "default"))
+}
+
+/// Check if an expression is the `self` receiver
+fn is_self(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(p) if p.path.is_ident("self"))
+}
+
+/// Bindings a variant pattern introduces, each with the payload field it
+/// reads and that field's type
+fn payload_bindings(pat: &Pat, variant: &PayloadVariant) -> Result<Vec<(String, String, RustType)>, String> {
+    let fields: Vec<(String, &Pat)> = match pat {
+        Pat::Paren(paren) => return payload_bindings(&paren.pat, variant),
+        Pat::Struct(pat_struct) => pat_struct.fields.iter()
+            .map(|field| (payload_field_key(&field.member), &*field.pat))
+            .collect(),
+        Pat::TupleStruct(pat_tuple) => {
+            let rest = pat_tuple.elems.iter().position(|elem| matches!(elem, Pat::Rest(_)));
+            if rest.is_some_and(|rest| rest + 1 != pat_tuple.elems.len()) {
+                return Err("`..` is only supported at the end of a tuple variant pattern".to_string());
+            }
+            pat_tuple.elems.iter()
+                .take(rest.unwrap_or(pat_tuple.elems.len()))
+                .enumerate()
+                .map(|(position, elem)| (position.to_string(), elem))
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    let mut bindings = Vec::new();
+    for (field, field_pat) in fields {
+        let field_type = variant.field_type(&field)
+            .ok_or_else(|| format!("{} has no field `{}`", variant.name, field))?
+            .clone();
+        match field_pat {
+            Pat::Wild(_) => {}
+            Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => {
+                bindings.push((pat_ident.ident.to_string(), field, field_type));
+            }
+            _ => return Err("only bindings and `_` are supported inside the fields of a variant pattern".to_string()),
+        }
+    }
+    Ok(bindings)
+}
+
+/// Record key of a payload field: its name, or its position in a tuple variant
+fn payload_field_key(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+/// Get the name of a named struct member
+fn member_name(member: &Member) -> Option<String> {
+    match member {
+        Member::Named(ident) => Some(ident.to_string()),
+        Member::Unnamed(_) => None,
+    }
+}
+
+/// Check if an identifier follows SCREAMING_SNAKE_CASE constant naming
+fn is_constant_name(name: &str) -> bool {
+    name.chars().any(|c| c.is_alphabetic())
+        && name.chars().all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Whether a macro is `assert!`, `assert_eq!`, `assert_ne!` or one of their `debug_` forms
+fn is_assertion(mac: &syn::Macro) -> bool {
+    mac.path.segments.last().is_some_and(|s| {
+        matches!(
+            s.ident.to_string().as_str(),
+            "assert" | "assert_eq" | "assert_ne" | "debug_assert" | "debug_assert_eq" | "debug_assert_ne"
+        )
+    })
+}
+
+/// C# name of a Rust local or binding; locals named after a C# keyword
+/// (such as `event` or `object`) are escaped with `@`
+fn local_name(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "base", "bool", "byte", "case", "catch", "char", "checked", "class",
+        "decimal", "default", "delegate", "do", "double", "event", "explicit", "extern", "finally",
+        "fixed", "float", "foreach", "goto", "implicit", "int", "interface", "internal", "is",
+        "lock", "long", "namespace", "new", "null", "object", "operator", "out", "override",
+        "params", "private", "protected", "public", "readonly", "sbyte", "sealed", "short",
+        "sizeof", "stackalloc", "string", "switch", "this", "throw", "try", "typeof", "uint",
+        "ulong", "unchecked", "ushort", "using", "virtual", "void", "volatile",
+    ];
+    let name = to_camel_case(name);
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("@{}", name)
+    } else {
+        name
+    }
+}
+
+/// Whether an expression is `Vec::new()`, `Vec::default()` or `Vec::with_capacity(n)`,
+/// or the same constructor on `VecDeque`
+fn is_empty_vec_constructor(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    let Expr::Path(path) = &*call.func else {
+        return false;
+    };
+    let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [.., "Vec" | "VecDeque", "new" | "default"] => call.args.is_empty(),
+        [.., "Vec" | "VecDeque", "with_capacity"] => call.args.len() == 1,
+        _ => false,
+    }
+}
+
+/// Translate a Rust format spec to the format suffix of a C# format item:
+/// `{:.N}` becomes `{i:FN}`, `{:0N}` becomes `{i:DN}` and `{:?}` is dropped.
+/// Other specs have no C# equivalent.
+fn csharp_format_spec(spec: &str) -> Option<String> {
+    let is_count = |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+    match spec {
+        "" | "?" => Some(String::new()),
+        _ if spec.starts_with('.') && is_count(&spec[1..]) => Some(format!(":F{}", &spec[1..])),
+        _ if spec.starts_with('0') && is_count(&spec[1..]) => Some(format!(":D{}", &spec[1..])),
+        _ => None,
+    }
+}
+
+/// A path expression naming a single identifier
+fn ident_expr(ident: syn::Ident) -> Expr {
+    Expr::Path(syn::ExprPath { attrs: Vec::new(), qself: None, path: ident.into() })
+}
+
+/// Quote a string as a C# string literal
+fn csharp_string_literal(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in value.chars() {
+        push_csharp_escaped(&mut literal, c, '"');
+    }
+    literal.push('"');
+    literal
+}
+
+/// Quote a character as a C# char literal
+fn csharp_char_literal(value: char) -> String {
+    let mut literal = String::from("'");
+    push_csharp_escaped(&mut literal, value, '\'');
+    literal.push('\'');
+    literal
+}
+
+/// Append a character with C# escaping. Control and non-ASCII characters
+/// become `\uXXXX` escapes, as UTF-16 surrogate pairs outside the BMP.
+fn push_csharp_escaped(literal: &mut String, c: char, quote: char) {
+    match c {
+        '\\' => literal.push_str("\\\\"),
+        '\n' => literal.push_str("\\n"),
+        '\r' => literal.push_str("\\r"),
+        '\t' => literal.push_str("\\t"),
+        '\0' => literal.push_str("\\0"),
+        c if c == quote => {
+            literal.push('\\');
+            literal.push(c);
+        }
+        ' '..='~' => literal.push(c),
+        c => {
+            let mut units = [0u16; 2];
+            for unit in c.encode_utf16(&mut units) {
+                literal.push_str(&format!("\\u{:04X}", unit));
+            }
+        }
+    }
+}
+
+fn unsupported(expr: &Expr, reason: &str) -> LoweringError {
+    LoweringError::UnsupportedExpression {
+        expression: quote::quote!(#expr).to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn unsupported_field(field: &syn::ExprField, reason: &str) -> LoweringError {
+    LoweringError::UnsupportedExpression {
+        expression: quote::quote!(#field).to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn unsupported_macro(expr_macro: &syn::ExprMacro, reason: &str) -> LoweringError {
+    LoweringError::UnsupportedExpression {
+        expression: quote::quote!(#expr_macro).to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn scoring_context() -> LoweringContext {
+        let mut embedded = EmbeddedStruct::new("scoring_system".to_string(), "ScoringSystem".to_string());
+        embedded.field_names = vec!["total_score".to_string(), "multiplier".to_string()];
+        embedded.method_names = vec!["add_score".to_string()];
+
+        let mut context = LoweringContext::new();
+        context.add_embedded_struct(embedded);
+        context
+    }
+
+    fn game_manager_context() -> LoweringContext {
+        let mut game_manager = UdonBehaviourStruct::new("GameManager".to_string());
+        let mut current_round = crate::multi_behavior::StructField::new("current_round".to_string(), RustType::I32);
+        current_round.visibility = crate::multi_behavior::Visibility::Public;
+        game_manager.add_field(current_round);
+        game_manager.add_field(crate::multi_behavior::StructField::new("secret_seed".to_string(), RustType::I32));
+        let mut time_remaining = crate::multi_behavior::StructField::new("time_remaining".to_string(), RustType::F32);
+        time_remaining.add_attribute(crate::multi_behavior::FieldAttribute::UdonSync);
+        game_manager.add_field(time_remaining);
+        game_manager.add_method(crate::multi_behavior::StructMethod::new("start_round".to_string(), RustType::Unit));
+
+        let mut context = LoweringContext::new();
+        context.add_behavior(&game_manager);
+        context
+    }
+
+    #[test]
+    fn test_lower_cross_behavior_public_field_read() {
+        let lowerer = BodyLowerer::new(game_manager_context());
+        let body = lowerer.lower_method_body(
+            "{ if let Some(gm) = gm_obj.get_component::<GameManager>() { let round = gm.current_round; gm.start_round(); } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        var gm = (UdonBehaviour)gmObj.GetComponent(typeof(UdonBehaviour));");
+        assert_eq!(lines[1], "        if (gm != null)");
+        assert_eq!(lines[3], "            var round = (int)gm.GetProgramVariable(\"currentRound\");");
+        assert_eq!(lines[4], "            gm.SendCustomEvent(\"StartRound\");");
+    }
+
+    #[test]
+    fn test_lower_cross_behavior_private_field_read_fails() {
+        let lowerer = BodyLowerer::new(game_manager_context());
+        let result = lowerer.lower_method_body(
+            "{ let gm = gm_obj.get_component::<GameManager>().unwrap(); let seed = gm.secret_seed; }",
+            false,
+        );
+        match result {
+            Err(LoweringError::UnsupportedExpression { reason, .. }) => assert!(reason.contains("private")),
+            other => panic!("expected private field error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lower_cross_behavior_synced_reads_use_get_program_variable() {
+        let mut context = game_manager_context();
+        context.add_udon_ref_field("game_manager".to_string(), "GameManager".to_string());
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body(
+            "{ let gm = gm_obj.get_component::<GameManager>().unwrap(); let left = gm.time_remaining; \
+               let round = self.game_manager.get(\"current_round\"); let synced = self.game_manager.get(\"time_remaining\"); }",
+            false,
+        ).unwrap();
+        assert!(body.contains("var left = (float)gm.GetProgramVariable(\"timeRemaining\");"), "{}", body);
+        assert!(body.contains("var round = (int)gameManager.GetProgramVariable(\"currentRound\");"), "{}", body);
+        assert!(body.contains("var synced = (float)gameManager.GetProgramVariable(\"timeRemaining\");"), "{}", body);
+
+        // Synced fields stay owned by the other behavior
+        let result = BodyLowerer::new(game_manager_context()).lower_method_body(
+            "{ let gm = gm_obj.get_component::<GameManager>().unwrap(); gm.time_remaining = 0.0; }",
+            false,
+        );
+        match result {
+            Err(LoweringError::UnsupportedExpression { reason, .. }) => assert!(reason.contains("cannot be set"), "{}", reason),
+            other => panic!("expected write error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lower_or_keeps_side_effecting_operand_short_circuited() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ if self.is_ready() || self.spawn_enemy() { self.count += 1; } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        // The side-effecting call stays the right operand of `||` and is not hoisted ahead of the condition
+        assert_eq!(lines[0], "        if (IsReady() || SpawnEnemy())");
+        assert_eq!(body.matches("SpawnEnemy()").count(), 1);
+    }
+
+    #[test]
+    fn test_lower_logical_chains_preserve_operators() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(can_start && self.should_show() || self.force_show());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "(canStart && ShouldShow()) || ForceShow()");
+
+        // Non-short-circuit `|` on bools must stay non-short-circuit
+        let expr: Expr = parse_quote!(self.try_a() | self.try_b());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "TryA() | TryB()");
+    }
+
+    #[test]
+    fn test_lower_data_list_add_get_round_trip() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ let mut spawns = DataList::new(); spawns.add(DataToken::from(5)); let first = spawns.get(0).as_int(); let total = spawns.count(); spawns.remove_at(0); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        var spawns = new DataList();");
+        assert_eq!(lines[1], "        spawns.Add(new DataToken(5));");
+        assert_eq!(lines[2], "        var first = spawns[0].Int;");
+        assert_eq!(lines[3], "        var total = spawns.Count;");
+        assert_eq!(lines[4], "        spawns.RemoveAt(0);");
+    }
+
+    #[test]
+    fn test_lower_data_token_accessors_only_on_data_tokens() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ let spawns = DataList::new(); let token = spawns.get(0); let first = token.as_int(); let scores = DataDictionary::new(); let best = scores.get(\"best\").as_float(); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[2], "        var first = token.Int;");
+        assert_eq!(lines[4], "        var best = scores[\"best\"].Float;");
+        let expr: Expr = parse_quote!(title.as_string());
+        assert!(lowerer.lower_expr(&expr).is_err());
+    }
+
+    #[test]
+    fn test_lower_vrc_json_calls_use_out_parameters() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(VRCJson::try_serialize_to_json(&state, JsonExportType::Minify, &mut json));
+        assert_eq!(
+            lowerer.lower_expr(&expr).unwrap(),
+            "VRCJson.TrySerializeToJson(state, JsonExportType.Minify, out json)"
+        );
+
+        let expr: Expr = parse_quote!(vrchat::VRCJson::try_deserialize_from_json(&text, &mut parsed));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "VRCJson.TryDeserializeFromJson(text, out parsed)");
+
+        let expr: Expr = parse_quote!(VRCJson::try_deserialize_from_json(&text, &parsed));
+        assert!(lowerer.lower_expr(&expr).is_err());
+    }
+
+    #[test]
+    fn test_lower_object_ownership_calls() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(Networking::set_owner(&player, &self.door));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "Networking.SetOwner(player, door)");
+
+        let expr: Expr = parse_quote!(vrchat::Networking::is_owner(&Networking::local_player(), &self.door));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "Networking.IsOwner(Networking.LocalPlayer, door)");
+
+        let expr: Expr = parse_quote!(networking::get_owner(&self.door));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "Networking.GetOwner(door)");
+
+        let expr: Expr = parse_quote!(Networking::is_master());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "Networking.IsMaster");
+    }
+
+    #[test]
+    fn test_lower_self_field_assignment() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body("{ self.round_timer += 1.5; }", false).unwrap();
+        assert_eq!(body, "        roundTimer += 1.5f;");
+    }
+
+    #[test]
+    fn test_lower_known_binding_calls() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(networking::is_master());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "Networking.IsMaster");
+
+        let expr: Expr = parse_quote!(unity::GameObject::find("UIController"));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "GameObject.Find(\"UIController\")");
+
+        let expr: Expr = parse_quote!(Time::fixed_delta_time());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "Time.fixedDeltaTime");
+
+    }
+
+    #[test]
+    fn test_find_child_is_null_checked_before_reading_its_game_object() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ if let Some(label) = panel.find_child(\"Label\") { label.set_active(true); } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        var labelTransform = panel.transform.Find(\"Label\");");
+        assert_eq!(lines[1], "        var label = labelTransform != null ? labelTransform.gameObject : null;");
+        assert_eq!(lines[2], "        if (label != null)");
+
+        let body = lowerer.lower_method_body("{ let icon = panel.find_child(\"Icon\"); }", false).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        var iconTransform = panel.transform.Find(\"Icon\");");
+        assert_eq!(lines[1], "        var icon = iconTransform != null ? iconTransform.gameObject : null;");
+
+        // Outside a binding the missing child could not be checked before `.gameObject` faults
+        let expr: Expr = parse_quote!(panel.find_child("Label").unwrap());
+        assert!(matches!(lowerer.lower_expr(&expr), Err(LoweringError::UnsupportedExpression { .. })));
+    }
+
+    #[test]
+    fn test_lower_sdk_properties_and_strings() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(player.display_name());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "player.displayName");
+
+        let expr: Expr = parse_quote!(text.set_text(&label));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "text.text = label");
+
+        let expr: Expr = parse_quote!(button.set_interactable(false));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "button.interactable = false");
+
+        let expr: Expr = parse_quote!(gm_obj.send_custom_event("StartRound"));
+        assert_eq!(
+            lowerer.lower_expr(&expr).unwrap(),
+            "((UdonBehaviour)gmObj.GetComponent(typeof(UdonBehaviour))).SendCustomEvent(\"StartRound\")"
+        );
+
+        let expr: Expr = parse_quote!(String::from("x"));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "\"x\"");
+
+        let expr: Expr = parse_quote!(String::new());
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "\"\"");
+    }
+
+    #[test]
+    fn test_unknown_calls_are_unsupported() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(player.frobnicate(1));
+        assert!(lowerer.lower_expr(&expr).is_err());
+
+        let expr: Expr = parse_quote!(Widget::build(1));
+        assert!(lowerer.lower_expr(&expr).is_err());
+
+        let expr: Expr = parse_quote!(format_time(seconds));
+        assert!(lowerer.lower_expr(&expr).is_err());
+    }
+
+    #[test]
+    fn test_lower_delayed_custom_events() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(self.door.send_custom_event_delayed_seconds("CloseDoor", 2.5));
+        assert_eq!(
+            lowerer.lower_expr(&expr).unwrap(),
+            "((UdonBehaviour)door.GetComponent(typeof(UdonBehaviour))).SendCustomEventDelayedSeconds(\"CloseDoor\", 2.5f)"
+        );
+
+        let expr: Expr = parse_quote!(door.send_custom_event_delayed_frames("PlayCreak", 1));
+        assert!(lowerer.lower_expr(&expr).unwrap().ends_with(".SendCustomEventDelayedFrames(\"PlayCreak\", 1)"));
+    }
+
+    #[test]
+    fn test_lower_tag_and_layer_calls() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(other.compare_tag("Player"));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "other.CompareTag(\"Player\")");
+
+        let body = lowerer.lower_method_body(
+            "{ let layer = LayerMask::name_to_layer(\"Pickup\"); self.door.set_layer(layer); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        var layer = LayerMask.NameToLayer(\"Pickup\");");
+        assert_eq!(lines[1], "        door.layer = layer;");
+
+        let expr: Expr = parse_quote!(LayerMask::get_mask(&["Player", "PlayerLocal"]));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "LayerMask.GetMask(\"Player\", \"PlayerLocal\")");
+
+        let expr: Expr = parse_quote!(LayerMask::contains(self.mask, other.layer()));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "((mask & (1 << other.layer)) != 0)");
+    }
+
+    #[test]
+    fn test_float_to_int_cast_truncates_unless_rounded_explicitly() {
+        let lowerer = BodyLowerer::default();
+        let lower = |source: &str| lowerer.lower_expr(&syn::parse_str(source).unwrap()).unwrap();
+
+        assert_eq!(lower("(self.round_timer * 10.0) as i32"), "(int)(roundTimer * 10.0f)");
+        assert_eq!(lower("seconds as i32"), "(int)seconds");
+        assert_eq!(lower("Mathf::round_to_int(self.round_timer * 10.0)"), "Mathf.RoundToInt(roundTimer * 10.0f)");
+        assert_eq!(lower("Mathf::floor_to_int(seconds)"), "Mathf.FloorToInt(seconds)");
+        assert_eq!(lower("unity::Mathf::ceil_to_int(seconds)"), "Mathf.CeilToInt(seconds)");
+    }
+
+    #[test]
+    fn test_lower_log_crate_macros() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ log::warn!(\"Round {} ended early\", self.round); log::info!(\"ready\"); error!(\"lost sync\"); log::debug!(\"tick\"); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        Debug.LogWarning(string.Format(\"Round {0} ended early\", round));");
+        assert_eq!(lines[1], "        Debug.Log(\"ready\");");
+        assert_eq!(lines[2], "        Debug.LogError(\"lost sync\");");
+        assert_eq!(lines[3], "        Debug.Log(\"tick\");");
+    }
+
+    #[test]
+    fn test_log_macros_translate_format_specs() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ let ratio = self.hits as f32 / 10.0; info!(\"accuracy {:.2} in round {:02} ({ratio:?})\", ratio, self.round); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[1], "        Debug.Log(string.Format(\"accuracy {0:F2} in round {1:D2} ({2})\", ratio, round, ratio));");
+
+        // A spec without a C# equivalent is rejected instead of emitting a template that throws
+        match lowerer.lower_method_body("{ warn!(\"{:>4}\", self.round); }", false) {
+            Err(LoweringError::UnsupportedExpression { reason, .. }) => assert!(reason.contains("{:>4}"), "{}", reason),
+            other => panic!("expected an unsupported format spec, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_release_strips_debug_logs() {
+        let mut context = LoweringContext::new();
+        context.set_strip_debug_logs(true);
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body(
+            "{ log::debug!(\"tick {}\", self.round); log::trace!(\"state\"); if self.round > 3 { debug!(\"late\"); } log::warn!(\"slow\"); }",
+            false,
+        ).unwrap();
+        assert!(!body.contains("tick"));
+        assert!(!body.contains("state"));
+        assert!(!body.contains("late"));
+        assert!(body.contains("Debug.LogWarning(\"slow\");"));
+    }
+
+    #[test]
+    fn test_lower_embedded_method_call() {
+        let lowerer = BodyLowerer::new(scoring_context());
+        let expr: Expr = parse_quote!(self.scoring_system.add_score(10));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "ScoringSystemAddScore(10)");
+
+        let expr: Expr = parse_quote!(self.scoring_system.total_score);
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "scoringSystemTotalScore");
+    }
+
+    #[test]
+    fn test_lower_embedded_struct_own_method() {
+        let context = scoring_context().with_self_prefix(Some("scoring_system".to_string()));
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body("{ self.total_score += points * self.multiplier; }", false).unwrap();
+        assert_eq!(body, "        scoringSystemTotalScore += points * scoringSystemMultiplier;");
+    }
+
+    #[test]
+    fn test_lower_if_else_chain() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ if self.state == 0 { self.start_round(); } else if self.state == 1 { return; } else { self.state = 0; } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        if (state == 0)");
+        assert_eq!(lines[2], "            StartRound();");
+        assert_eq!(lines[4], "        else if (state == 1)");
+        assert_eq!(lines[8], "        else");
+    }
+
+    #[test]
+    fn test_lower_matches_or_pattern() {
+        let lowerer = BodyLowerer::default();
+        let lower = |source: &str| lowerer.lower_expr(&syn::parse_str(source).unwrap()).unwrap();
+
+        assert_eq!(
+            lower("matches!(self.current_state, GameState::Playing | GameState::Paused)"),
+            "currentState == GameState.Playing || currentState == GameState.Paused"
+        );
+        assert_eq!(
+            lower("matches!(self.current_state, GameState::Playing | GameState::Paused if self.player_count > 1)"),
+            "(currentState == GameState.Playing || currentState == GameState.Paused) && playerCount > 1"
+        );
+        assert_eq!(lower("matches!(self.round, 1..=3)"), "(round >= 1 && round <= 3)");
+        assert_eq!(
+            lower("self.ready && matches!(self.state, State::A | State::B)"),
+            "ready && (state == State.A || state == State.B)"
+        );
+        assert_eq!(lower("!matches!(self.state, State::Idle)"), "!(state == State.Idle)");
+
+        let body = lowerer.lower_method_body("{ if matches!(self.state, State::A | State::B) { return; } }", false).unwrap();
+        assert!(body.starts_with("        if (state == State.A || state == State.B)"));
+
+        let result = lowerer.lower_expr(&syn::parse_str("matches!(self.next_state(), State::A | State::B)").unwrap());
+        assert!(matches!(result, Err(LoweringError::UnsupportedExpression { .. })));
+    }
+
+    fn counter_lowerer() -> BodyLowerer {
+        let mut counter = UdonBehaviourStruct::new("Counter".to_string());
+        counter.add_field(crate::multi_behavior::StructField::new("player_count".to_string(), RustType::U32));
+        counter.add_field(crate::multi_behavior::StructField::new("score".to_string(), RustType::I32));
+        counter.add_field(crate::multi_behavior::StructField::new("level".to_string(), RustType::U8));
+        BodyLowerer::new(LoweringContext::for_behavior(&counter))
+    }
+
+    #[test]
+    fn test_lower_saturating_sub_clamps_at_zero() {
+        let lowerer = counter_lowerer();
+        let body = lowerer.lower_method_body("{ self.player_count = self.player_count.saturating_sub(1); }", false).unwrap();
+        assert_eq!(body, "        playerCount = (playerCount < 1 ? 0 : playerCount - 1);");
+
+        // Signed values saturate at the bound the right-hand side moves towards
+        let body = lowerer.lower_method_body("{ let next = self.score.saturating_add(delta); }", false).unwrap();
+        assert_eq!(
+            body,
+            "        var next = (delta > 0 ? (score > int.MaxValue - delta ? int.MaxValue : score + delta) : (score < int.MinValue - delta ? int.MinValue : score + delta));"
+        );
+
+        // Wrapping is the default in C#; narrow types are cast back
+        let body = lowerer.lower_method_body("{ self.level = self.level.wrapping_add(1); }", false).unwrap();
+        assert_eq!(body, "        level = (byte)(level + 1);");
+
+        let result = lowerer.lower_method_body("{ let n = self.next().saturating_sub(1); }", false);
+        assert!(matches!(result, Err(LoweringError::UnsupportedExpression { .. })));
+    }
+
+    #[test]
+    fn test_lower_checked_add_guards_overflow() {
+        let lowerer = counter_lowerer();
+        let body = lowerer.lower_method_body(
+            "{ if let Some(total) = self.score.checked_add(points) { self.score = total; } else { self.report_overflow(); } }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        if ((points > 0 ? score <= int.MaxValue - points : score >= int.MinValue - points))",
+            "        {",
+            "            var total = score + points;",
+            "            score = total;",
+            "        }",
+            "        else",
+            "        {",
+            "            ReportOverflow();",
+            "        }",
+        ].join("\n"));
+
+        let body = lowerer.lower_method_body("{ self.player_count = self.player_count.checked_add(1).unwrap_or(0); }", false).unwrap();
+        assert_eq!(body, "        playerCount = (playerCount <= uint.MaxValue - 1 ? playerCount + 1 : 0);");
+
+        let result = lowerer.lower_method_body("{ let total = self.score.checked_add(1); }", false);
+        assert!(matches!(result, Err(LoweringError::UnsupportedExpression { .. })));
+    }
+
+    #[test]
+    fn test_lower_if_let_with_else_branch() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ if let Some(points) = self.bonus { self.add_score(points); } else { self.reset_streak(); } }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        if (bonus != null)",
+            "        {",
+            "            var points = bonus;",
+            "            AddScore(points);",
+            "        }",
+            "        else",
+            "        {",
+            "            ResetStreak();",
+            "        }",
+        ].join("\n"));
+
+        // Nested patterns test every level and bind only in the `Some` arm
+        let body = lowerer.lower_method_body(
+            "{ if let Some(Some(level @ 1..=3)) = self.next_level { self.load(level); } else if let Some(x) = self.pick() { self.load(x); } else { self.finish(); } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        if (nextLevel != null && (nextLevel >= 1 && nextLevel <= 3))");
+        assert_eq!(lines[2], "            var level = nextLevel;");
+        assert_eq!(lines[5], "        else");
+        assert_eq!(lines[7], "            var x = Pick();");
+        assert_eq!(lines[8], "            if (x != null)");
+        assert!(body.contains("                Finish();"));
+
+        let result = lowerer.lower_method_body("{ if let Some(Some(x)) = self.pick() { } }", false);
+        assert!(matches!(result, Err(LoweringError::UnsupportedExpression { .. })));
+    }
+
+    #[test]
+    fn test_tail_expression_becomes_return() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body("{ let total = self.a + self.b; total * 2 }", true).unwrap();
+        assert_eq!(body, "        var total = a + b;\n        return total * 2;");
+    }
+
+    #[test]
+    fn test_unsupported_expression_reports_error() {
+        let lowerer = BodyLowerer::default();
+        let result = lowerer.lower_method_body("{ let f = |x| x + 1; }", false);
+        assert!(matches!(result, Err(LoweringError::UnsupportedExpression { .. })));
+    }
+
+    #[test]
+    fn test_lower_while_let_pop_front_drains_queue_field() {
+        let mut context = LoweringContext::new();
+        context.add_array_field("event_queue".to_string(), "int".to_string());
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body(
+            "{ while let Some(event) = self.event_queue.pop_front() { self.handle_event(event); } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines, vec![
+            "        while (eventQueue.Length > 0)",
+            "        {",
+            "            var @event = eventQueue[0];",
+            "            { var eventQueueShrunk = new int[eventQueue.Length - 1]; System.Array.Copy(eventQueue, 1, eventQueueShrunk, 0, eventQueueShrunk.Length); eventQueue = eventQueueShrunk; }",
+            "            HandleEvent(@event);",
+            "        }",
+        ]);
+    }
+
+    #[test]
+    fn test_lower_while_let_some_rebinds_value_each_iteration() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ while let Some(target) = self.next_target() { if self.is_dead(target) { continue; } self.hit(target); } }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        while (true)");
+        assert_eq!(lines[2], "            var target = NextTarget();");
+        assert_eq!(lines[3], "            if (!(target != null))");
+        assert_eq!(lines[5], "                break;");
+        assert!(body.contains("Hit(target);"));
+    }
+
+    fn scores_lowerer() -> BodyLowerer {
+        let mut context = LoweringContext::new();
+        context.add_array_field("scores".to_string(), "int".to_string());
+        BodyLowerer::new(context)
+    }
+
+    #[test]
+    fn test_lower_seeded_shuffle_to_fisher_yates_loop() {
+        let body = scores_lowerer().lower_method_body(
+            "{ UdonSharpUtility::shuffle(&mut self.scores, self.round_seed); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines, vec![
+            "        {",
+            "            UnityEngine.Random.InitState(roundSeed);",
+            "            for (int scoresIndex = scores.Length - 1; scoresIndex > 0; scoresIndex--)",
+            "            {",
+            "                var scoresSwap = UnityEngine.Random.Range(0, scoresIndex + 1);",
+            "                var scoresSwapped = scores[scoresIndex];",
+            "                scores[scoresIndex] = scores[scoresSwap];",
+            "                scores[scoresSwap] = scoresSwapped;",
+            "            }",
+            "        }",
+        ]);
+    }
+
+    #[test]
+    fn test_lower_contains_searches_array_until_first_match() {
+        let body = scores_lowerer().lower_method_body(
+            "{ if self.scores.contains(&target) { self.hit(); } let missing = !self.scores.contains(&0); }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines, vec![
+            "        {",
+            "            var scoresFound = -1;",
+            "            for (int scoresIndex = 0; scoresIndex < scores.Length; scoresIndex++)",
+            "            {",
+            "                if (scores[scoresIndex] == target)",
+            "                {",
+            "                    scoresFound = scoresIndex;",
+            "                    break;",
+            "                }",
+            "            }",
+            "            if (scoresFound >= 0)",
+            "            {",
+            "                Hit();",
+            "            }",
+            "        }",
+            "        var missingAt = -1;",
+            "        for (int scoresIndex = 0; scoresIndex < scores.Length; scoresIndex++)",
+            "        {",
+            "            if (scores[scoresIndex] == 0)",
+            "            {",
+            "                missingAt = scoresIndex;",
+            "                break;",
+            "            }",
+            "        }",
+            "        var missing = missingAt < 0;",
+        ]);
+    }
+
+    #[test]
+    fn test_lower_retain_compacts_array_in_place() {
+        let body = scores_lowerer().lower_method_body("{ self.scores.retain(|s| *s != 0); }", false).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines, vec![
+            "        {",
+            "            var scoresKept = 0;",
+            "            for (int scoresIndex = 0; scoresIndex < scores.Length; scoresIndex++)",
+            "            {",
+            "                var s = scores[scoresIndex];",
+            "                if (s != 0)",
+            "                {",
+            "                    scores[scoresKept] = scores[scoresIndex];",
+            "                    scoresKept++;",
+            "                }",
+            "            }",
+            "            if (scoresKept < scores.Length)",
+            "            {",
+            "                var scoresShrunk = new int[scoresKept];",
+            "                System.Array.Copy(scores, scoresShrunk, scoresKept);",
+            "                scores = scoresShrunk;",
+            "            }",
+            "        }",
+        ]);
+    }
+
+    #[test]
+    fn test_lower_if_let_position_and_find_bind_the_match() {
+        let body = scores_lowerer().lower_method_body(
+            "{ if let Some(slot) = self.scores.iter().position(|&s| s == 0) { self.fill(slot); } \
+               if let Some(best) = self.scores.iter().find(|s| **s > limit) { self.report(best); } }",
+            false,
+        ).unwrap();
+        assert!(body.contains("            var slot = -1;"));
+        assert!(body.contains("                var s = scores[scoresIndex];\n                if (s == 0)"));
+        assert!(body.contains("            if (slot >= 0)\n            {\n                Fill(slot);"));
+        assert!(body.contains("            var bestIndex = -1;"));
+        assert!(body.contains("            if (bestIndex >= 0)\n            {\n                var best = scores[bestIndex];\n                Report(best);"));
+    }
+
+    fn assertion_lowerer(mode: AssertionMode) -> BodyLowerer {
+        let mut context = LoweringContext::new();
+        context.behavior_name = "ScoreKeeper".to_string();
+        context.set_assertion_mode(mode);
+        BodyLowerer::new(context)
+    }
+
+    #[test]
+    fn test_debug_assert_eq_logs_both_operands_on_failure() {
+        let body = assertion_lowerer(AssertionMode::Log).lower_method_body(
+            "{ assert_eq!(self.score, expected); debug_assert!(self.round > 0, \"round {} not started\", self.round); self.score += 1; }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines, vec![
+            "        if (score != expected)",
+            "        {",
+            "            Debug.LogError(\"[ScoreKeeper] assertion failed: score == expected\" + \" (left: \" + score + \", right: \" + expected + \")\");",
+            "        }",
+            "        if (!(round > 0))",
+            "        {",
+            "            Debug.LogError(\"[ScoreKeeper] assertion failed: \" + string.Format(\"round {0} not started\", round));",
+            "        }",
+            "        score += 1;",
+        ]);
+    }
+
+    #[test]
+    fn test_release_strips_assertions() {
+        let body = assertion_lowerer(AssertionMode::Strip).lower_method_body(
+            "{ assert_eq!(self.score, expected); assert_ne!(self.round, 0); debug_assert!(self.ready); self.score += 1; }",
+            false,
+        ).unwrap();
+        assert_eq!(body.trim(), "score += 1;");
+    }
+
+    #[test]
+    fn test_lower_if_expression_with_single_expression_branches_to_ternary() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ let role = if self.is_master { \"Host\" } else if self.score > 10 { \"Veteran\" } else { \"Guest\" }; self.label = role; }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        var role = (isMaster ? \"Host\" : (score > 10 ? \"Veteran\" : \"Guest\"));",
+            "        label = role;",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_lower_block_bodied_if_expression_through_a_declared_local() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ let speed: f32 = if self.sprinting { let boost = self.stamina * 0.5; self.stamina -= 1.0; self.base_speed + boost } else { self.base_speed }; self.current_speed = speed; }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        float speed;",
+            "        if (sprinting)",
+            "        {",
+            "            var boost = stamina * 0.5f;",
+            "            stamina -= 1.0f;",
+            "            speed = baseSpeed + boost;",
+            "        }",
+            "        else",
+            "        {",
+            "            speed = baseSpeed;",
+            "        }",
+            "        currentSpeed = speed;",
+        ].join("\n"));
+
+        // Without an annotation the type comes from a literal branch value
+        let body = lowerer.lower_method_body("{ let bonus = if self.streak > 3 { self.streak = 0; 2 } else { 1 }; }", false).unwrap();
+        assert!(body.starts_with("        int bonus;\n        if (streak > 3)"), "{}", body);
+        assert!(lowerer.lower_method_body("{ let speed = if self.sprinting { self.fast() } else { self.base_speed }; }", false).is_ok());
+        assert!(lowerer.lower_method_body("{ let speed = if self.sprinting { self.tick(); self.fast() } else { self.base_speed }; }", false).is_err());
+
+        // In tail position every branch returns its value
+        let body = lowerer.lower_method_body("{ if self.lives > 0 { let left = self.lives - 1; left } else { 0 } }", true).unwrap();
+        assert!(body.contains("            return left;\n        }\n        else\n        {\n            return 0;"), "{}", body);
+    }
+
+    #[test]
+    fn test_lower_loop_with_break_value_through_a_result_local() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ let mut slot = 0; let free = loop { if slot >= self.capacity { break -1; } if !self.taken[slot] { break slot; } slot += 1; }; self.next_slot = free; }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        var slot = 0;",
+            "        int free;",
+            "        while (true)",
+            "        {",
+            "            if (slot >= capacity)",
+            "            {",
+            "                free = -1;",
+            "                break;",
+            "            }",
+            "            if (!taken[slot])",
+            "            {",
+            "                free = slot;",
+            "                break;",
+            "            }",
+            "            slot += 1;",
+            "        }",
+            "        nextSlot = free;",
+        ].join("\n"));
+
+        // A `break` of a nested loop is left alone, and a tail loop returns its value
+        let body = lowerer.lower_method_body("{ loop { for i in 0..3 { if self.ready { break; } } if self.done { break self.total; } } }", true).unwrap();
+        assert!(body.contains("                    break;\n"), "{}", body);
+        assert!(body.contains("                return total;\n"), "{}", body);
+    }
+
+    #[test]
+    fn test_loop_binding_type_is_inferred_from_computed_break_values() {
+        let mut tracker = UdonBehaviourStruct::new("Tracker".to_string());
+        tracker.add_field(crate::multi_behavior::StructField::new("total".to_string(), RustType::F32));
+        let context = LoweringContext::for_behavior(&tracker)
+            .with_parameters(&[MethodParameter::new("limit".to_string(), RustType::U32)]);
+        let lowerer = BodyLowerer::new(context);
+
+        let lower = |source: &str| lowerer.lower_method_body(source, false).unwrap().lines().next().unwrap().to_string();
+        assert_eq!(lower("{ let found = 'search: loop { for i in 0..8 { if self.ready { break 'search i * 2; } } break 'search -1; }; }"), "        int found;");
+        assert_eq!(lower("{ let found = 'search: loop { for i in 0..8u8 { if self.ready { break 'search 2 * i; } } break 'search 0; }; }"), "        byte found;");
+        assert_eq!(lower("{ let half = loop { break self.total / 2.0; }; }"), "        float half;");
+        assert_eq!(lower("{ let next = loop { break limit + 1; }; }"), "        uint next;");
+        assert_eq!(lower("{ let scaled = loop { break limit as f32 * 0.5; }; }"), "        float scaled;");
+        assert_eq!(lower("{ let done = loop { break self.total > 10.0; }; }"), "        bool done;");
+
+        // The value of a call has no known type, so such a binding needs an annotation
+        match lowerer.lower_method_body("{ let pick = loop { break self.pick(); }; }", false) {
+            Err(LoweringError::UnsupportedStatement { reason, .. }) => assert!(reason.contains("annotate the binding"), "{}", reason),
+            other => panic!("expected an unannotated loop binding error, got {:?}", other),
+        }
+        assert_eq!(lower("{ let pick: i32 = loop { break self.pick(); }; }"), "        int pick;");
+    }
+
+    #[test]
+    fn test_reject_loop_without_an_exit() {
+        let lowerer = BodyLowerer::default();
+        let result = lowerer.lower_method_body("{ loop { self.ticks += 1; for i in 0..3 { if self.ready { break; } } } }", false);
+        match result {
+            Err(LoweringError::UnboundedLoop) => {}
+            other => panic!("expected an unbounded loop error, got {:?}", other),
+        }
+        assert!(lowerer.lower_method_body("{ loop { if self.ready { return; } } }", false).is_ok());
+    }
+
+    #[test]
+    fn test_elapsed_timer_lowers_to_time_arithmetic() {
+        let mut context = LoweringContext::new();
+        context.add_elapsed_timer_field("round_timer".to_string());
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body(
+            "{ if self.round_timer.has_elapsed(self.round_duration) { self.end_round(); self.round_timer.start(); } self.remaining = self.round_duration - self.round_timer.elapsed(); }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        if ((Time.time - roundTimer >= roundDuration))",
+            "        {",
+            "            EndRound();",
+            "            roundTimer = Time.time;",
+            "        }",
+            "        remaining = roundDuration - (Time.time - roundTimer);",
+        ].join("\n"));
+
+        assert!(lowerer.lower_method_body("{ self.round_timer.reset(); }", false).is_err());
+    }
+
+    fn game_event_lowerer() -> BodyLowerer {
+        let mut context = LoweringContext::new();
+        context.add_payload_enum(PayloadEnum {
+            name: "GameEvent".to_string(),
+            variants: vec![
+                PayloadVariant {
+                    name: "PlayerScored".to_string(),
+                    fields: vec![("player_name".to_string(), RustType::String), ("score".to_string(), RustType::I32)],
+                },
+                PayloadVariant { name: "RoundEnded".to_string(), fields: vec![("0".to_string(), RustType::F32)] },
+            ],
+        });
+        BodyLowerer::new(context)
+    }
+
+    #[test]
+    fn test_payload_enum_variants_lower_to_generated_constructors() {
+        let body = game_event_lowerer().lower_method_body(
+            "{ let scored = GameEvent::PlayerScored { score: self.points, player_name }; self.last_event = GameEvent::RoundEnded(self.round_time); }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        var scored = NewGameEventPlayerScored(playerName, points);",
+            "        lastEvent = NewGameEventRoundEnded(roundTime);",
+        ].join("\n"));
+
+        assert!(game_event_lowerer().lower_method_body("{ let e = GameEvent::PlayerScored { score: 1 }; }", false).is_err());
+    }
+
+    #[test]
+    fn test_match_on_payload_enum_reads_bound_fields_from_the_record() {
+        let lowerer = game_event_lowerer();
+        let body = lowerer.lower_method_body(
+            "{ match event { GameEvent::PlayerScored { player_name, score } => { self.total += score; self.leader = player_name; } GameEvent::RoundEnded(_) => self.round += 1, } }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        switch (@event[\"tag\"].String)",
+            "        {",
+            "            case \"PlayerScored\":",
+            "            {",
+            "                var playerName = @event[\"player_name\"].String;",
+            "                var score = @event[\"score\"].Int;",
+            "                total += score;",
+            "                leader = playerName;",
+            "                break;",
+            "            }",
+            "            case \"RoundEnded\":",
+            "            {",
+            "                round += 1;",
+            "                break;",
+            "            }",
+            "        }",
+        ].join("\n"));
+
+        let missing = lowerer.lower_method_body("{ match event { GameEvent::RoundEnded(time) => self.time = time, } }", false);
+        assert!(matches!(missing, Err(LoweringError::NonExhaustiveMatch { missing, .. }) if missing == vec!["PlayerScored".to_string()]));
+
+        // C# needs a default for every path to return
+        let body = lowerer.lower_method_body(
+            "{ match event { GameEvent::PlayerScored { score, .. } => score, GameEvent::RoundEnded(_) => 0, } }",
+            true,
+        ).unwrap();
+        assert_eq!(body, [
+            "        switch (@event[\"tag\"].String)",
+            "        {",
+            "            case \"PlayerScored\":",
+            "            {",
+            "                var score = @event[\"score\"].Int;",
+            "                return score;",
+            "            }",
+            "            case \"RoundEnded\":",
+            "            default:",
+            "            {",
+            "                return 0;",
+            "            }",
+            "        }",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_tail_match_over_enum_returns_from_a_default() {
+        let mut context = LoweringContext::new();
+        context.add_enum(IntEnum {
+            name: "GameState".to_string(),
+            variants: vec![("Waiting".to_string(), 0), ("Playing".to_string(), 1)],
+            default_variant: "Waiting".to_string(),
+        });
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body(
+            "{ match self.state { GameState::Waiting => \"waiting\".to_string(), GameState::Playing => \"playing\".to_string() } }",
+            true,
+        ).unwrap();
+        assert_eq!(body, [
+            "        switch (state)",
+            "        {",
+            "            case GameState.Waiting:",
+            "            {",
+            "                return \"waiting\";",
+            "            }",
+            "            case GameState.Playing:",
+            "            default:",
+            "            {",
+            "                return \"playing\";",
+            "            }",
+            "        }",
+        ].join("\n"));
+
+        // An explicit `_` arm is the default already
+        let body = lowerer.lower_method_body("{ match self.state { GameState::Waiting => 0, _ => 1 } }", true).unwrap();
+        assert_eq!(body.matches("default:").count(), 1);
+    }
+
+    #[test]
+    fn test_slice_parameter_is_iterated_and_indexed_as_an_array() {
+        let context = LoweringContext::new().with_parameters(&[
+            MethodParameter::new("targets".to_string(), RustType::Vec(Box::new(RustType::GameObject))),
+        ]);
+        let lowerer = BodyLowerer::new(context);
+        let body = lowerer.lower_method_body(
+            "{ if targets.is_empty() { return; } for target in targets.iter() { target.set_active(true); } let count = targets.len(); self.last = targets[count - 1]; }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        if ((targets.Length == 0))",
+            "        {",
+            "            return;",
+            "        }",
+            "        foreach (var target in targets)",
+            "        {",
+            "            target.SetActive(true);",
+            "        }",
+            "        var count = targets.Length;",
+            "        last = targets[count - 1];",
+        ].join("\n"));
+
+        // The caller would keep the old array
+        assert!(lowerer.lower_method_body("{ targets.push(self.spare); }", false).is_err());
+    }
+
+    #[test]
+    fn test_disable_behavior_sets_enabled_to_false() {
+        let lowerer = BodyLowerer::default();
+        let body = lowerer.lower_method_body(
+            "{ if let Err(reason) = self.bind_references() { log::error!(\"Scoreboard disabled: {}\", reason); self.disable_behavior(); return; } self.ready = true; }",
+            false,
+        ).unwrap();
+        assert_eq!(body, [
+            "        var reason = BindReferences();",
+            "        if (reason != null)",
+            "        {",
+            "            Debug.LogError(string.Format(\"Scoreboard disabled: {0}\", reason));",
+            "            enabled = false;",
+            "            return;",
+            "        }",
+            "        ready = true;",
+        ].join("\n"));
+
+        let body = lowerer.lower_method_body(
+            "{ if self.spawn_point.is_none() { return Err(\"spawn_point is not assigned\".to_string()); } Ok(()) }",
+            true,
+        ).unwrap();
+        assert!(body.contains("            return \"spawn_point is not assigned\";"), "{}", body);
+        assert!(body.ends_with("        return null;"), "{}", body);
+    }
+
+    #[test]
+    fn test_failed_assertion_can_disable_the_behavior() {
+        let body = assertion_lowerer(AssertionMode::LogAndDisable).lower_method_body(
+            "{ assert!(self.ready); self.score += 1; }",
+            false,
+        ).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines[0], "        if (!(ready))");
+        assert_eq!(lines[3], "            enabled = false;");
+        assert_eq!(lines[4], "            return;");
+    }
+
+    #[test]
+    fn test_string_and_char_literals_use_csharp_escapes() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!("say \"hi\"\n\tC:\\tmp\r\0");
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), r#""say \"hi\"\n\tC:\\tmp\r\0""#);
+
+        let expr: Expr = parse_quote!("del\u{7f} bell\u{7} café 🎉");
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), r#""del\u007F bell\u0007 caf\u00E9 \uD83C\uDF89""#);
+
+        let expr: Expr = parse_quote!('é');
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), r"'\u00E9'");
+        let expr: Expr = parse_quote!('\'');
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), r"'\''");
+        let expr: Expr = parse_quote!('"');
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "'\"'");
+        let expr: Expr = parse_quote!('\n');
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), r"'\n'");
+    }
+
+    #[test]
+    fn test_format_specs_become_csharp_format_strings() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(format!("{:.1}K", ratio));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "string.Format(\"{0:F1}K\", ratio)");
+
+        let expr: Expr = parse_quote!(format!("{}:{:02}", minutes, secs));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "string.Format(\"{0}:{1:D2}\", minutes, secs)");
+
+        let expr: Expr = parse_quote!(format!("state {:?}", state));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "string.Format(\"state {0}\", state)");
+
+        let expr: Expr = parse_quote!(format!("{1} before {0}", first, second));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "string.Format(\"{1} before {0}\", first, second)");
+    }
+
+    #[test]
+    fn test_inline_format_arguments_become_positional() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(format!("{:.1}K / {:02} / {player_count} of {player_count}", ratio, round));
+        assert_eq!(
+            lowerer.lower_expr(&expr).unwrap(),
+            "string.Format(\"{0:F1}K / {1:D2} / {2} of {2}\", ratio, round, playerCount)"
+        );
+
+        let expr: Expr = parse_quote!(format!("{name} has {score:03}", name = player_name, score = self.score));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "string.Format(\"{0} has {1:D3}\", playerName, score)");
+    }
+
+    #[test]
+    fn test_format_braces_are_escaped_for_the_output() {
+        let lowerer = BodyLowerer::default();
+        let expr: Expr = parse_quote!(format!("{{literal}}"));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "\"{literal}\"");
+
+        let expr: Expr = parse_quote!(format!("{{{}}}", value));
+        assert_eq!(lowerer.lower_expr(&expr).unwrap(), "string.Format(\"{{{0}}}\", value)");
+    }
+
+    #[test]
+    fn test_untranslatable_format_specs_are_unsupported() {
+        let lowerer = BodyLowerer::default();
+        for expr in [
+            parse_quote!(format!("{:>8}", name)),
+            parse_quote!(format!("{:#x}", flags)),
+            parse_quote!(format!("{:e}", value)),
+            parse_quote!(format!("{} {}", value)),
+        ] {
+            let expr: Expr = expr;
+            match lowerer.lower_expr(&expr) {
+                Err(LoweringError::UnsupportedExpression { .. }) => {}
+                other => panic!("expected an unsupported format string, got {:?}", other),
+            }
+        }
+    }
+}
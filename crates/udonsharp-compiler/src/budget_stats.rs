@@ -0,0 +1,299 @@
+//! Per-behavior budget table printed by `cargo udonsharp build --stats`
+//!
+//! A quick health check before upload: for every behavior, how many variables
+//! it syncs and roughly how many bytes they take per serialization, how many
+//! public variables and events it exposes, and how large its Udon program is
+//! estimated to be. Each figure with a known limit is shown against it and
+//! colored by how close it gets, composing the limits the analyzers enforce
+//! one at a time.
+
+use crate::config::UdonSharpConfig;
+use crate::multi_behavior::{MethodAttribute, RustType, StructAttribute, StructField, UdonBehaviourStruct, UdonSyncMode};
+use crate::quantization::Quantization;
+use crate::split_advisor::{SplitAdvisor, DEFAULT_INSTRUCTION_LIMIT};
+use crate::struct_analyzer::{AnalysisError, AnalysisResult, StructAnalyzer};
+
+/// Bytes a continuously synced behavior can send per serialization, "roughly
+/// 200 bytes" per VRChat Creator Documentation, Udon > Networking > Network
+/// Specs and Tips
+pub const CONTINUOUS_SYNC_BYTE_LIMIT: usize = 200;
+
+/// Bytes a manually synced behavior can send per serialization, as listed in
+/// the same Network Specs and Tips page
+pub const MANUAL_SYNC_BYTE_LIMIT: usize = 280_496;
+
+/// Bytes assumed for a synced string, whose length is only known at runtime
+const SYNCED_STRING_ESTIMATE: usize = 32;
+
+/// Elements assumed for a synced `Vec`, whose length is only known at runtime
+const SYNCED_VEC_ELEMENTS_ESTIMATE: usize = 8;
+
+/// Share of a limit from which usage is reported as close to it
+const NEAR_LIMIT_RATIO: f64 = 0.75;
+
+/// How close a figure is to its limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BudgetLevel {
+    Ok,
+    NearLimit,
+    OverLimit,
+}
+
+impl BudgetLevel {
+    fn of(value: usize, limit: usize) -> Self {
+        if value > limit {
+            BudgetLevel::OverLimit
+        } else if value as f64 >= limit as f64 * NEAR_LIMIT_RATIO {
+            BudgetLevel::NearLimit
+        } else {
+            BudgetLevel::Ok
+        }
+    }
+
+    /// ANSI color the figure is printed in
+    fn color(self) -> &'static str {
+        match self {
+            BudgetLevel::Ok => "\x1b[32m",
+            BudgetLevel::NearLimit => "\x1b[33m",
+            BudgetLevel::OverLimit => "\x1b[31m",
+        }
+    }
+}
+
+/// Budget usage of one behavior
+#[derive(Debug, Clone, PartialEq)]
+pub struct BehaviorBudget {
+    pub behavior: String,
+    pub synced_variables: usize,
+    /// Estimated bytes of one serialization of the synced variables
+    pub synced_bytes: usize,
+    /// Byte limit of the behavior's sync mode
+    pub sync_byte_limit: usize,
+    pub public_variables: usize,
+    pub public_variable_limit: usize,
+    /// Custom and network events the behavior handles
+    pub events: usize,
+    pub estimated_instructions: usize,
+    pub instruction_limit: usize,
+}
+
+impl BehaviorBudget {
+    /// Measure a behavior against the given instruction and public variable limits
+    pub fn new(udon_struct: &UdonBehaviourStruct, instruction_limit: usize, public_variable_limit: usize) -> Self {
+        let synced_fields = udon_struct.get_sync_fields();
+        let synced_bytes = synced_fields.iter()
+            .map(|field| synced_field_bytes(udon_struct, field))
+            .sum();
+        let manual_sync = udon_struct.attributes.contains(&StructAttribute::UdonSyncMode(UdonSyncMode::Manual));
+        let events = udon_struct.methods.iter()
+            .filter(|method| method.attributes.iter().any(|attribute| matches!(
+                attribute,
+                MethodAttribute::UdonEvent(_) | MethodAttribute::UdonNetworkEvent { .. }
+            )))
+            .count();
+
+        Self {
+            behavior: udon_struct.name.clone(),
+            synced_variables: synced_fields.len(),
+            synced_bytes,
+            sync_byte_limit: if manual_sync { MANUAL_SYNC_BYTE_LIMIT } else { CONTINUOUS_SYNC_BYTE_LIMIT },
+            public_variables: udon_struct.fields.iter().filter(|field| field.is_public()).count(),
+            public_variable_limit,
+            events,
+            estimated_instructions: SplitAdvisor::new(instruction_limit).estimate_instructions(udon_struct),
+            instruction_limit,
+        }
+    }
+
+    /// The level of the figure closest to its limit
+    pub fn level(&self) -> BudgetLevel {
+        [
+            BudgetLevel::of(self.synced_bytes, self.sync_byte_limit),
+            BudgetLevel::of(self.public_variables, self.public_variable_limit),
+            BudgetLevel::of(self.estimated_instructions, self.instruction_limit),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(BudgetLevel::Ok)
+    }
+}
+
+/// Budget usage of every behavior of a project
+#[derive(Debug, Clone, Default)]
+pub struct BudgetStats {
+    /// Behaviors ordered by name
+    pub behaviors: Vec<BehaviorBudget>,
+}
+
+impl BudgetStats {
+    /// Measure analyzed behaviors
+    pub fn new(behaviors: &[UdonBehaviourStruct], instruction_limit: usize, public_variable_limit: usize) -> Self {
+        let mut behaviors: Vec<BehaviorBudget> = behaviors.iter()
+            .map(|udon_struct| BehaviorBudget::new(udon_struct, instruction_limit, public_variable_limit))
+            .collect();
+        behaviors.sort_by(|a, b| a.behavior.cmp(&b.behavior));
+        Self { behaviors }
+    }
+
+    /// Measure analyzed behaviors against the limits of a build configuration
+    pub fn with_config(behaviors: &[UdonBehaviourStruct], config: &UdonSharpConfig) -> Self {
+        Self::new(behaviors, DEFAULT_INSTRUCTION_LIMIT, config.public_variable_limit)
+    }
+
+    /// Analyze the behaviors of a source file with the public variable limit
+    /// and lint levels of a build configuration, and measure them
+    pub fn from_source(source: &str, config: &UdonSharpConfig) -> AnalysisResult<Self> {
+        let file = syn::parse_file(source).map_err(|e| AnalysisError::ParseError { message: e.to_string() })?;
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.set_lint_levels(config.lints.clone());
+        analyzer.set_public_variable_limit(config.public_variable_limit);
+        let behaviors = analyzer.analyze_module(&file.items)?;
+        Ok(Self::with_config(&behaviors, config))
+    }
+
+    /// Format the table, coloring figures with a limit green, yellow or red
+    /// by how close they are to it when `color` is set
+    pub fn format_table(&self, color: bool) -> String {
+        let paint = |text: String, level: BudgetLevel| {
+            if color {
+                format!("{}{}\x1b[0m", level.color(), text)
+            } else {
+                text
+            }
+        };
+        let name_width = self.behaviors.iter()
+            .map(|budget| budget.behavior.len())
+            .chain(["Behavior".len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut lines = vec![format!(
+            "{:<name_width$}  {:>6}  {:>16}  {:>9}  {:>6}  {:>14}",
+            "Behavior", "Synced", "Synced bytes", "Public", "Events", "Instructions",
+        )];
+        for budget in &self.behaviors {
+            // Pad before coloring so the escape codes do not upset the alignment
+            let synced_bytes = format!("{:>16}", format!("~{}/{}", budget.synced_bytes, budget.sync_byte_limit));
+            let public_variables = format!("{:>9}", format!("{}/{}", budget.public_variables, budget.public_variable_limit));
+            let instructions = format!("{:>14}", format!("~{}/{}", budget.estimated_instructions, budget.instruction_limit));
+            lines.push(format!(
+                "{:<name_width$}  {:>6}  {}  {}  {:>6}  {}",
+                budget.behavior,
+                budget.synced_variables,
+                paint(synced_bytes, BudgetLevel::of(budget.synced_bytes, budget.sync_byte_limit)),
+                paint(public_variables, BudgetLevel::of(budget.public_variables, budget.public_variable_limit)),
+                budget.events,
+                paint(instructions, BudgetLevel::of(budget.estimated_instructions, budget.instruction_limit)),
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Estimated bytes a synced field takes in one serialization
+fn synced_field_bytes(udon_struct: &UdonBehaviourStruct, field: &StructField) -> usize {
+    if let Some(blob) = udon_struct.sync_blobs.iter().find(|blob| blob.blob_field_name() == field.name) {
+        // The array length is sent along with the packed bytes
+        return blob.byte_len() + 4;
+    }
+    if let Some(Ok(quantization)) = Quantization::for_field(field) {
+        return match quantization.packed_type() {
+            "byte" => 1,
+            "ushort" => 2,
+            "uint" => 4,
+            _ => 8,
+        };
+    }
+    synced_type_bytes(&field.field_type)
+}
+
+/// Estimated serialized size of a synced value of a type
+fn synced_type_bytes(rust_type: &RustType) -> usize {
+    match rust_type {
+        RustType::Bool | RustType::I8 | RustType::U8 => 1,
+        RustType::I16 | RustType::U16 | RustType::Char => 2,
+        RustType::I64 | RustType::U64 | RustType::F64 => 8,
+        RustType::Vector2 => 8,
+        RustType::Vector3 => 12,
+        RustType::Vector4 | RustType::Quaternion | RustType::Color => 16,
+        RustType::String => SYNCED_STRING_ESTIMATE,
+        RustType::Option(inner) => synced_type_bytes(inner),
+        RustType::Array(inner, size) => size * synced_type_bytes(inner),
+        // Arrays are sent with their length
+        RustType::Vec(inner) => 4 + SYNCED_VEC_ELEMENTS_ESTIMATE * synced_type_bytes(inner),
+        // Integers, floats, `Color32` and enums synced as their discriminant
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_report_synced_variables_of_a_behavior() {
+        let stats = BudgetStats::from_source(r#"
+            #[derive(UdonBehaviour)]
+            #[udon_sync_mode(Manual)]
+            pub struct Scoreboard {
+                #[udon_sync]
+                pub top_score: i32,
+                #[udon_sync]
+                pub leader: String,
+                #[udon_sync]
+                pub round_active: bool,
+                #[udon_public]
+                pub round_length: f32,
+                local_highlight: i32,
+            }
+
+            impl UdonBehaviour for Scoreboard {
+                fn start(&mut self) {}
+            }
+
+            impl Scoreboard {
+                #[udon_event("ResetScores")]
+                pub fn reset_scores(&mut self) {}
+            }
+        "#, &UdonSharpConfig::default()).unwrap();
+
+        let scoreboard = &stats.behaviors[0];
+        assert_eq!(scoreboard.behavior, "Scoreboard");
+        assert_eq!(scoreboard.synced_variables, 3);
+        assert_eq!(scoreboard.sync_byte_limit, MANUAL_SYNC_BYTE_LIMIT);
+        assert_eq!(scoreboard.events, 1);
+        assert_eq!(scoreboard.level(), BudgetLevel::Ok);
+
+        let table = stats.format_table(false);
+        let row = table.lines().nth(1).unwrap();
+        assert!(row.starts_with("Scoreboard"), "{}", table);
+        assert!(row.contains(&format!("/{}", MANUAL_SYNC_BYTE_LIMIT)), "{}", table);
+        assert!(!table.contains('\x1b'));
+        assert!(stats.format_table(true).contains("\x1b[32m"));
+    }
+
+    #[test]
+    fn test_stats_use_the_configured_public_variable_limit() {
+        let fields: String = (0..300).map(|i| format!("#[udon_public] pub slot_{}: i32,\n", i)).collect();
+        let source = format!(r#"
+            #[derive(UdonBehaviour)]
+            pub struct Inventory {{
+                {}
+            }}
+
+            impl UdonBehaviour for Inventory {{
+                fn start(&mut self) {{}}
+            }}
+        "#, fields);
+
+        assert!(BudgetStats::from_source(&source, &UdonSharpConfig::default()).is_err());
+
+        let config = UdonSharpConfig { public_variable_limit: 512, ..UdonSharpConfig::default() };
+        let stats = BudgetStats::from_source(&source, &config).unwrap();
+        let inventory = &stats.behaviors[0];
+        assert_eq!(inventory.public_variables, 300);
+        assert_eq!(inventory.public_variable_limit, 512);
+        assert_eq!(inventory.level(), BudgetLevel::Ok);
+        assert!(stats.format_table(false).contains("300/512"));
+    }
+}
@@ -6,11 +6,20 @@
 
 use crate::multi_behavior::{
     UdonBehaviourStruct, StructField, StructMethod, StructAttribute,
-    FieldAttribute, MethodAttribute, RustType,
+    FieldAttribute, MethodAttribute, NetworkEventTarget, RustType,
     UdonBehaviourTraitImpl, RustToCSharpTypeMapper, AttributeMapper,
-    is_valid_csharp_identifier
+    IntEnum, Visibility, blob_type_size, is_valid_csharp_identifier, input_event, INPUT_EVENTS, INPUT_EVENT_ARGS
 };
+use crate::body_lowering::{BodyLowerer, LoweringContext, LoweringError, unassigned_flag_name};
+use crate::config::{AssertionMode, MethodExposure};
+use crate::quantization::Quantization;
+use crate::record_pool::RecordPool;
+use crate::sync_layout::SyncLayout;
+use crate::event_forwarding::{forward_target_fields, EventForward};
+use crate::event_ids::{event_id_constant_name, EventIdTable};
 use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Result type for code generation operations
 pub type GenerationResult<T> = Result<T, GenerationError>;
@@ -30,6 +39,10 @@ pub enum GenerationError {
     InvalidMethodSignature { method_name: String, reason: String },
     /// Attribute validation failed
     AttributeValidationError { attribute: String, reason: String },
+    /// A `match` over an enum does not handle every variant
+    NonExhaustiveMatch { method_name: String, enum_name: String, missing: Vec<String> },
+    /// A `loop` in a method body can never be left
+    UnboundedLoop { method_name: String },
 }
 
 impl std::fmt::Display for GenerationError {
@@ -53,6 +66,20 @@ impl std::fmt::Display for GenerationError {
             GenerationError::AttributeValidationError { attribute, reason } => {
                 write!(f, "Attribute validation error for '{}': {}", attribute, reason)
             }
+            GenerationError::NonExhaustiveMatch { method_name, enum_name, missing } => {
+                write!(
+                    f,
+                    "Non-exhaustive match over '{}' in '{}': missing variant(s) {}; add the missing arms or a `_` arm",
+                    enum_name, method_name, missing.join(", ")
+                )
+            }
+            GenerationError::UnboundedLoop { method_name } => {
+                write!(
+                    f,
+                    "`loop` in '{}' has no `break`, `return` or `?` to leave it and would hang the Udon VM",
+                    method_name
+                )
+            }
         }
     }
 }
@@ -154,6 +181,24 @@ pub struct CodeGenerator {
     template_cache: HashMap<String, String>,
     /// Generated classes cache
     generated_classes: HashMap<String, GeneratedClass>,
+    /// Behaviors that method bodies may reach through `get_component::<T>()`
+    known_behaviors: HashMap<String, UdonBehaviourStruct>,
+    /// Whether `log::debug!` and `log::trace!` calls are dropped from method bodies
+    strip_debug_logs: bool,
+    /// How assertions in method bodies are lowered
+    assertion_mode: AssertionMode,
+    /// Whether unwraps of public `Option` references are guarded by null checks
+    safe_refs: bool,
+    /// Which plain `pub fn`s become public C# methods
+    method_exposure: MethodExposure,
+    /// Namespace wrapping generated classes
+    namespace: Option<String>,
+    /// Stable IDs of network events, kept across builds
+    event_ids: EventIdTable,
+    /// Methods whose bodies could not be lowered, recorded while generating
+    warnings: Mutex<Vec<String>>,
+    /// Whether code lowered for the class being generated names a data container type
+    emits_data_containers: AtomicBool,
 }
 
 impl CodeGenerator {
@@ -164,6 +209,69 @@ impl CodeGenerator {
             attribute_mapper: AttributeMapper::new(),
             template_cache: HashMap::new(),
             generated_classes: HashMap::new(),
+            known_behaviors: HashMap::new(),
+            strip_debug_logs: false,
+            assertion_mode: AssertionMode::default(),
+            safe_refs: false,
+            method_exposure: MethodExposure::default(),
+            namespace: None,
+            event_ids: EventIdTable::new(),
+            warnings: Mutex::new(Vec::new()),
+            emits_data_containers: AtomicBool::new(false),
+        }
+    }
+
+    /// Drop `log::debug!` and `log::trace!` calls from lowered method bodies
+    pub fn set_strip_debug_logs(&mut self, strip: bool) {
+        self.strip_debug_logs = strip;
+    }
+
+    /// Choose whether assertions are checked, checked and disable the behavior, or stripped
+    pub fn set_assertion_mode(&mut self, mode: AssertionMode) {
+        self.assertion_mode = mode;
+    }
+
+    /// Guard unwraps of public `Option` references with a null check that logs
+    /// the unassigned reference once instead of throwing on every call
+    pub fn set_safe_refs(&mut self, safe_refs: bool) {
+        self.safe_refs = safe_refs;
+    }
+
+    /// Choose whether plain `pub fn`s are public C# methods, and so callable as
+    /// events from Unity, or whether that is reserved for `#[udon_event]` methods
+    pub fn set_method_exposure(&mut self, exposure: MethodExposure) {
+        self.method_exposure = exposure;
+    }
+
+    /// Wrap generated classes in a C# namespace.
+    ///
+    /// All behaviors of a project share the namespace, so references between
+    /// them and to the SharedRuntime keep resolving by their plain class names.
+    pub fn set_namespace(&mut self, namespace: Option<String>) {
+        self.namespace = namespace;
+    }
+
+    /// Seed the network event IDs with the table of a previous build so
+    /// existing events keep their numbers
+    pub fn set_event_ids(&mut self, event_ids: EventIdTable) {
+        self.event_ids = event_ids;
+    }
+
+    /// Get the network event IDs assigned so far, to persist for the next build
+    pub fn event_ids(&self) -> &EventIdTable {
+        &self.event_ids
+    }
+
+    /// Get the generation warnings, such as method bodies that were stubbed out
+    pub fn get_warnings(&self) -> Vec<String> {
+        self.warnings.lock().map(|warnings| warnings.clone()).unwrap_or_default()
+    }
+
+    /// Register the behaviors of a project so cross-behavior accesses in
+    /// method bodies lower to `GetProgramVariable`/`SendCustomEvent`
+    pub fn register_behaviors(&mut self, behaviors: &[UdonBehaviourStruct]) {
+        for behavior in behaviors {
+            self.known_behaviors.insert(behavior.name.clone(), behavior.clone());
         }
     }
 
@@ -174,11 +282,18 @@ impl CodeGenerator {
 
         // Generate class components
         let class_name = self.generate_class_name(&udon_struct.name)?;
-        let using_statements = self.generate_using_statements(udon_struct)?;
+        self.emits_data_containers.store(false, Ordering::Relaxed);
         let class_attributes = self.generate_class_attributes(&udon_struct.attributes)?;
-        let fields = self.generate_fields(&udon_struct.fields)?;
+        let mut fields = self.generate_fields(&udon_struct.fields)?;
+        fields.extend(forward_target_fields(udon_struct));
+        fields.extend(self.generate_network_event_ids(udon_struct));
+        if let Some(layout) = SyncLayout::for_behavior(udon_struct) {
+            fields.extend(layout.fields());
+        }
         let mut methods = self.generate_unity_event_methods(udon_struct)?;
-        let custom_events = self.generate_custom_event_handlers(udon_struct)?;
+        let behavior_methods = self.generate_behavior_methods(udon_struct)?;
+        let mut custom_events = self.generate_custom_event_handlers(udon_struct)?;
+        custom_events.extend(self.generate_network_event_handlers(udon_struct)?);
 
         // Generate network synchronization methods if needed
         if udon_struct.has_networking() {
@@ -205,27 +320,36 @@ impl CodeGenerator {
             // This field will be added via the declaration in the optimization method
         }
 
-        // Generate complete source code
-        let source_code = self.generate_complete_class_source(
-            &class_name,
-            &using_statements,
-            &class_attributes,
-            &fields,
-            &methods,
-            &custom_events,
-        )?;
-
-        let generated_class = GeneratedClass {
+        if udon_struct.is_json_serializable() {
+            methods.extend(self.generate_json_methods(udon_struct)?);
+        }
+
+        methods.extend(udon_struct.int_enums.iter().map(|int_enum| generate_int_enum_conversion(&udon_struct.name, int_enum)));
+        methods.extend(udon_struct.payload_enums.iter().flat_map(|payload_enum| payload_enum.constructors()));
+        methods.extend(self.generate_sync_blob_methods(udon_struct));
+        if let Some(pool) = RecordPool::for_behavior(udon_struct) {
+            fields.extend(pool.fields());
+            methods.extend(pool.methods(&udon_struct.name));
+        }
+        fields.extend(unassigned_reference_flags(udon_struct, &methods, &behavior_methods, &custom_events));
+        // Usings come last since they depend on what the lowered bodies name
+        let using_statements = self.generate_using_statements(udon_struct)?;
+
+        let mut generated_class = GeneratedClass {
             class_name: class_name.clone(),
-            namespace: None, // UdonSharp classes typically don't use namespaces
+            namespace: self.namespace.clone(),
             using_statements,
             class_attributes,
             fields,
             methods,
             custom_events,
-            source_code,
+            source_code: String::new(),
         };
 
+        // Generate complete source code
+        generated_class.source_code = self.generate_complete_class_source(&generated_class, &behavior_methods)?;
+        generated_class.methods.extend(behavior_methods);
+
         // Cache the generated class
         self.generated_classes.insert(class_name, generated_class.clone());
 
@@ -282,6 +406,17 @@ impl CodeGenerator {
             usings.extend(field_usings);
         }
 
+        // Data containers in lowered bodies are only known once they are lowered;
+        // JSON methods use VRCJson, payload enums are held as DataDictionary
+        // records and record pools hold data containers
+        if self.emits_data_containers.load(Ordering::Relaxed)
+            || udon_struct.is_json_serializable()
+            || !udon_struct.payload_enums.is_empty()
+            || RecordPool::for_behavior(udon_struct).is_some()
+        {
+            usings.insert("using VRC.SDK3.Data;".to_string());
+        }
+
         // Add usings based on attributes
         let attribute_usings = self.attribute_mapper.get_required_usings_for_attributes(
             &udon_struct.fields.iter().flat_map(|f| &f.attributes).cloned().collect::<Vec<_>>(),
@@ -302,6 +437,16 @@ impl CodeGenerator {
             usings.insert("using VRC.SDKBase;".to_string());
         }
 
+        // Forwarded events are sent through UdonBehaviour references
+        if !EventForward::for_behavior(udon_struct).is_empty() {
+            usings.insert("using VRC.Udon;".to_string());
+        }
+
+        // Network event callers name their target through NetworkEventTarget
+        if udon_struct.methods.iter().any(|m| m.is_network_event()) {
+            usings.insert("using VRC.Udon.Common.Interfaces;".to_string());
+        }
+
         // Check if we need additional Unity usings based on methods
         if let Some(trait_impl) = &udon_struct.trait_impl {
             for method_name in &trait_impl.implemented_methods {
@@ -313,6 +458,9 @@ impl CodeGenerator {
                     "on_player_joined" | "on_player_left" => {
                         usings.insert("using VRC.SDKBase;".to_string());
                     }
+                    name if input_event(name).is_some() => {
+                        usings.insert("using VRC.Udon.Common;".to_string());
+                    }
                     _ => {}
                 }
             }
@@ -350,6 +498,29 @@ impl CodeGenerator {
         Ok(generated_fields)
     }
 
+    /// Generate a `{Event}EventId` constant for every network event
+    ///
+    /// IDs come from the event ID table, so they depend only on the event
+    /// names and not on the order the methods were declared or visited in.
+    fn generate_network_event_ids(&mut self, udon_struct: &UdonBehaviourStruct) -> Vec<GeneratedField> {
+        let events = udon_struct.methods.iter().filter_map(|m| m.network_event().map(|(name, _)| name));
+        let ids = self.event_ids.assign(&udon_struct.name, events);
+
+        ids.into_iter()
+            .map(|(event, id)| {
+                let name = event_id_constant_name(&event);
+                GeneratedField {
+                    declaration: format!("    public const int {} = {};", name, id),
+                    name,
+                    field_type: "int".to_string(),
+                    visibility: "public".to_string(),
+                    attributes: Vec::new(),
+                    default_value: Some(id.to_string()),
+                }
+            })
+            .collect()
+    }
+
     /// Generate a single field
     pub fn generate_single_field(&self, field: &StructField) -> GenerationResult<GeneratedField> {
         // Validate field attributes
@@ -379,15 +550,32 @@ impl CodeGenerator {
         // Generate visibility
         let visibility = self.attribute_mapper.map_field_visibility(field);
 
-        // Generate default value
-        let default_value = field.default_value.clone()
-            .or_else(|| {
-                if field.is_public() {
-                    Some(self.type_mapper.get_default_value(&field.field_type))
-                } else {
-                    None
-                }
+        // Quantized fields sync a packed integer behind a property of the original name
+        if let Some(quantization) = Quantization::for_field(field) {
+            let quantization = quantization.map_err(|reason| GenerationError::AttributeValidationError {
+                attribute: "udon_quantize".to_string(),
+                reason,
+            })?;
+            attributes.retain(|attr| attr != "[UdonSynced]");
+            let declaration = quantization.generate_declaration(&field.name, &csharp_type, &visibility);
+            return Ok(GeneratedField {
+                name: field_name,
+                field_type: csharp_type,
+                visibility,
+                attributes,
+                default_value: None,
+                declaration,
             });
+        }
+
+        // Generate default value. Defaults of fields that are not serialized are
+        // assigned in Start instead, see `generate_field_default_initializers`.
+        let default_value = if field.is_serialized() {
+            field.default_value.clone()
+                .or_else(|| Some(self.type_mapper.get_default_value(&field.field_type)))
+        } else {
+            None
+        };
 
         // Generate complete field declaration
         let declaration = self.generate_field_declaration(
@@ -441,11 +629,24 @@ impl CodeGenerator {
     fn generate_unity_event_methods(&self, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Vec<GeneratedMethod>> {
         let mut methods = Vec::new();
 
-        if let Some(trait_impl) = &udon_struct.trait_impl {
-            for method_name in &trait_impl.implemented_methods {
-                if let Some(unity_method) = self.generate_unity_event_method(method_name, udon_struct)? {
-                    methods.push(unity_method);
-                }
+        let mut handlers: Vec<String> = udon_struct.trait_impl.iter()
+            .flat_map(|trait_impl| trait_impl.implemented_methods.iter().cloned())
+            .collect();
+        // Private field defaults are assigned in Start, so they need one even when
+        // the behavior does not implement `start`
+        if !handlers.iter().any(|handler| handler == "start") && self.start_initialized_fields(udon_struct).next().is_some() {
+            handlers.insert(0, "start".to_string());
+        }
+        // Forwarded events get a handler even when the behavior does not implement one
+        for forward in EventForward::for_behavior(udon_struct) {
+            if !handlers.contains(&forward.handler()) {
+                handlers.push(forward.handler());
+            }
+        }
+
+        for method_name in &handlers {
+            if let Some(unity_method) = self.generate_unity_event_method(method_name, udon_struct)? {
+                methods.push(unity_method);
             }
         }
 
@@ -460,7 +661,26 @@ impl CodeGenerator {
             // Get method parameters and return type from the struct's trait implementation
             let (parameters, return_type) = self.get_unity_method_signature(method_name, udon_struct)?;
             
-            let method_body = self.generate_unity_method_body(method_name, udon_struct);
+            let mut method_body = self.generate_unity_method_body(method_name, udon_struct);
+            let lowered_body = match udon_struct.methods.iter().find(|m| m.name == method_name) {
+                Some(method) => self.lower_method_body(method, udon_struct)?,
+                None => None,
+            };
+            if let Some(lowered) = lowered_body {
+                // Start keeps its generated reference initialization ahead of the user body
+                method_body = if method_name == "start" {
+                    format!("{}\n\n{}", method_body, lowered)
+                } else {
+                    lowered
+                };
+            }
+            if let Some(hook_lines) = serialization_hook_lines(&csharp_name, udon_struct) {
+                method_body = format!("{}\n{}", hook_lines, method_body);
+            }
+            // Forwards run after the handler's own body
+            for forward in EventForward::for_behavior(udon_struct).iter().filter(|forward| forward.event == csharp_name) {
+                method_body = format!("{}\n\n{}", method_body, forward.forwarding_lines(udon_struct).join("\n"));
+            }
             let declaration = self.generate_unity_method_declaration_with_params(&csharp_name, &parameters, &return_type, &method_body);
 
             Ok(Some(GeneratedMethod {
@@ -478,31 +698,7 @@ impl CodeGenerator {
 
     /// Map Rust trait method names to Unity C# method names
     pub fn map_unity_method_name(&self, rust_method: &str) -> Option<String> {
-        match rust_method {
-            "start" => Some("Start".to_string()),
-            "update" => Some("Update".to_string()),
-            "fixed_update" => Some("FixedUpdate".to_string()),
-            "late_update" => Some("LateUpdate".to_string()),
-            "on_enable" => Some("OnEnable".to_string()),
-            "on_disable" => Some("OnDisable".to_string()),
-            "on_destroy" => Some("OnDestroy".to_string()),
-            "on_trigger_enter" => Some("OnTriggerEnter".to_string()),
-            "on_trigger_exit" => Some("OnTriggerExit".to_string()),
-            "on_trigger_stay" => Some("OnTriggerStay".to_string()),
-            "on_collision_enter" => Some("OnCollisionEnter".to_string()),
-            "on_collision_exit" => Some("OnCollisionExit".to_string()),
-            "on_collision_stay" => Some("OnCollisionStay".to_string()),
-            "on_player_joined" => Some("OnPlayerJoined".to_string()),
-            "on_player_left" => Some("OnPlayerLeft".to_string()),
-            "on_pickup" => Some("OnPickup".to_string()),
-            "on_drop" => Some("OnDrop".to_string()),
-            "on_pickup_use_down" => Some("OnPickupUseDown".to_string()),
-            "on_pickup_use_up" => Some("OnPickupUseUp".to_string()),
-            "on_station_entered" => Some("OnStationEntered".to_string()),
-            "on_station_exited" => Some("OnStationExited".to_string()),
-            "on_post_deserialization" => Some("OnDeserialization".to_string()),
-            _ => None, // Not a Unity event method
-        }
+        unity_event_name(rust_method)
     }
 
     /// Generate method body for Unity event methods
@@ -513,6 +709,11 @@ impl CodeGenerator {
                     "        // Initialize behavior".to_string(),
                 ];
 
+                if let Some(pool) = RecordPool::for_behavior(udon_struct) {
+                    body.extend(pool.start_lines());
+                }
+                body.extend(self.generate_field_default_initializers(udon_struct));
+
                 // Add GameObject reference initialization if needed
                 for field in &udon_struct.fields {
                     if self.type_mapper.is_gameobject_reference(&field.field_type) {
@@ -613,6 +814,62 @@ impl CodeGenerator {
         }
     }
 
+    /// Assign the defaults of private fields at the start of `Start`.
+    ///
+    /// Public fields keep their default as the declaration initializer so that
+    /// values set in the inspector are not overwritten. Constructor initializers
+    /// that are not constant are lowered like method bodies; one that cannot be
+    /// lowered is recorded as a warning and leaves the field at its default.
+    fn generate_field_default_initializers(&self, udon_struct: &UdonBehaviourStruct) -> Vec<String> {
+        self.start_initialized_fields(udon_struct)
+            .filter_map(|field| {
+                let value = match &field.start_initializer {
+                    Some(source) => self.lower_start_initializer(field, source, udon_struct)?,
+                    // DataList and DataDictionary fields would otherwise start out null
+                    None => field.default_value.clone()
+                        .unwrap_or_else(|| self.type_mapper.get_default_value(&field.field_type)),
+                };
+                Some(format!("        {} = {};", to_camel_case(&field.name), value))
+            })
+            .collect()
+    }
+
+    /// Fields whose initial value is assigned in `Start`
+    fn start_initialized_fields<'a>(&self, udon_struct: &'a UdonBehaviourStruct) -> impl Iterator<Item = &'a StructField> {
+        udon_struct.fields.iter()
+            // Quantized properties cannot have initializers either
+            .filter(|field| !field.is_serialized() || Quantization::for_field(field).is_some())
+            .filter(|field| {
+                field.default_value.is_some()
+                    || field.start_initializer.is_some()
+                    || matches!(&field.field_type, RustType::Custom(name) if name == "DataList" || name == "DataDictionary")
+            })
+    }
+
+    /// Lower the Rust initializer expression of a field for `Start`
+    fn lower_start_initializer(&self, field: &StructField, source: &str, udon_struct: &UdonBehaviourStruct) -> Option<String> {
+        let lowered = syn::parse_str::<syn::Expr>(source)
+            .map_err(|e| LoweringError::ParseError { message: e.to_string() })
+            .and_then(|expr| {
+                let lowerer = BodyLowerer::new(self.lowering_context(udon_struct));
+                let value = lowerer.lower_expr(&expr)?;
+                self.record_data_containers(&lowerer);
+                Ok(value)
+            });
+        match lowered {
+            Ok(value) => Some(value),
+            Err(error) => {
+                if let Ok(mut warnings) = self.warnings.lock() {
+                    warnings.push(format!(
+                        "Initializer of field '{}' of behavior '{}' could not be lowered, so it is not initialized in Start: {}",
+                        field.name, udon_struct.name, error
+                    ));
+                }
+                None
+            }
+        }
+    }
+
     /// Get Unity method signature (parameters and return type)
     fn get_unity_method_signature(&self, method_name: &str, udon_struct: &UdonBehaviourStruct) -> GenerationResult<(Vec<GeneratedParameter>, String)> {
         // Find the method in the struct's methods
@@ -666,7 +923,13 @@ impl CodeGenerator {
                         param_type: "Collision".to_string(),
                     }]
                 }
-                _ => Vec::new(),
+                name => match input_event(name) {
+                    Some(event) => vec![
+                        GeneratedParameter { name: "value".to_string(), param_type: event.csharp_value_type.to_string() },
+                        GeneratedParameter { name: "args".to_string(), param_type: INPUT_EVENT_ARGS.to_string() },
+                    ],
+                    None => Vec::new(),
+                },
             };
             
             Ok((parameters, "void".to_string()))
@@ -694,13 +957,144 @@ impl CodeGenerator {
         self.generate_unity_method_declaration_with_params(method_name, &[], "void", body)
     }
 
+    /// Context for lowering Rust code of a behavior
+    fn lowering_context(&self, udon_struct: &UdonBehaviourStruct) -> LoweringContext {
+        let mut context = LoweringContext::for_behavior(udon_struct);
+        for behavior in self.known_behaviors.values() {
+            context.add_behavior(behavior);
+        }
+        context.set_strip_debug_logs(self.strip_debug_logs);
+        context.set_assertion_mode(self.assertion_mode);
+        context.set_safe_refs(self.safe_refs);
+        context
+    }
+
+    /// Remember that the class needs `using VRC.SDK3.Data;` when lowered code names a data container
+    fn record_data_containers(&self, lowerer: &BodyLowerer) {
+        if lowerer.emits_data_containers() {
+            self.emits_data_containers.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Lower the Rust body of a method, if it has one.
+    ///
+    /// Bodies that use constructs without an UdonSharp lowering are replaced by
+    /// a comment naming the failing construct, and `return default;` when the
+    /// method returns a value, so the class still compiles; the stub is recorded
+    /// as a warning. A non-exhaustive enum `match` is an error instead, since
+    /// the unhandled variants would otherwise be silently ignored at runtime.
+    fn lower_method_body(&self, method: &StructMethod, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Option<String>> {
+        let Some(source) = method.body.as_ref() else {
+            return Ok(None);
+        };
+
+        // Methods flattened from an embedded struct resolve `self` against that struct
+        let embedded_owner = udon_struct.embedded_structs.iter()
+            .find(|e| e.method_names.iter().any(|m| e.mangle(m) == method.name))
+            .map(|e| e.field_name.clone());
+        let context = self.lowering_context(udon_struct)
+            .with_self_prefix(embedded_owner)
+            .with_parameters(&method.parameters)
+            .with_return_type(method.return_type.clone());
+        let lowerer = BodyLowerer::new(context);
+
+        let returns_value = method.return_type != RustType::Unit;
+        match lowerer.lower_method_body(source, returns_value) {
+            Ok(body) => {
+                self.record_data_containers(&lowerer);
+                Ok(Some(body))
+            }
+            Err(LoweringError::NonExhaustiveMatch { enum_name, missing }) => Err(GenerationError::NonExhaustiveMatch {
+                method_name: method.name.clone(),
+                enum_name,
+                missing,
+            }),
+            Err(LoweringError::UnboundedLoop) => Err(GenerationError::UnboundedLoop { method_name: method.name.clone() }),
+            Err(error) => {
+                if let Ok(mut warnings) = self.warnings.lock() {
+                    warnings.push(format!(
+                        "Method '{}' of behavior '{}' could not be lowered and was generated as a stub: {}",
+                        method.name, udon_struct.name, error
+                    ));
+                }
+                let mut body = format!("        // Rust body could not be lowered: {}", error);
+                if returns_value {
+                    body.push_str("\n        return default;");
+                }
+                Ok(Some(body))
+            }
+        }
+    }
+
+    /// Generate methods from inherent impls and flattened embedded structs
+    fn generate_behavior_methods(&self, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Vec<GeneratedMethod>> {
+        let mut methods = Vec::new();
+        let trait_methods = udon_struct.trait_impl.as_ref()
+            .map(|t| t.implemented_methods.clone())
+            .unwrap_or_default();
+
+        for method in &udon_struct.methods {
+            if method.is_custom_event() || method.is_network_event() || trait_methods.contains(&method.name) {
+                continue;
+            }
+
+            let method_name = to_pascal_case(&method.name);
+            let mut parameters = Vec::new();
+            for param in &method.parameters {
+                let param_type = self.type_mapper.map_type(&param.param_type)
+                    .map_err(|reason| GenerationError::TypeMappingError {
+                        rust_type: format!("{:?}", param.param_type),
+                        reason,
+                    })?;
+                parameters.push(GeneratedParameter {
+                    name: to_camel_case(&param.name),
+                    param_type,
+                });
+            }
+
+            let return_type = self.type_mapper.map_type(&method.return_type)
+                .map_err(|reason| GenerationError::TypeMappingError {
+                    rust_type: format!("{:?}", method.return_type),
+                    reason,
+                })?;
+            // Public C# methods are callable events, so `pub` only carries over
+            // when plain methods are exposed; see `MethodExposure`
+            let visibility = match (&method.visibility, self.method_exposure) {
+                (Visibility::Public, MethodExposure::PublicMethods) => "public",
+                _ => "private",
+            };
+            let body = self.lower_method_body(method, udon_struct)?
+                .unwrap_or_else(|| format!("        // {} implementation", method.name));
+
+            let param_list = parameters.iter()
+                .map(|p| format!("{} {}", p.param_type, p.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let declaration = format!(
+                "    {} {} {}({})\n    {{\n{}\n    }}",
+                visibility, return_type, method_name, param_list, body
+            );
+
+            methods.push(GeneratedMethod {
+                name: method_name,
+                return_type,
+                parameters,
+                attributes: Vec::new(),
+                body,
+                declaration,
+            });
+        }
+
+        Ok(methods)
+    }
+
     /// Generate custom event handlers
     fn generate_custom_event_handlers(&self, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Vec<CustomEventHandler>> {
         let mut handlers = Vec::new();
 
         for method in &udon_struct.methods {
             if method.is_custom_event() {
-                let handler = self.generate_custom_event_handler(method)?;
+                let handler = self.generate_custom_event_handler(method, udon_struct)?;
                 handlers.push(handler);
             }
         }
@@ -745,6 +1139,57 @@ impl CodeGenerator {
         Ok(helpers)
     }
 
+    /// Generate the handler and the typed caller of every network event
+    ///
+    /// `SendCustomNetworkEvent` invokes a public method by name, so the handler
+    /// is named after the event. The caller `Send{Event}` dispatches the event
+    /// to the target chosen in the attribute.
+    fn generate_network_event_handlers(&self, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Vec<CustomEventHandler>> {
+        let mut handlers = Vec::new();
+
+        for method in &udon_struct.methods {
+            let Some((event_name, target)) = method.network_event() else {
+                continue;
+            };
+            if !method.parameters.is_empty() {
+                return Err(GenerationError::InvalidMethodSignature {
+                    method_name: method.name.clone(),
+                    reason: "network events are invoked by name and cannot take parameters".to_string(),
+                });
+            }
+
+            let body = self.lower_method_body(method, udon_struct)?
+                .unwrap_or_else(|| format!("        // Network event handler for '{}'", event_name));
+            let declaration = format!("    public void {}()\n    {{\n{}\n    }}", event_name, body);
+            handlers.push(CustomEventHandler {
+                event_name: event_name.to_string(),
+                method_name: event_name.to_string(),
+                parameters: Vec::new(),
+                body,
+                declaration,
+            });
+
+            let caller_name = format!("Send{}", event_name);
+            let caller_body = format!(
+                "        SendCustomNetworkEvent(NetworkEventTarget.{}, nameof({}));",
+                target.csharp_name(), event_name
+            );
+            let caller_declaration = format!(
+                "    /// <summary>\n    /// Invoke {} on {} through the network\n    /// </summary>\n    public void {}()\n    {{\n{}\n    }}",
+                event_name, network_target_description(target), caller_name, caller_body
+            );
+            handlers.push(CustomEventHandler {
+                event_name: event_name.to_string(),
+                method_name: caller_name,
+                parameters: Vec::new(),
+                body: caller_body,
+                declaration: caller_declaration,
+            });
+        }
+
+        Ok(handlers)
+    }
+
     /// Generate body for SendCustomEvent helper method
     fn generate_send_custom_event_helper_body(&self) -> String {
         vec![
@@ -776,11 +1221,12 @@ impl CodeGenerator {
     }
 
     /// Generate a single custom event handler
-    fn generate_custom_event_handler(&self, method: &StructMethod) -> GenerationResult<CustomEventHandler> {
+    fn generate_custom_event_handler(&self, method: &StructMethod, udon_struct: &UdonBehaviourStruct) -> GenerationResult<CustomEventHandler> {
         // Extract event name from attributes
         let event_name = method.attributes.iter()
             .find_map(|attr| match attr {
                 MethodAttribute::UdonEvent(name) => Some(name.clone()),
+                _ => None,
             })
             .ok_or_else(|| GenerationError::MissingData {
                 item: "event name".to_string(),
@@ -805,7 +1251,8 @@ impl CodeGenerator {
         }
 
         // Generate method body
-        let body = self.generate_custom_event_body(&event_name, &parameters);
+        let body = self.lower_method_body(method, udon_struct)?
+            .unwrap_or_else(|| self.generate_custom_event_body(&event_name, &parameters));
 
         // Generate complete declaration with UdonSharp compatibility
         let param_list = parameters.iter()
@@ -1125,6 +1572,77 @@ impl CodeGenerator {
         }
     }
 
+    /// Generate the pack/unpack methods of each sync blob, plus the
+    /// serialization events that call them and check the sync layout when
+    /// the behavior does not implement those events itself
+    fn generate_sync_blob_methods(&self, udon_struct: &UdonBehaviourStruct) -> Vec<GeneratedMethod> {
+        let mut methods = Vec::new();
+
+        for blob in &udon_struct.sync_blobs {
+            let blob_field = to_camel_case(&blob.blob_field_name());
+            let method_suffix = to_pascal_case(&blob.blob_field_name());
+            let byte_len = blob.byte_len();
+
+            let mut pack = vec![
+                format!("        if ({} == null || {}.Length != {})", blob_field, blob_field, byte_len),
+                "        {".to_string(),
+                format!("            {} = new byte[{}];", blob_field, byte_len),
+                "        }".to_string(),
+            ];
+            let mut unpack = vec![
+                format!("        if ({} == null || {}.Length != {})", blob_field, blob_field, byte_len),
+                "        {".to_string(),
+                "            return;".to_string(),
+                "        }".to_string(),
+            ];
+            let mut offset = 0;
+            for (field, field_type) in &blob.fields {
+                let field = to_camel_case(field);
+                pack.push(pack_blob_field(&blob_field, offset, &field, field_type));
+                unpack.push(format!("        {} = {};", field, unpack_blob_field(&blob_field, offset, field_type)));
+                offset += blob_type_size(field_type).unwrap_or_default();
+            }
+
+            for (prefix, summary, body) in [
+                ("Pack", format!("Pack the fields of {} into the synced {}", blob.field_name, blob_field), pack),
+                ("Unpack", format!("Restore the fields of {} from the synced {}", blob.field_name, blob_field), unpack),
+            ] {
+                let name = format!("{}{}", prefix, method_suffix);
+                let body = body.join("\n");
+                methods.push(GeneratedMethod {
+                    declaration: format!(
+                        "    /// <summary>\n    /// {}\n    /// </summary>\n    private void {}()\n    {{\n{}\n    }}",
+                        summary, name, body
+                    ),
+                    name,
+                    return_type: "void".to_string(),
+                    parameters: Vec::new(),
+                    attributes: Vec::new(),
+                    body,
+                });
+            }
+        }
+
+        let implemented = udon_struct.trait_impl.as_ref().map(|t| t.implemented_methods.clone()).unwrap_or_default();
+        for (rust_event, csharp_event) in [("on_pre_serialization", "OnPreSerialization"), ("on_post_deserialization", "OnDeserialization")] {
+            if implemented.iter().any(|m| m == rust_event) {
+                continue;
+            }
+            if let Some(body) = serialization_hook_lines(csharp_event, udon_struct) {
+                methods.push(GeneratedMethod {
+                    declaration: self.generate_unity_method_declaration(csharp_event, &body),
+                    name: csharp_event.to_string(),
+                    return_type: "void".to_string(),
+                    parameters: Vec::new(),
+                    attributes: Vec::new(),
+                    body,
+                });
+            }
+        }
+
+        methods
+    }
+
     /// Generate OnDeserialization method body
     fn generate_on_deserialization_body(&self, udon_struct: &UdonBehaviourStruct) -> String {
         let mut body = vec![
@@ -1160,6 +1678,102 @@ impl CodeGenerator {
         body.join("\n")
     }
 
+    /// Generate VRCJson-based `ToJson`/`FromJson` methods for a `#[udon_json]` behavior.
+    ///
+    /// Fields are keyed by their Rust names so the JSON matches what serde
+    /// would produce for the same struct. VRCJson parses every number as a
+    /// double, so numeric fields are cast back on the way in.
+    fn generate_json_methods(&self, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Vec<GeneratedMethod>> {
+        let mut serialize_body = vec!["        DataDictionary data = new DataDictionary();".to_string()];
+        let mut deserialize_body = vec![
+            "        DataToken parsed;".to_string(),
+            "        if (!VRCJson.TryDeserializeFromJson(json, out parsed) || parsed.TokenType != TokenType.DataDictionary)".to_string(),
+            "        {".to_string(),
+            "            return false;".to_string(),
+            "        }".to_string(),
+            "        DataDictionary data = parsed.DataDictionary;".to_string(),
+            "        DataToken value;".to_string(),
+        ];
+
+        for field in &udon_struct.fields {
+            let field_name = to_camel_case(&field.name);
+            let csharp_type = self.type_mapper.map_type(&field.field_type)
+                .map_err(|reason| GenerationError::TypeMappingError {
+                    rust_type: format!("{:?}", field.field_type),
+                    reason,
+                })?;
+            let (token_type, read) = match &field.field_type {
+                RustType::Bool => ("Boolean", "value.Boolean".to_string()),
+                RustType::String => ("String", "value.String".to_string()),
+                RustType::F64 => ("Double", "value.Double".to_string()),
+                RustType::I8 | RustType::I16 | RustType::I32 | RustType::I64
+                | RustType::U8 | RustType::U16 | RustType::U32 | RustType::U64 | RustType::F32 => {
+                    ("Double", format!("({})value.Double", csharp_type))
+                }
+                other => {
+                    return Err(GenerationError::AttributeValidationError {
+                        attribute: "udon_json".to_string(),
+                        reason: format!(
+                            "field '{}' of '{}' has type {:?}; only bool, numeric and String fields can be serialized",
+                            field.name, udon_struct.name, other
+                        ),
+                    });
+                }
+            };
+
+            serialize_body.push(format!("        data.SetValue(\"{}\", {});", field.name, field_name));
+            deserialize_body.push(format!(
+                "        if (data.TryGetValue(\"{}\", TokenType.{}, out value))",
+                field.name, token_type
+            ));
+            deserialize_body.push("        {".to_string());
+            deserialize_body.push(format!("            {} = {};", field_name, read));
+            deserialize_body.push("        }".to_string());
+        }
+
+        serialize_body.extend([
+            "        DataToken json;".to_string(),
+            "        if (VRCJson.TrySerializeToJson(data, JsonExportType.Minify, out json))".to_string(),
+            "        {".to_string(),
+            "            return json.String;".to_string(),
+            "        }".to_string(),
+            format!("        Debug.LogError(\"[{}] Failed to serialize to JSON: \" + json.ToString());", udon_struct.name),
+            "        return null;".to_string(),
+        ]);
+        deserialize_body.push("        return true;".to_string());
+
+        let serialize_body = serialize_body.join("\n");
+        let deserialize_body = deserialize_body.join("\n");
+
+        Ok(vec![
+            GeneratedMethod {
+                name: "ToJson".to_string(),
+                return_type: "string".to_string(),
+                parameters: Vec::new(),
+                attributes: Vec::new(),
+                declaration: format!(
+                    "    /// <summary>\n    /// Serialize the fields of this behavior to JSON with VRCJson\n    /// </summary>\n    public string ToJson()\n    {{\n{}\n    }}",
+                    serialize_body
+                ),
+                body: serialize_body,
+            },
+            GeneratedMethod {
+                name: "FromJson".to_string(),
+                return_type: "bool".to_string(),
+                parameters: vec![GeneratedParameter {
+                    name: "json".to_string(),
+                    param_type: "string".to_string(),
+                }],
+                attributes: Vec::new(),
+                declaration: format!(
+                    "    /// <summary>\n    /// Restore the fields of this behavior from JSON with VRCJson\n    /// </summary>\n    public bool FromJson(string json)\n    {{\n{}\n    }}",
+                    deserialize_body
+                ),
+                body: deserialize_body,
+            },
+        ])
+    }
+
     /// Generate sync field change notification methods
     fn generate_sync_field_change_notifications(&self, udon_struct: &UdonBehaviourStruct) -> GenerationResult<Vec<GeneratedMethod>> {
         let mut notifications = Vec::new();
@@ -1243,16 +1857,14 @@ impl CodeGenerator {
         Ok(notifications)
     }
 
-    /// Generate complete C# class source code
+    /// Generate complete C# class source code, with the methods lowered from
+    /// the behavior's impls in their own section
     fn generate_complete_class_source(
         &self,
-        class_name: &str,
-        using_statements: &[String],
-        class_attributes: &[String],
-        fields: &[GeneratedField],
-        methods: &[GeneratedMethod],
-        custom_events: &[CustomEventHandler],
+        class: &GeneratedClass,
+        behavior_methods: &[GeneratedMethod],
     ) -> GenerationResult<String> {
+        let GeneratedClass { class_name, using_statements, class_attributes, fields, methods, custom_events, .. } = class;
         let mut source = Vec::new();
 
         // Add using statements
@@ -1270,14 +1882,8 @@ impl CodeGenerator {
         source.push(format!("public class {} : UdonSharpBehaviour", class_name));
         source.push("{".to_string());
 
-        // Add fields
-        if !fields.is_empty() {
-            source.push("    // Fields".to_string());
-            for field in fields {
-                source.push(field.declaration.clone());
-                source.push("".to_string()); // Empty line after each field
-            }
-        }
+        // Each section is wrapped in a region so large behaviors stay navigable in Unity
+        push_region(&mut source, "Fields", fields.iter().map(|f| &f.declaration));
 
         // Separate methods by type
         let (unity_methods, remaining_methods): (Vec<&GeneratedMethod>, Vec<&GeneratedMethod>) = methods.iter()
@@ -1286,44 +1892,28 @@ impl CodeGenerator {
         let (sync_methods, notification_methods): (Vec<&GeneratedMethod>, Vec<&GeneratedMethod>) = remaining_methods.iter()
             .partition(|m| self.is_sync_method(&m.name));
 
-        // Add Unity event methods
-        if !unity_methods.is_empty() {
-            source.push("    // Unity Event Methods".to_string());
-            for method in unity_methods {
-                source.push(method.declaration.clone());
-                source.push("".to_string()); // Empty line after each method
-            }
-        }
-
-        // Add network synchronization methods
-        if !sync_methods.is_empty() {
-            source.push("    // Network Synchronization Methods".to_string());
-            for method in sync_methods {
-                source.push(method.declaration.clone());
-                source.push("".to_string()); // Empty line after each method
-            }
-        }
+        push_region(&mut source, "Unity Events", unity_methods.iter().map(|m| &m.declaration));
+        // Methods lowered from inherent impls and embedded structs
+        push_region(&mut source, "Behaviour Methods", behavior_methods.iter().map(|m| &m.declaration));
+        push_region(&mut source, "Network Synchronization", sync_methods.iter().map(|m| &m.declaration));
+        // Network event handlers and generated helpers
+        push_region(&mut source, "Helpers", notification_methods.iter().map(|m| &m.declaration));
+        push_region(&mut source, "Custom Events", custom_events.iter().map(|h| &h.declaration));
 
-        // Add notification and helper methods
-        if !notification_methods.is_empty() {
-            source.push("    // Network Event Handlers and Helpers".to_string());
-            for method in notification_methods {
-                source.push(method.declaration.clone());
-                source.push("".to_string()); // Empty line after each method
-            }
-        }
+        source.push("}".to_string());
 
-        // Add custom event handlers
-        if !custom_events.is_empty() {
-            source.push("    // Custom Event Handlers".to_string());
-            for handler in custom_events {
-                source.push(handler.declaration.clone());
-                source.push("".to_string()); // Empty line after each handler
-            }
+        // Usings stay at file scope; everything after them moves into the namespace
+        if let Some(namespace) = &self.namespace {
+            let class_start = using_statements.len() + 1;
+            let class_source = source.split_off(class_start).join("\n");
+            source.push(format!("namespace {}", namespace));
+            source.push("{".to_string());
+            source.extend(class_source.lines().map(|line| {
+                if line.is_empty() { String::new() } else { format!("    {}", line) }
+            }));
+            source.push("}".to_string());
         }
 
-        source.push("}".to_string());
-
         Ok(source.join("\n"))
     }
 
@@ -1373,6 +1963,66 @@ impl Default for CodeGenerator {
     }
 }
 
+/// Append a `#region` of class members to a class body; empty sections are left out
+fn push_region<'a>(source: &mut Vec<String>, name: &str, declarations: impl IntoIterator<Item = &'a String>) {
+    let mut declarations = declarations.into_iter().peekable();
+    if declarations.peek().is_none() {
+        return;
+    }
+    source.push(format!("    #region {}", name));
+    source.push(String::new());
+    for declaration in declarations {
+        source.push(declaration.clone());
+        source.push(String::new()); // Empty line after each member
+    }
+    source.push("    #endregion".to_string());
+    source.push(String::new());
+}
+
+/// Unity events implemented by `UdonBehaviour` trait methods, other than input events
+const UNITY_EVENTS: &[(&str, &str)] = &[
+    ("start", "Start"),
+    ("update", "Update"),
+    ("fixed_update", "FixedUpdate"),
+    ("late_update", "LateUpdate"),
+    ("on_enable", "OnEnable"),
+    ("on_disable", "OnDisable"),
+    ("on_destroy", "OnDestroy"),
+    ("on_trigger_enter", "OnTriggerEnter"),
+    ("on_trigger_exit", "OnTriggerExit"),
+    ("on_trigger_stay", "OnTriggerStay"),
+    ("on_collision_enter", "OnCollisionEnter"),
+    ("on_collision_exit", "OnCollisionExit"),
+    ("on_collision_stay", "OnCollisionStay"),
+    ("on_player_joined", "OnPlayerJoined"),
+    ("on_player_left", "OnPlayerLeft"),
+    ("on_pickup", "OnPickup"),
+    ("on_drop", "OnDrop"),
+    ("on_pickup_use_down", "OnPickupUseDown"),
+    ("on_pickup_use_up", "OnPickupUseUp"),
+    ("on_station_entered", "OnStationEntered"),
+    ("on_station_exited", "OnStationExited"),
+    ("on_pre_serialization", "OnPreSerialization"),
+    ("on_post_deserialization", "OnDeserialization"),
+];
+
+/// Map a Rust trait method name to the Unity C# event it implements;
+/// `None` if it is not a Unity event method
+pub fn unity_event_name(rust_method: &str) -> Option<String> {
+    UNITY_EVENTS.iter()
+        .find(|(method, _)| *method == rust_method)
+        .map(|(_, event)| event.to_string())
+        .or_else(|| input_event(rust_method).map(|event| event.udon_name.to_string()))
+}
+
+/// Map a Unity C# event name to the Rust trait method handling it
+pub fn unity_event_method(event: &str) -> Option<String> {
+    UNITY_EVENTS.iter()
+        .find(|(_, name)| *name == event)
+        .map(|(method, _)| method.to_string())
+        .or_else(|| INPUT_EVENTS.iter().find(|input| input.udon_name == event).map(|input| input.method.to_string()))
+}
+
 /// Convert snake_case to camelCase
 pub fn to_camel_case(snake_case: &str) -> String {
     let mut result = String::new();
@@ -1402,6 +2052,139 @@ pub fn to_pascal_case(snake_case: &str) -> String {
     }
 }
 
+/// Describe the receivers of a network event for generated doc comments
+fn network_target_description(target: NetworkEventTarget) -> &'static str {
+    match target {
+        NetworkEventTarget::All => "all clients",
+        NetworkEventTarget::Others => "all other clients",
+        NetworkEventTarget::Owner => "the owner",
+    }
+}
+
+/// Generate the bounds-checked `{Enum}FromInt` switch used for integer to enum conversions
+fn generate_int_enum_conversion(behavior_name: &str, int_enum: &IntEnum) -> GeneratedMethod {
+    let name = int_enum.conversion_method_name();
+    let mut body = vec!["        switch (value)".to_string(), "        {".to_string()];
+    for (variant, discriminant) in &int_enum.variants {
+        body.push(format!("            case {}: return {}.{};", discriminant, int_enum.name, variant));
+    }
+    body.extend([
+        "            default:".to_string(),
+        format!(
+            "                Debug.LogWarning(\"[{}] \" + value + \" is not a valid {}; using {}.{}\");",
+            behavior_name, int_enum.name, int_enum.name, int_enum.default_variant
+        ),
+        format!("                return {}.{};", int_enum.name, int_enum.default_variant),
+        "        }".to_string(),
+    ]);
+    let body = body.join("\n");
+
+    GeneratedMethod {
+        declaration: format!(
+            "    /// <summary>\n    /// Convert an integer to {}, falling back to {} for values matching no variant\n    /// </summary>\n    private {} {}(long value)\n    {{\n{}\n    }}",
+            int_enum.name, int_enum.default_variant, int_enum.name, name, body
+        ),
+        name,
+        return_type: int_enum.name.clone(),
+        parameters: vec![GeneratedParameter { name: "value".to_string(), param_type: "long".to_string() }],
+        attributes: Vec::new(),
+        body,
+    }
+}
+
+/// Declare the `{field}UnassignedLogged` flags that null-reference guards in
+/// the generated methods set
+fn unassigned_reference_flags(
+    udon_struct: &UdonBehaviourStruct,
+    methods: &[GeneratedMethod],
+    behavior_methods: &[GeneratedMethod],
+    custom_events: &[CustomEventHandler],
+) -> Vec<GeneratedField> {
+    let declarations: Vec<&str> = methods.iter().chain(behavior_methods)
+        .map(|m| m.declaration.as_str())
+        .chain(custom_events.iter().map(|e| e.declaration.as_str()))
+        .collect();
+
+    udon_struct.fields.iter()
+        .map(|field| unassigned_flag_name(&to_camel_case(&field.name)))
+        .filter(|flag| declarations.iter().any(|declaration| declaration.contains(flag.as_str())))
+        .map(|flag| GeneratedField {
+            declaration: format!("    private bool {};", flag),
+            name: flag,
+            field_type: "bool".to_string(),
+            visibility: "private".to_string(),
+            attributes: Vec::new(),
+            default_value: None,
+        })
+        .collect()
+}
+
+/// Generated statements leading a serialization event: the sync layout
+/// stamp or check, then the blob pack or unpack calls
+fn serialization_hook_lines(csharp_event: &str, udon_struct: &UdonBehaviourStruct) -> Option<String> {
+    let layout = SyncLayout::for_behavior(udon_struct).map(|layout| match csharp_event {
+        "OnPreSerialization" => layout.stamp_lines(),
+        "OnDeserialization" => layout.check_lines(&udon_struct.name),
+        _ => Vec::new(),
+    });
+    let lines: Vec<String> = layout.into_iter()
+        .flatten()
+        .chain(sync_blob_calls(csharp_event, udon_struct))
+        .collect();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Pack or unpack calls for the sync blobs of a behavior, for the serialization events
+fn sync_blob_calls(csharp_event: &str, udon_struct: &UdonBehaviourStruct) -> Option<String> {
+    let prefix = match csharp_event {
+        "OnPreSerialization" => "Pack",
+        "OnDeserialization" => "Unpack",
+        _ => return None,
+    };
+    if udon_struct.sync_blobs.is_empty() {
+        return None;
+    }
+    Some(udon_struct.sync_blobs.iter()
+        .map(|blob| format!("        {}{}();", prefix, to_pascal_case(&blob.blob_field_name())))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// C# statements writing a blob field at a byte offset
+fn pack_blob_field(blob: &str, offset: usize, field: &str, field_type: &RustType) -> String {
+    match field_type {
+        RustType::Bool => format!("        {}[{}] = (byte)({} ? 1 : 0);", blob, offset, field),
+        RustType::U8 => format!("        {}[{}] = {};", blob, offset, field),
+        RustType::I8 => format!("        {}[{}] = (byte){};", blob, offset, field),
+        _ => format!(
+            "        System.Array.Copy(System.BitConverter.GetBytes({}), 0, {}, {}, {});",
+            field, blob, offset, blob_type_size(field_type).unwrap_or_default()
+        ),
+    }
+}
+
+/// C# expression reading a blob field at a byte offset
+fn unpack_blob_field(blob: &str, offset: usize, field_type: &RustType) -> String {
+    let converter = match field_type {
+        RustType::Bool => return format!("{}[{}] != 0", blob, offset),
+        RustType::U8 => return format!("{}[{}]", blob, offset),
+        RustType::I8 => return format!("(sbyte){}[{}]", blob, offset),
+        RustType::I16 => "ToInt16",
+        RustType::U16 => "ToUInt16",
+        RustType::I32 => "ToInt32",
+        RustType::U32 => "ToUInt32",
+        RustType::I64 => "ToInt64",
+        RustType::U64 => "ToUInt64",
+        RustType::F32 => "ToSingle",
+        _ => "ToDouble",
+    };
+    format!("System.BitConverter.{}({}, {})", converter, blob, offset)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1450,13 +2233,170 @@ mod tests {
     }
 
     #[test]
-    fn test_complete_class_generation() {
+    fn test_serialize_field_emits_private_serialized_field() {
         let mut generator = CodeGenerator::new();
-        
-        // Create a test struct
-        let mut test_struct = UdonBehaviourStruct::new("TestBehavior".to_string());
-        
-        // Add a field
+        let mut test_struct = UdonBehaviourStruct::new("Lobby".to_string());
+        let mut field = StructField::new("max_players".to_string(), RustType::I32);
+        field.set_visibility(Visibility::Public);
+        field.add_attribute(FieldAttribute::UdonSync);
+        field.add_attribute(FieldAttribute::UdonSerializeField);
+        field.set_default_value("8".to_string());
+        test_struct.add_field(field);
+
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("start".to_string());
+        trait_impl.check_completeness();
+        test_struct.set_trait_impl(trait_impl);
+
+        let generated = generator.generate_behavior_class(&test_struct).unwrap();
+        assert_eq!(generated.fields[0].visibility, "private");
+        assert!(generated.fields[0].attributes.contains(&"[SerializeField]".to_string()));
+        assert!(generated.source_code.contains("private int maxPlayers = 8;"));
+        // The inspector value must not be overwritten in Start
+        assert!(!generated.source_code.contains("        maxPlayers = 8;"));
+
+        let mut conflicting = StructField::new("speed".to_string(), RustType::F32);
+        conflicting.add_attribute(FieldAttribute::UdonPublic);
+        conflicting.add_attribute(FieldAttribute::UdonSerializeField);
+        assert!(generator.generate_single_field(&conflicting).is_err());
+    }
+
+    #[test]
+    fn test_class_sections_are_wrapped_in_regions_in_order() {
+        let mut scoreboard = UdonBehaviourStruct::new("Scoreboard".to_string());
+        scoreboard.add_field(StructField::new("score".to_string(), RustType::I32));
+        let mut reset = StructMethod::new("reset".to_string(), RustType::Unit);
+        reset.set_body("{ self.score = 0; }".to_string());
+        scoreboard.add_method(reset);
+        let mut on_goal = StructMethod::new("on_goal".to_string(), RustType::Unit);
+        on_goal.add_attribute(MethodAttribute::UdonEvent("OnGoal".to_string()));
+        scoreboard.add_method(on_goal);
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("start".to_string());
+        trait_impl.check_completeness();
+        scoreboard.set_trait_impl(trait_impl);
+
+        let generated = CodeGenerator::new().generate_behavior_class(&scoreboard).unwrap();
+        let source = &generated.source_code;
+        let regions: Vec<&str> = source.lines()
+            .filter_map(|line| line.trim().strip_prefix("#region "))
+            .collect();
+        assert_eq!(regions, vec!["Fields", "Unity Events", "Behaviour Methods", "Custom Events"], "{}", source);
+        assert_eq!(source.matches("    #endregion").count(), regions.len());
+
+        let field = source.find("int score").unwrap();
+        assert!(source.find("    #region Fields").unwrap() < field);
+        assert!(field < source.find("    #endregion").unwrap());
+    }
+
+    #[test]
+    fn test_udon_ref_field_lowers_to_udon_behaviour_calls() {
+        let mut game_manager = UdonBehaviourStruct::new("GameManager".to_string());
+        let mut round_number = StructField::new("round_number".to_string(), RustType::I32);
+        round_number.add_attribute(FieldAttribute::UdonPublic);
+        game_manager.add_field(round_number);
+        game_manager.add_method(StructMethod::new("reset_round".to_string(), RustType::Unit));
+
+        let mut scoreboard = UdonBehaviourStruct::new("Scoreboard".to_string());
+        let mut reference = StructField::new("game_manager".to_string(), RustType::UdonRef("GameManager".to_string()));
+        reference.add_attribute(FieldAttribute::UdonPublic);
+        scoreboard.add_field(reference);
+        let mut finish = StructMethod::new("finish".to_string(), RustType::Unit);
+        finish.set_body("{ if self.game_manager.is_valid() { self.game_manager.set(\"round_number\", 0); self.game_manager.call(\"reset_round\"); } }".to_string());
+        scoreboard.add_method(finish);
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("start".to_string());
+        trait_impl.check_completeness();
+        scoreboard.set_trait_impl(trait_impl);
+
+        let mut generator = CodeGenerator::new();
+        generator.register_behaviors(&[game_manager]);
+        let generated = generator.generate_behavior_class(&scoreboard).unwrap();
+        assert!(generated.source_code.contains("public UdonBehaviour gameManager = null;"), "{}", generated.source_code);
+        assert!(generated.using_statements.contains(&"using VRC.Udon;".to_string()));
+        assert!(generated.source_code.contains("if (gameManager != null)"));
+        assert!(generated.source_code.contains("gameManager.SetProgramVariable(\"roundNumber\", 0);"));
+        assert!(generated.source_code.contains("gameManager.SendCustomEvent(\"ResetRound\");"));
+
+        // Names are checked against the referenced behavior
+        let mut typo = StructMethod::new("finish".to_string(), RustType::Unit);
+        typo.set_body("{ self.game_manager.call(\"reset_rounds\"); }".to_string());
+        scoreboard.methods = vec![typo];
+        let generated = generator.generate_behavior_class(&scoreboard).unwrap();
+        assert!(generated.source_code.contains("behavior 'GameManager' has no method 'reset_rounds'"));
+    }
+
+    #[test]
+    fn test_method_exposure_controls_public_methods() {
+        let mut test_struct = UdonBehaviourStruct::new("Counter".to_string());
+        let mut add_points = StructMethod::new("add_points".to_string(), RustType::Unit);
+        add_points.set_visibility(Visibility::Public);
+        test_struct.add_method(add_points);
+        test_struct.add_method(StructMethod::new("recount".to_string(), RustType::Unit));
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("start".to_string());
+        trait_impl.check_completeness();
+        test_struct.set_trait_impl(trait_impl);
+
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&test_struct).unwrap();
+        assert!(generated.source_code.contains("    public void AddPoints()"));
+        assert!(generated.source_code.contains("    private void Recount()"));
+
+        generator.set_method_exposure(MethodExposure::UdonEventsOnly);
+        let generated = generator.generate_behavior_class(&test_struct).unwrap();
+        assert!(generated.source_code.contains("    private void AddPoints()"));
+        assert!(generated.source_code.contains("    private void Recount()"));
+    }
+
+    #[test]
+    fn test_namespace_wraps_classes_and_keeps_references() {
+        let mut scoreboard = UdonBehaviourStruct::new("Scoreboard".to_string());
+        scoreboard.add_method(StructMethod::new("refresh".to_string(), RustType::Unit));
+        let mut game_manager = UdonBehaviourStruct::new("GameManager".to_string());
+        let mut reference = StructField::new("scoreboard".to_string(), RustType::Custom("Scoreboard".to_string()));
+        reference.add_attribute(FieldAttribute::UdonPublic);
+        game_manager.add_field(reference);
+        for behavior in [&mut scoreboard, &mut game_manager] {
+            let mut trait_impl = UdonBehaviourTraitImpl::new();
+            trait_impl.add_method("start".to_string());
+            trait_impl.check_completeness();
+            behavior.set_trait_impl(trait_impl);
+        }
+
+        let mut generator = CodeGenerator::new();
+        generator.set_namespace(Some("MyWorld.Game".to_string()));
+        generator.register_behaviors(&[scoreboard.clone(), game_manager.clone()]);
+
+        for behavior in [&scoreboard, &game_manager] {
+            let generated = generator.generate_behavior_class(behavior).unwrap();
+            assert_eq!(generated.namespace.as_deref(), Some("MyWorld.Game"));
+            let source = &generated.source_code;
+            let namespace_at = source.find("namespace MyWorld.Game\n{").unwrap();
+            assert!(source.find("using UdonSharp;").unwrap() < namespace_at, "{}", source);
+            assert!(source.contains(&format!("    public class {} : UdonSharpBehaviour\n    {{", behavior.name)));
+            assert!(source.ends_with("    }\n}"));
+        }
+
+        // Both classes share the namespace, so the reference stays unqualified
+        let generated = generator.generate_behavior_class(&game_manager).unwrap();
+        assert!(generated.source_code.contains("        public Scoreboard scoreboard"), "{}", generated.source_code);
+
+        let config = crate::config::UdonSharpConfig {
+            namespace: Some("MyWorld.2Game".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_complete_class_generation() {
+        let mut generator = CodeGenerator::new();
+        
+        // Create a test struct
+        let mut test_struct = UdonBehaviourStruct::new("TestBehavior".to_string());
+        
+        // Add a field
         let mut field = StructField::new("player_count".to_string(), RustType::I32);
         field.add_attribute(FieldAttribute::UdonPublic);
         test_struct.add_field(field);
@@ -1479,6 +2419,395 @@ mod tests {
         assert!(generated.source_code.contains("public override void Start()"));
     }
 
+    #[test]
+    fn test_data_list_field_maps_to_sdk_type() {
+        let mut generator = CodeGenerator::new();
+        let mut test_struct = UdonBehaviourStruct::new("SpawnQueue".to_string());
+        test_struct.add_field(StructField::new("spawns".to_string(), RustType::Custom("DataList".to_string())));
+
+        let mut start = StructMethod::new("start".to_string(), RustType::Unit);
+        start.set_body("{ self.spawns.add(3); let next = self.spawns.get(0).as_int(); }".to_string());
+        test_struct.add_method(start);
+
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("start".to_string());
+        trait_impl.check_completeness();
+        test_struct.set_trait_impl(trait_impl);
+
+        let generated = generator.generate_behavior_class(&test_struct).unwrap();
+
+        assert!(generated.using_statements.contains(&"using VRC.SDK3.Data;".to_string()));
+        assert!(generated.source_code.contains("private DataList spawns;"));
+        assert!(generated.source_code.contains("        spawns = new DataList();"));
+        assert!(generated.source_code.contains("spawns.Add(3);"));
+        assert!(generated.source_code.contains("var next = spawns[0].Int;"));
+    }
+
+    #[test]
+    fn test_data_using_follows_lowered_data_containers() {
+        let behavior_with_start = |body: &str| {
+            let mut test_struct = UdonBehaviourStruct::new("Scoreboard".to_string());
+            let mut start = StructMethod::new("start".to_string(), RustType::Unit);
+            start.set_body(body.to_string());
+            test_struct.add_method(start);
+            let mut trait_impl = UdonBehaviourTraitImpl::new();
+            trait_impl.add_method("start".to_string());
+            test_struct.set_trait_impl(trait_impl);
+            CodeGenerator::new().generate_behavior_class(&test_struct).unwrap()
+        };
+
+        let generated = behavior_with_start("{ let scores = DataDictionary::new(); }");
+        assert!(generated.source_code.contains("var scores = new DataDictionary();"), "{}", generated.source_code);
+        assert!(generated.using_statements.contains(&"using VRC.SDK3.Data;".to_string()));
+
+        // Mentioning a container by name does not make the class use one
+        let generated = behavior_with_start("{ log::info!(\"DataList is empty\"); }");
+        assert!(!generated.using_statements.contains(&"using VRC.SDK3.Data;".to_string()), "{:?}", generated.using_statements);
+    }
+
+    #[test]
+    fn test_udon_json_struct_emits_vrc_json_methods() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                #[udon_json]
+                pub struct RoundState {
+                    round: i32,
+                    leader: String,
+                    started: bool,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for RoundState {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        assert!(behaviors[0].is_json_serializable());
+
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&behaviors[0]).unwrap();
+
+        assert!(generated.using_statements.contains(&"using VRC.SDK3.Data;".to_string()));
+        let to_json = generated.methods.iter().find(|m| m.name == "ToJson").unwrap();
+        assert!(to_json.body.contains("        data.SetValue(\"round\", round);"));
+        assert!(to_json.body.contains("VRCJson.TrySerializeToJson(data, JsonExportType.Minify, out json)"));
+        // On failure the token holds the error, not a string
+        assert!(to_json.body.contains("        Debug.LogError(\"[RoundState] Failed to serialize to JSON: \" + json.ToString());\n        return null;"), "{}", to_json.body);
+
+        let from_json = generated.methods.iter().find(|m| m.name == "FromJson").unwrap();
+        assert!(from_json.body.contains("VRCJson.TryDeserializeFromJson(json, out parsed)"));
+        assert!(from_json.body.contains("if (data.TryGetValue(\"round\", TokenType.Double, out value))"));
+        assert!(from_json.body.contains("            round = (int)value.Double;"));
+        assert!(from_json.body.contains("            leader = value.String;"));
+        assert!(generated.source_code.contains("public bool FromJson(string json)"));
+        assert!(!generated.source_code.contains("serde"));
+    }
+
+    #[test]
+    fn test_pooled_allocations_draw_from_pool_allocated_in_start() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                #[udon_pool(size = 16)]
+                pub struct EventBus {
+                    emitted: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl EventBus {
+                    pub fn emit(&mut self) {
+                        let record = DataDictionary::new();
+                        let scratch = DataList::new();
+                        self.emitted += 1;
+                        drop(record);
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for EventBus {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&behaviors[0]).unwrap();
+
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains("        _dataDictionaryPool = new DataDictionary[16];"));
+        assert!(start.body.contains("            _dataDictionaryPool[poolIndex] = new DataDictionary();"));
+
+        let emit = generated.methods.iter().find(|m| m.name == "Emit").unwrap();
+        assert!(emit.body.contains("var record = AcquireDataDictionary();"));
+        assert!(emit.body.contains("var scratch = new DataList();"));
+        assert!(emit.body.contains("ReleaseDataDictionary(record);"));
+        assert!(!emit.body.contains("new DataDictionary()"));
+
+        assert!(generated.fields.iter().any(|f| f.declaration == "    private DataDictionary[] _dataDictionaryPool;"));
+        assert!(generated.source_code.contains("private DataDictionary AcquireDataDictionary()"));
+        assert!(generated.using_statements.contains(&"using VRC.SDK3.Data;".to_string()));
+    }
+
+    #[test]
+    fn test_udon_forward_sends_event_to_targets_from_its_handler() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                #[udon_forward(event = "OnPlayerJoined", to = ["UIController", "GameLogic"])]
+                #[udon_forward(event = "OnPlayerLeft", to = ["UIController"])]
+                pub struct Lobby {
+                    players: i32,
+                    game_logic: UdonRef<GameLogic>,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Lobby {
+                    fn start(&mut self) {}
+                    fn on_player_joined(&mut self, player: VRCPlayerApi) {
+                        self.players += 1;
+                    }
+                }
+            },
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct UIController {
+                    shown: bool,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for UIController {
+                    fn start(&mut self) {}
+                }
+            },
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct GameLogic {
+                    round: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for GameLogic {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let behaviors = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&items).unwrap();
+        let lobby = behaviors.iter().find(|b| b.name == "Lobby").unwrap();
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(lobby).unwrap();
+
+        // The forwards follow the handler's own body, in the order the targets are listed
+        let joined = generated.methods.iter().find(|m| m.name == "OnPlayerJoined").unwrap();
+        let lines: Vec<&str> = joined.body.lines().collect();
+        let forward_start = lines.iter().position(|l| *l == "        // Forward OnPlayerJoined to UIController, GameLogic").unwrap();
+        assert!(lines[..forward_start].contains(&"        players += 1;"), "{}", joined.body);
+        assert_eq!(lines[forward_start + 1..], [
+            "        if (uiController != null)",
+            "        {",
+            "            uiController.SendCustomEvent(\"OnPlayerJoined\");",
+            "        }",
+            "        if (gameLogic != null)",
+            "        {",
+            "            gameLogic.SendCustomEvent(\"OnPlayerJoined\");",
+            "        }",
+        ]);
+
+        // A forwarded event the behavior does not handle gets a handler of its own
+        let left = generated.methods.iter().find(|m| m.name == "OnPlayerLeft").unwrap();
+        assert!(left.declaration.contains("public override void OnPlayerLeft(VRCPlayerApi player)"), "{}", left.declaration);
+        assert!(left.body.contains("uiController.SendCustomEvent(\"OnPlayerLeft\");"));
+        assert!(generated.source_code.contains("public UdonBehaviour uiController = null;"));
+        assert_eq!(generated.fields.iter().filter(|f| f.name == "gameLogic").count(), 1);
+
+        let typo: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                #[udon_forward(event = "OnPlayerJoined", to = ["UiController"])]
+                pub struct Lobby {
+                    players: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Lobby {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+        let error = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&typo).unwrap_err();
+        assert!(error.to_string().contains("forwards 'OnPlayerJoined' to 'UiController', which is not a behavior"), "{}", error);
+    }
+
+    #[test]
+    fn test_second_pool_on_a_behavior_is_an_error() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                #[udon_pool(size = 16)]
+                #[udon_pool(DataList, size = 4)]
+                pub struct EventBus {
+                    emitted: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for EventBus {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let error = analyzer.analyze_module(&items).unwrap_err();
+        assert!(error.to_string().contains("more than one #[udon_pool]"));
+    }
+
+    #[test]
+    fn test_quantized_vector3_syncs_as_packed_integer() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Ball {
+                    #[udon_sync]
+                    #[udon_quantize(bits = 16, min = -100.0, max = 100.0)]
+                    pub position: Vector3,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Ball {
+                    fn start(&mut self) {}
+
+                    fn update(&mut self) {
+                        self.position = self.position * 0.5;
+                    }
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&behaviors[0]).unwrap();
+
+        let source = &generated.source_code;
+        assert!(source.contains("    [UdonSynced] private ulong positionPacked;"));
+        assert!(!source.contains("[UdonSynced]\n    public Vector3 position"));
+        assert!(source.contains("    public Vector3 position\n    {"));
+        assert!(source.contains(
+            "get => new Vector3(-100f + (positionPacked & 0xFFFF) * 0.0030518044f, \
+             -100f + ((positionPacked >> 16) & 0xFFFF) * 0.0030518044f, \
+             -100f + ((positionPacked >> 32) & 0xFFFF) * 0.0030518044f);"
+        ));
+        assert!(source.contains(
+            "set => positionPacked = (ulong)Mathf.RoundToInt((Mathf.Clamp(value.x, -100f, 100f) + 100f) * 327.675f) | "
+        ));
+        assert!(source.contains("((ulong)Mathf.RoundToInt((Mathf.Clamp(value.z, -100f, 100f) + 100f) * 327.675f) << 32);"));
+        assert!(source.contains("values are off by at most 0.0015259"));
+    }
+
+    #[test]
+    fn test_network_event_generates_handler_and_caller() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Cannon {
+                    shots: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl Cannon {
+                    #[udon_network_event(target = "All")]
+                    pub fn fire_cannon(&mut self) {
+                        self.shots += 1;
+                    }
+
+                    #[udon_network_event(target = "Owner", name = "ReloadCannon")]
+                    pub fn reload(&mut self) {
+                        self.shots = 0;
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Cannon {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let fire = behaviors[0].methods.iter().find(|m| m.name == "fire_cannon").unwrap();
+        assert_eq!(fire.network_event(), Some(("FireCannon", NetworkEventTarget::All)));
+
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&behaviors[0]).unwrap();
+        assert!(generated.using_statements.contains(&"using VRC.Udon.Common.Interfaces;".to_string()));
+
+        let handler = generated.custom_events.iter().find(|e| e.method_name == "FireCannon").unwrap();
+        assert!(handler.declaration.starts_with("    public void FireCannon()"));
+        assert!(handler.body.contains("shots += 1;"));
+
+        let caller = generated.custom_events.iter().find(|e| e.method_name == "SendFireCannon").unwrap();
+        assert_eq!(caller.body, "        SendCustomNetworkEvent(NetworkEventTarget.All, nameof(FireCannon));");
+
+        let reload_caller = generated.custom_events.iter().find(|e| e.method_name == "SendReloadCannon").unwrap();
+        assert!(reload_caller.body.contains("NetworkEventTarget.Owner, nameof(ReloadCannon)"));
+        assert!(generated.source_code.contains("public void ReloadCannon()"));
+        assert!(!generated.source_code.contains("public void Reload()"));
+    }
+
+    fn network_events_behavior(events: &[&str]) -> UdonBehaviourStruct {
+        let methods: String = events.iter()
+            .map(|event| format!("#[udon_network_event(target = \"All\")] pub fn {}(&mut self) {{}}\n", event))
+            .collect();
+        let source = format!(
+            "#[derive(UdonBehaviour)] pub struct Arena {{ round: i32 }}
+            impl Arena {{ {} }}
+            impl UdonBehaviour for Arena {{ fn start(&mut self) {{}} }}",
+            methods
+        );
+        let file: syn::File = syn::parse_str(&source).unwrap();
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        analyzer.analyze_module(&file.items).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_network_event_ids_are_stable_across_builds() {
+        let first = CodeGenerator::new()
+            .generate_behavior_class(&network_events_behavior(&["start_round", "end_round", "award_point"]))
+            .unwrap();
+        let second = CodeGenerator::new()
+            .generate_behavior_class(&network_events_behavior(&["award_point", "start_round", "end_round"]))
+            .unwrap();
+
+        let ids = |class: &GeneratedClass| -> Vec<String> {
+            class.fields.iter().filter(|f| f.name.ends_with("EventId")).map(|f| f.declaration.clone()).collect()
+        };
+        assert_eq!(ids(&first), ids(&second));
+        assert_eq!(ids(&first), vec![
+            "    public const int AwardPointEventId = 0;",
+            "    public const int EndRoundEventId = 1;",
+            "    public const int StartRoundEventId = 2;",
+        ]);
+
+        // A build seeded with the previous table keeps existing IDs when an event is added
+        let mut generator = CodeGenerator::new();
+        let mut previous = EventIdTable::new();
+        previous.assign("Arena", ["AwardPoint", "EndRound", "StartRound"]);
+        generator.set_event_ids(previous);
+        let third = generator
+            .generate_behavior_class(&network_events_behavior(&["award_point", "countdown", "end_round", "start_round"]))
+            .unwrap();
+        assert!(third.source_code.contains("public const int StartRoundEventId = 2;"));
+        assert!(third.source_code.contains("public const int CountdownEventId = 3;"));
+        assert_eq!(generator.event_ids().id("Arena", "Countdown"), Some(3));
+    }
+
     #[test]
     fn test_case_conversion() {
         assert_eq!(to_camel_case("test_field"), "testField");
@@ -1486,4 +2815,835 @@ mod tests {
         assert_eq!(to_pascal_case("test_method"), "TestMethod");
         assert_eq!(to_pascal_case("on_player_joined"), "OnPlayerJoined");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_embedded_struct_method_call_rewrite() {
+        let mut generator = CodeGenerator::new();
+        let mut test_struct = UdonBehaviourStruct::new("GameManager".to_string());
+
+        let mut embedded = EmbeddedStruct::new("scoring_system".to_string(), "ScoringSystem".to_string());
+        embedded.field_names = vec!["total_score".to_string(), "multiplier".to_string()];
+        embedded.method_names = vec!["add_score".to_string()];
+        test_struct.add_field(StructField::new(embedded.mangle("total_score"), RustType::I32));
+        test_struct.add_field(StructField::new(embedded.mangle("multiplier"), RustType::I32));
+
+        let mut add_score = StructMethod::new(embedded.mangle("add_score"), RustType::Unit);
+        add_score.add_parameter(MethodParameter::new("points".to_string(), RustType::I32));
+        add_score.set_body("{ self.total_score += points * self.multiplier; }".to_string());
+        test_struct.add_method(add_score);
+        test_struct.add_embedded_struct(embedded);
+
+        let mut start = StructMethod::new("start".to_string(), RustType::Unit);
+        start.set_body("{ self.scoring_system.add_score(10); }".to_string());
+        test_struct.add_method(start);
+
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("start".to_string());
+        trait_impl.check_completeness();
+        test_struct.set_trait_impl(trait_impl);
+
+        let generated = generator.generate_behavior_class(&test_struct).unwrap();
+
+        assert!(generated.source_code.contains("private int scoringSystemTotalScore;"));
+        assert!(generated.source_code.contains("private int scoringSystemMultiplier;"));
+        assert!(generated.source_code.contains("private void ScoringSystemAddScore(int points)"));
+        assert!(generated.source_code.contains("scoringSystemTotalScore += points * scoringSystemMultiplier;"));
+        assert!(generated.source_code.contains("        ScoringSystemAddScore(10);"));
+    }
+
+    #[test]
+    fn test_struct_update_syntax_initializes_remaining_fields() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Scoreboard {
+                    max_score: i32,
+                    score: i32,
+                    paused: bool,
+                    title: String,
+                    history: Vec<i32>,
+                    target: GameObject,
+                }
+            },
+            syn::parse_quote! {
+                impl Scoreboard {
+                    pub fn new() -> Self {
+                        Self {
+                            max_score: 10,
+                            ..Default::default()
+                        }
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Scoreboard {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        for initializer in [
+            "maxScore = 10;",
+            "score = 0;",
+            "paused = false;",
+            "title = \"\";",
+            "history = new int[0];",
+        ] {
+            assert!(start.body.contains(initializer), "missing `{}` in {}", initializer, start.body);
+        }
+        // Reference fields already start out null
+        assert!(!start.body.contains("target = null;"), "{}", start.body);
+    }
+
+    #[test]
+    fn test_sync_blob_struct_syncs_as_packed_byte_array() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[udon_sync_blob]
+                pub struct PlayerState {
+                    health: i32,
+                    alive: bool,
+                }
+            },
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct PlayerTracker {
+                    #[udon_sync]
+                    pub state: PlayerState,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for PlayerTracker {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let tracker = &behaviors[0];
+        assert_eq!(tracker.sync_blobs[0].byte_len(), 5);
+        assert_eq!(tracker.get_sync_fields().iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), vec!["state_blob"]);
+
+        let generated = CodeGenerator::new().generate_behavior_class(tracker).unwrap();
+        assert!(generated.source_code.contains("[UdonSynced]"));
+        assert!(generated.source_code.contains("byte[] stateBlob"), "{}", generated.source_code);
+
+        let pack = generated.methods.iter().find(|m| m.name == "PackStateBlob").unwrap();
+        assert!(pack.body.contains("System.Array.Copy(System.BitConverter.GetBytes(stateHealth), 0, stateBlob, 0, 4);"), "{}", pack.body);
+        assert!(pack.body.contains("stateBlob[4] = (byte)(stateAlive ? 1 : 0);"));
+
+        let unpack = generated.methods.iter().find(|m| m.name == "UnpackStateBlob").unwrap();
+        assert!(unpack.body.contains("stateHealth = System.BitConverter.ToInt32(stateBlob, 0);"), "{}", unpack.body);
+        assert!(unpack.body.contains("stateAlive = stateBlob[4] != 0;"));
+
+        let pre_serialization = generated.methods.iter().find(|m| m.name == "OnPreSerialization").unwrap();
+        assert!(pre_serialization.body.ends_with("\n        PackStateBlob();"), "{}", pre_serialization.body);
+        let deserialization = generated.methods.iter().find(|m| m.name == "OnDeserialization").unwrap();
+        assert!(deserialization.body.ends_with("\n        UnpackStateBlob();"), "{}", deserialization.body);
+    }
+
+    #[test]
+    fn test_constant_field_default_folded_into_start() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct RoundTimer {
+                    round_duration: i32,
+                    elapsed: f32,
+                    deadline: f32,
+                }
+            },
+            syn::parse_quote! {
+                impl RoundTimer {
+                    pub fn new() -> Self {
+                        let now = time::time();
+                        Self {
+                            round_duration: 60 * 5,
+                            elapsed: time::time(),
+                            deadline: now + 300.0,
+                        }
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for RoundTimer {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        assert!(!behaviors[0].methods.iter().any(|m| m.name == "new"));
+
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&behaviors[0]).unwrap();
+
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains("        roundDuration = 300;"));
+        assert!(start.body.contains("        elapsed = Time.time;"), "{}", start.body);
+        assert!(!start.body.contains("deadline ="));
+        assert!(generated.source_code.contains("private int roundDuration;"));
+        assert_eq!(
+            analyzer.get_warnings().last().unwrap(),
+            "Behavior 'RoundTimer' initializes 'deadline' in its constructor with a value that is not constant and cannot be evaluated in Start, so it is not initialized; use a constant initial value or assign it in `start`"
+        );
+    }
+
+    #[test]
+    fn test_field_defaults_get_a_start_when_the_behavior_has_none() {
+        let mut spinner = UdonBehaviourStruct::new("Spinner".to_string());
+        let mut speed = StructField::new("speed".to_string(), RustType::F32);
+        speed.set_default_value("45.0f".to_string());
+        spinner.add_field(speed);
+        let mut trait_impl = UdonBehaviourTraitImpl::new();
+        trait_impl.add_method("update".to_string());
+        spinner.set_trait_impl(trait_impl);
+
+        let generated = CodeGenerator::new().generate_behavior_class(&spinner).unwrap();
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains("        speed = 45.0f;"), "{}", start.body);
+        assert!(generated.methods.iter().any(|m| m.name == "Update"));
+    }
+
+    fn game_state_behavior() -> UdonBehaviourStruct {
+        let file: syn::File = syn::parse_str(
+            "#[derive(UdonBehaviour)] pub struct Match { current_state: i32 }
+            #[derive(Default)] pub enum GameState { #[default] Waiting, Countdown, Playing = 5 }
+            impl Match {
+                pub fn restore(&mut self) { let state = GameState::from_i32(self.current_state); }
+                pub fn restore_cast(&mut self) { let state = self.current_state as GameState; }
+            }
+            impl UdonBehaviour for Match { fn start(&mut self) {} }",
+        ).unwrap();
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        analyzer.analyze_module(&file.items).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_int_to_enum_conversion_maps_valid_values() {
+        let behavior = game_state_behavior();
+        assert_eq!(behavior.int_enums.len(), 1);
+        let game_state = &behavior.int_enums[0];
+        assert_eq!(game_state.variants, vec![
+            ("Waiting".to_string(), 0),
+            ("Countdown".to_string(), 1),
+            ("Playing".to_string(), 5),
+        ]);
+        assert_eq!(game_state.variant_for(5), "Playing");
+
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let restore = generated.methods.iter().find(|m| m.name == "Restore").unwrap();
+        assert!(restore.body.contains("state = GameStateFromInt(currentState);"), "{}", restore.body);
+        let restore_cast = generated.methods.iter().find(|m| m.name == "RestoreCast").unwrap();
+        assert!(restore_cast.body.contains("state = GameStateFromInt(currentState);"), "{}", restore_cast.body);
+
+        let conversion = generated.methods.iter().find(|m| m.name == "GameStateFromInt").unwrap();
+        assert!(conversion.declaration.contains("private GameState GameStateFromInt(long value)"));
+        assert!(conversion.body.contains("case 1: return GameState.Countdown;"));
+        assert!(conversion.body.contains("case 5: return GameState.Playing;"));
+    }
+
+    #[test]
+    fn test_payload_enum_is_held_as_a_tagged_record() {
+        let file: syn::File = syn::parse_str(
+            "#[derive(UdonBehaviour)] pub struct Scoreboard { last_event: GameEvent, total: i32 }
+            pub enum GameEvent { PlayerScored { player_name: String, score: i32 }, RoundEnded(f32) }
+            impl Scoreboard {
+                pub fn record(&mut self, update: GameEvent) {
+                    match update {
+                        GameEvent::PlayerScored { score, .. } => self.total += score,
+                        GameEvent::RoundEnded(_) => self.total = 0,
+                    }
+                    self.last_event = update;
+                }
+            }
+            impl UdonBehaviour for Scoreboard { fn start(&mut self) {} }",
+        ).unwrap();
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behavior = analyzer.analyze_module(&file.items).unwrap().remove(0);
+        assert_eq!(behavior.payload_enums.len(), 1);
+        assert_eq!(behavior.fields[0].field_type, RustType::Custom("DataDictionary".to_string()));
+
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        assert!(generated.source_code.contains("private DataDictionary lastEvent"), "{}", generated.source_code);
+        let record = generated.methods.iter().find(|m| m.name == "Record").unwrap();
+        assert!(record.declaration.contains("Record(DataDictionary update)"), "{}", record.declaration);
+        assert!(record.body.contains("switch (update[\"tag\"].String)"), "{}", record.body);
+        let constructor = generated.methods.iter().find(|m| m.name == "NewGameEventRoundEnded").unwrap();
+        assert!(constructor.declaration.contains("private DataDictionary NewGameEventRoundEnded(float item0)"));
+        assert!(constructor.body.contains("tagged[\"0\"] = item0;"));
+    }
+
+    #[test]
+    fn test_slice_parameter_becomes_an_array_parameter() {
+        let file: syn::File = syn::parse_str(
+            "#[derive(UdonBehaviour)] pub struct Lights { lamps: Vec<GameObject>, lit: i32 }
+            impl Lights {
+                fn count_lit(&self, targets: &[GameObject]) -> i32 {
+                    let mut lit = 0;
+                    for target in targets.iter() {
+                        if target.active_self() { lit += 1; }
+                    }
+                    lit
+                }
+                pub fn refresh(&mut self) { self.lit = self.count_lit(&self.lamps); }
+            }
+            impl UdonBehaviour for Lights { fn start(&mut self) {} }",
+        ).unwrap();
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behavior = analyzer.analyze_module(&file.items).unwrap().remove(0);
+        let count_lit = behavior.methods.iter().find(|m| m.name == "count_lit").unwrap();
+        assert_eq!(count_lit.parameters[0].param_type, RustType::Vec(Box::new(RustType::GameObject)));
+
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let count_lit = generated.methods.iter().find(|m| m.name == "CountLit").unwrap();
+        assert!(count_lit.declaration.contains("int CountLit(GameObject[] targets)"), "{}", count_lit.declaration);
+        assert!(count_lit.body.contains("foreach (var target in targets)"), "{}", count_lit.body);
+        let refresh = generated.methods.iter().find(|m| m.name == "Refresh").unwrap();
+        assert!(refresh.body.contains("lit = CountLit(lamps);"), "{}", refresh.body);
+    }
+
+    #[test]
+    fn test_int_to_enum_conversion_falls_back_to_default_variant() {
+        let behavior = game_state_behavior();
+        assert_eq!(behavior.int_enums[0].variant_for(3), "Waiting");
+        assert_eq!(behavior.int_enums[0].variant_for(-1), "Waiting");
+
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let conversion = generated.methods.iter().find(|m| m.name == "GameStateFromInt").unwrap();
+        assert!(conversion.body.contains(
+            "Debug.LogWarning(\"[Match] \" + value + \" is not a valid GameState; using GameState.Waiting\");"
+        ), "{}", conversion.body);
+        assert!(conversion.body.contains("default:\n                Debug.LogWarning"));
+        assert!(conversion.body.contains("return GameState.Waiting;"));
+        assert!(generated.source_code.contains("GameStateFromInt(long value)"));
+    }
+
+    fn match_behavior(arms: &str) -> UdonBehaviourStruct {
+        let source = format!(
+            "#[derive(UdonBehaviour)] pub struct Match {{ current_state: i32, countdown: f32 }}
+            pub enum GameState {{ Waiting, Countdown, Playing }}
+            impl Match {{
+                pub fn tick(&mut self) {{
+                    let state = GameState::from_i32(self.current_state);
+                    match state {{ {} }}
+                }}
+            }}
+            impl UdonBehaviour for Match {{ fn start(&mut self) {{}} }}",
+            arms
+        );
+        let file: syn::File = syn::parse_str(&source).unwrap();
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        analyzer.analyze_module(&file.items).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_exhaustive_enum_match_lowers_to_switch() {
+        let behavior = match_behavior(
+            "GameState::Waiting => {}
+            GameState::Countdown => { self.countdown -= 1.0; }
+            GameState::Playing => self.countdown = 0.0,",
+        );
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let tick = generated.methods.iter().find(|m| m.name == "Tick").unwrap();
+        assert!(tick.body.contains("switch (state)"), "{}", tick.body);
+        assert!(tick.body.contains("case GameState.Waiting:"));
+        assert!(tick.body.contains("case GameState.Countdown:\n            {\n                countdown -= 1.0f;\n                break;"), "{}", tick.body);
+        assert!(tick.body.contains("case GameState.Playing:"));
+        assert!(!tick.body.contains("could not be lowered"));
+    }
+
+    #[test]
+    fn test_unlowerable_body_is_a_stub_reported_as_a_warning() {
+        let source = "#[derive(UdonBehaviour)] pub struct Scorer { bonus: i32 }
+            impl Scorer {
+                pub fn score(&self) -> i32 {
+                    fn doubled(value: i32) -> i32 { value * 2 }
+                    doubled(self.bonus)
+                }
+            }
+            impl UdonBehaviour for Scorer { fn start(&mut self) {} }";
+        let file: syn::File = syn::parse_str(source).unwrap();
+        let behavior = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&file.items).unwrap().remove(0);
+
+        let mut generator = CodeGenerator::new();
+        let generated = generator.generate_behavior_class(&behavior).unwrap();
+        let score = generated.methods.iter().find(|m| m.name == "Score").unwrap();
+        assert!(score.body.starts_with("        // Rust body could not be lowered: Unsupported statement"), "{}", score.body);
+        assert!(score.body.ends_with("\n        return default;"), "{}", score.body);
+
+        let warnings = generator.get_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Method 'score' of behavior 'Scorer' could not be lowered and was generated as a stub: "), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn test_ui_component_fields_import_unity_ui() {
+        let source = "#[derive(UdonBehaviour)] pub struct Hud { #[udon_public] label: Option<Text>, #[udon_public] start_button: Option<Button> }
+            impl UdonBehaviour for Hud { fn start(&mut self) {} }";
+        let file: syn::File = syn::parse_str(source).unwrap();
+        let behavior = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&file.items).unwrap().remove(0);
+
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let code = &generated.source_code;
+        assert_eq!(code.matches("using UnityEngine.UI;").count(), 1, "{}", code);
+        assert!(code.contains("public Text label = null;"), "{}", code);
+        assert!(code.contains("public Button startButton = null;"), "{}", code);
+    }
+
+    #[test]
+    fn test_loop_without_an_exit_is_an_error() {
+        let source = "#[derive(UdonBehaviour)] pub struct Spinner { count: i32 }
+            impl Spinner {
+                pub fn spin(&mut self) {
+                    loop { self.count += 1; }
+                }
+            }
+            impl UdonBehaviour for Spinner { fn start(&mut self) {} }";
+        let file: syn::File = syn::parse_str(source).unwrap();
+        let behavior = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&file.items).unwrap().remove(0);
+
+        let error = CodeGenerator::new().generate_behavior_class(&behavior).unwrap_err();
+        assert!(matches!(&error, GenerationError::UnboundedLoop { method_name } if method_name == "spin"), "{}", error);
+    }
+
+    #[test]
+    fn test_non_exhaustive_enum_match_is_an_error() {
+        let behavior = match_behavior(
+            "GameState::Waiting => {}
+            GameState::Playing => self.countdown = 0.0,",
+        );
+        let error = CodeGenerator::new().generate_behavior_class(&behavior).unwrap_err();
+        assert!(matches!(
+            &error,
+            GenerationError::NonExhaustiveMatch { enum_name, missing, .. }
+                if enum_name == "GameState" && missing == &vec!["Countdown".to_string()]
+        ), "{}", error);
+
+        let with_wildcard = match_behavior(
+            "GameState::Waiting => {}
+            _ => self.countdown = 0.0,",
+        );
+        let generated = CodeGenerator::new().generate_behavior_class(&with_wildcard).unwrap();
+        let tick = generated.methods.iter().find(|m| m.name == "Tick").unwrap();
+        assert!(tick.body.contains("default:"), "{}", tick.body);
+    }
+
+    fn transition_behavior(arms: &str) -> UdonBehaviourStruct {
+        let source = format!(
+            "#[derive(UdonBehaviour)] pub struct Match {{ countdown: f32 }}
+            pub enum GameState {{ Waiting, Countdown, Playing }}
+            impl Match {{
+                pub fn can_transition(&self, current: GameState, target: GameState) -> bool {{
+                    match (current, target) {{ {} }}
+                }}
+            }}
+            impl UdonBehaviour for Match {{ fn start(&mut self) {{}} }}",
+            arms
+        );
+        let file: syn::File = syn::parse_str(&source).unwrap();
+        crate::struct_analyzer::StructAnalyzer::new().analyze_module(&file.items).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_tuple_match_lowers_to_guarded_conditionals_in_arm_order() {
+        let behavior = transition_behavior(
+            "(GameState::Waiting, GameState::Countdown) => true,
+            (GameState::Countdown, GameState::Playing) | (GameState::Countdown, GameState::Waiting) => true,
+            (GameState::Playing, next) if next != GameState::Playing => self.countdown <= 0.0,
+            _ => false,",
+        );
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let method = generated.methods.iter().find(|m| m.name == "CanTransition").unwrap();
+        assert_eq!(method.body, [
+            "        if (current == GameState.Waiting && target == GameState.Countdown)",
+            "        {",
+            "            return true;",
+            "        }",
+            "        else if ((current == GameState.Countdown && target == GameState.Playing) || (current == GameState.Countdown && target == GameState.Waiting))",
+            "        {",
+            "            return true;",
+            "        }",
+            "        else if (current == GameState.Playing && target != GameState.Playing)",
+            "        {",
+            "            var next = target;",
+            "            return countdown <= 0.0f;",
+            "        }",
+            "        else",
+            "        {",
+            "            return false;",
+            "        }",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_tuple_match_must_cover_every_variant_combination() {
+        let behavior = transition_behavior(
+            "(GameState::Waiting, _) => true,
+            (GameState::Countdown, GameState::Playing) => true,
+            (GameState::Playing, GameState::Waiting) if self.countdown <= 0.0 => true,
+            (GameState::Playing, _) => false,",
+        );
+        let error = CodeGenerator::new().generate_behavior_class(&behavior).unwrap_err();
+        assert!(matches!(
+            &error,
+            GenerationError::NonExhaustiveMatch { enum_name, missing, .. }
+                if enum_name == "(GameState, GameState)"
+                    && missing == &vec!["(Countdown, Waiting)".to_string(), "(Countdown, Countdown)".to_string()]
+        ), "{}", error);
+
+        // Bindings cover every variant of their position like `_`
+        let behavior = transition_behavior(
+            "(GameState::Countdown, GameState::Playing) => true,
+            (GameState::Countdown, _) => false,
+            (state, _) => state == GameState::Waiting,",
+        );
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        let method = generated.methods.iter().find(|m| m.name == "CanTransition").unwrap();
+        assert!(method.body.contains("        else\n        {\n            var state = current;\n            return state == GameState.Waiting;"), "{}", method.body);
+    }
+
+    #[test]
+    fn test_default_impl_allocates_collection_backing_storage() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct FrameStats {
+                    frame_times: Vec<f32>,
+                    samples_by_name: HashMap<String, i32>,
+                    label: String,
+                }
+            },
+            syn::parse_quote! {
+                impl Default for FrameStats {
+                    fn default() -> Self {
+                        Self {
+                            frame_times: Vec::with_capacity(60),
+                            samples_by_name: HashMap::new(),
+                            label: String::new(),
+                        }
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for FrameStats {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains("frameTimes = new float[60];"), "{}", start.body);
+        assert!(start.body.contains("samplesByName = new DataDictionary();"), "{}", start.body);
+        assert!(generated.source_code.contains("private DataDictionary samplesByName;"), "{}", generated.source_code);
+        assert!(generated.source_code.contains("using VRC.SDK3.Data;"));
+    }
+
+    #[test]
+    fn test_public_method_returning_vec_lowers_to_array() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct ScoringSystem {
+                    player_count: i32,
+                    best_score: i32,
+                    last_score: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for ScoringSystem {
+                    fn start(&mut self) {}
+                }
+            },
+            syn::parse_quote! {
+                impl ScoringSystem {
+                    pub fn get_top_scores(&self) -> Vec<i32> {
+                        if self.player_count == 0 {
+                            return Vec::new();
+                        }
+                        vec![self.best_score, self.last_score]
+                    }
+
+                    pub fn get_no_scores(&self) -> Vec<i32> {
+                        vec![]
+                    }
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let top_scores = generated.methods.iter().find(|m| m.name == "GetTopScores").unwrap();
+        assert_eq!(top_scores.return_type, "int[]");
+        assert!(top_scores.body.contains("return new int[0];"), "{}", top_scores.body);
+        assert!(top_scores.body.contains("return new int[] { bestScore, lastScore };"), "{}", top_scores.body);
+
+        let no_scores = generated.methods.iter().find(|m| m.name == "GetNoScores").unwrap();
+        assert_eq!(no_scores.body.trim(), "return new int[0];");
+    }
+
+    #[test]
+    fn test_public_vec_of_game_objects_is_an_inspector_array() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct PhysicsController {
+                    #[udon_public]
+                    physics_objects: Vec<GameObject>,
+                    spawned: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for PhysicsController {
+                    fn start(&mut self) {
+                        if self.physics_objects.is_empty() {
+                            return;
+                        }
+                        let first = self.physics_objects[0].clone();
+                        self.physics_objects.push(first);
+                        self.spawned = self.physics_objects.len() as i32;
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl PhysicsController {
+                    pub fn reset(&mut self) {
+                        self.physics_objects.clear();
+                    }
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let field = generated.fields.iter().find(|f| f.name == "physicsObjects").unwrap();
+        assert_eq!(field.field_type, "GameObject[]");
+        assert_eq!(field.visibility, "public");
+        assert!(field.attributes.contains(&"[SerializeField]".to_string()), "{:?}", field.attributes);
+
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains("if ((physicsObjects.Length == 0))"), "{}", start.body);
+        assert!(start.body.contains("var physicsObjectsGrown = new GameObject[physicsObjects.Length + 1];"), "{}", start.body);
+        assert!(start.body.contains("System.Array.Copy(physicsObjects, physicsObjectsGrown, physicsObjects.Length);"));
+        assert!(start.body.contains("physicsObjectsGrown[physicsObjects.Length] = first;"));
+        assert!(start.body.contains("physicsObjects = physicsObjectsGrown;"));
+
+        let reset = generated.methods.iter().find(|m| m.name == "Reset").unwrap();
+        assert_eq!(reset.body.trim(), "physicsObjects = new GameObject[0];");
+    }
+
+    #[test]
+    fn test_input_jump_override_emits_udon_input_event() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct JetpackController {
+                    thrusting: bool,
+                    strafe: f32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for JetpackController {
+                    fn start(&mut self) {}
+
+                    fn on_input_jump(&mut self, value: bool, args: UdonInputEventArgs) {
+                        self.thrusting = value;
+                    }
+
+                    fn on_input_move_horizontal(&mut self, value: f32, args: UdonInputEventArgs) {
+                        self.strafe = value;
+                    }
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let jump = generated.methods.iter().find(|m| m.name == "InputJump").unwrap();
+        assert!(jump.declaration.starts_with("    public override void InputJump(bool value, UdonInputEventArgs args)"), "{}", jump.declaration);
+        assert_eq!(jump.body.trim(), "thrusting = value;");
+
+        let strafe = generated.methods.iter().find(|m| m.name == "InputMoveHorizontal").unwrap();
+        assert!(strafe.declaration.contains("InputMoveHorizontal(float value, UdonInputEventArgs args)"), "{}", strafe.declaration);
+        assert!(generated.using_statements.contains(&"using VRC.Udon.Common;".to_string()));
+    }
+
+    #[test]
+    fn test_safe_refs_guard_unwrapped_public_option_reference() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct DoorController {
+                    #[udon_public]
+                    door: Option<GameObject>,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for DoorController {
+                    fn start(&mut self) {}
+                }
+            },
+            syn::parse_quote! {
+                impl DoorController {
+                    pub fn open(&mut self) {
+                        self.door.unwrap().set_active(false);
+                    }
+
+                    pub fn is_wired(&self) -> bool {
+                        self.door.is_some()
+                    }
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+
+        let unguarded = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+        let open = unguarded.methods.iter().find(|m| m.name == "Open").unwrap();
+        assert_eq!(open.body.trim(), "door.SetActive(false);");
+        assert!(!unguarded.source_code.contains("doorUnassignedLogged"));
+
+        let mut generator = CodeGenerator::new();
+        generator.set_safe_refs(true);
+        let guarded = generator.generate_behavior_class(&behaviors[0]).unwrap();
+        let open = guarded.methods.iter().find(|m| m.name == "Open").unwrap();
+        let expected = [
+            "        if (door == null)",
+            "        {",
+            "            if (!doorUnassignedLogged)",
+            "            {",
+            "                Debug.LogError(\"Unassigned reference 'door' on DoorController\");",
+            "                doorUnassignedLogged = true;",
+            "            }",
+            "            return;",
+            "        }",
+            "        door.SetActive(false);",
+        ].join("\n");
+        assert_eq!(open.body, expected);
+        assert!(guarded.fields.iter().any(|f| f.declaration == "    private bool doorUnassignedLogged;"));
+
+        let is_wired = guarded.methods.iter().find(|m| m.name == "IsWired").unwrap();
+        assert_eq!(is_wired.body.trim(), "return (door != null);");
+    }
+
+    #[test]
+    fn test_vec_deque_event_queue_is_drained_from_its_backing_array() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct GameEventSystem {
+                    event_queue: VecDeque<i32>,
+                    processed: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for GameEventSystem {
+                    fn start(&mut self) {
+                        self.event_queue.push_back(1);
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl GameEventSystem {
+                    pub fn process_events(&mut self) {
+                        while let Some(event) = self.event_queue.pop_front() {
+                            self.processed += event;
+                        }
+                    }
+                }
+            },
+        ];
+
+        let mut analyzer = crate::struct_analyzer::StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let field = generated.fields.iter().find(|f| f.name == "eventQueue").unwrap();
+        assert_eq!(field.field_type, "int[]");
+
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains("eventQueueGrown[eventQueue.Length] = 1;"), "{}", start.body);
+
+        let process = generated.methods.iter().find(|m| m.name == "ProcessEvents").unwrap();
+        assert!(process.body.contains("while (eventQueue.Length > 0)"), "{}", process.body);
+        assert!(process.body.contains("var @event = eventQueue[0];"), "{}", process.body);
+        assert!(process.body.contains("System.Array.Copy(eventQueue, 1, eventQueueShrunk, 0, eventQueueShrunk.Length);"));
+        assert!(process.body.contains("processed += @event;"), "{}", process.body);
+    }
+
+    #[test]
+    fn test_failed_init_disables_the_behavior_before_update_runs() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Scoreboard {
+                    #[udon_public]
+                    pub display: Option<GameObject>,
+                    refreshes: i32,
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Scoreboard {
+                    fn start(&mut self) {
+                        if let Err(reason) = self.bind_references() {
+                            log::error!("Scoreboard disabled: {}", reason);
+                            self.disable_behavior();
+                        }
+                    }
+
+                    fn update(&mut self) {
+                        self.refreshes += 1;
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl Scoreboard {
+                    fn bind_references(&mut self) -> Result<(), String> {
+                        if self.display.is_none() {
+                            return Err("display is not assigned".to_string());
+                        }
+                        Ok(())
+                    }
+                }
+            },
+        ];
+
+        let behaviors = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&items).unwrap();
+        let generated = CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+
+        let bind = generated.methods.iter().find(|m| m.name == "BindReferences").unwrap();
+        assert_eq!(bind.return_type, "string");
+        assert!(bind.body.contains("if ((display == null))"), "{}", bind.body);
+        assert!(bind.body.contains("return \"display is not assigned\";"), "{}", bind.body);
+        assert!(bind.body.ends_with("return null;"), "{}", bind.body);
+
+        // Unity stops calling Update once the behavior is disabled
+        let start = generated.methods.iter().find(|m| m.name == "Start").unwrap();
+        assert!(start.body.contains(&[
+            "        var reason = BindReferences();",
+            "        if (reason != null)",
+            "        {",
+            "            Debug.LogError(string.Format(\"Scoreboard disabled: {0}\", reason));",
+            "            enabled = false;",
+            "        }",
+        ].join("\n")), "{}", start.body);
+    }
+}
@@ -784,6 +784,11 @@ mod tests {
                 behavior_files: std::collections::HashMap::new(),
                 shared_runtime_file: None,
                 shared_runtime: None,
+                hierarchy_prefab: None,
+                field_schemas: std::collections::BTreeMap::new(),
+                behavior_manifest: crate::behavior_manifest::BehaviorManifest::new(&[], "world"),
+                package_name: "world".to_string(),
+                event_ids: crate::event_ids::EventIdTable::new(),
                 communication_code: crate::standard_multi_behavior_integration::CommunicationCodeResult {
                     behavior_communications: std::collections::HashMap::new(),
                     total_communication_calls: 0,
@@ -798,8 +803,11 @@ mod tests {
                     has_networking: false,
                     dependency_count: 0,
                     circular_dependencies_detected: false,
+                    excluded_editor_only: vec![],
+                    main_behavior: None,
                 },
                 diagnostics: vec![],
+                behaviors: vec![],
             },
             &behaviors,
         );
@@ -3,8 +3,11 @@
 //! This module provides configuration options for controlling the Rust to UdonSharp
 //! compilation process.
 
+use crate::lint_levels::{validate_lint_levels, LintLevel};
+use crate::struct_analyzer::DEFAULT_PUBLIC_VARIABLE_LIMIT;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 /// Configuration error types
 #[derive(Debug, thiserror::Error)]
@@ -25,10 +28,24 @@ pub enum ConfigError {
     ConflictingSettings(String),
 }
 
+/// Read the package name from the Cargo.toml of a project
+pub fn load_package_name(project_dir: &Path) -> Result<String, ConfigError> {
+    let path = project_dir.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&path)
+        .map_err(|e| ConfigError::IoError(format!("Failed to read {:?}: {}", path, e)))?;
+    let manifest: toml::Value = toml::from_str(&manifest)
+        .map_err(|e| ConfigError::ParseError(format!("Failed to parse Cargo.toml: {}", e)))?;
+    manifest.get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| ConfigError::MissingField("package.name".to_string()))
+}
+
 /// Configuration for UdonSharp compilation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UdonSharpConfig {
-    /// Optional namespace for generated UdonSharp classes
+    /// Optional namespace wrapping generated behaviors and the SharedRuntime
     pub namespace: Option<String>,
     
     /// Synchronization mode for UdonSharp networking
@@ -52,6 +69,11 @@ pub struct UdonSharpConfig {
     /// Output directory for generated files
     pub output_directory: Option<String>,
     
+    /// Name of the crate being compiled, which seeds the GUIDs of generated
+    /// assets so that two crates imported into one Unity project never share one
+    #[serde(default)]
+    pub package_name: String,
+    
     /// Number of parallel jobs for compilation
     pub parallel_jobs: Option<usize>,
     
@@ -67,10 +89,78 @@ pub struct UdonSharpConfig {
     /// Capture test output
     pub capture_test_output: bool,
     
+    /// Write the intermediate WASM module to this path before it is transpiled
+    #[serde(default)]
+    pub emit_wasm: Option<PathBuf>,
+    
+    /// Drop `log::debug!` and `log::trace!` calls from generated code
+    #[serde(default)]
+    pub strip_debug_logs: bool,
+    
+    /// How `assert!`, `assert_eq!`, `assert_ne!` and their `debug_` forms are lowered
+    #[serde(default)]
+    pub assertions: AssertionMode,
+    
+    /// Which Rust methods become public C# methods that Unity can call as events
+    #[serde(default)]
+    pub method_exposure: MethodExposure,
+    
+    /// Inline private single-expression helpers of at most this many tokens
+    /// into their only caller; `None` keeps every helper as its own method
+    #[serde(default)]
+    pub inline_helper_max_tokens: Option<usize>,
+    
+    /// Level of each analysis lint by name; unlisted lints warn
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintLevel>,
+    
+    /// Guard unwraps of public `Option` references with a null check that
+    /// logs the unassigned reference once; always on with `generate_debug_info`
+    #[serde(default)]
+    pub safe_refs: bool,
+    
+    /// Compile and emit only these behaviors; empty compiles every behavior.
+    /// The rest of the crate is still analyzed so cross-behavior references resolve.
+    #[serde(default)]
+    pub selected_behaviors: Vec<String>,
+    
+    /// Emit an extra behavior showing the fields marked `#[udon_debug]` in-world
+    #[serde(default)]
+    pub emit_debug_overlay: bool,
+    
+    /// Write a JSON Schema of each behavior's `#[udon_public]` fields next to its script
+    #[serde(default)]
+    pub emit_field_schemas: bool,
+    
+    /// Name of the GameObject holding the SharedRuntime, which the generated
+    /// code looks up and the hierarchy prefab creates; defaults to `SharedRuntime`
+    #[serde(default = "default_shared_runtime_name")]
+    pub shared_runtime_name: String,
+    
+    /// Public variables a single behavior may expose before compilation fails;
+    /// analysis warns from an eighth below it
+    #[serde(default = "default_public_variable_limit")]
+    pub public_variable_limit: usize,
+    
+    /// Language the behaviors are emitted in
+    #[serde(default)]
+    pub target: ConversionTarget,
+    
     /// Multi-behavior compilation settings
     pub multi_behavior: MultiBehaviorSettings,
 }
 
+/// Default name of the GameObject holding the SharedRuntime
+pub const DEFAULT_SHARED_RUNTIME_NAME: &str = "SharedRuntime";
+
+fn default_shared_runtime_name() -> String {
+    DEFAULT_SHARED_RUNTIME_NAME.to_string()
+}
+
+fn default_public_variable_limit() -> usize {
+    DEFAULT_PUBLIC_VARIABLE_LIMIT
+}
+
 impl Default for UdonSharpConfig {
     fn default() -> Self {
         Self {
@@ -82,11 +172,25 @@ impl Default for UdonSharpConfig {
             asmdef_scan_directories: Vec::new(),
             custom_binding_rules: None,
             output_directory: None,
+            package_name: String::new(),
             parallel_jobs: None,
             check_only: false,
             test_mode: false,
             test_filter: None,
             capture_test_output: true,
+            emit_wasm: None,
+            strip_debug_logs: false,
+            assertions: AssertionMode::default(),
+            method_exposure: MethodExposure::default(),
+            inline_helper_max_tokens: None,
+            lints: BTreeMap::new(),
+            safe_refs: false,
+            selected_behaviors: Vec::new(),
+            emit_debug_overlay: false,
+            emit_field_schemas: false,
+            shared_runtime_name: default_shared_runtime_name(),
+            public_variable_limit: default_public_variable_limit(),
+            target: ConversionTarget::UdonSharp,
             multi_behavior: MultiBehaviorSettings::default(),
         }
     }
@@ -135,6 +239,9 @@ impl UdonSharpConfig {
         // Validate multi-behavior settings
         self.multi_behavior.validate()?;
         
+        // Validate lint names
+        validate_lint_levels(&self.lints)?;
+        
         // Validate target UdonSharp version
         if !self.is_valid_udonsharp_version(&self.target_udonsharp_version) {
             return Err(ConfigError::InvalidValue(format!(
@@ -157,6 +264,32 @@ impl UdonSharpConfig {
             }
         }
         
+        // Validate namespace
+        if let Some(namespace) = &self.namespace {
+            if !namespace.split('.').all(crate::multi_behavior::is_valid_csharp_identifier) {
+                return Err(ConfigError::InvalidValue(format!(
+                    "Invalid namespace: {}. Expected dot-separated C# identifiers, e.g. MyWorld.Game",
+                    namespace
+                )));
+            }
+        }
+        
+        // Validate SharedRuntime object name: it is embedded in a C# string literal
+        // and `GameObject.Find` reads '/' as a path separator
+        if self.shared_runtime_name.trim().is_empty() || self.shared_runtime_name.contains(['/', '"', '\\']) {
+            return Err(ConfigError::InvalidValue(format!(
+                "Invalid shared_runtime_name: {:?}. Expected a non-empty GameObject name without '/', '\"' or '\\'",
+                self.shared_runtime_name
+            )));
+        }
+        
+        // Validate public variable limit
+        if self.public_variable_limit == 0 {
+            return Err(ConfigError::InvalidValue(
+                "public_variable_limit must be greater than 0".to_string()
+            ));
+        }
+        
         // Validate output directory
         if let Some(output_dir) = &self.output_directory {
             if output_dir.is_empty() {
@@ -188,6 +321,39 @@ pub enum UdonSyncMode {
     Continuous,
 }
 
+/// Which Rust methods are exposed to Unity as callable events
+///
+/// UdonSharp makes every public C# method an event that UI buttons, animation
+/// events and `SendCustomEvent` can invoke by name. `#[udon_event]` methods and
+/// Unity event methods are always public; this setting decides what happens
+/// to the remaining `pub fn`s. Private Rust methods are always private in C#.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MethodExposure {
+    /// Every `pub fn` becomes a public C# method and thus a callable event
+    #[default]
+    PublicMethods,
+    /// Only `#[udon_event]` methods are callable; other `pub fn`s become private
+    UdonEventsOnly,
+}
+
+/// How assertions in method bodies are lowered
+///
+/// Udon has no panics, so a failed assertion is reported with
+/// `Debug.LogError` and execution carries on unless the behavior disables
+/// itself. Release builds strip assertions, as Rust does for `debug_assert!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssertionMode {
+    /// Check the condition and log an error when it does not hold
+    #[default]
+    Log,
+    /// Log the failure, disable the behavior and leave the method
+    LogAndDisable,
+    /// Drop assertions from the generated code
+    Strip,
+}
+
 /// Multi-behavior compilation settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiBehaviorSettings {
@@ -211,6 +377,15 @@ pub struct MultiBehaviorSettings {
     
     /// Initialization order management
     pub initialization_order: InitializationOrderSettings,
+    
+    /// Layout of the generated C# files
+    #[serde(default)]
+    pub output_layout: OutputLayout,
+    
+    /// Generate a `_Bootstrap` behavior that locates the SharedRuntime and
+    /// behaviors, assigns their references and starts them in order
+    #[serde(default)]
+    pub generate_bootstrap: bool,
 }
 
 impl Default for MultiBehaviorSettings {
@@ -223,6 +398,8 @@ impl Default for MultiBehaviorSettings {
             generate_prefabs: true,
             prefab_settings: PrefabGenerationSettings::default(),
             initialization_order: InitializationOrderSettings::default(),
+            output_layout: OutputLayout::PerBehavior,
+            generate_bootstrap: false,
         }
     }
 }
@@ -256,6 +433,38 @@ impl MultiBehaviorSettings {
     }
 }
 
+/// Output language of the behaviors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConversionTarget {
+    /// UdonSharp C# source, compiled to Udon by the UdonSharp compiler in Unity
+    #[default]
+    UdonSharp,
+    /// Raw Udon assembly (`.uasm`) lowered from the Rust source, for projects
+    /// that do not use UdonSharp; see `udon_assembly_lowering` for the subset
+    UdonAssembly,
+}
+
+impl ConversionTarget {
+    /// File extension of the generated behavior files
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            ConversionTarget::UdonSharp => "cs",
+            ConversionTarget::UdonAssembly => "uasm",
+        }
+    }
+}
+
+/// Layout of the generated C# files
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OutputLayout {
+    /// One `.cs` file per behavior, plus `SharedRuntime.cs`
+    #[default]
+    PerBehavior,
+    /// A single file with the given name containing the code of every behavior and
+    /// the SharedRuntime; each behavior keeps a stub file for its program asset
+    Bundled(String),
+}
+
 /// Naming convention for behavior classes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BehaviorNamingConvention {
@@ -332,6 +541,11 @@ pub struct PrefabGenerationSettings {
     /// Include example scene setup
     pub include_example_scene: bool,
     
+    /// Generate one prefab holding every behavior as a child GameObject, with
+    /// GameObject references between them pre-wired
+    #[serde(default)]
+    pub generate_hierarchy_prefab: bool,
+    
     /// Prefab output directory
     pub output_directory: Option<String>,
 }
@@ -343,6 +557,7 @@ impl Default for PrefabGenerationSettings {
             generate_master_prefab: true,
             auto_setup_references: true,
             include_example_scene: false,
+            generate_hierarchy_prefab: false,
             output_directory: None,
         }
     }
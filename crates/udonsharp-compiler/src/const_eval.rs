@@ -0,0 +1,298 @@
+//! Compile-time evaluation of simple constant expressions
+//!
+//! Field defaults written as `60 * 5`, `MAX_PLAYERS - 1` or a call to a
+//! small `const fn` are folded to literals during analysis so the generated
+//! C# initializes fields with constants instead of recomputing them at runtime.
+
+use crate::multi_behavior::RustType;
+use syn::{BinOp, Expr, Lit, UnOp};
+use std::collections::HashMap;
+
+/// Maximum `const fn` call depth, guarding against recursive definitions
+const MAX_CALL_DEPTH: usize = 16;
+
+/// A value produced by constant evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+impl ConstValue {
+    /// Format the value as a C# literal for a field of the given type
+    pub fn to_csharp_literal(&self, rust_type: &RustType) -> Option<String> {
+        match (self, rust_type) {
+            (ConstValue::Int(value), RustType::F32) => Some(format!("{}f", value)),
+            (ConstValue::Int(value), RustType::F64) => Some(format!("{}.0", value)),
+            (ConstValue::Int(value), _) if is_integer_type(rust_type) => Some(value.to_string()),
+            (ConstValue::Float(value), RustType::F32) => Some(format!("{}f", format_float(*value))),
+            (ConstValue::Float(value), RustType::F64) => Some(format_float(*value)),
+            (ConstValue::Bool(value), RustType::Bool) => Some(value.to_string()),
+            (ConstValue::Str(value), RustType::String) => Some(format!("{:?}", value)),
+            _ => None,
+        }
+    }
+}
+
+/// A `const fn` whose body is a single expression
+#[derive(Debug, Clone)]
+struct ConstFn {
+    /// Parameter names in declaration order
+    params: Vec<String>,
+    /// Body expression, kept as token source so the evaluator stays `Send`
+    body: String,
+}
+
+/// Evaluates constant expressions against the constants of a module
+#[derive(Debug, Clone, Default)]
+pub struct ConstEvaluator {
+    /// Known constants by name
+    constants: HashMap<String, ConstValue>,
+    /// Known single-expression `const fn`s by name
+    functions: HashMap<String, ConstFn>,
+}
+
+impl ConstEvaluator {
+    /// Create an empty evaluator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the `const` items and `const fn`s of a module
+    pub fn collect_items(&mut self, items: &[syn::Item]) {
+        for item in items {
+            if let syn::Item::Fn(item_fn) = item {
+                if item_fn.sig.constness.is_some() {
+                    self.add_const_fn(item_fn);
+                }
+            }
+        }
+
+        // Constants may refer to each other in any order; keep folding until stable
+        let mut pending: Vec<&syn::ItemConst> = items.iter()
+            .filter_map(|item| match item {
+                syn::Item::Const(item_const) => Some(item_const),
+                _ => None,
+            })
+            .collect();
+        loop {
+            let before = pending.len();
+            pending.retain(|item_const| match self.evaluate(&item_const.expr) {
+                Some(value) => {
+                    self.constants.insert(item_const.ident.to_string(), value);
+                    false
+                }
+                None => true,
+            });
+            if pending.is_empty() || pending.len() == before {
+                break;
+            }
+        }
+    }
+
+    /// Register a single `const fn`
+    fn add_const_fn(&mut self, item_fn: &syn::ItemFn) {
+        let [syn::Stmt::Expr(body, None)] = item_fn.block.stmts.as_slice() else {
+            return;
+        };
+        let params = item_fn.sig.inputs.iter()
+            .filter_map(|input| match input {
+                syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
+                    syn::Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+                    _ => None,
+                },
+                syn::FnArg::Receiver(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if params.len() != item_fn.sig.inputs.len() {
+            return;
+        }
+
+        self.functions.insert(item_fn.sig.ident.to_string(), ConstFn {
+            params,
+            body: quote::quote!(#body).to_string(),
+        });
+    }
+
+    /// Get a registered constant
+    pub fn constant(&self, name: &str) -> Option<&ConstValue> {
+        self.constants.get(name)
+    }
+
+    /// Evaluate an expression, returning `None` if it is not a constant
+    pub fn evaluate(&self, expr: &Expr) -> Option<ConstValue> {
+        self.evaluate_in(expr, &HashMap::new(), 0)
+    }
+
+    /// Evaluate an expression with local bindings for `const fn` parameters
+    fn evaluate_in(&self, expr: &Expr, locals: &HashMap<String, ConstValue>, depth: usize) -> Option<ConstValue> {
+        match expr {
+            Expr::Lit(expr_lit) => match &expr_lit.lit {
+                Lit::Int(int) => int.base10_parse::<i64>().ok().map(ConstValue::Int),
+                Lit::Float(float) => float.base10_parse::<f64>().ok().map(ConstValue::Float),
+                Lit::Bool(b) => Some(ConstValue::Bool(b.value)),
+                Lit::Str(s) => Some(ConstValue::Str(s.value())),
+                _ => None,
+            },
+            Expr::Path(expr_path) => {
+                let name = expr_path.path.segments.last()?.ident.to_string();
+                if let Some(ident) = expr_path.path.get_ident() {
+                    if let Some(value) = locals.get(&ident.to_string()) {
+                        return Some(value.clone());
+                    }
+                }
+                self.constants.get(&name).cloned()
+            }
+            Expr::Paren(paren) => self.evaluate_in(&paren.expr, locals, depth),
+            Expr::Group(group) => self.evaluate_in(&group.expr, locals, depth),
+            Expr::Unary(unary) => {
+                let value = self.evaluate_in(&unary.expr, locals, depth)?;
+                match (&unary.op, value) {
+                    (UnOp::Neg(_), ConstValue::Int(v)) => v.checked_neg().map(ConstValue::Int),
+                    (UnOp::Neg(_), ConstValue::Float(v)) => Some(ConstValue::Float(-v)),
+                    (UnOp::Not(_), ConstValue::Bool(v)) => Some(ConstValue::Bool(!v)),
+                    _ => None,
+                }
+            }
+            Expr::Binary(binary) => {
+                let left = self.evaluate_in(&binary.left, locals, depth)?;
+                let right = self.evaluate_in(&binary.right, locals, depth)?;
+                evaluate_binary(&binary.op, left, right)
+            }
+            Expr::Cast(cast) => {
+                let value = self.evaluate_in(&cast.expr, locals, depth)?;
+                let target = match &*cast.ty {
+                    syn::Type::Path(type_path) => type_path.path.segments.last()?.ident.to_string(),
+                    _ => return None,
+                };
+                match (value, target.as_str()) {
+                    (ConstValue::Int(v), "f32" | "f64") => Some(ConstValue::Float(v as f64)),
+                    (ConstValue::Float(v), t) if is_integer_name(t) => Some(ConstValue::Int(v as i64)),
+                    (ConstValue::Int(v), t) if is_integer_name(t) => Some(ConstValue::Int(v)),
+                    (ConstValue::Float(v), "f32" | "f64") => Some(ConstValue::Float(v)),
+                    _ => None,
+                }
+            }
+            Expr::Call(call) if depth < MAX_CALL_DEPTH => {
+                let Expr::Path(func) = &*call.func else {
+                    return None;
+                };
+                let function = self.functions.get(&func.path.segments.last()?.ident.to_string())?;
+                if function.params.len() != call.args.len() {
+                    return None;
+                }
+                let mut bindings = HashMap::new();
+                for (param, arg) in function.params.iter().zip(call.args.iter()) {
+                    bindings.insert(param.clone(), self.evaluate_in(arg, locals, depth)?);
+                }
+                let body: Expr = syn::parse_str(&function.body).ok()?;
+                self.evaluate_in(&body, &bindings, depth + 1)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Fold a binary operation on two constant values
+fn evaluate_binary(op: &BinOp, left: ConstValue, right: ConstValue) -> Option<ConstValue> {
+    use ConstValue::*;
+
+    match (left, right) {
+        (Int(l), Int(r)) => match op {
+            BinOp::Add(_) => l.checked_add(r).map(Int),
+            BinOp::Sub(_) => l.checked_sub(r).map(Int),
+            BinOp::Mul(_) => l.checked_mul(r).map(Int),
+            BinOp::Div(_) => l.checked_div(r).map(Int),
+            BinOp::Rem(_) => l.checked_rem(r).map(Int),
+            BinOp::BitAnd(_) => Some(Int(l & r)),
+            BinOp::BitOr(_) => Some(Int(l | r)),
+            BinOp::BitXor(_) => Some(Int(l ^ r)),
+            BinOp::Shl(_) => u32::try_from(r).ok().and_then(|r| l.checked_shl(r)).map(Int),
+            BinOp::Shr(_) => u32::try_from(r).ok().and_then(|r| l.checked_shr(r)).map(Int),
+            _ => compare(op, l, r),
+        },
+        (Float(l), Float(r)) => match op {
+            BinOp::Add(_) => Some(Float(l + r)),
+            BinOp::Sub(_) => Some(Float(l - r)),
+            BinOp::Mul(_) => Some(Float(l * r)),
+            BinOp::Div(_) if r != 0.0 => Some(Float(l / r)),
+            _ => compare(op, l, r),
+        },
+        (Bool(l), Bool(r)) => match op {
+            BinOp::And(_) => Some(Bool(l && r)),
+            BinOp::Or(_) => Some(Bool(l || r)),
+            BinOp::Eq(_) => Some(Bool(l == r)),
+            BinOp::Ne(_) => Some(Bool(l != r)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Fold a comparison operator
+fn compare<T: PartialOrd>(op: &BinOp, l: T, r: T) -> Option<ConstValue> {
+    let result = match op {
+        BinOp::Eq(_) => l == r,
+        BinOp::Ne(_) => l != r,
+        BinOp::Lt(_) => l < r,
+        BinOp::Le(_) => l <= r,
+        BinOp::Gt(_) => l > r,
+        BinOp::Ge(_) => l >= r,
+        _ => return None,
+    };
+    Some(ConstValue::Bool(result))
+}
+
+/// Format a float so C# parses it as a floating-point literal
+fn format_float(value: f64) -> String {
+    let formatted = value.to_string();
+    if formatted.contains('.') || formatted.contains('e') {
+        formatted
+    } else {
+        format!("{}.0", formatted)
+    }
+}
+
+fn is_integer_type(rust_type: &RustType) -> bool {
+    matches!(
+        rust_type,
+        RustType::I8 | RustType::I16 | RustType::I32 | RustType::I64
+            | RustType::U8 | RustType::U16 | RustType::U32 | RustType::U64
+    )
+}
+
+fn is_integer_name(name: &str) -> bool {
+    matches!(name, "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_evaluate_arithmetic_with_constants() {
+        let items: Vec<syn::Item> = vec![
+            parse_quote!(const ROUND_MINUTES: i32 = 5;),
+            parse_quote!(const fn minutes(count: i32) -> i32 { count * 60 }),
+        ];
+        let mut evaluator = ConstEvaluator::new();
+        evaluator.collect_items(&items);
+
+        let expr: Expr = parse_quote!(minutes(ROUND_MINUTES) + 30);
+        assert_eq!(evaluator.evaluate(&expr), Some(ConstValue::Int(330)));
+        assert_eq!(ConstValue::Int(330).to_csharp_literal(&RustType::F32), Some("330f".to_string()));
+    }
+
+    #[test]
+    fn test_non_constant_expression_is_not_folded() {
+        let evaluator = ConstEvaluator::new();
+        let expr: Expr = parse_quote!(time::delta_time() * 2.0);
+        assert_eq!(evaluator.evaluate(&expr), None);
+
+        let expr: Expr = parse_quote!(10 / 0);
+        assert_eq!(evaluator.evaluate(&expr), None);
+    }
+}
@@ -0,0 +1,230 @@
+//! Module-level constant lookup tables
+//!
+//! Gameplay code keeps fixed tables such as spawn offsets or color palettes in
+//! module constants like `const SPAWN_OFFSETS: [Vector3; 4] = [...]`. Rather
+//! than rebuilding the array at every use, each table becomes one
+//! `public static readonly` array of the SharedRuntime, initialized once and
+//! indexed by every behavior that refers to it. Elements must be constants
+//! Udon can construct: numbers, booleans, and the Unity value types built
+//! with `new` from constant components or named like `Vector3::ZERO`.
+
+use serde::{Deserialize, Serialize};
+use syn::{Expr, Type};
+
+use crate::body_lowering::lower_known_path;
+use crate::const_eval::{ConstEvaluator, ConstValue};
+use crate::multi_behavior::{RustToCSharpTypeMapper, RustType};
+
+/// Class the tables are declared on
+pub const CONST_TABLE_OWNER: &str = "SharedRuntime";
+
+/// A constant array shared by every behavior as a static readonly array
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConstTable {
+    /// Name of the Rust constant, kept as the C# field name
+    pub name: String,
+    /// Type of the elements
+    pub element_type: RustType,
+    /// C# expression of every element, in order
+    pub elements: Vec<String>,
+}
+
+impl ConstTable {
+    /// Describe a `const NAME: [T; N] = [...]` item; `None` when the constant
+    /// is not an array, an error when its elements cannot be built in Udon
+    pub fn from_item(item: &syn::ItemConst, evaluator: &ConstEvaluator) -> Option<Result<Self, String>> {
+        let Type::Array(array_type) = &*item.ty else {
+            return None;
+        };
+        Some(Self::from_array(item, &array_type.elem, evaluator))
+    }
+
+    fn from_array(item: &syn::ItemConst, element: &Type, evaluator: &ConstEvaluator) -> Result<Self, String> {
+        let element_type = element_type(element).ok_or_else(|| {
+            format!("elements of type `{}` cannot be constructed as Udon constants", quote::quote!(#element))
+        })?;
+        let Expr::Array(array) = &*item.expr else {
+            return Err("the table must be written as an array literal".to_string());
+        };
+        let elements = array.elems.iter()
+            .enumerate()
+            .map(|(index, expr)| element_literal(expr, &element_type, evaluator).ok_or_else(|| {
+                format!("element {} (`{}`) is not a constant Udon can construct", index, quote::quote!(#expr))
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { name: item.ident.to_string(), element_type, elements })
+    }
+
+    /// C# type of the table's elements
+    pub fn csharp_element_type(&self) -> String {
+        RustToCSharpTypeMapper::new().map_type(&self.element_type).unwrap_or_else(|_| "object".to_string())
+    }
+
+    /// Expression behaviors refer to the table through
+    pub fn reference(&self) -> String {
+        format!("{}.{}", CONST_TABLE_OWNER, self.name)
+    }
+
+    /// Declaration of the table on the SharedRuntime
+    pub fn declaration(&self) -> String {
+        let element_type = self.csharp_element_type();
+        let mut lines = vec![
+            format!("    public static readonly {}[] {} = new {}[]", element_type, self.name, element_type),
+            "    {".to_string(),
+        ];
+        lines.extend(self.elements.iter().map(|element| format!("        {},", element)));
+        lines.push("    };".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Element type of a table declared as `[ty; N]`
+fn element_type(ty: &Type) -> Option<RustType> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    element_type_named(&type_path.path.segments.last()?.ident.to_string())
+}
+
+/// Element types a table may hold, by name
+fn element_type_named(type_name: &str) -> Option<RustType> {
+    let rust_type = match type_name {
+        "bool" => RustType::Bool,
+        "i8" => RustType::I8,
+        "i16" => RustType::I16,
+        "i32" => RustType::I32,
+        "i64" => RustType::I64,
+        "u8" => RustType::U8,
+        "u16" => RustType::U16,
+        "u32" => RustType::U32,
+        "u64" => RustType::U64,
+        "f32" => RustType::F32,
+        "f64" => RustType::F64,
+        "Vector2" => RustType::Vector2,
+        "Vector3" => RustType::Vector3,
+        "Vector4" => RustType::Vector4,
+        "Quaternion" => RustType::Quaternion,
+        "Color" => RustType::Color,
+        _ => return None,
+    };
+    Some(rust_type)
+}
+
+/// C# expression of one element
+fn element_literal(expr: &Expr, element_type: &RustType, evaluator: &ConstEvaluator) -> Option<String> {
+    let components = match element_type {
+        RustType::Vector2 => 2,
+        RustType::Vector3 => 3,
+        RustType::Vector4 | RustType::Quaternion | RustType::Color => 4,
+        _ => return evaluator.evaluate(expr)?.to_csharp_literal(element_type),
+    };
+
+    match expr {
+        Expr::Path(expr_path) => {
+            let path: Vec<String> = expr_path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let path = path[path.len().saturating_sub(2)..].join("::");
+            lower_known_path(&path).or_else(|| known_color(&path)).map(str::to_string)
+        }
+        Expr::Call(call) => {
+            let Expr::Path(func) = &*call.func else {
+                return None;
+            };
+            let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let [.., type_name, constructor] = segments.as_slice() else {
+                return None;
+            };
+            if constructor != "new" || element_type != &element_type_named(type_name)? || call.args.len() != components {
+                return None;
+            }
+            let args = call.args.iter()
+                .map(|arg| match evaluator.evaluate(arg)? {
+                    value @ (ConstValue::Int(_) | ConstValue::Float(_)) => value.to_csharp_literal(&RustType::F32),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("new {}({})", type_name, args.join(", ")))
+        }
+        _ => None,
+    }
+}
+
+/// C# lowering of the named `Color` constants
+fn known_color(path: &str) -> Option<&'static str> {
+    match path {
+        "Color::WHITE" => Some("Color.white"),
+        "Color::BLACK" => Some("Color.black"),
+        "Color::RED" => Some("Color.red"),
+        "Color::GREEN" => Some("Color.green"),
+        "Color::BLUE" => Some("Color.blue"),
+        "Color::TRANSPARENT" => Some("Color.clear"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_table_elements_must_be_udon_constants() {
+        let evaluator = ConstEvaluator::new();
+        let palette: syn::ItemConst = parse_quote!(const PALETTE: [Color; 2] = [Color::RED, Color::new(0.5, 0.5, 0.5, 1.0)];);
+        let table = ConstTable::from_item(&palette, &evaluator).unwrap().unwrap();
+        assert_eq!(table.elements, vec!["Color.red", "new Color(0.5f, 0.5f, 0.5f, 1.0f)"]);
+
+        let not_a_table: syn::ItemConst = parse_quote!(const MAX_PLAYERS: i32 = 8;);
+        assert!(ConstTable::from_item(&not_a_table, &evaluator).is_none());
+
+        let names: syn::ItemConst = parse_quote!(const NAMES: [GameObject; 1] = [GameObject::find("Spawn")];);
+        assert!(ConstTable::from_item(&names, &evaluator).unwrap().is_err());
+
+        let computed: syn::ItemConst = parse_quote!(const OFFSETS: [Vector3; 1] = [Vector3::new(time(), 0.0, 0.0)];);
+        assert!(ConstTable::from_item(&computed, &evaluator).unwrap().unwrap_err().contains("element 0"));
+    }
+
+    #[test]
+    fn test_vector3_table_becomes_static_readonly_array_indexed_from_behaviors() {
+        let items: Vec<syn::Item> = vec![
+            parse_quote!(const SPACING: f32 = 2.0;),
+            parse_quote! {
+                const SPAWN_OFFSETS: [Vector3; 3] = [Vector3::ZERO, Vector3::new(SPACING, 0.0, 0.0), Vector3::new(-SPACING, 0.0, 1.5)];
+            },
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Spawner {
+                    next_spawn: i32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for Spawner {
+                    fn start(&mut self) {}
+                }
+            },
+            parse_quote! {
+                impl Spawner {
+                    pub fn spawn_point(&self) -> Vector3 {
+                        SPAWN_OFFSETS[self.next_spawn as usize]
+                    }
+                }
+            },
+        ];
+        let behaviors = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&items).unwrap();
+        assert_eq!(behaviors[0].const_tables.len(), 1);
+
+        let class = crate::code_generator::CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+        assert!(class.source_code.contains("return SharedRuntime.SPAWN_OFFSETS[nextSpawn];"), "{}", class.source_code);
+
+        let mut shared_items = crate::shared_runtime::SharedItems::new();
+        shared_items.add_const_tables(&behaviors);
+        let runtime = crate::shared_runtime::SharedRuntimeGenerator::new().generate_shared_runtime(&shared_items).unwrap();
+        assert!(runtime.contains(&[
+            "    public static readonly Vector3[] SPAWN_OFFSETS = new Vector3[]",
+            "    {",
+            "        Vector3.zero,",
+            "        new Vector3(2.0f, 0.0f, 0.0f),",
+            "        new Vector3(-2.0f, 0.0f, 1.5f),",
+            "    };",
+        ].join("\n")), "{}", runtime);
+    }
+}
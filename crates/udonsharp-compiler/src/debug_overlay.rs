@@ -0,0 +1,153 @@
+//! Generation of an in-world debug overlay behavior
+//!
+//! Sync problems are easiest to diagnose by watching the values on every
+//! client at once, which otherwise means adding logging and rebuilding. With
+//! `--emit-debug-overlay` the compiler emits one extra behavior that shows the
+//! fields marked `#[udon_debug]` on a UI text element and refreshes them once
+//! a second. Values are read with `GetProgramVariable`, so private fields can
+//! be shown without changing the behaviors being debugged.
+
+use crate::code_generator::to_camel_case;
+use crate::multi_behavior::UdonBehaviourStruct;
+
+/// Class name of the generated overlay behavior
+pub const DEBUG_OVERLAY_CLASS: &str = "UdonDebugOverlay";
+
+/// Seconds between two refreshes of the overlay text
+const REFRESH_INTERVAL_SECONDS: &str = "1.0f";
+
+/// Fields of one behavior shown on the overlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugOverlaySection {
+    /// Name of the behavior
+    pub behavior: String,
+    /// C# names of the displayed fields, in declaration order
+    pub fields: Vec<String>,
+}
+
+/// Fields marked `#[udon_debug]`, grouped by behavior in name order
+pub fn debug_overlay_sections(behaviors: &[UdonBehaviourStruct]) -> Vec<DebugOverlaySection> {
+    let mut sections: Vec<DebugOverlaySection> = behaviors.iter()
+        .map(|behavior| DebugOverlaySection {
+            behavior: behavior.name.clone(),
+            fields: behavior.fields.iter()
+                .filter(|field| field.is_debug_displayed())
+                .map(|field| to_camel_case(&field.name))
+                .collect(),
+        })
+        .filter(|section| !section.fields.is_empty())
+        .collect();
+    sections.sort_by(|a, b| a.behavior.cmp(&b.behavior));
+    sections
+}
+
+/// Name of the overlay field referencing a behavior, `GameStateManager` becoming `gameStateManager`
+fn reference_name(behavior: &str) -> String {
+    let mut chars = behavior.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Generate the overlay behavior, or `None` when no field is marked `#[udon_debug]`
+///
+/// Each behavior reference and the text element can be assigned in the
+/// inspector; references left empty are wired in `Start` to the object named
+/// after the behavior, as laid out by the hierarchy prefab, and the text to the
+/// first `Text` among the overlay's children.
+pub fn generate_debug_overlay(behaviors: &[UdonBehaviourStruct], namespace: Option<&str>) -> Option<String> {
+    let sections = debug_overlay_sections(behaviors);
+    if sections.is_empty() {
+        return None;
+    }
+
+    let mut class = vec![
+        "/// <summary>".to_string(),
+        "/// Shows the fields marked #[udon_debug] on an in-world text element".to_string(),
+        "/// </summary>".to_string(),
+        "[UdonBehaviourSyncMode(BehaviourSyncMode.None)]".to_string(),
+        format!("public class {} : UdonSharpBehaviour", DEBUG_OVERLAY_CLASS),
+        "{".to_string(),
+        "    [Tooltip(\"Text element the overlay writes to; the first Text among the children when empty\")]".to_string(),
+        "    public Text overlayText;".to_string(),
+    ];
+    for section in &sections {
+        class.push(format!("    public {} {};", section.behavior, reference_name(&section.behavior)));
+    }
+
+    class.extend([
+        String::new(),
+        "    public override void Start()".to_string(),
+        "    {".to_string(),
+        "        if (overlayText == null)".to_string(),
+        "        {".to_string(),
+        "            overlayText = GetComponentInChildren<Text>();".to_string(),
+        "        }".to_string(),
+    ]);
+    for section in &sections {
+        let reference = reference_name(&section.behavior);
+        class.extend([
+            format!("        if ({} == null)", reference),
+            "        {".to_string(),
+            format!("            GameObject {}Object = GameObject.Find(\"{}\");", reference, section.behavior),
+            format!("            if ({}Object != null)", reference),
+            "            {".to_string(),
+            format!("                {} = {}Object.GetComponent<{}>();", reference, reference, section.behavior),
+            "            }".to_string(),
+            "        }".to_string(),
+        ]);
+    }
+    class.extend([
+        "        RefreshOverlay();".to_string(),
+        "    }".to_string(),
+        String::new(),
+        "    public void RefreshOverlay()".to_string(),
+        "    {".to_string(),
+        "        if (overlayText != null)".to_string(),
+        "        {".to_string(),
+        "            string text = \"\";".to_string(),
+    ]);
+    for section in &sections {
+        let reference = reference_name(&section.behavior);
+        class.push(format!("            if ({} != null)", reference));
+        class.push("            {".to_string());
+        class.push(format!("                text += \"{}\\n\";", section.behavior));
+        for field in &section.fields {
+            class.push(format!(
+                "                text += \"  {}: \" + {}.GetProgramVariable(\"{}\") + \"\\n\";",
+                field, reference, field
+            ));
+        }
+        class.push("            }".to_string());
+    }
+    class.extend([
+        "            overlayText.text = text;".to_string(),
+        "        }".to_string(),
+        format!("        SendCustomEventDelayedSeconds(nameof(RefreshOverlay), {});", REFRESH_INTERVAL_SECONDS),
+        "    }".to_string(),
+        "}".to_string(),
+    ]);
+
+    let mut source: Vec<String> = [
+        "using UdonSharp;",
+        "using UnityEngine;",
+        "using UnityEngine.UI;",
+        "using VRC.SDKBase;",
+        "using VRC.Udon;",
+        "",
+    ].iter().map(|line| line.to_string()).collect();
+    match namespace {
+        Some(namespace) => {
+            source.push(format!("namespace {}", namespace));
+            source.push("{".to_string());
+            source.extend(class.iter().map(|line| {
+                if line.is_empty() { String::new() } else { format!("    {}", line) }
+            }));
+            source.push("}".to_string());
+        }
+        None => source.extend(class),
+    }
+
+    Some(source.join("\n"))
+}
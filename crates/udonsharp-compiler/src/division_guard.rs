@@ -0,0 +1,253 @@
+//! Dataflow check for unguarded division by fields that start at zero
+//!
+//! Averages such as `total_session_time / self.total_players as f32` divide
+//! by a counter that is zero until the first player arrives. In Udon an
+//! integer division by zero halts the behavior, and a float division yields
+//! NaN or Infinity that then spreads into synced fields. This module walks a
+//! method body in order, tracking which fields are known to be nonzero from
+//! the conditions guarding each path, and reports divisions by a zero-initialized
+//! field on a path where no such check has happened.
+
+use std::collections::{BTreeSet, HashSet};
+use syn::visit::Visit;
+use syn::{BinOp, Block, Expr, Lit, Stmt, UnOp};
+
+/// Find divisions by the given fields that are not guarded by a zero check
+///
+/// `zero_fields` are the `self` fields that may be zero, usually counters
+/// without a nonzero default.
+pub fn unguarded_divisions(block: &Block, zero_fields: &HashSet<String>) -> Vec<String> {
+    let mut checker = DivisionChecker {
+        zero_fields,
+        nonzero: BTreeSet::new(),
+        unguarded: BTreeSet::new(),
+    };
+    checker.visit_block(block);
+    checker.unguarded.into_iter().collect()
+}
+
+/// Walks a method body, tracking the fields known to be nonzero
+struct DivisionChecker<'a> {
+    zero_fields: &'a HashSet<String>,
+    nonzero: BTreeSet<String>,
+    unguarded: BTreeSet<String>,
+}
+
+impl DivisionChecker<'_> {
+    /// Visit a node with additional fields known to be nonzero
+    fn with_nonzero(&mut self, facts: BTreeSet<String>, visit: impl FnOnce(&mut Self)) {
+        let saved = self.nonzero.clone();
+        self.nonzero.extend(facts);
+        visit(self);
+        self.nonzero = saved;
+    }
+
+    fn check_divisor(&mut self, divisor: &Expr) {
+        if let Some(field) = self_field(divisor) {
+            if self.zero_fields.contains(&field) && !self.nonzero.contains(&field) {
+                self.unguarded.insert(field);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DivisionChecker<'_> {
+    fn visit_block(&mut self, block: &'ast Block) {
+        // Facts established by early exits only hold until the end of the block
+        let saved = self.nonzero.clone();
+        for stmt in &block.stmts {
+            self.visit_stmt(stmt);
+
+            // `if self.count == 0 { return; }` guards everything after it
+            if let Stmt::Expr(Expr::If(expr_if), _) = stmt {
+                if expr_if.else_branch.is_none() && leaves_block(&expr_if.then_branch) {
+                    self.nonzero.extend(nonzero_when_false(&expr_if.cond));
+                }
+            }
+        }
+        self.nonzero = saved;
+    }
+
+    fn visit_expr_if(&mut self, expr_if: &'ast syn::ExprIf) {
+        self.visit_expr(&expr_if.cond);
+        self.with_nonzero(nonzero_when_true(&expr_if.cond), |checker| {
+            checker.visit_block(&expr_if.then_branch);
+        });
+        if let Some((_, else_branch)) = &expr_if.else_branch {
+            self.with_nonzero(nonzero_when_false(&expr_if.cond), |checker| {
+                checker.visit_expr(else_branch);
+            });
+        }
+    }
+
+    fn visit_expr_while(&mut self, expr_while: &'ast syn::ExprWhile) {
+        self.visit_expr(&expr_while.cond);
+        self.with_nonzero(nonzero_when_true(&expr_while.cond), |checker| {
+            checker.visit_block(&expr_while.body);
+        });
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast syn::ExprBinary) {
+        match binary.op {
+            // `self.count > 0 && total / self.count > 1` only divides when the left side holds
+            BinOp::And(_) => {
+                self.visit_expr(&binary.left);
+                self.with_nonzero(nonzero_when_true(&binary.left), |checker| {
+                    checker.visit_expr(&binary.right);
+                });
+            }
+            BinOp::Or(_) => {
+                self.visit_expr(&binary.left);
+                self.with_nonzero(nonzero_when_false(&binary.left), |checker| {
+                    checker.visit_expr(&binary.right);
+                });
+            }
+            BinOp::Div(_) | BinOp::Rem(_) | BinOp::DivAssign(_) | BinOp::RemAssign(_) => {
+                self.check_divisor(&binary.right);
+                syn::visit::visit_expr_binary(self, binary);
+            }
+            _ => syn::visit::visit_expr_binary(self, binary),
+        }
+    }
+}
+
+/// Fields known to be nonzero when the condition is true
+fn nonzero_when_true(cond: &Expr) -> BTreeSet<String> {
+    match cond {
+        Expr::Paren(paren) => nonzero_when_true(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => nonzero_when_false(&unary.expr),
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+            let mut facts = nonzero_when_true(&binary.left);
+            facts.extend(nonzero_when_true(&binary.right));
+            facts
+        }
+        Expr::Binary(binary) => comparison_fact(&binary.left, &binary.op, &binary.right, false),
+        _ => BTreeSet::new(),
+    }
+}
+
+/// Fields known to be nonzero when the condition is false
+fn nonzero_when_false(cond: &Expr) -> BTreeSet<String> {
+    match cond {
+        Expr::Paren(paren) => nonzero_when_false(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => nonzero_when_true(&unary.expr),
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Or(_)) => {
+            let mut facts = nonzero_when_false(&binary.left);
+            facts.extend(nonzero_when_false(&binary.right));
+            facts
+        }
+        Expr::Binary(binary) => comparison_fact(&binary.left, &binary.op, &binary.right, true),
+        _ => BTreeSet::new(),
+    }
+}
+
+/// Whether a comparison between a field and a literal rules out zero
+fn comparison_fact(left: &Expr, op: &BinOp, right: &Expr, negated: bool) -> BTreeSet<String> {
+    let (field, op, value) = match (self_field(left), literal_value(right), self_field(right), literal_value(left)) {
+        (Some(field), Some(value), _, _) => (field, Comparison::from_op(op), value),
+        // `0 < self.count` compares the other way around
+        (_, _, Some(field), Some(value)) => (field, Comparison::from_op(op).map(Comparison::mirrored), value),
+        _ => return BTreeSet::new(),
+    };
+    let Some(op) = op.map(|op| if negated { op.negated() } else { op }) else {
+        return BTreeSet::new();
+    };
+
+    let excludes_zero = match op {
+        Comparison::Gt => value >= 0.0,
+        Comparison::Ge => value > 0.0,
+        Comparison::Lt => value <= 0.0,
+        Comparison::Le => value < 0.0,
+        Comparison::Ne => value == 0.0,
+        Comparison::Eq => value != 0.0,
+    };
+    if excludes_zero {
+        BTreeSet::from([field])
+    } else {
+        BTreeSet::new()
+    }
+}
+
+/// Comparison of a field (on the left) with a literal (on the right)
+#[derive(Clone, Copy)]
+enum Comparison {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl Comparison {
+    fn from_op(op: &BinOp) -> Option<Self> {
+        match op {
+            BinOp::Gt(_) => Some(Comparison::Gt),
+            BinOp::Ge(_) => Some(Comparison::Ge),
+            BinOp::Lt(_) => Some(Comparison::Lt),
+            BinOp::Le(_) => Some(Comparison::Le),
+            BinOp::Eq(_) => Some(Comparison::Eq),
+            BinOp::Ne(_) => Some(Comparison::Ne),
+            _ => None,
+        }
+    }
+
+    /// The comparison with its operands swapped
+    fn mirrored(self) -> Self {
+        match self {
+            Comparison::Gt => Comparison::Lt,
+            Comparison::Ge => Comparison::Le,
+            Comparison::Lt => Comparison::Gt,
+            Comparison::Le => Comparison::Ge,
+            other => other,
+        }
+    }
+
+    /// The comparison that holds when this one is false
+    fn negated(self) -> Self {
+        match self {
+            Comparison::Gt => Comparison::Le,
+            Comparison::Ge => Comparison::Lt,
+            Comparison::Lt => Comparison::Ge,
+            Comparison::Le => Comparison::Gt,
+            Comparison::Eq => Comparison::Ne,
+            Comparison::Ne => Comparison::Eq,
+        }
+    }
+}
+
+/// Get the `self` field an operand reads, looking through casts and parentheses
+fn self_field(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Paren(paren) => self_field(&paren.expr),
+        Expr::Cast(cast) => self_field(&cast.expr),
+        Expr::Field(field) => match (&*field.base, &field.member) {
+            (Expr::Path(base), syn::Member::Named(member)) if base.path.is_ident("self") => {
+                Some(member.to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Get the value of a numeric literal, including negated ones
+fn literal_value(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Paren(paren) => literal_value(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => literal_value(&unary.expr).map(|v| -v),
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int.base10_parse::<f64>().ok(),
+            Lit::Float(float) => float.base10_parse::<f64>().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether a block always leaves the enclosing block early
+fn leaves_block(block: &Block) -> bool {
+    block.stmts.iter().any(|stmt| {
+        matches!(stmt, Stmt::Expr(Expr::Return(_) | Expr::Continue(_) | Expr::Break(_), _))
+    })
+}
@@ -0,0 +1,80 @@
+//! Detection of `GameObject::find` calls with computed names
+//!
+//! `GameObject.Find` walks the whole scene and compares names, so it is slow,
+//! and a name assembled at runtime (`format!("PhysicsObject_{}", i)`) cannot be
+//! checked until the lookup silently returns nothing after an object is
+//! renamed. This module finds those calls so they can be replaced by
+//! references collected once in `start` or assigned in the inspector.
+
+use syn::visit::Visit;
+use syn::{Block, Expr};
+
+use crate::multi_behavior::UdonBehaviourStruct;
+
+/// A method that looks up game objects by a computed name
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicFind {
+    /// Name of the method containing the call
+    pub method: String,
+    /// Whether at least one of the calls runs inside a loop
+    pub in_loop: bool,
+}
+
+/// Methods calling `GameObject::find` with an argument that is not a string literal
+pub fn dynamic_finds(udon_struct: &UdonBehaviourStruct) -> Vec<DynamicFind> {
+    let mut methods: Vec<_> = udon_struct.methods.iter().collect();
+    methods.sort_by(|a, b| a.name.cmp(&b.name));
+
+    methods.into_iter()
+        .filter_map(|method| {
+            let block = syn::parse_str::<Block>(method.body.as_deref()?).ok()?;
+            let mut finder = DynamicFindFinder { loop_depth: 0, found: false, in_loop: false };
+            finder.visit_block(&block);
+            finder.found.then(|| DynamicFind { method: method.name.clone(), in_loop: finder.in_loop })
+        })
+        .collect()
+}
+
+/// Whether a call target is `GameObject::find`, with or without a module prefix
+fn is_game_object_find(func: &Expr) -> bool {
+    let Expr::Path(path) = func else {
+        return false;
+    };
+    let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    matches!(segments.as_slice(), [.., owner, function] if owner == "GameObject" && function == "find")
+}
+
+/// Whether a name argument is a string literal, looking through references and parentheses
+fn is_literal_name(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(lit.lit, syn::Lit::Str(_)),
+        Expr::Reference(reference) => is_literal_name(&reference.expr),
+        Expr::Paren(paren) => is_literal_name(&paren.expr),
+        Expr::Group(group) => is_literal_name(&group.expr),
+        _ => false,
+    }
+}
+
+/// Looks for `GameObject::find` calls with computed names, tracking loop nesting
+struct DynamicFindFinder {
+    loop_depth: usize,
+    found: bool,
+    in_loop: bool,
+}
+
+impl<'ast> Visit<'ast> for DynamicFindFinder {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        let is_loop = matches!(expr, Expr::ForLoop(_) | Expr::While(_) | Expr::Loop(_));
+        self.loop_depth += usize::from(is_loop);
+        syn::visit::visit_expr(self, expr);
+        self.loop_depth -= usize::from(is_loop);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if is_game_object_find(&call.func) && call.args.first().is_some_and(|name| !is_literal_name(name)) {
+            self.found = true;
+            self.in_loop |= self.loop_depth > 0;
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
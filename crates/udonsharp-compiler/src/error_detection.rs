@@ -6,8 +6,10 @@
 use crate::multi_behavior::{UdonBehaviourStruct, StructField, FieldAttribute, RustType};
 use crate::trait_validator::{TraitValidator, ValidationError};
 use crate::struct_analyzer::AnalysisError;
+use crate::split_advisor::SplitAdvisor;
 use udonsharp_core::error::{UdonSharpError, Diagnostic, DiagnosticLevel};
 use std::collections::{HashMap, HashSet};
+use syn::visit::Visit;
 
 /// Comprehensive error detection system for multi-behavior compilation
 pub struct CompilationErrorDetector {
@@ -19,6 +21,8 @@ pub struct CompilationErrorDetector {
     valid_attribute_combinations: HashMap<String, Vec<Vec<FieldAttribute>>>,
     /// Error patterns and their solutions
     error_patterns: HashMap<String, ErrorSolution>,
+    /// Standard library names imported by the `use` items of the analyzed module
+    module_imports: StdImports,
 }
 
 impl CompilationErrorDetector {
@@ -29,6 +33,7 @@ impl CompilationErrorDetector {
             supported_types: HashSet::new(),
             valid_attribute_combinations: HashMap::new(),
             error_patterns: HashMap::new(),
+            module_imports: StdImports::default(),
         };
         
         detector.initialize_supported_types();
@@ -412,9 +417,85 @@ pub callback: fn() -> (), // Function pointers not supported
             }
         }
         
+        errors.extend(self.check_concurrency_constructs(structs));
         errors
     }
     
+    /// Record the `use` items of the analyzed module, against which the paths
+    /// in field types and method bodies are resolved
+    pub fn set_module_imports(&mut self, items: &[syn::Item]) {
+        let mut imports = StdImports::default();
+        for item in items {
+            if let syn::Item::Use(item_use) = item {
+                imports.add(&item_use.tree, &mut Vec::new());
+            }
+        }
+        self.module_imports = imports;
+    }
+    
+    /// Check for threads, futures, locks and atomics, which have no meaning in
+    /// Udon's single-threaded VM
+    pub fn check_concurrency_constructs(&self, structs: &[UdonBehaviourStruct]) -> Vec<CompilationError> {
+        let mut errors = Vec::new();
+        
+        for udon_struct in structs {
+            for field in &udon_struct.fields {
+                let RustType::Custom(type_name) = &field.field_type else {
+                    continue;
+                };
+                let Ok(field_type) = syn::parse_str::<syn::Type>(type_name) else {
+                    continue;
+                };
+                let mut visitor = ConcurrencyConstructVisitor::new(self.module_imports.clone());
+                visitor.visit_type(&field_type);
+                
+                for (construct, reason) in visitor.found {
+                    errors.push(concurrency_error(udon_struct, Some(&field.name), None, &construct, reason));
+                }
+            }
+            
+            for method in &udon_struct.methods {
+                let Some(block) = method.body.as_deref().and_then(|body| syn::parse_str::<syn::Block>(body).ok()) else {
+                    continue;
+                };
+                let mut visitor = ConcurrencyConstructVisitor::new(self.module_imports.clone());
+                visitor.visit_block(&block);
+                
+                for (construct, reason) in visitor.found {
+                    errors.push(concurrency_error(udon_struct, None, Some(&method.name), &construct, reason));
+                }
+            }
+        }
+        
+        errors
+    }
+    
+    /// Warn about behaviors whose estimated size exceeds the instruction limit,
+    /// suggesting groups of methods that could move to helper behaviors
+    ///
+    /// The estimate is rough, so an oversized behavior does not fail the build.
+    pub fn check_behavior_size(&self, structs: &[UdonBehaviourStruct]) -> Vec<CompilationError> {
+        let advisor = SplitAdvisor::default();
+        
+        structs.iter()
+            .filter_map(|udon_struct| advisor.suggest(udon_struct))
+            .map(|suggestion| CompilationError {
+                error_type: ErrorType::BehaviorTooLarge,
+                struct_name: Some(suggestion.behavior.clone()),
+                field_name: None,
+                method_name: None,
+                message: format!(
+                    "Behavior '{}' is estimated at {} Udon instructions, over the limit of {}; consider splitting it as suggested",
+                    suggestion.behavior, suggestion.estimated_instructions, suggestion.instruction_limit
+                ),
+                suggestion: Some(suggestion.format_report()),
+                code_example: None,
+                severity: DiagnosticLevel::Warning,
+                source_location: None,
+            })
+            .collect()
+    }
+    
     /// Generate comprehensive error report
     pub fn generate_error_report(&self, structs: &[UdonBehaviourStruct]) -> CompilationErrorReport {
         let mut all_errors = Vec::new();
@@ -423,6 +504,7 @@ pub callback: fn() -> (), // Function pointers not supported
         all_errors.extend(self.detect_missing_trait_implementations(structs));
         all_errors.extend(self.validate_field_attributes(structs));
         all_errors.extend(self.check_unsupported_features(structs));
+        all_errors.extend(self.check_behavior_size(structs));
         
         // Categorize errors
         let mut errors_by_category = HashMap::new();
@@ -451,8 +533,12 @@ pub callback: fn() -> (), // Function pointers not supported
         match attribute {
             FieldAttribute::UdonPublic => "udon_public".to_string(),
             FieldAttribute::UdonSync => "udon_sync".to_string(),
+            FieldAttribute::UdonSerializeField => "udon_serialize_field".to_string(),
             FieldAttribute::Header(_) => "header".to_string(),
             FieldAttribute::Tooltip(_) => "tooltip".to_string(),
+            FieldAttribute::Range { .. } => "range".to_string(),
+            FieldAttribute::UdonQuantize { .. } => "udon_quantize".to_string(),
+            FieldAttribute::UdonDebug => "udon_debug".to_string(),
         }
     }
     
@@ -473,6 +559,7 @@ pub callback: fn() -> (), // Function pointers not supported
             RustType::Vector2 | RustType::Vector3 | RustType::Vector4 => true,
             RustType::Quaternion | RustType::Color | RustType::Color32 => true,
             RustType::GameObject | RustType::Transform | RustType::VRCPlayerApi => true,
+            RustType::UdonRef(_) => true,
             RustType::Option(inner) => self.is_type_supported(inner),
             RustType::Vec(inner) => self.is_type_supported(inner),
             RustType::HashMap(key, value) => self.is_type_supported(key) && self.is_type_supported(value),
@@ -556,7 +643,154 @@ pub callback: fn() -> (), // Function pointers not supported
             ErrorType::MissingTraitImplementation | ErrorType::MissingRequiredMethods => ErrorCategory::TraitImplementation,
             ErrorType::InvalidAttributeUsage | ErrorType::DuplicateAttribute => ErrorCategory::AttributeUsage,
             ErrorType::UnsupportedType | ErrorType::UnsupportedFeature => ErrorCategory::TypeValidation,
+            ErrorType::BehaviorTooLarge => ErrorCategory::BehaviorSize,
+        }
+    }
+}
+
+/// Explain why a concurrency type or module cannot be used in Udon
+fn concurrency_construct_reason(name: &str) -> Option<&'static str> {
+    match name {
+        "thread" | "JoinHandle" => Some("Udon runs every behavior on a single thread and cannot spawn threads"),
+        "Mutex" | "RwLock" | "Condvar" | "OnceLock" =>
+            Some("Udon is single-threaded, so locks have nothing to guard and cannot be compiled"),
+        "Arc" => Some("Udon has no shared-ownership threading; store the value in a field or another behavior instead"),
+        "mpsc" => Some("Udon has no threads to send messages between; use SendCustomEvent to talk to other behaviors"),
+        "Future" | "Waker" =>
+            Some("Udon has no async runtime; use SendCustomEventDelayedSeconds to schedule work instead"),
+        _ if name.starts_with("Atomic") => Some("Udon is single-threaded, so atomics compile to nothing meaningful; use a plain field"),
+        _ => None,
+    }
+}
+
+/// Build the error reported for a concurrency construct
+fn concurrency_error(
+    udon_struct: &UdonBehaviourStruct,
+    field_name: Option<&str>,
+    method_name: Option<&str>,
+    construct: &str,
+    reason: &str,
+) -> CompilationError {
+    let location = match (field_name, method_name) {
+        (Some(field), _) => format!("field '{}'", field),
+        (_, Some(method)) => format!("method '{}'", method),
+        _ => "behavior".to_string(),
+    };
+    
+    CompilationError {
+        error_type: ErrorType::UnsupportedFeature,
+        struct_name: Some(udon_struct.name.clone()),
+        field_name: field_name.map(str::to_string),
+        method_name: method_name.map(str::to_string),
+        message: format!(
+            "'{}' used in {} of '{}' is not supported: {}",
+            construct, location, udon_struct.name, reason
+        ),
+        suggestion: Some("Write the logic synchronously; Udon events run one at a time on the main thread".to_string()),
+        code_example: None,
+        severity: DiagnosticLevel::Error,
+        source_location: None,
+    }
+}
+
+/// Crates whose concurrency modules and types are flagged
+const STANDARD_CRATES: [&str; 3] = ["std", "core", "alloc"];
+
+/// Standard library names brought into scope by `use` items, mapped to their full path
+#[derive(Debug, Clone, Default)]
+struct StdImports(HashMap<String, Vec<String>>);
+
+impl StdImports {
+    /// Record the standard library names a `use` tree brings into scope
+    fn add(&mut self, tree: &syn::UseTree, prefix: &mut Vec<String>) {
+        match tree {
+            syn::UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                self.add(&path.tree, prefix);
+                prefix.pop();
+            }
+            syn::UseTree::Name(name) => self.add_name(prefix, &name.ident, &name.ident),
+            syn::UseTree::Rename(rename) => self.add_name(prefix, &rename.ident, &rename.rename),
+            syn::UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.add(tree, prefix);
+                }
+            }
+            syn::UseTree::Glob(_) => {}
+        }
+    }
+
+    fn add_name(&mut self, prefix: &[String], ident: &syn::Ident, alias: &syn::Ident) {
+        let mut path = prefix.to_vec();
+        if ident != "self" {
+            path.push(ident.to_string());
+        }
+        if path.len() > 1 && STANDARD_CRATES.contains(&path[0].as_str()) {
+            let alias = if alias == "self" { path.last().cloned().unwrap_or_default() } else { alias.to_string() };
+            self.0.insert(alias, path);
+        }
+    }
+
+    /// Segments of `path` with an imported first segment expanded to its full path
+    fn resolve(&self, path: &syn::Path) -> Vec<String> {
+        let mut names: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+        if let Some(imported) = names.first().and_then(|first| self.0.get(first)) {
+            names.splice(..1, imported.iter().cloned());
         }
+        names
+    }
+}
+
+/// Collects thread, async, lock and atomic constructs used in a method body or type
+///
+/// Only paths into the standard library count, written out in full
+/// (`std::thread::spawn`) or through a `use` of the module or the body, so a
+/// behavior's own `thread` module or `Mutex` type is not mistaken for the standard one.
+struct ConcurrencyConstructVisitor {
+    imports: StdImports,
+    found: Vec<(String, &'static str)>,
+}
+
+impl ConcurrencyConstructVisitor {
+    /// Create a visitor resolving paths against the module's imports
+    fn new(imports: StdImports) -> Self {
+        Self { imports, found: Vec::new() }
+    }
+
+    fn report(&mut self, construct: String, reason: &'static str) {
+        if !self.found.iter().any(|(existing, _)| *existing == construct) {
+            self.found.push((construct, reason));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ConcurrencyConstructVisitor {
+    fn visit_expr_await(&mut self, expr: &'ast syn::ExprAwait) {
+        self.report(".await".to_string(), "Udon has no async runtime, so futures can never be awaited");
+        syn::visit::visit_expr_await(self, expr);
+    }
+    
+    fn visit_expr_async(&mut self, expr: &'ast syn::ExprAsync) {
+        self.report("async block".to_string(), "Udon has no async runtime, so futures can never be polled");
+        syn::visit::visit_expr_async(self, expr);
+    }
+    
+    fn visit_item_use(&mut self, item_use: &'ast syn::ItemUse) {
+        self.imports.add(&item_use.tree, &mut Vec::new());
+    }
+
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        let names = self.imports.resolve(path);
+        let from_std = names.first().is_some_and(|first| STANDARD_CRATES.contains(&first.as_str()));
+        if let Some((position, reason)) = names.iter().enumerate()
+            .filter(|_| from_std)
+            .find_map(|(position, name)| concurrency_construct_reason(name).map(|reason| (position, reason)))
+        {
+            // Name the construct by the path up to and including the next segment, e.g. `thread::spawn`
+            let construct = names[position..names.len().min(position + 2)].join("::");
+            self.report(construct, reason);
+        }
+        syn::visit::visit_path(self, path);
     }
 }
 
@@ -589,6 +823,7 @@ pub enum ErrorType {
     DuplicateAttribute,
     UnsupportedType,
     UnsupportedFeature,
+    BehaviorTooLarge,
 }
 
 /// Error categories for organization
@@ -597,6 +832,7 @@ pub enum ErrorCategory {
     TraitImplementation,
     AttributeUsage,
     TypeValidation,
+    BehaviorSize,
 }
 
 /// Source location information
@@ -722,4 +958,88 @@ mod tests {
         assert_eq!(report.structs_analyzed, 1);
         assert!(report.has_blocking_errors);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_thread_spawn_is_rejected() {
+        let detector = CompilationErrorDetector::new();
+        let mut test_struct = create_test_struct("TestBehavior");
+        let mut method = StructMethod::new("start".to_string(), RustType::Unit);
+        method.body = Some("{ let handle = std::thread::spawn(|| { compute(); }); handle.join(); }".to_string());
+        test_struct.add_method(method);
+
+        let errors = detector.check_unsupported_features(&[test_struct]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_type, ErrorType::UnsupportedFeature);
+        assert_eq!(errors[0].method_name.as_deref(), Some("start"));
+        assert!(errors[0].message.contains("'thread::spawn'"));
+        assert!(errors[0].message.contains("single thread"));
+    }
+
+    #[test]
+    fn test_only_standard_library_concurrency_paths_are_rejected() {
+        let detector = CompilationErrorDetector::new();
+        let mut test_struct = create_test_struct("TestBehavior");
+        let mut method = StructMethod::new("start".to_string(), RustType::Unit);
+        // `thread` and `Mutex` here are the behavior's own, as nothing imports them
+        method.body = Some("{ use std::sync::{Arc, Mutex as Lock}; let shared = Arc::new(Lock::new(0)); thread::spawn(); Mutex::open(); }".to_string());
+        test_struct.add_method(method);
+        test_struct.add_field(StructField::new("lock".to_string(), RustType::Custom("Mutex<i32>".to_string())));
+
+        let errors = detector.check_concurrency_constructs(&[test_struct]);
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(errors.len(), 2, "{:?}", messages);
+        assert!(messages[0].contains("'Arc::new'"), "{}", messages[0]);
+        assert!(messages[1].contains("'Mutex::new'"), "{}", messages[1]);
+    }
+
+    #[test]
+    fn test_module_imports_resolve_concurrency_paths() {
+        let file: syn::File = syn::parse_str("use std::thread; use std::sync::Mutex as Lock;").unwrap();
+        let mut detector = CompilationErrorDetector::new();
+        detector.set_module_imports(&file.items);
+        let mut test_struct = create_test_struct("TestBehavior");
+        let mut method = StructMethod::new("start".to_string(), RustType::Unit);
+        method.body = Some("{ thread::spawn(|| {}); }".to_string());
+        test_struct.add_method(method);
+        test_struct.add_field(StructField::new("scores".to_string(), RustType::Custom("Lock<Vec<i32>>".to_string())));
+
+        let errors = detector.check_concurrency_constructs(&[test_struct]);
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(errors.len(), 2, "{:?}", messages);
+        assert_eq!(errors[0].field_name.as_deref(), Some("scores"));
+        assert!(messages[0].contains("'Mutex'"), "{}", messages[0]);
+        assert_eq!(errors[1].method_name.as_deref(), Some("start"));
+        assert!(messages[1].contains("'thread::spawn'"), "{}", messages[1]);
+    }
+
+    #[test]
+    fn test_plain_code_has_no_concurrency_errors() {
+        let detector = CompilationErrorDetector::new();
+        let mut test_struct = create_test_struct("TestBehavior");
+        let mut method = StructMethod::new("update".to_string(), RustType::Unit);
+        method.body = Some("{ self.timer += 1.0; if self.timer > 5.0 { self.timer = 0.0; } }".to_string());
+        test_struct.add_method(method);
+
+        assert!(detector.check_concurrency_constructs(&[test_struct]).is_empty());
+    }
+
+    #[test]
+    fn test_oversized_behavior_is_a_warning_with_split_advice() {
+        let detector = CompilationErrorDetector::new();
+        let increments = "self.score += 1;".repeat(1000);
+        let source = format!(
+            "#[derive(UdonBehaviour)] pub struct Arena {{ score: i32 }}
+            impl Arena {{ pub fn tally(&mut self) {{ {} }} }}
+            impl UdonBehaviour for Arena {{ fn start(&mut self) {{}} }}",
+            increments
+        );
+        let file: syn::File = syn::parse_str(&source).unwrap();
+        let arena = crate::struct_analyzer::StructAnalyzer::new().analyze_module(&file.items).unwrap();
+
+        let oversized = detector.check_behavior_size(&arena);
+        assert_eq!(oversized.len(), 1);
+        assert_eq!(oversized[0].severity, DiagnosticLevel::Warning);
+        assert!(oversized[0].message.ends_with("consider splitting it as suggested"), "{}", oversized[0].message);
+        assert!(oversized[0].suggestion.as_ref().unwrap().starts_with("Behavior 'Arena' is estimated at"));
+    }
+}
@@ -112,6 +112,18 @@ impl ErrorReporter {
                 help_url: Some("https://docs.vrchat.com/docs/udonsharp-limitations".to_string()),
             }
         );
+        
+        self.message_templates.insert(
+            ErrorType::BehaviorTooLarge,
+            MessageTemplate {
+                title: "Behavior Too Large".to_string(),
+                description: "This behavior compiles to more Udon instructions than a single program should hold".to_string(),
+                icon: "📏".to_string(),
+                color: TerminalColor::Yellow,
+                quick_fix: Some("Move the suggested method groups to helper behaviors".to_string()),
+                help_url: None,
+            }
+        );
     }
     
     /// Generate a comprehensive error report
@@ -484,6 +496,7 @@ impl ErrorReporter {
             ErrorCategory::TraitImplementation => "Trait Implementation".to_string(),
             ErrorCategory::AttributeUsage => "Attribute Usage".to_string(),
             ErrorCategory::TypeValidation => "Type Validation".to_string(),
+            ErrorCategory::BehaviorSize => "Behavior Size".to_string(),
         }
     }
     
@@ -492,6 +505,7 @@ impl ErrorReporter {
             ErrorCategory::TraitImplementation => "🔧",
             ErrorCategory::AttributeUsage => "🏷️",
             ErrorCategory::TypeValidation => "🔍",
+            ErrorCategory::BehaviorSize => "📏",
         }
     }
     
@@ -538,6 +552,7 @@ impl ErrorReporter {
             ErrorType::DuplicateAttribute => 2002,
             ErrorType::UnsupportedType => 3001,
             ErrorType::UnsupportedFeature => 3002,
+            ErrorType::BehaviorTooLarge => 4001,
         }
     }
 }
@@ -0,0 +1,132 @@
+//! Event forwarding for `#[udon_forward]` behaviors
+//!
+//! Worlds often relay an event from one behavior to others, e.g. a join
+//! handler that tells the UI and the game logic about the new player. A
+//! behavior marked `#[udon_forward(event = "OnPlayerJoined", to = ["UIController", "GameLogic"])]`
+//! gets a `SendCustomEvent("OnPlayerJoined")` per target appended to its
+//! `OnPlayerJoined` handler, which is generated when the behavior does not
+//! implement it. Each target is reached through the behavior's
+//! `UdonRef<Target>` field, or through a public `UdonBehaviour` field named
+//! after the target, assigned in the inspector, when there is none. The
+//! events are sent after the handler's own body, so an early `return` skips
+//! them, and event arguments such as the joining player are not forwarded.
+
+use crate::code_generator::{to_camel_case, unity_event_method, GeneratedField};
+use crate::multi_behavior::{RustType, StructAttribute, UdonBehaviourStruct};
+
+/// An event a behavior relays to other behaviors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventForward {
+    /// Unity event name, e.g. `OnPlayerJoined`
+    pub event: String,
+    /// Names of the behaviors the event is sent to, in order
+    pub targets: Vec<String>,
+}
+
+impl EventForward {
+    /// Create a forward, validating the event name and target list
+    pub fn new(event: &str, targets: Vec<String>) -> Result<Self, String> {
+        if unity_event_method(event).is_none() {
+            return Err(format!("'{}' is not a Unity event", event));
+        }
+        if targets.is_empty() {
+            return Err("`to` must name at least one behavior".to_string());
+        }
+        if let Some(duplicate) = targets.iter().enumerate().find_map(|(i, t)| targets[..i].contains(t).then_some(t)) {
+            return Err(format!("'{}' is listed more than once", duplicate));
+        }
+        Ok(Self { event: event.to_string(), targets })
+    }
+
+    /// Get the forwards of a behavior, in declaration order
+    pub fn for_behavior(udon_struct: &UdonBehaviourStruct) -> Vec<Self> {
+        udon_struct.attributes.iter().filter_map(|attribute| match attribute {
+            StructAttribute::Forward { event, targets } => Some(Self {
+                event: event.clone(),
+                targets: targets.clone(),
+            }),
+            _ => None,
+        }).collect()
+    }
+
+    /// Rust trait method handling the forwarded event, e.g. `on_player_joined`
+    pub fn handler(&self) -> String {
+        unity_event_method(&self.event).unwrap_or_default()
+    }
+
+    /// Statements sending the event to every target, appended to the handler
+    pub fn forwarding_lines(&self, udon_struct: &UdonBehaviourStruct) -> Vec<String> {
+        let mut lines = vec![format!("        // Forward {} to {}", self.event, self.targets.join(", "))];
+        for target in &self.targets {
+            let reference = target_reference(udon_struct, target);
+            lines.extend([
+                format!("        if ({} != null)", reference),
+                "        {".to_string(),
+                format!("            {}.SendCustomEvent(\"{}\");", reference, self.event),
+                "        }".to_string(),
+            ]);
+        }
+        lines
+    }
+}
+
+/// Public `UdonBehaviour` fields for forward targets the behavior holds no `UdonRef` to
+pub fn forward_target_fields(udon_struct: &UdonBehaviourStruct) -> Vec<GeneratedField> {
+    let mut fields: Vec<GeneratedField> = Vec::new();
+    for target in EventForward::for_behavior(udon_struct).iter().flat_map(|forward| &forward.targets) {
+        let name = target_reference(udon_struct, target);
+        if udon_ref_field(udon_struct, target).is_some() || fields.iter().any(|field| field.name == name) {
+            continue;
+        }
+        fields.push(GeneratedField {
+            declaration: format!("    public UdonBehaviour {} = null;", name),
+            name,
+            field_type: "UdonBehaviour".to_string(),
+            visibility: "public".to_string(),
+            attributes: Vec::new(),
+            default_value: Some("null".to_string()),
+        });
+    }
+    fields
+}
+
+/// C# name of the field a target is reached through
+fn target_reference(udon_struct: &UdonBehaviourStruct, target: &str) -> String {
+    udon_ref_field(udon_struct, target).unwrap_or_else(|| field_name_for(target))
+}
+
+/// C# name of the behavior's `UdonRef<target>` field, if it has one
+fn udon_ref_field(udon_struct: &UdonBehaviourStruct, target: &str) -> Option<String> {
+    udon_struct.fields.iter()
+        .find(|field| field.field_type == RustType::UdonRef(target.to_string()))
+        .map(|field| to_camel_case(&field.name))
+}
+
+/// camelCase field name for a behavior name, keeping acronyms together
+/// (`UIController` becomes `uiController`)
+fn field_name_for(behavior: &str) -> String {
+    let chars: Vec<char> = behavior.chars().collect();
+    let leading_upper = chars.iter().take_while(|c| c.is_uppercase()).count();
+    // The last capital of an acronym starts the next word
+    let lowered = if leading_upper > 1 && leading_upper < chars.len() { leading_upper - 1 } else { leading_upper };
+    chars.iter().enumerate()
+        .map(|(i, c)| if i < lowered { c.to_lowercase().next().unwrap_or(*c) } else { *c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_rejects_unknown_events_and_repeated_targets() {
+        assert!(EventForward::new("OnPlayerJumped", vec!["GameLogic".to_string()]).is_err());
+        assert!(EventForward::new("OnPlayerJoined", vec![]).is_err());
+        assert!(EventForward::new("OnPlayerJoined", vec!["GameLogic".to_string(), "GameLogic".to_string()]).is_err());
+
+        let forward = EventForward::new("OnDeserialization", vec!["GameLogic".to_string()]).unwrap();
+        assert_eq!(forward.handler(), "on_post_deserialization");
+        assert_eq!(field_name_for("UIController"), "uiController");
+        assert_eq!(field_name_for("GameLogic"), "gameLogic");
+    }
+}
@@ -0,0 +1,96 @@
+//! Stable numeric IDs for network events
+//!
+//! Network events are dispatched by name, but anything that stores an event as
+//! a number — a synced "last event" field read by late joiners, or a prefab
+//! referring to a handler — breaks if the number changes between builds. IDs
+//! are therefore assigned in name order rather than in the order behaviors or
+//! methods happen to be visited, and the pipeline saves the table next to the
+//! build output and loads it on the next build, so that adding an event never
+//! renumbers the existing ones.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// File the table is persisted to in the output directory
+pub const EVENT_ID_TABLE_FILE: &str = "network_event_ids.json";
+
+/// Numeric IDs of the network events of every behavior
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventIdTable {
+    /// Event IDs keyed by behavior name, then event name
+    behaviors: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl EventIdTable {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a table persisted by a previous build
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the table so the next build can keep its IDs
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Assign IDs to the events of a behavior and return them
+    ///
+    /// Events already in the table keep their ID. New events are numbered in
+    /// name order after the highest ID the behavior has used, so the IDs of
+    /// removed events are never handed to a different event.
+    pub fn assign<'a>(&mut self, behavior: &str, events: impl IntoIterator<Item = &'a str>) -> BTreeMap<String, u32> {
+        let ids = self.behaviors.entry(behavior.to_string()).or_default();
+        let mut next = ids.values().max().map_or(0, |max| max + 1);
+
+        let mut events: Vec<&str> = events.into_iter().collect();
+        events.sort_unstable();
+        events.dedup();
+
+        let mut assigned = BTreeMap::new();
+        for event in events {
+            let id = *ids.entry(event.to_string()).or_insert_with(|| {
+                next += 1;
+                next - 1
+            });
+            assigned.insert(event.to_string(), id);
+        }
+        assigned
+    }
+
+    /// Get the ID of an event
+    pub fn id(&self, behavior: &str, event: &str) -> Option<u32> {
+        self.behaviors.get(behavior)?.get(event).copied()
+    }
+}
+
+/// Name of the C# constant holding an event's ID
+pub fn event_id_constant_name(event: &str) -> String {
+    format!("{}EventId", event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_follow_name_order_and_survive_new_events() {
+        let mut table = EventIdTable::new();
+        let ids = table.assign("Cannon", ["Reload", "Fire", "Aim"]);
+        assert_eq!(ids.into_iter().collect::<Vec<_>>(), vec![
+            ("Aim".to_string(), 0),
+            ("Fire".to_string(), 1),
+            ("Reload".to_string(), 2),
+        ]);
+
+        let mut next_build = EventIdTable::from_json(&table.to_json()).unwrap();
+        let ids = next_build.assign("Cannon", ["Reload", "Fire", "Boost"]);
+        assert_eq!(ids["Fire"], 1);
+        assert_eq!(ids["Reload"], 2);
+        assert_eq!(ids["Boost"], 3);
+        assert_eq!(next_build.id("Cannon", "Aim"), Some(0));
+    }
+}
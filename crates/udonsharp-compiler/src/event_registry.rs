@@ -0,0 +1,365 @@
+//! Registry of the parameter fields expected by cross-behavior calls
+//!
+//! Udon custom events take no arguments, so calling a method of another
+//! behavior passes the arguments through public variables of the target: the
+//! sender sets one `{parameter}_param` field per parameter of the receiving
+//! method and then sends the event. A sender that forgets a field, sets a stale
+//! one, or sets one with the wrong type fails silently at runtime. This module
+//! records the parameter fields each method expects from its signature and
+//! checks every call site against them.
+
+use crate::body_lowering::BehaviorLocals;
+use crate::multi_behavior::{RustType, StructMethod, UdonBehaviourStruct};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use syn::visit::Visit;
+use syn::{Expr, Lit, Pat};
+
+/// Suffix of the public variables that carry call arguments
+pub const PARAMETER_FIELD_SUFFIX: &str = "_param";
+
+/// A public variable a receiving method reads one of its arguments from
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterField {
+    /// Name of the field on the receiving behavior
+    pub name: String,
+    /// Type of the parameter
+    pub field_type: RustType,
+}
+
+/// How a call site disagrees with the receiving method's signature
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventCallMismatch {
+    /// A parameter field of the receiving method is not set before the call
+    MissingField { field: String },
+    /// A parameter field is set that the receiving method does not take
+    UnexpectedField { field: String },
+    /// A parameter field is set with a value of another type
+    TypeMismatch { field: String, expected: RustType, actual: RustType },
+}
+
+impl fmt::Display for EventCallMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventCallMismatch::MissingField { field } => write!(f, "parameter field '{}' is not set", field),
+            EventCallMismatch::UnexpectedField { field } => {
+                write!(f, "parameter field '{}' is set but the method takes no such parameter", field)
+            }
+            EventCallMismatch::TypeMismatch { field, expected, actual } => {
+                write!(f, "parameter field '{}' expects {:?} but is set to {:?}", field, expected, actual)
+            }
+        }
+    }
+}
+
+/// A call to another behavior's method whose parameter fields do not match
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventCallError {
+    /// Behavior making the call
+    pub caller_behavior: String,
+    /// Method making the call
+    pub caller_method: String,
+    /// Behavior receiving the call
+    pub target_behavior: String,
+    /// Method receiving the call
+    pub target_method: String,
+    /// What is wrong with the call
+    pub mismatch: EventCallMismatch,
+}
+
+impl fmt::Display for EventCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Call from '{}::{}' to '{}::{}': {}",
+            self.caller_behavior, self.caller_method, self.target_behavior, self.target_method, self.mismatch
+        )
+    }
+}
+
+/// Parameter fields expected by every method of every behavior
+#[derive(Debug, Clone, Default)]
+pub struct EventSignatureRegistry {
+    /// Expected fields keyed by behavior name, then method name
+    signatures: HashMap<String, HashMap<String, Vec<ParameterField>>>,
+}
+
+impl EventSignatureRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the registry from the methods of the given behaviors
+    pub fn from_behaviors(behaviors: &[UdonBehaviourStruct]) -> Self {
+        let mut registry = Self::new();
+        for behavior in behaviors {
+            for method in &behavior.methods {
+                registry.register_method(&behavior.name, method);
+            }
+        }
+        registry
+    }
+
+    /// Register the parameter fields a method expects
+    pub fn register_method(&mut self, behavior: &str, method: &StructMethod) {
+        let fields = method.parameters.iter()
+            .map(|param| ParameterField {
+                name: parameter_field_name(&param.name),
+                field_type: param.param_type.clone(),
+            })
+            .collect();
+        self.signatures.entry(behavior.to_string()).or_default().insert(method.name.clone(), fields);
+    }
+
+    /// Get the parameter fields a method expects
+    pub fn expected_fields(&self, behavior: &str, method: &str) -> Option<&[ParameterField]> {
+        self.signatures.get(behavior)?.get(method).map(|fields| fields.as_slice())
+    }
+
+    /// Check the calls every behavior makes to other behaviors
+    pub fn check_calls(&self, behaviors: &[UdonBehaviourStruct]) -> Vec<EventCallError> {
+        let mut errors = Vec::new();
+
+        for behavior in behaviors {
+            let field_types: HashMap<String, RustType> = behavior.fields.iter()
+                .map(|field| (field.name.clone(), field.field_type.clone()))
+                .collect();
+
+            for method in &behavior.methods {
+                let Some(block) = method.body.as_deref().and_then(|body| syn::parse_str::<syn::Block>(body).ok()) else {
+                    continue;
+                };
+                let mut checker = CallChecker {
+                    registry: self,
+                    field_types: &field_types,
+                    locals: BehaviorLocals::default(),
+                    pending: HashMap::new(),
+                    calls: Vec::new(),
+                };
+                checker.visit_block(&block);
+
+                for (target_behavior, target_method, mismatch) in checker.calls {
+                    errors.push(EventCallError {
+                        caller_behavior: behavior.name.clone(),
+                        caller_method: method.name.clone(),
+                        target_behavior,
+                        target_method,
+                        mismatch,
+                    });
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+/// Name of the public variable carrying a parameter
+pub fn parameter_field_name(parameter: &str) -> String {
+    format!("{}{}", parameter, PARAMETER_FIELD_SUFFIX)
+}
+
+/// Walks a method body in order, pairing parameter-field writes with the call they precede
+struct CallChecker<'a> {
+    registry: &'a EventSignatureRegistry,
+    /// Field types of the calling behavior, for `self.field` values
+    field_types: &'a HashMap<String, RustType>,
+    /// Locals bound to another behavior
+    locals: BehaviorLocals,
+    /// Parameter fields set on each local since its last call, with the value type if known
+    pending: HashMap<String, BTreeMap<String, Option<RustType>>>,
+    /// Mismatches as `(behavior, method, mismatch)`
+    calls: Vec<(String, String, EventCallMismatch)>,
+}
+
+impl CallChecker<'_> {
+    /// Remember a binding if its initializer looks up a behavior
+    fn bind(&mut self, pat: &Pat, init: &Expr) {
+        let registry = self.registry;
+        self.locals.bind(pat, init, |behavior| registry.signatures.contains_key(behavior));
+    }
+
+    /// Best-effort type of a value assigned to a parameter field
+    fn value_type(&self, value: &Expr) -> Option<RustType> {
+        match value {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(_) => Some(RustType::I32),
+                Lit::Float(_) => Some(RustType::F32),
+                Lit::Bool(_) => Some(RustType::Bool),
+                Lit::Str(_) => Some(RustType::String),
+                _ => None,
+            },
+            Expr::Field(field) => match (&*field.base, &field.member) {
+                (Expr::Path(base), syn::Member::Named(member)) if base.path.is_ident("self") => {
+                    self.field_types.get(&member.to_string()).cloned()
+                }
+                _ => None,
+            },
+            Expr::Paren(paren) => self.value_type(&paren.expr),
+            Expr::Reference(reference) => self.value_type(&reference.expr),
+            _ => None,
+        }
+    }
+
+    fn check_call(&mut self, local: &str, method: &str) {
+        let behavior = self.locals.behavior(local).unwrap_or_default().to_string();
+        let Some(expected) = self.registry.expected_fields(&behavior, method) else {
+            return;
+        };
+        let mut set = self.pending.remove(local).unwrap_or_default();
+
+        for field in expected {
+            match set.remove(&field.name) {
+                None => self.calls.push((
+                    behavior.clone(),
+                    method.to_string(),
+                    EventCallMismatch::MissingField { field: field.name.clone() },
+                )),
+                Some(Some(actual)) if !types_compatible(&field.field_type, &actual) => self.calls.push((
+                    behavior.clone(),
+                    method.to_string(),
+                    EventCallMismatch::TypeMismatch {
+                        field: field.name.clone(),
+                        expected: field.field_type.clone(),
+                        actual,
+                    },
+                )),
+                Some(_) => {}
+            }
+        }
+        for field in set.into_keys() {
+            self.calls.push((behavior.clone(), method.to_string(), EventCallMismatch::UnexpectedField { field }));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for CallChecker<'_> {
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        syn::visit::visit_local(self, local);
+        if let Some(init) = &local.init {
+            self.bind(&local.pat, &init.expr);
+        }
+    }
+
+    fn visit_expr_let(&mut self, expr_let: &'ast syn::ExprLet) {
+        syn::visit::visit_expr_let(self, expr_let);
+        self.bind(&expr_let.pat, &expr_let.expr);
+    }
+
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        syn::visit::visit_expr_assign(self, assign);
+        if let Expr::Field(field) = &*assign.left {
+            if let (Some(local), syn::Member::Named(member)) = (self.locals.local(&field.base), &field.member) {
+                let member = member.to_string();
+                if member.ends_with(PARAMETER_FIELD_SUFFIX) {
+                    let value_type = self.value_type(&assign.right);
+                    self.pending.entry(local).or_default().insert(member, value_type);
+                }
+            }
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        syn::visit::visit_expr_method_call(self, call);
+        if let Some(local) = self.locals.local(&call.receiver) {
+            self.check_call(&local, &call.method.to_string());
+        }
+    }
+}
+
+/// Whether a value of type `actual` can be stored in a field of type `expected`
+fn types_compatible(expected: &RustType, actual: &RustType) -> bool {
+    if expected == actual {
+        return true;
+    }
+    // Unsuffixed literals take the type of the field they are assigned to
+    let is_integer = |t: &RustType| matches!(
+        t,
+        RustType::I8 | RustType::I16 | RustType::I32 | RustType::I64
+            | RustType::U8 | RustType::U16 | RustType::U32 | RustType::U64
+    );
+    match actual {
+        RustType::I32 => is_integer(expected),
+        RustType::F32 => matches!(expected, RustType::F64),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_analyzer::{AnalysisError, StructAnalyzer};
+
+    fn analyze(caller_body: &str) -> (Result<Vec<UdonBehaviourStruct>, AnalysisError>, Vec<AnalysisError>) {
+        let source = format!(
+            r#"
+            #[derive(UdonBehaviour)]
+            pub struct Scoreboard {{
+                pub points_param: i32,
+                pub reason_param: String,
+            }}
+
+            impl UdonBehaviour for Scoreboard {{
+                fn start(&mut self) {{}}
+            }}
+
+            impl Scoreboard {{
+                pub fn add_points(&mut self, points: i32, reason: String) {{}}
+            }}
+
+            #[derive(UdonBehaviour)]
+            pub struct Goal {{
+                pub scoreboard_object: GameObject,
+                bonus: i32,
+            }}
+
+            impl UdonBehaviour for Goal {{
+                fn start(&mut self) {{}}
+            }}
+
+            impl Goal {{
+                pub fn on_goal(&mut self) {caller_body}
+            }}
+            "#
+        );
+        let file: syn::File = syn::parse_str(&source).unwrap();
+        let mut analyzer = StructAnalyzer::new();
+        let result = analyzer.analyze_module(&file.items);
+        (result, analyzer.get_errors().to_vec())
+    }
+
+    #[test]
+    fn test_matching_call_is_clean() {
+        let (result, _) = analyze(
+            r#"{
+                let scoreboard = self.scoreboard_object.get_component::<Scoreboard>().unwrap();
+                scoreboard.points_param = self.bonus;
+                scoreboard.reason_param = "goal";
+                scoreboard.add_points();
+            }"#,
+        );
+        let behaviors = result.unwrap();
+        let registry = EventSignatureRegistry::from_behaviors(&behaviors);
+        assert_eq!(registry.expected_fields("Scoreboard", "add_points").unwrap().len(), 2);
+        assert!(registry.check_calls(&behaviors).is_empty());
+    }
+
+    #[test]
+    fn test_call_missing_a_parameter_field_errors() {
+        let (result, errors) = analyze(
+            r#"{
+                let scoreboard = self.scoreboard_object.get_component::<Scoreboard>().unwrap();
+                scoreboard.points_param = "three";
+                scoreboard.add_points();
+            }"#,
+        );
+        assert!(matches!(result, Err(AnalysisError::EventArgumentMismatch { .. })));
+
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages.iter().any(|m| m.contains("'Scoreboard::add_points' parameter field 'reason_param' is not set")));
+        assert!(messages.iter().any(|m| m.contains("parameter field 'points_param' expects I32 but is set to String")));
+        assert!(messages.iter().all(|m| m.starts_with("Call from 'Goal::on_goal'")));
+    }
+}
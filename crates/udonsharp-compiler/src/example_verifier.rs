@@ -0,0 +1,130 @@
+//! Compilation check of the bundled example crates
+//!
+//! The crates under `examples/` are what new users copy from, so an example
+//! that no longer compiles is worse than no example at all. This module runs
+//! the multi-behavior pipeline over each of them in memory, without writing
+//! any files, and reports which ones failed and why. It backs both a test of
+//! this crate and `cargo udonsharp check --examples`.
+
+use crate::config::UdonSharpConfig;
+use crate::lint_levels::load_project_lint_levels;
+use crate::standard_multi_behavior_integration::StandardMultiBehaviorIntegration;
+use std::fmt;
+use std::path::Path;
+use udonsharp_core::error::{CompilationContext, DiagnosticLevel};
+
+/// Marker the code generator leaves in methods whose Rust body it could not lower
+const UNLOWERED_BODY_MARKER: &str = "Rust body could not be lowered";
+
+/// Outcome of compiling one example crate
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleReport {
+    /// Directory name of the example
+    pub name: String,
+    /// Number of behaviors generated
+    pub behaviors: usize,
+    /// Number of methods emitted with a placeholder instead of their body
+    pub unlowered_methods: usize,
+    /// Why the example failed to compile, if it did
+    pub error: Option<String>,
+}
+
+impl ExampleReport {
+    /// Whether the example compiled without errors
+    pub fn success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl fmt::Display for ExampleReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "❌ {}: {}", self.name, error),
+            None if self.unlowered_methods > 0 => write!(
+                f,
+                "✅ {}: {} behavior(s), {} method body(ies) left as placeholders",
+                self.name, self.behaviors, self.unlowered_methods
+            ),
+            None => write!(f, "✅ {}: {} behavior(s)", self.name, self.behaviors),
+        }
+    }
+}
+
+/// Compile every example crate in a directory, in name order
+///
+/// An example is a subdirectory with a `src/lib.rs`. Each one is compiled with
+/// the default configuration and the lint levels of its own manifest.
+pub async fn verify_examples(examples_dir: &Path) -> std::io::Result<Vec<ExampleReport>> {
+    let mut examples: Vec<_> = std::fs::read_dir(examples_dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.join("src/lib.rs").is_file())
+        .collect();
+    examples.sort();
+
+    let mut reports = Vec::with_capacity(examples.len());
+    for example in examples {
+        reports.push(verify_example(&example).await);
+    }
+    Ok(reports)
+}
+
+/// Compile a single example crate
+pub async fn verify_example(example_dir: &Path) -> ExampleReport {
+    let mut report = ExampleReport {
+        name: example_dir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+        behaviors: 0,
+        unlowered_methods: 0,
+        error: None,
+    };
+
+    let source = match std::fs::read_to_string(example_dir.join("src/lib.rs")) {
+        Ok(source) => source,
+        Err(e) => {
+            report.error = Some(format!("failed to read src/lib.rs: {}", e));
+            return report;
+        }
+    };
+    let mut config = UdonSharpConfig::default();
+    match load_project_lint_levels(example_dir) {
+        Ok(lints) => config.lints = lints,
+        Err(e) => {
+            report.error = Some(e.to_string());
+            return report;
+        }
+    }
+
+    let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+    match integration.compile_multi_behavior(&source).await {
+        Ok(result) if result.success => {
+            report.behaviors = result.behavior_files.len();
+            report.unlowered_methods = result.behavior_files.values()
+                .map(|file| file.file_content.matches(UNLOWERED_BODY_MARKER).count())
+                .sum();
+        }
+        Ok(result) => {
+            let errors: Vec<String> = result.diagnostics.iter()
+                .filter(|diagnostic| diagnostic.level == DiagnosticLevel::Error)
+                .map(|diagnostic| diagnostic.message.clone())
+                .collect();
+            report.error = Some(if errors.is_empty() { "compilation failed".to_string() } else { errors.join("; ") });
+        }
+        Err(e) => report.error = Some(e.to_string()),
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bundled_examples_compile() {
+        let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../examples");
+        let reports = verify_examples(&examples_dir).await.unwrap();
+
+        assert!(!reports.is_empty(), "no examples found in {}", examples_dir.display());
+        let failures: Vec<String> = reports.iter().filter(|r| !r.success()).map(|r| r.to_string()).collect();
+        assert!(failures.is_empty(), "examples failed to compile:\n{}", failures.join("\n"));
+        assert!(reports.iter().all(|r| r.behaviors > 0));
+    }
+}
@@ -0,0 +1,172 @@
+//! JSON Schema export of the fields behaviors expose in the inspector
+//!
+//! External world-configuration tools need to know which values a behavior
+//! takes without parsing C#. With `--emit-field-schemas` the compiler writes a
+//! `{Behavior}.schema.json` next to each generated script describing its
+//! `#[udon_public]` fields: C# name, type, `#[udon_range]` bounds, tooltip
+//! and default value. Properties carry the C# field names, since those are
+//! the names Unity serializes the values under.
+
+use serde_json::{json, Map, Value};
+
+use crate::code_generator::to_camel_case;
+use crate::multi_behavior::{FieldAttribute, RustToCSharpTypeMapper, RustType, StructField, UdonBehaviourStruct};
+
+/// JSON Schema dialect the exported schemas declare
+pub const FIELD_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Name of the schema file written for a generated class
+pub fn field_schema_file_name(class_name: &str) -> String {
+    format!("{}.schema.json", class_name)
+}
+
+/// Build the schema of a behavior's `#[udon_public]` fields
+pub fn behavior_field_schema(udon_struct: &UdonBehaviourStruct) -> Value {
+    let type_mapper = RustToCSharpTypeMapper::new();
+    let properties: Map<String, Value> = udon_struct.fields.iter()
+        .filter(|field| field.attributes.contains(&FieldAttribute::UdonPublic))
+        .map(|field| (to_camel_case(&field.name), field_schema(field, &type_mapper)))
+        .collect();
+
+    json!({
+        "$schema": FIELD_SCHEMA_DIALECT,
+        "title": udon_struct.name,
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    })
+}
+
+/// Schema of one field, with its inspector metadata
+fn field_schema(field: &StructField, type_mapper: &RustToCSharpTypeMapper) -> Value {
+    let mut schema = type_schema(&field.field_type);
+    if let Ok(csharp_type) = type_mapper.map_type(&field.field_type) {
+        schema.insert("x-csharp-type".to_string(), Value::String(csharp_type));
+    }
+
+    for attribute in &field.attributes {
+        match attribute {
+            FieldAttribute::Range { min, max } => {
+                schema.extend(number_literal(min).map(|min| ("minimum".to_string(), min)));
+                schema.extend(number_literal(max).map(|max| ("maximum".to_string(), max)));
+            }
+            FieldAttribute::Tooltip(text) => {
+                schema.insert("description".to_string(), Value::String(text.clone()));
+            }
+            FieldAttribute::Header(text) => {
+                schema.insert("x-header".to_string(), Value::String(text.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(default) = field.default_value.as_deref().and_then(default_literal) {
+        schema.insert("default".to_string(), default);
+    }
+    Value::Object(schema)
+}
+
+/// JSON type of a field; object references have no JSON equivalent and only
+/// carry their C# type
+fn type_schema(rust_type: &RustType) -> Map<String, Value> {
+    let fixed_array = |components: usize| json!({
+        "type": "array",
+        "items": { "type": "number" },
+        "minItems": components,
+        "maxItems": components,
+    });
+    let schema = match rust_type {
+        RustType::Bool => json!({ "type": "boolean" }),
+        RustType::I8 | RustType::I16 | RustType::I32 | RustType::I64 | RustType::I128
+        | RustType::U8 | RustType::U16 | RustType::U32 | RustType::U64 | RustType::U128 => json!({ "type": "integer" }),
+        RustType::F32 | RustType::F64 => json!({ "type": "number" }),
+        RustType::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        RustType::String => json!({ "type": "string" }),
+        RustType::Vector2 => fixed_array(2),
+        RustType::Vector3 => fixed_array(3),
+        RustType::Vector4 | RustType::Quaternion | RustType::Color | RustType::Color32 => fixed_array(4),
+        RustType::Option(inner) => return type_schema(inner),
+        RustType::Vec(inner) | RustType::Array(inner, _) => json!({
+            "type": "array",
+            "items": Value::Object(type_schema(inner)),
+        }),
+        _ => json!({}),
+    };
+    match schema {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+/// JSON value of a numeric literal such as `-5` or `100.0`
+fn number_literal(literal: &str) -> Option<Value> {
+    if let Ok(integer) = literal.parse::<i64>() {
+        return Some(json!(integer));
+    }
+    literal.parse::<f64>().ok().and_then(|float| serde_json::Number::from_f64(float).map(Value::Number))
+}
+
+/// JSON value of a C# default literal; `None` for expressions such as `Vector3.zero`
+fn default_literal(literal: &str) -> Option<Value> {
+    match literal {
+        "true" => return Some(Value::Bool(true)),
+        "false" => return Some(Value::Bool(false)),
+        _ => {}
+    }
+    if literal.starts_with('"') {
+        return serde_json::from_str(literal).ok();
+    }
+    let number = literal.trim_end_matches(['f', 'd', 'm', 'L', 'U', 'u', 'l']);
+    number_literal(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_analyzer::StructAnalyzer;
+    use syn::{parse_quote, Item};
+
+    #[test]
+    fn test_ranged_float_with_tooltip_has_bounds_and_description() {
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct GameSettings {
+                    #[udon_public]
+                    #[udon_range(0.0, 100.0)]
+                    #[tooltip("Seconds before a round ends")]
+                    pub round_seconds: f32,
+                    #[udon_public]
+                    pub max_players: i32,
+                    hidden_state: i32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for GameSettings {
+                    fn start(&mut self) {}
+                }
+            },
+            parse_quote! {
+                impl GameSettings {
+                    pub fn new() -> Self {
+                        Self { round_seconds: 60.0, max_players: 8, hidden_state: 0 }
+                    }
+                }
+            },
+        ];
+        let behaviors = StructAnalyzer::new().analyze_module(&items).unwrap();
+
+        let schema = behavior_field_schema(&behaviors[0]);
+        assert_eq!(schema["title"], "GameSettings");
+        assert_eq!(schema["properties"]["roundSeconds"], json!({
+            "type": "number",
+            "x-csharp-type": "float",
+            "minimum": 0.0,
+            "maximum": 100.0,
+            "description": "Seconds before a round ends",
+            "default": 60.0,
+        }));
+        assert_eq!(schema["properties"]["maxPlayers"]["default"], 8);
+        assert!(schema["properties"].get("hiddenState").is_none());
+    }
+}
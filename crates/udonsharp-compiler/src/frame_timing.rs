@@ -0,0 +1,60 @@
+//! Detection of frame-time reads in the physics step
+//!
+//! `Time::delta_time()` is the time since the last rendered frame, which is
+//! what `update` wants. Code running in `fixed_update` steps physics at a fixed
+//! rate and should scale by `Time::fixed_delta_time()` instead; reading
+//! `delta_time` there usually means the code was moved from `update` without
+//! being adapted. This module finds those reads in the methods that only run
+//! from `fixed_update`.
+
+use std::collections::BTreeSet;
+use syn::visit::Visit;
+use syn::{Block, Expr};
+
+use crate::hot_path::reachable_methods;
+use crate::multi_behavior::UdonBehaviourStruct;
+
+/// Methods that run only from `fixed_update` and read `Time::delta_time()`.
+///
+/// Helpers also reached from `update` or `late_update` are skipped, since
+/// frame time is correct for those callers.
+pub fn fixed_update_delta_time_reads(udon_struct: &UdonBehaviourStruct) -> Vec<String> {
+    let frame_methods = reachable_methods(udon_struct, &["update", "late_update"]);
+    let fixed_methods: BTreeSet<String> = reachable_methods(udon_struct, &["fixed_update"])
+        .into_iter()
+        .filter(|method| !frame_methods.contains(method))
+        .collect();
+
+    udon_struct.methods.iter()
+        .filter(|method| fixed_methods.contains(&method.name))
+        .filter(|method| {
+            let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<Block>(b).ok()) else {
+                return false;
+            };
+            let mut finder = DeltaTimeFinder { found: false };
+            finder.visit_block(&block);
+            finder.found
+        })
+        .map(|method| method.name.clone())
+        .collect()
+}
+
+/// Looks for calls of `delta_time()`, `Time::delta_time()` or `time::delta_time()`
+struct DeltaTimeFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for DeltaTimeFinder {
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let Expr::Path(path) = &*call.func {
+            let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let is_delta_time = match segments.as_slice() {
+                [function] => function == "delta_time",
+                [.., owner, function] => function == "delta_time" && (owner == "Time" || owner == "time"),
+                [] => false,
+            };
+            self.found |= is_delta_time;
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
@@ -0,0 +1,298 @@
+//! Inlining of small helper methods into their only caller
+//!
+//! Every method call in Udon is a handful of extern and jump instructions, so
+//! tiny helpers such as `fn is_host(&self) -> bool { Networking::is_master() }`
+//! cost more to call than to evaluate. A private helper whose body is a single
+//! expression below a size threshold, and which is called from exactly one
+//! place, is substituted into that call and removed from the behavior.
+//!
+//! Helpers are left alone when inlining could change behavior: recursive or
+//! chained helpers (their body calls a method on `self`), bodies with
+//! assignments, macros, loops or early returns, and calls whose arguments are
+//! not plain literals, paths or field reads, since substituting those could
+//! evaluate them a different number of times.
+
+use crate::multi_behavior::{UdonBehaviourStruct, Visibility};
+use proc_macro2::{TokenStream, TokenTree};
+use std::collections::HashMap;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{Block, Expr, Stmt};
+
+/// Default largest helper body, in tokens, that is inlined
+pub const DEFAULT_INLINE_MAX_TOKENS: usize = 32;
+
+/// Inline small single-caller helpers of a behavior, returning the inlined helper names
+pub fn inline_small_helpers(udon_struct: &mut UdonBehaviourStruct, max_tokens: usize) -> Vec<String> {
+    let mut inlined = Vec::new();
+
+    // Inlining one helper never makes another one inlinable, since helpers
+    // calling methods on `self` are excluded, so a single pass is enough
+    let mut bodies: HashMap<String, Block> = udon_struct.methods.iter()
+        .filter_map(|m| Some((m.name.clone(), syn::parse_str::<Block>(m.body.as_deref()?).ok()?)))
+        .collect();
+
+    let mut call_sites: HashMap<String, Vec<String>> = HashMap::new();
+    for (caller, block) in &bodies {
+        let mut counter = SelfCallCounter { calls: Vec::new() };
+        counter.visit_block(block);
+        for callee in counter.calls {
+            call_sites.entry(callee).or_default().push(caller.clone());
+        }
+    }
+
+    let mut names: Vec<String> = udon_struct.methods.iter().map(|m| m.name.clone()).collect();
+    names.sort();
+    for name in names {
+        let Some(method) = udon_struct.methods.iter().find(|m| m.name == name) else {
+            continue;
+        };
+        let trait_method = udon_struct.trait_impl.as_ref()
+            .is_some_and(|t| t.implemented_methods.contains(&method.name));
+        if method.visibility == Visibility::Public
+            || !method.attributes.is_empty()
+            || method.is_unity_event()
+            || trait_method
+        {
+            continue;
+        }
+
+        let Some(expr) = bodies.get(&name).and_then(|block| inlinable_expr(block, max_tokens)) else {
+            continue;
+        };
+        let [caller] = call_sites.get(&name).map(Vec::as_slice).unwrap_or_default() else {
+            continue;
+        };
+        if *caller == name {
+            continue;
+        }
+
+        let parameters: Vec<String> = method.parameters.iter().map(|p| p.name.clone()).collect();
+        let Some(caller_block) = bodies.get_mut(caller) else {
+            continue;
+        };
+        let mut inliner = CallInliner { helper: &name, parameters: &parameters, body: &expr, inlined: false };
+        inliner.visit_block_mut(caller_block);
+        if !inliner.inlined {
+            continue;
+        }
+
+        let caller_body = quote::quote!(#caller_block).to_string();
+        if let Some(caller_method) = udon_struct.methods.iter_mut().find(|m| m.name == *caller) {
+            caller_method.set_body(caller_body);
+        }
+        udon_struct.methods.retain(|m| m.name != name);
+        bodies.remove(&name);
+        inlined.push(name);
+    }
+
+    inlined
+}
+
+/// Get the expression of a helper body that qualifies for inlining
+fn inlinable_expr(block: &Block, max_tokens: usize) -> Option<Expr> {
+    let [Stmt::Expr(expr, None)] = block.stmts.as_slice() else {
+        return None;
+    };
+    if token_count(quote::quote!(#expr)) > max_tokens {
+        return None;
+    }
+
+    let mut checker = SideEffectChecker { bounded: true };
+    checker.visit_expr(expr);
+    checker.bounded.then(|| expr.clone())
+}
+
+/// Number of tokens in a stream, counting the contents of groups
+fn token_count(tokens: TokenStream) -> usize {
+    tokens.into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => 2 + token_count(group.stream()),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Whether an expression is `self`
+fn is_self(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(path) if path.path.is_ident("self"))
+}
+
+/// Whether an argument can be substituted for a parameter without changing
+/// how often or when it is evaluated
+fn is_pure_argument(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) | Expr::Path(_) => true,
+        Expr::Field(field) => is_pure_argument(&field.base),
+        Expr::Paren(paren) => is_pure_argument(&paren.expr),
+        Expr::Reference(reference) => is_pure_argument(&reference.expr),
+        _ => false,
+    }
+}
+
+/// Collects the names of methods called on `self`
+struct SelfCallCounter {
+    calls: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for SelfCallCounter {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if is_self(&call.receiver) {
+            self.calls.push(call.method.to_string());
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Rejects expressions whose effects could change when moved into the caller
+struct SideEffectChecker {
+    bounded: bool,
+}
+
+impl<'ast> Visit<'ast> for SideEffectChecker {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::Assign(_) | Expr::Macro(_) | Expr::Return(_) | Expr::Try(_) | Expr::Await(_)
+            | Expr::Loop(_) | Expr::While(_) | Expr::ForLoop(_) | Expr::Break(_) | Expr::Continue(_)
+            | Expr::Closure(_) | Expr::Block(_) => self.bounded = false,
+            Expr::Binary(binary) if is_compound_assignment(&binary.op) => self.bounded = false,
+            Expr::MethodCall(call) if is_self(&call.receiver) => self.bounded = false,
+            _ => syn::visit::visit_expr(self, expr),
+        }
+    }
+}
+
+fn is_compound_assignment(op: &syn::BinOp) -> bool {
+    use syn::BinOp::*;
+    matches!(
+        op,
+        AddAssign(_) | SubAssign(_) | MulAssign(_) | DivAssign(_) | RemAssign(_)
+            | BitXorAssign(_) | BitAndAssign(_) | BitOrAssign(_) | ShlAssign(_) | ShrAssign(_)
+    )
+}
+
+/// Replaces the call of a helper with its body, substituting the arguments
+struct CallInliner<'a> {
+    helper: &'a str,
+    parameters: &'a [String],
+    body: &'a Expr,
+    inlined: bool,
+}
+
+impl VisitMut for CallInliner<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::MethodCall(call) = expr {
+            if is_self(&call.receiver)
+                && call.method == self.helper
+                && call.args.len() == self.parameters.len()
+                && call.args.iter().all(is_pure_argument)
+            {
+                let arguments: HashMap<&str, &Expr> = self.parameters.iter()
+                    .map(String::as_str)
+                    .zip(call.args.iter())
+                    .collect();
+                let mut body = self.body.clone();
+                ParameterSubstitution { arguments: &arguments }.visit_expr_mut(&mut body);
+                *expr = syn::parse_quote!((#body));
+                self.inlined = true;
+                return;
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// Replaces parameter names with the arguments of the call
+pub(crate) struct ParameterSubstitution<'a> {
+    pub(crate) arguments: &'a HashMap<&'a str, &'a Expr>,
+}
+
+impl VisitMut for ParameterSubstitution<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(path) = expr {
+            if let Some(argument) = path.path.get_ident().and_then(|ident| self.arguments.get(ident.to_string().as_str())) {
+                *expr = (*argument).clone();
+                return;
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_analyzer::StructAnalyzer;
+
+    fn analyze(methods: &str) -> UdonBehaviourStruct {
+        let source = format!(
+            r#"
+            #[derive(UdonBehaviour)]
+            pub struct Lobby {{
+                players: i32,
+                capacity: i32,
+            }}
+
+            impl UdonBehaviour for Lobby {{
+                fn start(&mut self) {{}}
+            }}
+
+            impl Lobby {{ {} }}
+            "#,
+            methods
+        );
+        let file: syn::File = syn::parse_str(&source).unwrap();
+        StructAnalyzer::new().analyze_module(&file.items).unwrap().remove(0)
+    }
+
+    fn body(udon_struct: &UdonBehaviourStruct, method: &str) -> String {
+        udon_struct.methods.iter().find(|m| m.name == method).unwrap().body.clone().unwrap()
+    }
+
+    #[test]
+    fn test_single_caller_helper_is_inlined() {
+        let mut lobby = analyze(
+            r#"
+            fn has_room(&self, extra: i32) -> bool {
+                self.players + extra <= self.capacity
+            }
+
+            pub fn try_join(&mut self) {
+                if self.has_room(1) {
+                    self.players += 1;
+                }
+            }
+            "#,
+        );
+
+        assert_eq!(inline_small_helpers(&mut lobby, DEFAULT_INLINE_MAX_TOKENS), vec!["has_room".to_string()]);
+        assert!(lobby.methods.iter().all(|m| m.name != "has_room"));
+        let try_join = body(&lobby, "try_join");
+        assert!(try_join.contains("if (self . players + 1 <= self . capacity)"), "{}", try_join);
+    }
+
+    #[test]
+    fn test_helper_with_several_callers_is_kept() {
+        let mut lobby = analyze(
+            r#"
+            fn is_full(&self) -> bool {
+                self.players >= self.capacity
+            }
+
+            pub fn try_join(&mut self) {
+                if !self.is_full() {
+                    self.players += 1;
+                }
+            }
+
+            pub fn status(&self) -> bool {
+                self.is_full()
+            }
+            "#,
+        );
+
+        assert!(inline_small_helpers(&mut lobby, DEFAULT_INLINE_MAX_TOKENS).is_empty());
+        assert!(lobby.methods.iter().any(|m| m.name == "is_full"));
+        assert!(body(&lobby, "status").contains("self . is_full ()"));
+    }
+}
@@ -0,0 +1,108 @@
+//! Detection of per-frame work that scales with collection size
+//!
+//! Rolling windows such as `self.frame_times.remove(0)` after a `push` look
+//! cheap in Rust, but Vec fields lower to arrays and removing the front
+//! element becomes an explicit shift of every remaining element. In methods
+//! that run every frame this is an O(n) copy per frame. This module finds the
+//! methods reachable from the per-frame events and the front removals in them.
+
+use std::collections::{BTreeSet, HashMap};
+use syn::visit::Visit;
+use syn::{Block, Expr, Lit};
+
+use crate::multi_behavior::UdonBehaviourStruct;
+
+/// Unity events that run every frame or physics step
+pub const FRAME_EVENTS: &[&str] = &["update", "fixed_update", "late_update"];
+
+/// Names of the methods that run every frame: the frame events and every
+/// method they call on `self`, directly or transitively
+pub fn update_reachable_methods(udon_struct: &UdonBehaviourStruct) -> BTreeSet<String> {
+    reachable_methods(udon_struct, FRAME_EVENTS)
+}
+
+/// Names of the given root methods and every method they call on `self`,
+/// directly or transitively
+pub fn reachable_methods(udon_struct: &UdonBehaviourStruct, roots: &[&str]) -> BTreeSet<String> {
+    let calls: HashMap<&str, BTreeSet<String>> = udon_struct.methods.iter()
+        .map(|method| {
+            let mut collector = SelfCallCollector { calls: BTreeSet::new() };
+            if let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<Block>(b).ok()) {
+                collector.visit_block(&block);
+            }
+            (method.name.as_str(), collector.calls)
+        })
+        .collect();
+
+    let mut reachable = BTreeSet::new();
+    let mut pending: Vec<String> = roots.iter()
+        .filter(|root| calls.contains_key(**root))
+        .map(|root| root.to_string())
+        .collect();
+    while let Some(method) = pending.pop() {
+        if !reachable.insert(method.clone()) {
+            continue;
+        }
+        if let Some(callees) = calls.get(method.as_str()) {
+            pending.extend(callees.iter().filter(|callee| calls.contains_key(callee.as_str())).cloned());
+        }
+    }
+    reachable
+}
+
+/// Fields whose first element is removed with `self.field.remove(0)`
+pub fn front_removals(block: &Block) -> Vec<String> {
+    let mut finder = FrontRemovalFinder { fields: BTreeSet::new() };
+    finder.visit_block(block);
+    finder.fields.into_iter().collect()
+}
+
+/// Collects the names of methods called on `self`
+struct SelfCallCollector {
+    calls: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for SelfCallCollector {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if matches!(&*call.receiver, Expr::Path(path) if path.path.is_ident("self")) {
+            self.calls.insert(call.method.to_string());
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Collects fields shifted by `remove(0)`
+struct FrontRemovalFinder {
+    fields: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for FrontRemovalFinder {
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if call.method == "remove" && call.args.len() == 1 && is_zero(&call.args[0]) {
+            if let Some(field) = self_field(&call.receiver) {
+                self.fields.insert(field);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+}
+
+fn is_zero(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(&lit.lit, Lit::Int(int) if int.base10_digits() == "0"),
+        Expr::Paren(paren) => is_zero(&paren.expr),
+        _ => false,
+    }
+}
+
+fn self_field(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Field(field) => match (&*field.base, &field.member) {
+            (Expr::Path(base), syn::Member::Named(member)) if base.path.is_ident("self") => {
+                Some(member.to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
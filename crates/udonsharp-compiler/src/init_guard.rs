@@ -0,0 +1,132 @@
+//! Detection of initialization guards that are never opened
+//!
+//! Behaviors often keep an `initialized: bool` field and return early from
+//! `update` until `start` sets it. If a refactor drops the assignment from
+//! `start`, `update` silently never runs again. This module finds boolean
+//! fields used as such a guard in the frame events and reports the ones that
+//! neither `start` nor the methods it calls ever set to `true`.
+
+use std::collections::BTreeSet;
+use syn::visit::Visit;
+use syn::{Block, Expr, Lit, Stmt, UnOp};
+
+use crate::hot_path::{reachable_methods, FRAME_EVENTS};
+use crate::multi_behavior::{RustType, UdonBehaviourStruct, Visibility};
+
+/// Field names that mark a boolean as an initialization guard wherever it is read
+const GUARD_FIELD_NAMES: &[&str] = &["initialized", "is_initialized", "ready", "is_ready", "started", "has_started"];
+
+/// Guard fields read in the frame events that `start` never sets to `true`.
+///
+/// A field is a guard when a frame event returns early while it is false, or
+/// when it has a conventional guard name and is read there. Guards that start
+/// out `true`, or that another entry point such as an event handler sets to
+/// `true` (deliberately deferred initialization), are not reported.
+pub fn unset_init_guards(udon_struct: &UdonBehaviourStruct) -> Vec<String> {
+    let bool_fields: BTreeSet<String> = udon_struct.fields.iter()
+        .filter(|f| f.field_type == RustType::Bool && f.default_value.as_deref() != Some("true"))
+        .map(|f| f.name.clone())
+        .collect();
+    if bool_fields.is_empty() {
+        return Vec::new();
+    }
+
+    let mut finder = GuardFinder { fields: &bool_fields, guards: BTreeSet::new() };
+    for method in udon_struct.methods.iter().filter(|m| FRAME_EVENTS.contains(&m.name.as_str())) {
+        if let Some(block) = parse_body(method.body.as_deref()) {
+            finder.visit_block(&block);
+        }
+    }
+    if finder.guards.is_empty() {
+        return Vec::new();
+    }
+
+    let start_methods = reachable_methods(udon_struct, &["start"]);
+    let other_roots: Vec<&str> = udon_struct.methods.iter()
+        .filter(|m| m.name != "start")
+        .filter(|m| m.is_unity_event() || m.is_custom_event() || m.is_network_event() || m.visibility == Visibility::Public)
+        .map(|m| m.name.as_str())
+        .collect();
+    let other_methods = reachable_methods(udon_struct, &other_roots);
+    let mut set_in_start = TrueAssignmentFinder { fields: BTreeSet::new() };
+    let mut set_elsewhere = TrueAssignmentFinder { fields: BTreeSet::new() };
+    for method in &udon_struct.methods {
+        let Some(block) = parse_body(method.body.as_deref()) else {
+            continue;
+        };
+        if start_methods.contains(&method.name) {
+            set_in_start.visit_block(&block);
+        } else if other_methods.contains(&method.name) {
+            set_elsewhere.visit_block(&block);
+        }
+    }
+
+    finder.guards.into_iter()
+        .filter(|field| !set_in_start.fields.contains(field) && !set_elsewhere.fields.contains(field))
+        .collect()
+}
+
+fn parse_body(body: Option<&str>) -> Option<Block> {
+    body.and_then(|b| syn::parse_str::<Block>(b).ok())
+}
+
+/// Collects boolean fields that guard a frame event
+struct GuardFinder<'a> {
+    fields: &'a BTreeSet<String>,
+    guards: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for GuardFinder<'_> {
+    fn visit_expr_if(&mut self, expr_if: &'ast syn::ExprIf) {
+        // `if !self.field { return; }`
+        if let Expr::Unary(unary) = &*expr_if.cond {
+            if let (UnOp::Not(_), Some(field)) = (&unary.op, self_field(&unary.expr)) {
+                let returns = matches!(
+                    expr_if.then_branch.stmts.first(),
+                    Some(Stmt::Expr(Expr::Return(_), _))
+                );
+                if returns && self.fields.contains(&field) {
+                    self.guards.insert(field);
+                }
+            }
+        }
+        syn::visit::visit_expr_if(self, expr_if);
+    }
+
+    fn visit_expr_field(&mut self, field: &'ast syn::ExprField) {
+        if let Some(name) = self_field(&Expr::Field(field.clone())) {
+            if self.fields.contains(&name) && GUARD_FIELD_NAMES.contains(&name.as_str()) {
+                self.guards.insert(name);
+            }
+        }
+        syn::visit::visit_expr_field(self, field);
+    }
+}
+
+/// Collects fields assigned `self.field = true`
+struct TrueAssignmentFinder {
+    fields: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for TrueAssignmentFinder {
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        let is_true = matches!(&*assign.right, Expr::Lit(lit) if matches!(&lit.lit, Lit::Bool(b) if b.value));
+        if let (true, Some(field)) = (is_true, self_field(&assign.left)) {
+            self.fields.insert(field);
+        }
+        syn::visit::visit_expr_assign(self, assign);
+    }
+}
+
+fn self_field(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Field(field) => match (&*field.base, &field.member) {
+            (Expr::Path(base), syn::Member::Named(member)) if base.path.is_ident("self") => {
+                Some(member.to_string())
+            }
+            _ => None,
+        },
+        Expr::Paren(paren) => self_field(&paren.expr),
+        _ => None,
+    }
+}
@@ -54,42 +54,41 @@ impl InitializationCoordinator {
     }
     
     /// Automatically determine initialization order using topological sort
+    ///
+    /// Dependencies come before the behaviors that call them; behaviors with no
+    /// ordering constraint between them keep the order they were declared in.
     fn auto_determine_order(&self, behavior_units: &[BehaviorUnit]) -> Result<Vec<String>> {
-        let mut dependency_graph = self.build_dependency_graph(behavior_units)?;
+        let dependency_graph = self.build_dependency_graph(behavior_units)?;
         let mut initialization_order = Vec::new();
-        let mut in_degree = HashMap::new();
-        let mut queue = VecDeque::new();
+        let mut remaining_dependencies = HashMap::new();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
         
-        // Calculate in-degrees for all behaviors
+        // Count the dependencies of each behavior that are part of this project
         for behavior_unit in behavior_units {
-            in_degree.insert(behavior_unit.name.clone(), 0);
-        }
-        
-        for (behavior_name, dependencies) in &dependency_graph {
-            for dependency in dependencies {
-                *in_degree.entry(dependency.clone()).or_insert(0) += 1;
+            let dependencies: Vec<&String> = dependency_graph[&behavior_unit.name].iter()
+                .filter(|dependency| behavior_units.iter().any(|b| &b.name == *dependency))
+                .collect();
+            for dependency in &dependencies {
+                dependents.entry(dependency.as_str()).or_default().push(&behavior_unit.name);
             }
+            remaining_dependencies.insert(behavior_unit.name.as_str(), dependencies.len());
         }
         
-        // Find behaviors with no dependencies (in-degree 0)
-        for (behavior_name, degree) in &in_degree {
-            if *degree == 0 {
-                queue.push_back(behavior_name.clone());
-            }
-        }
+        // Start with behaviors that depend on nothing
+        let mut queue: VecDeque<&str> = behavior_units.iter()
+            .map(|b| b.name.as_str())
+            .filter(|name| remaining_dependencies[name] == 0)
+            .collect();
         
         // Process behaviors in topological order
         while let Some(current_behavior) = queue.pop_front() {
-            initialization_order.push(current_behavior.clone());
+            initialization_order.push(current_behavior.to_string());
             
-            // Reduce in-degree for dependent behaviors
-            if let Some(dependencies) = dependency_graph.get(&current_behavior) {
-                for dependency in dependencies {
-                    if let Some(degree) = in_degree.get_mut(dependency) {
-                        *degree -= 1;
-                        if *degree == 0 {
-                            queue.push_back(dependency.clone());
-                        }
+            for dependent in dependents.get(current_behavior).into_iter().flatten() {
+                if let Some(remaining) = remaining_dependencies.get_mut(dependent) {
+                    *remaining -= 1;
+                    if *remaining == 0 {
+                        queue.push_back(dependent);
                     }
                 }
             }
@@ -376,6 +375,110 @@ impl InitializationCoordinator {
         Ok(code)
     }
     
+    /// Generate the `_Bootstrap` behavior that wires a multi-behavior system together
+    ///
+    /// The bootstrap locates the SharedRuntime and every behavior among its own
+    /// children unless they are assigned in the inspector, assigns each
+    /// behavior's cross-references, and then activates them in initialization
+    /// order, so a scene only needs the bootstrap object with the behaviors
+    /// beneath it.
+    pub fn generate_bootstrap(
+        &self,
+        behavior_units: &[BehaviorUnit],
+        include_shared_runtime: bool,
+        namespace: Option<&str>,
+    ) -> Result<BootstrapGenerationResult> {
+        let behavior_order = self.determine_initialization_order(behavior_units)?;
+        let mut initialization_order = Vec::new();
+        if include_shared_runtime {
+            initialization_order.push(SHARED_RUNTIME_CLASS_NAME.to_string());
+        }
+        initialization_order.extend(behavior_order.iter().map(|name| self.to_pascal_case(name)));
+        
+        let mut code = String::new();
+        code.push_str("using UnityEngine;\n");
+        code.push_str("using VRC.SDKBase;\n");
+        code.push_str("using VRC.Udon;\n");
+        code.push_str("using UdonSharp;\n\n");
+        
+        let indent = if let Some(ns) = namespace {
+            code.push_str(&format!("namespace {}\n{{\n", ns));
+            "    "
+        } else {
+            ""
+        };
+        
+        code.push_str(&format!("{}/// <summary>\n", indent));
+        code.push_str(&format!("{}/// Wires up and starts the multi-behavior system\n", indent));
+        code.push_str(&format!("{}/// Initialization order: {}\n", indent, initialization_order.join(", ")));
+        code.push_str(&format!("{}/// </summary>\n", indent));
+        code.push_str(&format!("{}[UdonBehaviourSyncMode(BehaviourSyncMode.None)]\n", indent));
+        code.push_str(&format!("{}public class {} : UdonSharpBehaviour\n", indent, BOOTSTRAP_CLASS_NAME));
+        code.push_str(&format!("{}{{\n", indent));
+        
+        // One field per component, filled in the inspector or found among the children
+        for class_name in &initialization_order {
+            code.push_str(&format!("{}    [SerializeField] private {} {};\n", indent, class_name, bootstrap_field_name(class_name)));
+        }
+        
+        code.push_str(&format!("\n{}    void Start()\n", indent));
+        code.push_str(&format!("{}    {{\n", indent));
+        code.push_str(&format!("{}        // Locate components that were not assigned in the inspector\n", indent));
+        for class_name in &initialization_order {
+            let field = bootstrap_field_name(class_name);
+            code.push_str(&format!("{}        if ({} == null) {} = GetComponentInChildren<{}>(true);\n", indent, field, field, class_name));
+            code.push_str(&format!("{}        if ({} == null)\n", indent, field));
+            code.push_str(&format!("{}        {{\n", indent));
+            code.push_str(&format!("{}            Debug.LogError(\"[{}] No {} found under the bootstrap object\");\n", indent, BOOTSTRAP_CLASS_NAME, class_name));
+            code.push_str(&format!("{}            return;\n", indent));
+            code.push_str(&format!("{}        }}\n", indent));
+        }
+        
+        code.push_str(&format!("\n{}        // Assign cross-references\n", indent));
+        for behavior_name in &behavior_order {
+            let Some(behavior_unit) = behavior_units.iter().find(|b| &b.name == behavior_name) else {
+                continue;
+            };
+            let field = bootstrap_field_name(&self.to_pascal_case(behavior_name));
+            let mut targets: Vec<&String> = behavior_unit.inter_behavior_calls.iter()
+                .filter(|call| call.call_type == CallType::Direct)
+                .map(|call| &call.target_behavior)
+                .filter(|target| behavior_units.iter().any(|b| &b.name == *target))
+                .collect();
+            targets.sort();
+            targets.dedup();
+            for target in targets {
+                code.push_str(&format!(
+                    "{}        {}.SetProgramVariable(\"_{}_reference\", {});\n",
+                    indent, field, self.to_snake_case(target), bootstrap_field_name(&self.to_pascal_case(target))
+                ));
+            }
+            if include_shared_runtime && !behavior_unit.shared_dependencies.is_empty() {
+                code.push_str(&format!(
+                    "{}        {}.SetProgramVariable(\"_sharedRuntime\", {});\n",
+                    indent, field, bootstrap_field_name(SHARED_RUNTIME_CLASS_NAME)
+                ));
+            }
+        }
+        
+        code.push_str(&format!("\n{}        // Start components in initialization order\n", indent));
+        for class_name in &initialization_order {
+            code.push_str(&format!("{}        {}.gameObject.SetActive(true);\n", indent, bootstrap_field_name(class_name)));
+        }
+        code.push_str(&format!("{}        Debug.Log(\"[{}] Started {} component(s)\");\n", indent, BOOTSTRAP_CLASS_NAME, initialization_order.len()));
+        code.push_str(&format!("{}    }}\n", indent));
+        code.push_str(&format!("{}}}\n", indent));
+        
+        if namespace.is_some() {
+            code.push_str("}\n");
+        }
+        
+        Ok(BootstrapGenerationResult {
+            bootstrap_code: code,
+            initialization_order,
+        })
+    }
+    
     /// Generate Unity script execution order settings
     fn generate_script_execution_order(&self, initialization_order: &[String]) -> Result<ScriptExecutionOrder> {
         let mut execution_order = Vec::new();
@@ -431,6 +534,30 @@ impl InitializationCoordinator {
     }
 }
 
+/// Class name of the generated bootstrap behavior
+pub const BOOTSTRAP_CLASS_NAME: &str = "_Bootstrap";
+
+/// Class name of the generated SharedRuntime
+const SHARED_RUNTIME_CLASS_NAME: &str = "SharedRuntime";
+
+/// Name of the bootstrap field holding a component
+fn bootstrap_field_name(class_name: &str) -> String {
+    let mut chars = class_name.chars();
+    match chars.next() {
+        Some(first) => format!("_{}{}", first.to_lowercase(), chars.as_str()),
+        None => String::new(),
+    }
+}
+
+/// Result of bootstrap generation
+#[derive(Debug)]
+pub struct BootstrapGenerationResult {
+    /// Generated `_Bootstrap` class code
+    pub bootstrap_code: String,
+    /// Class names in the order the bootstrap starts them, SharedRuntime first
+    pub initialization_order: Vec<String>,
+}
+
 /// Result of coordinator generation
 #[derive(Debug)]
 pub struct CoordinatorGenerationResult {
@@ -534,4 +661,62 @@ impl ScriptExecutionOrder {
         
         code
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use udonsharp_core::attributes::UdonBehaviourMarker;
+    use wasm2usharp_enhanced::InterBehaviorCall;
+
+    fn behavior_unit(name: &str, calls: &[&str], uses_shared_runtime: bool) -> BehaviorUnit {
+        BehaviorUnit {
+            name: name.to_string(),
+            entry_function: format!("{}_start", name.to_lowercase()),
+            entry_function_index: 0,
+            unity_events: vec!["Start".to_string()],
+            local_functions: HashSet::new(),
+            shared_dependencies: if uses_shared_runtime { ["clamp".to_string()].into_iter().collect() } else { HashSet::new() },
+            inter_behavior_calls: calls.iter()
+                .map(|target| InterBehaviorCall {
+                    source_behavior: name.to_string(),
+                    target_behavior: target.to_string(),
+                    function_name: "refresh".to_string(),
+                    call_type: CallType::Direct,
+                })
+                .collect(),
+            attribute_config: UdonBehaviourMarker {
+                name: Some(name.to_string()),
+                events: vec!["Start".to_string()],
+                dependencies: Vec::new(),
+                auto_sync: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_lists_shared_runtime_and_behaviors_in_init_order() {
+        // Door calls Scoreboard, which calls Timer, so Timer starts first
+        let units = vec![
+            behavior_unit("Door", &["Scoreboard"], false),
+            behavior_unit("Scoreboard", &["Timer"], true),
+            behavior_unit("Timer", &[], false),
+        ];
+        let coordinator = InitializationCoordinator::new(InitializationOrderSettings::default());
+        let bootstrap = coordinator.generate_bootstrap(&units, true, None).unwrap();
+
+        assert_eq!(bootstrap.initialization_order, vec!["SharedRuntime", "Timer", "Scoreboard", "Door"]);
+        let code = &bootstrap.bootstrap_code;
+        assert!(code.contains("public class _Bootstrap : UdonSharpBehaviour"));
+        assert!(code.contains("/// Initialization order: SharedRuntime, Timer, Scoreboard, Door"));
+        assert!(code.contains("if (_timer == null) _timer = GetComponentInChildren<Timer>(true);"));
+        assert!(code.contains("_door.SetProgramVariable(\"_scoreboard_reference\", _scoreboard);"));
+        assert!(code.contains("_scoreboard.SetProgramVariable(\"_sharedRuntime\", _sharedRuntime);"));
+        assert!(!code.contains("_door.SetProgramVariable(\"_sharedRuntime\""));
+
+        let activations: Vec<usize> = ["_sharedRuntime", "_timer", "_scoreboard", "_door"].iter()
+            .map(|field| code.find(&format!("{}.gameObject.SetActive(true);", field)).unwrap())
+            .collect();
+        assert!(activations.windows(2).all(|pair| pair[0] < pair[1]), "{}", code);
+    }
+}
@@ -720,6 +720,7 @@ impl CustomEventRouter {
         let event_name = method.attributes.iter()
             .find_map(|attr| match attr {
                 MethodAttribute::UdonEvent(name) => Some(name.clone()),
+                _ => None,
             });
 
         if let Some(event_name) = event_name {
@@ -5,15 +5,38 @@
 
 pub mod config;
 pub mod pipeline;
+pub mod phase_log;
 pub mod wasm_compiler;
 pub mod optimizer;
 pub mod prefab_generator;
+pub mod asset_meta;
 pub mod initialization_coordinator;
 pub mod multi_behavior;
 pub mod struct_analyzer;
 pub mod trait_validator;
 pub mod behavior_dependency_analyzer;
 pub mod code_generator;
+pub mod body_lowering;
+pub mod const_eval;
+pub mod split_advisor;
+pub mod master_guard;
+pub mod division_guard;
+pub mod hot_path;
+pub mod frame_timing;
+pub mod init_guard;
+pub mod dynamic_find;
+pub mod serde_compat;
+pub mod lint_levels;
+pub mod helper_inlining;
+pub mod event_registry;
+pub mod event_ids;
+pub mod quantization;
+pub mod record_pool;
+pub mod event_forwarding;
+pub mod const_tables;
+pub mod sync_layout;
+pub mod method_expansion;
+pub mod udon_assembly_lowering;
 pub mod inter_behavior_communication;
 pub mod shared_runtime;
 pub mod error_detection;
@@ -24,15 +47,39 @@ pub mod standard_multi_behavior_integration;
 pub mod debug_info_generator;
 pub mod dependency_analyzer_tool;
 pub mod compilation_reporter;
+pub mod example_verifier;
+pub mod debug_overlay;
+pub mod field_schema;
+pub mod behavior_manifest;
+pub mod unity_package;
+pub mod budget_stats;
+pub mod payload_enum;
+pub mod reload_signal;
 
 pub use config::*;
 pub use pipeline::*;
+pub use phase_log::*;
 pub use prefab_generator::*;
+pub use asset_meta::*;
 pub use initialization_coordinator::*;
 pub use multi_behavior::*;
 pub use struct_analyzer::*;
 pub use behavior_dependency_analyzer::*;
 pub use code_generator::*;
+pub use body_lowering::*;
+pub use const_eval::*;
+pub use split_advisor::*;
+pub use quantization::*;
+pub use record_pool::*;
+pub use event_forwarding::*;
+pub use const_tables::*;
+pub use sync_layout::*;
+pub use helper_inlining::*;
+pub use lint_levels::*;
+pub use event_registry::*;
+pub use event_ids::*;
+pub use method_expansion::*;
+pub use udon_assembly_lowering::*;
 pub use inter_behavior_communication::*;
 pub use shared_runtime::*;
 pub use error_detection::*;
@@ -43,6 +90,14 @@ pub use standard_multi_behavior_integration::*;
 pub use debug_info_generator::*;
 pub use dependency_analyzer_tool::*;
 pub use compilation_reporter::*;
+pub use example_verifier::*;
+pub use debug_overlay::*;
+pub use field_schema::*;
+pub use behavior_manifest::*;
+pub use unity_package::*;
+pub use budget_stats::*;
+pub use payload_enum::*;
+pub use reload_signal::*;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,134 @@
+//! Project-wide and item-level control of analysis lints
+//!
+//! Every lint of the struct analyzer has a name and reports at `warn` unless
+//! configured otherwise. A project sets levels in the `[lints]` table of
+//! `udonsharp.toml` or in `[package.metadata.udonsharp.lints]` of its
+//! Cargo.toml: `deny` turns the lint's findings into analysis errors that fail
+//! the build, and `allow` silences it. `#[udon_allow(lint_name)]` on a
+//! behavior, field or method silences a lint for just that item, the way
+//! `#[allow]` does for rustc lints.
+
+use crate::config::ConfigError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Names of the lints reported by the struct analyzer
+pub const LINTS: &[&str] = &[
+    "cross_behavior_access",
+    "cross_behavior_mutation",
+    "master_guard_placement",
+    "unguarded_division",
+    "master_dependent_start",
+    "front_removal_in_update",
+    "string_enum_sync",
+    "unset_init_guard",
+    "delta_time_in_fixed_update",
+    "dynamic_find",
+    "missing_constructor",
+    "non_constant_initializer",
+];
+
+/// How findings of a lint are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    /// Findings are dropped
+    Allow,
+    /// Findings are reported as warnings
+    #[default]
+    Warn,
+    /// Findings are reported as errors and fail the build
+    Deny,
+}
+
+/// Check that every configured lint exists, so typos do not go unnoticed
+pub fn validate_lint_levels(levels: &BTreeMap<String, LintLevel>) -> Result<(), ConfigError> {
+    match levels.keys().find(|lint| !LINTS.contains(&lint.as_str())) {
+        Some(lint) => Err(ConfigError::InvalidValue(format!(
+            "unknown lint '{}'; expected one of: {}",
+            lint,
+            LINTS.join(", ")
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Read the lint levels of `[package.metadata.udonsharp.lints]` from a Cargo.toml
+pub fn lint_levels_from_manifest(manifest: &str) -> Result<BTreeMap<String, LintLevel>, ConfigError> {
+    let manifest: toml::Value = toml::from_str(manifest)
+        .map_err(|e| ConfigError::ParseError(format!("Failed to parse Cargo.toml: {}", e)))?;
+    let Some(lints) = manifest.get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("udonsharp"))
+        .and_then(|udonsharp| udonsharp.get("lints"))
+    else {
+        return Ok(BTreeMap::new());
+    };
+
+    let levels: BTreeMap<String, LintLevel> = lints.clone().try_into()
+        .map_err(|e| ConfigError::ParseError(format!("Invalid [package.metadata.udonsharp.lints]: {}", e)))?;
+    validate_lint_levels(&levels)?;
+    Ok(levels)
+}
+
+/// Read the lint levels of a project from its Cargo.toml and `udonsharp.toml`
+///
+/// Levels in `udonsharp.toml` override those in the manifest. Missing files
+/// contribute no levels.
+pub fn load_project_lint_levels(project_dir: &Path) -> Result<BTreeMap<String, LintLevel>, ConfigError> {
+    let read = |name: &str| -> Result<Option<String>, ConfigError> {
+        let path = project_dir.join(name);
+        if !path.exists() {
+            return Ok(None);
+        }
+        std::fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|e| ConfigError::IoError(format!("Failed to read {:?}: {}", path, e)))
+    };
+
+    let mut levels = match read("Cargo.toml")? {
+        Some(manifest) => lint_levels_from_manifest(&manifest)?,
+        None => BTreeMap::new(),
+    };
+
+    if let Some(content) = read("udonsharp.toml")? {
+        #[derive(Deserialize)]
+        struct LintsTable {
+            #[serde(default)]
+            lints: BTreeMap<String, LintLevel>,
+        }
+        let table: LintsTable = toml::from_str(&content)
+            .map_err(|e| ConfigError::ParseError(format!("Failed to parse udonsharp.toml: {}", e)))?;
+        validate_lint_levels(&table.lints)?;
+        levels.extend(table.lints);
+    }
+
+    Ok(levels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_lint_levels_are_read_and_checked() {
+        let manifest = r#"
+            [package]
+            name = "world"
+            version = "0.1.0"
+
+            [package.metadata.udonsharp.lints]
+            dynamic_find = "deny"
+            string_enum_sync = "allow"
+        "#;
+        let levels = lint_levels_from_manifest(manifest).unwrap();
+        assert_eq!(levels["dynamic_find"], LintLevel::Deny);
+        assert_eq!(levels["string_enum_sync"], LintLevel::Allow);
+
+        assert!(lint_levels_from_manifest("[package]\nname = \"world\"\n").unwrap().is_empty());
+
+        let typo = "[package.metadata.udonsharp.lints]\ndynamic_fnd = \"deny\"\n";
+        assert!(lint_levels_from_manifest(typo).unwrap_err().to_string().contains("unknown lint 'dynamic_fnd'"));
+    }
+}
@@ -0,0 +1,358 @@
+//! Dataflow check for misplaced master guards
+//!
+//! Methods such as `start_round` early-return when the local player is not
+//! the master, since only the master may write synced state. A synced field
+//! written before that guard runs on every client and is overwritten by the
+//! next serialization. This module walks a method body in order, tracking
+//! whether `is_master()` is known to be true, and reports synced-field
+//! writes that happen on a path where it may be false.
+//!
+//! It also finds synced writes whose value or control flow depends on
+//! `is_master()` at all, for flagging master-driven initialization in `Start`.
+
+use std::collections::{BTreeSet, HashSet};
+use syn::visit::Visit;
+use syn::{BinOp, Block, Expr, Pat, Stmt, UnOp};
+
+/// Find synced fields written where `is_master()` may be false.
+///
+/// Only methods that check `is_master()` somewhere are inspected: a method
+/// without any guard is assumed to run on the owner by design, while a
+/// method with a guard signals that its writes are meant for the master.
+pub fn unguarded_synced_writes(block: &Block, synced_fields: &HashSet<String>) -> Vec<String> {
+    let mut guard_finder = MasterCheckFinder::default();
+    guard_finder.visit_block(block);
+    if !guard_finder.found {
+        return Vec::new();
+    }
+
+    let mut checker = GuardChecker {
+        synced_fields,
+        unguarded: BTreeSet::new(),
+    };
+    checker.check_block(block, false);
+    checker.unguarded.into_iter().collect()
+}
+
+/// Find synced fields whose writes depend on the result of `is_master()`.
+///
+/// A write depends on the master check when it happens inside a branch on
+/// `is_master()`, after an early exit on it, or when the written value reads
+/// it. Locals initialized from `is_master()` count as master checks too.
+pub fn master_dependent_synced_writes(block: &Block, synced_fields: &HashSet<String>) -> Vec<String> {
+    let mut checker = DependenceChecker {
+        synced_fields,
+        master_locals: HashSet::new(),
+        written: BTreeSet::new(),
+    };
+    checker.check_block(block, false);
+    checker.written.into_iter().collect()
+}
+
+/// Walks statements in order, tracking whether control flow depends on the master check
+struct DependenceChecker<'a> {
+    synced_fields: &'a HashSet<String>,
+    master_locals: HashSet<String>,
+    written: BTreeSet<String>,
+}
+
+impl DependenceChecker<'_> {
+    fn check_block(&mut self, block: &Block, mut dependent: bool) {
+        for stmt in &block.stmts {
+            match stmt {
+                Stmt::Local(local) => {
+                    let Some(init) = &local.init else { continue };
+                    if self.reads_master(&init.expr) {
+                        if let Some(name) = local_name(&local.pat) {
+                            self.master_locals.insert(name);
+                        }
+                    }
+                    self.check_expr(&init.expr, dependent);
+                }
+                Stmt::Expr(expr, _) => {
+                    self.check_expr(expr, dependent);
+
+                    // `if !is_master() { return; }` makes everything after it depend on the check
+                    if let Expr::If(expr_if) = expr {
+                        if self.reads_master(&expr_if.cond) && diverges(&expr_if.then_branch) {
+                            dependent = true;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_expr(&mut self, expr: &Expr, dependent: bool) {
+        match expr {
+            Expr::If(expr_if) => {
+                let dependent = dependent || self.reads_master(&expr_if.cond);
+                self.check_block(&expr_if.then_branch, dependent);
+                if let Some((_, else_branch)) = &expr_if.else_branch {
+                    self.check_expr(else_branch, dependent);
+                }
+            }
+            Expr::Match(expr_match) => {
+                let dependent = dependent || self.reads_master(&expr_match.expr);
+                for arm in &expr_match.arms {
+                    self.check_expr(&arm.body, dependent);
+                }
+            }
+            Expr::Block(expr_block) => self.check_block(&expr_block.block, dependent),
+            Expr::Assign(assign) if dependent || self.reads_master(&assign.right) => {
+                self.record(&assign.left);
+            }
+            Expr::Binary(binary) if is_compound_assignment(&binary.op)
+                && (dependent || self.reads_master(&binary.right)) =>
+            {
+                self.record(&binary.left);
+            }
+            _ => {}
+        }
+    }
+
+    fn record(&mut self, target: &Expr) {
+        if let Some(field) = self_field_root(target) {
+            if self.synced_fields.contains(&field) {
+                self.written.insert(field);
+            }
+        }
+    }
+
+    /// Whether an expression calls `is_master()` or reads a local holding its result
+    fn reads_master(&self, expr: &Expr) -> bool {
+        let mut finder = MasterReadFinder {
+            master_locals: &self.master_locals,
+            found: false,
+        };
+        finder.visit_expr(expr);
+        finder.found
+    }
+}
+
+/// Finds `is_master()` calls and reads of locals holding their result
+struct MasterReadFinder<'a> {
+    master_locals: &'a HashSet<String>,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for MasterReadFinder<'_> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        let is_master_local = matches!(
+            expr,
+            Expr::Path(path) if path.path.get_ident().is_some_and(|ident| self.master_locals.contains(&ident.to_string()))
+        );
+        if is_master_check(expr) || is_master_local {
+            self.found = true;
+            return;
+        }
+        syn::visit::visit_expr(self, expr);
+    }
+}
+
+/// Get the name bound by a simple `let` pattern
+fn local_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+        Pat::Type(pat_type) => local_name(&pat_type.pat),
+        _ => None,
+    }
+}
+
+/// Walks statements in order, tracking whether the master check has passed
+struct GuardChecker<'a> {
+    synced_fields: &'a HashSet<String>,
+    unguarded: BTreeSet<String>,
+}
+
+impl GuardChecker<'_> {
+    /// Check a block, returning whether the master check holds after it
+    fn check_block(&mut self, block: &Block, mut guarded: bool) -> bool {
+        for stmt in &block.stmts {
+            guarded = match stmt {
+                Stmt::Local(local) => {
+                    if let Some(init) = &local.init {
+                        self.scan(&init.expr, guarded);
+                    }
+                    guarded
+                }
+                Stmt::Expr(expr, _) => self.check_expr(expr, guarded),
+                _ => guarded,
+            };
+        }
+        guarded
+    }
+
+    /// Check an expression, returning whether the master check holds after it
+    fn check_expr(&mut self, expr: &Expr, guarded: bool) -> bool {
+        match expr {
+            Expr::If(expr_if) => {
+                self.scan(&expr_if.cond, guarded);
+                let then_guarded = guarded || true_implies_master(&expr_if.cond);
+                let else_guarded = guarded || false_implies_master(&expr_if.cond);
+                self.check_block(&expr_if.then_branch, then_guarded);
+                if let Some((_, else_branch)) = &expr_if.else_branch {
+                    self.check_expr(else_branch, else_guarded);
+                }
+
+                // `if !is_master() { return; }` guards everything after it
+                let then_exits = diverges(&expr_if.then_branch);
+                let else_exits = matches!(
+                    expr_if.else_branch.as_ref().map(|(_, e)| &**e),
+                    Some(Expr::Block(block)) if diverges(&block.block)
+                );
+                guarded || (then_exits && else_guarded) || (else_exits && then_guarded)
+            }
+            Expr::Block(expr_block) => self.check_block(&expr_block.block, guarded),
+            Expr::Unsafe(expr_unsafe) => self.check_block(&expr_unsafe.block, guarded),
+            Expr::While(expr_while) => {
+                self.scan(&expr_while.cond, guarded);
+                self.check_block(&expr_while.body, guarded || true_implies_master(&expr_while.cond));
+                guarded
+            }
+            Expr::ForLoop(for_loop) => {
+                self.scan(&for_loop.expr, guarded);
+                self.check_block(&for_loop.body, guarded);
+                guarded
+            }
+            Expr::Loop(expr_loop) => {
+                self.check_block(&expr_loop.body, guarded);
+                guarded
+            }
+            Expr::Match(expr_match) => {
+                self.scan(&expr_match.expr, guarded);
+                for arm in &expr_match.arms {
+                    self.check_expr(&arm.body, guarded);
+                }
+                guarded
+            }
+            _ => {
+                self.scan(expr, guarded);
+                guarded
+            }
+        }
+    }
+
+    /// Record synced writes anywhere inside an expression that does not affect the guard
+    fn scan(&mut self, expr: &Expr, guarded: bool) {
+        if guarded {
+            return;
+        }
+        let mut scanner = WriteScanner {
+            synced_fields: self.synced_fields,
+            written: &mut self.unguarded,
+        };
+        scanner.visit_expr(expr);
+    }
+}
+
+/// Collects writes to synced `self` fields
+struct WriteScanner<'a> {
+    synced_fields: &'a HashSet<String>,
+    written: &'a mut BTreeSet<String>,
+}
+
+impl WriteScanner<'_> {
+    fn record(&mut self, target: &Expr) {
+        if let Some(field) = self_field_root(target) {
+            if self.synced_fields.contains(&field) {
+                self.written.insert(field);
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for WriteScanner<'_> {
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        self.record(&assign.left);
+        syn::visit::visit_expr_assign(self, assign);
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast syn::ExprBinary) {
+        if is_compound_assignment(&binary.op) {
+            self.record(&binary.left);
+        }
+        syn::visit::visit_expr_binary(self, binary);
+    }
+}
+
+/// Finds any `is_master()` check in a method body
+#[derive(Default)]
+struct MasterCheckFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for MasterCheckFinder {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        if is_master_check(expr) {
+            self.found = true;
+            return;
+        }
+        syn::visit::visit_expr(self, expr);
+    }
+}
+
+/// Whether the expression is an `is_master()` call
+fn is_master_check(expr: &Expr) -> bool {
+    match expr {
+        Expr::Call(call) => matches!(
+            &*call.func,
+            Expr::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "is_master")
+        ),
+        Expr::MethodCall(call) => call.method == "is_master",
+        _ => false,
+    }
+}
+
+/// Whether the condition being true means the local player is the master
+fn true_implies_master(cond: &Expr) -> bool {
+    match cond {
+        Expr::Paren(paren) => true_implies_master(&paren.expr),
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+            true_implies_master(&binary.left) || true_implies_master(&binary.right)
+        }
+        other => is_master_check(other),
+    }
+}
+
+/// Whether the condition being false means the local player is the master
+fn false_implies_master(cond: &Expr) -> bool {
+    match cond {
+        Expr::Paren(paren) => false_implies_master(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => true_implies_master(&unary.expr),
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Or(_)) => {
+            false_implies_master(&binary.left) || false_implies_master(&binary.right)
+        }
+        _ => false,
+    }
+}
+
+/// Whether a block always leaves the method
+fn diverges(block: &Block) -> bool {
+    block.stmts.iter().any(|stmt| matches!(stmt, Stmt::Expr(Expr::Return(_), _)))
+}
+
+/// Get the `self` field an assignment target writes to
+fn self_field_root(target: &Expr) -> Option<String> {
+    match target {
+        Expr::Field(field) => match (&*field.base, &field.member) {
+            (Expr::Path(base), syn::Member::Named(member)) if base.path.is_ident("self") => {
+                Some(member.to_string())
+            }
+            _ => self_field_root(&field.base),
+        },
+        Expr::Index(index) => self_field_root(&index.expr),
+        Expr::Paren(paren) => self_field_root(&paren.expr),
+        _ => None,
+    }
+}
+
+fn is_compound_assignment(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::AddAssign(_) | BinOp::SubAssign(_) | BinOp::MulAssign(_) | BinOp::DivAssign(_)
+            | BinOp::RemAssign(_) | BinOp::BitXorAssign(_) | BinOp::BitAndAssign(_)
+            | BinOp::BitOrAssign(_) | BinOp::ShlAssign(_) | BinOp::ShrAssign(_)
+    )
+}
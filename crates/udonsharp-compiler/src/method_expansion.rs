@@ -0,0 +1,144 @@
+//! Expansion of a single Rust method to its generated C#
+//!
+//! Debugging a lowering issue usually concerns one method, but the generator
+//! emits whole classes. This module runs analysis and code generation for the
+//! behavior that owns a method and returns only that method's C#, in the
+//! spirit of `cargo expand`.
+
+use crate::code_generator::{to_pascal_case, CodeGenerator};
+use crate::struct_analyzer::StructAnalyzer;
+use std::fmt;
+
+/// Errors that can occur while expanding a method
+#[derive(Debug, Clone)]
+pub enum ExpandError {
+    /// The path is not of the form `method` or `Behavior::method`
+    InvalidPath { path: String },
+    /// The source could not be parsed or analyzed
+    Analysis { message: String },
+    /// No behavior with the given name exists
+    BehaviorNotFound { behavior: String },
+    /// No behavior defines the method
+    MethodNotFound { path: String },
+    /// Several behaviors define the method and no behavior was given
+    AmbiguousMethod { method: String, behaviors: Vec<String> },
+    /// Code generation for the owning behavior failed
+    Generation { behavior: String, message: String },
+}
+
+impl fmt::Display for ExpandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpandError::InvalidPath { path } => {
+                write!(f, "Invalid method path '{}': expected 'method' or 'Behavior::method'", path)
+            }
+            ExpandError::Analysis { message } => write!(f, "Analysis failed: {}", message),
+            ExpandError::BehaviorNotFound { behavior } => write!(f, "No behavior named '{}'", behavior),
+            ExpandError::MethodNotFound { path } => write!(f, "No method '{}' found", path),
+            ExpandError::AmbiguousMethod { method, behaviors } => write!(
+                f,
+                "Method '{}' is defined by several behaviors ({}); use 'Behavior::{}'",
+                method, behaviors.join(", "), method
+            ),
+            ExpandError::Generation { behavior, message } => {
+                write!(f, "Code generation for '{}' failed: {}", behavior, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExpandError {}
+
+/// Generate the C# of a single method, given as `method` or `Behavior::method`
+pub fn expand_method(rust_source: &str, path: &str) -> Result<String, ExpandError> {
+    let (behavior_name, method_name) = match path.split("::").collect::<Vec<_>>().as_slice() {
+        [method] if !method.is_empty() => (None, *method),
+        [behavior, method] if !behavior.is_empty() && !method.is_empty() => (Some(*behavior), *method),
+        _ => return Err(ExpandError::InvalidPath { path: path.to_string() }),
+    };
+
+    let file: syn::File = syn::parse_str(rust_source)
+        .map_err(|e| ExpandError::Analysis { message: e.to_string() })?;
+    let behaviors = StructAnalyzer::new().analyze_module(&file.items)
+        .map_err(|e| ExpandError::Analysis { message: e.to_string() })?;
+
+    let candidates: Vec<_> = match behavior_name {
+        Some(name) => {
+            let behavior = behaviors.iter().find(|b| b.name == name)
+                .ok_or_else(|| ExpandError::BehaviorNotFound { behavior: name.to_string() })?;
+            vec![behavior]
+        }
+        None => behaviors.iter().collect(),
+    };
+    let owners: Vec<_> = candidates.into_iter()
+        .filter(|b| b.methods.iter().any(|m| m.name == method_name))
+        .collect();
+    let behavior = match owners.as_slice() {
+        [] => return Err(ExpandError::MethodNotFound { path: path.to_string() }),
+        [behavior] => *behavior,
+        _ => {
+            return Err(ExpandError::AmbiguousMethod {
+                method: method_name.to_string(),
+                behaviors: owners.iter().map(|b| b.name.clone()).collect(),
+            })
+        }
+    };
+    let method = behavior.methods.iter().find(|m| m.name == method_name)
+        .ok_or_else(|| ExpandError::MethodNotFound { path: path.to_string() })?;
+
+    let mut generator = CodeGenerator::new();
+    generator.register_behaviors(&behaviors);
+    let generated = generator.generate_behavior_class(behavior)
+        .map_err(|e| ExpandError::Generation { behavior: behavior.name.clone(), message: e.to_string() })?;
+
+    let csharp_name = generator.map_unity_method_name(method_name)
+        .or_else(|| method.network_event().map(|(event, _)| event.to_string()))
+        .unwrap_or_else(|| to_pascal_case(method_name));
+
+    generated.methods.iter()
+        .find(|m| m.name == csharp_name)
+        .map(|m| m.declaration.clone())
+        .or_else(|| {
+            generated.custom_events.iter()
+                .find(|e| e.method_name == csharp_name)
+                .map(|e| e.declaration.clone())
+        })
+        .ok_or_else(|| ExpandError::MethodNotFound { path: path.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+        #[derive(UdonBehaviour)]
+        pub struct Scoreboard {
+            score: i32,
+        }
+
+        impl UdonBehaviour for Scoreboard {
+            fn start(&mut self) {}
+        }
+
+        impl Scoreboard {
+            pub fn add_points(&mut self, points: i32) {
+                self.score += points;
+                log_info("points added");
+            }
+        }
+    "#;
+
+    #[test]
+    fn test_expand_method_prints_only_its_csharp() {
+        let expanded = expand_method(SOURCE, "Scoreboard::add_points").unwrap();
+        assert!(expanded.contains("public void AddPoints(int points)"));
+        assert!(expanded.contains("score += points;"));
+        assert!(expanded.contains("points added"));
+        assert!(!expanded.contains("class Scoreboard"));
+        assert!(!expanded.contains("void Start()"));
+
+        assert_eq!(expand_method(SOURCE, "add_points").unwrap(), expanded);
+        assert!(matches!(expand_method(SOURCE, "Scoreboard::missing"), Err(ExpandError::MethodNotFound { .. })));
+        assert!(matches!(expand_method(SOURCE, "Other::add_points"), Err(ExpandError::BehaviorNotFound { .. })));
+    }
+}
@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::const_tables::ConstTable;
+use crate::payload_enum::PayloadEnum;
 
 /// Represents an analyzed Rust UdonBehaviour struct with all its metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +24,23 @@ pub struct UdonBehaviourStruct {
     pub trait_impl: Option<UdonBehaviourTraitImpl>,
     /// Names of other UdonBehaviour structs this depends on
     pub dependencies: Vec<String>,
+    /// Plain structs embedded as fields and flattened into this behavior
+    pub embedded_structs: Vec<EmbeddedStruct>,
+    /// Fieldless enums this behavior converts from integers
+    #[serde(default)]
+    pub int_enums: Vec<IntEnum>,
+    /// Fieldless enums this behavior matches on, used to check match exhaustiveness
+    #[serde(default)]
+    pub matched_enums: Vec<IntEnum>,
+    /// Enums with data this behavior constructs or matches on, held as tagged records
+    #[serde(default)]
+    pub payload_enums: Vec<PayloadEnum>,
+    /// Embedded `#[udon_sync_blob]` structs synced as one byte array each
+    #[serde(default)]
+    pub sync_blobs: Vec<SyncBlob>,
+    /// Module constant tables this behavior indexes, declared on the SharedRuntime
+    #[serde(default)]
+    pub const_tables: Vec<ConstTable>,
 }
 
 impl UdonBehaviourStruct {
@@ -34,6 +53,12 @@ impl UdonBehaviourStruct {
             attributes: Vec::new(),
             trait_impl: None,
             dependencies: Vec::new(),
+            embedded_structs: Vec::new(),
+            int_enums: Vec::new(),
+            matched_enums: Vec::new(),
+            payload_enums: Vec::new(),
+            sync_blobs: Vec::new(),
+            const_tables: Vec::new(),
         }
     }
 
@@ -64,12 +89,45 @@ impl UdonBehaviourStruct {
         }
     }
 
+    /// Add a flattened embedded struct to this behavior
+    pub fn add_embedded_struct(&mut self, embedded: EmbeddedStruct) {
+        self.embedded_structs.push(embedded);
+    }
+
+    /// Get the embedded struct stored in the given field, if any
+    pub fn get_embedded_struct(&self, field_name: &str) -> Option<&EmbeddedStruct> {
+        self.embedded_structs.iter().find(|e| e.field_name == field_name)
+    }
+
     /// Check if this struct has networking capabilities
     pub fn has_networking(&self) -> bool {
         self.fields.iter().any(|f| f.has_sync_attribute()) ||
         self.attributes.iter().any(|a| matches!(a, StructAttribute::UdonSyncMode(_)))
     }
 
+    /// Check if this behavior is a development tool excluded from release builds
+    pub fn is_editor_only(&self) -> bool {
+        self.attributes.contains(&StructAttribute::EditorOnly)
+    }
+
+    /// Check if this behavior serializes its fields through VRCJson
+    pub fn is_json_serializable(&self) -> bool {
+        self.attributes.contains(&StructAttribute::Json)
+    }
+
+    /// Check if this behavior is marked `#[udon_main]` as the world's primary behavior
+    pub fn is_main(&self) -> bool {
+        self.attributes.contains(&StructAttribute::Main)
+    }
+
+    /// Get the script execution order set by `#[udon_execution_order(n)]`
+    pub fn execution_order(&self) -> Option<i32> {
+        self.attributes.iter().find_map(|a| match a {
+            StructAttribute::ExecutionOrder(order) => Some(*order),
+            _ => None,
+        })
+    }
+
     /// Get all synchronized fields
     pub fn get_sync_fields(&self) -> Vec<&StructField> {
         self.fields.iter().filter(|f| f.has_sync_attribute()).collect()
@@ -114,6 +172,8 @@ pub struct StructField {
     pub attributes: Vec<FieldAttribute>,
     /// Default value if any
     pub default_value: Option<String>,
+    /// Rust source of an initializer that is not constant, evaluated in `Start`
+    pub start_initializer: Option<String>,
 }
 
 impl StructField {
@@ -125,6 +185,7 @@ impl StructField {
             visibility: Visibility::Private,
             attributes: Vec::new(),
             default_value: None,
+            start_initializer: None,
         }
     }
 
@@ -150,10 +211,28 @@ impl StructField {
 
     /// Check if this field is public
     pub fn is_public(&self) -> bool {
+        if self.has_serialize_field_attribute() {
+            return false;
+        }
         self.attributes.iter().any(|a| matches!(a, FieldAttribute::UdonPublic)) ||
         matches!(self.visibility, Visibility::Public)
     }
 
+    /// Check if this field is emitted as a `[SerializeField] private` field
+    pub fn has_serialize_field_attribute(&self) -> bool {
+        self.attributes.iter().any(|a| matches!(a, FieldAttribute::UdonSerializeField))
+    }
+
+    /// Check if this field is shown on the debug overlay
+    pub fn is_debug_displayed(&self) -> bool {
+        self.attributes.contains(&FieldAttribute::UdonDebug)
+    }
+
+    /// Check if this field is serialized, and so editable in the inspector
+    pub fn is_serialized(&self) -> bool {
+        self.is_public() || self.has_serialize_field_attribute()
+    }
+
     /// Validate the field definition
     pub fn validate(&self) -> Result<(), String> {
         if !is_valid_csharp_identifier(&self.name) {
@@ -176,6 +255,13 @@ pub struct StructMethod {
     pub return_type: RustType,
     /// Whether this is an async method
     pub is_async: bool,
+    /// Whether the method takes `&mut self` and so may change the behavior's fields
+    #[serde(default)]
+    pub mutates_self: bool,
+    /// Visibility of the method
+    pub visibility: Visibility,
+    /// Rust source of the method body, used for lowering to C#
+    pub body: Option<String>,
 }
 
 impl StructMethod {
@@ -187,6 +273,9 @@ impl StructMethod {
             parameters: Vec::new(),
             return_type,
             is_async: false,
+            mutates_self: false,
+            visibility: Visibility::Private,
+            body: None,
         }
     }
 
@@ -205,6 +294,21 @@ impl StructMethod {
         self.is_async = is_async;
     }
 
+    /// Set whether this method takes `&mut self`
+    pub fn set_mutates_self(&mut self, mutates_self: bool) {
+        self.mutates_self = mutates_self;
+    }
+
+    /// Set the visibility of this method
+    pub fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    /// Set the Rust source of the method body
+    pub fn set_body(&mut self, body: String) {
+        self.body = Some(body);
+    }
+
     /// Check if this is a Unity event method
     pub fn is_unity_event(&self) -> bool {
         matches!(self.name.as_str(), 
@@ -215,8 +319,8 @@ impl StructMethod {
             "on_player_joined" | "on_player_left" |
             "on_pickup" | "on_drop" | "on_pickup_use_down" | "on_pickup_use_up" |
             "on_station_entered" | "on_station_exited" |
-            "on_post_deserialization"
-        )
+            "on_pre_serialization" | "on_post_deserialization"
+        ) || input_event(&self.name).is_some()
     }
 
     /// Check if this is a custom event handler
@@ -224,6 +328,19 @@ impl StructMethod {
         self.attributes.iter().any(|a| matches!(a, MethodAttribute::UdonEvent(_)))
     }
 
+    /// Get the event name and default target of a network event handler
+    pub fn network_event(&self) -> Option<(&str, NetworkEventTarget)> {
+        self.attributes.iter().find_map(|a| match a {
+            MethodAttribute::UdonNetworkEvent { name, target } => Some((name.as_str(), *target)),
+            _ => None,
+        })
+    }
+
+    /// Check if this is a network event handler
+    pub fn is_network_event(&self) -> bool {
+        self.network_event().is_some()
+    }
+
     /// Validate the method definition
     pub fn validate(&self) -> Result<(), String> {
         if !is_valid_csharp_identifier(&self.name) {
@@ -233,6 +350,124 @@ impl StructMethod {
     }
 }
 
+/// A plain (non-behavior) struct embedded as a field of a behavior.
+///
+/// Udon has no nested reference types for plain data, so the fields and
+/// methods of an embedded struct are flattened into the owning behavior's
+/// class under a name-mangled prefix (`scoring_system.total` becomes
+/// `scoring_system_total`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddedStruct {
+    /// Name of the behavior field holding the embedded struct
+    pub field_name: String,
+    /// Rust type name of the embedded struct
+    pub type_name: String,
+    /// Original field names of the embedded struct
+    pub field_names: Vec<String>,
+    /// Original method names of the embedded struct
+    pub method_names: Vec<String>,
+}
+
+impl EmbeddedStruct {
+    /// Create a new embedded struct record
+    pub fn new(field_name: String, type_name: String) -> Self {
+        Self {
+            field_name,
+            type_name,
+            field_names: Vec::new(),
+            method_names: Vec::new(),
+        }
+    }
+
+    /// Get the flattened name of a member of the embedded struct
+    pub fn mangle(&self, member: &str) -> String {
+        format!("{}_{}", self.field_name, member)
+    }
+
+    /// Check if the embedded struct declares a field
+    pub fn has_field(&self, name: &str) -> bool {
+        self.field_names.iter().any(|f| f == name)
+    }
+
+    /// Check if the embedded struct declares a method
+    pub fn has_method(&self, name: &str) -> bool {
+        self.method_names.iter().any(|m| m == name)
+    }
+}
+
+/// An embedded `#[udon_sync_blob]` struct synced as a single `byte[]`
+///
+/// The struct's fields are flattened into the behavior like any embedded
+/// struct but are not synced themselves. They are packed into the synced
+/// blob field in `OnPreSerialization` and unpacked in `OnDeserialization`,
+/// in declaration order and little-endian, matching `to_udon_bytes` on the
+/// Rust side.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncBlob {
+    /// Name of the behavior field holding the struct
+    pub field_name: String,
+    /// Flattened fields in declaration order, with their types
+    pub fields: Vec<(String, RustType)>,
+}
+
+impl SyncBlob {
+    /// Name of the synced `byte[]` field
+    pub fn blob_field_name(&self) -> String {
+        format!("{}_blob", self.field_name)
+    }
+
+    /// Number of bytes the packed fields take
+    pub fn byte_len(&self) -> usize {
+        self.fields.iter().filter_map(|(_, field_type)| blob_type_size(field_type)).sum()
+    }
+}
+
+/// Packed size of a type allowed in a `#[udon_sync_blob]` struct
+pub fn blob_type_size(rust_type: &RustType) -> Option<usize> {
+    match rust_type {
+        RustType::Bool | RustType::I8 | RustType::U8 => Some(1),
+        RustType::I16 | RustType::U16 => Some(2),
+        RustType::I32 | RustType::U32 | RustType::F32 => Some(4),
+        RustType::I64 | RustType::U64 | RustType::F64 => Some(8),
+        _ => None,
+    }
+}
+
+/// A fieldless enum converted from an integer, such as a state synced as `i32`
+///
+/// `GameState::from_i32(n)` and `n as GameState` lower to a generated
+/// `{Enum}FromInt` switch over the discriminants. Integers matching no
+/// variant log a warning and produce the default variant, so a corrupt or
+/// out-of-date synced value cannot leave the behavior in an undefined state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntEnum {
+    /// Name of the enum
+    pub name: String,
+    /// Variants and their discriminants, in declaration order
+    pub variants: Vec<(String, i64)>,
+    /// Variant produced for integers outside the enum: the `#[default]` variant, else the first
+    pub default_variant: String,
+}
+
+impl IntEnum {
+    /// Name of the generated conversion method
+    pub fn conversion_method_name(&self) -> String {
+        format!("{}FromInt", self.name)
+    }
+
+    /// Whether the enum declares a variant
+    pub fn has_variant(&self, variant: &str) -> bool {
+        self.variants.iter().any(|(name, _)| name == variant)
+    }
+
+    /// Variant an integer converts to, matching the generated switch
+    pub fn variant_for(&self, value: i64) -> &str {
+        self.variants.iter()
+            .find(|(_, discriminant)| *discriminant == value)
+            .map_or(self.default_variant.as_str(), |(variant, _)| variant.as_str())
+    }
+}
+
 /// Method parameter information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MethodParameter {
@@ -280,6 +515,8 @@ pub enum RustType {
     
     // VRChat types
     VRCPlayerApi,
+    /// `UdonRef<T>` to the named behavior, held as a `UdonBehaviour`
+    UdonRef(String),
     
     // Container types
     Option(Box<RustType>),
@@ -302,11 +539,14 @@ impl RustType {
             RustType::Vector2 | RustType::Vector3 | RustType::Vector4 => true,
             RustType::Quaternion | RustType::Color | RustType::Color32 => true,
             RustType::GameObject | RustType::Transform => true,
-            RustType::VRCPlayerApi => true,
+            RustType::VRCPlayerApi | RustType::UdonRef(_) => true,
             RustType::Option(inner) => inner.is_udonsharp_compatible(),
             RustType::Vec(inner) => inner.is_udonsharp_compatible(),
             RustType::Array(inner, _) => inner.is_udonsharp_compatible(),
             RustType::Unit => true,
+            RustType::Custom(name) => {
+                is_data_container(name) || is_ui_component(name) || name == INPUT_EVENT_ARGS || name == ELAPSED_TIMER
+            }
             _ => false,
         }
     }
@@ -338,10 +578,34 @@ pub enum FieldAttribute {
     UdonPublic,
     /// #[udon_sync] - makes field synchronized across network
     UdonSync,
+    /// #[udon_serialize_field] - keeps the field private but inspector-editable
+    /// through `[SerializeField]`. Syncing works as for public fields, but other
+    /// behaviors cannot read or write the field directly.
+    UdonSerializeField,
     /// #[header("text")] - adds header in Unity inspector
     Header(String),
     /// #[tooltip("text")] - adds tooltip in Unity inspector
     Tooltip(String),
+    /// #[udon_range(0.0, 100.0)] - limits the inspector value to a slider over the
+    /// range. The bounds are kept as literals so the attribute stays hashable.
+    Range {
+        /// Smallest value the inspector allows
+        min: String,
+        /// Largest value the inspector allows
+        max: String,
+    },
+    /// #[udon_quantize(bits = 16, min = -100.0, max = 100.0)] - syncs the field packed
+    /// into an integer. The range bounds are kept as literals so the attribute stays hashable.
+    UdonQuantize {
+        /// Bits per component
+        bits: u8,
+        /// Smallest representable value
+        min: String,
+        /// Largest representable value
+        max: String,
+    },
+    /// #[udon_debug] - shows the field on the debug overlay emitted with `--emit-debug-overlay`
+    UdonDebug,
 }
 
 /// Attributes that can be applied to struct methods
@@ -349,6 +613,46 @@ pub enum FieldAttribute {
 pub enum MethodAttribute {
     /// #[udon_event("EventName")] - creates custom event handler
     UdonEvent(String),
+    /// #[udon_network_event(target = "All")] - creates a handler invoked through
+    /// SendCustomNetworkEvent, plus a caller that dispatches to the default target
+    UdonNetworkEvent {
+        /// Event name, which is also the name of the generated handler
+        name: String,
+        /// Target the generated caller sends the event to
+        target: NetworkEventTarget,
+    },
+}
+
+/// Receivers of a custom network event
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum NetworkEventTarget {
+    /// Every client, including the sender
+    All,
+    /// Every client except the sender
+    Others,
+    /// Only the owner of the object
+    Owner,
+}
+
+impl NetworkEventTarget {
+    /// Parse a target name as written in the attribute
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "All" => Some(NetworkEventTarget::All),
+            "Others" => Some(NetworkEventTarget::Others),
+            "Owner" => Some(NetworkEventTarget::Owner),
+            _ => None,
+        }
+    }
+
+    /// C# name of the target in `VRC.Udon.Common.Interfaces.NetworkEventTarget`
+    pub fn csharp_name(&self) -> &'static str {
+        match self {
+            NetworkEventTarget::All => "All",
+            NetworkEventTarget::Others => "Others",
+            NetworkEventTarget::Owner => "Owner",
+        }
+    }
 }
 
 /// Attributes that can be applied to structs
@@ -356,6 +660,18 @@ pub enum MethodAttribute {
 pub enum StructAttribute {
     /// #[udon_sync_mode(Manual)] - sets synchronization mode
     UdonSyncMode(UdonSyncMode),
+    /// #[udon_editor_only] - behavior is only built in debug builds
+    EditorOnly,
+    /// #[udon_json] - generates VRCJson-based ToJson/FromJson methods
+    Json,
+    /// #[udon_execution_order(n)] - runs the behavior's events before higher orders
+    ExecutionOrder(i32),
+    /// #[udon_pool(size = n)] - pre-allocates a pool of reusable records in Start
+    Pool { record_type: String, size: usize },
+    /// #[udon_main] - the primary behavior of the world
+    Main,
+    /// #[udon_forward(event = "..", to = [..])] - relays a Unity event to other behaviors
+    Forward { event: String, targets: Vec<String> },
 }
 
 /// UdonSharp synchronization modes
@@ -495,9 +811,12 @@ impl RustToCSharpTypeMapper {
                 let value_type = self.map_type(value)?;
                 Ok(format!("Dictionary<{}, {}>", key_type, value_type))
             },
+            // A timer is the time it was started at
+            RustType::Custom(name) if name == ELAPSED_TIMER => Ok("float".to_string()),
             RustType::Custom(name) => {
                 Ok(name.clone())
             },
+            RustType::UdonRef(_) => Ok("UdonBehaviour".to_string()),
             _ => {
                 Err(format!("Unsupported type: {:?}", rust_type))
             }
@@ -535,6 +854,7 @@ impl RustToCSharpTypeMapper {
             RustType::GameObject => "null".to_string(),
             RustType::Transform => "null".to_string(),
             RustType::VRCPlayerApi => "null".to_string(),
+            RustType::UdonRef(_) => "null".to_string(),
             RustType::Option(_) => "null".to_string(),
             RustType::Vec(inner) => {
                 let inner_type = self.map_type(inner).unwrap_or("object".to_string());
@@ -549,6 +869,9 @@ impl RustToCSharpTypeMapper {
                 let value_type = self.map_type(value).unwrap_or("object".to_string());
                 format!("new Dictionary<{}, {}>()", key_type, value_type)
             },
+            RustType::Custom(name) if name == "DataList" => "new DataList()".to_string(),
+            RustType::Custom(name) if name == "DataDictionary" => "new DataDictionary()".to_string(),
+            RustType::Custom(name) if name == ELAPSED_TIMER => "0.0f".to_string(),
             RustType::Custom(_) => "null".to_string(),
             _ => "null".to_string(),
         }
@@ -594,9 +917,21 @@ impl RustToCSharpTypeMapper {
             RustType::VRCPlayerApi => {
                 usings.push("using VRC.SDKBase;".to_string());
             },
+            RustType::UdonRef(_) => {
+                usings.push("using VRC.Udon;".to_string());
+            },
             RustType::HashMap(_, _) => {
                 usings.push("using System.Collections.Generic;".to_string());
             },
+            RustType::Custom(name) if is_data_container(name) => {
+                usings.push("using VRC.SDK3.Data;".to_string());
+            },
+            RustType::Custom(name) if is_ui_component(name) => {
+                usings.push("using UnityEngine.UI;".to_string());
+            },
+            RustType::Custom(name) if name == INPUT_EVENT_ARGS => {
+                usings.push("using VRC.Udon.Common;".to_string());
+            },
             RustType::Option(inner) | RustType::Vec(inner) | RustType::Array(inner, _) => {
                 usings.extend(self.get_required_usings(inner));
             },
@@ -675,6 +1010,10 @@ impl AttributeMapper {
             FieldAttribute::UdonSync, 
             vec!["[UdonSynced]".to_string()]
         );
+        self.field_mappings.insert(
+            FieldAttribute::UdonSerializeField,
+            vec!["[SerializeField]".to_string()]
+        );
 
         // Class attribute mappings
         self.class_mappings.insert(
@@ -700,6 +1039,9 @@ impl AttributeMapper {
             FieldAttribute::Tooltip(text) => {
                 vec![format!("[Tooltip(\"{}\")]", text)]
             },
+            FieldAttribute::Range { min, max } => {
+                vec![format!("[Range({}f, {}f)]", min, max)]
+            },
             attr if self.field_mappings.contains_key(attr) => {
                 self.field_mappings[attr].clone()
             },
@@ -709,6 +1051,9 @@ impl AttributeMapper {
 
     /// Map a class attribute to C# attributes
     pub fn map_class_attribute(&self, attribute: &StructAttribute) -> Vec<String> {
+        if let StructAttribute::ExecutionOrder(order) = attribute {
+            return vec![format!("[DefaultExecutionOrder({})]", order)];
+        }
         self.class_mappings.get(attribute).cloned().unwrap_or_default()
     }
 
@@ -741,8 +1086,8 @@ impl AttributeMapper {
         // Add default value if specified
         if let Some(default) = &field.default_value {
             field_line.push_str(&format!(" = {}", default));
-        } else if field.is_public() {
-            // For public fields, add default value to ensure proper initialization
+        } else if field.is_serialized() {
+            // For serialized fields, add default value to ensure proper initialization
             let default_value = type_mapper.get_default_value(&field.field_type);
             if default_value != "null" {
                 field_line.push_str(&format!(" = {}", default_value));
@@ -772,8 +1117,15 @@ impl AttributeMapper {
         let has_public = field.attributes.iter().any(|a| matches!(a, FieldAttribute::UdonPublic));
         let has_sync = field.attributes.iter().any(|a| matches!(a, FieldAttribute::UdonSync));
         
-        // UdonSync fields should typically be public for networking to work properly
-        if has_sync && !has_public && !matches!(field.visibility, Visibility::Public) {
+        if has_public && field.has_serialize_field_attribute() {
+            return Err(format!(
+                "Field '{}' cannot be both #[udon_public] and #[udon_serialize_field]",
+                field.name
+            ));
+        }
+
+        // UdonSync fields should typically be serialized for networking to work properly
+        if has_sync && !field.is_serialized() {
             return Err(format!(
                 "Field '{}' has #[udon_sync] but is not public. Synchronized fields should be public for proper networking.",
                 field.name
@@ -797,7 +1149,7 @@ impl AttributeMapper {
         }
         
         // Check if we need Unity usings for SerializeField
-        let needs_unity = field_attributes.iter().any(|a| matches!(a, FieldAttribute::UdonPublic | FieldAttribute::Header(_) | FieldAttribute::Tooltip(_)));
+        let needs_unity = field_attributes.iter().any(|a| matches!(a, FieldAttribute::UdonPublic | FieldAttribute::UdonSerializeField | FieldAttribute::Header(_) | FieldAttribute::Tooltip(_) | FieldAttribute::Range { .. }));
         
         if needs_unity {
             usings.push("using UnityEngine;".to_string());
@@ -818,6 +1170,9 @@ impl AttributeMapper {
                     // Custom event methods should be public
                     attributes.push(format!("// Custom event handler for '{}'", event_name));
                 }
+                MethodAttribute::UdonNetworkEvent { name, target } => {
+                    attributes.push(format!("// Network event handler for '{}' (default target: {})", name, target.csharp_name()));
+                }
             }
         }
         
@@ -831,6 +1186,52 @@ impl Default for AttributeMapper {
     }
 }
 
+/// Whether a custom type is one of the VRChat data containers in `VRC.SDK3.Data`
+pub fn is_data_container(name: &str) -> bool {
+    matches!(name, "DataList" | "DataDictionary" | "DataToken")
+}
+
+/// Whether a custom type is one of the Unity UI components in `UnityEngine.UI`
+pub fn is_ui_component(name: &str) -> bool {
+    matches!(name, "Text" | "Button" | "Image" | "Slider" | "Toggle" | "InputField" | "Dropdown" | "ScrollRect")
+}
+
+/// The core `ElapsedTimer`, emitted as a `float` holding its start time
+pub const ELAPSED_TIMER: &str = "ElapsedTimer";
+
+/// Type of the arguments passed to VRChat input events, in `VRC.Udon.Common`
+pub const INPUT_EVENT_ARGS: &str = "UdonInputEventArgs";
+
+/// A VRChat input event handled through a trait method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputEvent {
+    /// Name of the trait method
+    pub method: &'static str,
+    /// Name of the Udon event
+    pub udon_name: &'static str,
+    /// Rust type of the `value` parameter
+    pub rust_value_type: &'static str,
+    /// C# type of the `value` parameter
+    pub csharp_value_type: &'static str,
+}
+
+/// VRChat input events: buttons pass a `bool`, movement and look axes an `f32`
+pub const INPUT_EVENTS: &[InputEvent] = &[
+    InputEvent { method: "on_input_jump", udon_name: "InputJump", rust_value_type: "bool", csharp_value_type: "bool" },
+    InputEvent { method: "on_input_use", udon_name: "InputUse", rust_value_type: "bool", csharp_value_type: "bool" },
+    InputEvent { method: "on_input_grab", udon_name: "InputGrab", rust_value_type: "bool", csharp_value_type: "bool" },
+    InputEvent { method: "on_input_drop", udon_name: "InputDrop", rust_value_type: "bool", csharp_value_type: "bool" },
+    InputEvent { method: "on_input_move_horizontal", udon_name: "InputMoveHorizontal", rust_value_type: "f32", csharp_value_type: "float" },
+    InputEvent { method: "on_input_move_vertical", udon_name: "InputMoveVertical", rust_value_type: "f32", csharp_value_type: "float" },
+    InputEvent { method: "on_input_look_horizontal", udon_name: "InputLookHorizontal", rust_value_type: "f32", csharp_value_type: "float" },
+    InputEvent { method: "on_input_look_vertical", udon_name: "InputLookVertical", rust_value_type: "f32", csharp_value_type: "float" },
+];
+
+/// Get the input event handled by a trait method
+pub fn input_event(method: &str) -> Option<&'static InputEvent> {
+    INPUT_EVENTS.iter().find(|event| event.method == method)
+}
+
 /// Utility function to check if a string is a valid C# identifier
 pub fn is_valid_csharp_identifier(name: &str) -> bool {
     if name.is_empty() {
@@ -958,6 +1359,24 @@ mod tests {
         assert_eq!(mapper.map_type(&hashmap).unwrap(), "Dictionary<string, int>");
     }
 
+    #[test]
+    fn test_ui_component_types_use_unity_ui() {
+        let mapper = RustToCSharpTypeMapper::new();
+
+        let label = RustType::Option(Box::new(RustType::Custom("Text".to_string())));
+        assert_eq!(mapper.map_type(&label).unwrap(), "Text");
+        assert!(label.is_udonsharp_compatible());
+        assert_eq!(mapper.get_required_usings(&label), vec!["using UnityEngine.UI;".to_string()]);
+
+        let sliders = RustType::Vec(Box::new(RustType::Custom("Slider".to_string())));
+        assert_eq!(mapper.get_required_usings(&sliders), vec!["using UnityEngine.UI;".to_string()]);
+
+        // Other custom types are not assumed to be UI components
+        let inventory = RustType::Custom("Inventory".to_string());
+        assert!(!inventory.is_udonsharp_compatible());
+        assert!(mapper.get_required_usings(&inventory).is_empty());
+    }
+
     #[test]
     fn test_attribute_validation() {
         let mapper = AttributeMapper::new();
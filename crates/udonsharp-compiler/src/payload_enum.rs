@@ -0,0 +1,180 @@
+//! Enums whose variants carry data
+//!
+//! Udon has no sum types, and UdonSharp compiles no classes or structs other
+//! than behaviors, so a value of an enum such as
+//! `GameEvent::PlayerScored { player_name, score }` becomes a `DataDictionary`
+//! record: a `"tag"` entry naming the variant and one entry per payload field,
+//! keyed by the field name or, for tuple variants, by position. Constructing a
+//! variant calls a generated `New{Enum}{Variant}` method of the behavior, and
+//! a `match` over the enum switches on the tag and reads the fields an arm
+//! binds back out of the record. Fields, parameters and return values of the
+//! enum are typed `DataDictionary`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::code_generator::{to_camel_case, GeneratedMethod, GeneratedParameter};
+use crate::multi_behavior::{RustToCSharpTypeMapper, RustType};
+
+/// Record entry holding the name of the variant
+pub const PAYLOAD_ENUM_TAG_KEY: &str = "tag";
+
+/// C# type values of a payload enum are held in
+pub const PAYLOAD_ENUM_RECORD_TYPE: &str = "DataDictionary";
+
+/// Local the generated constructors build the record in
+const RECORD_LOCAL: &str = "tagged";
+
+/// An enum with at least one variant carrying data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PayloadEnum {
+    /// Name of the enum
+    pub name: String,
+    /// Variants in declaration order
+    pub variants: Vec<PayloadVariant>,
+}
+
+/// A variant of a payload enum
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PayloadVariant {
+    /// Name of the variant, stored as the record's tag
+    pub name: String,
+    /// Payload fields in declaration order; fields of tuple variants are
+    /// named by their position
+    pub fields: Vec<(String, RustType)>,
+}
+
+impl PayloadVariant {
+    /// Type of a payload field
+    pub fn field_type(&self, field: &str) -> Option<&RustType> {
+        self.fields.iter().find(|(name, _)| name == field).map(|(_, field_type)| field_type)
+    }
+}
+
+impl PayloadEnum {
+    /// Get a variant by name
+    pub fn variant(&self, name: &str) -> Option<&PayloadVariant> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    /// Name of the generated method constructing a variant
+    pub fn constructor_name(&self, variant: &str) -> String {
+        format!("New{}{}", self.name, variant)
+    }
+
+    /// `New{Enum}{Variant}` methods building the record of each variant
+    pub fn constructors(&self) -> Vec<GeneratedMethod> {
+        self.variants.iter().map(|variant| self.constructor(variant)).collect()
+    }
+
+    fn constructor(&self, variant: &PayloadVariant) -> GeneratedMethod {
+        let mapper = RustToCSharpTypeMapper::new();
+        let parameters: Vec<GeneratedParameter> = variant.fields.iter()
+            .map(|(field, field_type)| GeneratedParameter {
+                name: parameter_name(field),
+                param_type: mapper.map_type(field_type).unwrap_or_else(|_| "object".to_string()),
+            })
+            .collect();
+
+        let mut body = vec![
+            format!("        {} {} = new {}();", PAYLOAD_ENUM_RECORD_TYPE, RECORD_LOCAL, PAYLOAD_ENUM_RECORD_TYPE),
+            format!("        {}[\"{}\"] = \"{}\";", RECORD_LOCAL, PAYLOAD_ENUM_TAG_KEY, variant.name),
+        ];
+        for ((field, field_type), parameter) in variant.fields.iter().zip(&parameters) {
+            body.push(format!("        {}[\"{}\"] = {};", RECORD_LOCAL, field, payload_token(&parameter.name, field_type)));
+        }
+        body.push(format!("        return {};", RECORD_LOCAL));
+        let body = body.join("\n");
+
+        let name = self.constructor_name(&variant.name);
+        let signature: Vec<String> = parameters.iter()
+            .map(|parameter| format!("{} {}", parameter.param_type, parameter.name))
+            .collect();
+        GeneratedMethod {
+            declaration: format!(
+                "    /// <summary>\n    /// Construct {}::{} as a tagged record\n    /// </summary>\n    private {} {}({})\n    {{\n{}\n    }}",
+                self.name, variant.name, PAYLOAD_ENUM_RECORD_TYPE, name, signature.join(", "), body
+            ),
+            name,
+            return_type: PAYLOAD_ENUM_RECORD_TYPE.to_string(),
+            parameters,
+            attributes: Vec::new(),
+            body,
+        }
+    }
+}
+
+/// Expression testing the tag of a record, for a `switch`
+pub fn payload_tag(record: &str) -> String {
+    format!("{}[\"{}\"].String", record, PAYLOAD_ENUM_TAG_KEY)
+}
+
+/// Expression reading a payload field of a record back as its C# type
+pub fn read_payload_field(record: &str, field: &str, field_type: &RustType) -> String {
+    let token = format!("{}[\"{}\"]", record, field);
+    let accessor = match field_type {
+        RustType::Bool => "Boolean",
+        RustType::I8 => "SByte",
+        RustType::U8 => "Byte",
+        RustType::I16 => "Short",
+        RustType::U16 => "UShort",
+        RustType::I32 => "Int",
+        RustType::U32 => "UInt",
+        RustType::I64 => "Long",
+        RustType::U64 => "ULong",
+        RustType::F32 => "Float",
+        RustType::F64 => "Double",
+        RustType::String => "String",
+        RustType::Custom(name) if name == "DataList" || name == "DataDictionary" => name.as_str(),
+        _ => {
+            let csharp_type = RustToCSharpTypeMapper::new().map_type(field_type).unwrap_or_else(|_| "object".to_string());
+            return format!("({}){}.Reference", csharp_type, token);
+        }
+    };
+    format!("{}.{}", token, accessor)
+}
+
+/// Value stored in a record entry; types without an implicit `DataToken`
+/// conversion are stored as references
+fn payload_token(value: &str, field_type: &RustType) -> String {
+    match field_type {
+        RustType::Bool | RustType::I8 | RustType::U8 | RustType::I16 | RustType::U16 | RustType::I32
+        | RustType::U32 | RustType::I64 | RustType::U64 | RustType::F32 | RustType::F64 | RustType::String => {
+            value.to_string()
+        }
+        RustType::Custom(name) if name == "DataList" || name == "DataDictionary" => value.to_string(),
+        _ => format!("new DataToken({})", value),
+    }
+}
+
+/// Constructor parameter of a payload field; tuple fields become `item0`, `item1`, ...
+fn parameter_name(field: &str) -> String {
+    if field.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("item{}", field)
+    } else {
+        to_camel_case(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructor_stores_tag_and_payload() {
+        let game_event = PayloadEnum {
+            name: "GameEvent".to_string(),
+            variants: vec![PayloadVariant {
+                name: "PlayerScored".to_string(),
+                fields: vec![("player_name".to_string(), RustType::String), ("spawn".to_string(), RustType::Vector3)],
+            }],
+        };
+
+        let constructor = &game_event.constructors()[0];
+        assert_eq!(constructor.name, "NewGameEventPlayerScored");
+        assert!(constructor.declaration.contains("private DataDictionary NewGameEventPlayerScored(string playerName, Vector3 spawn)"));
+        assert!(constructor.body.contains("tagged[\"tag\"] = \"PlayerScored\";"));
+        assert!(constructor.body.contains("tagged[\"player_name\"] = playerName;"));
+        assert!(constructor.body.contains("tagged[\"spawn\"] = new DataToken(spawn);"));
+        assert_eq!(read_payload_field("gameEvent", "spawn", &RustType::Vector3), "(Vector3)gameEvent[\"spawn\"].Reference");
+    }
+}
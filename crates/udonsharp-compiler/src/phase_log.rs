@@ -0,0 +1,157 @@
+//! Ordered log of compilation pipeline phases
+//!
+//! The pipeline records each phase it runs together with its duration and
+//! outcome. When a build fails, the log shows exactly how far compilation got.
+//! Records carry the same information as the profiler's completed operations
+//! so `udonsharp-performance` can fold them into a profiling session.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Phases of the WASM compilation pipeline, in execution order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PipelinePhase {
+    ParseWasm,
+    AnalyzeOop,
+    SplitBehaviors,
+    GenerateSharedRuntime,
+    EmitCSharp,
+}
+
+impl PipelinePhase {
+    /// All phases in execution order
+    pub const ALL: [PipelinePhase; 5] = [
+        PipelinePhase::ParseWasm,
+        PipelinePhase::AnalyzeOop,
+        PipelinePhase::SplitBehaviors,
+        PipelinePhase::GenerateSharedRuntime,
+        PipelinePhase::EmitCSharp,
+    ];
+
+    /// Human-readable phase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            PipelinePhase::ParseWasm => "parse WASM",
+            PipelinePhase::AnalyzeOop => "analyze OOP",
+            PipelinePhase::SplitBehaviors => "split behaviors",
+            PipelinePhase::GenerateSharedRuntime => "generate shared runtime",
+            PipelinePhase::EmitCSharp => "emit C#",
+        }
+    }
+}
+
+impl fmt::Display for PipelinePhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Outcome of a recorded phase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhaseStatus {
+    /// The phase ran to completion
+    Completed,
+    /// The phase failed with the given error
+    Failed(String),
+}
+
+/// A single recorded phase
+#[derive(Debug, Clone)]
+pub struct PhaseRecord {
+    pub phase: PipelinePhase,
+    pub status: PhaseStatus,
+    pub duration: Duration,
+}
+
+impl PhaseRecord {
+    /// Whether the phase completed successfully
+    pub fn succeeded(&self) -> bool {
+        self.status == PhaseStatus::Completed
+    }
+}
+
+/// Ordered record of the phases a compilation went through
+#[derive(Debug, Clone, Default)]
+pub struct PhaseLog {
+    records: Vec<PhaseRecord>,
+}
+
+impl PhaseLog {
+    /// Create an empty phase log
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clear all records
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    /// Record the outcome of a phase that started at `started`
+    pub fn record<T, E: fmt::Display>(&mut self, phase: PipelinePhase, started: Instant, result: &Result<T, E>) {
+        let status = match result {
+            Ok(_) => PhaseStatus::Completed,
+            Err(error) => PhaseStatus::Failed(error.to_string()),
+        };
+        self.records.push(PhaseRecord {
+            phase,
+            status,
+            duration: started.elapsed(),
+        });
+    }
+
+    /// Get the recorded phases in order
+    pub fn records(&self) -> &[PhaseRecord] {
+        &self.records
+    }
+
+    /// Get the status of a phase, if it was run
+    pub fn status(&self, phase: PipelinePhase) -> Option<&PhaseStatus> {
+        self.records.iter().find(|r| r.phase == phase).map(|r| &r.status)
+    }
+
+    /// Get the first phase that failed
+    pub fn failed_phase(&self) -> Option<&PhaseRecord> {
+        self.records.iter().find(|r| !r.succeeded())
+    }
+
+    /// Format the log for display, listing phases that were not run as skipped
+    pub fn format_report(&self) -> String {
+        let mut lines = vec!["Pipeline phase log:".to_string()];
+
+        for phase in PipelinePhase::ALL {
+            let line = match self.records.iter().find(|r| r.phase == phase) {
+                Some(PhaseRecord { status: PhaseStatus::Completed, duration, .. }) => {
+                    format!("  ✓ {} ({:.2?})", phase, duration)
+                }
+                Some(PhaseRecord { status: PhaseStatus::Failed(error), duration, .. }) => {
+                    format!("  ✗ {} ({:.2?}): {}", phase, duration, error)
+                }
+                None => format!("  - {} (not run)", phase),
+            };
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_log_report_order() {
+        let mut log = PhaseLog::new();
+        log.record::<(), String>(PipelinePhase::ParseWasm, Instant::now(), &Ok(()));
+        log.record::<(), String>(PipelinePhase::AnalyzeOop, Instant::now(), &Err("bad section".to_string()));
+
+        let report = log.format_report();
+        let lines: Vec<&str> = report.lines().collect();
+        assert!(lines[1].starts_with("  ✓ parse WASM"));
+        assert!(lines[2].starts_with("  ✗ analyze OOP") && lines[2].ends_with("bad section"));
+        assert_eq!(lines[5], "  - emit C# (not run)");
+        assert_eq!(lines.len(), 6);
+        assert_eq!(log.failed_phase().map(|r| r.phase), Some(PipelinePhase::AnalyzeOop));
+    }
+}
@@ -3,10 +3,14 @@
 //! This module orchestrates the entire compilation process from Rust source
 //! to UdonSharp-compatible C# code.
 
-use crate::config::UdonSharpConfig;
+use crate::config::{ConversionTarget, UdonSharpConfig};
+use crate::multi_behavior::UdonBehaviourStruct;
 use crate::prefab_generator::{UnityPrefabGenerator, PrefabGenerationResult};
-use crate::initialization_coordinator::{InitializationCoordinator, CoordinatorGenerationResult};
-use crate::standard_multi_behavior_integration::StandardMultiBehaviorPipelineExt;
+use crate::asset_meta::{write_asset_meta, AssetGuidSeed};
+use crate::event_ids::{EventIdTable, EVENT_ID_TABLE_FILE};
+use crate::phase_log::{PhaseLog, PipelinePhase};
+use crate::initialization_coordinator::{InitializationCoordinator, CoordinatorGenerationResult, BOOTSTRAP_CLASS_NAME};
+use crate::standard_multi_behavior_integration::{StandardMultiBehaviorIntegration, StandardMultiBehaviorPipelineExt};
 use udonsharp_core::{UdonSharpResult, error::CompilationContext};
 use wasm2usharp_enhanced::{
     EnhancedWasm2USharpPipeline, 
@@ -15,13 +19,17 @@ use wasm2usharp_enhanced::{
     OopBehaviorAnalyzer,
     BehaviorUnit
 };
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
 
 /// Main compilation pipeline
 pub struct CompilationPipeline {
     config: UdonSharpConfig,
     context: CompilationContext,
+    /// Phases run by the most recent compilation
+    phase_log: Mutex<PhaseLog>,
 }
 
 impl CompilationPipeline {
@@ -33,12 +41,29 @@ impl CompilationPipeline {
             CompilationContext::new()
         };
         
-        Self { config, context }
+        Self::with_context(config, context)
     }
     
     /// Create a pipeline with a custom context
     pub fn with_context(config: UdonSharpConfig, context: CompilationContext) -> Self {
-        Self { config, context }
+        Self {
+            config,
+            context,
+            phase_log: Mutex::new(PhaseLog::new()),
+        }
+    }
+    
+    /// Get the phase log of the most recent compilation
+    pub fn phase_log(&self) -> PhaseLog {
+        self.phase_log.lock().map(|log| log.clone()).unwrap_or_default()
+    }
+    
+    /// Record the outcome of a pipeline phase and pass the result through
+    fn record_phase<T>(&self, phase: PipelinePhase, started: Instant, result: UdonSharpResult<T>) -> UdonSharpResult<T> {
+        if let Ok(mut log) = self.phase_log.lock() {
+            log.record(phase, started, &result);
+        }
+        result
     }
     
     /// Compile a Rust project to UdonSharp
@@ -56,33 +81,67 @@ impl CompilationPipeline {
         // Step 2: Check if we should use standard multi-behavior pattern
         if self.should_use_standard_multi_behavior(&rust_source)? {
             self.context.info("Using standard multi-behavior compilation pattern");
+            if self.config.emit_wasm.is_some() {
+                self.context.warning("No intermediate WASM is produced for standard multi-behavior projects; nothing to emit");
+            }
             return self.compile_with_standard_multi_behavior(&rust_source).await;
         }
         
+        // Udon assembly cannot be produced from WASM
+        if self.config.target == ConversionTarget::UdonAssembly {
+            return Err(udonsharp_core::UdonSharpError::compilation(
+                "The Udon assembly target is lowered from #[derive(UdonBehaviour)] structs; none were found in the project"
+            ));
+        }
+        
         // Step 3: Fall back to WASM-based compilation for legacy support
         self.context.info("Using WASM-based compilation (legacy mode)");
         
+        let compilation_result = match self.compile_wasm_project(&project_path).await {
+            Ok(result) => result,
+            Err(error) => {
+                // Show how far the pipeline got so failures can be reported precisely
+                self.context.error(self.phase_log().format_report());
+                return Err(error);
+            }
+        };
+        
+        if !self.context.should_continue() {
+            return Err(udonsharp_core::UdonSharpError::compilation("Compilation failed due to errors"));
+        }
+        
+        self.context.info("Compilation completed successfully");
+        Ok(compilation_result)
+    }
+    
+    /// Compile a project through the WASM pipeline, recording each phase
+    async fn compile_wasm_project<P: AsRef<Path>>(&self, project_path: P) -> UdonSharpResult<CompilationResult> {
+        if let Ok(mut log) = self.phase_log.lock() {
+            log.clear();
+        }
+        
         // Parse Rust source code and compile to WASM
-        let wasm_bytes = self.compile_rust_to_wasm(&project_path).await?;
+        let started = Instant::now();
+        let wasm_bytes = self.compile_rust_to_wasm(&project_path).await;
+        let wasm_bytes = self.record_phase(PipelinePhase::ParseWasm, started, wasm_bytes)?;
+        
+        if let Some(emit_path) = &self.config.emit_wasm {
+            self.emit_intermediate_wasm(emit_path, &wasm_bytes)?;
+        }
         
         // Analyze WASM for multi-behavior patterns
-        let behavior_analysis = self.analyze_multi_behavior_patterns(&wasm_bytes)?;
+        let started = Instant::now();
+        let behavior_analysis = self.analyze_multi_behavior_patterns(&wasm_bytes);
+        let behavior_analysis = self.record_phase(PipelinePhase::AnalyzeOop, started, behavior_analysis)?;
         
         // Generate UdonSharp code
-        let compilation_result = if behavior_analysis.behavior_units.len() > 1 {
+        if behavior_analysis.behavior_units.len() > 1 {
             // Multi-behavior compilation
-            self.compile_multi_behavior(&wasm_bytes, &behavior_analysis).await?
+            self.compile_multi_behavior(&wasm_bytes, &behavior_analysis).await
         } else {
             // Single behavior compilation (legacy path)
-            self.compile_single_behavior(&wasm_bytes).await?
-        };
-        
-        if !self.context.should_continue() {
-            return Err(udonsharp_core::UdonSharpError::compilation("Compilation failed due to errors"));
+            self.compile_single_behavior(&wasm_bytes).await
         }
-        
-        self.context.info("Compilation completed successfully");
-        Ok(compilation_result)
     }
     
     /// Read Rust source code from project
@@ -126,7 +185,11 @@ impl CompilationPipeline {
         
         self.context.info(format!("Found {} UdonBehaviour derive annotations", udon_behaviour_count));
         
-        Ok(udon_behaviour_count >= self.config.multi_behavior.min_behaviors_threshold)
+        // A #[udon_main] behavior designates the world explicitly, so no count is needed,
+        // and Udon assembly is only lowered from source
+        Ok(udon_behaviour_count >= self.config.multi_behavior.min_behaviors_threshold
+            || rust_source.contains("#[udon_main]")
+            || (self.config.target == ConversionTarget::UdonAssembly && udon_behaviour_count > 0))
     }
 
     /// Compile Rust source to WASM
@@ -144,6 +207,19 @@ impl CompilationPipeline {
         Ok(vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]) // WASM magic number + version
     }
     
+    /// Write the intermediate WASM module so it can be inspected with external tools
+    fn emit_intermediate_wasm(&self, emit_path: &Path, wasm_bytes: &[u8]) -> UdonSharpResult<()> {
+        if let Some(parent) = emit_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("Failed to create directory: {}", e)))?;
+        }
+        std::fs::write(emit_path, wasm_bytes)
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("Failed to write WASM to {:?}: {}", emit_path, e)))?;
+        
+        self.context.info(format!("Wrote intermediate WASM ({} bytes) to {:?}", wasm_bytes.len(), emit_path));
+        Ok(())
+    }
+    
     /// Analyze WASM for multi-behavior patterns
     fn analyze_multi_behavior_patterns(&self, wasm_bytes: &[u8]) -> UdonSharpResult<MultiBehaviorAnalysis> {
         self.context.info("Analyzing WASM for multi-behavior patterns...");
@@ -193,14 +269,42 @@ impl CompilationPipeline {
             naming_convention,
             include_debug_info: self.config.generate_debug_info,
             custom_templates: HashMap::new(),
+            output_layout: match &self.config.multi_behavior.output_layout {
+                crate::config::OutputLayout::PerBehavior => wasm2usharp_enhanced::OutputLayout::PerBehavior,
+                crate::config::OutputLayout::Bundled(file_name) => wasm2usharp_enhanced::OutputLayout::Bundled(file_name.clone()),
+            },
         };
         
         let file_generator = MultiBehaviorFileGenerator::with_config(multi_behavior_config);
         
         // Generate behavior files
+        let started = Instant::now();
         let generation_result = file_generator.generate_all_files(&analysis.behavior_units, &analysis.shared_functions)
-            .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("File generation failed: {}", e)))?;
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("File generation failed: {}", e)));
+        let generation_result = self.record_phase(PipelinePhase::SplitBehaviors, started, generation_result)?;
+        
+        // Write SharedRuntime file if generated
+        let mut shared_runtime_file = None;
+        if let Some(shared_runtime) = &generation_result.shared_runtime_file {
+            let started = Instant::now();
+            let file_path = "SharedRuntime.cs".to_string();
+            let written = self.write_generated_file(&file_path, &shared_runtime.content);
+            self.record_phase(PipelinePhase::GenerateSharedRuntime, started, written)?;
+            shared_runtime_file = Some(file_path);
+        }
         
+        let started = Instant::now();
+        let emitted = self.emit_multi_behavior_files(analysis, generation_result, shared_runtime_file);
+        self.record_phase(PipelinePhase::EmitCSharp, started, emitted)
+    }
+    
+    /// Write the behavior, coordinator and prefab files of a multi-behavior compilation
+    fn emit_multi_behavior_files(
+        &self,
+        analysis: &MultiBehaviorAnalysis,
+        generation_result: wasm2usharp_enhanced::MultiBehaviorGenerationResult,
+        shared_runtime_file: Option<String>,
+    ) -> UdonSharpResult<CompilationResult> {
         // Generate initialization coordinator if enabled
         let mut coordinator_file = None;
         let mut coordinator_metadata = None;
@@ -228,6 +332,21 @@ impl CompilationPipeline {
             coordinator_metadata = Some(coordinator_result);
         }
         
+        // Generate the bootstrap behavior if enabled
+        let mut bootstrap_file = None;
+        if self.config.multi_behavior.generate_bootstrap {
+            let coordinator = InitializationCoordinator::new(
+                self.config.multi_behavior.initialization_order.clone()
+            );
+            let has_shared_runtime = generation_result.shared_runtime_file.is_some();
+            let bootstrap = coordinator.generate_bootstrap(&analysis.behavior_units, has_shared_runtime, self.config.namespace.as_deref())
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("Bootstrap generation failed: {}", e)))?;
+            
+            let bootstrap_file_name = format!("{}.cs", BOOTSTRAP_CLASS_NAME);
+            self.write_generated_file(&bootstrap_file_name, &bootstrap.bootstrap_code)?;
+            bootstrap_file = Some(bootstrap_file_name);
+        }
+        
         // Generate prefabs if enabled
         let mut prefab_files = HashMap::new();
         let mut prefab_metadata = None;
@@ -283,22 +402,27 @@ impl CompilationPipeline {
         // Write files to disk
         let mut output_files = Vec::new();
         let mut behavior_files = HashMap::new();
-        let mut shared_runtime_file = None;
         
-        // Write behavior files
+        // Write the bundle holding the code of every behavior, if requested
+        let mut bundle_path = None;
+        if let Some(bundle) = &generation_result.bundled_file {
+            let file_path = format!("{}.cs", bundle.name.trim_end_matches(".cs"));
+            self.write_generated_file(&file_path, &bundle.content)?;
+            output_files.push(file_path.clone());
+            bundle_path = Some(file_path);
+        }
+        
+        // Write behavior files, which are program stubs when the code is bundled
         for (behavior_name, generated_file) in generation_result.behavior_files {
             let file_path = format!("{}.cs", generated_file.name.trim_end_matches(".cs"));
             self.write_generated_file(&file_path, &generated_file.content)?;
             output_files.push(file_path.clone());
-            behavior_files.insert(behavior_name, file_path);
+            behavior_files.insert(behavior_name, bundle_path.clone().unwrap_or(file_path));
         }
         
-        // Write SharedRuntime file if generated
-        if let Some(shared_runtime) = generation_result.shared_runtime_file {
-            let file_path = "SharedRuntime.cs".to_string();
-            self.write_generated_file(&file_path, &shared_runtime.content)?;
+        // SharedRuntime was written before the behavior files
+        if let Some(file_path) = &shared_runtime_file {
             output_files.push(file_path.clone());
-            shared_runtime_file = Some(file_path);
         }
         
         // Add prefab files to output
@@ -308,6 +432,7 @@ impl CompilationPipeline {
         if let Some(coord_file) = &coordinator_file {
             output_files.push(coord_file.clone());
         }
+        output_files.extend(bootstrap_file);
         
         let metadata = MultiBehaviorMetadata {
             total_behaviors: generation_result.metadata.total_behaviors,
@@ -327,6 +452,7 @@ impl CompilationPipeline {
             prefab_metadata,
             coordinator_file,
             coordinator_metadata,
+            behaviors: Vec::new(),
         })
     }
     
@@ -335,13 +461,12 @@ impl CompilationPipeline {
         self.context.info("Compiling with single behavior (legacy mode)...");
         
         // Use the enhanced pipeline for single behavior
-        let mut pipeline = EnhancedWasm2USharpPipeline::new();
-        let conversion_result = pipeline.convert(wasm_bytes)
-            .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("WASM conversion failed: {}", e)))?;
-        
-        // Write the main file
+        let started = Instant::now();
         let main_file_path = "Main.cs".to_string();
-        self.write_generated_file(&main_file_path, &conversion_result.main_class)?;
+        let emitted = EnhancedWasm2USharpPipeline::new().convert(wasm_bytes)
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("WASM conversion failed: {}", e)))
+            .and_then(|conversion_result| self.write_generated_file(&main_file_path, &conversion_result.main_class));
+        self.record_phase(PipelinePhase::EmitCSharp, started, emitted)?;
         
         Ok(CompilationResult {
             success: true,
@@ -354,6 +479,7 @@ impl CompilationPipeline {
             prefab_metadata: None,
             coordinator_file: None,
             coordinator_metadata: None,
+            behaviors: Vec::new(),
         })
     }
     
@@ -362,8 +488,13 @@ impl CompilationPipeline {
         use std::fs;
         use std::io::Write;
         
+        let output_dir = Path::new(self.config.output_directory.as_deref().unwrap_or(""));
+        let output_path = output_dir.join(file_path);
+        let file_path = output_path.to_string_lossy();
+        let file_path = file_path.as_ref();
+        
         // Create output directory if it doesn't exist
-        if let Some(parent) = std::path::Path::new(file_path).parent() {
+        if let Some(parent) = Path::new(file_path).parent() {
             fs::create_dir_all(parent)
                 .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("Failed to create directory: {}", e)))?;
         }
@@ -375,14 +506,50 @@ impl CompilationPipeline {
         file.write_all(content.as_bytes())
             .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("Failed to write file {}: {}", file_path, e)))?;
         
+        // Emit the Unity .meta file so the asset keeps a stable GUID across imports
+        write_asset_meta(Path::new(file_path), &AssetGuidSeed::new(&self.config.package_name, output_dir))
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!("Failed to write meta file for {}: {}", file_path, e)))?;
+        
         self.context.info(format!("Generated file: {}", file_path));
         Ok(())
     }
-    
+
+    /// Load the network event IDs saved by the previous build
+    ///
+    /// A missing table starts the numbering afresh; an unreadable one does too,
+    /// with a warning since existing events may be renumbered.
+    pub(crate) fn load_event_ids(&self) -> EventIdTable {
+        let table_path = match &self.config.output_directory {
+            Some(output_directory) => Path::new(output_directory).join(EVENT_ID_TABLE_FILE),
+            None => PathBuf::from(EVENT_ID_TABLE_FILE),
+        };
+        let json = match std::fs::read_to_string(&table_path) {
+            Ok(json) => json,
+            Err(_) => return EventIdTable::new(),
+        };
+        EventIdTable::from_json(&json).unwrap_or_else(|e| {
+            self.context.warning(format!(
+                "Ignoring unreadable network event IDs in {:?} ({}); existing events may be renumbered",
+                table_path, e
+            ));
+            EventIdTable::new()
+        })
+    }
+
+    /// Save the network event IDs next to the build output for the next build
+    pub(crate) fn save_event_ids(&self, event_ids: &EventIdTable) -> UdonSharpResult<()> {
+        self.write_generated_file(EVENT_ID_TABLE_FILE, &event_ids.to_json())
+    }
+
     /// Get the compilation context
     pub fn context(&self) -> &CompilationContext {
         &self.context
     }
+    
+    /// Get the configuration of the pipeline
+    pub fn config(&self) -> &UdonSharpConfig {
+        &self.config
+    }
 }
 
 /// Result of a compilation operation
@@ -405,6 +572,8 @@ pub struct CompilationResult {
     pub coordinator_file: Option<String>,
     /// Coordinator generation metadata
     pub coordinator_metadata: Option<CoordinatorGenerationResult>,
+    /// Behaviors analyzed from source, empty for WASM-based builds
+    pub behaviors: Vec<UdonBehaviourStruct>,
 }
 
 /// Metadata about multi-behavior compilation
@@ -433,11 +602,21 @@ struct MultiBehaviorAnalysis {
 
 impl CompilationPipeline {
     /// Check a project for errors without building
+    ///
+    /// Standard multi-behavior projects are analyzed and generated in memory,
+    /// so a check reports the same diagnostics and lints as a build without
+    /// writing any files.
     pub async fn check_project<P: AsRef<Path>>(&self, project_path: P) -> UdonSharpResult<CompilationResult> {
         self.context.info(format!("Checking project: {:?}", project_path.as_ref()));
         
-        // TODO: Implement project checking logic
-        // This would parse and validate Rust code without generating output
+        let rust_source = self.read_rust_source(&project_path)?;
+        if self.should_use_standard_multi_behavior(&rust_source)? {
+            let mut integration = StandardMultiBehaviorIntegration::new(self.config.clone(), self.context.clone());
+            let result = integration.compile_multi_behavior(&rust_source).await?;
+            return Ok(result.to_compilation_result());
+        }
+        
+        // TODO: Implement project checking logic for WASM-based projects
         
         Ok(CompilationResult {
             success: true,
@@ -450,6 +629,7 @@ impl CompilationPipeline {
             prefab_metadata: None,
             coordinator_file: None,
             coordinator_metadata: None,
+            behaviors: Vec::new(),
         })
     }
     
@@ -471,6 +651,7 @@ impl CompilationPipeline {
             prefab_metadata: None,
             coordinator_file: None,
             coordinator_metadata: None,
+            behaviors: Vec::new(),
         })
     }
 }
\ No newline at end of file
@@ -5,16 +5,21 @@
 //! UdonSharp projects.
 
 use crate::config::{PrefabGenerationSettings, InitializationOrderSettings};
+use crate::code_generator::to_camel_case;
+use crate::multi_behavior::{RustType, UdonBehaviourStruct};
 use wasm2usharp_enhanced::{BehaviorUnit, InterBehaviorCall, CallType};
 use anyhow::{Result, Context};
 use std::collections::{HashMap, HashSet};
 use serde_json::{json, Value};
+use syn::visit::Visit;
 use uuid::Uuid;
 
 /// Unity prefab generator for multi-behavior projects
 pub struct UnityPrefabGenerator {
     settings: PrefabGenerationSettings,
     initialization_settings: InitializationOrderSettings,
+    /// Name of the SharedRuntime object added to the hierarchy prefab, if any
+    shared_runtime_object: Option<String>,
 }
 
 impl UnityPrefabGenerator {
@@ -23,8 +28,16 @@ impl UnityPrefabGenerator {
         Self {
             settings,
             initialization_settings,
+            shared_runtime_object: None,
         }
     }
+
+    /// Add a GameObject with the given name holding the SharedRuntime to the
+    /// hierarchy prefab, so the generated `SharedRuntime.Instance` lookup finds it
+    pub fn with_shared_runtime_object(mut self, object_name: impl Into<String>) -> Self {
+        self.shared_runtime_object = Some(object_name.into());
+        self
+    }
     
     /// Generate all prefab files for the given behavior units
     pub fn generate_prefabs(&self, behavior_units: &[BehaviorUnit], namespace: Option<&str>) -> Result<PrefabGenerationResult> {
@@ -445,6 +458,83 @@ impl UnityPrefabGenerator {
         }))
     }
     
+    /// Generate a single prefab holding every behavior as a child GameObject.
+    ///
+    /// Children are named after the `GameObject::find` calls that target them,
+    /// so lookups by name still succeed, and public GameObject reference fields
+    /// are pre-wired to the sibling they resolve to.
+    pub fn generate_hierarchy_prefab(&self, behaviors: &[UdonBehaviourStruct], namespace: Option<&str>) -> Result<HierarchyPrefab> {
+        let (prefab_data, unresolved_references) = self.create_hierarchy_prefab_structure(behaviors, namespace)?;
+        Ok(HierarchyPrefab {
+            content: self.serialize_prefab_to_yaml(&prefab_data)?,
+            unresolved_references,
+        })
+    }
+
+    /// Create the hierarchy prefab structure, returning it with the find targets
+    /// that did not resolve to a behavior
+    fn create_hierarchy_prefab_structure(&self, behaviors: &[UdonBehaviourStruct], namespace: Option<&str>) -> Result<(Value, Vec<String>)> {
+        let resolution = resolve_find_targets(behaviors);
+
+        let root_object_id = self.generate_file_id();
+        let root_transform_id = self.generate_file_id();
+        let object_ids: HashMap<&str, (u64, u64, u64)> = behaviors.iter()
+            .map(|b| (b.name.as_str(), (self.generate_file_id(), self.generate_file_id(), self.generate_file_id())))
+            .collect();
+        let shared_runtime_ids = self.shared_runtime_object.as_ref()
+            .map(|name| (name, (self.generate_file_id(), self.generate_file_id(), self.generate_file_id())));
+
+        let mut children: Vec<u64> = behaviors.iter().map(|b| object_ids[b.name.as_str()].1).collect();
+        children.extend(shared_runtime_ids.map(|(_, (_, transform_id, _))| transform_id));
+        let mut objects = vec![
+            game_object_entry(root_object_id, &[root_transform_id], "MultiBehaviorWorld"),
+            transform_entry(root_transform_id, root_object_id, 0, children, 0),
+        ];
+
+        for (index, behavior) in behaviors.iter().enumerate() {
+            let (object_id, transform_id, behaviour_id) = object_ids[behavior.name.as_str()];
+            let object_name = resolution.object_names.get(&behavior.name).unwrap_or(&behavior.name);
+
+            let serialized_fields: Vec<Value> = resolution.wired_fields.get(&behavior.name)
+                .into_iter()
+                .flatten()
+                .map(|(field_name, target)| json!({
+                    "fieldName": field_name,
+                    "fieldType": "UnityEngine.GameObject",
+                    "value": {
+                        "fileID": object_ids[target.as_str()].0
+                    }
+                }))
+                .collect();
+
+            objects.push(game_object_entry(object_id, &[transform_id, behaviour_id], object_name));
+            objects.push(transform_entry(transform_id, object_id, root_transform_id, Vec::new(), index));
+            objects.push(udon_behaviour_entry(
+                behaviour_id,
+                object_id,
+                &self.generate_class_name(&behavior.name, namespace),
+                serialized_fields,
+            ));
+        }
+
+        if let Some((name, (object_id, transform_id, behaviour_id))) = shared_runtime_ids {
+            objects.push(game_object_entry(object_id, &[transform_id, behaviour_id], name));
+            objects.push(transform_entry(transform_id, object_id, root_transform_id, Vec::new(), behaviors.len()));
+            objects.push(udon_behaviour_entry(
+                behaviour_id,
+                object_id,
+                &self.generate_class_name("SharedRuntime", namespace),
+                Vec::new(),
+            ));
+        }
+
+        Ok((json!({
+            "%YAML 1.1": null,
+            "%TAG !u! tag:unity3d.com,2011:": null,
+            "objects": objects
+        }), resolution.unresolved))
+    }
+    
     /// Create UdonBehaviour component data
     fn create_udon_behaviour_component(&self, component_id: u64, class_name: &str, behavior_unit: &BehaviorUnit) -> Result<Value> {
         let mut serialized_fields = Vec::new();
@@ -718,6 +808,193 @@ impl UnityPrefabGenerator {
     }
 }
 
+/// A prefab holding every behavior as a child GameObject
+#[derive(Debug)]
+pub struct HierarchyPrefab {
+    /// Prefab content
+    pub content: String,
+    /// `GameObject::find` targets that did not match any behavior
+    pub unresolved_references: Vec<String>,
+}
+
+/// How `GameObject::find` targets map onto sibling objects
+#[derive(Debug, Default)]
+struct FindResolution {
+    /// Child object name of each behavior whose name differs from the behavior
+    object_names: HashMap<String, String>,
+    /// Serialized GameObject fields of each behavior, with the behavior they point at
+    wired_fields: HashMap<String, Vec<(String, String)>>,
+    /// Descriptions of find targets that match no behavior
+    unresolved: Vec<String>,
+}
+
+/// Resolve the `find` targets of every behavior to sibling behaviors.
+///
+/// Literal `GameObject::find("Name")` calls in method bodies and the
+/// `GameObject.Find("fieldName")` fallback emitted for private reference fields
+/// both look objects up by name, so the target child is named to match.
+/// Public reference fields are serialized and get wired directly instead.
+fn resolve_find_targets(behaviors: &[UdonBehaviourStruct]) -> FindResolution {
+    let by_key: HashMap<String, &str> = behaviors.iter()
+        .map(|b| (find_key(&b.name), b.name.as_str()))
+        .collect();
+    let mut resolution = FindResolution::default();
+    let mut name_sources: HashMap<String, String> = HashMap::new();
+
+    let mut claim_name = |resolution: &mut FindResolution, target: &str, find_name: &str, source: String| {
+        match resolution.object_names.get(target) {
+            Some(existing) if existing != find_name => resolution.unresolved.push(format!(
+                "{} looks up '{}', but '{}' is already named '{}' for {}",
+                source, find_name, target, existing, name_sources[target]
+            )),
+            Some(_) => {}
+            None => {
+                resolution.object_names.insert(target.to_string(), find_name.to_string());
+                name_sources.insert(target.to_string(), source);
+            }
+        }
+    };
+
+    // Literal names in method bodies must match exactly, so they win
+    for behavior in behaviors {
+        for method in &behavior.methods {
+            let Some(block) = method.body.as_deref().and_then(|body| syn::parse_str::<syn::Block>(body).ok()) else {
+                continue;
+            };
+            let mut finder = FindCallVisitor::default();
+            finder.visit_block(&block);
+            for find_name in finder.names {
+                let source = format!("'{}::{}'", behavior.name, method.name);
+                match by_key.get(&find_key(&find_name)) {
+                    Some(target) => claim_name(&mut resolution, target, &find_name, source),
+                    None => resolution.unresolved.push(format!("{} looks up '{}', which is not a behavior", source, find_name)),
+                }
+            }
+        }
+    }
+
+    for behavior in behaviors {
+        for field in &behavior.fields {
+            let is_reference = matches!(&field.field_type, RustType::GameObject)
+                || matches!(&field.field_type, RustType::Option(inner) if **inner == RustType::GameObject);
+            if !is_reference {
+                continue;
+            }
+            let field_name = to_camel_case(&field.name);
+            let source = format!("field '{}::{}'", behavior.name, field.name);
+            let Some(target) = by_key.get(&find_key(&field.name)) else {
+                resolution.unresolved.push(format!("{} does not name a behavior", source));
+                continue;
+            };
+            if field.is_serialized() {
+                resolution.wired_fields.entry(behavior.name.clone()).or_default().push((field_name, target.to_string()));
+            } else {
+                claim_name(&mut resolution, target, &field_name, source);
+            }
+        }
+    }
+
+    resolution
+}
+
+/// Normalize a name so `ui_controller`, `uiController` and `UIController` compare equal
+fn find_key(name: &str) -> String {
+    name.chars().filter(|c| *c != '_').flat_map(char::to_lowercase).collect()
+}
+
+/// Collects the string literal names passed to `GameObject::find`
+#[derive(Default)]
+struct FindCallVisitor {
+    names: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for FindCallVisitor {
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(func) = &*call.func {
+            let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            if segments.ends_with(&["GameObject".to_string(), "find".to_string()]) {
+                if let Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(name), .. })) = call.args.first() {
+                    self.names.push(name.value());
+                }
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
+
+/// Build a GameObject entry with the given components
+fn game_object_entry(object_id: u64, component_ids: &[u64], name: &str) -> Value {
+    let components: Vec<Value> = component_ids.iter()
+        .map(|id| json!({ "component": { "fileID": id } }))
+        .collect();
+    json!({
+        object_id.to_string(): {
+            "GameObject": {
+                "m_ObjectHideFlags": 0,
+                "serializedVersion": 6,
+                "m_Component": components,
+                "m_Layer": 0,
+                "m_Name": name,
+                "m_TagString": "Untagged",
+                "m_IsActive": 1
+            }
+        }
+    })
+}
+
+/// Build an UdonSharp behaviour entry attached to a GameObject
+fn udon_behaviour_entry(behaviour_id: u64, object_id: u64, class_identifier: &str, serialized_fields: Vec<Value>) -> Value {
+    json!({
+        behaviour_id.to_string(): {
+            "MonoBehaviour": {
+                "m_ObjectHideFlags": 0,
+                "m_GameObject": {
+                    "fileID": object_id
+                },
+                "m_Enabled": 1,
+                "m_EditorHideFlags": 0,
+                "m_Script": {
+                    "fileID": 11500000,
+                    "guid": "45115577ef41a5b4ca741ed302693907",
+                    "type": 3
+                },
+                "m_Name": "",
+                "m_EditorClassIdentifier": class_identifier,
+                "serializedUdonProgramAsset": {
+                    "fileID": 0
+                },
+                "udonSharpBackingUdonBehaviour": {
+                    "fileID": 0
+                },
+                "serializedFields": serialized_fields
+            }
+        }
+    })
+}
+
+/// Build a Transform entry; a parent of 0 marks the root
+fn transform_entry(transform_id: u64, object_id: u64, parent_id: u64, children: Vec<u64>, root_order: usize) -> Value {
+    let children: Vec<Value> = children.into_iter().map(|id| json!({ "fileID": id })).collect();
+    json!({
+        transform_id.to_string(): {
+            "Transform": {
+                "m_ObjectHideFlags": 0,
+                "m_GameObject": {
+                    "fileID": object_id
+                },
+                "m_LocalRotation": { "x": 0, "y": 0, "z": 0, "w": 1 },
+                "m_LocalPosition": { "x": 0, "y": 0, "z": 0 },
+                "m_LocalScale": { "x": 1, "y": 1, "z": 1 },
+                "m_Children": children,
+                "m_Father": {
+                    "fileID": parent_id
+                },
+                "m_RootOrder": root_order
+            }
+        }
+    })
+}
+
 /// Result of prefab generation
 #[derive(Debug)]
 pub struct PrefabGenerationResult {
@@ -742,4 +1019,51 @@ pub struct PrefabGenerationMetadata {
     pub has_dependencies: bool,
     /// Generation timestamp
     pub generated_at: chrono::DateTime<chrono::Utc>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multi_behavior::{StructField, FieldAttribute};
+
+    /// Find the single entry of the given Unity type satisfying a predicate
+    fn find_entry<'a>(objects: &'a [Value], kind: &str, predicate: impl Fn(&Value) -> bool) -> (u64, &'a Value) {
+        objects.iter()
+            .filter_map(|entry| entry.as_object()?.iter().next())
+            .filter_map(|(id, data)| Some((id.parse::<u64>().ok()?, data.get(kind)?)))
+            .find(|(_, data)| predicate(data))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hierarchy_prefab_wires_reference_to_sibling() {
+        let mut game_manager = UdonBehaviourStruct::new("GameManager".to_string());
+        let mut reference = StructField::new(
+            "ui_controller".to_string(),
+            RustType::Option(Box::new(RustType::GameObject)),
+        );
+        reference.add_attribute(FieldAttribute::UdonPublic);
+        game_manager.add_field(reference);
+        let ui_controller = UdonBehaviourStruct::new("UIController".to_string());
+
+        let generator = UnityPrefabGenerator::new(PrefabGenerationSettings::default(), InitializationOrderSettings::default());
+        let (prefab, unresolved) = generator
+            .create_hierarchy_prefab_structure(&[game_manager, ui_controller], None)
+            .unwrap();
+        assert!(unresolved.is_empty(), "{:?}", unresolved);
+
+        let objects = prefab["objects"].as_array().unwrap();
+        let (ui_object_id, _) = find_entry(objects, "GameObject", |o| o["m_Name"] == "UIController");
+        let (gm_object_id, _) = find_entry(objects, "GameObject", |o| o["m_Name"] == "GameManager");
+        let (_, gm_behaviour) = find_entry(objects, "MonoBehaviour", |b| b["m_GameObject"]["fileID"] == gm_object_id);
+
+        let fields = gm_behaviour["serializedFields"].as_array().unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0]["fieldName"], "uiController");
+        assert_eq!(fields[0]["value"]["fileID"], ui_object_id);
+
+        let (_, ui_transform) = find_entry(objects, "Transform", |t| t["m_GameObject"]["fileID"] == ui_object_id);
+        let (root_transform_id, _) = find_entry(objects, "Transform", |t| t["m_Father"]["fileID"] == 0);
+        assert_eq!(ui_transform["m_Father"]["fileID"], root_transform_id);
+    }
+}
@@ -0,0 +1,247 @@
+//! Quantized sync of float fields
+//!
+//! Synced floats cost four bytes per component on every serialization, which
+//! adds up for worlds syncing many positions. A field marked
+//! `#[udon_quantize(bits = 16, min = -100.0, max = 100.0)]` is instead clamped
+//! to the declared range and packed into a single synced integer, and the
+//! generated C# exposes the original field as a property whose getter and
+//! setter unpack and pack it.
+//!
+//! Each component is stored as one of `2^bits - 1` evenly spaced steps, so a
+//! value read back is off by at most half a step: `(max - min) / (2 * (2^bits - 1))`.
+//! Values outside the range are clamped to it, and an unset packed field
+//! decodes to `min` rather than zero.
+
+use crate::code_generator::to_camel_case;
+use crate::multi_behavior::{FieldAttribute, RustType, StructField};
+
+/// Largest supported number of bits per component; float mantissas hold 24 bits
+pub const MAX_QUANTIZE_BITS: u8 = 24;
+
+/// Packing of a quantized field into a synced integer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantization {
+    /// Bits per component
+    pub bits: u8,
+    /// Smallest representable value
+    pub min: f32,
+    /// Largest representable value
+    pub max: f32,
+    /// Number of float components of the field
+    pub components: usize,
+}
+
+impl Quantization {
+    /// Create a quantization, validating that the components fit into 64 bits
+    pub fn new(bits: u8, min: f32, max: f32, components: usize) -> Result<Self, String> {
+        if bits == 0 || bits > MAX_QUANTIZE_BITS {
+            return Err(format!("bits must be between 1 and {}", MAX_QUANTIZE_BITS));
+        }
+        if min.partial_cmp(&max) != Some(std::cmp::Ordering::Less) {
+            return Err(format!("min ({}) must be less than max ({})", min, max));
+        }
+        if bits as usize * components > 64 {
+            return Err(format!(
+                "{} components of {} bits do not fit into a 64-bit synced integer",
+                components, bits
+            ));
+        }
+        Ok(Self { bits, min, max, components })
+    }
+
+    /// Get the quantization of a field, if it is marked `#[udon_quantize]`
+    pub fn for_field(field: &StructField) -> Option<Result<Self, String>> {
+        let (bits, min, max) = field.attributes.iter().find_map(|attribute| match attribute {
+            FieldAttribute::UdonQuantize { bits, min, max } => Some((*bits, min, max)),
+            _ => None,
+        })?;
+
+        Some((|| {
+            let components = match field.field_type {
+                RustType::F32 => 1,
+                RustType::Vector2 => 2,
+                RustType::Vector3 => 3,
+                _ => return Err("only f32, Vector2 and Vector3 fields can be quantized".to_string()),
+            };
+            if !field.has_sync_attribute() {
+                return Err("quantized fields must also be #[udon_sync]".to_string());
+            }
+            let min = min.parse::<f32>().map_err(|_| format!("min '{}' is not a number", min))?;
+            let max = max.parse::<f32>().map_err(|_| format!("max '{}' is not a number", max))?;
+            Self::new(bits, min, max, components)
+        })())
+    }
+
+    /// Number of steps a component is divided into
+    fn levels(&self) -> u64 {
+        (1u64 << self.bits) - 1
+    }
+
+    /// Distance between two representable values
+    pub fn step(&self) -> f32 {
+        (self.max - self.min) / self.levels() as f32
+    }
+
+    /// Largest difference between a value in range and the value read back
+    pub fn max_error(&self) -> f32 {
+        self.step() / 2.0
+    }
+
+    /// Smallest C# integer type holding all packed components
+    pub fn packed_type(&self) -> &'static str {
+        match self.bits as usize * self.components {
+            0..=8 => "byte",
+            9..=16 => "ushort",
+            17..=32 => "uint",
+            _ => "ulong",
+        }
+    }
+
+    /// Pack component values the way the generated setter does
+    pub fn pack(&self, values: &[f32]) -> u64 {
+        let scale = self.levels() as f32 / (self.max - self.min);
+        values.iter().enumerate().fold(0, |packed, (i, value)| {
+            let level = ((value.clamp(self.min, self.max) - self.min) * scale).round() as u64;
+            packed | (level.min(self.levels()) << (i * self.bits as usize))
+        })
+    }
+
+    /// Unpack component values the way the generated getter does
+    pub fn unpack(&self, packed: u64) -> Vec<f32> {
+        (0..self.components)
+            .map(|i| {
+                let level = (packed >> (i * self.bits as usize)) & self.levels();
+                self.min + level as f32 * self.step()
+            })
+            .collect()
+    }
+
+    /// Name of the synced integer backing a quantized field
+    pub fn packed_field_name(field_name: &str) -> String {
+        format!("{}Packed", to_camel_case(field_name))
+    }
+
+    /// Generate the packed synced field and the property that packs and unpacks it
+    pub fn generate_declaration(&self, field_name: &str, csharp_type: &str, visibility: &str) -> String {
+        let property = to_camel_case(field_name);
+        let packed = Self::packed_field_name(field_name);
+        let components: Vec<String> = match self.components {
+            1 => vec!["value".to_string()],
+            n => ["x", "y", "z"][..n].iter().map(|c| format!("value.{}", c)).collect(),
+        };
+
+        let mask = format!("0x{:X}", self.levels());
+        let unpacked: Vec<String> = (0..self.components)
+            .map(|i| {
+                let shift = i * self.bits as usize;
+                let level = if shift == 0 {
+                    format!("({} & {})", packed, mask)
+                } else {
+                    format!("(({} >> {}) & {})", packed, shift, mask)
+                };
+                format!("{} + {} * {}", float_literal(self.min), level, float_literal(self.step()))
+            })
+            .collect();
+        let getter = if self.components == 1 {
+            unpacked[0].clone()
+        } else {
+            format!("new {}({})", csharp_type, unpacked.join(", "))
+        };
+
+        let scale = self.levels() as f32 / (self.max - self.min);
+        let packed_levels: Vec<String> = components.iter().enumerate()
+            .map(|(i, component)| {
+                let clamped = format!("Mathf.Clamp({}, {}, {})", component, float_literal(self.min), float_literal(self.max));
+                let level = format!(
+                    "(ulong)Mathf.RoundToInt({} * {})",
+                    offset_from_min(&clamped, self.min),
+                    float_literal(scale)
+                );
+                match i * self.bits as usize {
+                    0 => level,
+                    shift => format!("({} << {})", level, shift),
+                }
+            })
+            .collect();
+        let packed_type = self.packed_type();
+        let setter = if packed_type == "ulong" {
+            packed_levels.join(" | ")
+        } else {
+            format!("({})({})", packed_type, packed_levels.join(" | "))
+        };
+
+        [
+            format!(
+                "    // {} is quantized to {} bits per component over [{}, {}]; values are off by at most {}",
+                property, self.bits, self.min, self.max, self.max_error()
+            ),
+            format!("    [UdonSynced] private {} {};", packed_type, packed),
+            format!("    {} {} {}", visibility, csharp_type, property),
+            "    {".to_string(),
+            format!("        get => {};", getter),
+            format!("        set => {} = {};", packed, setter),
+            "    }".to_string(),
+        ]
+        .join("\n")
+    }
+}
+
+/// Format a float as a C# `float` literal
+fn float_literal(value: f32) -> String {
+    format!("{}f", value)
+}
+
+/// Subtract `min` from a C# expression without writing `- -x`
+fn offset_from_min(expr: &str, min: f32) -> String {
+    if min == 0.0 {
+        expr.to_string()
+    } else if min < 0.0 {
+        format!("({} + {})", expr, float_literal(-min))
+    } else {
+        format!("({} - {})", expr, float_literal(min))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quantized_position() -> StructField {
+        let mut field = StructField::new("position".to_string(), RustType::Vector3);
+        field.add_attribute(FieldAttribute::UdonSync);
+        field.add_attribute(FieldAttribute::UdonQuantize {
+            bits: 16,
+            min: "-100".to_string(),
+            max: "100".to_string(),
+        });
+        field
+    }
+
+    #[test]
+    fn test_quantized_vector3_round_trips_within_tolerance() {
+        let quantization = Quantization::for_field(&quantized_position()).unwrap().unwrap();
+        assert_eq!(quantization.packed_type(), "ulong");
+        assert!(quantization.max_error() < 0.0016);
+
+        for value in [[0.0, 0.0, 0.0], [12.345, -67.891, 99.999], [-100.0, 100.0, 0.5]] {
+            let unpacked = quantization.unpack(quantization.pack(&value));
+            for (original, read_back) in value.iter().zip(&unpacked) {
+                assert!((original - read_back).abs() <= quantization.max_error() * 1.01, "{} became {}", original, read_back);
+            }
+        }
+
+        // Out-of-range values clamp to the declared range
+        assert_eq!(quantization.unpack(quantization.pack(&[250.0, -250.0, 0.0]))[..2], [100.0, -100.0]);
+    }
+
+    #[test]
+    fn test_quantization_rejects_invalid_settings() {
+        let mut unsynced = StructField::new("speed".to_string(), RustType::F32);
+        unsynced.add_attribute(FieldAttribute::UdonQuantize { bits: 8, min: "0".to_string(), max: "10".to_string() });
+        assert!(Quantization::for_field(&unsynced).unwrap().is_err());
+
+        assert!(Quantization::new(22, -1.0, 1.0, 3).is_err());
+        assert!(Quantization::new(8, 1.0, 1.0, 1).is_err());
+        assert_eq!(Quantization::new(8, 0.0, 1.0, 1).unwrap().packed_type(), "byte");
+    }
+}
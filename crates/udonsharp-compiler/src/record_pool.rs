@@ -0,0 +1,222 @@
+//! Pre-allocated record pools for `#[udon_pool]` behaviors
+//!
+//! Allocating in Udon is slow and the garbage it leaves behind causes frame
+//! hitches, which adds up for event systems that create a record per emit. A
+//! behavior marked `#[udon_pool(size = 32)]` allocates a fixed pool of
+//! `DataDictionary` records in `Start` (`#[udon_pool(DataList, size = 32)]`
+//! pools lists instead). Allocation sites of the pooled type in the
+//! behavior's methods draw a cleared record from the pool, and `drop(record)`
+//! returns it. When every record is in use the pool logs a warning and hands
+//! out the oldest record again.
+
+use crate::code_generator::{GeneratedField, GeneratedMethod, GeneratedParameter};
+use crate::multi_behavior::{StructAttribute, UdonBehaviourStruct};
+
+/// Types that can be pooled; each has a parameterless constructor and `Clear`
+pub const POOLABLE_RECORD_TYPES: &[&str] = &["DataDictionary", "DataList"];
+
+/// Record type pooled when `#[udon_pool]` does not name one
+pub const DEFAULT_POOLED_RECORD_TYPE: &str = "DataDictionary";
+
+/// Name of the loop variable used by the generated pool code
+const POOL_INDEX: &str = "poolIndex";
+
+/// A fixed pool of reusable records owned by a behavior
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordPool {
+    /// C# type of the pooled records
+    pub record_type: String,
+    /// Number of records allocated in `Start`
+    pub size: usize,
+}
+
+impl RecordPool {
+    /// Create a pool, validating the record type and size
+    pub fn new(record_type: &str, size: usize) -> Result<Self, String> {
+        if !POOLABLE_RECORD_TYPES.contains(&record_type) {
+            return Err(format!(
+                "'{}' cannot be pooled; expected one of: {}",
+                record_type,
+                POOLABLE_RECORD_TYPES.join(", ")
+            ));
+        }
+        if size == 0 {
+            return Err("size must be at least 1".to_string());
+        }
+        Ok(Self { record_type: record_type.to_string(), size })
+    }
+
+    /// Get the pool of a behavior, if it is marked `#[udon_pool]`
+    pub fn for_behavior(udon_struct: &UdonBehaviourStruct) -> Option<Self> {
+        udon_struct.attributes.iter().find_map(|attribute| match attribute {
+            StructAttribute::Pool { record_type, size } => Some(Self {
+                record_type: record_type.clone(),
+                size: *size,
+            }),
+            _ => None,
+        })
+    }
+
+    /// Whether a lowered constructor call such as `new DataDictionary` creates a pooled record
+    pub fn allocates(&self, constructor: &str) -> bool {
+        constructor.strip_prefix("new ") == Some(self.record_type.as_str())
+    }
+
+    /// Name of the method handing out a record from the pool
+    pub fn acquire_method(&self) -> String {
+        format!("Acquire{}", self.record_type)
+    }
+
+    /// Name of the method returning a record to the pool
+    pub fn release_method(&self) -> String {
+        format!("Release{}", self.record_type)
+    }
+
+    /// Prefix of the private fields backing the pool, e.g. `_dataDictionaryPool`
+    fn field_prefix(&self) -> String {
+        let mut chars = self.record_type.chars();
+        let first = chars.next().map(|c| c.to_lowercase().to_string()).unwrap_or_default();
+        format!("_{}{}Pool", first, chars.as_str())
+    }
+
+    /// Statements allocating the pool, placed at the start of `Start`
+    pub fn start_lines(&self) -> Vec<String> {
+        let pool = self.field_prefix();
+        vec![
+            format!("        // Pre-allocate {} pooled {} records", self.size, self.record_type),
+            format!("        {} = new {}[{}];", pool, self.record_type, self.size),
+            format!("        {}InUse = new bool[{}];", pool, self.size),
+            format!("        {}Acquired = new int[{}];", pool, self.size),
+            format!("        for (int {i} = 0; {i} < {}; {i}++)", self.size, i = POOL_INDEX),
+            "        {".to_string(),
+            format!("            {}[{}] = new {}();", pool, POOL_INDEX, self.record_type),
+            "        }".to_string(),
+        ]
+    }
+
+    /// Private fields backing the pool
+    pub fn fields(&self) -> Vec<GeneratedField> {
+        let pool = self.field_prefix();
+        [
+            (pool.clone(), format!("{}[]", self.record_type)),
+            (format!("{}InUse", pool), "bool[]".to_string()),
+            (format!("{}Acquired", pool), "int[]".to_string()),
+            (format!("{}Clock", pool), "int".to_string()),
+        ]
+        .into_iter()
+        .map(|(name, field_type)| GeneratedField {
+            declaration: format!("    private {} {};", field_type, name),
+            name,
+            field_type,
+            visibility: "private".to_string(),
+            attributes: Vec::new(),
+            default_value: None,
+        })
+        .collect()
+    }
+
+    /// `Acquire{Type}` and `Release{Type}` methods of the pool
+    pub fn methods(&self, behavior_name: &str) -> Vec<GeneratedMethod> {
+        let pool = self.field_prefix();
+        let (size, record_type, i) = (self.size, &self.record_type, POOL_INDEX);
+
+        let acquire_body = [
+            "        int index = -1;".to_string(),
+            "        int oldest = 0;".to_string(),
+            format!("        for (int {i} = 0; {i} < {}; {i}++)", size),
+            "        {".to_string(),
+            format!("            if (!{}InUse[{}])", pool, i),
+            "            {".to_string(),
+            format!("                index = {};", i),
+            "                break;".to_string(),
+            "            }".to_string(),
+            format!("            if ({p}Acquired[{i}] < {p}Acquired[oldest])", p = pool),
+            "            {".to_string(),
+            format!("                oldest = {};", i),
+            "            }".to_string(),
+            "        }".to_string(),
+            "        if (index < 0)".to_string(),
+            "        {".to_string(),
+            format!(
+                "            Debug.LogWarning(\"[{}] {} pool of {} exhausted, reusing the oldest record\");",
+                behavior_name, record_type, size
+            ),
+            "            index = oldest;".to_string(),
+            "        }".to_string(),
+            format!("        {}Clock++;", pool),
+            format!("        {}InUse[index] = true;", pool),
+            format!("        {p}Acquired[index] = {p}Clock;", p = pool),
+            format!("        {} record = {}[index];", record_type, pool),
+            "        record.Clear();".to_string(),
+            "        return record;".to_string(),
+        ]
+        .join("\n");
+
+        let release_body = [
+            format!("        for (int {i} = 0; {i} < {}; {i}++)", size),
+            "        {".to_string(),
+            format!("            if ({}[{}] == record)", pool, i),
+            "            {".to_string(),
+            format!("                {}InUse[{}] = false;", pool, i),
+            "                return;".to_string(),
+            "            }".to_string(),
+            "        }".to_string(),
+        ]
+        .join("\n");
+
+        vec![
+            GeneratedMethod {
+                name: self.acquire_method(),
+                return_type: record_type.clone(),
+                parameters: Vec::new(),
+                attributes: Vec::new(),
+                declaration: format!(
+                    "    /// <summary>\n    /// Take a cleared {} from the pool, reusing the oldest when all are in use\n    /// </summary>\n    private {} {}()\n    {{\n{}\n    }}",
+                    record_type, record_type, self.acquire_method(), acquire_body
+                ),
+                body: acquire_body,
+            },
+            GeneratedMethod {
+                name: self.release_method(),
+                return_type: "void".to_string(),
+                parameters: vec![GeneratedParameter {
+                    name: "record".to_string(),
+                    param_type: record_type.clone(),
+                }],
+                attributes: Vec::new(),
+                declaration: format!(
+                    "    /// <summary>\n    /// Return a {} to the pool\n    /// </summary>\n    private void {}({} record)\n    {{\n{}\n    }}",
+                    record_type, self.release_method(), record_type, release_body
+                ),
+                body: release_body,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_rejects_unpoolable_types_and_empty_pools() {
+        assert!(RecordPool::new("GameObject", 8).is_err());
+        assert!(RecordPool::new("DataList", 0).is_err());
+        let pool = RecordPool::new("DataList", 8).unwrap();
+        assert!(pool.allocates("new DataList"));
+        assert!(!pool.allocates("new DataDictionary"));
+        assert_eq!(pool.acquire_method(), "AcquireDataList");
+    }
+
+    #[test]
+    fn test_exhausted_pool_reuses_the_oldest_record() {
+        let pool = RecordPool::new("DataDictionary", 4).unwrap();
+        let acquire = &pool.methods("EventBus")[0];
+        assert!(acquire.body.contains("if (_dataDictionaryPoolAcquired[poolIndex] < _dataDictionaryPoolAcquired[oldest])"));
+        assert!(acquire.body.contains(
+            "Debug.LogWarning(\"[EventBus] DataDictionary pool of 4 exhausted, reusing the oldest record\");"
+        ));
+        assert!(acquire.body.contains("            index = oldest;"));
+        assert!(acquire.body.contains("        record.Clear();"));
+    }
+}
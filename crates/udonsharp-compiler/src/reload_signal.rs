@@ -0,0 +1,153 @@
+//! Reload signal for the Unity editor during watch builds
+//!
+//! After each successful build, `udonsharp build --watch` writes
+//! `reload.signal` into the output directory. An editor script polls the file
+//! and re-imports only the scripts it names instead of refreshing the whole
+//! asset database. The signal is JSON:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "timestamp": 1760659200123,
+//!   "behaviors": ["GameManager", "Scoreboard"],
+//!   "scripts": ["GameManager.cs", "Scoreboard.cs"]
+//! }
+//! ```
+//!
+//! - `version` is the format version, bumped on incompatible changes
+//! - `timestamp` is the time of the build in milliseconds since the Unix
+//!   epoch; a signal with a timestamp already handled can be ignored
+//! - `behaviors` are the behaviors whose generated script changed since the
+//!   previous build, sorted by name
+//! - `scripts` are the changed scripts, relative to the output directory
+//!
+//! The signal is written to a temporary file and renamed into place, so the
+//! editor never reads a partially written one.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Name of the signal file in the output directory
+pub const RELOAD_SIGNAL_FILE: &str = "reload.signal";
+
+/// Version of the signal format
+pub const RELOAD_SIGNAL_VERSION: u32 = 1;
+
+/// Behaviors rebuilt by a watch build, for the editor to re-import
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReloadSignal {
+    pub version: u32,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Behaviors whose generated script changed, sorted by name
+    pub behaviors: Vec<String>,
+    /// Changed scripts relative to the output directory
+    pub scripts: Vec<String>,
+}
+
+impl ReloadSignal {
+    /// Create a signal stamped with the current time
+    pub fn new(behaviors: Vec<String>, scripts: Vec<String>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        Self { version: RELOAD_SIGNAL_VERSION, timestamp, behaviors, scripts }
+    }
+
+    /// Write the signal into the output directory, replacing the previous one
+    pub fn write_to(&self, output_dir: &Path) -> io::Result<PathBuf> {
+        fs::create_dir_all(output_dir)?;
+        let path = output_dir.join(RELOAD_SIGNAL_FILE);
+        let partial = output_dir.join(format!("{}.tmp", RELOAD_SIGNAL_FILE));
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&partial, json)?;
+        fs::rename(&partial, &path)?;
+        Ok(path)
+    }
+}
+
+/// Hashes of the generated behavior scripts of a build, compared with the
+/// previous build to find the behaviors that changed
+#[derive(Debug, Clone, Default)]
+pub struct ScriptSnapshot {
+    /// Script path and content hash, keyed by behavior
+    scripts: BTreeMap<String, (String, u64)>,
+}
+
+impl ScriptSnapshot {
+    /// Hash the script of every behavior of a build, given as paths relative
+    /// to the output directory; scripts that cannot be read are left out
+    pub fn capture(output_dir: &Path, behavior_files: &HashMap<String, String>) -> Self {
+        let scripts = behavior_files.iter()
+            .filter_map(|(behavior, script)| {
+                let content = fs::read(output_dir.join(script)).ok()?;
+                let mut hasher = DefaultHasher::new();
+                content.hash(&mut hasher);
+                Some((behavior.clone(), (script.clone(), hasher.finish())))
+            })
+            .collect();
+        Self { scripts }
+    }
+
+    /// Signal naming the behaviors that are new or changed since `previous`;
+    /// `None` when the build changed none
+    pub fn reload_signal(&self, previous: &ScriptSnapshot) -> Option<ReloadSignal> {
+        let changed: Vec<(&String, &String)> = self.scripts.iter()
+            .filter(|(behavior, script)| previous.scripts.get(*behavior) != Some(*script))
+            .map(|(behavior, (script, _))| (behavior, script))
+            .collect();
+        if changed.is_empty() {
+            return None;
+        }
+        // Bundled behaviors share one script
+        let scripts: BTreeSet<&String> = changed.iter().map(|(_, script)| *script).collect();
+        Some(ReloadSignal::new(
+            changed.iter().map(|(behavior, _)| behavior.to_string()).collect(),
+            scripts.into_iter().cloned().collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebuild_signals_the_behaviors_whose_script_changed() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let output = output_dir.path();
+        let behavior_files: HashMap<String, String> = [
+            ("GameManager".to_string(), "GameManager.cs".to_string()),
+            ("Scoreboard".to_string(), "Scoreboard.cs".to_string()),
+        ].into_iter().collect();
+        fs::write(output.join("GameManager.cs"), "public class GameManager {}").unwrap();
+        fs::write(output.join("Scoreboard.cs"), "public class Scoreboard {}").unwrap();
+        let first_build = ScriptSnapshot::capture(output, &behavior_files);
+
+        // The first build signals every behavior
+        let signal = first_build.reload_signal(&ScriptSnapshot::default()).unwrap();
+        assert_eq!(signal.behaviors, vec!["GameManager", "Scoreboard"]);
+
+        fs::write(output.join("Scoreboard.cs"), "public class Scoreboard { private int score; }").unwrap();
+        let rebuild = ScriptSnapshot::capture(output, &behavior_files);
+        let signal = rebuild.reload_signal(&first_build).unwrap();
+        let path = signal.write_to(output).unwrap();
+        assert_eq!(path, output.join(RELOAD_SIGNAL_FILE));
+
+        let written: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["version"], RELOAD_SIGNAL_VERSION);
+        assert_eq!(written["behaviors"], serde_json::json!(["Scoreboard"]));
+        assert_eq!(written["scripts"], serde_json::json!(["Scoreboard.cs"]));
+        assert!(written["timestamp"].as_u64().unwrap() > 0);
+        assert!(!output.join("reload.signal.tmp").exists());
+
+        assert!(ScriptSnapshot::capture(output, &behavior_files).reload_signal(&rebuild).is_none());
+    }
+}
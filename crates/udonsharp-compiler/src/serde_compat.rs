@@ -0,0 +1,106 @@
+//! Detection of `serde`/`serde_json` usage in behavior methods
+//!
+//! serde serializes through generic visitor code that has no Udon
+//! counterpart, so a `serde_json::to_string` call cannot be lowered and
+//! would otherwise only show up as a method left as a placeholder. The only
+//! JSON implementation available in a world is VRCJson, which `#[udon_json]`
+//! behaviors use through their generated `ToJson`/`FromJson` methods. This
+//! module finds serde calls in behavior methods and tells apart the ones
+//! that can be routed to those methods.
+
+use syn::visit::Visit;
+use syn::{Block, Expr};
+
+use crate::multi_behavior::UdonBehaviourStruct;
+
+/// Crates whose calls are reported as serde usage
+const SERDE_CRATES: &[&str] = &["serde", "serde_json"];
+
+/// A serde call in a behavior method
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerdeUse {
+    /// Name of the method containing the call
+    pub method: String,
+    /// Path of the called function or macro, such as `serde_json::from_str`
+    pub call: String,
+    /// Whether the call is lowered to the behavior's VRCJson `ToJson` method
+    pub routed: bool,
+}
+
+/// serde calls in the methods of a behavior, in method name order
+pub fn serde_uses(udon_struct: &UdonBehaviourStruct) -> Vec<SerdeUse> {
+    let mut methods: Vec<_> = udon_struct.methods.iter().collect();
+    methods.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut uses = Vec::new();
+    for method in methods {
+        let Some(block) = method.body.as_deref().and_then(|body| syn::parse_str::<Block>(body).ok()) else {
+            continue;
+        };
+        let mut finder = SerdeFinder { calls: Vec::new() };
+        finder.visit_block(&block);
+        for (call, to_json) in finder.calls {
+            uses.push(SerdeUse {
+                method: method.name.clone(),
+                call,
+                routed: to_json && udon_struct.is_json_serializable(),
+            });
+        }
+    }
+    uses
+}
+
+/// Whether a call serializes the behavior itself, as in `serde_json::to_string(&self)`,
+/// which a `#[udon_json]` behavior answers with its generated `ToJson` method
+pub fn is_self_to_json_call(call: &syn::ExprCall) -> bool {
+    let Expr::Path(path) = &*call.func else {
+        return false;
+    };
+    let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let serializes = matches!(
+        segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice(),
+        ["serde_json", "to_string" | "to_string_pretty"]
+    );
+    serializes && call.args.len() == 1 && is_self_arg(&call.args[0])
+}
+
+/// Whether an argument is `self` or `&self`
+fn is_self_arg(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(path) => path.path.is_ident("self"),
+        Expr::Reference(reference) => is_self_arg(&reference.expr),
+        Expr::Paren(paren) => is_self_arg(&paren.expr),
+        _ => false,
+    }
+}
+
+/// Joined path if it starts with one of the serde crates
+fn serde_path(path: &syn::Path) -> Option<String> {
+    let first = path.segments.first()?.ident.to_string();
+    SERDE_CRATES.contains(&first.as_str()).then(|| {
+        path.segments.iter().map(|s| s.ident.to_string()).collect::<Vec<_>>().join("::")
+    })
+}
+
+/// Collects serde function calls and macros, noting which serialize `self`
+struct SerdeFinder {
+    calls: Vec<(String, bool)>,
+}
+
+impl<'ast> Visit<'ast> for SerdeFinder {
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let Expr::Path(path) = &*call.func {
+            if let Some(joined) = serde_path(&path.path) {
+                self.calls.push((joined, is_self_to_json_call(call)));
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if let Some(joined) = serde_path(&mac.path) {
+            self.calls.push((format!("{}!", joined), false));
+        }
+        syn::visit::visit_macro(self, mac);
+    }
+}
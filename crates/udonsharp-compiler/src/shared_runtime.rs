@@ -5,6 +5,7 @@
 //! containing common functionality.
 
 use crate::multi_behavior::{UdonBehaviourStruct, StructMethod, RustType, RustToCSharpTypeMapper};
+use crate::const_tables::ConstTable;
 use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 
@@ -166,6 +167,9 @@ pub struct SharedItems {
     pub constants: Vec<SharedDataType>,
     /// Static state variables
     pub static_variables: Vec<SharedDataType>,
+    /// Module constant tables, emitted as static readonly arrays
+    #[serde(default)]
+    pub tables: Vec<ConstTable>,
 }
 
 impl SharedItems {
@@ -176,13 +180,24 @@ impl SharedItems {
             types: Vec::new(),
             constants: Vec::new(),
             static_variables: Vec::new(),
+            tables: Vec::new(),
         }
     }
 
     /// Check if there are any shared items
     pub fn has_shared_items(&self) -> bool {
         !self.functions.is_empty() || !self.types.is_empty() || 
-        !self.constants.is_empty() || !self.static_variables.is_empty()
+        !self.constants.is_empty() || !self.static_variables.is_empty() ||
+        !self.tables.is_empty()
+    }
+
+    /// Add the constant tables the behaviors index, each declared once
+    pub fn add_const_tables(&mut self, behaviors: &[UdonBehaviourStruct]) {
+        for table in behaviors.iter().flat_map(|behavior| &behavior.const_tables) {
+            if !self.tables.iter().any(|existing| existing.name == table.name) {
+                self.tables.push(table.clone());
+            }
+        }
     }
 
     /// Get all function names
@@ -243,6 +258,7 @@ impl SharedFunctionDetector {
         excluded_functions.insert("on_pickup_use_up".to_string());
         excluded_functions.insert("on_station_entered".to_string());
         excluded_functions.insert("on_station_exited".to_string());
+        excluded_functions.insert("on_pre_serialization".to_string());
         excluded_functions.insert("on_post_deserialization".to_string());
 
         Self {
@@ -646,6 +662,8 @@ pub struct SharedRuntimeGenerator {
     class_template: String,
     /// Namespace for the generated class
     namespace: Option<String>,
+    /// Name of the GameObject the singleton is looked up by
+    object_name: String,
 }
 
 impl SharedRuntimeGenerator {
@@ -655,6 +673,7 @@ impl SharedRuntimeGenerator {
             type_mapper: RustToCSharpTypeMapper::new(),
             class_template: Self::default_class_template(),
             namespace: None,
+            object_name: crate::config::DEFAULT_SHARED_RUNTIME_NAME.to_string(),
         }
     }
 
@@ -663,6 +682,11 @@ impl SharedRuntimeGenerator {
         self.namespace = Some(namespace);
     }
 
+    /// Set the name of the GameObject the generated `Instance` looks up
+    pub fn set_object_name(&mut self, object_name: String) {
+        self.object_name = object_name;
+    }
+
     /// Generate SharedRuntime.cs class from shared items
     pub fn generate_shared_runtime(&self, shared_items: &SharedItems) -> SharedRuntimeResult<String> {
         if !shared_items.has_shared_items() {
@@ -689,6 +713,12 @@ impl SharedRuntimeGenerator {
             class_content.push('\n');
         }
 
+        // Generate lookup tables
+        if !shared_items.tables.is_empty() {
+            class_content.push_str(&self.generate_tables(&shared_items.tables));
+            class_content.push('\n');
+        }
+
         // Generate static variables
         if !shared_items.static_variables.is_empty() {
             class_content.push_str(&self.generate_static_variables(&shared_items.static_variables)?);
@@ -781,10 +811,14 @@ public class SharedRuntime : UdonSharpBehaviour
         {{
             if (_instance == null)
             {{
-                _instance = FindObjectOfType<SharedRuntime>();
+                GameObject runtimeObject = GameObject.Find("{object_name}");
+                if (runtimeObject != null)
+                {{
+                    _instance = runtimeObject.GetComponent<SharedRuntime>();
+                }}
                 if (_instance == null)
                 {{
-                    Debug.LogError("SharedRuntime instance not found in scene. Please add a SharedRuntime GameObject.");
+                    Debug.LogError("SharedRuntime instance not found in scene. Please add a GameObject named '{object_name}' with the SharedRuntime behaviour.");
                 }}
             }}
             return _instance;
@@ -816,7 +850,8 @@ public class SharedRuntime : UdonSharpBehaviour
     
     #endregion
     
-"#
+"#,
+            object_name = self.object_name,
         )
     }
 
@@ -849,6 +884,24 @@ public class SharedRuntime : UdonSharpBehaviour
         Ok(content)
     }
 
+    /// Generate lookup tables section
+    fn generate_tables(&self, tables: &[ConstTable]) -> String {
+        let mut content = String::new();
+        content.push_str("    #region Shared Lookup Tables\n\n");
+
+        for table in tables {
+            content.push_str(&format!(
+                "    /// <summary>\n    /// Constant table `{}`, initialized once\n    /// </summary>\n",
+                table.name
+            ));
+            content.push_str(&table.declaration());
+            content.push_str("\n\n");
+        }
+
+        content.push_str("    #endregion\n");
+        content
+    }
+
     /// Generate static variables section
     fn generate_static_variables(&self, static_vars: &[SharedDataType]) -> SharedRuntimeResult<String> {
         let mut content = String::new();
@@ -1329,6 +1382,7 @@ impl SharedDataTypeExtractor {
             RustType::GameObject => "GameObject".to_string(),
             RustType::Transform => "Transform".to_string(),
             RustType::VRCPlayerApi => "VRCPlayerApi".to_string(),
+            RustType::UdonRef(target) => format!("UdonRef<{}>", target),
             RustType::Option(inner) => format!("Option<{}>", self.get_type_name(inner)),
             RustType::Vec(inner) => format!("Vec<{}>", self.get_type_name(inner)),
             RustType::HashMap(key, value) => format!("HashMap<{}, {}>", self.get_type_name(key), self.get_type_name(value)),
@@ -1451,7 +1505,7 @@ impl SharedDataTypeExtractor {
             RustType::Color | RustType::Color32 | RustType::GameObject | RustType::Transform => false,
             
             // Skip VRChat types
-            RustType::VRCPlayerApi => false,
+            RustType::VRCPlayerApi | RustType::UdonRef(_) => false,
             
             // Include complex generic types
             RustType::Option(_) | RustType::Vec(_) | RustType::HashMap(_, _) | RustType::Array(_, _) => true,
@@ -1569,12 +1623,17 @@ impl SharedRuntimeExtractor {
 
     /// Extract all shared items from multiple behaviors
     pub fn extract_shared_items(&mut self, behaviors: &[UdonBehaviourStruct]) -> SharedRuntimeResult<SharedItems> {
+        let mut shared_items = SharedItems::new();
+
+        // Constant tables live on the SharedRuntime even when one behavior uses them
+        shared_items.add_const_tables(behaviors);
         if behaviors.len() < 2 {
+            if shared_items.has_shared_items() {
+                return Ok(shared_items);
+            }
             return Err(SharedRuntimeError::NoSharedFunctions);
         }
 
-        let mut shared_items = SharedItems::new();
-
         // Extract shared functions
         self.function_detector.analyze_function_usage(behaviors)?;
         match self.function_detector.extract_shared_functions() {
@@ -0,0 +1,282 @@
+//! Split suggestions for oversized behaviors
+//!
+//! Each behavior compiles to its own Udon program, and large programs load
+//! slowly and hit the VM's practical size limits. When the estimated
+//! instruction count of a behavior exceeds the limit, the advisor groups its
+//! methods into clusters that share fields, using the field-usage graph from
+//! `struct_analyzer`, and recommends moving the smaller clusters to helper
+//! behaviors.
+
+use crate::multi_behavior::UdonBehaviourStruct;
+use crate::struct_analyzer::FieldUsageGraph;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::visit::Visit;
+
+/// Default estimated instruction budget of a single behavior
+pub const DEFAULT_INSTRUCTION_LIMIT: usize = 10_000;
+
+/// Rough number of Udon instructions a single Rust expression lowers to
+const INSTRUCTIONS_PER_EXPRESSION: usize = 4;
+
+/// Methods that share fields and can move to a helper behavior together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodCluster {
+    pub methods: Vec<String>,
+    pub fields: Vec<String>,
+    pub estimated_instructions: usize,
+}
+
+/// Recommended grouping for an oversized behavior
+#[derive(Debug, Clone)]
+pub struct SplitSuggestion {
+    pub behavior: String,
+    pub estimated_instructions: usize,
+    pub instruction_limit: usize,
+    /// Clusters ordered largest first; the first one stays in the behavior
+    pub clusters: Vec<MethodCluster>,
+    /// Methods that touch no fields and can stay wherever they are called from
+    pub unclustered_methods: Vec<String>,
+}
+
+impl SplitSuggestion {
+    /// Format the suggestion as a recommended grouping
+    pub fn format_report(&self) -> String {
+        let mut lines = vec![format!(
+            "Behavior '{}' is estimated at {} Udon instructions (limit {}).",
+            self.behavior, self.estimated_instructions, self.instruction_limit
+        )];
+
+        if self.clusters.len() < 2 {
+            lines.push("No independent method groups were found; every method shares fields with the others.".to_string());
+            return lines.join("\n");
+        }
+
+        lines.push(format!("Suggested split into {} groups of methods that share fields:", self.clusters.len()));
+        for (index, cluster) in self.clusters.iter().enumerate() {
+            let destination = if index == 0 {
+                format!("keep in {}", self.behavior)
+            } else {
+                "move to a helper behavior".to_string()
+            };
+            lines.push(format!(
+                "  Group {} ({}, ~{} instructions)",
+                index + 1, destination, cluster.estimated_instructions
+            ));
+            lines.push(format!("    fields: {}", cluster.fields.join(", ")));
+            lines.push(format!("    methods: {}", cluster.methods.join(", ")));
+        }
+        if !self.unclustered_methods.is_empty() {
+            lines.push(format!("  Methods without field access: {}", self.unclustered_methods.join(", ")));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Estimates behavior size and suggests split points for oversized behaviors
+#[derive(Debug, Clone)]
+pub struct SplitAdvisor {
+    instruction_limit: usize,
+}
+
+impl SplitAdvisor {
+    /// Create an advisor with the given instruction limit
+    pub fn new(instruction_limit: usize) -> Self {
+        Self { instruction_limit }
+    }
+
+    /// Get the instruction limit
+    pub fn instruction_limit(&self) -> usize {
+        self.instruction_limit
+    }
+
+    /// Estimate the number of Udon instructions a behavior compiles to
+    pub fn estimate_instructions(&self, udon_struct: &UdonBehaviourStruct) -> usize {
+        method_estimates(udon_struct).values().sum::<usize>() + udon_struct.fields.len()
+    }
+
+    /// Suggest a split if the behavior exceeds the instruction limit
+    pub fn suggest(&self, udon_struct: &UdonBehaviourStruct) -> Option<SplitSuggestion> {
+        let estimated_instructions = self.estimate_instructions(udon_struct);
+        if estimated_instructions <= self.instruction_limit {
+            return None;
+        }
+
+        let graph = FieldUsageGraph::from_behavior(udon_struct);
+        let estimates = method_estimates(udon_struct);
+        let methods: Vec<&String> = graph.fields_by_method.keys().collect();
+        let index_of: BTreeMap<&String, usize> = methods.iter().enumerate().map(|(i, m)| (*m, i)).collect();
+
+        // Union methods that share a field or call each other
+        let mut parents: Vec<usize> = (0..methods.len()).collect();
+        let mut field_owner: BTreeMap<&String, usize> = BTreeMap::new();
+        for (index, method) in methods.iter().enumerate() {
+            for field in graph.fields_used_by(method) {
+                match field_owner.get(field) {
+                    Some(&owner) => union(&mut parents, owner, index),
+                    None => {
+                        field_owner.insert(field, index);
+                    }
+                }
+            }
+            for callee in graph.methods_called_by(method) {
+                if let Some(&callee_index) = index_of.get(callee) {
+                    union(&mut parents, index, callee_index);
+                }
+            }
+        }
+
+        let mut groups: BTreeMap<usize, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+        let mut unclustered_methods = Vec::new();
+        for (index, method) in methods.iter().enumerate() {
+            let root = find(&mut parents, index);
+            let fields: BTreeSet<String> = graph.fields_used_by(method).cloned().collect();
+            let calls_or_is_called = graph.methods_called_by(method).next().is_some()
+                || graph.calls_by_method.values().any(|callees| callees.contains(*method));
+            if fields.is_empty() && !calls_or_is_called {
+                unclustered_methods.push((*method).clone());
+                continue;
+            }
+            let group = groups.entry(root).or_default();
+            group.0.insert((*method).clone());
+            group.1.extend(fields);
+        }
+
+        let mut clusters: Vec<MethodCluster> = groups.into_values()
+            .map(|(methods, fields)| MethodCluster {
+                estimated_instructions: methods.iter().map(|m| estimates.get(m).copied().unwrap_or_default()).sum(),
+                methods: methods.into_iter().collect(),
+                fields: fields.into_iter().collect(),
+            })
+            .collect();
+        clusters.sort_by(|a, b| b.estimated_instructions.cmp(&a.estimated_instructions).then_with(|| a.methods.cmp(&b.methods)));
+
+        Some(SplitSuggestion {
+            behavior: udon_struct.name.clone(),
+            estimated_instructions,
+            instruction_limit: self.instruction_limit,
+            clusters,
+            unclustered_methods,
+        })
+    }
+}
+
+impl Default for SplitAdvisor {
+    fn default() -> Self {
+        Self::new(DEFAULT_INSTRUCTION_LIMIT)
+    }
+}
+
+/// Estimate the instructions of every method by counting its expressions
+fn method_estimates(udon_struct: &UdonBehaviourStruct) -> BTreeMap<String, usize> {
+    udon_struct.methods.iter()
+        .map(|method| {
+            let mut counter = ExpressionCounter::default();
+            if let Some(block) = method.body.as_deref().and_then(|body| syn::parse_str::<syn::Block>(body).ok()) {
+                counter.visit_block(&block);
+            }
+            // Every method has call and return overhead even when empty
+            (method.name.clone(), 1 + counter.expressions * INSTRUCTIONS_PER_EXPRESSION)
+        })
+        .collect()
+}
+
+fn find(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[index] = root;
+    root
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    if a != b {
+        parents[a.max(b)] = a.min(b);
+    }
+}
+
+/// Counts the expressions in a method body
+#[derive(Default)]
+struct ExpressionCounter {
+    expressions: usize,
+}
+
+impl<'ast> Visit<'ast> for ExpressionCounter {
+    fn visit_expr(&mut self, expr: &'ast syn::Expr) {
+        self.expressions += 1;
+        syn::visit::visit_expr(self, expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_analyzer::StructAnalyzer;
+
+    #[test]
+    fn test_independent_field_clusters_yield_two_groups() {
+        let items: Vec<syn::Item> = vec![
+            syn::parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Arena {
+                    score: i32,
+                    multiplier: i32,
+                    door_open: bool,
+                    door_timer: f32,
+                }
+            },
+            syn::parse_quote! {
+                impl Arena {
+                    pub fn add_score(&mut self, points: i32) {
+                        self.score += points * self.multiplier;
+                        self.log_score();
+                    }
+                    pub fn log_score(&self) {
+                        log_info(&format!("score {}", self.score));
+                    }
+                    pub fn reset_multiplier(&mut self) {
+                        self.multiplier = 1;
+                    }
+                    pub fn open_door(&mut self) {
+                        self.door_open = true;
+                        self.door_timer = 5.0;
+                    }
+                    pub fn tick_door(&mut self, delta: f32) {
+                        self.door_timer -= delta;
+                        if self.door_timer <= 0.0 {
+                            self.door_open = false;
+                        }
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl UdonBehaviour for Arena {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+        let mut analyzer = StructAnalyzer::new();
+        let behaviors = analyzer.analyze_module(&items).unwrap();
+        let arena = &behaviors[0];
+
+        assert!(SplitAdvisor::default().suggest(arena).is_none());
+
+        let suggestion = SplitAdvisor::new(10).suggest(arena).unwrap();
+        assert_eq!(suggestion.clusters.len(), 2);
+
+        let score_group = suggestion.clusters.iter().find(|c| c.fields.contains(&"score".to_string())).unwrap();
+        assert_eq!(score_group.fields, vec!["multiplier", "score"]);
+        assert_eq!(score_group.methods, vec!["add_score", "log_score", "reset_multiplier"]);
+
+        let door_group = suggestion.clusters.iter().find(|c| c.fields.contains(&"door_open".to_string())).unwrap();
+        assert_eq!(door_group.fields, vec!["door_open", "door_timer"]);
+        assert_eq!(door_group.methods, vec!["open_door", "tick_door"]);
+        assert_eq!(suggestion.unclustered_methods, vec!["start"]);
+
+        let report = suggestion.format_report();
+        assert!(report.contains("Suggested split into 2 groups"));
+        assert!(report.contains("move to a helper behavior"));
+    }
+}
@@ -5,10 +5,10 @@
 //! with current build tools and workflows.
 
 use crate::{
-    config::{UdonSharpConfig, MultiBehaviorSettings},
+    config::{ConversionTarget, UdonSharpConfig},
     pipeline::{CompilationPipeline, CompilationResult, MultiBehaviorMetadata},
     multi_behavior::{UdonBehaviourStruct, RustToCSharpTypeMapper, AttributeMapper},
-    struct_analyzer::StructAnalyzer,
+    struct_analyzer::{find_main_behavior, StructAnalyzer},
     trait_validator::TraitValidator,
     behavior_dependency_analyzer::BehaviorDependencyAnalyzer,
     code_generator::CodeGenerator,
@@ -17,10 +17,21 @@ use crate::{
     error_detection::CompilationErrorDetector,
     error_reporting::ErrorReporter,
     runtime_validation::RuntimeValidator,
+    asset_meta::{write_asset_meta, write_asset_meta_with_execution_order, AssetGuidSeed},
+    prefab_generator::UnityPrefabGenerator,
+    helper_inlining::inline_small_helpers,
+    debug_overlay::{debug_overlay_sections, generate_debug_overlay, DEBUG_OVERLAY_CLASS},
+    field_schema::{behavior_field_schema, field_schema_file_name},
+    behavior_manifest::{BehaviorManifest, BEHAVIOR_MANIFEST_FILE},
+    event_ids::EventIdTable,
+    udon_assembly_lowering::lower_to_udon_assembly,
 };
 use udonsharp_core::{UdonSharpResult, error::CompilationContext};
 use std::path::Path;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// File name of the prefab holding every behavior as a child GameObject
+pub const HIERARCHY_PREFAB_FILE: &str = "MultiBehaviorWorld.prefab";
 
 /// Standard multi-behavior pattern integration with the compilation pipeline
 pub struct StandardMultiBehaviorIntegration {
@@ -42,22 +53,42 @@ impl StandardMultiBehaviorIntegration {
     pub fn new(config: UdonSharpConfig, context: CompilationContext) -> Self {
         let type_mapper = RustToCSharpTypeMapper::new();
         let attribute_mapper = AttributeMapper::new();
+        let mut code_generator = CodeGenerator::new();
+        code_generator.set_strip_debug_logs(config.strip_debug_logs);
+        code_generator.set_assertion_mode(config.assertions);
+        code_generator.set_safe_refs(config.safe_refs || config.generate_debug_info);
+        code_generator.set_method_exposure(config.method_exposure);
+        code_generator.set_namespace(config.namespace.clone());
+        let mut shared_runtime_generator = SharedRuntimeGenerator::new();
+        shared_runtime_generator.set_object_name(config.shared_runtime_name.clone());
+        if let Some(namespace) = &config.namespace {
+            shared_runtime_generator.set_namespace(namespace.clone());
+        }
+        
+        let mut struct_analyzer = StructAnalyzer::new();
+        struct_analyzer.set_lint_levels(config.lints.clone());
+        struct_analyzer.set_public_variable_limit(config.public_variable_limit);
         
         Self {
             config: config.clone(),
             context,
-            struct_analyzer: StructAnalyzer::new(),
+            struct_analyzer,
             trait_validator: TraitValidator::new(),
             dependency_analyzer: BehaviorDependencyAnalyzer::new(),
-            code_generator: CodeGenerator::new(),
+            code_generator,
             communication_generator: InterBehaviorCommunicationCoordinator::new(),
-            shared_runtime_generator: SharedRuntimeGenerator::new(),
+            shared_runtime_generator,
             error_detector: CompilationErrorDetector::new(),
             error_reporter: ErrorReporter::new(),
             runtime_validator: RuntimeValidator::new(),
         }
     }
 
+    /// Seed the network event IDs with the table of a previous build
+    pub fn set_event_ids(&mut self, event_ids: EventIdTable) {
+        self.code_generator.set_event_ids(event_ids);
+    }
+
     /// Check if the project should use standard multi-behavior pattern
     pub fn should_use_multi_behavior(&mut self, rust_source: &str) -> UdonSharpResult<bool> {
         if !self.config.multi_behavior.enabled {
@@ -74,6 +105,17 @@ impl StandardMultiBehaviorIntegration {
 
         self.context.info(format!("Found {} UdonBehaviour structs", behavior_count));
 
+        // A #[udon_main] behavior is compiled from source however many behaviors there are
+        if let Some(main) = find_main_behavior(&analysis_result)? {
+            self.context.info(format!("Using '{}' (#[udon_main]) as the main behavior", main.name));
+            return Ok(true);
+        }
+
+        // Udon assembly is only lowered from source, so even a single behavior is
+        if self.config.target == ConversionTarget::UdonAssembly && behavior_count > 0 {
+            return Ok(true);
+        }
+
         Ok(behavior_count >= self.config.multi_behavior.min_behaviors_threshold)
     }
 
@@ -83,6 +125,8 @@ impl StandardMultiBehaviorIntegration {
 
         // Step 1: Analyze structs
         let structs = self.analyze_structs(rust_source)?;
+        let (mut structs, excluded_editor_only) = self.select_build_behaviors(structs)?;
+        self.inline_helpers(&mut structs);
         
         // Step 2: Validate trait implementations
         let trait_validation = self.validate_traits(&structs)?;
@@ -93,25 +137,33 @@ impl StandardMultiBehaviorIntegration {
         // Step 4: Detect compilation errors early
         self.detect_compilation_errors(&structs, &trait_validation, &dependency_analysis)?;
         
-        // Step 5: Generate code for each behavior
-        let behavior_files = self.generate_behavior_files(&structs)?;
+        // Step 5: Narrow the output to the behaviors selected on the command line
+        let selected = self.select_requested_behaviors(&structs, &dependency_analysis)?;
+        
+        // Step 6: Generate code for each behavior
+        let mut behavior_files = match self.config.target {
+            ConversionTarget::UdonSharp => self.generate_behavior_files(&structs, &selected)?,
+            ConversionTarget::UdonAssembly => self.generate_udon_assembly_files(&selected)?,
+        };
+        behavior_files.extend(self.generate_debug_overlay(&selected));
         
-        // Step 6: Generate inter-behavior communication
-        let communication_code = self.generate_communication_code(&structs, &dependency_analysis)?;
+        // Step 7: Generate inter-behavior communication
+        let communication_code = self.generate_communication_code(&selected, &dependency_analysis)?;
         
-        // Step 7: Generate SharedRuntime if needed
-        let shared_runtime = self.generate_shared_runtime(&structs)?;
+        // Step 8: Generate SharedRuntime if needed
+        let shared_runtime = self.generate_shared_runtime(&selected)?;
         
-        // Step 8: Validate generated code
+        // Step 9: Validate generated code
         self.validate_generated_code(&behavior_files, &shared_runtime)?;
         
-        // Step 9: Create compilation result
+        // Step 10: Create compilation result, with the prefab hierarchy and field schemas if requested
         let result = self.create_compilation_result(
-            &structs,
+            &selected,
             behavior_files,
             communication_code,
             shared_runtime,
             &dependency_analysis,
+            excluded_editor_only,
         )?;
 
         self.context.info("Standard multi-behavior compilation completed successfully");
@@ -127,6 +179,10 @@ impl StandardMultiBehaviorIntegration {
         let items = &syntax_tree.items;
         
         let analysis_result = self.struct_analyzer.analyze_module(items)?;
+        self.error_detector.set_module_imports(items);
+        for warning in self.struct_analyzer.get_warnings() {
+            self.context.warning(warning.clone());
+        }
         
         if analysis_result.is_empty() {
             return Err(udonsharp_core::UdonSharpError::compilation(
@@ -138,6 +194,104 @@ impl StandardMultiBehaviorIntegration {
         Ok(analysis_result)
     }
 
+    /// Drop editor-only behaviors from release builds.
+    ///
+    /// Debug builds (`generate_debug_info`) keep every behavior. Returns the
+    /// behaviors to build and the names of the excluded editor-only behaviors.
+    fn select_build_behaviors(
+        &self,
+        structs: Vec<UdonBehaviourStruct>,
+    ) -> UdonSharpResult<(Vec<UdonBehaviourStruct>, Vec<String>)> {
+        if self.config.generate_debug_info {
+            return Ok((structs, Vec::new()));
+        }
+
+        let (editor_only, kept): (Vec<_>, Vec<_>) = structs.into_iter()
+            .partition(|s| s.is_editor_only());
+        let excluded: Vec<String> = editor_only.into_iter().map(|s| s.name).collect();
+
+        for behavior in &kept {
+            if let Some(dependency) = behavior.dependencies.iter().find(|d| excluded.contains(d)) {
+                return Err(udonsharp_core::UdonSharpError::compilation(format!(
+                    "Behavior '{}' depends on editor-only behavior '{}', which is excluded from release builds",
+                    behavior.name, dependency
+                )));
+            }
+        }
+
+        if kept.is_empty() {
+            return Err(udonsharp_core::UdonSharpError::compilation(
+                "All UdonBehaviour structs are editor-only; nothing to build in a release build"
+            ));
+        }
+
+        for name in &excluded {
+            self.context.info(format!("Excluding editor-only behavior '{}' from release build", name));
+        }
+
+        Ok((kept, excluded))
+    }
+
+    /// Pick the behaviors named in `selected_behaviors`, or all of them when none are named.
+    ///
+    /// Every selected name must exist, and so must every behavior a selected
+    /// behavior depends on, even though the dependencies themselves are not emitted.
+    fn select_requested_behaviors(
+        &self,
+        structs: &[UdonBehaviourStruct],
+        dependency_analysis: &DependencyAnalysisResult,
+    ) -> UdonSharpResult<Vec<UdonBehaviourStruct>> {
+        if self.config.selected_behaviors.is_empty() {
+            return Ok(structs.to_vec());
+        }
+
+        let available: Vec<&str> = structs.iter().map(|s| s.name.as_str()).collect();
+        if let Some(unknown) = self.config.selected_behaviors.iter().find(|name| !available.contains(&name.as_str())) {
+            let mut names = available.clone();
+            names.sort_unstable();
+            return Err(udonsharp_core::UdonSharpError::compilation(format!(
+                "Unknown behavior '{}'; available behaviors: {}",
+                unknown,
+                names.join(", ")
+            )));
+        }
+
+        let selected: Vec<UdonBehaviourStruct> = structs.iter()
+            .filter(|s| self.config.selected_behaviors.contains(&s.name))
+            .cloned()
+            .collect();
+
+        for behavior in &selected {
+            let graph_dependencies = dependency_analysis.dependency_graph.get(&behavior.name).into_iter().flatten();
+            for dependency in behavior.dependencies.iter().chain(graph_dependencies) {
+                if !available.contains(&dependency.as_str()) {
+                    return Err(udonsharp_core::UdonSharpError::compilation(format!(
+                        "Behavior '{}' depends on '{}', which is not defined in this crate",
+                        behavior.name, dependency
+                    )));
+                }
+            }
+        }
+
+        for name in available.iter().filter(|name| !selected.iter().any(|s| s.name == **name)) {
+            self.context.info(format!("Skipping behavior '{}' (not selected)", name));
+        }
+
+        Ok(selected)
+    }
+
+    /// Inline small single-caller helpers when `inline_helper_max_tokens` is set
+    fn inline_helpers(&self, structs: &mut [UdonBehaviourStruct]) {
+        let Some(max_tokens) = self.config.inline_helper_max_tokens else {
+            return;
+        };
+        for udon_struct in structs {
+            for helper in inline_small_helpers(udon_struct, max_tokens) {
+                self.context.info(format!("Inlined helper '{}::{}' into its only caller", udon_struct.name, helper));
+            }
+        }
+    }
+
     /// Validate UdonBehaviour trait implementations
     fn validate_traits(&self, structs: &[UdonBehaviourStruct]) -> UdonSharpResult<TraitValidationResult> {
         self.context.info("Validating UdonBehaviour trait implementations...");
@@ -243,6 +397,11 @@ impl StandardMultiBehaviorIntegration {
         errors.extend(self.error_detector.detect_missing_trait_implementations(structs));
         // Add other error checks as needed
 
+        for oversized in self.error_detector.check_behavior_size(structs) {
+            let advice = oversized.suggestion.unwrap_or_default();
+            self.context.warning(format!("{}\n{}", oversized.message, advice));
+        }
+
         if !errors.is_empty() {
             let error_messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
             return Err(udonsharp_core::UdonSharpError::compilation(
@@ -255,12 +414,20 @@ impl StandardMultiBehaviorIntegration {
     }
 
     /// Generate C# files for each behavior
-    fn generate_behavior_files(&mut self, structs: &[UdonBehaviourStruct]) -> UdonSharpResult<HashMap<String, GeneratedBehaviorFile>> {
+    ///
+    /// Every analyzed behavior is registered with the code generator so calls into
+    /// unselected behaviors still lower, but only `selected` ones produce files.
+    fn generate_behavior_files(
+        &mut self,
+        structs: &[UdonBehaviourStruct],
+        selected: &[UdonBehaviourStruct],
+    ) -> UdonSharpResult<HashMap<String, GeneratedBehaviorFile>> {
         self.context.info("Generating C# behavior files...");
         
         let mut behavior_files = HashMap::new();
+        self.code_generator.register_behaviors(structs);
         
-        for behavior_struct in structs {
+        for behavior_struct in selected {
             self.context.info(format!("Generating code for behavior: {}", behavior_struct.name));
             
             let generated_code = self.code_generator.generate_behavior_class(behavior_struct)?;
@@ -268,20 +435,120 @@ impl StandardMultiBehaviorIntegration {
             let file = GeneratedBehaviorFile {
                 behavior_name: behavior_struct.name.clone(),
                 class_name: behavior_struct.name.clone(),
+                file_name: format!("{}.cs", behavior_struct.name),
                 file_content: generated_code.source_code.clone(),
                 using_statements: generated_code.using_statements.clone(),
                 namespace: self.config.namespace.clone(),
                 has_networking: behavior_struct.has_networking(),
                 dependencies: behavior_struct.dependencies.clone(),
+                execution_order: behavior_struct.execution_order(),
             };
             
             behavior_files.insert(behavior_struct.name.clone(), file);
         }
 
+        for warning in self.code_generator.get_warnings() {
+            self.context.warning(warning);
+        }
+
         self.context.info(format!("Generated {} behavior files", behavior_files.len()));
         Ok(behavior_files)
     }
 
+    /// Lower each selected behavior to an Udon assembly file, failing on the
+    /// first construct that requires UdonSharp
+    fn generate_udon_assembly_files(
+        &self,
+        selected: &[UdonBehaviourStruct],
+    ) -> UdonSharpResult<HashMap<String, GeneratedBehaviorFile>> {
+        self.context.info("Lowering behaviors to Udon assembly...");
+
+        let mut behavior_files = HashMap::new();
+        for behavior_struct in selected {
+            let assembly = lower_to_udon_assembly(behavior_struct)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(e.to_string()))?;
+
+            let file = GeneratedBehaviorFile {
+                behavior_name: behavior_struct.name.clone(),
+                class_name: behavior_struct.name.clone(),
+                file_name: format!("{}.{}", behavior_struct.name, ConversionTarget::UdonAssembly.file_extension()),
+                file_content: assembly,
+                using_statements: Vec::new(),
+                namespace: None,
+                has_networking: behavior_struct.has_networking(),
+                dependencies: behavior_struct.dependencies.clone(),
+                execution_order: behavior_struct.execution_order(),
+            };
+            behavior_files.insert(behavior_struct.name.clone(), file);
+        }
+
+        self.context.info(format!("Lowered {} behaviors to Udon assembly", behavior_files.len()));
+        Ok(behavior_files)
+    }
+
+    /// Generate the debug overlay behavior when `emit_debug_overlay` is set,
+    /// keyed like the other behavior files
+    fn generate_debug_overlay(&self, structs: &[UdonBehaviourStruct]) -> Option<(String, GeneratedBehaviorFile)> {
+        if !self.config.emit_debug_overlay {
+            return None;
+        }
+        if self.config.target == ConversionTarget::UdonAssembly {
+            self.context.warning("--emit-debug-overlay needs UdonSharp; no overlay generated for the Udon assembly target");
+            return None;
+        }
+        let Some(source) = generate_debug_overlay(structs, self.config.namespace.as_deref()) else {
+            self.context.warning("--emit-debug-overlay is set, but no field is marked #[udon_debug]; no overlay generated");
+            return None;
+        };
+
+        self.context.info(format!("Generated {} for the fields marked #[udon_debug]", DEBUG_OVERLAY_CLASS));
+        let file = GeneratedBehaviorFile {
+            behavior_name: DEBUG_OVERLAY_CLASS.to_string(),
+            class_name: DEBUG_OVERLAY_CLASS.to_string(),
+            file_name: format!("{}.cs", DEBUG_OVERLAY_CLASS),
+            file_content: source,
+            using_statements: vec![
+                "using UdonSharp;".to_string(),
+                "using UnityEngine;".to_string(),
+                "using UnityEngine.UI;".to_string(),
+                "using VRC.SDKBase;".to_string(),
+                "using VRC.Udon;".to_string(),
+            ],
+            namespace: self.config.namespace.clone(),
+            has_networking: false,
+            dependencies: debug_overlay_sections(structs).into_iter().map(|s| s.behavior).collect(),
+            execution_order: None,
+        };
+        Some((DEBUG_OVERLAY_CLASS.to_string(), file))
+    }
+
+    /// Generate the JSON Schema of each behavior's `#[udon_public]` fields when
+    /// `emit_field_schemas` is set, keyed by schema file name
+    fn generate_field_schemas(
+        &self,
+        structs: &[UdonBehaviourStruct],
+        behavior_files: &HashMap<String, GeneratedBehaviorFile>,
+    ) -> UdonSharpResult<BTreeMap<String, String>> {
+        let mut schemas = BTreeMap::new();
+        if !self.config.emit_field_schemas {
+            return Ok(schemas);
+        }
+
+        for behavior in structs {
+            let Some(file) = behavior_files.get(&behavior.name) else {
+                continue;
+            };
+            let schema = serde_json::to_string_pretty(&behavior_field_schema(behavior))
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(format!(
+                    "Failed to serialize the field schema of '{}': {}", behavior.name, e
+                )))?;
+            schemas.insert(field_schema_file_name(&file.class_name), schema);
+        }
+
+        self.context.info(format!("Exported {} field schemas", schemas.len()));
+        Ok(schemas)
+    }
+
     /// Generate inter-behavior communication code
     fn generate_communication_code(
         &self,
@@ -309,7 +576,12 @@ impl StandardMultiBehaviorIntegration {
         self.context.info("Generating SharedRuntime class...");
         
         // TODO: Implement shared items extraction from structs
-        let shared_items = crate::shared_runtime::SharedItems::new();
+        let mut shared_items = crate::shared_runtime::SharedItems::new();
+        shared_items.add_const_tables(structs);
+        if !shared_items.has_shared_items() {
+            self.context.info("No shared content found, skipping SharedRuntime generation");
+            return Ok(None);
+        }
         
         let shared_runtime_code = self.shared_runtime_generator.generate_shared_runtime(&shared_items)?;
         
@@ -361,6 +633,30 @@ impl StandardMultiBehaviorIntegration {
         Ok(())
     }
 
+    /// Generate one prefab with every behavior as a child GameObject, if enabled,
+    /// plus the SharedRuntime object when one was generated
+    fn generate_hierarchy_prefab(&self, structs: &[UdonBehaviourStruct], has_shared_runtime: bool) -> UdonSharpResult<Option<String>> {
+        let settings = &self.config.multi_behavior;
+        if !settings.generate_prefabs || !settings.prefab_settings.generate_hierarchy_prefab {
+            return Ok(None);
+        }
+
+        let mut generator = UnityPrefabGenerator::new(settings.prefab_settings.clone(), settings.initialization_order.clone());
+        if has_shared_runtime {
+            generator = generator.with_shared_runtime_object(self.config.shared_runtime_name.clone());
+        }
+        let prefab = generator.generate_hierarchy_prefab(structs, self.config.namespace.as_deref())
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                format!("Failed to generate hierarchy prefab: {}", e)
+            ))?;
+
+        for unresolved in &prefab.unresolved_references {
+            self.context.warning(format!("Hierarchy prefab: {} and was left unwired", unresolved));
+        }
+
+        Ok(Some(prefab.content))
+    }
+
     /// Create the final compilation result
     fn create_compilation_result(
         &self,
@@ -369,13 +665,16 @@ impl StandardMultiBehaviorIntegration {
         communication_code: CommunicationCodeResult,
         shared_runtime: Option<SharedRuntimeFile>,
         dependency_analysis: &DependencyAnalysisResult,
+        excluded_editor_only: Vec<String>,
     ) -> UdonSharpResult<StandardMultiBehaviorCompilationResult> {
+        let hierarchy_prefab = self.generate_hierarchy_prefab(structs, shared_runtime.is_some())?;
+        let field_schemas = self.generate_field_schemas(structs, &behavior_files)?;
         let mut output_files = Vec::new();
         let mut behavior_file_paths = HashMap::new();
         
         // Add behavior files
         for (behavior_name, file) in &behavior_files {
-            let file_path = format!("{}.cs", file.class_name);
+            let file_path = file.file_name.clone();
             output_files.push(file_path.clone());
             behavior_file_paths.insert(behavior_name.clone(), file_path);
         }
@@ -389,6 +688,13 @@ impl StandardMultiBehaviorIntegration {
             None
         };
         
+        if hierarchy_prefab.is_some() {
+            output_files.push(HIERARCHY_PREFAB_FILE.to_string());
+        }
+        
+        output_files.extend(field_schemas.keys().cloned());
+        output_files.push(BEHAVIOR_MANIFEST_FILE.to_string());
+        
         let metadata = StandardMultiBehaviorMetadata {
             total_behaviors: behavior_files.len(),
             total_files: output_files.len(),
@@ -399,6 +705,8 @@ impl StandardMultiBehaviorIntegration {
             has_networking: behavior_files.values().any(|f| f.has_networking),
             dependency_count: dependency_analysis.dependency_graph.len(),
             circular_dependencies_detected: !dependency_analysis.circular_dependencies.is_empty(),
+            excluded_editor_only,
+            main_behavior: find_main_behavior(structs)?.map(|main| main.name.clone()),
         };
         
         Ok(StandardMultiBehaviorCompilationResult {
@@ -407,9 +715,15 @@ impl StandardMultiBehaviorIntegration {
             behavior_files,
             shared_runtime_file: shared_runtime_file_path,
             shared_runtime,
+            hierarchy_prefab,
+            field_schemas,
+            behavior_manifest: BehaviorManifest::with_extension(structs, self.config.target.file_extension(), &self.config.package_name),
+            package_name: self.config.package_name.clone(),
+            event_ids: self.code_generator.event_ids().clone(),
             communication_code,
             metadata,
             diagnostics: self.context.reporter.diagnostics().to_vec(),
+            behaviors: structs.to_vec(),
         })
     }
 
@@ -449,11 +763,15 @@ pub struct DependencyAnalysisResult {
 pub struct GeneratedBehaviorFile {
     pub behavior_name: String,
     pub class_name: String,
+    /// Name of the written file, with the extension of the conversion target
+    pub file_name: String,
     pub file_content: String,
     pub using_statements: Vec<String>,
     pub namespace: Option<String>,
     pub has_networking: bool,
     pub dependencies: Vec<String>,
+    /// Script execution order recorded in the `.meta` file, if set
+    pub execution_order: Option<i32>,
 }
 
 /// Generated SharedRuntime file information
@@ -486,6 +804,10 @@ pub struct StandardMultiBehaviorMetadata {
     pub has_networking: bool,
     pub dependency_count: usize,
     pub circular_dependencies_detected: bool,
+    /// Editor-only behaviors left out of this (release) build
+    pub excluded_editor_only: Vec<String>,
+    /// Behavior marked `#[udon_main]` as the world's primary behavior, if any
+    pub main_behavior: Option<String>,
 }
 
 /// Complete result of standard multi-behavior compilation
@@ -496,9 +818,21 @@ pub struct StandardMultiBehaviorCompilationResult {
     pub behavior_files: HashMap<String, GeneratedBehaviorFile>,
     pub shared_runtime_file: Option<String>,
     pub shared_runtime: Option<SharedRuntimeFile>,
+    /// Prefab holding every behavior as a child GameObject, if enabled
+    pub hierarchy_prefab: Option<String>,
+    /// JSON Schemas of the behaviors' public fields by file name, if enabled
+    pub field_schemas: BTreeMap<String, String>,
+    /// Manifest of the generated behaviors for Unity-side tooling
+    pub behavior_manifest: BehaviorManifest,
+    /// Package the files belong to, which seeds the GUIDs of their `.meta` files
+    pub package_name: String,
+    /// Network event IDs of this build, to persist for the next one
+    pub event_ids: EventIdTable,
     pub communication_code: CommunicationCodeResult,
     pub metadata: StandardMultiBehaviorMetadata,
     pub diagnostics: Vec<udonsharp_core::Diagnostic>,
+    /// Analyzed behaviors the files were generated from
+    pub behaviors: Vec<UdonBehaviourStruct>,
 }
 
 impl StandardMultiBehaviorCompilationResult {
@@ -511,9 +845,13 @@ impl StandardMultiBehaviorCompilationResult {
             inter_behavior_calls: self.metadata.inter_behavior_calls,
         };
         
+        let prefab_files: HashMap<String, String> = self.hierarchy_prefab.iter()
+            .map(|_| (HIERARCHY_PREFAB_FILE.to_string(), HIERARCHY_PREFAB_FILE.to_string()))
+            .collect();
+        
         let behavior_file_paths: HashMap<String, String> = self.behavior_files
             .iter()
-            .map(|(name, file)| (name.clone(), format!("{}.cs", file.class_name)))
+            .map(|(name, file)| (name.clone(), file.file_name.clone()))
             .collect();
         
         CompilationResult {
@@ -523,10 +861,11 @@ impl StandardMultiBehaviorCompilationResult {
             behavior_files: behavior_file_paths,
             shared_runtime_file: self.shared_runtime_file,
             multi_behavior_metadata: Some(multi_behavior_metadata),
-            prefab_files: HashMap::new(),
+            prefab_files,
             prefab_metadata: None,
             coordinator_file: None,
             coordinator_metadata: None,
+            behaviors: self.behaviors,
         }
     }
 
@@ -543,9 +882,12 @@ impl StandardMultiBehaviorCompilationResult {
                 format!("Failed to create output directory: {}", e)
             ))?;
         
+        let seed = AssetGuidSeed::new(&self.package_name, output_path);
+        
         // Write behavior files
+        let mut behavior_manifest = self.behavior_manifest.clone();
         for (_, file) in &self.behavior_files {
-            let file_path = output_path.join(format!("{}.cs", file.class_name));
+            let file_path = output_path.join(&file.file_name);
             let mut f = fs::File::create(&file_path)
                 .map_err(|e| udonsharp_core::UdonSharpError::compilation(
                     format!("Failed to create file {:?}: {}", file_path, e)
@@ -555,6 +897,12 @@ impl StandardMultiBehaviorCompilationResult {
                 .map_err(|e| udonsharp_core::UdonSharpError::compilation(
                     format!("Failed to write file {:?}: {}", file_path, e)
                 ))?;
+            
+            let guid = write_asset_meta_with_execution_order(&file_path, &seed, file.execution_order.unwrap_or(0))
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                    format!("Failed to write meta file for {:?}: {}", file_path, e)
+                ))?;
+            behavior_manifest.set_guid(&file.file_name, &guid);
         }
         
         // Write SharedRuntime file if present
@@ -569,8 +917,57 @@ impl StandardMultiBehaviorCompilationResult {
                 .map_err(|e| udonsharp_core::UdonSharpError::compilation(
                     format!("Failed to write SharedRuntime file: {}", e)
                 ))?;
+            
+            write_asset_meta(&file_path, &seed)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                    format!("Failed to write SharedRuntime meta file: {}", e)
+                ))?;
+        }
+        
+        // Write the hierarchy prefab if present
+        if let Some(prefab) = &self.hierarchy_prefab {
+            let file_path = output_path.join(HIERARCHY_PREFAB_FILE);
+            fs::write(&file_path, prefab)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                    format!("Failed to write hierarchy prefab: {}", e)
+                ))?;
+            
+            write_asset_meta(&file_path, &seed)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                    format!("Failed to write hierarchy prefab meta file: {}", e)
+                ))?;
+        }
+        
+        // Write the field schemas if present
+        for (file_name, schema) in &self.field_schemas {
+            let file_path = output_path.join(file_name);
+            fs::write(&file_path, schema)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                    format!("Failed to write field schema {:?}: {}", file_path, e)
+                ))?;
+            
+            write_asset_meta(&file_path, &seed)
+                .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                    format!("Failed to write meta file for {:?}: {}", file_path, e)
+                ))?;
         }
         
+        // Write the behavior manifest with the GUIDs the scripts ended up with
+        let file_path = output_path.join(BEHAVIOR_MANIFEST_FILE);
+        let manifest = behavior_manifest.to_json()
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                format!("Failed to serialize the behavior manifest: {}", e)
+            ))?;
+        fs::write(&file_path, manifest)
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                format!("Failed to write behavior manifest: {}", e)
+            ))?;
+        
+        write_asset_meta(&file_path, &seed)
+            .map_err(|e| udonsharp_core::UdonSharpError::compilation(
+                format!("Failed to write behavior manifest meta file: {}", e)
+            ))?;
+        
         Ok(())
     }
 
@@ -592,6 +989,15 @@ impl StandardMultiBehaviorCompilationResult {
         if self.metadata.circular_dependencies_detected {
             report.push_str("⚠️  Circular dependencies detected and resolved\n");
         }
+
+        if let Some(main) = &self.metadata.main_behavior {
+            report.push_str(&format!("Main Behavior: {}\n", main));
+        }
+
+        if !self.metadata.excluded_editor_only.is_empty() {
+            report.push_str(&format!("Excluded Editor-Only Behaviors: {}\n",
+                self.metadata.excluded_editor_only.join(", ")));
+        }
         
         report.push_str("\n--- Generated Files ---\n");
         for file_path in &self.output_files {
@@ -634,8 +1040,7 @@ impl StandardMultiBehaviorPipelineExt for CompilationPipeline {
     ) -> UdonSharpResult<CompilationResult> {
         // Create integration instance
         let mut integration = StandardMultiBehaviorIntegration::new(
-            // We need to get the config from the pipeline - this would need to be exposed
-            UdonSharpConfig::default(), // Placeholder - would need actual config
+            self.config().clone(),
             self.context().clone(),
         );
         
@@ -643,7 +1048,8 @@ impl StandardMultiBehaviorPipelineExt for CompilationPipeline {
         if integration.should_use_multi_behavior(rust_source)? {
             integration.context().info("Using standard multi-behavior compilation pattern");
             
-            // Compile using standard multi-behavior pattern
+            // Compile using standard multi-behavior pattern, keeping the event IDs of the previous build
+            integration.set_event_ids(self.load_event_ids());
             let result = integration.compile_multi_behavior(rust_source).await?;
             
             // Write files to disk
@@ -652,6 +1058,7 @@ impl StandardMultiBehaviorPipelineExt for CompilationPipeline {
             } else {
                 result.write_files_to_disk(".")?;
             }
+            self.save_event_ids(&result.event_ids)?;
             
             // Convert to standard CompilationResult
             Ok(result.to_compilation_result())
@@ -714,11 +1121,13 @@ mod tests {
         behavior_files.insert("TestBehavior".to_string(), GeneratedBehaviorFile {
             behavior_name: "TestBehavior".to_string(),
             class_name: "TestBehavior".to_string(),
+            file_name: "TestBehavior.cs".to_string(),
             file_content: "// Generated code".to_string(),
             using_statements: vec!["using UnityEngine;".to_string()],
             namespace: None,
             has_networking: false,
             dependencies: vec![],
+            execution_order: None,
         });
         
         let result = StandardMultiBehaviorCompilationResult {
@@ -727,6 +1136,11 @@ mod tests {
             behavior_files,
             shared_runtime_file: None,
             shared_runtime: None,
+            hierarchy_prefab: None,
+            field_schemas: BTreeMap::new(),
+            behavior_manifest: BehaviorManifest::new(&[], "world"),
+            package_name: "world".to_string(),
+            event_ids: EventIdTable::new(),
             communication_code: CommunicationCodeResult {
                 behavior_communications: HashMap::new(),
                 total_communication_calls: 0,
@@ -741,12 +1155,599 @@ mod tests {
                 has_networking: false,
                 dependency_count: 0,
                 circular_dependencies_detected: false,
+                excluded_editor_only: vec![],
+                main_behavior: None,
             },
             diagnostics: vec![],
+            behaviors: vec![],
         };
         
         let compilation_result = result.to_compilation_result();
         assert!(compilation_result.success);
         assert_eq!(compilation_result.output_files.len(), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_written_scripts_have_meta_files() {
+        let mut behavior_files = HashMap::new();
+        for name in ["GameManager", "UIController"] {
+            behavior_files.insert(name.to_string(), GeneratedBehaviorFile {
+                behavior_name: name.to_string(),
+                class_name: name.to_string(),
+                file_name: format!("{}.cs", name),
+                file_content: format!("public class {} {{}}", name),
+                using_statements: vec![],
+                namespace: None,
+                has_networking: false,
+                dependencies: vec![],
+                execution_order: None,
+            });
+        }
+
+        let result = StandardMultiBehaviorCompilationResult {
+            success: true,
+            output_files: vec!["GameManager.cs".to_string(), "UIController.cs".to_string()],
+            behavior_files,
+            shared_runtime_file: None,
+            shared_runtime: None,
+            hierarchy_prefab: None,
+            field_schemas: BTreeMap::new(),
+            behavior_manifest: BehaviorManifest::new(&[], "world"),
+            package_name: "world".to_string(),
+            event_ids: EventIdTable::new(),
+            communication_code: CommunicationCodeResult {
+                behavior_communications: HashMap::new(),
+                total_communication_calls: 0,
+                gameobject_references: HashMap::new(),
+                custom_events: HashMap::new(),
+            },
+            metadata: StandardMultiBehaviorMetadata {
+                total_behaviors: 2,
+                total_files: 2,
+                shared_functions_count: 0,
+                inter_behavior_calls: 0,
+                has_networking: false,
+                dependency_count: 0,
+                circular_dependencies_detected: false,
+                excluded_editor_only: vec![],
+                main_behavior: None,
+            },
+            diagnostics: vec![],
+            behaviors: vec![],
+        };
+
+        let output_dir = tempfile::tempdir().unwrap();
+        result.write_files_to_disk(output_dir.path()).unwrap();
+
+        for output_file in &result.output_files {
+            let script_path = output_dir.path().join(output_file);
+            assert!(script_path.exists());
+
+            let meta = std::fs::read_to_string(crate::asset_meta::meta_path(&script_path)).unwrap();
+            assert!(meta.contains(&format!("guid: {}", crate::asset_meta::stable_guid("world", output_file))));
+            assert!(meta.contains("MonoImporter:"));
+        }
+    }
+
+    const EDITOR_ONLY_SOURCE: &str = r#"
+        #[derive(UdonBehaviour)]
+        pub struct GameManager {
+            score: i32,
+        }
+
+        impl UdonBehaviour for GameManager {
+            fn start(&mut self) {
+                self.score = 0;
+            }
+        }
+
+        #[derive(UdonBehaviour)]
+        #[udon_editor_only]
+        pub struct StatsOverlay {
+            frame_count: i32,
+        }
+
+        impl UdonBehaviour for StatsOverlay {
+            fn start(&mut self) {
+                self.frame_count = 0;
+            }
+        }
+    "#;
+
+    #[tokio::test]
+    async fn test_release_build_omits_editor_only_behavior() {
+        let mut config = UdonSharpConfig { generate_debug_info: false, ..UdonSharpConfig::default() };
+        config.multi_behavior.generate_shared_runtime = false;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+
+        let result = integration.compile_multi_behavior(EDITOR_ONLY_SOURCE).await.unwrap();
+        assert!(result.behavior_files.contains_key("GameManager"));
+        assert!(!result.behavior_files.contains_key("StatsOverlay"));
+        assert_eq!(result.metadata.excluded_editor_only, vec!["StatsOverlay".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_shared_runtime_is_skipped_without_shared_items() {
+        let mut config = UdonSharpConfig::default();
+        config.multi_behavior.prefab_settings.generate_hierarchy_prefab = true;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+
+        let result = integration.compile_multi_behavior(EDITOR_ONLY_SOURCE).await.unwrap();
+        assert!(result.shared_runtime.is_none());
+        assert!(result.shared_runtime_file.is_none());
+        assert!(!result.output_files.iter().any(|file| file.starts_with("SharedRuntime")), "{:?}", result.output_files);
+        assert!(!result.hierarchy_prefab.unwrap().contains("m_Name: SharedRuntime"));
+    }
+
+    #[tokio::test]
+    async fn test_compilation_result_carries_the_built_behaviors() {
+        let mut config = UdonSharpConfig { generate_debug_info: false, public_variable_limit: 64, ..UdonSharpConfig::default() };
+        config.multi_behavior.generate_shared_runtime = false;
+        let mut integration = StandardMultiBehaviorIntegration::new(config.clone(), CompilationContext::new());
+
+        let result = integration.compile_multi_behavior(EDITOR_ONLY_SOURCE).await.unwrap().to_compilation_result();
+        let names: Vec<&str> = result.behaviors.iter().map(|behavior| behavior.name.as_str()).collect();
+        assert_eq!(names, vec!["GameManager"]);
+
+        let stats = crate::budget_stats::BudgetStats::with_config(&result.behaviors, &config);
+        assert_eq!(stats.behaviors.len(), 1);
+        assert_eq!(stats.behaviors[0].public_variable_limit, 64);
+    }
+
+    #[tokio::test]
+    async fn test_debug_build_includes_editor_only_behavior() {
+        let mut config = UdonSharpConfig { generate_debug_info: true, ..UdonSharpConfig::default() };
+        config.multi_behavior.generate_shared_runtime = false;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+
+        let result = integration.compile_multi_behavior(EDITOR_ONLY_SOURCE).await.unwrap();
+        assert!(result.behavior_files.contains_key("GameManager"));
+        assert!(result.behavior_files.contains_key("StatsOverlay"));
+        assert!(result.metadata.excluded_editor_only.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_udon_main_selects_the_primary_behavior() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            #[udon_main]
+            pub struct HelloWorld {
+                greeting: String,
+            }
+
+            impl UdonBehaviour for HelloWorld {
+                fn start(&mut self) {}
+            }
+        "#;
+        let mut config = UdonSharpConfig::default();
+        config.multi_behavior.generate_shared_runtime = false;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+
+        // One behavior is below the multi-behavior threshold, but #[udon_main] needs no guessing
+        assert!(integration.should_use_multi_behavior(source).unwrap());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+        assert_eq!(result.metadata.main_behavior.as_deref(), Some("HelloWorld"));
+        assert!(result.generate_report().contains("Main Behavior: HelloWorld"));
+
+        let result = integration.compile_multi_behavior(EDITOR_ONLY_SOURCE).await.unwrap();
+        assert_eq!(result.metadata.main_behavior, None);
+    }
+
+    #[test]
+    fn test_two_main_behaviors_are_an_error() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            #[udon_main]
+            pub struct HelloWorld {
+                greeting: String,
+            }
+
+            impl UdonBehaviour for HelloWorld {
+                fn start(&mut self) {}
+            }
+
+            #[derive(UdonBehaviour)]
+            #[udon_main]
+            pub struct Lobby {
+                players: i32,
+            }
+
+            impl UdonBehaviour for Lobby {
+                fn start(&mut self) {}
+            }
+        "#;
+        let mut integration = StandardMultiBehaviorIntegration::new(UdonSharpConfig::default(), CompilationContext::new());
+
+        let error = integration.should_use_multi_behavior(source).unwrap_err();
+        assert!(error.to_string().contains("Behaviors 'HelloWorld', 'Lobby' are all marked #[udon_main]"));
+    }
+
+    #[tokio::test]
+    async fn test_execution_order_is_written_to_script_meta() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            #[udon_execution_order(-10)]
+            pub struct GameLogic {
+                round: i32,
+            }
+
+            impl UdonBehaviour for GameLogic {
+                fn start(&mut self) {}
+
+                fn update(&mut self) {
+                    self.round += 1;
+                }
+            }
+
+            #[derive(UdonBehaviour)]
+            #[udon_execution_order(20)]
+            pub struct UiController {
+                shown_round: i32,
+            }
+
+            impl UdonBehaviour for UiController {
+                fn start(&mut self) {}
+
+                fn update(&mut self) {
+                    self.shown_round = 0;
+                }
+            }
+        "#;
+        let mut config = UdonSharpConfig::default();
+        config.multi_behavior.generate_shared_runtime = false;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+
+        assert_eq!(result.behavior_files["GameLogic"].execution_order, Some(-10));
+        assert!(result.behavior_files["UiController"].file_content.contains("[DefaultExecutionOrder(20)]"));
+
+        let output_dir = tempfile::tempdir().unwrap();
+        result.write_files_to_disk(output_dir.path()).unwrap();
+        let meta = |name: &str| {
+            std::fs::read_to_string(crate::asset_meta::meta_path(&output_dir.path().join(name))).unwrap()
+        };
+        assert!(meta("GameLogic.cs").contains("  executionOrder: -10\n"));
+        assert!(meta("UiController.cs").contains("  executionOrder: 20\n"));
+    }
+
+    #[tokio::test]
+    async fn test_manifest_lists_each_behavior_with_its_file_and_guid() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameLogic {
+                #[udon_public]
+                pub round_duration: f32,
+            }
+
+            impl UdonBehaviour for GameLogic {
+                fn start(&mut self) {}
+            }
+
+            #[derive(UdonBehaviour)]
+            pub struct UiController {
+                shown_round: i32,
+            }
+
+            impl UdonBehaviour for UiController {
+                fn start(&mut self) {}
+            }
+        "#;
+        let mut config = UdonSharpConfig::default();
+        config.multi_behavior.generate_shared_runtime = false;
+        config.package_name = "arena_world".to_string();
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+        assert!(result.output_files.contains(&BEHAVIOR_MANIFEST_FILE.to_string()));
+
+        // A GUID Unity already knows the script by is kept, and the manifest reports it
+        let output_dir = tempfile::tempdir().unwrap();
+        let kept_guid = "0123456789abcdef0123456789abcdef";
+        std::fs::write(
+            crate::asset_meta::meta_path(&output_dir.path().join("UiController.cs")),
+            format!("fileFormatVersion: 2\nguid: {}\n", kept_guid),
+        ).unwrap();
+        result.write_files_to_disk(output_dir.path()).unwrap();
+
+        let manifest: BehaviorManifest = serde_json::from_str(
+            &std::fs::read_to_string(output_dir.path().join(BEHAVIOR_MANIFEST_FILE)).unwrap()
+        ).unwrap();
+        let listed: Vec<(&str, &str, &str)> = manifest.behaviors.iter()
+            .map(|behavior| (behavior.class_name.as_str(), behavior.file.as_str(), behavior.guid.as_str()))
+            .collect();
+        let game_logic_guid = crate::asset_meta::stable_guid("arena_world", "GameLogic.cs");
+        assert_eq!(listed, vec![
+            ("GameLogic", "GameLogic.cs", game_logic_guid.as_str()),
+            ("UiController", "UiController.cs", kept_guid),
+        ]);
+        assert_eq!(manifest.behaviors[0].public_fields[0].name, "roundDuration");
+    }
+
+    const SELECTION_SOURCE: &str = r#"
+        #[derive(UdonBehaviour)]
+        pub struct GameStateManager {
+            #[udon_public]
+            scoreboard: Option<GameObject>,
+            round: i32,
+        }
+
+        impl UdonBehaviour for GameStateManager {
+            fn start(&mut self) {
+                self.round = 1;
+            }
+        }
+
+        impl GameStateManager {
+            pub fn end_round(&mut self) {
+                if let Some(board_obj) = &self.scoreboard {
+                    if let Some(board) = board_obj.get_component::<ScoreBoard>() {
+                        board.reset_score();
+                    }
+                }
+            }
+        }
+
+        #[derive(UdonBehaviour)]
+        pub struct ScoreBoard {
+            score: i32,
+        }
+
+        impl UdonBehaviour for ScoreBoard {
+            fn start(&mut self) {
+                self.score = 0;
+            }
+        }
+
+        impl ScoreBoard {
+            pub fn reset_score(&mut self) {
+                self.score = 0;
+            }
+        }
+
+        #[derive(UdonBehaviour)]
+        pub struct LobbyMusic {
+            volume: f32,
+        }
+
+        impl UdonBehaviour for LobbyMusic {
+            fn start(&mut self) {
+                self.volume = 1.0;
+            }
+        }
+    "#;
+
+    #[tokio::test]
+    async fn test_selected_behavior_is_the_only_one_emitted() {
+        let config = UdonSharpConfig {
+            selected_behaviors: vec!["GameStateManager".to_string()],
+            ..UdonSharpConfig::default()
+        };
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+
+        let result = integration.compile_multi_behavior(SELECTION_SOURCE).await.unwrap();
+        assert_eq!(result.behavior_files.keys().collect::<Vec<_>>(), vec!["GameStateManager"]);
+        // The unselected ScoreBoard is still known, so the call into it lowers
+        let content = &result.behavior_files["GameStateManager"].file_content;
+        assert!(content.contains("SendCustomEvent(\"ResetScore\")"), "{}", content);
+        assert!(result.shared_runtime.is_none());
+        assert_eq!(result.output_files, vec!["GameStateManager.cs".to_string(), BEHAVIOR_MANIFEST_FILE.to_string()]);
+        assert_eq!(result.metadata.total_behaviors, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_selected_behavior_is_an_error() {
+        let config = UdonSharpConfig {
+            selected_behaviors: vec!["GameManager".to_string()],
+            ..UdonSharpConfig::default()
+        };
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+
+        let error = integration.compile_multi_behavior(SELECTION_SOURCE).await.unwrap_err().to_string();
+        assert!(error.contains("Unknown behavior 'GameManager'"), "{}", error);
+        assert!(error.contains("GameStateManager, LobbyMusic, ScoreBoard"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_udon_debug_fields_are_shown_on_the_debug_overlay() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameStateManager {
+                #[udon_public]
+                #[udon_sync]
+                #[udon_debug]
+                current_round: i32,
+                round_timer: f32,
+            }
+
+            impl UdonBehaviour for GameStateManager {
+                fn start(&mut self) {
+                    self.current_round = 0;
+                }
+            }
+
+            #[derive(UdonBehaviour)]
+            pub struct ScoreBoard {
+                score: i32,
+            }
+
+            impl UdonBehaviour for ScoreBoard {
+                fn start(&mut self) {
+                    self.score = 0;
+                }
+            }
+        "#;
+        let config = UdonSharpConfig { emit_debug_overlay: true, ..UdonSharpConfig::default() };
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+
+        let overlay = &result.behavior_files[DEBUG_OVERLAY_CLASS];
+        assert_eq!(overlay.dependencies, vec!["GameStateManager".to_string()]);
+        let content = &overlay.file_content;
+        assert!(content.contains("public class UdonDebugOverlay : UdonSharpBehaviour"), "{}", content);
+        assert!(content.contains("text += \"  currentRound: \" + gameStateManager.GetProgramVariable(\"currentRound\") + \"\\n\";"), "{}", content);
+        assert!(!content.contains("roundTimer"), "{}", content);
+        assert!(!content.contains("ScoreBoard"), "{}", content);
+        assert!(content.contains("SendCustomEventDelayedSeconds(nameof(RefreshOverlay), 1.0f);"));
+        assert!(result.output_files.contains(&"UdonDebugOverlay.cs".to_string()));
+
+        // Without the option the marked field only affects the overlay that is not emitted
+        let mut integration = StandardMultiBehaviorIntegration::new(UdonSharpConfig::default(), CompilationContext::new());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+        assert!(!result.behavior_files.contains_key(DEBUG_OVERLAY_CLASS));
+    }
+
+    #[tokio::test]
+    async fn test_shared_runtime_name_sets_lookup_and_prefab_object() {
+        let source = r#"
+            const SPAWN_OFFSETS: [Vector3; 2] = [Vector3::ZERO, Vector3::new(2.0, 0.0, 0.0)];
+
+            #[derive(UdonBehaviour)]
+            pub struct Spawner {
+                next_spawn: i32,
+            }
+
+            impl UdonBehaviour for Spawner {
+                fn start(&mut self) {
+                    self.next_spawn = 0;
+                }
+            }
+
+            impl Spawner {
+                pub fn spawn_point(&self) -> Vector3 {
+                    SPAWN_OFFSETS[self.next_spawn as usize]
+                }
+            }
+        "#;
+        let mut config = UdonSharpConfig { shared_runtime_name: "ArenaRuntime".to_string(), ..UdonSharpConfig::default() };
+        config.multi_behavior.prefab_settings.generate_hierarchy_prefab = true;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+
+        let runtime = &result.shared_runtime.as_ref().unwrap().file_content;
+        assert!(runtime.contains("GameObject runtimeObject = GameObject.Find(\"ArenaRuntime\");"), "{}", runtime);
+        assert!(runtime.contains("_instance = runtimeObject.GetComponent<SharedRuntime>();"), "{}", runtime);
+
+        let prefab = result.hierarchy_prefab.as_ref().unwrap();
+        assert!(prefab.contains("m_Name: ArenaRuntime"), "{}", prefab);
+        assert!(!prefab.contains("m_Name: SharedRuntime"), "{}", prefab);
+
+        // The default name is used when the option is left unset
+        let mut config = UdonSharpConfig::default();
+        config.multi_behavior.prefab_settings.generate_hierarchy_prefab = true;
+        let mut integration = StandardMultiBehaviorIntegration::new(config, CompilationContext::new());
+        let result = integration.compile_multi_behavior(source).await.unwrap();
+        assert!(result.shared_runtime.unwrap().file_content.contains("GameObject.Find(\"SharedRuntime\")"));
+        assert!(result.hierarchy_prefab.unwrap().contains("m_Name: SharedRuntime"));
+    }
+
+    #[tokio::test]
+    async fn test_check_denies_lint_warnings_reported_by_the_analysis() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            #[udon_main]
+            pub struct SessionStats {
+                average_session_time: f32,
+                total_session_time: f32,
+                total_players: i32,
+            }
+
+            impl UdonBehaviour for SessionStats {
+                fn start(&mut self) {}
+            }
+
+            impl SessionStats {
+                pub fn update_average(&mut self) {
+                    self.average_session_time = self.total_session_time / self.total_players as f32;
+                }
+            }
+        "#;
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir(project.path().join("src")).unwrap();
+        std::fs::write(project.path().join("src/lib.rs"), source).unwrap();
+
+        let config = UdonSharpConfig { check_only: true, ..UdonSharpConfig::default() };
+        let pipeline = CompilationPipeline::with_context(config, CompilationContext::new());
+        let result = pipeline.check_project(project.path()).await.unwrap();
+
+        // The analysis runs on a clone of the context; its lint still counts
+        assert!(result.success);
+        assert_eq!(pipeline.context().exit_status(true), udonsharp_core::error::ExitStatus::DeniedWarnings);
+        assert_eq!(pipeline.context().exit_status(false), udonsharp_core::error::ExitStatus::Success);
+        assert!(!project.path().join("SessionStats.cs").exists());
+    }
+
+    #[tokio::test]
+    async fn test_build_keeps_the_network_event_ids_of_the_previous_build() {
+        let arena = |events: &[&str]| {
+            let methods: String = events.iter()
+                .map(|event| format!("#[udon_network_event(target = \"All\")] pub fn {}(&mut self) {{}}\n", event))
+                .collect();
+            format!(
+                "#[derive(UdonBehaviour)] #[udon_main] pub struct Arena {{ round: i32 }}
+                impl Arena {{ {} }}
+                impl UdonBehaviour for Arena {{ fn start(&mut self) {{}} }}",
+                methods
+            )
+        };
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir(project.path().join("src")).unwrap();
+        let output_dir = project.path().join("out");
+        let config = UdonSharpConfig {
+            output_directory: Some(output_dir.to_string_lossy().into_owned()),
+            ..UdonSharpConfig::default()
+        };
+
+        std::fs::write(project.path().join("src/lib.rs"), arena(&["start_round", "end_round"])).unwrap();
+        CompilationPipeline::new(config.clone()).compile_project(project.path()).await.unwrap();
+        assert!(output_dir.join(crate::event_ids::EVENT_ID_TABLE_FILE).exists());
+
+        // An event sorting before the existing ones is numbered after them
+        std::fs::write(project.path().join("src/lib.rs"), arena(&["award_point", "start_round", "end_round"])).unwrap();
+        CompilationPipeline::new(config).compile_project(project.path()).await.unwrap();
+        let script = std::fs::read_to_string(output_dir.join("Arena.cs")).unwrap();
+        assert!(script.contains("public const int EndRoundEventId = 0;"));
+        assert!(script.contains("public const int StartRoundEventId = 1;"));
+        assert!(script.contains("public const int AwardPointEventId = 2;"));
+    }
+
+    #[tokio::test]
+    async fn test_udon_assembly_target_writes_uasm_files() {
+        let source = r#"
+            #[derive(UdonBehaviour)]
+            pub struct Counter {
+                #[udon_sync]
+                pub count: i32,
+            }
+
+            impl UdonBehaviour for Counter {
+                fn start(&mut self) {
+                    self.count = 0;
+                }
+            }
+
+            impl Counter {
+                #[udon_event("Increment")]
+                pub fn increment(&mut self) {
+                    self.count += 1;
+                }
+            }
+        "#;
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir(project.path().join("src")).unwrap();
+        std::fs::write(project.path().join("src/lib.rs"), source).unwrap();
+        let output_dir = project.path().join("out");
+        let config = UdonSharpConfig {
+            output_directory: Some(output_dir.to_string_lossy().into_owned()),
+            target: ConversionTarget::UdonAssembly,
+            ..UdonSharpConfig::default()
+        };
+
+        let result = CompilationPipeline::new(config).compile_project(project.path()).await.unwrap();
+
+        assert_eq!(result.behavior_files["Counter"], "Counter.uasm");
+        let uasm = std::fs::read_to_string(output_dir.join("Counter.uasm")).unwrap();
+        assert!(uasm.contains(".data_start"));
+        assert!(uasm.contains("    .export Increment\n"));
+        assert!(output_dir.join("Counter.uasm.meta").exists());
+        assert!(!output_dir.join("Counter.cs").exists());
+    }
+}
@@ -6,16 +6,45 @@
 use crate::multi_behavior::{
     UdonBehaviourStruct, StructField, StructMethod, StructAttribute, MethodParameter,
     RustType, Visibility, FieldAttribute, MethodAttribute, UdonSyncMode,
-    UdonBehaviourTraitImpl, is_valid_csharp_identifier
+    UdonBehaviourTraitImpl, EmbeddedStruct, IntEnum, NetworkEventTarget, RustToCSharpTypeMapper, SyncBlob,
+    blob_type_size, is_valid_csharp_identifier
 };
 use crate::trait_validator::{TraitValidator, ValidationError};
 use crate::behavior_dependency_analyzer::{BehaviorDependencyAnalyzer, DependencyError, DependencyAnalysisResult};
+use crate::body_lowering::{enum_variant_pattern, BehaviorInterface, BehaviorLocals};
+use crate::code_generator::to_pascal_case;
+use crate::const_eval::{ConstEvaluator, ConstValue};
+use crate::const_tables::ConstTable;
+use crate::payload_enum::{PayloadEnum, PayloadVariant, PAYLOAD_ENUM_RECORD_TYPE};
+use crate::master_guard::{master_dependent_synced_writes, unguarded_synced_writes};
+use crate::division_guard::unguarded_divisions;
+use crate::hot_path::{front_removals, update_reachable_methods};
+use crate::frame_timing::fixed_update_delta_time_reads;
+use crate::init_guard::unset_init_guards;
+use crate::dynamic_find::dynamic_finds;
+use crate::serde_compat::serde_uses;
+use crate::lint_levels::LintLevel;
+use crate::event_registry::EventSignatureRegistry;
+use crate::quantization::Quantization;
+use crate::record_pool::{RecordPool, DEFAULT_POOLED_RECORD_TYPE};
+use crate::event_forwarding::EventForward;
 use syn::{
-    Item, ItemStruct, ItemImpl, Fields, Field, Type, Attribute, Meta, Lit,
+    Item, ItemStruct, ItemImpl, Fields, Field, Type, Attribute, Meta, Lit, LitStr,
     ImplItem, ImplItemFn, FnArg, ReturnType, Pat, PatType, Visibility as SynVisibility,
     parse::Parse, parse::ParseStream, Token, punctuated::Punctuated
 };
-use std::collections::HashMap;
+use syn::visit::Visit;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Default number of public variables a single behavior may expose. VRChat
+/// documents no fixed cap for Udon programs, so this is a conservative budget
+/// that keeps inspectors usable; override it with `public_variable_limit`
+pub const DEFAULT_PUBLIC_VARIABLE_LIMIT: usize = 256;
+
+/// Public variable count at which analysis starts warning, an eighth below `limit`
+pub fn public_variable_warning_threshold(limit: usize) -> usize {
+    limit - limit / 8
+}
 
 /// Result type for struct analysis operations
 pub type AnalysisResult<T> = Result<T, AnalysisError>;
@@ -45,6 +74,20 @@ pub enum AnalysisError {
     CircularDependency { cycle: Vec<String>, description: String },
     /// Missing dependency reference
     MissingDependency { behavior: String, missing_dependency: String },
+    /// Behavior exposes more public variables than Udon allows
+    TooManyPublicVariables { struct_name: String, count: usize, limit: usize },
+    /// A call to another behavior does not set the parameter fields its method expects
+    EventArgumentMismatch { struct_name: String, method_name: String, reason: String },
+    /// Finding of a lint whose level is set to `deny`
+    DeniedLint { lint: String, message: String },
+    /// A behavior method calls into serde, which cannot run in Udon
+    UnsupportedSerde { struct_name: String, method_name: String, call: String, udon_json: bool },
+    /// More than one behavior is marked `#[udon_main]`
+    MultipleMainBehaviors { behaviors: Vec<String> },
+    /// A `#[udon_forward]` target names no behavior of the project
+    UnknownForwardTarget { behavior: String, event: String, target: String },
+    /// A module constant array cannot be emitted as a static readonly table
+    InvalidConstTable { name: String, reason: String },
 }
 
 impl std::fmt::Display for AnalysisError {
@@ -88,6 +131,35 @@ impl std::fmt::Display for AnalysisError {
             AnalysisError::MissingDependency { behavior, missing_dependency } => {
                 write!(f, "Behavior '{}' depends on missing behavior '{}'", behavior, missing_dependency)
             }
+            AnalysisError::TooManyPublicVariables { struct_name, count, limit } => {
+                write!(f, "Behavior '{}' exposes {} public variables, exceeding the Udon limit of {}. \
+                           Group related configuration into a single synced or serialized blob field",
+                       struct_name, count, limit)
+            }
+            AnalysisError::EventArgumentMismatch { struct_name, method_name, reason } => {
+                write!(f, "Call from '{}::{}' does not match the receiving method: {}", struct_name, method_name, reason)
+            }
+            AnalysisError::DeniedLint { lint, message } => {
+                write!(f, "{} (lint `{}` is set to deny)", message, lint)
+            }
+            AnalysisError::UnsupportedSerde { struct_name, method_name, call, udon_json } => {
+                write!(f, "Method '{}::{}' calls `{}`, but serde is not supported in Udon; use #[udon_json]/VRCJson", struct_name, method_name, call)?;
+                if *udon_json {
+                    write!(f, ". '{}' is #[udon_json], so serialize with `serde_json::to_string(&self)` or the generated `ToJson`/`FromJson` methods", struct_name)
+                } else {
+                    write!(f, ". Mark '{}' #[udon_json] to generate VRCJson-backed `ToJson`/`FromJson` methods", struct_name)
+                }
+            }
+            AnalysisError::MultipleMainBehaviors { behaviors } => {
+                write!(f, "Behaviors {} are all marked #[udon_main]; a world has at most one main behavior",
+                       behaviors.iter().map(|b| format!("'{}'", b)).collect::<Vec<_>>().join(", "))
+            }
+            AnalysisError::UnknownForwardTarget { behavior, event, target } => {
+                write!(f, "Behavior '{}' forwards '{}' to '{}', which is not a behavior of this project", behavior, event, target)
+            }
+            AnalysisError::InvalidConstTable { name, reason } => {
+                write!(f, "Constant table '{}' cannot be shared as a static readonly array: {}", name, reason)
+            }
         }
     }
 }
@@ -114,6 +186,25 @@ pub struct StructAnalyzer {
     trait_validator: TraitValidator,
     /// Dependency analyzer for inter-behavior dependencies
     dependency_analyzer: BehaviorDependencyAnalyzer,
+    /// Plain (non-behavior) structs that may be embedded in behaviors, kept
+    /// as token source so the analyzer stays `Send`
+    plain_structs: HashMap<String, String>,
+    /// Inherent impl methods of plain structs, kept as token source
+    plain_impls: HashMap<String, Vec<String>>,
+    /// Enums with data declared in the module, lowered to tagged records
+    payload_enums: HashMap<String, PayloadEnum>,
+    /// Evaluator for constant field defaults
+    const_evaluator: ConstEvaluator,
+    /// Configured lint levels by lint name; unlisted lints warn
+    lint_levels: BTreeMap<String, LintLevel>,
+    /// Lints allowed with `#[udon_allow]`, keyed by `Behavior` or `Behavior::member`
+    lint_allows: HashMap<String, BTreeSet<String>>,
+    /// Behaviors with a `new()` constructor or a `Default` impl
+    constructed: HashSet<String>,
+    /// Fields each behavior's constructor initializes with a value that is not constant
+    non_constant_initializers: BTreeMap<String, Vec<String>>,
+    /// Public variables a behavior may expose before analysis fails
+    public_variable_limit: usize,
 }
 
 impl StructAnalyzer {
@@ -126,9 +217,33 @@ impl StructAnalyzer {
             warnings: Vec::new(),
             trait_validator: TraitValidator::new(),
             dependency_analyzer: BehaviorDependencyAnalyzer::new(),
+            plain_structs: HashMap::new(),
+            plain_impls: HashMap::new(),
+            payload_enums: HashMap::new(),
+            const_evaluator: ConstEvaluator::new(),
+            lint_levels: BTreeMap::new(),
+            lint_allows: HashMap::new(),
+            constructed: HashSet::new(),
+            non_constant_initializers: BTreeMap::new(),
+            public_variable_limit: DEFAULT_PUBLIC_VARIABLE_LIMIT,
         }
     }
 
+    /// Set the level of a lint, see `lint_levels::LINTS` for the names
+    pub fn set_lint_level(&mut self, lint: &str, level: LintLevel) {
+        self.lint_levels.insert(lint.to_string(), level);
+    }
+
+    /// Replace the configured lint levels
+    pub fn set_lint_levels(&mut self, levels: BTreeMap<String, LintLevel>) {
+        self.lint_levels = levels;
+    }
+
+    /// Set the number of public variables a behavior may expose
+    pub fn set_public_variable_limit(&mut self, limit: usize) {
+        self.public_variable_limit = limit;
+    }
+
     /// Analyze a Rust module and extract UdonBehaviour structs
     pub fn analyze_module(&mut self, items: &[Item]) -> AnalysisResult<Vec<UdonBehaviourStruct>> {
         // Clear previous analysis results
@@ -136,11 +251,33 @@ impl StructAnalyzer {
         self.trait_implementations.clear();
         self.errors.clear();
         self.warnings.clear();
+        self.plain_structs.clear();
+        self.plain_impls.clear();
+        self.payload_enums.clear();
+        self.lint_allows.clear();
+        self.constructed.clear();
+        self.non_constant_initializers.clear();
+
+        // Collect the lints items opt out of with `#[udon_allow]`
+        self.collect_lint_allows(items);
+
+        // Collect plain structs and their impls so embedded fields can be flattened
+        self.collect_plain_structs(items);
+
+        // Collect enums with data so fields and parameters holding them are typed as records
+        self.collect_payload_enums(items);
+
+        // Collect module constants so field defaults can be folded to literals
+        self.const_evaluator = ConstEvaluator::new();
+        self.const_evaluator.collect_items(items);
 
         // First pass: find all structs with UdonBehaviour derive
         for item in items {
             if let Item::Struct(item_struct) = item {
                 if self.has_udon_behaviour_derive(&item_struct.attrs) {
+                    if derives_default(&item_struct.attrs) {
+                        self.constructed.insert(item_struct.ident.to_string());
+                    }
                     match self.analyze_struct(item_struct) {
                         Ok(udon_struct) => {
                             self.parsed_structs.insert(udon_struct.name.clone(), udon_struct);
@@ -153,19 +290,32 @@ impl StructAnalyzer {
             }
         }
 
-        // Second pass: find trait implementations
+        // Second pass: find trait implementations and inherent methods
         for item in items {
             if let Item::Impl(item_impl) = item {
                 if let Some(trait_path) = &item_impl.trait_ {
                     if self.is_udon_behaviour_trait(&trait_path.1) {
                         match self.analyze_trait_impl(item_impl) {
-                            Ok((struct_name, trait_impl)) => {
+                            Ok((struct_name, trait_impl, methods)) => {
+                                if let Some(udon_struct) = self.parsed_structs.get_mut(&struct_name) {
+                                    for method in methods {
+                                        udon_struct.add_method(method);
+                                    }
+                                }
                                 self.trait_implementations.insert(struct_name, trait_impl);
                             }
                             Err(error) => {
                                 self.errors.push(error);
                             }
                         }
+                    } else if trait_path.1.is_ident("Default") {
+                        if let Some(struct_name) = impl_target_name(item_impl) {
+                            self.analyze_default_impl(&struct_name, item_impl);
+                        }
+                    }
+                } else if let Some(struct_name) = impl_target_name(item_impl) {
+                    if self.parsed_structs.contains_key(&struct_name) {
+                        self.analyze_inherent_impl(&struct_name, item_impl);
                     }
                 }
             }
@@ -178,6 +328,15 @@ impl StructAnalyzer {
             }
         }
 
+        // Record the fieldless enums each behavior converts from integers or matches on
+        self.attach_enums(items);
+
+        // Record the enums with data each behavior constructs or matches on
+        self.attach_payload_enums();
+
+        // Record the module constant tables each behavior indexes
+        self.attach_const_tables(items);
+
         // Validate all parsed structs
         for udon_struct in self.parsed_structs.values() {
             if let Err(error_msg) = udon_struct.validate() {
@@ -185,12 +344,65 @@ impl StructAnalyzer {
             }
         }
 
+        // A world designates at most one main behavior
+        if let Err(error) = find_main_behavior(self.parsed_structs.values()) {
+            self.errors.push(error);
+        }
+
+        // Forwarded events must reach behaviors that exist
+        self.check_forward_targets();
+
+        // Check public variable counts against the Udon limit
+        self.check_public_variable_counts();
+
         // Validate UdonBehaviour trait implementations
         self.validate_trait_implementations();
 
         // Analyze dependencies and detect circular dependencies
         self.analyze_dependencies();
 
+        // Flag reads of other behaviors' private fields, which Udon cannot perform
+        self.lint_cross_behavior_access();
+
+        // Flag reads through a behavior reference that expect a call on it to have mutated it
+        self.lint_cross_behavior_mutation();
+
+        // Check that calls to other behaviors set the parameter fields their methods expect
+        self.check_event_call_arguments();
+
+        // Flag synced writes that run before the method's master guard
+        self.lint_master_guard_placement();
+
+        // Flag divisions by counters that start at zero without a zero check
+        self.lint_unguarded_division();
+
+        // Flag synced state initialized in Start based on `is_master()`
+        self.lint_master_dependent_start();
+
+        // Flag `remove(0)` shifts in methods that run every frame
+        self.lint_front_removal_in_update();
+
+        // Suggest integer sync for synced strings that only hold a few fixed values
+        self.lint_string_enum_sync();
+
+        // Flag initialization guards that `start` never opens
+        self.lint_unset_init_guard();
+
+        // Flag frame-time reads in code that only runs in the physics step
+        self.lint_delta_time_in_fixed_update();
+
+        // Flag scene lookups by names computed at runtime
+        self.lint_dynamic_find();
+
+        // Flag behaviors whose private fields have no constructor to take initial values from
+        self.lint_missing_constructor();
+
+        // Flag constructor initializers that cannot be assigned in Start
+        self.lint_non_constant_initializer();
+
+        // Reject serde calls that cannot be routed to VRCJson
+        self.check_serde_usage();
+
         // Return results or errors
         if !self.errors.is_empty() {
             Err(self.errors[0].clone())
@@ -258,8 +470,26 @@ impl StructAnalyzer {
 
         // Parse struct attributes
         for attr in &item_struct.attrs {
-            if let Ok(struct_attr) = self.parse_struct_attribute(attr) {
-                udon_struct.add_attribute(struct_attr);
+            match self.parse_struct_attribute(attr) {
+                Ok(StructAttribute::Pool { .. }) if RecordPool::for_behavior(&udon_struct).is_some() => {
+                    return Err(AnalysisError::ParseError {
+                        message: format!("Behavior '{}' has more than one #[udon_pool]; a behavior pools a single record type", struct_name),
+                    });
+                }
+                Ok(StructAttribute::Forward { event, .. })
+                    if EventForward::for_behavior(&udon_struct).iter().any(|forward| forward.event == event) =>
+                {
+                    return Err(AnalysisError::ParseError {
+                        message: format!(
+                            "Behavior '{}' forwards '{}' more than once; list every target in one #[udon_forward]",
+                            struct_name, event
+                        ),
+                    });
+                }
+                Ok(struct_attr) => udon_struct.add_attribute(struct_attr),
+                // A malformed pool or forward would otherwise be dropped without notice
+                Err(error) if attr.path().is_ident("udon_pool") || attr.path().is_ident("udon_forward") => return Err(error),
+                Err(_) => {}
             }
         }
 
@@ -267,6 +497,13 @@ impl StructAnalyzer {
         match &item_struct.fields {
             Fields::Named(fields_named) => {
                 for field in &fields_named.named {
+                    if let Some(type_name) = self.embedded_type_name(&field.ty) {
+                        if let Err(error) = self.flatten_embedded_field(&mut udon_struct, field, &type_name) {
+                            self.errors.push(error);
+                        }
+                        continue;
+                    }
+
                     match self.analyze_field(&struct_name, field) {
                         Ok(struct_field) => {
                             udon_struct.add_field(struct_field);
@@ -308,8 +545,12 @@ impl StructAnalyzer {
             });
         }
 
-        // Parse field type
-        let rust_type = self.parse_type(&field.ty)?;
+        // Parse field type. Udon has no generic dictionary, so map fields are
+        // stored in a `DataDictionary`
+        let rust_type = match self.parse_type(&field.ty)? {
+            RustType::HashMap(_, _) => RustType::Custom("DataDictionary".to_string()),
+            rust_type => rust_type,
+        };
 
         // Check if type is UdonSharp compatible
         if !rust_type.is_udonsharp_compatible() {
@@ -330,7 +571,7 @@ impl StructAnalyzer {
         struct_field.set_visibility(visibility);
 
         // Parse field attributes
-        for attr in &field.attrs {
+        for attr in field.attrs.iter().filter(|attr| !is_ignored_field_attribute(attr)) {
             match self.parse_field_attribute(attr) {
                 Ok(field_attr) => {
                     struct_field.add_attribute(field_attr);
@@ -341,6 +582,15 @@ impl StructAnalyzer {
             }
         }
 
+        if let Some(Err(reason)) = Quantization::for_field(&struct_field) {
+            self.errors.push(AnalysisError::InvalidFieldAttribute {
+                struct_name: struct_name.to_string(),
+                field_name: field_name.clone(),
+                attribute: "udon_quantize".to_string(),
+                reason,
+            });
+        }
+
         Ok(struct_field)
     }
 
@@ -383,7 +633,8 @@ impl StructAnalyzer {
                                             }
                                         }
                                     }
-                                    "Vec" => {
+                                    // A queue is drained from the front of the same backing array
+                                    "Vec" | "VecDeque" => {
                                         if args.args.len() == 1 {
                                             if let syn::GenericArgument::Type(inner_ty) = &args.args[0] {
                                                 let inner_type = self.parse_type(inner_ty)?;
@@ -391,6 +642,15 @@ impl StructAnalyzer {
                                             }
                                         }
                                     }
+                                    "UdonRef" => {
+                                        if args.args.len() == 1 {
+                                            if let syn::GenericArgument::Type(Type::Path(target)) = &args.args[0] {
+                                                if let Some(segment) = target.path.segments.last() {
+                                                    return Ok(RustType::UdonRef(segment.ident.to_string()));
+                                                }
+                                            }
+                                        }
+                                    }
                                     "HashMap" => {
                                         if args.args.len() == 2 {
                                             if let (syn::GenericArgument::Type(key_ty), syn::GenericArgument::Type(value_ty)) = 
@@ -416,6 +676,9 @@ impl StructAnalyzer {
                                 "GameObject" => Ok(RustType::GameObject),
                                 "Transform" => Ok(RustType::Transform),
                                 "VRCPlayerApi" => Ok(RustType::VRCPlayerApi),
+                                name if self.payload_enums.contains_key(name) => {
+                                    Ok(RustType::Custom(PAYLOAD_ENUM_RECORD_TYPE.to_string()))
+                                }
                                 _ => Ok(RustType::Custom(ident.to_string())),
                             }
                         }
@@ -442,6 +705,10 @@ impl StructAnalyzer {
                 // For now, we'll treat arrays as Vec since we can't easily parse the size
                 Ok(RustType::Vec(Box::new(inner_type)))
             }
+            // A slice is passed as the array it borrows from, and other
+            // references as the value they point to
+            Type::Reference(reference) => self.parse_type(&reference.elem),
+            Type::Slice(slice) => Ok(RustType::Vec(Box::new(self.parse_type(&slice.elem)?))),
             Type::Tuple(type_tuple) => {
                 if type_tuple.elems.is_empty() {
                     Ok(RustType::Unit)
@@ -463,6 +730,86 @@ impl StructAnalyzer {
 
     /// Parse a struct attribute
     fn parse_struct_attribute(&self, attr: &Attribute) -> AnalysisResult<StructAttribute> {
+        if attr.path().is_ident("udon_editor_only") {
+            return Ok(StructAttribute::EditorOnly);
+        }
+
+        if attr.path().is_ident("udon_json") {
+            return Ok(StructAttribute::Json);
+        }
+
+        if attr.path().is_ident("udon_main") {
+            return Ok(StructAttribute::Main);
+        }
+
+        if attr.path().is_ident("udon_execution_order") {
+            let order = attr.parse_args::<syn::Expr>().ok()
+                .and_then(|order| match order {
+                    syn::Expr::Lit(syn::ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<i32>().ok(),
+                    syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match *expr {
+                        syn::Expr::Lit(syn::ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<i32>().ok().map(|n| -n),
+                        _ => None,
+                    },
+                    _ => None,
+                });
+            return order.map(StructAttribute::ExecutionOrder).ok_or_else(|| AnalysisError::ParseError {
+                message: format!("#[udon_execution_order] expects an integer, found: {}", quote::quote!(#attr)),
+            });
+        }
+
+        if attr.path().is_ident("udon_pool") {
+            let (mut record_type, mut size) = (None, None);
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("size") {
+                    size = Some(meta.value()?.parse::<syn::LitInt>()?.base10_parse::<usize>()?);
+                } else if let Some(ident) = meta.path.get_ident().filter(|_| record_type.is_none()) {
+                    record_type = Some(ident.to_string());
+                } else {
+                    return Err(meta.error("expected the pooled type followed by `size`"));
+                }
+                Ok(())
+            }).map_err(|e| AnalysisError::ParseError {
+                message: format!("Invalid #[udon_pool] arguments: {}", e),
+            })?;
+            let size = size.ok_or_else(|| AnalysisError::ParseError {
+                message: "#[udon_pool] requires `size`".to_string(),
+            })?;
+            let pool = RecordPool::new(record_type.as_deref().unwrap_or(DEFAULT_POOLED_RECORD_TYPE), size)
+                .map_err(|reason| AnalysisError::ParseError {
+                    message: format!("Invalid #[udon_pool]: {}", reason),
+                })?;
+            return Ok(StructAttribute::Pool { record_type: pool.record_type, size: pool.size });
+        }
+
+        if attr.path().is_ident("udon_forward") {
+            let (mut event, mut targets) = (None, None);
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("event") {
+                    event = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("to") {
+                    let list = meta.value()?.parse::<syn::ExprArray>()?;
+                    targets = Some(list.elems.iter().map(|target| match target {
+                        syn::Expr::Lit(syn::ExprLit { lit: Lit::Str(name), .. }) => Ok(name.value()),
+                        other => Err(syn::Error::new_spanned(other, "expected a behavior name string")),
+                    }).collect::<syn::Result<Vec<String>>>()?);
+                } else {
+                    return Err(meta.error("expected `event` or `to`"));
+                }
+                Ok(())
+            }).map_err(|e| AnalysisError::ParseError {
+                message: format!("Invalid #[udon_forward] arguments: {}", e),
+            })?;
+            let (Some(event), Some(targets)) = (event, targets) else {
+                return Err(AnalysisError::ParseError {
+                    message: "#[udon_forward] requires `event` and `to`".to_string(),
+                });
+            };
+            let forward = EventForward::new(&event, targets).map_err(|reason| AnalysisError::ParseError {
+                message: format!("Invalid #[udon_forward]: {}", reason),
+            })?;
+            return Ok(StructAttribute::Forward { event: forward.event, targets: forward.targets });
+        }
+
         if attr.path().is_ident("udon_sync_mode") {
             match &attr.meta {
                 Meta::List(meta_list) => {
@@ -491,40 +838,47 @@ impl StructAnalyzer {
             return Ok(FieldAttribute::UdonPublic);
         } else if attr.path().is_ident("udon_sync") {
             return Ok(FieldAttribute::UdonSync);
-        } else if attr.path().is_ident("header") {
-            match &attr.meta {
-                Meta::List(meta_list) => {
-                    let tokens = &meta_list.tokens;
-                    let token_str = tokens.to_string();
-                    // Extract string literal from tokens
-                    if let Some(start) = token_str.find('"') {
-                        if let Some(end) = token_str.rfind('"') {
-                            if start < end {
-                                let header_text = token_str[start + 1..end].to_string();
-                                return Ok(FieldAttribute::Header(header_text));
-                            }
-                        }
+        } else if attr.path().is_ident("udon_serialize_field") {
+            return Ok(FieldAttribute::UdonSerializeField);
+        } else if attr.path().is_ident("udon_debug") {
+            return Ok(FieldAttribute::UdonDebug);
+        } else if attr.path().is_ident("udon_quantize") {
+            let (mut bits, mut min, mut max) = (None, None, None);
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bits") {
+                    bits = Some(meta.value()?.parse::<syn::LitInt>()?.base10_parse::<u8>()?);
+                } else if meta.path.is_ident("min") || meta.path.is_ident("max") {
+                    let value: syn::Expr = meta.value()?.parse()?;
+                    let literal = quote::quote!(#value).to_string().replace(' ', "");
+                    let literal = literal.trim_end_matches("f32").to_string();
+                    if meta.path.is_ident("min") {
+                        min = Some(literal);
+                    } else {
+                        max = Some(literal);
                     }
+                } else {
+                    return Err(meta.error("expected `bits`, `min` or `max`"));
                 }
-                _ => {}
+                Ok(())
+            }).map_err(|e| AnalysisError::ParseError {
+                message: format!("Invalid #[udon_quantize] arguments: {}", e),
+            })?;
+            return match (bits, min, max) {
+                (Some(bits), Some(min), Some(max)) => Ok(FieldAttribute::UdonQuantize { bits, min, max }),
+                _ => Err(AnalysisError::ParseError {
+                    message: "#[udon_quantize] requires `bits`, `min` and `max`".to_string(),
+                }),
+            };
+        } else if is_inspector_attribute(attr, "header") {
+            if let Ok(text) = attr.parse_args::<LitStr>() {
+                return Ok(FieldAttribute::Header(text.value()));
             }
-        } else if attr.path().is_ident("tooltip") {
-            match &attr.meta {
-                Meta::List(meta_list) => {
-                    let tokens = &meta_list.tokens;
-                    let token_str = tokens.to_string();
-                    // Extract string literal from tokens
-                    if let Some(start) = token_str.find('"') {
-                        if let Some(end) = token_str.rfind('"') {
-                            if start < end {
-                                let tooltip_text = token_str[start + 1..end].to_string();
-                                return Ok(FieldAttribute::Tooltip(tooltip_text));
-                            }
-                        }
-                    }
-                }
-                _ => {}
+        } else if is_inspector_attribute(attr, "tooltip") {
+            if let Ok(text) = attr.parse_args::<LitStr>() {
+                return Ok(FieldAttribute::Tooltip(text.value()));
             }
+        } else if is_inspector_attribute(attr, "range") {
+            return parse_range_attribute(attr);
         }
         
         Err(AnalysisError::ParseError {
@@ -533,7 +887,7 @@ impl StructAnalyzer {
     }
 
     /// Analyze a trait implementation
-    fn analyze_trait_impl(&mut self, item_impl: &syn::ItemImpl) -> AnalysisResult<(String, UdonBehaviourTraitImpl)> {
+    fn analyze_trait_impl(&mut self, item_impl: &syn::ItemImpl) -> AnalysisResult<(String, UdonBehaviourTraitImpl, Vec<StructMethod>)> {
         // Get the struct name this impl is for
         let struct_name = if let Type::Path(type_path) = &*item_impl.self_ty {
             type_path.path.segments.last()
@@ -548,6 +902,7 @@ impl StructAnalyzer {
         };
 
         let mut trait_impl = UdonBehaviourTraitImpl::new();
+        let mut methods = Vec::new();
 
         // Analyze each method in the impl
         for item in &item_impl.items {
@@ -558,8 +913,7 @@ impl StructAnalyzer {
                 // Parse method for additional metadata
                 match self.analyze_method(&struct_name, impl_fn) {
                     Ok(struct_method) => {
-                        // We could store methods here if needed for the struct
-                        // For now, we just track that the method exists
+                        methods.push(struct_method);
                     }
                     Err(error) => {
                         self.errors.push(error);
@@ -569,7 +923,401 @@ impl StructAnalyzer {
         }
 
         trait_impl.check_completeness();
-        Ok((struct_name, trait_impl))
+        Ok((struct_name, trait_impl, methods))
+    }
+
+    /// Analyze an inherent impl block of a behavior and record its methods
+    fn analyze_inherent_impl(&mut self, struct_name: &str, item_impl: &ItemImpl) {
+        for item in &item_impl.items {
+            if let ImplItem::Fn(impl_fn) = item {
+                if is_constructor(impl_fn) {
+                    self.constructed.insert(struct_name.to_string());
+                    self.apply_constructor_defaults(struct_name, impl_fn);
+                    continue;
+                }
+                match self.analyze_method(struct_name, impl_fn) {
+                    Ok(struct_method) => {
+                        if let Some(udon_struct) = self.parsed_structs.get_mut(struct_name) {
+                            udon_struct.add_method(struct_method);
+                        }
+                    }
+                    Err(error) => {
+                        self.errors.push(error);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record the field initializers of a behavior's `Default::default()` the
+    /// same way as those of a `new()` constructor
+    fn analyze_default_impl(&mut self, struct_name: &str, item_impl: &ItemImpl) {
+        if !self.parsed_structs.contains_key(struct_name) {
+            return;
+        }
+        for item in &item_impl.items {
+            if let ImplItem::Fn(impl_fn) = item {
+                if impl_fn.sig.ident == "default" {
+                    self.constructed.insert(struct_name.to_string());
+                    self.apply_constructor_defaults(struct_name, impl_fn);
+                }
+            }
+        }
+    }
+
+    /// Record the constant field initializers of a `new()` constructor as field
+    /// defaults. Collection constructors allocate the field's backing storage
+    /// (see `collection_initializer`), and `None`, `String::new()` and the like
+    /// take the type's default. Other initializers that are not constant are
+    /// kept as the field's Start initializer and lowered into `Start`, unless
+    /// they read the constructor's parameters or locals, or the field is
+    /// public, whose inspector value `Start` would overwrite; those are
+    /// reported by the `non_constant_initializer` lint.
+    ///
+    /// With struct update syntax (`..Default::default()`) the fields the
+    /// literal leaves out take their type's default, so they are initialized
+    /// in `Start` like the listed ones. Fields that default to `null` are left
+    /// alone since C# already starts them out null.
+    fn apply_constructor_defaults(&mut self, struct_name: &str, impl_fn: &ImplItemFn) {
+        let Some(syn::Stmt::Expr(syn::Expr::Struct(literal), None)) = impl_fn.block.stmts.last() else {
+            return;
+        };
+        let Some(udon_struct) = self.parsed_structs.get_mut(struct_name) else {
+            return;
+        };
+        let locals = constructor_locals(impl_fn);
+
+        for field_value in &literal.fields {
+            let syn::Member::Named(member) = &field_value.member else {
+                continue;
+            };
+            let member = member.to_string();
+            let Some(field) = udon_struct.fields.iter_mut().find(|f| f.name == member) else {
+                continue;
+            };
+            if let Some(literal) = self.const_evaluator.evaluate(&field_value.expr)
+                .and_then(|value| value.to_csharp_literal(&field.field_type))
+                .or_else(|| collection_initializer(&field_value.expr, field, &self.const_evaluator))
+            {
+                field.set_default_value(literal);
+            } else if is_default_initializer(&field_value.expr) {
+                let default = RustToCSharpTypeMapper::new().get_default_value(&field.field_type);
+                if default != "null" {
+                    field.set_default_value(default);
+                }
+            } else if (!field.is_serialized() || Quantization::for_field(field).is_some())
+                && !reads_any(&field_value.expr, &locals)
+            {
+                let expr = &field_value.expr;
+                field.start_initializer = Some(quote::quote!(#expr).to_string());
+            } else {
+                self.non_constant_initializers.entry(struct_name.to_string()).or_default().push(member);
+            }
+        }
+
+        let Some(rest) = &literal.rest else {
+            return;
+        };
+        if !is_default_call(rest, struct_name) {
+            return;
+        }
+        let listed: HashSet<String> = literal.fields.iter()
+            .filter_map(|field_value| match &field_value.member {
+                syn::Member::Named(member) => Some(member.to_string()),
+                syn::Member::Unnamed(_) => None,
+            })
+            .collect();
+        let type_mapper = RustToCSharpTypeMapper::new();
+        for field in udon_struct.fields.iter_mut().filter(|f| !listed.contains(&f.name) && f.default_value.is_none()) {
+            let default = type_mapper.get_default_value(&field.field_type);
+            if default != "null" {
+                field.set_default_value(default);
+            }
+        }
+    }
+
+    /// Collect plain structs and their inherent impls from a module
+    fn collect_plain_structs(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Struct(item_struct) if !self.has_udon_behaviour_derive(&item_struct.attrs) => {
+                    if matches!(item_struct.fields, Fields::Named(_)) {
+                        self.plain_structs.insert(
+                            item_struct.ident.to_string(),
+                            quote::quote!(#item_struct).to_string(),
+                        );
+                    }
+                }
+                Item::Impl(item_impl) if item_impl.trait_.is_none() => {
+                    if let Some(struct_name) = impl_target_name(item_impl) {
+                        let methods = item_impl.items.iter().filter_map(|item| match item {
+                            ImplItem::Fn(impl_fn) => Some(quote::quote!(#impl_fn).to_string()),
+                            _ => None,
+                        });
+                        self.plain_impls.entry(struct_name).or_default().extend(methods);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collect the enums whose variants carry data, naming the fields of
+    /// tuple variants by position
+    fn collect_payload_enums(&mut self, items: &[Item]) {
+        let definitions: Vec<&syn::ItemEnum> = items.iter()
+            .filter_map(|item| match item {
+                Item::Enum(item_enum) if item_enum.variants.iter().any(|v| !matches!(v.fields, Fields::Unit)) => Some(item_enum),
+                _ => None,
+            })
+            .collect();
+        // Register every name first so a payload holding another such enum is typed as a record
+        for item_enum in &definitions {
+            let name = item_enum.ident.to_string();
+            self.payload_enums.insert(name.clone(), PayloadEnum { name, variants: Vec::new() });
+        }
+
+        for item_enum in definitions {
+            let mut variants = Vec::new();
+            for variant in &item_enum.variants {
+                let mut fields = Vec::new();
+                for (position, field) in variant.fields.iter().enumerate() {
+                    let name = field.ident.as_ref().map_or_else(|| position.to_string(), |ident| ident.to_string());
+                    match self.parse_type(&field.ty) {
+                        Ok(field_type) => fields.push((name, field_type)),
+                        Err(error) => self.errors.push(error),
+                    }
+                }
+                variants.push(PayloadVariant { name: variant.ident.to_string(), fields });
+            }
+            let name = item_enum.ident.to_string();
+            self.payload_enums.insert(name.clone(), PayloadEnum { name, variants });
+        }
+    }
+
+    /// Record the lints named by `#[udon_allow(...)]` on behaviors, their
+    /// fields and the methods of their impl blocks
+    fn collect_lint_allows(&mut self, items: &[Item]) {
+        let mut allow = |key: String, attrs: &[Attribute]| {
+            let lints: BTreeSet<String> = attrs.iter()
+                .filter(|attr| attr.path().is_ident("udon_allow"))
+                .filter_map(|attr| attr.parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated).ok())
+                .flat_map(|lints| lints.into_iter().map(|lint| lint.to_string()))
+                .collect();
+            if !lints.is_empty() {
+                self.lint_allows.entry(key).or_default().extend(lints);
+            }
+        };
+
+        for item in items {
+            match item {
+                Item::Struct(item_struct) => {
+                    let struct_name = item_struct.ident.to_string();
+                    allow(struct_name.clone(), &item_struct.attrs);
+                    for field in &item_struct.fields {
+                        if let Some(ident) = &field.ident {
+                            allow(format!("{}::{}", struct_name, ident), &field.attrs);
+                        }
+                    }
+                }
+                Item::Impl(item_impl) => {
+                    let Some(struct_name) = impl_target_name(item_impl) else {
+                        continue;
+                    };
+                    for impl_item in &item_impl.items {
+                        if let ImplItem::Fn(impl_fn) = impl_item {
+                            allow(format!("{}::{}", struct_name, impl_fn.sig.ident), &impl_fn.attrs);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Attach the fieldless enums a behavior converts from integers with
+    /// `Enum::from_i32(n)` or `n as Enum`, or matches on, to that behavior
+    fn attach_enums(&mut self, items: &[Item]) {
+        let enums: HashMap<String, IntEnum> = items.iter()
+            .filter_map(|item| match item {
+                Item::Enum(item_enum) => int_enum_definition(item_enum),
+                _ => None,
+            })
+            .map(|int_enum| (int_enum.name.clone(), int_enum))
+            .collect();
+        if enums.is_empty() {
+            return;
+        }
+
+        for udon_struct in self.parsed_structs.values_mut() {
+            let mut visitor = EnumUsageVisitor { enums: &enums, converted: BTreeSet::new(), matched: BTreeSet::new() };
+            for method in &udon_struct.methods {
+                if let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) {
+                    visitor.visit_block(&block);
+                }
+            }
+            udon_struct.int_enums = visitor.converted.iter().map(|name| enums[name].clone()).collect();
+            udon_struct.matched_enums = visitor.matched.iter().map(|name| enums[name].clone()).collect();
+        }
+    }
+
+    /// Attach the enums with data a behavior's methods construct or match on,
+    /// which get a generated constructor per variant
+    fn attach_payload_enums(&mut self) {
+        if self.payload_enums.is_empty() {
+            return;
+        }
+
+        for udon_struct in self.parsed_structs.values_mut() {
+            let mut visitor = PayloadEnumUsageVisitor { enums: &self.payload_enums, used: BTreeSet::new() };
+            for method in &udon_struct.methods {
+                if let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) {
+                    visitor.visit_block(&block);
+                }
+            }
+            udon_struct.payload_enums = visitor.used.iter().map(|name| self.payload_enums[name].clone()).collect();
+        }
+    }
+
+    /// Attach the module `const` arrays a behavior's methods refer to, which
+    /// become static readonly tables of the SharedRuntime
+    fn attach_const_tables(&mut self, items: &[Item]) {
+        let mut tables = HashMap::new();
+        for item in items {
+            let Item::Const(item_const) = item else {
+                continue;
+            };
+            match ConstTable::from_item(item_const, &self.const_evaluator) {
+                Some(Ok(table)) => {
+                    tables.insert(table.name.clone(), table);
+                }
+                Some(Err(reason)) => self.errors.push(AnalysisError::InvalidConstTable {
+                    name: item_const.ident.to_string(),
+                    reason,
+                }),
+                None => {}
+            }
+        }
+        if tables.is_empty() {
+            return;
+        }
+
+        for udon_struct in self.parsed_structs.values_mut() {
+            let mut visitor = ConstTableUsageVisitor { tables: &tables, used: BTreeSet::new() };
+            for method in &udon_struct.methods {
+                if let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) {
+                    visitor.visit_block(&block);
+                }
+            }
+            udon_struct.const_tables = visitor.used.iter().map(|name| tables[name].clone()).collect();
+        }
+    }
+
+    /// Get the name of a plain struct if the type refers to one
+    fn embedded_type_name(&self, ty: &Type) -> Option<String> {
+        if let Type::Path(type_path) = ty {
+            let segment = type_path.path.segments.last()?;
+            let name = segment.ident.to_string();
+            if segment.arguments.is_empty() && self.plain_structs.contains_key(&name) {
+                return Some(name);
+            }
+        }
+        None
+    }
+
+    /// Flatten an embedded plain struct field into the owning behavior.
+    ///
+    /// Each field and method of the embedded struct is added to the behavior
+    /// under a `<field>_<member>` name, and attributes on the embedding field
+    /// (such as `#[udon_sync]`) are applied to every flattened field.
+    ///
+    /// A synced `#[udon_sync_blob]` struct instead syncs one `<field>_blob`
+    /// byte array that its flattened fields are packed into.
+    fn flatten_embedded_field(&mut self, udon_struct: &mut UdonBehaviourStruct, field: &Field, type_name: &str) -> AnalysisResult<()> {
+        let field_name = field.ident.as_ref()
+            .ok_or_else(|| AnalysisError::ParseError {
+                message: "Field must have a name".to_string(),
+            })?
+            .to_string();
+
+        let plain_source = self.plain_structs.get(type_name).cloned()
+            .ok_or_else(|| AnalysisError::ParseError {
+                message: format!("Unknown embedded struct '{}'", type_name),
+            })?;
+        let plain_struct: ItemStruct = syn::parse_str(&plain_source)
+            .map_err(|e| AnalysisError::ParseError { message: e.to_string() })?;
+        let mut embedded = EmbeddedStruct::new(field_name.clone(), type_name.to_string());
+
+        let mut outer_attributes = Vec::new();
+        for attr in field.attrs.iter().filter(|attr| !is_ignored_field_attribute(attr)) {
+            match self.parse_field_attribute(attr) {
+                Ok(field_attr) => outer_attributes.push(field_attr),
+                Err(error) => self.errors.push(error),
+            }
+        }
+
+        let is_blob = outer_attributes.contains(&FieldAttribute::UdonSync)
+            && plain_struct.attrs.iter().any(|attr| attr.path().is_ident("udon_sync_blob"));
+        let mut sync_blob = SyncBlob { field_name: field_name.clone(), fields: Vec::new() };
+        if is_blob {
+            outer_attributes.retain(|attr| *attr != FieldAttribute::UdonSync);
+        }
+
+        if let Fields::Named(fields_named) = &plain_struct.fields {
+            for inner_field in &fields_named.named {
+                let mut struct_field = self.analyze_field(type_name, inner_field)?;
+                embedded.field_names.push(struct_field.name.clone());
+                struct_field.name = embedded.mangle(&struct_field.name);
+                for attr in &outer_attributes {
+                    if !struct_field.attributes.contains(attr) {
+                        struct_field.add_attribute(attr.clone());
+                    }
+                }
+                if is_blob {
+                    if blob_type_size(&struct_field.field_type).is_none() {
+                        return Err(AnalysisError::InvalidFieldAttribute {
+                            struct_name: type_name.to_string(),
+                            field_name: embedded.field_names.last().cloned().unwrap_or_default(),
+                            attribute: "udon_sync_blob".to_string(),
+                            reason: format!(
+                                "{:?} has no fixed size; sync blob fields must be bool, integers, f32 or f64",
+                                struct_field.field_type
+                            ),
+                        });
+                    }
+                    sync_blob.fields.push((struct_field.name.clone(), struct_field.field_type.clone()));
+                }
+                udon_struct.add_field(struct_field);
+            }
+        }
+
+        if is_blob {
+            // The blob takes the place of the embedding field, including its visibility
+            let mut blob_field = StructField::new(sync_blob.blob_field_name(), RustType::Vec(Box::new(RustType::U8)));
+            if matches!(field.vis, syn::Visibility::Public(_)) {
+                blob_field.set_visibility(Visibility::Public);
+            }
+            for attr in outer_attributes.iter().chain([&FieldAttribute::UdonSync]) {
+                blob_field.add_attribute(attr.clone());
+            }
+            blob_field.set_default_value(format!("new byte[{}]", sync_blob.byte_len()));
+            udon_struct.add_field(blob_field);
+            udon_struct.sync_blobs.push(sync_blob);
+        }
+
+        let impl_methods = self.plain_impls.get(type_name).cloned().unwrap_or_default();
+        for method_source in &impl_methods {
+            let impl_fn: ImplItemFn = syn::parse_str(method_source)
+                .map_err(|e| AnalysisError::ParseError { message: e.to_string() })?;
+            let mut struct_method = self.analyze_method(type_name, &impl_fn)?;
+            embedded.method_names.push(struct_method.name.clone());
+            struct_method.name = embedded.mangle(&struct_method.name);
+            struct_method.set_visibility(Visibility::Private);
+            udon_struct.add_method(struct_method);
+        }
+
+        udon_struct.add_embedded_struct(embedded);
+        Ok(())
     }
 
     /// Analyze a method implementation
@@ -577,8 +1325,10 @@ impl StructAnalyzer {
         let method_name = impl_fn.sig.ident.to_string();
 
         // Parse return type
+        // A `Result<(), E>` returns its error as a string, null on success
         let return_type = match &impl_fn.sig.output {
             ReturnType::Default => RustType::Unit,
+            ReturnType::Type(_, ty) if is_unit_result(ty) => RustType::String,
             ReturnType::Type(_, ty) => self.parse_type(ty)?,
         };
 
@@ -586,7 +1336,18 @@ impl StructAnalyzer {
 
         // Parse method attributes
         for attr in &impl_fn.attrs {
-            if let Ok(method_attr) = self.parse_method_attribute(attr) {
+            if attr.path().is_ident("udon_network_event") {
+                let network_event = self.parse_network_event_attribute(struct_name, impl_fn, attr)?;
+                struct_method.add_attribute(network_event);
+            } else if attr.path().is_ident("udon_event") && impl_fn.sig.inputs.iter().any(|input| matches!(input, FnArg::Typed(_))) {
+                return Err(AnalysisError::InvalidMethodSignature {
+                    struct_name: struct_name.to_string(),
+                    method_name,
+                    reason: "#[udon_event] methods are invoked by name through SendCustomEvent and cannot take parameters; \
+                        set a #[udon_public] field on the behavior before sending the event, or use #[udon_network_event] \
+                        for events raised over the network".to_string(),
+                });
+            } else if let Ok(method_attr) = self.parse_method_attribute(attr) {
                 struct_method.add_attribute(method_attr);
             }
         }
@@ -594,9 +1355,8 @@ impl StructAnalyzer {
         // Parse method parameters
         for input in &impl_fn.sig.inputs {
             match input {
-                FnArg::Receiver(_) => {
-                    // Skip self parameter
-                    continue;
+                FnArg::Receiver(receiver) => {
+                    struct_method.set_mutates_self(receiver.mutability.is_some());
                 }
                 FnArg::Typed(pat_type) => {
                     let param = self.analyze_method_parameter(pat_type)?;
@@ -610,6 +1370,13 @@ impl StructAnalyzer {
             struct_method.set_async(true);
         }
 
+        if matches!(impl_fn.vis, SynVisibility::Public(_)) {
+            struct_method.set_visibility(Visibility::Public);
+        }
+
+        let block = &impl_fn.block;
+        struct_method.set_body(quote::quote!(#block).to_string());
+
         Ok(struct_method)
     }
 
@@ -639,14 +1406,53 @@ impl StructAnalyzer {
         })
     }
 
-    /// Analyze a method parameter
-    fn analyze_method_parameter(&self, pat_type: &PatType) -> AnalysisResult<MethodParameter> {
-        let param_name = if let Pat::Ident(pat_ident) = &*pat_type.pat {
-            pat_ident.ident.to_string()
-        } else {
-            return Err(AnalysisError::ParseError {
-                message: "Complex parameter patterns are not supported".to_string(),
-            });
+    /// Parse `#[udon_network_event(target = "All", name = "Event")]`
+    ///
+    /// Both arguments are optional; the target defaults to `All` and the name
+    /// to the PascalCase method name.
+    fn parse_network_event_attribute(&self, struct_name: &str, impl_fn: &ImplItemFn, attr: &Attribute) -> AnalysisResult<MethodAttribute> {
+        let method_name = impl_fn.sig.ident.to_string();
+        let invalid = |reason: String| AnalysisError::InvalidMethodSignature {
+            struct_name: struct_name.to_string(),
+            method_name: method_name.clone(),
+            reason,
+        };
+
+        let mut target = NetworkEventTarget::All;
+        let mut name = to_pascal_case(&method_name);
+        if let Meta::List(_) = &attr.meta {
+            attr.parse_nested_meta(|meta| {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("target") {
+                    target = NetworkEventTarget::parse(&value.value())
+                        .ok_or_else(|| meta.error("expected target \"All\", \"Others\" or \"Owner\""))?;
+                } else if meta.path.is_ident("name") {
+                    name = value.value();
+                } else {
+                    return Err(meta.error("expected `target` or `name`"));
+                }
+                Ok(())
+            }).map_err(|e| invalid(format!("invalid #[udon_network_event] arguments: {}", e)))?;
+        }
+
+        if !is_valid_csharp_identifier(&name) {
+            return Err(invalid(format!("network event name '{}' is not a valid C# identifier", name)));
+        }
+        if impl_fn.sig.inputs.iter().any(|input| matches!(input, FnArg::Typed(_))) {
+            return Err(invalid("network events are invoked by name and cannot take parameters".to_string()));
+        }
+
+        Ok(MethodAttribute::UdonNetworkEvent { name, target })
+    }
+
+    /// Analyze a method parameter
+    fn analyze_method_parameter(&self, pat_type: &PatType) -> AnalysisResult<MethodParameter> {
+        let param_name = if let Pat::Ident(pat_ident) = &*pat_type.pat {
+            pat_ident.ident.to_string()
+        } else {
+            return Err(AnalysisError::ParseError {
+                message: "Complex parameter patterns are not supported".to_string(),
+            });
         };
 
         let param_type = self.parse_type(&pat_type.ty)?;
@@ -662,6 +1468,51 @@ impl StructAnalyzer {
         Ok(parameter)
     }
 
+    /// Report `#[udon_forward]` targets that name no parsed behavior
+    fn check_forward_targets(&mut self) {
+        let mut errors = Vec::new();
+        for udon_struct in self.parsed_structs.values() {
+            for forward in EventForward::for_behavior(udon_struct) {
+                for target in forward.targets.iter().filter(|target| !self.parsed_structs.contains_key(*target)) {
+                    errors.push(AnalysisError::UnknownForwardTarget {
+                        behavior: udon_struct.name.clone(),
+                        event: forward.event.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
+        errors.sort_by_key(|error| error.to_string());
+        self.errors.extend(errors);
+    }
+
+    /// Count public variables per behavior and report behaviors that approach
+    /// or exceed the number of public variables Udon can expose
+    fn check_public_variable_counts(&mut self) {
+        let limit = self.public_variable_limit;
+        let mut behavior_names: Vec<String> = self.parsed_structs.keys().cloned().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let count = self.parsed_structs[&behavior_name].fields.iter()
+                .filter(|field| field.is_public())
+                .count();
+
+            if count > limit {
+                self.errors.push(AnalysisError::TooManyPublicVariables {
+                    struct_name: behavior_name,
+                    count,
+                    limit,
+                });
+            } else if count >= public_variable_warning_threshold(limit) {
+                self.warnings.push(format!(
+                    "Behavior '{}' exposes {} of at most {} public variables; consider grouping related configuration into a single synced or serialized blob field",
+                    behavior_name, count, limit
+                ));
+            }
+        }
+    }
+
     /// Validate UdonBehaviour trait implementations for all parsed structs
     fn validate_trait_implementations(&mut self) {
         let structs: Vec<UdonBehaviourStruct> = self.parsed_structs.values().cloned().collect();
@@ -777,6 +1628,438 @@ impl StructAnalyzer {
         }
     }
 
+    /// Warn about method bodies that read private fields of another behavior.
+    ///
+    /// Udon only exposes public and synced variables through
+    /// `GetProgramVariable`, so any other field reached via
+    /// `get_component::<T>()` can never be read.
+    fn lint_cross_behavior_access(&mut self) {
+        let private_fields: HashMap<String, HashSet<String>> = self.parsed_structs.values()
+            .map(|s| {
+                let fields = BehaviorInterface::from_behavior(s).fields.into_iter()
+                    .filter(|(_, info)| !info.is_readable())
+                    .map(|(name, _)| name)
+                    .collect();
+                (s.name.clone(), fields)
+            })
+            .collect();
+
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            for method in &udon_struct.methods {
+                let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) else {
+                    continue;
+                };
+
+                let mut visitor = CrossBehaviorAccessVisitor {
+                    private_fields: &private_fields,
+                    locals: BehaviorLocals::default(),
+                    accesses: Vec::new(),
+                };
+                visitor.visit_block(&block);
+
+                for (target, field) in visitor.accesses {
+                    findings.push((format!("{}::{}", behavior_name, method.name), format!(
+                        "Method '{}::{}' reads private field '{}' of behavior '{}'; Udon cannot access private variables of another behavior, mark the field `pub` or #[udon_public]",
+                        behavior_name, method.name, field, target
+                    )));
+                }
+            }
+        }
+
+        self.report_lint("cross_behavior_access", findings);
+    }
+
+    /// Warn about reads of another behavior's fields after calling one of its
+    /// methods through the same reference.
+    ///
+    /// The code reads as a `&mut` borrow whose mutation is visible right
+    /// away, but after lowering the call is a custom event sent to the other
+    /// behavior and the read a `GetProgramVariable`; there is no reference
+    /// that carries the change, and a networked or delayed event has not run
+    /// yet when the read happens.
+    fn lint_cross_behavior_mutation(&mut self) {
+        let behaviors: HashSet<String> = self.parsed_structs.keys().cloned().collect();
+        let mutating_methods: HashMap<String, HashSet<String>> = self.parsed_structs.iter()
+            .map(|(name, udon_struct)| (
+                name.clone(),
+                udon_struct.methods.iter().filter(|m| m.mutates_self).map(|m| m.name.clone()).collect(),
+            ))
+            .collect();
+
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            for method in &udon_struct.methods {
+                let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) else {
+                    continue;
+                };
+
+                let mut visitor = CrossBehaviorMutationVisitor {
+                    behaviors: &behaviors,
+                    mutating_methods: &mutating_methods,
+                    locals: BehaviorLocals::default(),
+                    called: HashMap::new(),
+                    reads: Vec::new(),
+                };
+                visitor.visit_block(&block);
+
+                for (target, called, field) in visitor.reads {
+                    findings.push((format!("{}::{}", behavior_name, method.name), format!(
+                        "Method '{}::{}' calls '{}::{}' and then reads '{}' through the same reference, expecting the call to have mutated it; \
+                         cross-behavior calls are custom events in Udon, not mutations through a reference, so have '{}' report its new state instead",
+                        behavior_name, method.name, target, called, field, target
+                    )));
+                }
+            }
+        }
+
+        self.report_lint("cross_behavior_mutation", findings);
+    }
+
+    /// Report calls to other behaviors whose parameter fields do not match the
+    /// receiving method's signature; see `EventSignatureRegistry`
+    fn check_event_call_arguments(&mut self) {
+        let mut behaviors: Vec<UdonBehaviourStruct> = self.parsed_structs.values().cloned().collect();
+        behaviors.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let registry = EventSignatureRegistry::from_behaviors(&behaviors);
+        for error in registry.check_calls(&behaviors) {
+            self.errors.push(AnalysisError::EventArgumentMismatch {
+                struct_name: error.caller_behavior,
+                method_name: error.caller_method,
+                reason: format!("'{}::{}' {}", error.target_behavior, error.target_method, error.mismatch),
+            });
+        }
+    }
+
+    /// Warn about synced-field writes that can run when the local player is not master.
+    ///
+    /// Only methods that check `is_master()` somewhere are linted; a write on a
+    /// path the guard does not cover means the guard is misplaced.
+    fn lint_master_guard_placement(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            let synced_fields: HashSet<String> = udon_struct.get_sync_fields().iter()
+                .map(|f| f.name.clone())
+                .collect();
+            if synced_fields.is_empty() {
+                continue;
+            }
+
+            for method in &udon_struct.methods {
+                let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) else {
+                    continue;
+                };
+
+                for field in unguarded_synced_writes(&block, &synced_fields) {
+                    findings.push((format!("{}::{}", behavior_name, method.name), format!(
+                        "Method '{}::{}' writes synced field '{}' on a path where `is_master()` may be false, but checks `is_master()` elsewhere; move the master guard before the write",
+                        behavior_name, method.name, field
+                    )));
+                }
+            }
+        }
+
+        self.report_lint("master_guard_placement", findings);
+    }
+
+    /// Warn about synced state initialized in `start` based on `is_master()`.
+    ///
+    /// Master status is not always settled when `Start` runs for late
+    /// joiners, so initialization driven by it is better deferred.
+    fn lint_master_dependent_start(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            let synced_fields: HashSet<String> = udon_struct.get_sync_fields().iter()
+                .map(|f| f.name.clone())
+                .collect();
+            if synced_fields.is_empty() {
+                continue;
+            }
+
+            let Some(block) = udon_struct.methods.iter()
+                .find(|m| m.name == "start")
+                .and_then(|m| m.body.as_ref())
+                .and_then(|b| syn::parse_str::<syn::Block>(b).ok())
+            else {
+                continue;
+            };
+
+            for field in master_dependent_synced_writes(&block, &synced_fields) {
+                findings.push((format!("{}::start", behavior_name), format!(
+                    "Method '{}::start' initializes synced field '{}' based on `is_master()`, which may be unreliable in Start for late joiners; consider deferring it to the first `update` or `on_player_joined`",
+                    behavior_name, field
+                )));
+            }
+        }
+
+        self.report_lint("master_dependent_start", findings);
+    }
+
+    /// Warn about divisions by numeric fields that start at zero and are not
+    /// checked for zero on the path to the division
+    fn lint_unguarded_division(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            let zero_fields: HashSet<String> = udon_struct.fields.iter()
+                .filter(|f| is_numeric_type(&f.field_type) && starts_at_zero(f.default_value.as_deref()))
+                .map(|f| f.name.clone())
+                .collect();
+            if zero_fields.is_empty() {
+                continue;
+            }
+
+            for method in &udon_struct.methods {
+                let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) else {
+                    continue;
+                };
+
+                for field in unguarded_divisions(&block, &zero_fields) {
+                    findings.push((format!("{}::{}", behavior_name, method.name), format!(
+                        "Method '{}::{}' divides by '{}', which starts at 0 and is not checked for zero first; guard the division with `if self.{} > 0` to avoid a halted behavior or NaN/Infinity in synced values",
+                        behavior_name, method.name, field, field
+                    )));
+                }
+            }
+        }
+
+        self.report_lint("unguarded_division", findings);
+    }
+
+    /// Warn about `self.field.remove(0)` in methods reachable from the frame
+    /// events, which shifts the whole array every frame once lowered
+    fn lint_front_removal_in_update(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            let hot_methods = update_reachable_methods(udon_struct);
+
+            for method in udon_struct.methods.iter().filter(|m| hot_methods.contains(&m.name)) {
+                let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) else {
+                    continue;
+                };
+
+                for field in front_removals(&block) {
+                    findings.push((format!("{}::{}", behavior_name, method.name), format!(
+                        "Method '{}::{}' runs every frame and calls `self.{}.remove(0)`, which shifts every remaining element once lowered to an array; use a fixed-size ring buffer with a head index instead",
+                        behavior_name, method.name, field
+                    )));
+                }
+            }
+        }
+
+        self.report_lint("front_removal_in_update", findings);
+    }
+
+    /// Advise against synced `String` fields that only ever hold a small fixed
+    /// set of literals or enum names, which an `i32` would sync far cheaper
+    fn lint_string_enum_sync(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            let string_fields: HashSet<String> = udon_struct.get_sync_fields().iter()
+                .filter(|f| f.field_type == RustType::String)
+                .map(|f| f.name.clone())
+                .collect();
+            if string_fields.is_empty() {
+                continue;
+            }
+
+            let mut visitor = StringAssignmentVisitor {
+                fields: &string_fields,
+                values: BTreeMap::new(),
+                variable: HashSet::new(),
+            };
+            for method in &udon_struct.methods {
+                if let Some(block) = method.body.as_ref().and_then(|b| syn::parse_str::<syn::Block>(b).ok()) {
+                    visitor.visit_block(&block);
+                }
+            }
+
+            for (field, values) in &visitor.values {
+                if visitor.variable.contains(field) || values.len() > STRING_ENUM_MAX_VALUES {
+                    continue;
+                }
+                let listed: Vec<String> = values.iter().map(|v| format!("\"{}\"", v)).collect();
+                findings.push((format!("{}::{}", behavior_name, field), format!(
+                    "Synced String field '{}::{}' is only ever assigned {} fixed value(s) ({}); sync an i32 or enum instead to save bandwidth and rule out misspelled states",
+                    behavior_name, field, values.len(), listed.join(", ")
+                )));
+            }
+        }
+
+        self.report_lint("string_enum_sync", findings);
+    }
+
+    /// Warn about `initialized`-style guards read in the frame events that
+    /// `start` never sets to `true`, which would keep `update` from ever running
+    fn lint_unset_init_guard(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            for field in unset_init_guards(&self.parsed_structs[behavior_name]) {
+                findings.push((format!("{}::{}", behavior_name, field), format!(
+                    "Field '{}::{}' guards the frame events but is never set to true in `start`, so the guarded code never runs; set `self.{} = true` once initialization is done",
+                    behavior_name, field, field
+                )));
+            }
+        }
+
+        self.report_lint("unset_init_guard", findings);
+    }
+
+    /// Warn about `Time::delta_time()` reads in methods that only run from
+    /// `fixed_update`, which should use `Time::fixed_delta_time()`
+    fn lint_delta_time_in_fixed_update(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            for method in fixed_update_delta_time_reads(&self.parsed_structs[behavior_name]) {
+                findings.push((format!("{}::{}", behavior_name, method), format!(
+                    "Method '{}::{}' runs in FixedUpdate but reads `Time::delta_time()`; use `Time::fixed_delta_time()` so the code steps with physics rather than with the frame rate",
+                    behavior_name, method
+                )));
+            }
+        }
+
+        self.report_lint("delta_time_in_fixed_update", findings);
+    }
+
+    /// Warn about `GameObject::find` calls whose name is computed at runtime,
+    /// which are slow and break silently when objects are renamed
+    fn lint_dynamic_find(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            for find in dynamic_finds(&self.parsed_structs[behavior_name]) {
+                findings.push((format!("{}::{}", behavior_name, find.method), format!(
+                    "Method '{}::{}' calls `GameObject::find` with a computed name{}; Find searches the whole scene and returns nothing once an object is renamed, so collect the references once in `start` or assign them in the inspector",
+                    behavior_name, find.method, if find.in_loop { " inside a loop" } else { "" }
+                )));
+            }
+        }
+
+        self.report_lint("dynamic_find", findings);
+    }
+
+    /// Warn about behaviors with non-public fields but neither a `new()`
+    /// constructor nor a `Default` impl. Initial values of fields the
+    /// inspector does not set come from the constructor, so without one those
+    /// fields are not initialized in `Start`.
+    fn lint_missing_constructor(&mut self) {
+        let mut findings = Vec::new();
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        for behavior_name in behavior_names {
+            if self.constructed.contains(behavior_name) {
+                continue;
+            }
+            let private_fields: Vec<&str> = self.parsed_structs[behavior_name].fields.iter()
+                .filter(|field| !field.is_public() && !field.has_serialize_field_attribute())
+                .map(|field| field.name.as_str())
+                .collect();
+            if private_fields.is_empty() {
+                continue;
+            }
+            findings.push((behavior_name.clone(), format!(
+                "Behavior '{}' has non-public fields ({}) but no `new()` or `Default` impl, so they are not initialized in Start; add a constructor with their initial values or derive `Default`",
+                behavior_name, private_fields.join(", ")
+            )));
+        }
+
+        self.report_lint("missing_constructor", findings);
+    }
+
+    /// Warn about constructor initializers that cannot be evaluated in `Start`,
+    /// since the field would silently start out at its type's default
+    fn lint_non_constant_initializer(&mut self) {
+        let findings = self.non_constant_initializers.iter()
+            .map(|(behavior_name, fields)| (behavior_name.clone(), format!(
+                "Behavior '{}' initializes {} in its constructor with a value that is not constant and cannot be evaluated in Start, so it is not initialized; use a constant initial value or assign it in `start`",
+                behavior_name,
+                fields.iter().map(|field| format!("'{}'", field)).collect::<Vec<_>>().join(", ")
+            )))
+            .collect();
+
+        self.report_lint("non_constant_initializer", findings);
+    }
+
+    /// Reject serde calls in behavior methods, other than a `#[udon_json]`
+    /// behavior serializing itself, which is lowered to its `ToJson` method
+    fn check_serde_usage(&mut self) {
+        let mut behavior_names: Vec<&String> = self.parsed_structs.keys().collect();
+        behavior_names.sort();
+
+        let mut errors = Vec::new();
+        for behavior_name in behavior_names {
+            let udon_struct = &self.parsed_structs[behavior_name];
+            for serde_use in serde_uses(udon_struct).into_iter().filter(|u| !u.routed) {
+                errors.push(AnalysisError::UnsupportedSerde {
+                    struct_name: behavior_name.clone(),
+                    method_name: serde_use.method,
+                    call: serde_use.call,
+                    udon_json: udon_struct.is_json_serializable(),
+                });
+            }
+        }
+        self.errors.extend(errors);
+    }
+
+    /// Report the findings of a lint at its configured level
+    ///
+    /// Findings are `(item, message)` pairs, the item being the
+    /// `Behavior::member` the finding is about. Findings on items that allow
+    /// the lint, or whose behavior does, are dropped.
+    fn report_lint(&mut self, lint: &str, findings: Vec<(String, String)>) {
+        let level = self.lint_levels.get(lint).copied().unwrap_or_default();
+        for (item, message) in findings {
+            let behavior = item.split("::").next().unwrap_or_default();
+            let allowed = [item.as_str(), behavior].iter()
+                .any(|key| self.lint_allows.get(*key).is_some_and(|lints| lints.contains(lint)));
+            if allowed {
+                continue;
+            }
+            match level {
+                LintLevel::Allow => {}
+                LintLevel::Warn => self.warnings.push(message),
+                LintLevel::Deny => self.errors.push(AnalysisError::DeniedLint { lint: lint.to_string(), message }),
+            }
+        }
+    }
+
     /// Convert dependency error to analysis error
     fn convert_dependency_error(&self, dep_error: DependencyError) -> AnalysisError {
         match dep_error {
@@ -819,143 +2102,1257 @@ impl Default for StructAnalyzer {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use syn::parse_quote;
+/// Which fields and sibling methods each method of a behavior touches through `self`
+#[derive(Debug, Clone, Default)]
+pub struct FieldUsageGraph {
+    /// Fields read or written by each method
+    pub fields_by_method: BTreeMap<String, BTreeSet<String>>,
+    /// Methods of the same behavior called by each method
+    pub calls_by_method: BTreeMap<String, BTreeSet<String>>,
+}
 
-    #[test]
-    fn test_struct_analyzer_creation() {
-        let analyzer = StructAnalyzer::new();
-        assert_eq!(analyzer.parsed_structs.len(), 0);
-        assert_eq!(analyzer.errors.len(), 0);
-    }
+impl FieldUsageGraph {
+    /// Build the graph from the method bodies of a behavior
+    pub fn from_behavior(udon_struct: &UdonBehaviourStruct) -> Self {
+        let fields: HashSet<&str> = udon_struct.fields.iter().map(|f| f.name.as_str()).collect();
+        let methods: HashSet<&str> = udon_struct.methods.iter().map(|m| m.name.as_str()).collect();
+        let mut graph = Self::default();
 
-    #[test]
-    fn test_has_udon_behaviour_derive() {
-        let analyzer = StructAnalyzer::new();
-        
-        let attrs: Vec<Attribute> = vec![
-            parse_quote!(#[derive(UdonBehaviour)]),
-        ];
-        
-        assert!(analyzer.has_udon_behaviour_derive(&attrs));
-        
-        let attrs_without: Vec<Attribute> = vec![
-            parse_quote!(#[derive(Debug)]),
-        ];
-        
-        assert!(!analyzer.has_udon_behaviour_derive(&attrs_without));
+        for method in &udon_struct.methods {
+            let mut visitor = SelfUsageVisitor {
+                fields: &fields,
+                methods: &methods,
+                used_fields: BTreeSet::new(),
+                called_methods: BTreeSet::new(),
+            };
+            if let Some(block) = method.body.as_deref().and_then(|body| syn::parse_str::<syn::Block>(body).ok()) {
+                visitor.visit_block(&block);
+            }
+            visitor.called_methods.remove(&method.name);
+            graph.fields_by_method.insert(method.name.clone(), visitor.used_fields);
+            graph.calls_by_method.insert(method.name.clone(), visitor.called_methods);
+        }
+
+        graph
     }
 
-    #[test]
-    fn test_parse_basic_types() {
-        let analyzer = StructAnalyzer::new();
-        
-        let ty: Type = parse_quote!(i32);
-        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::I32);
-        
-        let ty: Type = parse_quote!(String);
-        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::String);
-        
-        let ty: Type = parse_quote!(bool);
-        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::Bool);
+    /// Get the fields a method uses
+    pub fn fields_used_by(&self, method: &str) -> impl Iterator<Item = &String> {
+        self.fields_by_method.get(method).into_iter().flatten()
     }
 
-    #[test]
-    fn test_parse_unity_types() {
-        let analyzer = StructAnalyzer::new();
-        
-        let ty: Type = parse_quote!(Vector3);
-        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::Vector3);
-        
-        let ty: Type = parse_quote!(GameObject);
-        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::GameObject);
-        
-        let ty: Type = parse_quote!(unity::GameObject);
-        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::GameObject);
+    /// Get the sibling methods a method calls
+    pub fn methods_called_by(&self, method: &str) -> impl Iterator<Item = &String> {
+        self.calls_by_method.get(method).into_iter().flatten()
     }
+}
 
-    #[test]
-    fn test_parse_generic_types() {
-        let analyzer = StructAnalyzer::new();
-        
-        let ty: Type = parse_quote!(Option<i32>);
-        if let RustType::Option(inner) = analyzer.parse_type(&ty).unwrap() {
-            assert_eq!(*inner, RustType::I32);
-        } else {
-            panic!("Expected Option type");
-        }
-        
-        let ty: Type = parse_quote!(Vec<String>);
-        if let RustType::Vec(inner) = analyzer.parse_type(&ty).unwrap() {
-            assert_eq!(*inner, RustType::String);
-        } else {
-            panic!("Expected Vec type");
+/// Collects `self.field` accesses and `self.method()` calls in a method body
+struct SelfUsageVisitor<'a> {
+    fields: &'a HashSet<&'a str>,
+    methods: &'a HashSet<&'a str>,
+    used_fields: BTreeSet<String>,
+    called_methods: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for SelfUsageVisitor<'_> {
+    fn visit_expr_field(&mut self, field: &'ast syn::ExprField) {
+        if let (syn::Expr::Path(base), syn::Member::Named(member)) = (&*field.base, &field.member) {
+            let member = member.to_string();
+            if base.path.is_ident("self") && self.fields.contains(member.as_str()) {
+                self.used_fields.insert(member);
+            }
         }
+        syn::visit::visit_expr_field(self, field);
     }
 
-    #[test]
-    fn test_analyze_simple_struct() {
-        let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct TestBehavior {
-                    #[udon_public]
-                    pub player_count: i32,
-                    #[udon_sync]
-                    pub game_state: String,
-                }
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if let syn::Expr::Path(receiver) = &*call.receiver {
+            let method = call.method.to_string();
+            if receiver.path.is_ident("self") && self.methods.contains(method.as_str()) {
+                self.called_methods.insert(method);
             }
-        ];
-        
-        let result = analyzer.analyze_module(&items).unwrap();
-        assert_eq!(result.len(), 1);
-        
-        let behavior = &result[0];
-        assert_eq!(behavior.name, "TestBehavior");
-        assert_eq!(behavior.fields.len(), 2);
-        
-        let player_count_field = &behavior.fields[0];
-        assert_eq!(player_count_field.name, "player_count");
-        assert_eq!(player_count_field.field_type, RustType::I32);
-        assert!(player_count_field.attributes.contains(&FieldAttribute::UdonPublic));
+        }
+        syn::visit::visit_expr_method_call(self, call);
     }
+}
 
-    #[test]
+/// Finds field accesses on locals bound by `get_component::<T>()` that target
+/// private fields of another behavior
+struct CrossBehaviorAccessVisitor<'a> {
+    /// Private field names keyed by behavior name
+    private_fields: &'a HashMap<String, HashSet<String>>,
+    /// Locals bound to another behavior
+    locals: BehaviorLocals,
+    /// Offending accesses as `(behavior, field)`
+    accesses: Vec<(String, String)>,
+}
+
+impl CrossBehaviorAccessVisitor<'_> {
+    /// Remember a binding if its initializer looks up another behavior
+    fn bind(&mut self, pat: &Pat, init: &syn::Expr) {
+        let private_fields = self.private_fields;
+        self.locals.bind(pat, init, |behavior| private_fields.contains_key(behavior));
+    }
+}
+
+impl<'ast> Visit<'ast> for CrossBehaviorAccessVisitor<'_> {
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        if let Some(init) = &local.init {
+            self.bind(&local.pat, &init.expr);
+        }
+        syn::visit::visit_local(self, local);
+    }
+
+    fn visit_expr_let(&mut self, expr_let: &'ast syn::ExprLet) {
+        self.bind(&expr_let.pat, &expr_let.expr);
+        syn::visit::visit_expr_let(self, expr_let);
+    }
+
+    fn visit_expr_field(&mut self, field: &'ast syn::ExprField) {
+        if let (Some(local), syn::Member::Named(member)) = (self.locals.local(&field.base), &field.member) {
+            let behavior = self.locals.behavior(&local).unwrap_or_default();
+            let member = member.to_string();
+            if self.private_fields[behavior].contains(&member) {
+                self.accesses.push((behavior.to_string(), member));
+            }
+        }
+        syn::visit::visit_expr_field(self, field);
+    }
+}
+
+/// Finds fields of another behavior read after a method of it was called
+/// through the same local
+struct CrossBehaviorMutationVisitor<'a> {
+    /// Names of the behaviors of the module
+    behaviors: &'a HashSet<String>,
+    /// Methods taking `&mut self` of each behavior; calls to other methods
+    /// cannot be expected to mutate anything
+    mutating_methods: &'a HashMap<String, HashSet<String>>,
+    /// Locals bound to another behavior
+    locals: BehaviorLocals,
+    /// Locals a mutating method was called on, mapped to the first one called
+    called: HashMap<String, String>,
+    /// Reads after a call as `(behavior, method called, field)`
+    reads: Vec<(String, String, String)>,
+}
+
+impl CrossBehaviorMutationVisitor<'_> {
+    /// Remember a binding if its initializer looks up another behavior
+    fn bind(&mut self, pat: &Pat, init: &syn::Expr) {
+        let behaviors = self.behaviors;
+        self.locals.bind(pat, init, |behavior| behaviors.contains(behavior));
+    }
+}
+
+impl<'ast> Visit<'ast> for CrossBehaviorMutationVisitor<'_> {
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        if let Some(init) = &local.init {
+            self.bind(&local.pat, &init.expr);
+        }
+        syn::visit::visit_local(self, local);
+    }
+
+    fn visit_expr_let(&mut self, expr_let: &'ast syn::ExprLet) {
+        self.bind(&expr_let.pat, &expr_let.expr);
+        syn::visit::visit_expr_let(self, expr_let);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        syn::visit::visit_expr_method_call(self, call);
+        if let Some(local) = self.locals.local(&call.receiver) {
+            let method = call.method.to_string();
+            let mutating = self.locals.behavior(&local)
+                .and_then(|behavior| self.mutating_methods.get(behavior))
+                .is_some_and(|methods| methods.contains(&method));
+            if mutating {
+                self.called.entry(local).or_insert(method);
+            }
+        }
+    }
+
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        // Writing a field is not a read that expects a mutation
+        if let syn::Expr::Field(field) = &*assign.left {
+            if self.locals.local(&field.base).is_some() {
+                self.visit_expr(&assign.right);
+                return;
+            }
+        }
+        syn::visit::visit_expr_assign(self, assign);
+    }
+
+    fn visit_expr_field(&mut self, field: &'ast syn::ExprField) {
+        if let (Some(local), syn::Member::Named(member)) = (self.locals.local(&field.base), &field.member) {
+            if let Some(called) = self.called.get(&local) {
+                let read = (self.locals.behavior(&local).unwrap_or_default().to_string(), called.clone(), member.to_string());
+                if !self.reads.contains(&read) {
+                    self.reads.push(read);
+                }
+            }
+        }
+        syn::visit::visit_expr_field(self, field);
+    }
+}
+
+/// Largest number of distinct values a synced string may hold to be flagged as an enum
+const STRING_ENUM_MAX_VALUES: usize = 16;
+
+/// Collects the values assigned to `self` string fields, separating fixed
+/// literals and enum names from anything computed at runtime
+struct StringAssignmentVisitor<'a> {
+    /// Synced string fields to track
+    fields: &'a HashSet<String>,
+    /// Fixed values assigned to each field
+    values: BTreeMap<String, BTreeSet<String>>,
+    /// Fields assigned or mutated with a runtime value
+    variable: HashSet<String>,
+}
+
+impl StringAssignmentVisitor<'_> {
+    /// Get the tracked `self` field an expression refers to
+    fn tracked_field(&self, expr: &syn::Expr) -> Option<String> {
+        match expr {
+            syn::Expr::Field(field) => match (&*field.base, &field.member) {
+                (syn::Expr::Path(base), syn::Member::Named(member))
+                    if base.path.is_ident("self") && self.fields.contains(&member.to_string()) =>
+                {
+                    Some(member.to_string())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for StringAssignmentVisitor<'_> {
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        if let Some(field) = self.tracked_field(&assign.left) {
+            match fixed_string_value(&assign.right) {
+                Some(value) => {
+                    self.values.entry(field).or_default().insert(value);
+                }
+                None => {
+                    self.variable.insert(field);
+                }
+            }
+        }
+        syn::visit::visit_expr_assign(self, assign);
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast syn::ExprBinary) {
+        // `self.log += "..."` builds a runtime value
+        if matches!(binary.op, syn::BinOp::AddAssign(_)) {
+            if let Some(field) = self.tracked_field(&binary.left) {
+                self.variable.insert(field);
+            }
+        }
+        syn::visit::visit_expr_binary(self, binary);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if matches!(call.method.to_string().as_str(), "push_str" | "push" | "insert_str" | "clear" | "truncate") {
+            if let Some(field) = self.tracked_field(&call.receiver) {
+                self.variable.insert(field);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Get the fixed value a string expression always produces: a literal, or
+/// the name of an enum variant converted with `to_string()`/`format!("{:?}")`
+fn fixed_string_value(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: Lit::Str(s), .. }) => Some(s.value()),
+        syn::Expr::Paren(paren) => fixed_string_value(&paren.expr),
+        syn::Expr::MethodCall(call)
+            if call.args.is_empty() && matches!(call.method.to_string().as_str(), "to_string" | "to_owned" | "into") =>
+        {
+            fixed_string_value(&call.receiver).or_else(|| enum_variant_name(&call.receiver))
+        }
+        syn::Expr::Call(call) if call.args.len() == 1 => match &*call.func {
+            syn::Expr::Path(func) if func.path.segments.len() == 2 && func.path.segments[0].ident == "String" => {
+                fixed_string_value(&call.args[0])
+            }
+            _ => None,
+        },
+        syn::Expr::Macro(mac) if mac.mac.path.is_ident("format") => {
+            let args = mac.mac.parse_body_with(Punctuated::<syn::Expr, Token![,]>::parse_terminated).ok()?;
+            match (args.first(), args.len()) {
+                (Some(syn::Expr::Lit(syn::ExprLit { lit: Lit::Str(fmt), .. })), 1) => Some(fmt.value()),
+                (Some(syn::Expr::Lit(syn::ExprLit { lit: Lit::Str(fmt), .. })), 2)
+                    if fmt.value() == "{:?}" || fmt.value() == "{}" => enum_variant_name(&args[1]),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Get `Enum::Variant` as a name if the expression is a unit enum variant path
+fn enum_variant_name(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(path) if path.path.segments.len() >= 2 => {
+            let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let [.., enum_name, variant] = segments.as_slice() else {
+                return None;
+            };
+            let is_type_name = |name: &str| name.starts_with(|c: char| c.is_ascii_uppercase());
+            (is_type_name(enum_name) && is_type_name(variant)).then(|| format!("{}::{}", enum_name, variant))
+        }
+        syn::Expr::Paren(paren) => enum_variant_name(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Attributes of a field that carry nothing for the generated C#: doc
+/// comments and `#[udon_allow]`, which is read by `collect_lint_allows`
+fn is_ignored_field_attribute(attr: &Attribute) -> bool {
+    attr.path().is_ident("doc") || attr.path().is_ident("udon_allow")
+}
+
+/// Check for an inspector attribute in either its shorthand (`#[header]`) or
+/// `udon_`-prefixed (`#[udon_header]`) spelling
+fn is_inspector_attribute(attr: &Attribute, name: &str) -> bool {
+    let path = attr.path();
+    path.is_ident(name) || path.get_ident().is_some_and(|ident| *ident == format!("udon_{}", name))
+}
+
+/// Parse `#[udon_range(min, max)]`, keeping both bounds as literals
+fn parse_range_attribute(attr: &Attribute) -> AnalysisResult<FieldAttribute> {
+    let invalid = |reason: String| AnalysisError::ParseError {
+        message: format!("Invalid #[udon_range] arguments: {}", reason),
+    };
+    let bounds = attr
+        .parse_args_with(syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        .map_err(|e| invalid(e.to_string()))?;
+    let bounds: Vec<String> = bounds.iter()
+        .map(|bound| quote::quote!(#bound).to_string().replace(' ', "").trim_end_matches("f32").to_string())
+        .collect();
+    let [min, max] = <[String; 2]>::try_from(bounds)
+        .map_err(|_| invalid("expected `min, max`".to_string()))?;
+    match (min.parse::<f64>(), max.parse::<f64>()) {
+        (Ok(low), Ok(high)) if low >= high => Err(invalid(format!("{} is not below {}", min, max))),
+        (Ok(_), Ok(_)) => Ok(FieldAttribute::Range { min, max }),
+        _ => Err(invalid("bounds must be numeric literals".to_string())),
+    }
+}
+
+/// Describe a fieldless enum with integer discriminants; `None` for enums with data
+fn int_enum_definition(item_enum: &syn::ItemEnum) -> Option<IntEnum> {
+    let mut variants = Vec::new();
+    let mut next = 0i64;
+    let mut default_variant = None;
+
+    for variant in &item_enum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return None;
+        }
+        if let Some((_, discriminant)) = &variant.discriminant {
+            next = match discriminant {
+                syn::Expr::Lit(syn::ExprLit { lit: Lit::Int(int), .. }) => int.base10_parse().ok()?,
+                syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match &**expr {
+                    syn::Expr::Lit(syn::ExprLit { lit: Lit::Int(int), .. }) => -int.base10_parse::<i64>().ok()?,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+        }
+        let name = variant.ident.to_string();
+        if variant.attrs.iter().any(|attr| attr.path().is_ident("default")) {
+            default_variant = Some(name.clone());
+        }
+        variants.push((name, next));
+        next += 1;
+    }
+
+    let default_variant = default_variant.or_else(|| variants.first().map(|(name, _)| name.clone()))?;
+    Some(IntEnum { name: item_enum.ident.to_string(), variants, default_variant })
+}
+
+/// Collects the enums a method body converts from integers or matches on
+struct EnumUsageVisitor<'a> {
+    enums: &'a HashMap<String, IntEnum>,
+    converted: BTreeSet<String>,
+    matched: BTreeSet<String>,
+}
+
+impl EnumUsageVisitor<'_> {
+    /// Record the enums an arm pattern tests, including inside `|`
+    /// alternatives and the elements of tuple patterns
+    fn match_pattern(&mut self, pat: &syn::Pat) {
+        match pat {
+            syn::Pat::Or(or) => or.cases.iter().for_each(|case| self.match_pattern(case)),
+            syn::Pat::Tuple(tuple) => tuple.elems.iter().for_each(|elem| self.match_pattern(elem)),
+            syn::Pat::Paren(paren) => self.match_pattern(&paren.pat),
+            pat => {
+                if let Some((int_enum, _)) = enum_variant_pattern(pat, self.enums) {
+                    self.matched.insert(int_enum.name.clone());
+                }
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for EnumUsageVisitor<'_> {
+    fn visit_expr_match(&mut self, expr_match: &'ast syn::ExprMatch) {
+        for arm in &expr_match.arms {
+            self.match_pattern(&arm.pat);
+        }
+        syn::visit::visit_expr_match(self, expr_match);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(func) = &*call.func {
+            let segments: Vec<String> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            if let [enum_name, constructor] = segments.as_slice() {
+                if is_int_enum_constructor(constructor) && self.enums.contains_key(enum_name) {
+                    self.converted.insert(enum_name.clone());
+                }
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_cast(&mut self, cast: &'ast syn::ExprCast) {
+        if let Type::Path(type_path) = &*cast.ty {
+            if let Some(ident) = type_path.path.get_ident() {
+                if self.enums.contains_key(&ident.to_string()) {
+                    self.converted.insert(ident.to_string());
+                }
+            }
+        }
+        syn::visit::visit_expr_cast(self, cast);
+    }
+}
+
+/// Collects the enums with data a method body names in an expression or pattern
+struct PayloadEnumUsageVisitor<'a> {
+    enums: &'a HashMap<String, PayloadEnum>,
+    used: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for PayloadEnumUsageVisitor<'_> {
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        for segment in &path.segments {
+            let name = segment.ident.to_string();
+            if self.enums.contains_key(&name) {
+                self.used.insert(name);
+            }
+        }
+        syn::visit::visit_path(self, path);
+    }
+}
+
+/// Collects the module constant tables a method body refers to
+struct ConstTableUsageVisitor<'a> {
+    tables: &'a HashMap<String, ConstTable>,
+    used: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast> for ConstTableUsageVisitor<'_> {
+    fn visit_expr_path(&mut self, expr_path: &'ast syn::ExprPath) {
+        if let Some(ident) = expr_path.path.get_ident() {
+            if self.tables.contains_key(&ident.to_string()) {
+                self.used.insert(ident.to_string());
+            }
+        }
+    }
+}
+
+/// Whether an associated function name converts an integer to an enum
+pub fn is_int_enum_constructor(name: &str) -> bool {
+    matches!(name, "from_i32" | "from_int" | "from_u8" | "from_i64")
+}
+
+/// Whether an expression is `Default::default()`, `Self::default()` or `{struct_name}::default()`
+fn is_default_call(expr: &syn::Expr, struct_name: &str) -> bool {
+    let syn::Expr::Call(call) = expr else {
+        return false;
+    };
+    let syn::Expr::Path(path) = &*call.func else {
+        return false;
+    };
+    let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    call.args.is_empty()
+        && matches!(
+            segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice(),
+            [owner, "default"] if *owner == "Default" || *owner == "Self" || *owner == struct_name
+        )
+}
+
+/// Translate a collection constructor used as a field initializer into the
+/// C# that allocates it: `Vec::with_capacity(n)` becomes a backing array of
+/// `n` elements and `HashMap::new()` a `DataDictionary`
+fn collection_initializer(expr: &syn::Expr, field: &StructField, const_evaluator: &ConstEvaluator) -> Option<String> {
+    let syn::Expr::Call(call) = expr else {
+        return None;
+    };
+    let syn::Expr::Path(path) = &*call.func else {
+        return None;
+    };
+    let segments: Vec<String> = path.path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    match (&field.field_type, segments.as_slice(), call.args.len()) {
+        (RustType::Vec(inner), [.., "Vec" | "VecDeque", "with_capacity"], 1) => {
+            let Some(ConstValue::Int(capacity)) = const_evaluator.evaluate(&call.args[0]) else {
+                return None;
+            };
+            let element_type = RustToCSharpTypeMapper::new().map_type(inner).ok()?;
+            (capacity >= 0).then(|| format!("new {}[{}]", element_type, capacity))
+        }
+        (RustType::Vec(inner), [.., "Vec" | "VecDeque", "new" | "default"], 0) => {
+            let element_type = RustToCSharpTypeMapper::new().map_type(inner).ok()?;
+            Some(format!("new {}[0]", element_type))
+        }
+        (RustType::Custom(name), [.., "HashMap" | "DataDictionary", "new" | "default"], 0) if name == "DataDictionary" => {
+            Some("new DataDictionary()".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Whether a constructor initializer is `None` or a call producing its type's
+/// default value, such as `String::new()` or `Default::default()`
+fn is_default_initializer(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Path(path) => path.path.is_ident("None"),
+        syn::Expr::Call(call) if call.args.is_empty() => matches!(
+            &*call.func,
+            syn::Expr::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "new" || segment.ident == "default")
+        ),
+        _ => false,
+    }
+}
+
+/// Names of the parameters and `let` bindings of a constructor
+fn constructor_locals(impl_fn: &ImplItemFn) -> HashSet<String> {
+    let mut locals = HashSet::new();
+    for input in &impl_fn.sig.inputs {
+        if let syn::FnArg::Typed(pat_type) = input {
+            collect_pattern_idents(&pat_type.pat, &mut locals);
+        }
+    }
+    for stmt in &impl_fn.block.stmts {
+        if let syn::Stmt::Local(local) = stmt {
+            collect_pattern_idents(&local.pat, &mut locals);
+        }
+    }
+    locals
+}
+
+fn collect_pattern_idents(pat: &syn::Pat, idents: &mut HashSet<String>) {
+    match pat {
+        syn::Pat::Ident(pat_ident) => {
+            idents.insert(pat_ident.ident.to_string());
+        }
+        syn::Pat::Type(pat_type) => collect_pattern_idents(&pat_type.pat, idents),
+        syn::Pat::Tuple(tuple) => tuple.elems.iter().for_each(|elem| collect_pattern_idents(elem, idents)),
+        syn::Pat::TupleStruct(tuple) => tuple.elems.iter().for_each(|elem| collect_pattern_idents(elem, idents)),
+        syn::Pat::Struct(pat_struct) => pat_struct.fields.iter().for_each(|field| collect_pattern_idents(&field.pat, idents)),
+        _ => {}
+    }
+}
+
+/// Whether an expression reads any of the given local names
+fn reads_any(expr: &syn::Expr, locals: &HashSet<String>) -> bool {
+    struct LocalReads<'a> {
+        locals: &'a HashSet<String>,
+        found: bool,
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for LocalReads<'_> {
+        fn visit_expr_path(&mut self, path: &'ast syn::ExprPath) {
+            if path.qself.is_none() && path.path.get_ident().is_some_and(|ident| self.locals.contains(&ident.to_string())) {
+                self.found = true;
+            }
+        }
+    }
+
+    let mut reads = LocalReads { locals, found: false };
+    syn::visit::Visit::visit_expr(&mut reads, expr);
+    reads.found
+}
+
+/// Whether a struct derives `Default`
+fn derives_default(attrs: &[Attribute]) -> bool {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(meta_list) => Some(meta_list.tokens.to_string()),
+            _ => None,
+        })
+        .any(|derives| derives.split(',').any(|derive| derive.rsplit("::").next().map(str::trim) == Some("Default")))
+}
+
+/// Check if a function is a `new()` constructor taking no receiver
+fn is_constructor(impl_fn: &ImplItemFn) -> bool {
+    impl_fn.sig.ident == "new"
+        && !impl_fn.sig.inputs.iter().any(|input| matches!(input, FnArg::Receiver(_)))
+}
+
+/// Find the behavior marked `#[udon_main]`, if any.
+///
+/// Without one, a project with several behaviors is compiled as a
+/// multi-behavior world; two or more marked behaviors are an error.
+pub fn find_main_behavior<'a>(
+    behaviors: impl IntoIterator<Item = &'a UdonBehaviourStruct>,
+) -> AnalysisResult<Option<&'a UdonBehaviourStruct>> {
+    let mains: Vec<&UdonBehaviourStruct> = behaviors.into_iter().filter(|b| b.is_main()).collect();
+    if mains.len() > 1 {
+        let mut behaviors: Vec<String> = mains.iter().map(|b| b.name.clone()).collect();
+        behaviors.sort();
+        return Err(AnalysisError::MultipleMainBehaviors { behaviors });
+    }
+    Ok(mains.into_iter().next())
+}
+
+/// Check whether a type is `Result<(), E>`
+fn is_unit_result(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    segment.ident == "Result"
+        && matches!(args.args.first(), Some(syn::GenericArgument::Type(Type::Tuple(unit))) if unit.elems.is_empty())
+}
+
+/// Get the name of the type an impl block targets
+fn impl_target_name(item_impl: &ItemImpl) -> Option<String> {
+    if let Type::Path(type_path) = &*item_impl.self_ty {
+        type_path.path.segments.last().map(|seg| seg.ident.to_string())
+    } else {
+        None
+    }
+}
+
+/// Check if a type is an integer or floating point number
+fn is_numeric_type(rust_type: &RustType) -> bool {
+    matches!(
+        rust_type,
+        RustType::I8 | RustType::I16 | RustType::I32 | RustType::I64 | RustType::I128
+            | RustType::U8 | RustType::U16 | RustType::U32 | RustType::U64 | RustType::U128
+            | RustType::F32 | RustType::F64
+    )
+}
+
+/// Check if a field default, given as a C# literal, leaves the field at zero
+fn starts_at_zero(default_value: Option<&str>) -> bool {
+    default_value.is_none_or(|literal| {
+        literal.trim_end_matches(|c: char| c.is_ascii_alphabetic())
+            .parse::<f64>()
+            .is_ok_and(|value| value == 0.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_struct_analyzer_creation() {
+        let analyzer = StructAnalyzer::new();
+        assert_eq!(analyzer.parsed_structs.len(), 0);
+        assert_eq!(analyzer.errors.len(), 0);
+    }
+
+    #[test]
+    fn test_has_udon_behaviour_derive() {
+        let analyzer = StructAnalyzer::new();
+        
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[derive(UdonBehaviour)]),
+        ];
+        
+        assert!(analyzer.has_udon_behaviour_derive(&attrs));
+        
+        let attrs_without: Vec<Attribute> = vec![
+            parse_quote!(#[derive(Debug)]),
+        ];
+        
+        assert!(!analyzer.has_udon_behaviour_derive(&attrs_without));
+    }
+
+    #[test]
+    fn test_parse_basic_types() {
+        let analyzer = StructAnalyzer::new();
+        
+        let ty: Type = parse_quote!(i32);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::I32);
+        
+        let ty: Type = parse_quote!(String);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::String);
+        
+        let ty: Type = parse_quote!(bool);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::Bool);
+    }
+
+    #[test]
+    fn test_parse_unity_types() {
+        let analyzer = StructAnalyzer::new();
+        
+        let ty: Type = parse_quote!(Vector3);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::Vector3);
+        
+        let ty: Type = parse_quote!(GameObject);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::GameObject);
+        
+        let ty: Type = parse_quote!(unity::GameObject);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::GameObject);
+    }
+
+    #[test]
+    fn test_parse_generic_types() {
+        let analyzer = StructAnalyzer::new();
+        
+        let ty: Type = parse_quote!(Option<i32>);
+        if let RustType::Option(inner) = analyzer.parse_type(&ty).unwrap() {
+            assert_eq!(*inner, RustType::I32);
+        } else {
+            panic!("Expected Option type");
+        }
+        
+        let ty: Type = parse_quote!(Vec<String>);
+        if let RustType::Vec(inner) = analyzer.parse_type(&ty).unwrap() {
+            assert_eq!(*inner, RustType::String);
+        } else {
+            panic!("Expected Vec type");
+        }
+
+        let ty: Type = parse_quote!(UdonRef<GameManager>);
+        assert_eq!(analyzer.parse_type(&ty).unwrap(), RustType::UdonRef("GameManager".to_string()));
+    }
+
+    #[test]
+    fn test_analyze_simple_struct() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    #[udon_public]
+                    pub player_count: i32,
+                    #[udon_sync]
+                    pub game_state: String,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items).unwrap();
+        assert_eq!(result.len(), 1);
+        
+        let behavior = &result[0];
+        assert_eq!(behavior.name, "TestBehavior");
+        assert_eq!(behavior.fields.len(), 2);
+        
+        let player_count_field = &behavior.fields[0];
+        assert_eq!(player_count_field.name, "player_count");
+        assert_eq!(player_count_field.field_type, RustType::I32);
+        assert!(player_count_field.attributes.contains(&FieldAttribute::UdonPublic));
+    }
+
+    #[test]
     fn test_invalid_struct_name() {
         let mut analyzer = StructAnalyzer::new();
         
         let items: Vec<Item> = vec![
             parse_quote! {
                 #[derive(UdonBehaviour)]
-                pub struct 123InvalidName {
+                pub struct 123InvalidName {
+                    field: i32,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items);
+        assert!(result.is_err());
+        
+        if let Err(AnalysisError::InvalidStructName { name, .. }) = result {
+            assert_eq!(name, "123InvalidName");
+        } else {
+            panic!("Expected InvalidStructName error");
+        }
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    unsupported_field: i128,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items);
+        assert!(result.is_err());
+        
+        let errors = analyzer.get_errors();
+        assert!(!errors.is_empty());
+        
+        if let AnalysisError::UnsupportedType { rust_type, .. } = &errors[0] {
+            assert!(rust_type.contains("I128"));
+        } else {
+            panic!("Expected UnsupportedType error");
+        }
+    }
+
+    // Additional comprehensive tests for Requirements 1.1, 1.2, 1.4, 2.1, 7.3
+
+    #[test]
+    fn test_multiple_struct_detection() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct PlayerManager {
+                    #[udon_public]
+                    player_count: i32,
+                }
+            },
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct UIController {
+                    #[udon_sync]
+                    ui_state: String,
+                }
+            },
+            parse_quote! {
+                pub struct RegularStruct {
+                    data: i32,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items).unwrap();
+        assert_eq!(result.len(), 2);
+        
+        let names: Vec<&str> = result.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"PlayerManager"));
+        assert!(names.contains(&"UIController"));
+    }
+
+    #[test]
+    fn test_header_and_tooltip_accept_both_spellings() {
+        let mut analyzer = StructAnalyzer::new();
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    #[header("Round Settings")]
+                    #[tooltip("Seconds per \"round\"")]
+                    short_form: f32,
+                    #[udon_header("Round Settings")]
+                    #[udon_tooltip("Seconds per \"round\"")]
+                    prefixed_form: f32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for TestBehavior {
+                    fn start(&mut self) {}
+                }
+            },
+        ];
+
+        let result = analyzer.analyze_module(&items).unwrap();
+        let fields = &result[0].fields;
+        let expected = vec![
+            FieldAttribute::Header("Round Settings".to_string()),
+            FieldAttribute::Tooltip("Seconds per \"round\"".to_string()),
+        ];
+        assert_eq!(fields[0].attributes, expected);
+        assert_eq!(fields[1].attributes, expected);
+    }
+
+    #[test]
+    fn test_field_attribute_parsing() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    #[udon_public]
+                    public_field: i32,
+                    #[udon_sync]
+                    sync_field: String,
+                    #[udon_sync_mode(Manual)]
+                    manual_sync_field: bool,
+                    private_field: f32,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items).unwrap();
+        let behavior = &result[0];
+        
+        assert_eq!(behavior.fields.len(), 4);
+        
+        // Check public field
+        let public_field = behavior.fields.iter().find(|f| f.name == "public_field").unwrap();
+        assert!(public_field.attributes.contains(&FieldAttribute::UdonPublic));
+        
+        // Check sync field
+        let sync_field = behavior.fields.iter().find(|f| f.name == "sync_field").unwrap();
+        assert!(sync_field.attributes.contains(&FieldAttribute::UdonSync));
+        
+        // Check manual sync field - this would be a struct attribute, not field attribute
+        let manual_sync_field = behavior.fields.iter().find(|f| f.name == "manual_sync_field").unwrap();
+        // Manual sync mode is a struct attribute, not a field attribute
+        assert!(manual_sync_field.attributes.is_empty() || manual_sync_field.attributes.contains(&FieldAttribute::UdonSync));
+        
+        // Check private field has no attributes
+        let private_field = behavior.fields.iter().find(|f| f.name == "private_field").unwrap();
+        assert!(private_field.attributes.is_empty());
+    }
+
+    #[test]
+    fn test_trait_implementation_validation() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    field: i32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for TestBehavior {
+                    fn start(&mut self) {
+                        self.field = 0;
+                    }
+                    
+                    fn update(&mut self) {
+                        // Update logic
+                    }
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items).unwrap();
+        let behavior = &result[0];
+        
+        assert!(behavior.trait_impl.is_some());
+        let trait_impl = behavior.trait_impl.as_ref().unwrap();
+        assert!(trait_impl.implemented_methods.contains(&"start".to_string()));
+        assert!(trait_impl.implemented_methods.contains(&"update".to_string()));
+    }
+
+    #[test]
+    fn test_missing_trait_implementation() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    field: i32,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items);
+        
+        // Should fail validation due to missing trait implementation
+        assert!(result.is_err() || analyzer.get_errors().iter().any(|e| {
+            matches!(e, AnalysisError::MissingTraitImplementation { .. })
+        }));
+    }
+
+    #[test]
+    fn test_circular_dependency_detection() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct BehaviorA {
+                    behavior_b_ref: Option<unity::GameObject>,
+                }
+            },
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct BehaviorB {
+                    behavior_a_ref: Option<unity::GameObject>,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for BehaviorA {
+                    fn start(&mut self) {
+                        self.behavior_b_ref = unity::GameObject::find("BehaviorB");
+                    }
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for BehaviorB {
+                    fn start(&mut self) {
+                        self.behavior_a_ref = unity::GameObject::find("BehaviorA");
+                    }
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items);
+        
+        // Should detect circular dependency
+        let has_circular_dependency = result.is_err() || 
+            analyzer.get_errors().iter().any(|e| {
+                matches!(e, AnalysisError::CircularDependency { .. })
+            });
+        
+        assert!(has_circular_dependency, "Should detect circular dependency between BehaviorA and BehaviorB");
+    }
+
+    #[test]
+    fn test_complex_type_parsing() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct ComplexBehavior {
+                    optional_gameobject: Option<unity::GameObject>,
+                    gameobject_list: Vec<unity::GameObject>,
+                    string_map: HashMap<String, i32>,
+                    nested_option: Option<Vec<String>>,
+                    unity_vector: unity::Vector3,
+                    unity_transform: unity::Transform,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items).unwrap();
+        let behavior = &result[0];
+        
+        assert_eq!(behavior.fields.len(), 6);
+        
+        // Verify complex type parsing
+        let optional_go = behavior.fields.iter().find(|f| f.name == "optional_gameobject").unwrap();
+        assert!(matches!(optional_go.field_type, RustType::Option(_)));
+        
+        let go_list = behavior.fields.iter().find(|f| f.name == "gameobject_list").unwrap();
+        assert!(matches!(go_list.field_type, RustType::Vec(_)));
+        
+        let string_map = behavior.fields.iter().find(|f| f.name == "string_map").unwrap();
+        assert!(matches!(string_map.field_type, RustType::HashMap(_, _)));
+    }
+
+    #[test]
+    fn test_invalid_field_attributes() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct TestBehavior {
+                    #[udon_sync]
+                    #[udon_public]
+                    conflicting_field: i32,
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items);
+        
+        // Should detect conflicting attributes
+        let has_attribute_error = result.is_err() || 
+            analyzer.get_errors().iter().any(|e| {
+                matches!(e, AnalysisError::InvalidFieldAttribute { .. })
+            });
+        
+        assert!(has_attribute_error, "Should detect conflicting field attributes");
+    }
+
+    #[test]
+    fn test_struct_naming_conventions() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct lowercase_struct {
                     field: i32,
                 }
             }
         ];
         
         let result = analyzer.analyze_module(&items);
-        assert!(result.is_err());
         
-        if let Err(AnalysisError::InvalidStructName { name, .. }) = result {
-            assert_eq!(name, "123InvalidName");
-        } else {
-            panic!("Expected InvalidStructName error");
+        // Should generate warning for non-PascalCase naming
+        if result.is_ok() {
+            let warnings = analyzer.get_warnings();
+            assert!(!warnings.is_empty());
+            assert!(warnings.iter().any(|w| w.contains("PascalCase")));
         }
     }
 
     #[test]
-    fn test_unsupported_type() {
+    fn test_dependency_analysis() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct GameManager {
+                    ui_controller_ref: Option<unity::GameObject>,
+                    score_tracker_ref: Option<unity::GameObject>,
+                }
+            },
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct UIController {
+                    // No dependencies
+                }
+            },
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct ScoreTracker {
+                    // No dependencies
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for GameManager {
+                    fn start(&mut self) {
+                        self.ui_controller_ref = unity::GameObject::find("UIController");
+                        self.score_tracker_ref = unity::GameObject::find("ScoreTracker");
+                    }
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items).unwrap();
+        
+        // Verify dependency analysis
+        let game_manager = result.iter().find(|s| s.name == "GameManager").unwrap();
+        assert_eq!(game_manager.dependencies.len(), 2);
+        assert!(game_manager.dependencies.contains(&"UIController".to_string()));
+        assert!(game_manager.dependencies.contains(&"ScoreTracker".to_string()));
+    }
+
+    #[test]
+    fn test_async_method_rejection() {
         let mut analyzer = StructAnalyzer::new();
         
         let items: Vec<Item> = vec![
             parse_quote! {
                 #[derive(UdonBehaviour)]
                 pub struct TestBehavior {
+                    field: i32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for TestBehavior {
+                    async fn start(&mut self) {
+                        // Async methods not supported
+                    }
+                }
+            }
+        ];
+        
+        let result = analyzer.analyze_module(&items);
+        
+        // Should reject async methods
+        let has_async_error = result.is_err() || 
+            analyzer.get_errors().iter().any(|e| {
+                matches!(e, AnalysisError::AsyncMethodNotSupported { .. })
+            });
+        
+        assert!(has_async_error, "Should reject async methods in UdonBehaviour trait");
+    }
+
+    #[test]
+    fn test_udon_event_with_parameters_is_rejected() {
+        let mut analyzer = StructAnalyzer::new();
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Door {
+                    #[udon_public]
+                    pub open_speed: f32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for Door {
+                    fn start(&mut self) {}
+                }
+            },
+            parse_quote! {
+                impl Door {
+                    #[udon_event("OpenDoor")]
+                    pub fn open_door(&mut self, speed: f32) {
+                        self.open_speed = speed;
+                    }
+                }
+            },
+        ];
+
+        let _ = analyzer.analyze_module(&items);
+        let error = analyzer.get_errors().iter().find_map(|error| match error {
+            AnalysisError::InvalidMethodSignature { method_name, reason, .. } => Some((method_name.clone(), reason.clone())),
+            _ => None,
+        });
+        let (method_name, reason) = error.expect("a parameterized udon_event should be rejected");
+        assert_eq!(method_name, "open_door");
+        assert!(reason.contains("#[udon_public]") && reason.contains("#[udon_network_event]"), "{}", reason);
+    }
+
+    #[test]
+    fn test_udon_event_without_parameters_is_clean() {
+        let mut analyzer = StructAnalyzer::new();
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct Door {
+                    #[udon_public]
+                    pub open_speed: f32,
+                }
+            },
+            parse_quote! {
+                impl UdonBehaviour for Door {
+                    fn start(&mut self) {}
+                }
+            },
+            parse_quote! {
+                impl Door {
+                    #[udon_event("OpenDoor")]
+                    pub fn open_door(&mut self) {
+                        self.open_speed = 2.0;
+                    }
+                }
+            },
+        ];
+
+        let structs = analyzer.analyze_module(&items).unwrap();
+        assert!(analyzer.get_errors().is_empty(), "{:?}", analyzer.get_errors());
+        assert!(structs[0].methods.iter().any(|method| method.name == "open_door"));
+    }
+
+    #[test]
+    fn test_error_accumulation() {
+        let mut analyzer = StructAnalyzer::new();
+        
+        let items: Vec<Item> = vec![
+            parse_quote! {
+                #[derive(UdonBehaviour)]
+                pub struct 123InvalidName {
                     unsupported_field: i128,
+                    #[invalid_attribute]
+                    bad_attribute_field: i32,
                 }
             }
         ];
@@ -963,369 +3360,889 @@ mod tests {
         let result = analyzer.analyze_module(&items);
         assert!(result.is_err());
         
+        // Should accumulate multiple errors
         let errors = analyzer.get_errors();
-        assert!(!errors.is_empty());
-        
-        if let AnalysisError::UnsupportedType { rust_type, .. } = &errors[0] {
-            assert!(rust_type.contains("I128"));
-        } else {
-            panic!("Expected UnsupportedType error");
-        }
+        assert!(errors.len() >= 2, "Should accumulate multiple errors");
     }
 
-    // Additional comprehensive tests for Requirements 1.1, 1.2, 1.4, 2.1, 7.3
-
     #[test]
-    fn test_multiple_struct_detection() {
+    fn test_embedded_struct_flattening() {
         let mut analyzer = StructAnalyzer::new();
-        
+
         let items: Vec<Item> = vec![
             parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct PlayerManager {
-                    #[udon_public]
-                    player_count: i32,
+                pub struct ScoringSystem {
+                    total_score: i32,
+                    multiplier: i32,
+                }
+            },
+            parse_quote! {
+                impl ScoringSystem {
+                    pub fn add_score(&mut self, points: i32) {
+                        self.total_score += points * self.multiplier;
+                    }
                 }
             },
             parse_quote! {
                 #[derive(UdonBehaviour)]
-                pub struct UIController {
-                    #[udon_sync]
-                    ui_state: String,
+                pub struct GameManager {
+                    scoring_system: ScoringSystem,
                 }
             },
             parse_quote! {
-                pub struct RegularStruct {
-                    data: i32,
+                impl UdonBehaviour for GameManager {
+                    fn start(&mut self) {
+                        self.scoring_system.add_score(10);
+                    }
                 }
             }
         ];
-        
+
         let result = analyzer.analyze_module(&items).unwrap();
-        assert_eq!(result.len(), 2);
-        
-        let names: Vec<&str> = result.iter().map(|s| s.name.as_str()).collect();
-        assert!(names.contains(&"PlayerManager"));
-        assert!(names.contains(&"UIController"));
+        let behavior = &result[0];
+
+        let field_names: Vec<&str> = behavior.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(field_names, vec!["scoring_system_total_score", "scoring_system_multiplier"]);
+
+        let embedded = behavior.get_embedded_struct("scoring_system").unwrap();
+        assert_eq!(embedded.type_name, "ScoringSystem");
+        assert!(embedded.has_method("add_score"));
+        assert!(behavior.methods.iter().any(|m| m.name == "scoring_system_add_score"));
+    }
+
+    fn behavior_with_public_fields(count: usize) -> Vec<Item> {
+        let fields: String = (0..count).map(|i| format!("pub setting_{}: i32,\n", i)).collect();
+        let source = format!(
+            "#[derive(UdonBehaviour)] pub struct ConfigPanel {{ {} }} impl UdonBehaviour for ConfigPanel {{ fn start(&mut self) {{}} }}",
+            fields
+        );
+        syn::parse_str::<syn::File>(&source).unwrap().items
     }
 
     #[test]
-    fn test_field_attribute_parsing() {
+    fn test_public_variable_count_under_threshold() {
         let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct TestBehavior {
-                    #[udon_public]
-                    public_field: i32,
-                    #[udon_sync]
-                    sync_field: String,
-                    #[udon_sync_mode(Manual)]
-                    manual_sync_field: bool,
-                    private_field: f32,
+        let items = behavior_with_public_fields(public_variable_warning_threshold(DEFAULT_PUBLIC_VARIABLE_LIMIT) - 1);
+
+        assert!(analyzer.analyze_module(&items).is_ok());
+        assert!(!analyzer.get_warnings().iter().any(|w| w.contains("public variables")));
+    }
+
+    #[test]
+    fn test_public_variable_count_approaching_limit_warns() {
+        let mut analyzer = StructAnalyzer::new();
+        let items = behavior_with_public_fields(DEFAULT_PUBLIC_VARIABLE_LIMIT);
+
+        assert!(analyzer.analyze_module(&items).is_ok());
+        assert!(analyzer.get_warnings().iter().any(|w| w.contains("exposes 256 of at most 256 public variables")));
+    }
+
+    #[test]
+    fn test_public_variable_count_over_limit_errors() {
+        let mut analyzer = StructAnalyzer::new();
+        let items = behavior_with_public_fields(DEFAULT_PUBLIC_VARIABLE_LIMIT + 1);
+
+        let result = analyzer.analyze_module(&items);
+        assert!(matches!(
+            result,
+            Err(AnalysisError::TooManyPublicVariables { count: 257, limit: DEFAULT_PUBLIC_VARIABLE_LIMIT, .. })
+        ));
+    }
+
+    #[test]
+    fn test_public_variable_limit_is_configurable() {
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.set_public_variable_limit(16);
+
+        assert!(analyzer.analyze_module(&behavior_with_public_fields(14)).is_ok());
+        assert!(analyzer.get_warnings().iter().any(|w| w.contains("exposes 14 of at most 16 public variables")));
+
+        let result = analyzer.analyze_module(&behavior_with_public_fields(17));
+        assert!(matches!(
+            result,
+            Err(AnalysisError::TooManyPublicVariables { count: 17, limit: 16, .. })
+        ));
+    }
+
+    /// Analyze a module and return its warnings that contain `needle`
+    fn lint_warnings(source: &str, needle: &str) -> Vec<String> {
+        let file: syn::File = syn::parse_str(source).unwrap();
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.analyze_module(&file.items).unwrap();
+        analyzer.get_warnings().iter()
+            .filter(|w| w.contains(needle))
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_private_cross_behavior_read_is_flagged() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameManager {
+                pub current_round: i32,
+                secret_seed: i32,
+                #[udon_sync]
+                round_timer: f32,
+            }
+
+            impl UdonBehaviour for GameManager {
+                fn start(&mut self) {}
+            }
+
+            #[derive(UdonBehaviour)]
+            pub struct UIController {
+                pub game_manager: Option<GameObject>,
+            }
+
+            impl UdonBehaviour for UIController {
+                fn start(&mut self) {}
+            }
+
+            impl UIController {
+                fn refresh(&self, gm_obj: GameObject) {
+                    if let Some(gm) = gm_obj.get_component::<GameManager>() {
+                        let round = gm.current_round;
+                        let seed = gm.secret_seed;
+                        let timer = gm.round_timer;
+                    }
                 }
             }
-        ];
-        
-        let result = analyzer.analyze_module(&items).unwrap();
-        let behavior = &result[0];
-        
-        assert_eq!(behavior.fields.len(), 4);
-        
-        // Check public field
-        let public_field = behavior.fields.iter().find(|f| f.name == "public_field").unwrap();
-        assert!(public_field.attributes.contains(&FieldAttribute::UdonPublic));
-        
-        // Check sync field
-        let sync_field = behavior.fields.iter().find(|f| f.name == "sync_field").unwrap();
-        assert!(sync_field.attributes.contains(&FieldAttribute::UdonSync));
-        
-        // Check manual sync field - this would be a struct attribute, not field attribute
-        let manual_sync_field = behavior.fields.iter().find(|f| f.name == "manual_sync_field").unwrap();
-        // Manual sync mode is a struct attribute, not a field attribute
-        assert!(manual_sync_field.attributes.is_empty() || manual_sync_field.attributes.contains(&FieldAttribute::UdonSync));
-        
-        // Check private field has no attributes
-        let private_field = behavior.fields.iter().find(|f| f.name == "private_field").unwrap();
-        assert!(private_field.attributes.is_empty());
+        "#, "reads private field");
+        assert_eq!(warnings, vec!["Method 'UIController::refresh' reads private field 'secret_seed' of behavior 'GameManager'; Udon cannot access private variables of another behavior, mark the field `pub` or #[udon_public]"]);
+    }
+
+    /// Behaviors where `Scoreboard` reaches `Counter` through a local, with `method` on `Scoreboard`
+    fn cross_behavior_mutation_source(method: &str) -> String {
+        format!(r#"
+            #[derive(UdonBehaviour)]
+            pub struct Counter {{
+                pub count: i32,
+            }}
+
+            impl UdonBehaviour for Counter {{
+                fn start(&mut self) {{}}
+            }}
+
+            impl Counter {{
+                pub fn increment(&mut self) {{
+                    self.count += 1;
+                }}
+
+                pub fn get_count(&self) -> i32 {{
+                    self.count
+                }}
+            }}
+
+            #[derive(UdonBehaviour)]
+            pub struct Scoreboard {{
+                pub shown: i32,
+            }}
+
+            impl UdonBehaviour for Scoreboard {{
+                fn start(&mut self) {{}}
+            }}
+
+            impl Scoreboard {{
+                {}
+            }}
+        "#, method)
     }
 
     #[test]
-    fn test_trait_implementation_validation() {
-        let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct TestBehavior {
-                    field: i32,
+    fn test_read_after_cross_behavior_call_is_flagged() {
+        let warnings = lint_warnings(&cross_behavior_mutation_source(r#"
+            fn add_point(&mut self, counter_obj: GameObject) {
+                let counter = counter_obj.get_component::<Counter>().unwrap();
+                counter.increment();
+                self.shown = counter.count;
+            }
+        "#), "not mutations through a reference");
+        assert_eq!(warnings, vec!["Method 'Scoreboard::add_point' calls 'Counter::increment' and then reads 'count' through the same reference, expecting the call to have mutated it; cross-behavior calls are custom events in Udon, not mutations through a reference, so have 'Counter' report its new state instead"]);
+
+        // Reading before the call, or only calling, expects no mutation
+        let warnings = lint_warnings(&cross_behavior_mutation_source(r#"
+            fn add_point(&mut self, counter_obj: GameObject) {
+                let counter = counter_obj.get_component::<Counter>().unwrap();
+                self.shown = counter.count + 1;
+                counter.count = self.shown;
+                counter.increment();
+            }
+        "#), "not mutations through a reference");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_read_after_cross_behavior_getter_is_clean() {
+        let warnings = lint_warnings(&cross_behavior_mutation_source(r#"
+            fn refresh(&mut self, counter_obj: GameObject) {
+                let counter = counter_obj.get_component::<Counter>().unwrap();
+                let previous = counter.get_count();
+                self.shown = counter.count - previous;
+            }
+        "#), "not mutations through a reference");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_synced_write_after_master_guard_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameManager {
+                #[udon_sync]
+                pub round_number: i32,
+                pub local_score: i32,
+            }
+
+            impl UdonBehaviour for GameManager {
+                fn start(&mut self) {}
+            }
+
+            impl GameManager {
+                pub fn start_round(&mut self) {
+                    self.local_score = 0;
+                    if !networking::is_master() {
+                        return;
+                    }
+                    self.round_number += 1;
+                }
+            }
+        "#, "move the master guard");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_synced_write_before_master_guard_warns() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameManager {
+                #[udon_sync]
+                pub round_number: i32,
+                pub local_score: i32,
+            }
+
+            impl UdonBehaviour for GameManager {
+                fn start(&mut self) {}
+            }
+
+            impl GameManager {
+                pub fn start_round(&mut self) {
+                    self.round_number += 1;
+                    if !networking::is_master() {
+                        return;
+                    }
+                    self.local_score = 0;
+                }
+            }
+        "#, "move the master guard");
+        assert_eq!(warnings, vec!["Method 'GameManager::start_round' writes synced field 'round_number' on a path where `is_master()` may be false, but checks `is_master()` elsewhere; move the master guard before the write"]);
+    }
+
+    #[test]
+    fn test_front_removal_reachable_from_update_warns() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct StatsManager {
+                frame_times: Vec<f32>,
+            }
+
+            impl UdonBehaviour for StatsManager {
+                fn start(&mut self) {}
+                fn update(&mut self) {
+                    self.record_frame(0.016);
+                }
+            }
+
+            impl StatsManager {
+                fn record_frame(&mut self, time: f32) {
+                    self.frame_times.push(time);
+                    if self.frame_times.len() > 60 {
+                        self.frame_times.remove(0);
+                    }
+                }
+            }
+        "#, "ring buffer");
+        assert_eq!(warnings, vec!["Method 'StatsManager::record_frame' runs every frame and calls `self.frame_times.remove(0)`, which shifts every remaining element once lowered to an array; use a fixed-size ring buffer with a head index instead"]);
+    }
+
+    #[test]
+    fn test_front_removal_outside_frame_events_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct StatsManager {
+                frame_times: Vec<f32>,
+            }
+
+            impl UdonBehaviour for StatsManager {
+                fn start(&mut self) {}
+                fn update(&mut self) {
+                    self.record_frame(0.016);
+                }
+            }
+
+            impl StatsManager {
+                fn record_frame(&mut self, time: f32) {
+                    self.frame_times.push(time);
+                }
+
+                pub fn drop_oldest(&mut self) {
+                    self.frame_times.remove(0);
+                }
+            }
+        "#, "ring buffer");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_synced_string_used_as_enum_advises_int_sync() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameManager {
+                #[udon_sync]
+                pub phase: String,
+                #[udon_sync]
+                pub winner_name: String,
+            }
+
+            impl UdonBehaviour for GameManager {
+                fn start(&mut self) {}
+            }
+
+            impl GameManager {
+                pub fn start_game(&mut self) {
+                    self.phase = "playing".to_string();
+                }
+
+                pub fn end_game(&mut self) {
+                    self.phase = String::from("finished");
+                    self.winner_name = format!("{:?}", Team::Red);
+                }
+            }
+        "#, "sync an i32 or enum instead");
+        assert_eq!(warnings, vec![
+            "Synced String field 'GameManager::phase' is only ever assigned 2 fixed value(s) (\"finished\", \"playing\"); sync an i32 or enum instead to save bandwidth and rule out misspelled states",
+            "Synced String field 'GameManager::winner_name' is only ever assigned 1 fixed value(s) (\"Team::Red\"); sync an i32 or enum instead to save bandwidth and rule out misspelled states",
+        ]);
+    }
+
+    #[test]
+    fn test_synced_string_with_runtime_values_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameManager {
+                #[udon_sync]
+                pub phase: String,
+                #[udon_sync]
+                pub winner_name: String,
+            }
+
+            impl UdonBehaviour for GameManager {
+                fn start(&mut self) {}
+            }
+
+            impl GameManager {
+                pub fn start_game(&mut self) {
+                    self.phase = "playing".to_string();
+                    self.phase.push_str(" (overtime)");
+                }
+
+                pub fn end_game(&mut self, player: VRCPlayerApi) {
+                    self.winner_name = player.get_display_name();
+                }
+            }
+        "#, "sync an i32 or enum instead");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_master_driven_sync_init_in_start_warns() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct PlayerTracker {
+                #[udon_sync]
+                pub player_count: i32,
+                #[udon_sync]
+                pub session_seed: i32,
+                pub is_host: bool,
+            }
+
+            impl UdonBehaviour for PlayerTracker {
+                fn start(&mut self) {
+                    let master = networking::is_master();
+                    if master {
+                        self.player_count = 1;
+                    }
+                    if !is_master() {
+                        return;
+                    }
+                    self.session_seed = 42;
+                }
+            }
+        "#, "may be unreliable in Start");
+        assert_eq!(warnings, vec![
+            "Method 'PlayerTracker::start' initializes synced field 'player_count' based on `is_master()`, which may be unreliable in Start for late joiners; consider deferring it to the first `update` or `on_player_joined`",
+            "Method 'PlayerTracker::start' initializes synced field 'session_seed' based on `is_master()`, which may be unreliable in Start for late joiners; consider deferring it to the first `update` or `on_player_joined`",
+        ]);
+    }
+
+    #[test]
+    fn test_benign_master_read_in_start_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct PlayerTracker {
+                #[udon_sync]
+                pub player_count: i32,
+                #[udon_sync]
+                pub session_seed: i32,
+                pub is_host: bool,
+            }
+
+            impl UdonBehaviour for PlayerTracker {
+                fn start(&mut self) {
+                    self.player_count = 0;
+                    self.is_host = is_master();
+                    if is_master() {
+                        log_info("hosting");
+                    }
+                }
+            }
+        "#, "may be unreliable in Start");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_unguarded_divide_by_count_warns() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct SessionStats {
+                #[udon_sync]
+                pub average_session_time: f32,
+                pub total_session_time: f32,
+                pub total_players: i32,
+            }
+
+            impl UdonBehaviour for SessionStats {
+                fn start(&mut self) {}
+            }
+
+            impl SessionStats {
+                pub fn update_average(&mut self) {
+                    self.average_session_time = self.total_session_time / self.total_players as f32;
+                }
+            }
+        "#, "not checked for zero");
+        assert_eq!(warnings, vec!["Method 'SessionStats::update_average' divides by 'total_players', which starts at 0 and is not checked for zero first; guard the division with `if self.total_players > 0` to avoid a halted behavior or NaN/Infinity in synced values"]);
+    }
+
+    #[test]
+    fn test_guarded_divide_by_count_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct SessionStats {
+                #[udon_sync]
+                pub average_session_time: f32,
+                pub total_session_time: f32,
+                pub total_players: i32,
+            }
+
+            impl UdonBehaviour for SessionStats {
+                fn start(&mut self) {}
+            }
+
+            impl SessionStats {
+                pub fn update_average(&mut self) {
+                    if self.total_players > 0 {
+                        self.average_session_time = self.total_session_time / self.total_players as f32;
+                    }
+                    if self.total_players == 0 {
+                        return;
+                    }
+                    let share = 1.0 / (self.total_players as f32);
+                    self.total_session_time /= self.total_players as f32;
+                    log_info(&format!("{}", share));
+                }
+            }
+        "#, "not checked for zero");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    /// A behavior whose frame events are guarded by `initialized`, with `start_body` as its `start`
+    fn init_guard_source(start_body: &str) -> String {
+        format!(r#"
+            #[derive(UdonBehaviour)]
+            pub struct Spinner {{
+                initialized: bool,
+                angle: f32,
+            }}
+
+            impl UdonBehaviour for Spinner {{
+                fn start(&mut self) {{ {} }}
+
+                fn update(&mut self) {{
+                    if !self.initialized {{
+                        return;
+                    }}
+                    self.angle += 1.0;
+                }}
+            }}
+
+            impl Spinner {{
+                fn setup(&mut self) {{
+                    self.angle = 0.0;
+                    self.initialized = true;
+                }}
+            }}
+        "#, start_body)
+    }
+
+    #[test]
+    fn test_init_guard_set_in_start_is_clean() {
+        let needle = "never set to true in `start`";
+        assert!(lint_warnings(&init_guard_source("self.initialized = true;"), needle).is_empty());
+        // Setting it in a helper called from start counts too
+        assert!(lint_warnings(&init_guard_source("self.setup();"), needle).is_empty());
+    }
+
+    #[test]
+    fn test_init_guard_never_set_warns() {
+        let warnings = lint_warnings(&init_guard_source("self.angle = 0.0;"), "never set to true in `start`");
+        assert_eq!(warnings, vec!["Field 'Spinner::initialized' guards the frame events but is never set to true in `start`, so the guarded code never runs; set `self.initialized = true` once initialization is done"]);
+    }
+
+    #[test]
+    fn test_delta_time_in_fixed_update_warns() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameLogic {
+                velocity: f32,
+                position: f32,
+            }
+
+            impl UdonBehaviour for GameLogic {
+                fn start(&mut self) {}
+                fn fixed_update(&mut self) {
+                    self.velocity -= 9.81 * Time::delta_time();
+                    self.integrate();
+                }
+            }
+
+            impl GameLogic {
+                fn integrate(&mut self) {
+                    self.position += self.velocity * Time::delta_time();
+                }
+            }
+        "#, "Time::fixed_delta_time()");
+        assert_eq!(warnings, vec![
+            "Method 'GameLogic::fixed_update' runs in FixedUpdate but reads `Time::delta_time()`; use `Time::fixed_delta_time()` so the code steps with physics rather than with the frame rate",
+            "Method 'GameLogic::integrate' runs in FixedUpdate but reads `Time::delta_time()`; use `Time::fixed_delta_time()` so the code steps with physics rather than with the frame rate",
+        ]);
+    }
+
+    #[test]
+    fn test_delta_time_in_update_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct GameLogic {
+                velocity: f32,
+                position: f32,
+            }
+
+            impl UdonBehaviour for GameLogic {
+                fn start(&mut self) {}
+                fn update(&mut self) {
+                    self.velocity -= 9.81 * Time::delta_time();
+                    self.integrate();
                 }
-            },
-            parse_quote! {
-                impl UdonBehaviour for TestBehavior {
-                    fn start(&mut self) {
-                        self.field = 0;
-                    }
-                    
-                    fn update(&mut self) {
-                        // Update logic
-                    }
+                fn fixed_update(&mut self) {
+                    self.velocity *= 1.0 - 0.1 * Time::fixed_delta_time();
+                    self.integrate();
                 }
             }
-        ];
-        
-        let result = analyzer.analyze_module(&items).unwrap();
-        let behavior = &result[0];
-        
-        assert!(behavior.trait_impl.is_some());
-        let trait_impl = behavior.trait_impl.as_ref().unwrap();
-        assert!(trait_impl.implemented_methods.contains(&"start".to_string()));
-        assert!(trait_impl.implemented_methods.contains(&"update".to_string()));
+
+            impl GameLogic {
+                fn integrate(&mut self) {
+                    self.position += self.velocity * Time::delta_time();
+                }
+            }
+        "#, "Time::fixed_delta_time()");
+        assert!(warnings.is_empty(), "{:?}", warnings);
     }
 
     #[test]
-    fn test_missing_trait_implementation() {
-        let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct TestBehavior {
-                    field: i32,
+    fn test_find_with_computed_name_warns() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct PhysicsController {
+                physics_objects: Vec<GameObject>,
+            }
+
+            impl UdonBehaviour for PhysicsController {
+                fn start(&mut self) {
+                    self.setup_physics_objects();
                 }
             }
-        ];
-        
-        let result = analyzer.analyze_module(&items);
-        
-        // Should fail validation due to missing trait implementation
-        assert!(result.is_err() || analyzer.get_errors().iter().any(|e| {
-            matches!(e, AnalysisError::MissingTraitImplementation { .. })
-        }));
+
+            impl PhysicsController {
+                fn setup_physics_objects(&mut self) {
+                    for i in 0..20 {
+                        let obj_name = format!("PhysicsObject_{}", i);
+                        if let Some(obj) = GameObject::find(&obj_name) {
+                            self.physics_objects.push(obj);
+                        }
+                    }
+                }
+            }
+        "#, "GameObject::find");
+        assert_eq!(warnings, vec!["Method 'PhysicsController::setup_physics_objects' calls `GameObject::find` with a computed name inside a loop; Find searches the whole scene and returns nothing once an object is renamed, so collect the references once in `start` or assign them in the inspector"]);
     }
 
     #[test]
-    fn test_circular_dependency_detection() {
-        let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct BehaviorA {
-                    behavior_b_ref: Option<unity::GameObject>,
+    fn test_find_with_literal_name_is_clean() {
+        let warnings = lint_warnings(r#"
+            #[derive(UdonBehaviour)]
+            pub struct PhysicsController {
+                physics_objects: Vec<GameObject>,
+            }
+
+            impl UdonBehaviour for PhysicsController {
+                fn start(&mut self) {
+                    self.setup_physics_objects();
                 }
-            },
+            }
+
+            impl PhysicsController {
+                fn setup_physics_objects(&mut self) {
+                    if let Some(obj) = unity::GameObject::find("PhysicsObject_0") {
+                        self.physics_objects.push(obj);
+                    }
+                }
+            }
+        "#, "GameObject::find");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    fn computed_find_items(setup: syn::ImplItemFn) -> Vec<Item> {
+        vec![
             parse_quote! {
                 #[derive(UdonBehaviour)]
-                pub struct BehaviorB {
-                    behavior_a_ref: Option<unity::GameObject>,
+                pub struct SpawnManager {
+                    spawn_points: Vec<GameObject>,
                 }
             },
             parse_quote! {
-                impl UdonBehaviour for BehaviorA {
+                impl UdonBehaviour for SpawnManager {
                     fn start(&mut self) {
-                        self.behavior_b_ref = unity::GameObject::find("BehaviorB");
+                        self.collect_spawn_points();
                     }
                 }
             },
             parse_quote! {
-                impl UdonBehaviour for BehaviorB {
-                    fn start(&mut self) {
-                        self.behavior_a_ref = unity::GameObject::find("BehaviorA");
-                    }
+                impl SpawnManager {
+                    #setup
                 }
-            }
-        ];
-        
-        let result = analyzer.analyze_module(&items);
-        
-        // Should detect circular dependency
-        let has_circular_dependency = result.is_err() || 
-            analyzer.get_errors().iter().any(|e| {
-                matches!(e, AnalysisError::CircularDependency { .. })
-            });
-        
-        assert!(has_circular_dependency, "Should detect circular dependency between BehaviorA and BehaviorB");
+            },
+        ]
     }
 
     #[test]
-    fn test_complex_type_parsing() {
-        let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct ComplexBehavior {
-                    optional_gameobject: Option<unity::GameObject>,
-                    gameobject_list: Vec<unity::GameObject>,
-                    string_map: HashMap<String, i32>,
-                    nested_option: Option<Vec<String>>,
-                    unity_vector: unity::Vector3,
-                    unity_transform: unity::Transform,
+    fn test_denied_lint_fails_analysis() {
+        let items = computed_find_items(parse_quote! {
+            fn collect_spawn_points(&mut self) {
+                for i in 0..4 {
+                    if let Some(point) = GameObject::find(&format!("Spawn_{}", i)) {
+                        self.spawn_points.push(point);
+                    }
                 }
             }
-        ];
-        
-        let result = analyzer.analyze_module(&items).unwrap();
-        let behavior = &result[0];
-        
-        assert_eq!(behavior.fields.len(), 6);
-        
-        // Verify complex type parsing
-        let optional_go = behavior.fields.iter().find(|f| f.name == "optional_gameobject").unwrap();
-        assert!(matches!(optional_go.field_type, RustType::Option(_)));
-        
-        let go_list = behavior.fields.iter().find(|f| f.name == "gameobject_list").unwrap();
-        assert!(matches!(go_list.field_type, RustType::Vec(_)));
-        
-        let string_map = behavior.fields.iter().find(|f| f.name == "string_map").unwrap();
-        assert!(matches!(string_map.field_type, RustType::HashMap(_, _)));
+        });
+
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.set_lint_level("dynamic_find", LintLevel::Deny);
+        let error = analyzer.analyze_module(&items).unwrap_err();
+        assert!(matches!(&error, AnalysisError::DeniedLint { lint, .. } if lint == "dynamic_find"), "{:?}", error);
+        assert!(error.to_string().contains("lint `dynamic_find` is set to deny"));
+        assert!(!analyzer.get_warnings().iter().any(|w| w.contains("GameObject::find")));
     }
 
     #[test]
-    fn test_invalid_field_attributes() {
+    fn test_allowed_lint_is_silenced() {
+        let setup: syn::ImplItemFn = parse_quote! {
+            fn collect_spawn_points(&mut self) {
+                for i in 0..4 {
+                    if let Some(point) = GameObject::find(&format!("Spawn_{}", i)) {
+                        self.spawn_points.push(point);
+                    }
+                }
+            }
+        };
+
         let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
+        analyzer.set_lint_level("dynamic_find", LintLevel::Allow);
+        analyzer.analyze_module(&computed_find_items(setup.clone())).unwrap();
+        assert!(!analyzer.get_warnings().iter().any(|w| w.contains("GameObject::find")));
+
+        // `#[udon_allow]` silences the lint for one method even when it is denied
+        let mut allowed = setup;
+        allowed.attrs.push(parse_quote!(#[udon_allow(dynamic_find)]));
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.set_lint_level("dynamic_find", LintLevel::Deny);
+        analyzer.analyze_module(&computed_find_items(allowed)).unwrap();
+        assert!(!analyzer.get_warnings().iter().any(|w| w.contains("GameObject::find")));
+    }
+
+    fn round_timer_items(constructor: Option<Item>) -> Vec<Item> {
+        let mut items: Vec<Item> = vec![
             parse_quote! {
                 #[derive(UdonBehaviour)]
-                pub struct TestBehavior {
-                    #[udon_sync]
+                pub struct RoundTimer {
                     #[udon_public]
-                    conflicting_field: i32,
+                    pub round_seconds: f32,
+                    remaining: f32,
+                    running: bool,
                 }
-            }
+            },
+            parse_quote! {
+                impl UdonBehaviour for RoundTimer {
+                    fn start(&mut self) {}
+                }
+            },
         ];
-        
-        let result = analyzer.analyze_module(&items);
-        
-        // Should detect conflicting attributes
-        let has_attribute_error = result.is_err() || 
-            analyzer.get_errors().iter().any(|e| {
-                matches!(e, AnalysisError::InvalidFieldAttribute { .. })
-            });
-        
-        assert!(has_attribute_error, "Should detect conflicting field attributes");
+        items.extend(constructor);
+        items
     }
 
     #[test]
-    fn test_struct_naming_conventions() {
+    fn test_private_fields_without_constructor_warn() {
         let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct lowercase_struct {
-                    field: i32,
-                }
-            }
-        ];
-        
-        let result = analyzer.analyze_module(&items);
-        
-        // Should generate warning for non-PascalCase naming
-        if result.is_ok() {
-            let warnings = analyzer.get_warnings();
-            assert!(!warnings.is_empty());
-            assert!(warnings.iter().any(|w| w.contains("PascalCase")));
-        }
+        analyzer.analyze_module(&round_timer_items(None)).unwrap();
+        let warnings: Vec<&String> = analyzer.get_warnings().iter().filter(|w| w.contains("no `new()` or `Default` impl")).collect();
+        assert_eq!(warnings, vec![
+            "Behavior 'RoundTimer' has non-public fields (remaining, running) but no `new()` or `Default` impl, so they are not initialized in Start; add a constructor with their initial values or derive `Default`",
+        ]);
+
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.set_lint_level("missing_constructor", LintLevel::Deny);
+        let error = analyzer.analyze_module(&round_timer_items(None)).unwrap_err();
+        assert!(matches!(&error, AnalysisError::DeniedLint { lint, .. } if lint == "missing_constructor"), "{:?}", error);
     }
 
     #[test]
-    fn test_dependency_analysis() {
-        let mut analyzer = StructAnalyzer::new();
-        
+    fn test_pub_fields_without_constructor_do_not_warn() {
         let items: Vec<Item> = vec![
             parse_quote! {
                 #[derive(UdonBehaviour)]
-                pub struct GameManager {
-                    ui_controller_ref: Option<unity::GameObject>,
-                    score_tracker_ref: Option<unity::GameObject>,
+                pub struct Scoreboard {
+                    pub value: i32,
+                    #[udon_public]
+                    pub label: String,
                 }
             },
             parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct UIController {
-                    // No dependencies
+                impl UdonBehaviour for Scoreboard {
+                    fn start(&mut self) {}
                 }
             },
+        ];
+        let mut analyzer = StructAnalyzer::new();
+        analyzer.analyze_module(&items).unwrap();
+        assert!(!analyzer.get_warnings().iter().any(|w| w.contains("no `new()` or `Default` impl")), "{:?}", analyzer.get_warnings());
+    }
+
+    #[test]
+    fn test_doc_comments_on_fields_are_not_field_attributes() {
+        let items: Vec<Item> = vec![
             parse_quote! {
                 #[derive(UdonBehaviour)]
-                pub struct ScoreTracker {
-                    // No dependencies
+                pub struct Scoreboard {
+                    /// Score shown to every player
+                    #[udon_sync]
+                    pub score: i32,
+                    #[doc = "Name of the round"]
+                    round_name: String,
                 }
             },
             parse_quote! {
-                impl UdonBehaviour for GameManager {
-                    fn start(&mut self) {
-                        self.ui_controller_ref = unity::GameObject::find("UIController");
-                        self.score_tracker_ref = unity::GameObject::find("ScoreTracker");
-                    }
+                impl UdonBehaviour for Scoreboard {
+                    fn start(&mut self) {}
                 }
-            }
+            },
         ];
-        
-        let result = analyzer.analyze_module(&items).unwrap();
-        
-        // Verify dependency analysis
-        let game_manager = result.iter().find(|s| s.name == "GameManager").unwrap();
-        assert_eq!(game_manager.dependencies.len(), 2);
-        assert!(game_manager.dependencies.contains(&"UIController".to_string()));
-        assert!(game_manager.dependencies.contains(&"ScoreTracker".to_string()));
+        let behaviors = StructAnalyzer::new().analyze_module(&items).unwrap();
+        let fields = &behaviors[0].fields;
+        assert_eq!(fields[0].attributes, vec![FieldAttribute::UdonSync]);
+        assert!(fields[1].attributes.is_empty(), "{:?}", fields[1].attributes);
     }
 
     #[test]
-    fn test_async_method_rejection() {
+    fn test_behavior_with_default_impl_has_no_missing_constructor_warning() {
+        let default_impl: Item = parse_quote! {
+            impl Default for RoundTimer {
+                fn default() -> Self {
+                    Self { round_seconds: 90.0, remaining: 90.0, running: false }
+                }
+            }
+        };
         let mut analyzer = StructAnalyzer::new();
-        
+        analyzer.analyze_module(&round_timer_items(Some(default_impl))).unwrap();
+        assert!(!analyzer.get_warnings().iter().any(|w| w.contains("no `new()` or `Default` impl")), "{:?}", analyzer.get_warnings());
+    }
+
+    fn analyze_save_system(json_attr: Option<syn::Attribute>, save_body: syn::Block) -> AnalysisResult<Vec<UdonBehaviourStruct>> {
+        let mut save_system: ItemStruct = parse_quote! {
+            #[derive(UdonBehaviour)]
+            pub struct SaveSystem {
+                level: i32,
+                saved: String,
+            }
+        };
+        save_system.attrs.extend(json_attr);
         let items: Vec<Item> = vec![
+            Item::Struct(save_system),
             parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct TestBehavior {
-                    field: i32,
+                impl UdonBehaviour for SaveSystem {
+                    fn start(&mut self) {}
                 }
             },
             parse_quote! {
-                impl UdonBehaviour for TestBehavior {
-                    async fn start(&mut self) {
-                        // Async methods not supported
-                    }
+                impl SaveSystem {
+                    pub fn save(&mut self) #save_body
                 }
-            }
+            },
         ];
-        
-        let result = analyzer.analyze_module(&items);
-        
-        // Should reject async methods
-        let has_async_error = result.is_err() || 
-            analyzer.get_errors().iter().any(|e| {
-                matches!(e, AnalysisError::AsyncMethodNotSupported { .. })
-            });
-        
-        assert!(has_async_error, "Should reject async methods in UdonBehaviour trait");
+        StructAnalyzer::new().analyze_module(&items)
     }
 
     #[test]
-    fn test_error_accumulation() {
-        let mut analyzer = StructAnalyzer::new();
-        
-        let items: Vec<Item> = vec![
-            parse_quote! {
-                #[derive(UdonBehaviour)]
-                pub struct 123InvalidName {
-                    unsupported_field: i128,
-                    #[invalid_attribute]
-                    bad_attribute_field: i32,
-                }
-            }
-        ];
-        
-        let result = analyzer.analyze_module(&items);
-        assert!(result.is_err());
-        
-        // Should accumulate multiple errors
-        let errors = analyzer.get_errors();
-        assert!(errors.len() >= 2, "Should accumulate multiple errors");
+    fn test_serde_call_is_rejected_with_vrc_json_advice() {
+        let error = analyze_save_system(None, parse_quote! {{
+            self.saved = serde_json::to_string(&self.level).unwrap();
+        }}).unwrap_err();
+
+        assert!(matches!(&error, AnalysisError::UnsupportedSerde { call, .. } if call == "serde_json::to_string"), "{:?}", error);
+        let message = error.to_string();
+        assert!(message.contains("serde is not supported in Udon; use #[udon_json]/VRCJson"), "{}", message);
+        assert!(message.contains("Mark 'SaveSystem' #[udon_json]"), "{}", message);
+
+        let error = analyze_save_system(Some(parse_quote!(#[udon_json])), parse_quote! {{
+            let restored: SaveSystem = serde_json::from_str(&self.saved).unwrap();
+        }}).unwrap_err();
+        assert!(error.to_string().contains("calls `serde_json::from_str`"), "{}", error);
+        assert!(error.to_string().contains("generated `ToJson`/`FromJson` methods"), "{}", error);
+    }
+
+    #[test]
+    fn test_vrc_json_serialization_is_clean() {
+        let behaviors = analyze_save_system(Some(parse_quote!(#[udon_json])), parse_quote! {{
+            self.saved = serde_json::to_string(&self).unwrap();
+        }}).unwrap();
+        let generated = crate::code_generator::CodeGenerator::new().generate_behavior_class(&behaviors[0]).unwrap();
+        let save = generated.methods.iter().find(|m| m.name == "Save").unwrap();
+        assert_eq!(save.body.trim(), "saved = ToJson();");
+
+        let behaviors = analyze_save_system(None, parse_quote! {{
+            let mut json = DataToken::default();
+            VRCJson::try_serialize_to_json(&self.level, JsonExportType::Minify, &mut json);
+        }});
+        assert!(behaviors.is_ok(), "{:?}", behaviors.err());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,202 @@
+//! Sync layout signatures
+//!
+//! Udon deserializes synced fields by position, so two clients running builds
+//! with different synced fields, or the same fields in another order, read
+//! each other's data into the wrong fields without any error. Every behavior
+//! with synced state therefore embeds a signature of its layout: a hash of the
+//! synced field names and types, in order. The owner stamps the signature into
+//! a synced field of its own in `OnPreSerialization`, and `OnDeserialization`
+//! logs a warning when the received signature differs from the local one, so
+//! version skew shows up in the log instead of as an unexplained desync.
+
+use crate::code_generator::GeneratedField;
+use crate::multi_behavior::{RustToCSharpTypeMapper, RustType, UdonBehaviourStruct};
+use crate::quantization::Quantization;
+
+/// Name of the constant holding the local signature
+pub const SYNC_LAYOUT_CONSTANT: &str = "SyncLayoutSignature";
+
+/// Name of the synced field carrying the owner's signature
+pub const SYNC_LAYOUT_FIELD: &str = "syncLayoutSignature";
+
+/// The ordered synced fields of a behavior
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncLayout {
+    /// One `name:type` entry per synced field, in declaration order
+    pub entries: Vec<String>,
+}
+
+impl SyncLayout {
+    /// Get the layout of a behavior, if it syncs any fields
+    pub fn for_behavior(udon_struct: &UdonBehaviourStruct) -> Option<Self> {
+        let mapper = RustToCSharpTypeMapper::new();
+        let csharp_type = |rust_type: &RustType| mapper.map_type(rust_type).unwrap_or_else(|_| format!("{:?}", rust_type));
+
+        let entries: Vec<String> = udon_struct.get_sync_fields().into_iter()
+            .map(|field| {
+                let mut entry = format!("{}:{}", field.name, csharp_type(&field.field_type));
+                // A blob's byte[] says nothing about what is packed into it
+                if let Some(blob) = udon_struct.sync_blobs.iter().find(|blob| blob.blob_field_name() == field.name) {
+                    let packed: Vec<String> = blob.fields.iter()
+                        .map(|(name, field_type)| format!("{}:{}", name, csharp_type(field_type)))
+                        .collect();
+                    entry.push_str(&format!("({})", packed.join(",")));
+                }
+                if let Some(Ok(quantization)) = Quantization::for_field(field) {
+                    entry.push_str(&format!("@{}", quantization.bits));
+                }
+                entry
+            })
+            .collect();
+
+        if entries.is_empty() {
+            None
+        } else {
+            Some(Self { entries })
+        }
+    }
+
+    /// Stable 32-bit FNV-1a hash of the layout
+    pub fn signature(&self) -> i32 {
+        let hash = self.entries.join(";").bytes().fold(0x811c9dc5u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        });
+        hash as i32
+    }
+
+    /// The signature constant and the synced field carrying it
+    pub fn fields(&self) -> Vec<GeneratedField> {
+        let signature = self.signature().to_string();
+        vec![
+            GeneratedField {
+                name: SYNC_LAYOUT_CONSTANT.to_string(),
+                field_type: "int".to_string(),
+                visibility: "private".to_string(),
+                attributes: Vec::new(),
+                default_value: Some(signature.clone()),
+                declaration: format!("    private const int {} = {};", SYNC_LAYOUT_CONSTANT, signature),
+            },
+            GeneratedField {
+                name: SYNC_LAYOUT_FIELD.to_string(),
+                field_type: "int".to_string(),
+                visibility: "private".to_string(),
+                attributes: vec!["[UdonSynced]".to_string()],
+                default_value: Some(SYNC_LAYOUT_CONSTANT.to_string()),
+                declaration: format!("    [UdonSynced] private int {} = {};", SYNC_LAYOUT_FIELD, SYNC_LAYOUT_CONSTANT),
+            },
+        ]
+    }
+
+    /// Statements of `OnPreSerialization` stamping the local signature.
+    ///
+    /// The field is restamped on every send because a client that took over
+    /// ownership would otherwise pass on the signature it last received.
+    pub fn stamp_lines(&self) -> Vec<String> {
+        vec![format!("        {} = {};", SYNC_LAYOUT_FIELD, SYNC_LAYOUT_CONSTANT)]
+    }
+
+    /// Statements of `OnDeserialization` warning about a received signature that differs
+    pub fn check_lines(&self, behavior_name: &str) -> Vec<String> {
+        vec![
+            format!("        if ({} != {})", SYNC_LAYOUT_FIELD, SYNC_LAYOUT_CONSTANT),
+            "        {".to_string(),
+            format!(
+                "            Debug.LogWarning(\"[{}] Sync layout mismatch: received \" + {} + \", expected \" + {} + \"; the owner may be running a different build\");",
+                behavior_name, SYNC_LAYOUT_FIELD, SYNC_LAYOUT_CONSTANT
+            ),
+            "        }".to_string(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::code_generator::CodeGenerator;
+    use crate::struct_analyzer::StructAnalyzer;
+
+    fn scoreboard(fields: syn::ItemStruct) -> UdonBehaviourStruct {
+        let items: Vec<syn::Item> = vec![
+            syn::Item::Struct(fields),
+            syn::parse_quote! {
+                impl UdonBehaviour for Scoreboard {
+                    fn start(&mut self) {}
+                    fn on_post_deserialization(&mut self) {
+                        self.refresh();
+                    }
+                }
+            },
+            syn::parse_quote! {
+                impl Scoreboard {
+                    pub fn refresh(&mut self) {}
+                }
+            },
+        ];
+        StructAnalyzer::new().analyze_module(&items).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_reordering_synced_fields_changes_signature() {
+        let original = scoreboard(syn::parse_quote! {
+            #[derive(UdonBehaviour)]
+            pub struct Scoreboard {
+                #[udon_sync]
+                pub round: i32,
+                #[udon_sync]
+                pub leader_score: i32,
+                pub local_score: i32,
+            }
+        });
+        let reordered = scoreboard(syn::parse_quote! {
+            #[derive(UdonBehaviour)]
+            pub struct Scoreboard {
+                pub local_score: i32,
+                #[udon_sync]
+                pub leader_score: i32,
+                #[udon_sync]
+                pub round: i32,
+            }
+        });
+
+        let layout = SyncLayout::for_behavior(&original).unwrap();
+        assert_eq!(layout.entries, vec!["round:int", "leader_score:int"]);
+        assert_eq!(layout.signature(), SyncLayout::for_behavior(&original).unwrap().signature());
+        assert_ne!(layout.signature(), SyncLayout::for_behavior(&reordered).unwrap().signature());
+
+        let unsynced = scoreboard(syn::parse_quote! {
+            #[derive(UdonBehaviour)]
+            pub struct Scoreboard {
+                pub local_score: i32,
+            }
+        });
+        assert!(SyncLayout::for_behavior(&unsynced).is_none());
+    }
+
+    #[test]
+    fn test_deserialization_handler_checks_the_received_signature() {
+        let behavior = scoreboard(syn::parse_quote! {
+            #[derive(UdonBehaviour)]
+            pub struct Scoreboard {
+                #[udon_sync]
+                pub round: i32,
+            }
+        });
+        let signature = SyncLayout::for_behavior(&behavior).unwrap().signature();
+
+        let generated = CodeGenerator::new().generate_behavior_class(&behavior).unwrap();
+        assert!(generated.source_code.contains(&format!("    private const int SyncLayoutSignature = {};", signature)));
+        assert!(generated.source_code.contains("    [UdonSynced] private int syncLayoutSignature = SyncLayoutSignature;"));
+
+        let deserialization = generated.methods.iter().find(|m| m.name == "OnDeserialization").unwrap();
+        assert!(deserialization.body.starts_with(&[
+            "        if (syncLayoutSignature != SyncLayoutSignature)",
+            "        {",
+            "            Debug.LogWarning(\"[Scoreboard] Sync layout mismatch: received \" + syncLayoutSignature + \", expected \" + SyncLayoutSignature + \"; the owner may be running a different build\");",
+            "        }",
+        ].join("\n")), "{}", deserialization.body);
+        assert!(deserialization.body.contains("Refresh();"), "{}", deserialization.body);
+
+        let pre_serialization = generated.methods.iter().find(|m| m.name == "OnPreSerialization").unwrap();
+        assert_eq!(pre_serialization.body, "        syncLayoutSignature = SyncLayoutSignature;");
+    }
+}
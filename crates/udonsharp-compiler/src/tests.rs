@@ -150,4 +150,54 @@ mod tests {
         let summary = stats.summary();
         assert!(summary.contains("20.0% reduction"));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_phase_log_marks_failed_code_generation() {
+        use crate::phase_log::{PhaseStatus, PipelinePhase};
+        use crate::pipeline::CompilationPipeline;
+
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(project.path().join("src")).unwrap();
+        std::fs::write(project.path().join("src/lib.rs"), "pub fn main() {}").unwrap();
+
+        // Point the output directory at a regular file so emitting C# fails
+        let blocker = tempfile::NamedTempFile::new().unwrap();
+        let config = UdonSharpConfig {
+            output_directory: Some(blocker.path().to_string_lossy().into_owned()),
+            ..UdonSharpConfig::default()
+        };
+
+        let pipeline = CompilationPipeline::new(config);
+        assert!(pipeline.compile_project(project.path()).await.is_err());
+
+        let log = pipeline.phase_log();
+        assert_eq!(log.status(PipelinePhase::ParseWasm), Some(&PhaseStatus::Completed));
+        assert_eq!(log.status(PipelinePhase::AnalyzeOop), Some(&PhaseStatus::Completed));
+        assert!(matches!(log.status(PipelinePhase::EmitCSharp), Some(PhaseStatus::Failed(_))));
+        assert_eq!(log.failed_phase().map(|r| r.phase), Some(PipelinePhase::EmitCSharp));
+    }
+
+    #[tokio::test]
+    async fn test_emit_wasm_writes_intermediate_module() {
+        use crate::pipeline::CompilationPipeline;
+
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(project.path().join("src")).unwrap();
+        std::fs::write(project.path().join("src/lib.rs"), "pub fn main() {}").unwrap();
+
+        let output = tempfile::tempdir().unwrap();
+        let emit_path = output.path().join("debug/intermediate.wasm");
+        let config = UdonSharpConfig {
+            output_directory: Some(output.path().to_string_lossy().into_owned()),
+            emit_wasm: Some(emit_path.clone()),
+            ..UdonSharpConfig::default()
+        };
+
+        let pipeline = CompilationPipeline::new(config);
+        let _ = pipeline.compile_project(project.path()).await;
+
+        let wasm = std::fs::read(&emit_path).unwrap();
+        assert!(!wasm.is_empty());
+        assert_eq!(&wasm[..4], b"\0asm");
+    }
+}
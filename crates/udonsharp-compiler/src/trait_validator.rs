@@ -4,7 +4,7 @@
 //! #[derive(UdonBehaviour)] properly implement the UdonBehaviour trait
 //! with all required methods.
 
-use crate::multi_behavior::{UdonBehaviourStruct, UdonBehaviourTraitImpl, StructMethod};
+use crate::multi_behavior::{UdonBehaviourStruct, UdonBehaviourTraitImpl, StructMethod, input_event, INPUT_EVENTS, INPUT_EVENT_ARGS};
 use std::collections::HashSet;
 
 /// Result type for trait validation operations
@@ -99,7 +99,9 @@ impl TraitValidator {
         optional_methods.insert("on_pickup_use_up".to_string());
         optional_methods.insert("on_station_entered".to_string());
         optional_methods.insert("on_station_exited".to_string());
+        optional_methods.insert("on_pre_serialization".to_string());
         optional_methods.insert("on_post_deserialization".to_string());
+        optional_methods.extend(INPUT_EVENTS.iter().map(|event| event.method.to_string()));
 
         let mut unity_event_methods = HashSet::new();
         unity_event_methods.insert("start".to_string());
@@ -123,7 +125,9 @@ impl TraitValidator {
         unity_event_methods.insert("on_pickup_use_up".to_string());
         unity_event_methods.insert("on_station_entered".to_string());
         unity_event_methods.insert("on_station_exited".to_string());
+        unity_event_methods.insert("on_pre_serialization".to_string());
         unity_event_methods.insert("on_post_deserialization".to_string());
+        unity_event_methods.extend(INPUT_EVENTS.iter().map(|event| event.method.to_string()));
 
         Self {
             required_methods,
@@ -308,7 +312,7 @@ impl TraitValidator {
                     });
                 }
             }
-            "on_post_deserialization" => {
+            "on_pre_serialization" | "on_post_deserialization" => {
                 // This method should have no parameters
                 if !method.parameters.is_empty() {
                     return Err(ValidationError::InvalidMethodSignature {
@@ -319,6 +323,15 @@ impl TraitValidator {
                     });
                 }
             }
+            // Input events take the input value and the event arguments
+            name if input_event(name).is_some() && method.parameters.len() != 2 => {
+                return Err(ValidationError::InvalidMethodSignature {
+                    struct_name: struct_name.to_string(),
+                    method_name: method.name.clone(),
+                    expected: expected_signature,
+                    found: actual_signature,
+                });
+            }
             _ => {
                 // Custom methods - less strict validation
             }
@@ -353,12 +366,13 @@ impl TraitValidator {
                 "fn {}(&mut self, player: VRCPlayerApi)".to_string()
             }
             "on_pickup" | "on_drop" | "on_pickup_use_down" | "on_pickup_use_up" |
-            "on_post_deserialization" => {
+            "on_pre_serialization" | "on_post_deserialization" => {
                 "fn {}(&mut self)".to_string()
             }
-            _ => {
-                "fn {}(&mut self, ...)".to_string()
-            }
+            name => match input_event(name) {
+                Some(event) => format!("fn {{}}(&mut self, value: {}, args: {})", event.rust_value_type, INPUT_EVENT_ARGS),
+                None => "fn {}(&mut self, ...)".to_string(),
+            },
         }
     }
 
@@ -0,0 +1,677 @@
+//! Lowering of simple behaviors to Udon assembly
+//!
+//! Creators who do not use UdonSharp can still run behaviors written in Rust
+//! by compiling them to raw Udon assembly (`.uasm`). Only a subset of Rust
+//! maps onto Udon's stack machine without UdonSharp's runtime support: field
+//! reads and writes, arithmetic and comparisons on primitive fields and
+//! literals, `if`/`else`, `return`, logging and event sends. Events must take
+//! no parameters and return nothing. Anything else is reported as an error
+//! naming the construct, so the behavior can be compiled through UdonSharp
+//! instead.
+
+use crate::code_generator::{to_pascal_case, CodeGenerator};
+use crate::multi_behavior::{MethodAttribute, RustType, StructMethod, UdonBehaviourStruct};
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::fmt;
+use syn::{BinOp, Block, Expr, Lit, Stmt, UnOp};
+use wasm2usharp_enhanced::{UdonAssemblyProgram, UdonInstruction, UdonVariable};
+
+/// Extern that sends a custom event to a behavior
+const SEND_CUSTOM_EVENT: &str = "VRCUdonCommonInterfacesIUdonEventReceiver.__SendCustomEvent__SystemString__SystemVoid";
+
+/// Extern that requests serialization of the synced fields of a behavior
+const REQUEST_SERIALIZATION: &str = "VRCUdonCommonInterfacesIUdonEventReceiver.__RequestSerialization__SystemVoid";
+
+/// Name of the heap variable holding the behavior itself
+const THIS_VARIABLE: &str = "__this";
+
+/// Errors that can occur while lowering a behavior to Udon assembly
+#[derive(Debug, Clone)]
+pub enum UdonAssemblyLoweringError {
+    /// A field type has no Udon assembly equivalent
+    UnsupportedField { behavior: String, field: String, rust_type: String },
+    /// A method cannot be exposed as an Udon event
+    UnsupportedMethod { behavior: String, method: String, reason: String },
+    /// A method body uses a construct outside the supported subset
+    UnsupportedConstruct { behavior: String, method: String, construct: String },
+    /// A method body could not be parsed
+    InvalidBody { behavior: String, method: String, message: String },
+    /// The generated program is inconsistent
+    Render { behavior: String, message: String },
+}
+
+impl fmt::Display for UdonAssemblyLoweringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UdonAssemblyLoweringError::UnsupportedField { behavior, field, rust_type } => write!(
+                f,
+                "Field '{}::{}' has type {}, which requires UdonSharp",
+                behavior, field, rust_type
+            ),
+            UdonAssemblyLoweringError::UnsupportedMethod { behavior, method, reason } => write!(
+                f,
+                "Method '{}::{}' {}, which requires UdonSharp",
+                behavior, method, reason
+            ),
+            UdonAssemblyLoweringError::UnsupportedConstruct { behavior, method, construct } => write!(
+                f,
+                "Method '{}::{}' uses {}, which requires UdonSharp",
+                behavior, method, construct
+            ),
+            UdonAssemblyLoweringError::InvalidBody { behavior, method, message } => {
+                write!(f, "Failed to parse the body of '{}::{}': {}", behavior, method, message)
+            }
+            UdonAssemblyLoweringError::Render { behavior, message } => {
+                write!(f, "Failed to emit Udon assembly for '{}': {}", behavior, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UdonAssemblyLoweringError {}
+
+type LoweringResult<T> = Result<T, UdonAssemblyLoweringError>;
+
+/// Lower a behavior to Udon assembly
+pub fn lower_to_udon_assembly(behavior: &UdonBehaviourStruct) -> LoweringResult<String> {
+    let mut lowerer = AssemblyLowerer::new(behavior)?;
+    for method in &behavior.methods {
+        lowerer.lower_event(method)?;
+    }
+    lowerer.program.render().map_err(|e| UdonAssemblyLoweringError::Render {
+        behavior: behavior.name.clone(),
+        message: e.to_string(),
+    })
+}
+
+/// Lowers the methods of one behavior into an Udon program
+struct AssemblyLowerer<'a> {
+    behavior: &'a UdonBehaviourStruct,
+    program: UdonAssemblyProgram,
+    /// Udon type of every field
+    field_types: HashMap<String, &'static str>,
+    /// Udon event name of every method
+    event_names: HashMap<String, String>,
+    /// Constant variables, keyed by type and value
+    constants: HashMap<(String, String), String>,
+    /// Method currently being lowered, for error messages
+    method: String,
+    next_temporary: usize,
+    next_label: usize,
+}
+
+impl<'a> AssemblyLowerer<'a> {
+    fn new(behavior: &'a UdonBehaviourStruct) -> LoweringResult<Self> {
+        let mut program = UdonAssemblyProgram::new();
+        let mut field_types = HashMap::new();
+        for field in &behavior.fields {
+            let udon_type = udon_type(&field.field_type).ok_or_else(|| UdonAssemblyLoweringError::UnsupportedField {
+                behavior: behavior.name.clone(),
+                field: field.name.clone(),
+                rust_type: format!("{:?}", field.field_type),
+            })?;
+
+            let value = field.default_value.as_deref()
+                .map(assembly_literal)
+                .unwrap_or_else(|| default_literal(udon_type).to_string());
+            let mut variable = UdonVariable::new(&field.name, udon_type).with_value(value);
+            if field.is_serialized() {
+                variable = variable.exported();
+            }
+            if field.has_sync_attribute() {
+                variable = variable.synced();
+            }
+            program.add_variable(variable);
+            field_types.insert(field.name.clone(), udon_type);
+        }
+
+        let generator = CodeGenerator::new();
+        let event_names = behavior.methods.iter()
+            .map(|method| (method.name.clone(), event_name(&generator, method)))
+            .collect();
+
+        Ok(Self {
+            behavior,
+            program,
+            field_types,
+            event_names,
+            constants: HashMap::new(),
+            method: String::new(),
+            next_temporary: 0,
+            next_label: 0,
+        })
+    }
+
+    /// Lower a method to an exported event
+    fn lower_event(&mut self, method: &StructMethod) -> LoweringResult<()> {
+        self.method = method.name.clone();
+        if !method.parameters.is_empty() {
+            return Err(self.unsupported_method("takes parameters"));
+        }
+        if method.return_type != RustType::Unit {
+            return Err(self.unsupported_method("returns a value"));
+        }
+        if method.is_async {
+            return Err(self.unsupported_method("is async"));
+        }
+
+        let mut code = Vec::new();
+        if let Some(body) = &method.body {
+            let block: Block = syn::parse_str(body).map_err(|e| UdonAssemblyLoweringError::InvalidBody {
+                behavior: self.behavior.name.clone(),
+                method: method.name.clone(),
+                message: e.to_string(),
+            })?;
+            self.lower_block(&block, &mut code)?;
+        }
+
+        let name = self.event_names[&method.name].clone();
+        self.program.add_event(name, code);
+        Ok(())
+    }
+
+    fn lower_block(&mut self, block: &Block, code: &mut Vec<UdonInstruction>) -> LoweringResult<()> {
+        for stmt in &block.stmts {
+            match stmt {
+                Stmt::Expr(expr, _) => self.lower_statement(expr, code)?,
+                Stmt::Local(_) => return Err(self.unsupported("local variables")),
+                Stmt::Macro(mac) => {
+                    return Err(self.unsupported(&format!("the macro `{}!`", mac.mac.path.to_token_stream())))
+                }
+                Stmt::Item(_) => return Err(self.unsupported("nested items")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Lower an expression evaluated for its effect
+    fn lower_statement(&mut self, expr: &Expr, code: &mut Vec<UdonInstruction>) -> LoweringResult<()> {
+        match expr {
+            Expr::Assign(assign) => {
+                let (field, field_type) = self.field_target(&assign.left)?;
+                let value = self.lower_expr(&assign.right, Some(field_type), code)?;
+                code.push(UdonInstruction::Push(value));
+                code.push(UdonInstruction::Push(field));
+                code.push(UdonInstruction::Copy);
+                Ok(())
+            }
+            Expr::Binary(binary) if compound_operator(&binary.op).is_some() => {
+                let (field, field_type) = self.field_target(&binary.left)?;
+                let operator = compound_operator(&binary.op).unwrap_or_default();
+                if !is_numeric(field_type) {
+                    return Err(self.unsupported(&format!("`{}=` on a {} field", symbol(&binary.op), field_type)));
+                }
+                let value = self.lower_expr(&binary.right, Some(field_type), code)?;
+                code.push(UdonInstruction::Push(field.clone()));
+                code.push(UdonInstruction::Push(value));
+                code.push(UdonInstruction::Push(field));
+                code.push(UdonInstruction::Extern(binary_extern(field_type, operator, field_type)));
+                Ok(())
+            }
+            Expr::If(expr_if) => {
+                let condition = self.lower_expr(&expr_if.cond, Some("SystemBoolean"), code)?;
+                let else_label = self.label();
+                let end_label = self.label();
+                code.push(UdonInstruction::Push(condition));
+                code.push(UdonInstruction::JumpIfFalse(else_label.clone()));
+                self.lower_block(&expr_if.then_branch, code)?;
+                code.push(UdonInstruction::Jump(end_label.clone()));
+                code.push(UdonInstruction::Label(else_label));
+                if let Some((_, else_branch)) = &expr_if.else_branch {
+                    self.lower_statement(else_branch, code)?;
+                }
+                code.push(UdonInstruction::Label(end_label));
+                Ok(())
+            }
+            Expr::Block(expr_block) => self.lower_block(&expr_block.block, code),
+            Expr::Return(ret) if ret.expr.is_none() => {
+                code.push(UdonInstruction::Return);
+                Ok(())
+            }
+            Expr::MethodCall(call) if is_self(&call.receiver) => {
+                let method = call.method.to_string();
+                let event = match (method.as_str(), call.args.first()) {
+                    ("send_custom_event", Some(Expr::Lit(lit))) if call.args.len() == 1 => match &lit.lit {
+                        Lit::Str(name) => name.value(),
+                        _ => return Err(self.unsupported("`send_custom_event` with a non-string event name")),
+                    },
+                    ("request_serialization", None) => {
+                        let this = self.this_variable();
+                        code.push(UdonInstruction::Push(this));
+                        code.push(UdonInstruction::Extern(REQUEST_SERIALIZATION.to_string()));
+                        return Ok(());
+                    }
+                    (_, None) if self.event_names.contains_key(&method) => self.event_names[&method].clone(),
+                    _ => return Err(self.unsupported(&format!("the method call `self.{}(..)`", method))),
+                };
+                self.send_custom_event(&event, code);
+                Ok(())
+            }
+            Expr::Call(call) => {
+                let Expr::Path(path) = &*call.func else {
+                    return Err(self.unsupported("a computed function call"));
+                };
+                let function = path.path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+                let args: Vec<_> = call.args.iter().collect();
+                match (function.as_str(), args.as_slice()) {
+                    ("log_info" | "debug_log" | "log_warning" | "log_warn" | "log_error", [message]) => {
+                        let message = self.lower_expr(message, None, code)?;
+                        let method = match function.as_str() {
+                            "log_warning" | "log_warn" => "LogWarning",
+                            "log_error" => "LogError",
+                            _ => "Log",
+                        };
+                        code.push(UdonInstruction::Push(message));
+                        code.push(UdonInstruction::Extern(format!("UnityEngineDebug.__{}__SystemObject__SystemVoid", method)));
+                        Ok(())
+                    }
+                    ("request_serialization", []) => {
+                        let this = self.this_variable();
+                        code.push(UdonInstruction::Push(this));
+                        code.push(UdonInstruction::Extern(REQUEST_SERIALIZATION.to_string()));
+                        Ok(())
+                    }
+                    _ => Err(self.unsupported(&format!("the function call `{}(..)`", function))),
+                }
+            }
+            other => Err(self.unsupported(&describe(other))),
+        }
+    }
+
+    /// Lower an expression to the heap variable holding its value
+    fn lower_expr(&mut self, expr: &Expr, expected: Option<&'static str>, code: &mut Vec<UdonInstruction>) -> LoweringResult<String> {
+        match expr {
+            Expr::Paren(paren) => self.lower_expr(&paren.expr, expected, code),
+            Expr::Lit(lit) => self.literal(&lit.lit, expected, false),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                if let Expr::Lit(lit) = &*unary.expr {
+                    return self.literal(&lit.lit, expected, true);
+                }
+                let operand_type = self.expr_type(&unary.expr).or(expected).unwrap_or("SystemInt32");
+                let operand = self.lower_expr(&unary.expr, Some(operand_type), code)?;
+                self.unary(operand, operand_type, "UnaryMinus", code)
+            }
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => {
+                let operand = self.lower_expr(&unary.expr, Some("SystemBoolean"), code)?;
+                self.unary(operand, "SystemBoolean", "UnaryNegation", code)
+            }
+            Expr::Field(_) => self.field_target(expr).map(|(field, _)| field),
+            Expr::Binary(binary) => {
+                let Some((operator, is_comparison)) = binary_operator(&binary.op) else {
+                    return Err(self.unsupported(&format!("the `{}` operator", symbol(&binary.op))));
+                };
+                let operand_type = if matches!(binary.op, BinOp::And(_) | BinOp::Or(_)) {
+                    "SystemBoolean"
+                } else {
+                    self.expr_type(&binary.left)
+                        .or_else(|| self.expr_type(&binary.right))
+                        .or(if is_comparison { None } else { expected })
+                        .unwrap_or("SystemInt32")
+                };
+                if !is_comparison && operand_type != "SystemBoolean" && !is_numeric(operand_type) {
+                    return Err(self.unsupported(&format!("`{}` on {} values", symbol(&binary.op), operand_type)));
+                }
+
+                let left = self.lower_expr(&binary.left, Some(operand_type), code)?;
+                let right = self.lower_expr(&binary.right, Some(operand_type), code)?;
+                let result_type = if is_comparison { "SystemBoolean" } else { operand_type };
+                let result = self.temporary(result_type);
+                code.push(UdonInstruction::Push(left));
+                code.push(UdonInstruction::Push(right));
+                code.push(UdonInstruction::Push(result.clone()));
+                code.push(UdonInstruction::Extern(binary_extern(operand_type, operator, result_type)));
+                Ok(result)
+            }
+            other => Err(self.unsupported(&describe(other))),
+        }
+    }
+
+    /// Emit a unary operator extern into a new temporary
+    fn unary(&mut self, operand: String, operand_type: &'static str, operator: &str, code: &mut Vec<UdonInstruction>) -> LoweringResult<String> {
+        let result = self.temporary(operand_type);
+        code.push(UdonInstruction::Push(operand));
+        code.push(UdonInstruction::Push(result.clone()));
+        code.push(UdonInstruction::Extern(format!(
+            "{ty}.__op_{op}__{ty}__{ty}",
+            ty = operand_type,
+            op = operator
+        )));
+        Ok(result)
+    }
+
+    /// Get the variable and type of a `self` field
+    fn field_target(&self, expr: &Expr) -> LoweringResult<(String, &'static str)> {
+        if let Expr::Field(field) = expr {
+            if let (true, syn::Member::Named(member)) = (is_self(&field.base), &field.member) {
+                let name = member.to_string();
+                if let Some(udon_type) = self.field_types.get(&name) {
+                    return Ok((name, udon_type));
+                }
+            }
+        }
+        Err(self.unsupported(&format!("the place expression `{}`", expr.to_token_stream())))
+    }
+
+    /// Udon type of an expression, if it does not depend on context
+    fn expr_type(&self, expr: &Expr) -> Option<&'static str> {
+        match expr {
+            Expr::Paren(paren) => self.expr_type(&paren.expr),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => Some("SystemBoolean"),
+            Expr::Unary(unary) => self.expr_type(&unary.expr),
+            Expr::Field(_) => self.field_target(expr).ok().map(|(_, ty)| ty),
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Bool(_) => Some("SystemBoolean"),
+                Lit::Str(_) => Some("SystemString"),
+                Lit::Int(int) => suffix_type(int.suffix()),
+                Lit::Float(float) => suffix_type(float.suffix()).or(Some("SystemSingle")),
+                _ => None,
+            },
+            Expr::Binary(binary) => match binary_operator(&binary.op) {
+                Some((_, true)) => Some("SystemBoolean"),
+                _ => self.expr_type(&binary.left).or_else(|| self.expr_type(&binary.right)),
+            },
+            _ => None,
+        }
+    }
+
+    /// Get the constant variable holding a literal
+    fn literal(&mut self, lit: &Lit, expected: Option<&'static str>, negated: bool) -> LoweringResult<String> {
+        let sign = if negated { "-" } else { "" };
+        let (udon_type, value) = match lit {
+            Lit::Bool(value) if !negated => ("SystemBoolean", value.value.to_string()),
+            Lit::Str(value) if !negated => ("SystemString", format!("{:?}", value.value())),
+            Lit::Int(int) => {
+                let udon_type = suffix_type(int.suffix())
+                    .or(expected.filter(|ty| is_numeric(ty)))
+                    .unwrap_or("SystemInt32");
+                (udon_type, format!("{}{}", sign, int.base10_digits()))
+            }
+            Lit::Float(float) => {
+                let udon_type = suffix_type(float.suffix())
+                    .or(expected.filter(|ty| matches!(*ty, "SystemSingle" | "SystemDouble")))
+                    .unwrap_or("SystemSingle");
+                (udon_type, format!("{}{}", sign, float.base10_digits()))
+            }
+            _ => return Err(self.unsupported(&format!("the literal `{}{}`", sign, lit.to_token_stream()))),
+        };
+
+        let key = (udon_type.to_string(), value.clone());
+        if let Some(name) = self.constants.get(&key) {
+            return Ok(name.clone());
+        }
+        let name = format!("__const_{}", self.constants.len());
+        self.program.add_variable(UdonVariable::new(&name, udon_type).with_value(value));
+        self.constants.insert(key, name.clone());
+        Ok(name)
+    }
+
+    /// Declare a new temporary variable
+    fn temporary(&mut self, udon_type: &str) -> String {
+        let name = format!("__temp_{}", self.next_temporary);
+        self.next_temporary += 1;
+        self.program.add_variable(UdonVariable::new(&name, udon_type));
+        name
+    }
+
+    fn label(&mut self) -> String {
+        let name = format!("__label_{}", self.next_label);
+        self.next_label += 1;
+        name
+    }
+
+    /// Get the variable referring to the behavior itself, declaring it on first use
+    fn this_variable(&mut self) -> String {
+        if !self.program.has_variable(THIS_VARIABLE) {
+            self.program.add_variable(UdonVariable::new(THIS_VARIABLE, "VRCUdonUdonBehaviour").with_value("this"));
+        }
+        THIS_VARIABLE.to_string()
+    }
+
+    fn send_custom_event(&mut self, event: &str, code: &mut Vec<UdonInstruction>) {
+        let this = self.this_variable();
+        let name = self.literal(&syn::parse_quote!(#event), None, false)
+            .expect("string literals are always supported");
+        code.push(UdonInstruction::Push(this));
+        code.push(UdonInstruction::Push(name));
+        code.push(UdonInstruction::Extern(SEND_CUSTOM_EVENT.to_string()));
+    }
+
+    fn unsupported(&self, construct: &str) -> UdonAssemblyLoweringError {
+        UdonAssemblyLoweringError::UnsupportedConstruct {
+            behavior: self.behavior.name.clone(),
+            method: self.method.clone(),
+            construct: construct.to_string(),
+        }
+    }
+
+    fn unsupported_method(&self, reason: &str) -> UdonAssemblyLoweringError {
+        UdonAssemblyLoweringError::UnsupportedMethod {
+            behavior: self.behavior.name.clone(),
+            method: self.method.clone(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Udon event name of a method: `_start` for Unity events, the event name for custom events
+fn event_name(generator: &CodeGenerator, method: &StructMethod) -> String {
+    if let Some(unity_name) = generator.map_unity_method_name(&method.name) {
+        let mut chars = unity_name.chars();
+        let first = chars.next().map(|c| c.to_ascii_lowercase()).unwrap_or_default();
+        return format!("_{}{}", first, chars.as_str());
+    }
+    method.attributes.iter()
+        .find_map(|attribute| match attribute {
+            MethodAttribute::UdonEvent(name) if !name.is_empty() => Some(name.clone()),
+            MethodAttribute::UdonNetworkEvent { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .unwrap_or_else(|| to_pascal_case(&method.name))
+}
+
+/// Udon type of a field type that can live on the heap without UdonSharp
+fn udon_type(rust_type: &RustType) -> Option<&'static str> {
+    match rust_type {
+        RustType::Bool => Some("SystemBoolean"),
+        RustType::I32 => Some("SystemInt32"),
+        RustType::I64 => Some("SystemInt64"),
+        RustType::U32 => Some("SystemUInt32"),
+        RustType::F32 => Some("SystemSingle"),
+        RustType::F64 => Some("SystemDouble"),
+        RustType::String => Some("SystemString"),
+        RustType::GameObject => Some("UnityEngineGameObject"),
+        RustType::Transform => Some("UnityEngineTransform"),
+        _ => None,
+    }
+}
+
+fn is_numeric(udon_type: &str) -> bool {
+    matches!(udon_type, "SystemInt32" | "SystemInt64" | "SystemUInt32" | "SystemSingle" | "SystemDouble")
+}
+
+/// Udon type named by a literal suffix such as `i64` or `f32`
+fn suffix_type(suffix: &str) -> Option<&'static str> {
+    match suffix {
+        "i32" => Some("SystemInt32"),
+        "i64" => Some("SystemInt64"),
+        "u32" => Some("SystemUInt32"),
+        "f32" => Some("SystemSingle"),
+        "f64" => Some("SystemDouble"),
+        _ => None,
+    }
+}
+
+/// Initial value of a field without a default
+fn default_literal(udon_type: &str) -> &'static str {
+    match udon_type {
+        "SystemBoolean" => "false",
+        ty if is_numeric(ty) => "0",
+        _ => "null",
+    }
+}
+
+/// Convert a C# default literal to its Udon assembly form
+fn assembly_literal(csharp: &str) -> String {
+    if csharp.starts_with('"') {
+        return csharp.to_string();
+    }
+    csharp.trim_end_matches(['f', 'F', 'd', 'D', 'L', 'l', 'u', 'U']).to_string()
+}
+
+/// Operator name and whether the operator is a comparison
+fn binary_operator(op: &BinOp) -> Option<(&'static str, bool)> {
+    match op {
+        BinOp::Add(_) => Some(("Addition", false)),
+        BinOp::Sub(_) => Some(("Subtraction", false)),
+        BinOp::Mul(_) => Some(("Multiplication", false)),
+        BinOp::Div(_) => Some(("Division", false)),
+        BinOp::And(_) => Some(("ConditionalAnd", false)),
+        BinOp::Or(_) => Some(("ConditionalOr", false)),
+        BinOp::Lt(_) => Some(("LessThan", true)),
+        BinOp::Le(_) => Some(("LessThanOrEqual", true)),
+        BinOp::Gt(_) => Some(("GreaterThan", true)),
+        BinOp::Ge(_) => Some(("GreaterThanOrEqual", true)),
+        BinOp::Eq(_) => Some(("Equality", true)),
+        BinOp::Ne(_) => Some(("Inequality", true)),
+        _ => None,
+    }
+}
+
+/// Operator name of a compound assignment
+fn compound_operator(op: &BinOp) -> Option<&'static str> {
+    match op {
+        BinOp::AddAssign(_) => Some("Addition"),
+        BinOp::SubAssign(_) => Some("Subtraction"),
+        BinOp::MulAssign(_) => Some("Multiplication"),
+        BinOp::DivAssign(_) => Some("Division"),
+        _ => None,
+    }
+}
+
+fn binary_extern(operand_type: &str, operator: &str, result_type: &str) -> String {
+    format!("{ty}.__op_{op}__{ty}_{ty}__{result}", ty = operand_type, op = operator, result = result_type)
+}
+
+fn symbol(op: &BinOp) -> String {
+    op.to_token_stream().to_string()
+}
+
+fn is_self(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(path) if path.path.is_ident("self"))
+}
+
+/// Describe an unsupported expression for error messages
+fn describe(expr: &Expr) -> String {
+    match expr {
+        Expr::ForLoop(_) => "a `for` loop".to_string(),
+        Expr::While(_) => "a `while` loop".to_string(),
+        Expr::Loop(_) => "a `loop`".to_string(),
+        Expr::Match(_) => "a `match` expression".to_string(),
+        Expr::Closure(_) => "a closure".to_string(),
+        Expr::Index(_) => "indexing".to_string(),
+        Expr::Return(_) => "a `return` with a value".to_string(),
+        Expr::Macro(mac) => format!("the macro `{}!`", mac.mac.path.to_token_stream()),
+        Expr::MethodCall(call) => format!("the method call `.{}(..)`", call.method),
+        Expr::Cast(_) => "a cast".to_string(),
+        other => format!("the expression `{}`", other.to_token_stream()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::struct_analyzer::StructAnalyzer;
+
+    fn analyze(source: &str) -> Vec<UdonBehaviourStruct> {
+        let file: syn::File = syn::parse_str(source).unwrap();
+        StructAnalyzer::new().analyze_module(&file.items).unwrap()
+    }
+
+    #[test]
+    fn test_counter_lowers_to_udon_assembly() {
+        let behaviors = analyze(r#"
+            #[derive(UdonBehaviour)]
+            pub struct Counter {
+                #[udon_sync]
+                pub count: i32,
+                limit: i32,
+            }
+
+            impl UdonBehaviour for Counter {
+                fn start(&mut self) {
+                    self.count = 0;
+                }
+            }
+
+            impl Counter {
+                #[udon_event("Increment")]
+                pub fn increment(&mut self) {
+                    self.count += 1;
+                    if self.count >= self.limit {
+                        self.reset();
+                    }
+                }
+
+                pub fn reset(&mut self) {
+                    self.count = 0;
+                    log_info("counter reset");
+                }
+            }
+        "#);
+
+        let uasm = lower_to_udon_assembly(&behaviors[0]).unwrap();
+        assert!(uasm.contains("    .export count\n"));
+        assert!(uasm.contains("    .sync count, none\n"));
+        assert!(uasm.contains("    count: %SystemInt32, 0\n"));
+        assert!(uasm.contains("    limit: %SystemInt32, 0\n"));
+        assert!(uasm.contains("    .export _start\n    _start:\n"));
+        assert!(uasm.contains("    .export Increment\n    Increment:\n"));
+        assert!(uasm.contains("    .export Reset\n    Reset:\n"));
+        assert!(uasm.contains(
+            "PUSH, count\n        PUSH, __const_1\n        PUSH, count\n        \
+             EXTERN, \"SystemInt32.__op_Addition__SystemInt32_SystemInt32__SystemInt32\""
+        ));
+        assert!(uasm.contains("EXTERN, \"SystemInt32.__op_GreaterThanOrEqual__SystemInt32_SystemInt32__SystemBoolean\""));
+        assert!(uasm.contains("JUMP_IF_FALSE, 0x"));
+        assert!(uasm.contains(&format!("EXTERN, \"{}\"", SEND_CUSTOM_EVENT)));
+        assert!(uasm.contains("__this: %VRCUdonUdonBehaviour, this"));
+        assert!(uasm.contains("\"Reset\""));
+        assert!(uasm.contains("EXTERN, \"UnityEngineDebug.__Log__SystemObject__SystemVoid\""));
+        assert!(uasm.trim_end().ends_with(".code_end"));
+    }
+
+    #[test]
+    fn test_complex_behavior_is_rejected() {
+        let behaviors = analyze(r#"
+            #[derive(UdonBehaviour)]
+            pub struct Leaderboard {
+                scores: Vec<i32>,
+            }
+
+            impl UdonBehaviour for Leaderboard {
+                fn start(&mut self) {}
+            }
+        "#);
+        let error = lower_to_udon_assembly(&behaviors[0]).unwrap_err();
+        assert!(matches!(error, UdonAssemblyLoweringError::UnsupportedField { ref field, .. } if field == "scores"));
+
+        let behaviors = analyze(r#"
+            #[derive(UdonBehaviour)]
+            pub struct Spawner {
+                total: i32,
+            }
+
+            impl UdonBehaviour for Spawner {
+                fn start(&mut self) {
+                    for i in 0..10 {
+                        self.total += i;
+                    }
+                }
+            }
+        "#);
+        let error = lower_to_udon_assembly(&behaviors[0]).unwrap_err();
+        assert!(matches!(error, UdonAssemblyLoweringError::UnsupportedConstruct { ref construct, .. } if construct == "a `for` loop"));
+        assert_eq!(error.to_string(), "Method 'Spawner::start' uses a `for` loop, which requires UdonSharp");
+    }
+}
@@ -0,0 +1,197 @@
+//! `.unitypackage` export of the generated output
+//!
+//! A creator otherwise copies scripts, prefabs and manifests into the Unity
+//! project by hand and hopes every `.meta` file came along. `cargo udonsharp
+//! package` instead collects the generated assets of an output directory into
+//! one `.unitypackage`, imported with Assets > Import Package. The format is a
+//! gzipped tar holding one directory per asset, named by the asset's GUID,
+//! with the asset itself, its `.meta` file and the path it is imported to.
+//! GUIDs come from the `.meta` files written next to the assets, so importing
+//! a package over an earlier import updates the same assets in place.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::asset_meta::{meta_file_content, meta_path, read_meta_guid, AssetGuidSeed, AssetMetaKind};
+use crate::behavior_manifest::BEHAVIOR_MANIFEST_FILE;
+
+/// Folder of the Unity project the assets are imported into by default
+pub const DEFAULT_PACKAGE_ASSET_ROOT: &str = "Assets/UdonSharp/Generated";
+
+/// An asset of a package
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageAsset {
+    /// Path the asset is imported to, relative to the Unity project
+    pub pathname: String,
+    /// GUID of the asset, also the name of its directory in the archive
+    pub guid: String,
+    /// Content of the asset's `.meta` file
+    pub meta: String,
+    /// File the asset is read from
+    pub source: PathBuf,
+}
+
+/// Generated assets to be written as a `.unitypackage`
+#[derive(Debug, Clone, Default)]
+pub struct UnityPackage {
+    /// Assets ordered by pathname
+    pub assets: Vec<PackageAsset>,
+}
+
+impl UnityPackage {
+    /// Collect the generated scripts, prefabs, scenes, manifests and field
+    /// schemas that `package_name` wrote below `output_dir`, to be imported
+    /// under `asset_root`.
+    ///
+    /// An asset without a `.meta` file gets the one the compiler would have
+    /// written for it.
+    pub fn collect(output_dir: &Path, asset_root: &str, package_name: &str) -> io::Result<Self> {
+        let mut files = Vec::new();
+        collect_files(output_dir, &mut files)?;
+        let seed = AssetGuidSeed::new(package_name, output_dir);
+
+        let asset_root = asset_root.trim_end_matches('/');
+        let mut assets = Vec::new();
+        for source in files.into_iter().filter(|path| is_generated_asset(path)) {
+            let relative = source.strip_prefix(output_dir).unwrap_or(&source);
+            let relative: Vec<String> = relative.components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+
+            let existing_meta = meta_path(&source);
+            let (guid, meta) = match read_meta_guid(&existing_meta) {
+                Some(guid) => (guid, fs::read_to_string(&existing_meta)?),
+                None => {
+                    let guid = seed.guid(&source);
+                    let meta = meta_file_content(AssetMetaKind::from_path(&source), &guid);
+                    (guid, meta)
+                }
+            };
+
+            assets.push(PackageAsset {
+                pathname: format!("{}/{}", asset_root, relative.join("/")),
+                guid,
+                meta,
+                source,
+            });
+        }
+        assets.sort_by(|a, b| a.pathname.cmp(&b.pathname));
+
+        Ok(Self { assets })
+    }
+
+    /// Write the package as a gzipped tar
+    pub fn write_to(&self, writer: impl Write) -> io::Result<()> {
+        let mut archive = tar::Builder::new(GzEncoder::new(writer, Compression::default()));
+        for asset in &self.assets {
+            let contents = fs::read(&asset.source)?;
+            append_entry(&mut archive, &format!("{}/asset", asset.guid), &contents)?;
+            append_entry(&mut archive, &format!("{}/asset.meta", asset.guid), asset.meta.as_bytes())?;
+            append_entry(&mut archive, &format!("{}/pathname", asset.guid), asset.pathname.as_bytes())?;
+        }
+        archive.into_inner()?.finish()?.flush()
+    }
+
+    /// Write the package to a file, creating its directory
+    pub fn write_file(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        self.write_to(io::BufWriter::new(fs::File::create(path)?))
+    }
+}
+
+/// Whether a file is an asset the compiler generates
+fn is_generated_asset(path: &Path) -> bool {
+    let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    matches!(path.extension().and_then(|e| e.to_str()), Some("cs" | "prefab" | "unity"))
+        || file_name == BEHAVIOR_MANIFEST_FILE
+        || file_name.ends_with(".schema.json")
+}
+
+/// Every file below a directory, in a stable order
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn append_entry<W: Write>(archive: &mut tar::Builder<W>, path: &str, contents: &[u8]) -> io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    archive.append_data(&mut header, path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::io::Read;
+
+    use crate::asset_meta::{stable_guid, write_asset_meta};
+
+    #[test]
+    fn test_package_holds_each_generated_asset_with_meta_and_pathname() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let output = output_dir.path();
+        fs::write(output.join("GameManager.cs"), "public class GameManager {}").unwrap();
+        let game_manager_guid = write_asset_meta(&output.join("GameManager.cs"), &AssetGuidSeed::new("world", output)).unwrap();
+        fs::write(output.join("SharedRuntime.cs"), "public class SharedRuntime {}").unwrap();
+        fs::write(output.join(BEHAVIOR_MANIFEST_FILE), "{}").unwrap();
+        fs::write(output.join("GameManager.schema.json"), "{}").unwrap();
+        fs::create_dir(output.join("Prefabs")).unwrap();
+        fs::write(output.join("Prefabs/MultiBehaviorWorld.prefab"), "%YAML 1.1").unwrap();
+        fs::write(output.join("build.log"), "not an asset").unwrap();
+
+        let package = UnityPackage::collect(output, DEFAULT_PACKAGE_ASSET_ROOT, "world").unwrap();
+        let archive_path = output.join("out/Generated.unitypackage");
+        package.write_file(&archive_path).unwrap();
+
+        let mut entries = BTreeMap::new();
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(fs::File::open(&archive_path).unwrap()));
+        for entry in archive.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let path = entry.path().unwrap().to_string_lossy().into_owned();
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents).unwrap();
+            entries.insert(path, contents);
+        }
+
+        let mut pathnames: Vec<&str> = entries.iter()
+            .filter(|(path, _)| path.ends_with("/pathname"))
+            .map(|(_, pathname)| pathname.as_str())
+            .collect();
+        pathnames.sort();
+        assert_eq!(pathnames, vec![
+            "Assets/UdonSharp/Generated/GameManager.cs",
+            "Assets/UdonSharp/Generated/GameManager.schema.json",
+            "Assets/UdonSharp/Generated/Prefabs/MultiBehaviorWorld.prefab",
+            "Assets/UdonSharp/Generated/SharedRuntime.cs",
+            "Assets/UdonSharp/Generated/behaviors.manifest.json",
+        ]);
+        assert_eq!(entries.len(), 5 * 3);
+
+        // The GUID of an existing .meta file is kept, the rest get stable ones
+        assert_eq!(entries[&format!("{}/asset", game_manager_guid)], "public class GameManager {}");
+        assert_eq!(entries[&format!("{}/pathname", game_manager_guid)], "Assets/UdonSharp/Generated/GameManager.cs");
+        let shared_runtime_guid = stable_guid("world", "SharedRuntime.cs");
+        assert!(entries[&format!("{}/asset.meta", shared_runtime_guid)].contains("MonoImporter:"));
+        let prefab_guid = stable_guid("world", "Prefabs/MultiBehaviorWorld.prefab");
+        assert_eq!(entries[&format!("{}/pathname", prefab_guid)], "Assets/UdonSharp/Generated/Prefabs/MultiBehaviorWorld.prefab");
+    }
+}
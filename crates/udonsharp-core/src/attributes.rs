@@ -39,6 +39,12 @@
 /// This allows the field to be visible in the Unity Inspector
 pub struct UdonPublic;
 
+/// Marks a field as `[SerializeField] private` in the generated UdonSharp code
+/// The field stays editable in the Unity Inspector and can still be synced,
+/// but other behaviors cannot access it directly, unlike a public field
+#[derive(Default)]
+pub struct UdonSerializeField;
+
 /// Marks a field for network synchronization
 /// The field will be automatically synchronized across all clients
 pub struct UdonSync {
@@ -82,6 +88,21 @@ pub struct UdonRange {
     pub max: f32,
 }
 
+/// Packs a synced `f32`, `Vector2` or `Vector3` field into a smaller synced integer
+///
+/// Each component is clamped to `min..=max` and stored in `bits` bits, so
+/// values are off by at most `(max - min) / (2 * (2^bits - 1))` after a
+/// round trip. For example 16 bits over `-100.0..=100.0` is accurate to
+/// about 0.0015. All components must fit into 64 bits together.
+pub struct UdonQuantize {
+    /// Bits per component
+    pub bits: u8,
+    /// Smallest representable value
+    pub min: f32,
+    /// Largest representable value
+    pub max: f32,
+}
+
 /// Marks a field as a text area in the Inspector
 pub struct UdonTextArea {
     /// Minimum number of lines
@@ -133,6 +154,12 @@ impl UdonPublic {
     }
 }
 
+impl UdonSerializeField {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
 impl UdonSync {
     pub fn new(mode: SyncMode) -> Self {
         Self { mode }
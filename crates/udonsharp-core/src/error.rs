@@ -5,6 +5,8 @@
 
 use thiserror::Error;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 /// Main error type for UdonSharp operations
 #[derive(Error, Debug)]
@@ -518,12 +520,55 @@ impl std::fmt::Display for Diagnostic {
     }
 }
 
+/// Exit status of a build or check, the contract CI pipelines rely on
+///
+/// | Code | Meaning |
+/// |------|---------|
+/// | 0 | Succeeded; warnings are allowed unless denied |
+/// | 1 | Failed with errors |
+/// | 2 | Succeeded with warnings while warnings are denied |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitStatus {
+    Success,
+    Errors,
+    DeniedWarnings,
+}
+
+impl ExitStatus {
+    /// Pick the status for the given diagnostic counts
+    pub fn from_counts(error_count: usize, warning_count: usize, deny_warnings: bool) -> Self {
+        if error_count > 0 {
+            ExitStatus::Errors
+        } else if deny_warnings && warning_count > 0 {
+            ExitStatus::DeniedWarnings
+        } else {
+            ExitStatus::Success
+        }
+    }
+
+    /// Process exit code of the status
+    pub fn code(self) -> i32 {
+        match self {
+            ExitStatus::Success => 0,
+            ExitStatus::Errors => 1,
+            ExitStatus::DeniedWarnings => 2,
+        }
+    }
+}
+
 /// Compilation context for tracking progress and diagnostics
+///
+/// Clones share the logged warning and error counts, so diagnostics logged
+/// on a clone handed to a pipeline stage still count toward the build status.
 #[derive(Debug, Clone)]
 pub struct CompilationContext {
     pub reporter: DiagnosticCollector,
     debug_mode: bool,
     verbose: bool,
+    /// Warnings logged through `warning`, which only has shared access to the context
+    logged_warnings: Arc<AtomicUsize>,
+    /// Errors logged through `error`
+    logged_errors: Arc<AtomicUsize>,
 }
 
 impl CompilationContext {
@@ -533,6 +578,8 @@ impl CompilationContext {
             reporter: DiagnosticCollector::new(),
             debug_mode: false,
             verbose: false,
+            logged_warnings: Arc::new(AtomicUsize::new(0)),
+            logged_errors: Arc::new(AtomicUsize::new(0)),
         }
     }
     
@@ -542,6 +589,8 @@ impl CompilationContext {
             reporter: DiagnosticCollector::new(),
             debug_mode: true,
             verbose: true,
+            logged_warnings: Arc::new(AtomicUsize::new(0)),
+            logged_errors: Arc::new(AtomicUsize::new(0)),
         }
     }
     
@@ -563,6 +612,7 @@ impl CompilationContext {
     /// Log a warning message
     pub fn warning<S: Into<String>>(&self, message: S) {
         let msg = message.into();
+        self.logged_warnings.fetch_add(1, Ordering::Relaxed);
         log::warn!("{}", msg);
         println!("⚠️  {}", msg);
     }
@@ -570,6 +620,7 @@ impl CompilationContext {
     /// Log an error message
     pub fn error<S: Into<String>>(&self, message: S) {
         let msg = message.into();
+        self.logged_errors.fetch_add(1, Ordering::Relaxed);
         log::error!("{}", msg);
         println!("❌ {}", msg);
     }
@@ -579,10 +630,25 @@ impl CompilationContext {
         !self.reporter.has_errors()
     }
     
+    /// Number of errors reported or logged so far
+    pub fn error_count(&self) -> usize {
+        self.reporter.error_count() + self.logged_errors.load(Ordering::Relaxed)
+    }
+    
+    /// Number of warnings reported or logged so far
+    pub fn warning_count(&self) -> usize {
+        self.reporter.warning_count() + self.logged_warnings.load(Ordering::Relaxed)
+    }
+    
+    /// Exit status for the diagnostics so far
+    pub fn exit_status(&self, deny_warnings: bool) -> ExitStatus {
+        ExitStatus::from_counts(self.error_count(), self.warning_count(), deny_warnings)
+    }
+    
     /// Print a summary of the compilation
     pub fn print_summary(&self) {
-        let error_count = self.reporter.error_count();
-        let warning_count = self.reporter.warning_count();
+        let error_count = self.error_count();
+        let warning_count = self.warning_count();
         
         if error_count > 0 {
             println!("❌ Compilation failed with {} error(s)", error_count);
@@ -614,4 +680,32 @@ impl Default for CompilationContext {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warnings_pass_unless_denied() {
+        let mut context = CompilationContext::new();
+        assert_eq!(context.exit_status(true), ExitStatus::Success);
+
+        context.warning("Synced field is never written");
+        context.reporter.warning("Unused behavior");
+        assert_eq!(context.warning_count(), 2);
+        assert_eq!(context.exit_status(false).code(), 0);
+        assert_eq!(context.exit_status(true), ExitStatus::DeniedWarnings);
+        assert_eq!(context.exit_status(true).code(), 2);
+        assert_eq!(context.clone().exit_status(true), ExitStatus::DeniedWarnings);
+    }
+
+    #[test]
+    fn test_errors_fail_regardless_of_deny_warnings() {
+        let context = CompilationContext::new();
+        context.warning("Synced field is never written");
+        context.error("Unsupported type");
+        assert_eq!(context.exit_status(false), ExitStatus::Errors);
+        assert_eq!(context.exit_status(true).code(), 1);
+    }
+}
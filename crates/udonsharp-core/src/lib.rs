@@ -8,6 +8,9 @@ pub mod multi_behavior_errors;
 pub mod prelude;
 pub mod testing;
 
+#[cfg(feature = "native-test")]
+pub mod native;
+
 #[cfg(test)]
 pub mod integration_tests;
 
@@ -17,15 +20,15 @@ pub mod test_runner;
 pub use traits::*;
 pub use types::{
     Vector2, Vector3, Vector4, Quaternion, Color, Color32,
-    VRCPlayerApi, GameObject, Transform, Collider, Collision,
+    VRCPlayerApi, GameObject, Transform, Collider, Collision, LayerMask,
     UdonSyncMode, NetworkEventTarget, UdonFieldInfo, Rigidbody, 
     Animator, AudioSource, Light, VRCStation, VRCPickup, 
     VRCObjectPool, Text, Button, Slider, Toggle, UdonSharpUtility, 
-    Networking, Mathf, Random, Time
+    Networking, Mathf, Random, Time, ElapsedTimer, UdonRef
 };
 pub use attributes::{
-    UdonPublic, UdonSync, UdonEvent, UdonNetworkEvent,
-    UdonHeader, UdonTooltip, UdonRange, UdonTextArea,
+    UdonPublic, UdonSerializeField, UdonSync, UdonEvent, UdonNetworkEvent,
+    UdonHeader, UdonTooltip, UdonRange, UdonQuantize, UdonTextArea,
     UdonSpace, UdonPropertyDrawer, SyncMode
 };
 pub use error::*;
@@ -0,0 +1,320 @@
+//! Native engine APIs for logic tests
+//!
+//! With the `native-test` feature, `Time`, `Networking` and the `log` macros
+//! stop being compile-time stand-ins and run on the CPU, so behavior logic can
+//! be unit-tested as ordinary Rust under `cargo test`. Unlike the
+//! `testing::UdonTestEnvironment`, nothing here models Udon semantics such as
+//! event dispatch or serialization: there is a clock the test advances, a
+//! player list with a master, per-object ownership, and a log buffer.
+//!
+//! Every test thread gets its own session, so tests stay isolated when
+//! `cargo test` runs them in parallel. Call `reset` at the start of a test.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Once;
+
+use crate::types::{GameObject, Networking, ObjectHandle, Time, VRCPlayerApi};
+
+/// Interval of the physics step, matching Unity's default
+pub const FIXED_DELTA_TIME: f32 = 0.02;
+
+/// Id of the player the test runs as
+pub const LOCAL_PLAYER_ID: u32 = 1;
+
+static LOGGER_INIT: Once = Once::new();
+static LOGGER: NativeLogger = NativeLogger;
+
+thread_local! {
+    static SESSION: RefCell<NativeSession> = RefCell::new(NativeSession::new());
+}
+
+/// A message written through the `log` macros
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoggedMessage {
+    pub level: log::Level,
+    pub message: String,
+}
+
+/// State behind the native engine APIs of one test thread
+#[derive(Debug, Clone)]
+struct NativeSession {
+    time: f32,
+    delta_time: f32,
+    fixed_time: f32,
+    time_scale: f32,
+    frame_count: u32,
+    /// Ids of the players in the instance, in join order; the first is the master
+    players: Vec<u32>,
+    next_player_id: u32,
+    /// Owner of every object that changed hands; the rest belong to the master
+    owners: HashMap<u32, u32>,
+    logs: Vec<LoggedMessage>,
+}
+
+impl NativeSession {
+    fn new() -> Self {
+        Self {
+            time: 0.0,
+            delta_time: 0.0,
+            fixed_time: 0.0,
+            time_scale: 1.0,
+            frame_count: 0,
+            players: vec![LOCAL_PLAYER_ID],
+            next_player_id: LOCAL_PLAYER_ID + 1,
+            owners: HashMap::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    fn master(&self) -> u32 {
+        self.players.first().copied().unwrap_or(LOCAL_PLAYER_ID)
+    }
+
+    fn owner(&self, object: &GameObject) -> u32 {
+        self.owners.get(&object.handle.id).copied().unwrap_or_else(|| self.master())
+    }
+}
+
+fn with_session<R>(f: impl FnOnce(&mut NativeSession) -> R) -> R {
+    SESSION.with(|session| f(&mut session.borrow_mut()))
+}
+
+/// Start the current thread over: time zero, the local player alone as master,
+/// no ownership transfers, an empty log. Also routes the `log` macros here,
+/// unless the process already installed another logger.
+pub fn reset() {
+    LOGGER_INIT.call_once(|| {
+        if log::set_logger(&LOGGER).is_ok() {
+            log::set_max_level(log::LevelFilter::Trace);
+        }
+    });
+    with_session(|session| *session = NativeSession::new());
+}
+
+/// Advance the clock by one frame of `seconds` unscaled time
+pub fn advance_frame(seconds: f32) {
+    with_session(|session| {
+        session.delta_time = seconds * session.time_scale;
+        session.time += session.delta_time;
+        session.frame_count += 1;
+        while session.fixed_time + FIXED_DELTA_TIME <= session.time {
+            session.fixed_time += FIXED_DELTA_TIME;
+        }
+    });
+}
+
+/// Number of frames advanced since the last `reset`
+pub fn frame_count() -> u32 {
+    with_session(|session| session.frame_count)
+}
+
+/// The player the test runs as
+pub fn local_player() -> VRCPlayerApi {
+    VRCPlayerApi { handle: ObjectHandle { id: LOCAL_PLAYER_ID } }
+}
+
+/// Add a remote player to the instance
+pub fn join_player() -> VRCPlayerApi {
+    let id = with_session(|session| {
+        let id = session.next_player_id;
+        session.next_player_id += 1;
+        session.players.push(id);
+        id
+    });
+    VRCPlayerApi { handle: ObjectHandle { id } }
+}
+
+/// Remove a player; when the master leaves, the next player to have joined takes over
+pub fn leave_player(player: &VRCPlayerApi) {
+    with_session(|session| {
+        let id = player.handle.id;
+        session.players.retain(|&p| p != id);
+        session.owners.retain(|_, &mut owner| owner != id);
+    });
+}
+
+/// An object ownership can be queried and transferred for, identified by `id`
+pub fn object(id: u32) -> GameObject {
+    GameObject::from_handle(ObjectHandle { id })
+}
+
+/// Messages logged on this thread since the last `reset`
+pub fn logged_messages() -> Vec<LoggedMessage> {
+    with_session(|session| session.logs.clone())
+}
+
+pub(crate) fn is_local(player: &VRCPlayerApi) -> bool {
+    player.handle.id == LOCAL_PLAYER_ID
+}
+
+pub(crate) fn is_master(player: &VRCPlayerApi) -> bool {
+    with_session(|session| session.master() == player.handle.id)
+}
+
+/// Collects `log` records into the session of the thread that wrote them
+struct NativeLogger;
+
+impl log::Log for NativeLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        let entry = LoggedMessage { level: record.level(), message: record.args().to_string() };
+        with_session(|session| session.logs.push(entry));
+    }
+
+    fn flush(&self) {}
+}
+
+impl Time {
+    pub fn time() -> f32 {
+        with_session(|session| session.time)
+    }
+
+    pub fn delta_time() -> f32 {
+        with_session(|session| session.delta_time)
+    }
+
+    /// Interval of the physics step; use this instead of `delta_time` in `fixed_update`
+    pub fn fixed_delta_time() -> f32 {
+        FIXED_DELTA_TIME
+    }
+
+    pub fn fixed_time() -> f32 {
+        with_session(|session| session.fixed_time)
+    }
+
+    pub fn time_scale() -> f32 {
+        with_session(|session| session.time_scale)
+    }
+
+    pub fn set_time_scale(scale: f32) {
+        with_session(|session| session.time_scale = scale.max(0.0));
+    }
+}
+
+impl Networking {
+    /// Check if the local player is the owner of an object
+    pub fn is_owner(player: &VRCPlayerApi, target: &GameObject) -> bool {
+        with_session(|session| session.owner(target) == player.handle.id)
+    }
+
+    /// Set the owner of an object
+    pub fn set_owner(player: &VRCPlayerApi, target: &GameObject) {
+        with_session(|session| {
+            if session.players.contains(&player.handle.id) {
+                session.owners.insert(target.handle.id, player.handle.id);
+            }
+        });
+    }
+
+    /// Get the owner of an object
+    pub fn get_owner(target: &GameObject) -> VRCPlayerApi {
+        VRCPlayerApi { handle: ObjectHandle { id: with_session(|session| session.owner(target)) } }
+    }
+
+    /// Check if the local player is the master
+    pub fn is_master(player: &VRCPlayerApi) -> bool {
+        is_master(player)
+    }
+
+    /// Get the master player
+    pub fn get_master() -> VRCPlayerApi {
+        VRCPlayerApi { handle: ObjectHandle { id: with_session(|session| session.master()) } }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ElapsedTimer, Vector3};
+
+    /// A patrol that waits at each point before walking to the next, run by the master
+    struct Patrol {
+        points: [Vector3; 2],
+        target: usize,
+        position: Vector3,
+        waited: f32,
+    }
+
+    impl Patrol {
+        const WAIT: f32 = 0.5;
+        const SPEED: f32 = 2.0;
+
+        fn update(&mut self) {
+            if !Networking::is_master(&local_player()) {
+                return;
+            }
+            let goal = self.points[self.target];
+            let remaining = Vector3::distance(self.position, goal);
+            if remaining > 0.0 {
+                self.position = Vector3::move_towards(self.position, goal, Self::SPEED * Time::delta_time());
+                return;
+            }
+            self.waited += Time::delta_time();
+            if self.waited >= Self::WAIT {
+                self.waited = 0.0;
+                self.target = (self.target + 1) % self.points.len();
+                log::info!("Patrol heading to point {}", self.target);
+            }
+        }
+    }
+
+    #[test]
+    fn test_master_patrol_steps_with_the_native_clock() {
+        reset();
+        let mut patrol = Patrol {
+            points: [Vector3::ZERO, Vector3::new(1.0, 0.0, 0.0)],
+            target: 1,
+            position: Vector3::ZERO,
+            waited: 0.0,
+        };
+
+        for _ in 0..4 {
+            advance_frame(0.25);
+            patrol.update();
+        }
+        assert_eq!(patrol.position, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(patrol.target, 0);
+        assert_eq!(Time::time(), 1.0);
+        assert_eq!(frame_count(), 4);
+        assert_eq!(logged_messages(), vec![LoggedMessage {
+            level: log::Level::Info,
+            message: "Patrol heading to point 0".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn test_elapsed_timer_follows_the_native_clock() {
+        reset();
+        let mut timer = ElapsedTimer::new();
+        advance_frame(0.5);
+        timer.start();
+        assert!(!timer.has_elapsed(1.0));
+
+        advance_frame(0.75);
+        assert_eq!(timer.elapsed(), 0.75);
+        assert!(!timer.has_elapsed(1.0));
+
+        advance_frame(0.25);
+        assert!(timer.has_elapsed(1.0));
+    }
+
+    #[test]
+    fn test_ownership_falls_back_to_the_next_master() {
+        reset();
+        let remote = join_player();
+        let door = object(7);
+        assert!(Networking::is_owner(&local_player(), &door));
+
+        Networking::set_owner(&remote, &door);
+        assert!(!Networking::is_owner(&local_player(), &door));
+
+        leave_player(&local_player());
+        assert!(remote.is_master());
+        assert!(Networking::is_owner(&remote, &door));
+        assert!(Networking::is_owner(&remote, &object(8)));
+    }
+}
@@ -5,14 +5,14 @@
 pub use crate::traits::*;
 pub use crate::types::{
     Vector2, Vector3, Vector4, Quaternion, Color, Color32,
-    VRCPlayerApi, GameObject, Transform, Collider, Collision,
+    VRCPlayerApi, GameObject, Transform, Collider, Collision, LayerMask,
     UdonSyncMode, UdonFieldInfo, Rigidbody, Animator, AudioSource,
     Light, VRCStation, VRCPickup, VRCObjectPool, Text, Button,
-    Slider, Toggle, UdonSharpUtility, Networking, Mathf, Random, Time
+    Slider, Toggle, UdonSharpUtility, Networking, Mathf, Random, Time, ElapsedTimer, UdonRef
 };
 pub use crate::attributes::{
-    UdonPublic, UdonSync, UdonEvent, UdonNetworkEvent,
-    UdonHeader, UdonTooltip, UdonRange, UdonTextArea,
+    UdonPublic, UdonSerializeField, UdonSync, UdonEvent, UdonNetworkEvent,
+    UdonHeader, UdonTooltip, UdonRange, UdonQuantize, UdonTextArea,
     UdonSpace, UdonPropertyDrawer, SyncMode
 };
 // Re-export NetworkEventTarget from types only to avoid ambiguity
@@ -3,8 +3,10 @@
 //! This module provides testing utilities for UdonSharp development, including
 //! mock VRChat and Unity environments, assertion systems, and test runners.
 
+use std::any::Any;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, Once};
+use crate::traits::UdonBehaviour;
 use crate::types::*;
 // use crate::error::UdonSharpError;
 
@@ -22,6 +24,98 @@ impl UdonTestEnvironment {
         setup_mock_environment();
         Self { _private: () }
     }
+    
+    /// Add a root object to the mock scene; `GameObject::find` resolves it while it is active.
+    ///
+    /// The mock environment is shared by all tests of a process, so object
+    /// names should be unique to the test that spawns them.
+    pub fn spawn_object(&self, name: &str) -> GameObject {
+        with_mock_environment_mut(|env| env.spawn_object(name))
+    }
+    
+    /// Attach a component to a spawned object, replacing any of the same type
+    pub fn add_component<T: std::any::Any + Send + Sync>(&self, object: &GameObject, component: T) {
+        with_mock_environment_mut(|env| {
+            let name = env.object_name(object).expect("object was not spawned in the test environment");
+            env.add_component(&name, component);
+        });
+    }
+    
+    /// Get a copy of a component attached with `add_component`
+    pub fn component<T: std::any::Any + Clone>(&self, object: &GameObject) -> Option<T> {
+        with_mock_environment(|env| {
+            let name = env.object_name(object)?;
+            env.get_component::<T>(&name).cloned()
+        })
+    }
+    
+    /// Move an object under a new parent
+    pub fn set_parent(&self, child: &GameObject, parent: &GameObject) {
+        with_mock_environment_mut(|env| {
+            let child = env.object_name(child).expect("child was not spawned in the test environment");
+            let parent = env.object_name(parent).expect("parent was not spawned in the test environment");
+            env.set_parent(&child, &parent);
+        });
+    }
+    
+    /// Get the name of a spawned object, e.g. to check what `find` resolved
+    pub fn name_of(&self, object: &GameObject) -> Option<String> {
+        with_mock_environment(|env| env.object_name(object))
+    }
+    
+    /// Advance time by one frame of `seconds`, firing delayed events that fall due
+    pub fn advance_time(&self, seconds: f32) {
+        with_mock_environment_mut(|env| env.time_state.advance_time(seconds));
+    }
+    
+    /// Names of the delayed events fired on an object so far, in firing order
+    pub fn fired_events(&self, object: &GameObject) -> Vec<String> {
+        with_mock_environment(|env| {
+            let Some(name) = env.object_name(object) else {
+                return Vec::new();
+            };
+            env.time_state.fired_events.iter()
+                .filter(|fired| fired.object_name == name)
+                .map(|fired| fired.event_name.clone())
+                .collect()
+        })
+    }
+    
+    /// Attach a behavior to a spawned object. Custom events sent to the
+    /// object, by the test or through another behavior's `UdonRef`, run its
+    /// `on_custom_event`.
+    pub fn add_behavior<T: UdonBehaviour + Send + 'static>(&self, object: &GameObject, behavior: T) {
+        with_mock_environment_mut(|env| {
+            env.object_name(object).expect("object was not spawned in the test environment");
+            env.add_behavior(object.handle.id, behavior);
+        });
+    }
+    
+    /// Reference the behavior on an object, as an `UdonRef` field assigned
+    /// in the inspector would; combine with `GameObject::find` to link
+    /// behaviors by name
+    pub fn udon_ref<T: UdonBehaviour>(&self, object: &GameObject) -> UdonRef<T> {
+        UdonRef::from_handle(object.handle.clone())
+    }
+    
+    /// Run `f` on the behavior attached to an object, e.g. to assert on its
+    /// fields; `None` when the object has no behavior of type `T`
+    pub fn with_behavior<T: UdonBehaviour + Send + 'static, R>(&self, object: &GameObject, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let instance = with_mock_environment(|env| env.behaviors.get(&object.handle.id).map(|b| b.instance.clone()))?;
+        let behavior = instance.downcast::<Mutex<T>>().ok()?;
+        let mut behavior = behavior.lock().unwrap();
+        Some(f(&mut behavior))
+    }
+    
+    /// Send a custom event to the behavior on an object, as another behavior would
+    pub fn send_custom_event(&self, object: &GameObject, event_name: &str) {
+        dispatch_custom_event(object.handle.id, event_name);
+    }
+    
+    /// Get a variable set on an object's behavior through `UdonRef::set`
+    pub fn program_variable<V: Any + Clone>(&self, object: &GameObject, name: &str) -> Option<V> {
+        with_mock_environment(|env| env.program_variable::<V>(object.handle.id, name))
+    }
 }
 
 impl Drop for UdonTestEnvironment {
@@ -39,6 +133,12 @@ pub struct MockEnvironment {
     pub time_state: MockTimeState,
     pub physics_state: MockPhysicsState,
     pub audio_state: MockAudioState,
+    /// Id handed to the next spawned object; `GameObject` handles refer to objects by id
+    pub next_object_id: u32,
+    /// Behaviors attached to objects, by object id
+    pub behaviors: HashMap<u32, MockBehavior>,
+    /// Variables set through `UdonRef::set`, by object id and variable name
+    pub program_variables: HashMap<u32, HashMap<String, Arc<dyn Any + Send + Sync>>>,
 }
 
 impl MockEnvironment {
@@ -46,6 +146,9 @@ impl MockEnvironment {
         Self {
             players: vec![MockVRCPlayerApi::local_player()],
             game_objects: HashMap::new(),
+            next_object_id: 1,
+            behaviors: HashMap::new(),
+            program_variables: HashMap::new(),
             networking_state: MockNetworkingState::new(),
             time_state: MockTimeState::new(),
             physics_state: MockPhysicsState::new(),
@@ -53,6 +156,30 @@ impl MockEnvironment {
         }
     }
     
+    /// Attach a behavior to an object, replacing any attached before
+    pub fn add_behavior<T: UdonBehaviour + Send + 'static>(&mut self, object_id: u32, behavior: T) {
+        let behavior = Arc::new(Mutex::new(behavior));
+        let receiver = behavior.clone();
+        self.behaviors.insert(object_id, MockBehavior {
+            instance: behavior,
+            dispatch: Arc::new(move |event_name: &str| {
+                let mut receiver = receiver.try_lock()
+                    .expect("custom event sent to a behavior that is still handling an event");
+                receiver.on_custom_event(event_name);
+            }),
+        });
+    }
+    
+    /// Record a variable set on an object's behavior
+    pub fn set_program_variable<V: Any + Send + Sync>(&mut self, object_id: u32, name: &str, value: V) {
+        self.program_variables.entry(object_id).or_default().insert(name.to_string(), Arc::new(value));
+    }
+    
+    /// Get a variable set on an object's behavior, if it was set with type `V`
+    pub fn program_variable<V: Any + Clone>(&self, object_id: u32, name: &str) -> Option<V> {
+        self.program_variables.get(&object_id)?.get(name)?.downcast_ref::<V>().cloned()
+    }
+    
     pub fn add_player(&mut self, display_name: &str) -> MockVRCPlayerApi {
         let player = MockVRCPlayerApi::new(self.players.len() as u32, display_name);
         self.players.push(player.clone());
@@ -60,7 +187,16 @@ impl MockEnvironment {
     }
     
     pub fn create_game_object(&mut self, name: &str) -> MockGameObject {
-        let game_object = MockGameObject::new(name);
+        // A new object of the same name replaces the old one in the hierarchy
+        if let Some(parent) = self.game_objects.get(name).and_then(|old| old.transform.parent.clone()) {
+            if let Some(parent) = self.game_objects.get_mut(&parent) {
+                parent.transform.children.retain(|child| child != name);
+            }
+        }
+        
+        let mut game_object = MockGameObject::new(name);
+        game_object.id = self.next_object_id;
+        self.next_object_id += 1;
         self.game_objects.insert(name.to_string(), game_object.clone());
         game_object
     }
@@ -68,6 +204,138 @@ impl MockEnvironment {
     pub fn find_game_object(&self, name: &str) -> Option<&MockGameObject> {
         self.game_objects.get(name)
     }
+    
+    /// Create a root object and get a `GameObject` handle to it
+    pub fn spawn_object(&mut self, name: &str) -> GameObject {
+        let id = self.create_game_object(name).id;
+        GameObject::from_handle(ObjectHandle { id })
+    }
+    
+    /// Get the name of the object a `GameObject` handle refers to
+    pub fn object_name(&self, object: &GameObject) -> Option<String> {
+        self.game_objects.values()
+            .find(|candidate| candidate.id == object.handle.id)
+            .map(|candidate| candidate.name.clone())
+    }
+    
+    /// Attach a component to an object, replacing any of the same type
+    pub fn add_component<T: std::any::Any + Send + Sync>(&mut self, object_name: &str, component: T) {
+        if let Some(object) = self.game_objects.get_mut(object_name) {
+            object.components.insert(std::any::type_name::<T>().to_string(), Box::new(component));
+        }
+    }
+    
+    /// Get a component attached to an object
+    pub fn get_component<T: std::any::Any>(&self, object_name: &str) -> Option<&T> {
+        self.game_objects.get(object_name)?
+            .components.get(std::any::type_name::<T>())?
+            .downcast_ref::<T>()
+    }
+    
+    /// Move an object under a new parent, keeping both sides of the hierarchy in step.
+    ///
+    /// Panics if either object does not exist or the parent is the child or one of its descendants.
+    pub fn set_parent(&mut self, child: &str, parent: &str) {
+        assert!(self.game_objects.contains_key(child), "No object named '{}' in the mock scene", child);
+        assert!(self.game_objects.contains_key(parent), "No object named '{}' in the mock scene", parent);
+        let mut ancestor = Some(parent.to_string());
+        while let Some(name) = ancestor {
+            assert!(name != child, "Cannot parent '{}' under its own descendant '{}'", child, parent);
+            ancestor = self.game_objects.get(&name).and_then(|o| o.transform.parent.clone());
+        }
+        
+        let old_parent = self.game_objects[child].transform.parent.clone();
+        if let Some(old_parent) = old_parent.and_then(|name| self.game_objects.get_mut(&name)) {
+            old_parent.transform.children.retain(|name| name != child);
+        }
+        if let Some(parent_object) = self.game_objects.get_mut(parent) {
+            parent_object.transform.children.push(child.to_string());
+        }
+        if let Some(child_object) = self.game_objects.get_mut(child) {
+            child_object.transform.parent = Some(parent.to_string());
+        }
+    }
+    
+    /// Check if an object and all of its ancestors are active
+    pub fn is_active_in_hierarchy(&self, object_name: &str) -> bool {
+        let mut current = Some(object_name.to_string());
+        while let Some(name) = current {
+            match self.game_objects.get(&name) {
+                Some(object) if object.active => current = object.transform.parent.clone(),
+                _ => return false,
+            }
+        }
+        true
+    }
+    
+    /// Resolve a name or `Parent/Child` path the way `GameObject.Find` does:
+    /// only active objects are found, and a leading `/` anchors the path at a root
+    pub fn find(&self, path: &str) -> Option<&MockGameObject> {
+        let (anchored, path) = match path.strip_prefix('/') {
+            Some(rest) => (true, rest),
+            None => (false, path),
+        };
+        let mut segments = path.split('/');
+        let first = segments.next()?;
+        let start = self.game_objects.get(first)
+            .filter(|object| !anchored || object.transform.parent.is_none())?;
+        let found = self.descend(start, segments)?;
+        self.is_active_in_hierarchy(&found.name).then_some(found)
+    }
+    
+    /// Resolve a name or path below an object the way `Transform.Find` does,
+    /// which also finds inactive children
+    pub fn find_child(&self, parent_name: &str, path: &str) -> Option<&MockGameObject> {
+        let parent = self.game_objects.get(parent_name)?;
+        self.descend(parent, path.split('/'))
+    }
+    
+    /// Follow path segments through direct children
+    fn descend<'a, 'p>(&'a self, start: &'a MockGameObject, segments: impl Iterator<Item = &'p str>) -> Option<&'a MockGameObject> {
+        let mut current = start;
+        for segment in segments {
+            let child = current.transform.children.iter().find(|child| child.as_str() == segment)?;
+            current = self.game_objects.get(child)?;
+        }
+        Some(current)
+    }
+    
+    /// Get the owner of an object; objects nobody took ownership of belong to the master
+    pub fn get_owner(&self, object_name: &str) -> Option<&MockVRCPlayerApi> {
+        match self.networking_state.get_owner(object_name) {
+            Some(owner_id) => self.players.iter().find(|p| p.player_id == owner_id),
+            None => self.players.iter().find(|p| p.is_master),
+        }
+    }
+    
+    /// Transfer ownership of an object to a player
+    pub fn set_owner(&mut self, player_id: u32, object_name: &str) {
+        self.networking_state.set_owner(object_name, player_id);
+    }
+    
+    /// Check if a player owns an object
+    pub fn is_owner(&self, player_id: u32, object_name: &str) -> bool {
+        self.get_owner(object_name).is_some_and(|owner| owner.player_id == player_id)
+    }
+}
+
+/// A behavior attached to a mock object
+///
+/// The mock does not map variables onto the behavior's Rust fields: values
+/// set through `UdonRef::set` are kept in `MockEnvironment::program_variables`
+/// and read back by `UdonRef::get`.
+#[derive(Clone)]
+pub struct MockBehavior {
+    /// The behavior, an `Arc<Mutex<T>>`
+    pub instance: Arc<dyn Any + Send + Sync>,
+    /// Runs the behavior's `on_custom_event`
+    pub dispatch: Arc<dyn Fn(&str) + Send + Sync>,
+}
+
+impl std::fmt::Debug for MockBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockBehavior").finish_non_exhaustive()
+    }
 }
 
 /// Mock VRCPlayerApi for testing
@@ -136,6 +404,8 @@ impl MockVRCPlayerApi {
 /// Mock GameObject for testing
 #[derive(Debug)]
 pub struct MockGameObject {
+    /// Id that `GameObject` handles to this object carry
+    pub id: u32,
     pub name: String,
     pub active: bool,
     pub transform: MockTransform,
@@ -145,6 +415,7 @@ pub struct MockGameObject {
 impl Clone for MockGameObject {
     fn clone(&self) -> Self {
         Self {
+            id: self.id,
             name: self.name.clone(),
             active: self.active,
             transform: self.transform.clone(),
@@ -156,6 +427,7 @@ impl Clone for MockGameObject {
 impl MockGameObject {
     pub fn new(name: &str) -> Self {
         Self {
+            id: 0,
             name: name.to_string(),
             active: true,
             transform: MockTransform::new(),
@@ -247,13 +519,43 @@ impl MockNetworkingState {
     }
 }
 
+/// When a delayed custom event falls due
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventDue {
+    /// Once `time` reaches this value
+    Time(f32),
+    /// Once `frame_count` reaches this value
+    Frame(u64),
+}
+
+/// A custom event scheduled with `SendCustomEventDelayedSeconds`/`DelayedFrames`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledEvent {
+    pub object_name: String,
+    pub event_name: String,
+    pub due: EventDue,
+}
+
+/// A delayed custom event that has fired
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiredEvent {
+    pub object_name: String,
+    pub event_name: String,
+}
+
 /// Mock time state for testing
+///
+/// Every `advance_time` call is one frame. Delayed events fire at the end of
+/// the frame in which they fall due, in the order they were scheduled.
 #[derive(Debug)]
 pub struct MockTimeState {
     pub time: f32,
     pub delta_time: f32,
     pub fixed_time: f32,
     pub time_scale: f32,
+    pub frame_count: u64,
+    pub scheduled_events: Vec<ScheduledEvent>,
+    pub fired_events: Vec<FiredEvent>,
 }
 
 impl MockTimeState {
@@ -263,6 +565,9 @@ impl MockTimeState {
             delta_time: 0.016667, // 60 FPS
             fixed_time: 0.0,
             time_scale: 1.0,
+            frame_count: 0,
+            scheduled_events: Vec::new(),
+            fired_events: Vec::new(),
         }
     }
     
@@ -270,12 +575,45 @@ impl MockTimeState {
         self.time += delta * self.time_scale;
         self.fixed_time += delta * self.time_scale;
         self.delta_time = delta * self.time_scale;
+        self.frame_count += 1;
+        
+        let (time, frame) = (self.time, self.frame_count);
+        let (due, pending): (Vec<_>, Vec<_>) = self.scheduled_events.drain(..).partition(|event| match event.due {
+            EventDue::Time(at) => time >= at,
+            EventDue::Frame(at) => frame >= at,
+        });
+        self.scheduled_events = pending;
+        self.fired_events.extend(due.into_iter().map(|event| FiredEvent {
+            object_name: event.object_name,
+            event_name: event.event_name,
+        }));
+    }
+    
+    /// Schedule an event to fire once `delay` seconds of scaled time have passed
+    pub fn schedule_event_delayed_seconds(&mut self, object_name: &str, event_name: &str, delay: f32) {
+        self.schedule_event(object_name, event_name, EventDue::Time(self.time + delay.max(0.0)));
+    }
+    
+    /// Schedule an event to fire after `frames` frames, at least one
+    pub fn schedule_event_delayed_frames(&mut self, object_name: &str, event_name: &str, frames: i32) {
+        self.schedule_event(object_name, event_name, EventDue::Frame(self.frame_count + frames.max(1) as u64));
+    }
+    
+    fn schedule_event(&mut self, object_name: &str, event_name: &str, due: EventDue) {
+        self.scheduled_events.push(ScheduledEvent {
+            object_name: object_name.to_string(),
+            event_name: event_name.to_string(),
+            due,
+        });
     }
     
     pub fn reset_time(&mut self) {
         self.time = 0.0;
         self.fixed_time = 0.0;
         self.delta_time = 0.016667;
+        self.frame_count = 0;
+        self.scheduled_events.clear();
+        self.fired_events.clear();
     }
 }
 
@@ -489,12 +827,36 @@ pub fn setup_mock_environment() {
     }
 }
 
+/// Check if the mock environment has been set up
+pub fn is_mock_environment_ready() -> bool {
+    INIT.is_completed()
+}
+
 /// Cleanup mock environment after testing
 pub fn cleanup_mock_environment() {
     // Environment is automatically cleaned up when the test ends
     // This function is here for explicit cleanup if needed
 }
 
+/// Deliver a custom event to the behavior attached to an object; `false`
+/// when the object has none.
+///
+/// The environment is unlocked while the behavior runs, so it can send
+/// events on to other behaviors, but not back to one still handling an event.
+pub(crate) fn dispatch_custom_event(object_id: u32, event_name: &str) -> bool {
+    if !is_mock_environment_ready() {
+        return false;
+    }
+    let dispatch = with_mock_environment(|env| env.behaviors.get(&object_id).map(|b| b.dispatch.clone()));
+    match dispatch {
+        Some(dispatch) => {
+            dispatch(event_name);
+            true
+        }
+        None => false,
+    }
+}
+
 /// Execute a function with access to the mock environment
 pub fn with_mock_environment<F, R>(f: F) -> R
 where
@@ -532,6 +894,9 @@ pub fn get_mock_environment_state() -> MockEnvironment {
         MockEnvironment {
             players: env.players.clone(),
             game_objects: env.game_objects.clone(),
+            next_object_id: env.next_object_id,
+            behaviors: env.behaviors.clone(),
+            program_variables: env.program_variables.clone(),
             networking_state: MockNetworkingState {
                 is_master: env.networking_state.is_master,
                 owner_map: env.networking_state.owner_map.clone(),
@@ -542,6 +907,9 @@ pub fn get_mock_environment_state() -> MockEnvironment {
                 delta_time: env.time_state.delta_time,
                 fixed_time: env.time_state.fixed_time,
                 time_scale: env.time_state.time_scale,
+                frame_count: env.time_state.frame_count,
+                scheduled_events: env.time_state.scheduled_events.clone(),
+                fired_events: env.time_state.fired_events.clone(),
             },
             physics_state: MockPhysicsState {
                 gravity: env.physics_state.gravity,
@@ -567,6 +935,33 @@ mod tests {
         assert!(env.game_objects.is_empty());
     }
     
+    #[test]
+    fn test_mathf_int_rounding_differs_from_truncation() {
+        use crate::types::Mathf;
+        assert_eq!(-2.7f32 as i32, -2);
+        assert_eq!(Mathf::round_to_int(-2.7), -3);
+        assert_eq!(Mathf::round_to_int(2.5), 2);
+        assert_eq!(Mathf::round_to_int(3.5), 4);
+        assert_eq!(Mathf::floor_to_int(-2.3), -3);
+        assert_eq!(Mathf::ceil_to_int(2.3), 3);
+    }
+    
+    #[test]
+    fn test_seeded_shuffle_is_deterministic() {
+        let mut first: Vec<i32> = (0..16).collect();
+        let mut second = first.clone();
+        let mut reseeded = first.clone();
+        crate::types::UdonSharpUtility::shuffle(&mut first, 42);
+        crate::types::UdonSharpUtility::shuffle(&mut second, 42);
+        crate::types::UdonSharpUtility::shuffle(&mut reseeded, 7);
+        
+        assert_eq!(first, second);
+        assert_ne!(first, reseeded);
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..16).collect::<Vec<i32>>());
+    }
+    
     #[test]
     fn test_mock_player_creation() {
         let player = MockVRCPlayerApi::new(1, "TestPlayer");
@@ -606,6 +1001,132 @@ mod tests {
         assert!(!networking.is_owner("TestObject", 2));
     }
     
+    #[test]
+    fn test_object_ownership_defaults_to_master() {
+        let mut env = MockEnvironment::new();
+        let remote = env.add_player("Remote");
+        env.create_game_object("Door");
+        
+        assert!(env.is_owner(0, "Door"));
+        assert!(!env.is_owner(remote.player_id, "Door"));
+        
+        env.set_owner(remote.player_id, "Door");
+        assert_eq!(env.get_owner("Door").map(|p| p.display_name.as_str()), Some("Remote"));
+        assert!(!env.is_owner(0, "Door"));
+        assert!(env.is_owner(0, "Lever"));
+    }
+    
+    #[test]
+    fn test_mock_hierarchy_find_and_find_child() {
+        let mut env = MockEnvironment::new();
+        env.spawn_object("World");
+        env.spawn_object("Scoreboard");
+        env.spawn_object("Label");
+        env.spawn_object("Tracker");
+        env.set_parent("Scoreboard", "World");
+        env.set_parent("Label", "Scoreboard");
+        env.add_component("Label", String::from("Score: 0"));
+        
+        assert_eq!(env.find("Label").map(|o| o.name.as_str()), Some("Label"));
+        assert_eq!(env.find("World/Scoreboard/Label").map(|o| o.name.as_str()), Some("Label"));
+        assert!(env.find("/Scoreboard").is_none());
+        assert!(env.find("World/Label").is_none());
+        assert_eq!(env.find_child("World", "Scoreboard/Label").map(|o| o.name.as_str()), Some("Label"));
+        assert!(env.find_child("Tracker", "Label").is_none());
+        assert_eq!(env.get_component::<String>("Label").map(String::as_str), Some("Score: 0"));
+        
+        // Inactive ancestors hide objects from find but not from find_child
+        env.game_objects.get_mut("Scoreboard").unwrap().set_active(false);
+        assert!(env.find("Label").is_none());
+        assert!(env.find_child("Scoreboard", "Label").is_some());
+        
+        // Reparenting updates both sides of the hierarchy
+        env.set_parent("Label", "Tracker");
+        assert!(env.find_child("Scoreboard", "Label").is_none());
+        assert_eq!(env.find("Tracker/Label").map(|o| o.name.as_str()), Some("Label"));
+    }
+    
+    #[test]
+    fn test_game_object_find_resolves_spawned_objects() {
+        let test_env = UdonTestEnvironment::new();
+        let tracker = test_env.spawn_object("FindTestPlayerTracker");
+        let panel = test_env.spawn_object("FindTestPanel");
+        test_env.set_parent(&panel, &tracker);
+        test_env.add_component(&tracker, 42i32);
+        
+        let found = GameObject::find("FindTestPlayerTracker").unwrap();
+        assert_eq!(test_env.name_of(&found).as_deref(), Some("FindTestPlayerTracker"));
+        assert_eq!(test_env.component::<i32>(&found), Some(42));
+        
+        let child = found.find_child("FindTestPanel").unwrap();
+        assert_eq!(test_env.name_of(&child).as_deref(), Some("FindTestPanel"));
+        assert!(found.find_child("FindTestMissing").is_none());
+        assert!(GameObject::find("FindTestMissing").is_none());
+    }
+    
+    struct Counter {
+        count: i32,
+        display: UdonRef<Display>,
+    }
+    
+    impl UdonBehaviour for Counter {
+        fn on_custom_event(&mut self, event_name: &str) {
+            if event_name == "increment" {
+                self.count += 1;
+                self.display.set("text", format!("Count: {}", self.count));
+                self.display.call("refresh");
+            }
+        }
+    }
+    
+    #[derive(Default)]
+    struct Display {
+        refreshes: u32,
+    }
+    
+    impl UdonBehaviour for Display {
+        fn on_custom_event(&mut self, event_name: &str) {
+            if event_name == "refresh" {
+                self.refreshes += 1;
+            }
+        }
+    }
+    
+    #[test]
+    fn test_counter_increment_updates_display_through_find_linked_reference() {
+        let test_env = UdonTestEnvironment::new();
+        let hud = test_env.spawn_object("LinkTestHud");
+        let counter = test_env.spawn_object("LinkTestCounter");
+        let display = test_env.spawn_object("LinkTestDisplay");
+        test_env.set_parent(&display, &hud);
+        test_env.add_behavior(&display, Display::default());
+        
+        let found = GameObject::find("LinkTestHud/LinkTestDisplay").unwrap();
+        let display_ref = test_env.udon_ref::<Display>(&found);
+        assert!(display_ref.is_valid());
+        test_env.add_behavior(&counter, Counter { count: 0, display: display_ref.clone() });
+        
+        test_env.send_custom_event(&counter, "increment");
+        counter.send_custom_event("increment");
+        
+        assert_eq!(test_env.program_variable::<String>(&display, "text").as_deref(), Some("Count: 2"));
+        assert_eq!(display_ref.get::<String>("text"), "Count: 2");
+        assert_eq!(test_env.with_behavior(&display, |d: &mut Display| d.refreshes), Some(2));
+        assert_eq!(test_env.with_behavior(&counter, |c: &mut Counter| c.count), Some(2));
+        assert!(test_env.with_behavior(&display, |c: &mut Counter| c.count).is_none());
+        assert!(!test_env.udon_ref::<Display>(&hud).is_valid());
+    }
+    
+    #[test]
+    #[should_panic(expected = "own descendant")]
+    fn test_set_parent_rejects_cycles() {
+        let mut env = MockEnvironment::new();
+        env.spawn_object("Root");
+        env.spawn_object("Child");
+        env.set_parent("Child", "Root");
+        env.set_parent("Root", "Child");
+    }
+    
     #[test]
     fn test_time_state() {
         let mut time_state = MockTimeState::new();
@@ -618,6 +1139,35 @@ mod tests {
         assert_eq!(time_state.time, 0.0);
     }
     
+    #[test]
+    fn test_delayed_event_fires_after_advancing_past_the_delay() {
+        let mut time_state = MockTimeState::new();
+        time_state.schedule_event_delayed_seconds("Door", "CloseDoor", 2.0);
+        time_state.schedule_event_delayed_frames("Door", "PlayCreak", 2);
+        
+        time_state.advance_time(1.5);
+        assert!(time_state.fired_events.is_empty());
+        
+        time_state.advance_time(0.25);
+        let fired: Vec<&str> = time_state.fired_events.iter().map(|e| e.event_name.as_str()).collect();
+        assert_eq!(fired, vec!["PlayCreak"]);
+        
+        time_state.advance_time(0.5);
+        let fired: Vec<&str> = time_state.fired_events.iter().map(|e| e.event_name.as_str()).collect();
+        assert_eq!(fired, vec!["PlayCreak", "CloseDoor"]);
+        assert!(time_state.scheduled_events.is_empty());
+    }
+    
+    #[test]
+    fn test_game_object_delayed_event_fires_in_test_environment() {
+        let test_env = UdonTestEnvironment::new();
+        let door = test_env.spawn_object("DelayTestDoor");
+        
+        door.send_custom_event_delayed_seconds("CloseDoor", 2.0);
+        test_env.advance_time(2.5);
+        assert_eq!(test_env.fired_events(&door), vec!["CloseDoor".to_string()]);
+    }
+    
     #[test]
     fn test_audio_source() {
         let mut audio_source = MockAudioSource::new("TestClip");
@@ -8,6 +8,23 @@ pub trait UdonBehaviour {
     /// This is equivalent to UdonSharp's Start() method
     fn start(&mut self) {}
     
+    /// Disable this behavior so Unity stops calling its update methods
+    /// This is equivalent to UdonSharp's `enabled = false`
+    ///
+    /// Meant for `start` finding a misconfiguration it cannot recover from,
+    /// such as a missing required reference. With the checks in a method
+    /// returning `Result<(), String>`, a failed initialization is handled as
+    ///
+    /// ```ignore
+    /// fn start(&mut self) {
+    ///     if let Err(reason) = self.bind_references() {
+    ///         log::error!("Scoreboard disabled: {}", reason);
+    ///         self.disable_behavior();
+    ///     }
+    /// }
+    /// ```
+    fn disable_behavior(&mut self) {}
+    
     /// Called once per frame
     /// This is equivalent to UdonSharp's Update() method
     fn update(&mut self) {}
@@ -72,6 +89,38 @@ pub trait UdonBehaviour {
     /// This is equivalent to UdonSharp's OnApplicationPause() method
     fn on_application_pause(&mut self, _pause: bool) {}
     
+    /// Called when the jump button is pressed or released
+    /// This is equivalent to UdonSharp's InputJump() method
+    fn on_input_jump(&mut self, _value: bool, _args: UdonInputEventArgs) {}
+    
+    /// Called when the use button is pressed or released
+    /// This is equivalent to UdonSharp's InputUse() method
+    fn on_input_use(&mut self, _value: bool, _args: UdonInputEventArgs) {}
+    
+    /// Called when the grab button is pressed or released
+    /// This is equivalent to UdonSharp's InputGrab() method
+    fn on_input_grab(&mut self, _value: bool, _args: UdonInputEventArgs) {}
+    
+    /// Called when the drop button is pressed or released
+    /// This is equivalent to UdonSharp's InputDrop() method
+    fn on_input_drop(&mut self, _value: bool, _args: UdonInputEventArgs) {}
+    
+    /// Called when the horizontal movement axis changes
+    /// This is equivalent to UdonSharp's InputMoveHorizontal() method
+    fn on_input_move_horizontal(&mut self, _value: f32, _args: UdonInputEventArgs) {}
+    
+    /// Called when the vertical movement axis changes
+    /// This is equivalent to UdonSharp's InputMoveVertical() method
+    fn on_input_move_vertical(&mut self, _value: f32, _args: UdonInputEventArgs) {}
+    
+    /// Called when the horizontal look axis changes
+    /// This is equivalent to UdonSharp's InputLookHorizontal() method
+    fn on_input_look_horizontal(&mut self, _value: f32, _args: UdonInputEventArgs) {}
+    
+    /// Called when the vertical look axis changes
+    /// This is equivalent to UdonSharp's InputLookVertical() method
+    fn on_input_look_vertical(&mut self, _value: f32, _args: UdonInputEventArgs) {}
+    
     /// Get the UdonSharp type name for this behaviour
     fn get_udon_type_name(&self) -> &'static str {
         "UdonBehaviour"
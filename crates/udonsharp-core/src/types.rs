@@ -16,7 +16,7 @@ impl Vector2 {
     pub const LEFT: Vector2 = Vector2 { x: -1.0, y: 0.0 };
     pub const RIGHT: Vector2 = Vector2 { x: 1.0, y: 0.0 };
     
-    pub fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
     
@@ -52,7 +52,7 @@ impl Vector3 {
     pub const FORWARD: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
     pub const BACK: Vector3 = Vector3 { x: 0.0, y: 0.0, z: -1.0 };
     
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
     
@@ -92,6 +92,27 @@ impl Vector3 {
             z: self.x * other.y - self.y * other.x,
         }
     }
+    
+    /// Distance between two points
+    pub fn distance(a: Vector3, b: Vector3) -> f32 {
+        (a - b).magnitude()
+    }
+    
+    /// Linear interpolation from `a` to `b`, with `t` clamped to 0..=1
+    pub fn lerp(a: Vector3, b: Vector3, t: f32) -> Self {
+        a + (b - a) * t.clamp(0.0, 1.0)
+    }
+    
+    /// Move `current` towards `target` by at most `max_distance_delta`, without overshooting
+    pub fn move_towards(current: Vector3, target: Vector3, max_distance_delta: f32) -> Self {
+        let to_target = target - current;
+        let distance = to_target.magnitude();
+        if distance <= max_distance_delta || distance == 0.0 {
+            target
+        } else {
+            current + to_target * (max_distance_delta / distance)
+        }
+    }
 }
 
 impl std::ops::Add for Vector3 {
@@ -143,7 +164,7 @@ impl Vector4 {
     pub const ZERO: Vector4 = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
     pub const ONE: Vector4 = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
     
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self { x, y, z, w }
     }
 }
@@ -160,7 +181,7 @@ pub struct Quaternion {
 impl Quaternion {
     pub const IDENTITY: Quaternion = Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
     
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self { x, y, z, w }
     }
     
@@ -234,7 +255,7 @@ impl Color {
     pub const BLUE: Color = Color { r: 0.0, g: 0.0, b: 1.0, a: 1.0 };
     pub const TRANSPARENT: Color = Color { r: 0.0, g: 0.0, b: 0.0, a: 0.0 };
     
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self { r, g, b, a }
     }
     
@@ -253,7 +274,7 @@ pub struct Color32 {
 }
 
 impl Color32 {
-    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self { r, g, b, a }
     }
     
@@ -277,12 +298,18 @@ impl VRCPlayerApi {
     
     pub fn is_local(&self) -> bool {
         // This will be replaced with actual binding in generated code
-        false
+        #[cfg(feature = "native-test")]
+        { crate::native::is_local(self) }
+        #[cfg(not(feature = "native-test"))]
+        { false }
     }
     
     pub fn is_master(&self) -> bool {
         // This will be replaced with actual binding in generated code
-        false
+        #[cfg(feature = "native-test")]
+        { crate::native::is_master(self) }
+        #[cfg(not(feature = "native-test"))]
+        { false }
     }
     
     pub fn respawn(&self) {
@@ -301,9 +328,35 @@ pub struct GameObject {
 }
 
 impl GameObject {
-    pub fn find(_name: &str) -> Option<Self> {
-        // This will be replaced with actual binding in generated code
-        None
+    pub(crate) fn from_handle(handle: ObjectHandle) -> Self {
+        Self { handle }
+    }
+    
+    /// Find an active object by name or `Parent/Child` path.
+    ///
+    /// Outside Unity this resolves against the objects spawned in the
+    /// `testing::UdonTestEnvironment`, if one has been set up.
+    pub fn find(name: &str) -> Option<Self> {
+        if !crate::testing::is_mock_environment_ready() {
+            return None;
+        }
+        crate::testing::with_mock_environment(|env| {
+            env.find(name).map(|object| Self::from_handle(ObjectHandle { id: object.id }))
+        })
+    }
+    
+    /// Find a child by name or path below this object, including inactive ones.
+    ///
+    /// Bind the result with `let` or `if let`: it lowers to `transform.Find(name)`
+    /// and reads `gameObject` only when the child was found.
+    pub fn find_child(&self, name: &str) -> Option<Self> {
+        if !crate::testing::is_mock_environment_ready() {
+            return None;
+        }
+        crate::testing::with_mock_environment(|env| {
+            let parent = env.object_name(self)?;
+            env.find_child(&parent, name).map(|object| Self::from_handle(ObjectHandle { id: object.id }))
+        })
     }
     
     pub fn new(_name: &str) -> Self {
@@ -325,7 +378,53 @@ impl GameObject {
         Transform { handle: ObjectHandle::default() }
     }
     
-    pub fn send_custom_event(&self, _event_name: &str) {
+    /// Send a custom event to this object's behavior.
+    ///
+    /// In tests the event runs the `on_custom_event` of the behavior
+    /// attached with `UdonTestEnvironment::add_behavior`, if any.
+    pub fn send_custom_event(&self, event_name: &str) {
+        crate::testing::dispatch_custom_event(self.handle.id, event_name);
+    }
+    
+    /// Send a custom event to this object's behavior after `delay` seconds.
+    ///
+    /// Lowers to `SendCustomEventDelayedSeconds` on the object's
+    /// `UdonBehaviour`; in tests the event fires once `advance_time` passes
+    /// the delay.
+    pub fn send_custom_event_delayed_seconds(&self, event_name: &str, delay: f32) {
+        self.schedule_mock_event(|time, object| time.schedule_event_delayed_seconds(object, event_name, delay));
+    }
+    
+    /// Send a custom event to this object's behavior after `frames` frames.
+    ///
+    /// Lowers to `SendCustomEventDelayedFrames`; in tests every `advance_time`
+    /// call counts as one frame.
+    pub fn send_custom_event_delayed_frames(&self, event_name: &str, frames: i32) {
+        self.schedule_mock_event(|time, object| time.schedule_event_delayed_frames(object, event_name, frames));
+    }
+    
+    fn schedule_mock_event(&self, schedule: impl FnOnce(&mut crate::testing::MockTimeState, &str)) {
+        if !crate::testing::is_mock_environment_ready() {
+            return;
+        }
+        crate::testing::with_mock_environment_mut(|env| {
+            if let Some(object) = env.object_name(self) {
+                schedule(&mut env.time_state, &object);
+            }
+        });
+    }
+    
+    pub fn compare_tag(&self, _tag: &str) -> bool {
+        // This will be replaced with actual binding in generated code
+        false
+    }
+    
+    pub fn layer(&self) -> i32 {
+        // This will be replaced with actual binding in generated code
+        0
+    }
+    
+    pub fn set_layer(&mut self, _layer: i32) {
         // This will be replaced with actual binding in generated code
     }
 }
@@ -373,6 +472,146 @@ pub struct Collision {
     pub(crate) handle: ObjectHandle,
 }
 
+/// Hand an input event came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandType {
+    Right,
+    Left,
+}
+
+/// Arguments passed to VRChat input events such as `InputJump`
+///
+/// Maps to `VRC.Udon.Common.UdonInputEventArgs`; field reads lower to its
+/// `boolValue`, `floatValue` and `handType` fields.
+#[derive(Debug, Clone)]
+pub struct UdonInputEventArgs {
+    /// Button state of button inputs
+    pub bool_value: bool,
+    /// Axis value of movement and look inputs
+    pub float_value: f32,
+    /// Hand the input came from
+    pub hand_type: HandType,
+}
+
+/// Typed reference to another UdonBehaviour
+///
+/// Hold one as an `#[udon_public]` field to wire behaviors together in the
+/// inspector. The field is emitted as a `UdonBehaviour` variable, `call`
+/// lowers to `SendCustomEvent` and `set` to `SetProgramVariable`, with event
+/// and variable names checked against `T` when it is compiled alongside.
+pub struct UdonRef<T: crate::traits::UdonBehaviour> {
+    pub(crate) handle: ObjectHandle,
+    behavior: std::marker::PhantomData<T>,
+}
+
+impl<T: crate::traits::UdonBehaviour> UdonRef<T> {
+    /// Create an unassigned reference
+    pub fn new() -> Self {
+        Self::from_handle(ObjectHandle::default())
+    }
+
+    pub(crate) fn from_handle(handle: ObjectHandle) -> Self {
+        Self { handle, behavior: std::marker::PhantomData }
+    }
+
+    /// Check if the reference has been assigned in the inspector.
+    ///
+    /// In tests a reference is valid once its object has a behavior attached.
+    pub fn is_valid(&self) -> bool {
+        crate::testing::is_mock_environment_ready()
+            && crate::testing::with_mock_environment(|env| env.behaviors.contains_key(&self.handle.id))
+    }
+
+    /// Run a public method of the referenced behavior as a custom event.
+    ///
+    /// In tests this runs the behavior's `on_custom_event`.
+    pub fn call(&self, event: &str) {
+        crate::testing::dispatch_custom_event(self.handle.id, event);
+    }
+
+    /// Read a public or synced variable of the referenced behavior.
+    ///
+    /// In tests this returns the value last passed to `set`, or the default.
+    pub fn get<V: Default + Clone + 'static>(&self, variable: &str) -> V {
+        if !crate::testing::is_mock_environment_ready() {
+            return V::default();
+        }
+        crate::testing::with_mock_environment(|env| env.program_variable::<V>(self.handle.id, variable))
+            .unwrap_or_default()
+    }
+
+    /// Set a public variable of the referenced behavior
+    pub fn set<V: std::any::Any + Send + Sync>(&self, variable: &str, value: V) {
+        if !crate::testing::is_mock_environment_ready() {
+            return;
+        }
+        crate::testing::with_mock_environment_mut(|env| env.set_program_variable(self.handle.id, variable, value));
+    }
+}
+
+impl<T: crate::traits::UdonBehaviour> Default for UdonRef<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: crate::traits::UdonBehaviour> Clone for UdonRef<T> {
+    fn clone(&self) -> Self {
+        Self::from_handle(self.handle.clone())
+    }
+}
+
+impl<T: crate::traits::UdonBehaviour> std::fmt::Debug for UdonRef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UdonRef").field("handle", &self.handle).finish()
+    }
+}
+
+/// Unity LayerMask utilities
+///
+/// Masks are plain `i32` bit sets with one bit per layer, as in Unity.
+/// Layer names resolve against the layers VRChat projects define; custom
+/// layers are only known inside Unity.
+pub struct LayerMask;
+
+impl LayerMask {
+    /// Layers defined by every VRChat project, indexed by layer number
+    const BUILT_IN_LAYERS: [&'static str; 22] = [
+        "Default", "TransparentFX", "Ignore Raycast", "Item", "Water", "UI", "", "",
+        "Interactive", "Player", "PlayerLocal", "Environment", "UiMenu", "Pickup",
+        "PickupNoEnvironment", "StereoLeft", "StereoRight", "Walkthrough",
+        "MirrorReflection", "reserved2", "reserved3", "reserved4",
+    ];
+    
+    /// Get the layer number of a layer name, or -1 if there is no such layer
+    pub fn name_to_layer(layer_name: &str) -> i32 {
+        Self::BUILT_IN_LAYERS.iter()
+            .position(|name| !name.is_empty() && *name == layer_name)
+            .map_or(-1, |layer| layer as i32)
+    }
+    
+    /// Get the name of a layer number, or an empty string if it is unnamed
+    pub fn layer_to_name(layer: i32) -> String {
+        usize::try_from(layer).ok()
+            .and_then(|layer| Self::BUILT_IN_LAYERS.get(layer))
+            .map(|name| name.to_string())
+            .unwrap_or_default()
+    }
+    
+    /// Build a mask containing the named layers; unknown names are ignored
+    pub fn get_mask(layer_names: &[&str]) -> i32 {
+        layer_names.iter()
+            .map(|name| Self::name_to_layer(name))
+            .filter(|layer| *layer >= 0)
+            .fold(0, |mask, layer| mask | (1 << layer))
+    }
+    
+    /// Check whether a mask contains a layer, e.g. to filter collisions
+    pub fn contains(mask: i32, layer: i32) -> bool {
+        (0..32).contains(&layer) && mask & (1 << layer) != 0
+    }
+}
+
 // Internal handle type for Unity objects
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ObjectHandle {
@@ -426,10 +665,14 @@ pub struct UdonFieldInfo {
     pub name: &'static str,
     pub type_name: &'static str,
     pub is_public: bool,
+    /// Emitted as a `[SerializeField] private` field
+    pub is_serialize_field: bool,
     pub is_sync: bool,
     pub sync_mode: UdonSyncMode,
     pub header_text: Option<String>,
     pub tooltip_text: Option<String>,
+    /// Inspector bounds from `#[udon_range(min, max)]`
+    pub range: Option<(f32, f32)>,
 }
 
 /// Unity event types that can be handled by UdonBehaviour
@@ -862,11 +1105,29 @@ impl UdonSharpUtility {
     pub fn get_players() -> Vec<VRCPlayerApi> {
         Vec::new()
     }
+    
+    /// Shuffle `items` in place with a Fisher–Yates shuffle seeded by `seed`
+    ///
+    /// Generated code seeds `UnityEngine.Random` with `InitState(seed)` before
+    /// shuffling, so every client shuffling with the same seed gets the same
+    /// order. Note that this reseeds Unity's shared random generator.
+    pub fn shuffle<T>(items: &mut [T], seed: i32) {
+        // Stand-in for Unity's generator outside of Udon (xorshift64)
+        let mut state = (seed as u32 as u64) ^ 0x9E37_79B9_7F4A_7C15;
+        for index in (1..items.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            items.swap(index, (state % (index as u64 + 1)) as usize);
+        }
+    }
 }
 
 /// VRChat Networking utilities
 pub struct Networking;
 
+// The `native-test` feature replaces these stand-ins with the player list in `native`
+#[cfg(not(feature = "native-test"))]
 impl Networking {
     /// Check if the local player is the owner of an object
     pub fn is_owner(_player: &VRCPlayerApi, _target: &GameObject) -> bool {
@@ -959,6 +1220,22 @@ impl Mathf {
     pub fn round(value: f32) -> f32 {
         value.round()
     }
+    
+    /// Round to the nearest integer; halves round to the even neighbour as
+    /// `Mathf.RoundToInt` does, so `2.5` becomes `2`
+    pub fn round_to_int(value: f32) -> i32 {
+        value.round_ties_even() as i32
+    }
+    
+    /// Round down to an integer, like `Mathf.FloorToInt`
+    pub fn floor_to_int(value: f32) -> i32 {
+        value.floor() as i32
+    }
+    
+    /// Round up to an integer, like `Mathf.CeilToInt`
+    pub fn ceil_to_int(value: f32) -> i32 {
+        value.ceil() as i32
+    }
 }
 
 /// Unity Random utilities
@@ -984,6 +1261,8 @@ impl Random {
 /// Unity Time utilities
 pub struct Time;
 
+// The `native-test` feature replaces these stand-ins with the clock in `native`
+#[cfg(not(feature = "native-test"))]
 impl Time {
     pub fn time() -> f32 {
         // This will be replaced with actual binding in generated code
@@ -995,6 +1274,12 @@ impl Time {
         0.016
     }
     
+    /// Interval of the physics step; use this instead of `delta_time` in `fixed_update`
+    pub fn fixed_delta_time() -> f32 {
+        // This will be replaced with actual binding in generated code
+        0.02
+    }
+    
     pub fn fixed_time() -> f32 {
         // This will be replaced with actual binding in generated code
         0.0
@@ -1008,4 +1293,36 @@ impl Time {
     pub fn set_time_scale(_scale: f32) {
         // This will be replaced with actual binding in generated code
     }
-}
\ No newline at end of file
+}
+/// Seconds elapsed since a point in time, measured with `Time::time()`
+///
+/// The compiler lowers a timer field to a `float` holding its start time and
+/// each call to arithmetic against `Time.time`, so a timer costs no more than
+/// a hand-written `start_time` field. `has_elapsed` is inclusive: a timer for
+/// `2.0` seconds has elapsed on the frame where exactly `2.0` seconds passed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ElapsedTimer {
+    start_time: f32,
+}
+
+impl ElapsedTimer {
+    /// A timer started at time zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restart the timer from the current time
+    pub fn start(&mut self) {
+        self.start_time = Time::time();
+    }
+
+    /// Seconds since the timer was started
+    pub fn elapsed(&self) -> f32 {
+        Time::time() - self.start_time
+    }
+
+    /// Whether at least `seconds` have passed since the timer was started
+    pub fn has_elapsed(&self, seconds: f32) -> bool {
+        self.elapsed() >= seconds
+    }
+}
@@ -6,7 +6,7 @@ use syn::{parse_macro_input, DeriveInput, Data, Fields, Field, Attribute, Meta,
 /// 
 /// This macro generates the necessary boilerplate for UdonSharp compatibility
 /// and processes UdonSharp-specific attributes.
-#[proc_macro_derive(UdonBehaviour, attributes(udon_sync_mode, udon_public, udon_sync, udon_event, udon_header, udon_tooltip, udon_range, udon_text_area))]
+#[proc_macro_derive(UdonBehaviour, attributes(udon_sync_mode, udon_editor_only, udon_json, udon_public, udon_serialize_field, udon_sync, udon_event, udon_header, udon_tooltip, header, tooltip, range, udon_range, udon_quantize, udon_text_area, udon_allow, udon_execution_order, udon_debug, udon_pool, udon_main, udon_forward))]
 pub fn derive_udon_behaviour(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -56,10 +56,108 @@ pub fn udon_sync(_args: TokenStream, input: TokenStream) -> TokenStream {
 }
 
 /// Attribute macro for marking methods as UdonSharp events
+///
+/// Events are invoked by name through `SendCustomEvent`, which passes no
+/// arguments, so the method cannot take parameters besides `&mut self`.
 #[proc_macro_attribute]
 pub fn udon_event(_args: TokenStream, input: TokenStream) -> TokenStream {
-    // For now, just pass through the input
     // The actual processing happens in the derive macro
+    let Ok(method) = syn::parse::<syn::ImplItemFn>(input.clone()) else {
+        return input;
+    };
+    if let Some(param) = method.sig.inputs.iter().find(|input| matches!(input, syn::FnArg::Typed(_))) {
+        return syn::Error::new_spanned(param, UDON_EVENT_PARAMETER_ERROR)
+            .to_compile_error()
+            .into();
+    }
+    input
+}
+
+const UDON_EVENT_PARAMETER_ERROR: &str = "#[udon_event] methods are invoked by name through SendCustomEvent and cannot take parameters; \
+    set a #[udon_public] field on the behavior before sending the event, or use #[udon_network_event] for events raised over the network";
+
+/// Attribute macro for marking methods as UdonSharp network events
+///
+/// The compiler generates a handler named after the event plus a
+/// `Send{Event}` caller that invokes it through `SendCustomNetworkEvent` on
+/// the chosen target. The target defaults to `All` and the event name to the
+/// PascalCase method name.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// impl Cannon {
+///     #[udon_network_event(target = "Owner")]
+///     pub fn fire_cannon(&mut self) {
+///         // Runs on the owner when SendFireCannon() is called
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn udon_network_event(args: TokenStream, input: TokenStream) -> TokenStream {
+    let method = parse_macro_input!(input as syn::ImplItemFn);
+
+    let mut target = syn::LitStr::new("All", proc_macro2::Span::call_site());
+    let mut event_name = None;
+    let parser = syn::meta::parser(|meta| {
+        let value: syn::LitStr = meta.value()?.parse()?;
+        if meta.path.is_ident("target") {
+            if !matches!(value.value().as_str(), "All" | "Others" | "Owner") {
+                return Err(syn::Error::new_spanned(&value, "expected target \"All\", \"Others\" or \"Owner\""));
+            }
+            target = value;
+        } else if meta.path.is_ident("name") {
+            event_name = Some(value);
+        } else {
+            return Err(meta.error("expected `target` or `name`"));
+        }
+        Ok(())
+    });
+    if let Err(err) = syn::parse::Parser::parse(parser, args) {
+        return err.to_compile_error().into();
+    }
+
+    if let Some(param) = method.sig.inputs.iter().find(|input| matches!(input, syn::FnArg::Typed(_))) {
+        return syn::Error::new_spanned(param, "network events are invoked by name and cannot take parameters")
+            .to_compile_error()
+            .into();
+    }
+
+    let fn_name = &method.sig.ident;
+    let event_name = event_name.map(|name| name.value())
+        .unwrap_or_else(|| snake_to_pascal_case(&fn_name.to_string()));
+    let const_prefix = fn_name.to_string().to_uppercase();
+    let name_const = syn::Ident::new(&format!("{}_NETWORK_EVENT_NAME", const_prefix), fn_name.span());
+    let target_const = syn::Ident::new(&format!("{}_NETWORK_EVENT_TARGET", const_prefix), fn_name.span());
+
+    let expanded = quote! {
+        #method
+
+        pub const #name_const: &'static str = #event_name;
+        pub const #target_const: &'static str = #target;
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Attribute macro for silencing compiler lints on a behavior or method
+///
+/// Takes the names of the lints to allow, as listed in the compiler's
+/// `lint_levels::LINTS`. Fields use the same attribute through the derive.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// impl SpawnManager {
+///     #[udon_allow(dynamic_find)]
+///     fn collect_spawn_points(&mut self) {
+///         // Runs once from start
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn udon_allow(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // The lint names are read by the compiler
     input
 }
 
@@ -71,6 +169,192 @@ pub fn udon_sync_mode(_args: TokenStream, input: TokenStream) -> TokenStream {
     input
 }
 
+/// Attribute macro for marking behaviors as editor-only development tools
+///
+/// Editor-only behaviors are compiled into debug builds and left out of release builds.
+#[proc_macro_attribute]
+pub fn udon_editor_only(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // For now, just pass through the input
+    // The actual processing happens in the compiler
+    input
+}
+
+/// Attribute macro for setting the script execution order of a behavior
+///
+/// `#[udon_execution_order(-10)]` runs the behavior's `Update` and other
+/// events before behaviors with a higher order, like Unity's script execution
+/// order settings.
+#[proc_macro_attribute]
+pub fn udon_execution_order(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // For now, just pass through the input
+    // The actual processing happens in the compiler
+    input
+}
+
+/// Attribute macro for marking the world's main behavior
+///
+/// `#[udon_main]` designates the primary behavior explicitly instead of
+/// relying on `pub use ... as MainBehaviour` aliases. At most one behavior may
+/// be marked; without one, a project with several behaviors is compiled as a
+/// multi-behavior world.
+#[proc_macro_attribute]
+pub fn udon_main(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // For now, just pass through the input
+    // The actual processing happens in the compiler
+    input
+}
+
+/// Attribute macro for forwarding a Unity event to other behaviors
+///
+/// `#[udon_forward(event = "OnPlayerJoined", to = ["UIController", "GameLogic"])]`
+/// sends `OnPlayerJoined` to each listed behavior after the behavior's own
+/// handler runs. Targets must name behaviors of the project.
+#[proc_macro_attribute]
+pub fn udon_forward(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // For now, just pass through the input
+    // The actual processing happens in the compiler
+    input
+}
+
+/// Attribute macro for pre-allocating a pool of reusable records
+///
+/// `#[udon_pool(size = 32)]` allocates 32 `DataDictionary` records in `Start`
+/// (`#[udon_pool(DataList, size = 32)]` pools lists). Allocations of the pooled
+/// type draw a cleared record from the pool and `drop` returns it; when the
+/// pool runs out, the oldest record is logged and reused.
+#[proc_macro_attribute]
+pub fn udon_pool(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // For now, just pass through the input
+    // The actual processing happens in the compiler
+    input
+}
+
+/// Attribute macro for serializing a behavior's fields as JSON
+///
+/// The compiler generates `ToJson`/`FromJson` methods that go through VRChat's
+/// native `VRCJson` instead of serde.
+#[proc_macro_attribute]
+pub fn udon_json(_args: TokenStream, input: TokenStream) -> TokenStream {
+    // For now, just pass through the input
+    // The actual processing happens in the compiler
+    input
+}
+
+/// Attribute macro for syncing a small plain struct as one `byte[]`
+///
+/// Embedded in a behavior under `#[udon_sync]`, the struct's fields are
+/// packed into a single synced byte array instead of being synced one by one.
+/// Fields must be fixed-size primitives (`bool`, integers, `f32`, `f64`) and
+/// are packed in declaration order, little-endian, with no padding. The
+/// generated `to_udon_bytes`/`from_udon_bytes` use the same layout as the C#
+/// the compiler emits, so the packing can be tested in Rust.
+///
+/// ```rust
+/// use udonsharp_macros::udon_sync_blob;
+///
+/// #[udon_sync_blob]
+/// pub struct PlayerState {
+///     health: i32,
+///     alive: bool,
+/// }
+///
+/// let state = PlayerState { health: 80, alive: true };
+/// assert_eq!(state.to_udon_bytes().len(), PlayerState::UDON_BLOB_SIZE);
+/// ```
+#[proc_macro_attribute]
+pub fn udon_sync_blob(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(input as syn::ItemStruct);
+
+    match generate_sync_blob(&item_struct) {
+        Ok(blob_impl) => TokenStream::from(quote! {
+            #item_struct
+            #blob_impl
+        }),
+        Err(err) => {
+            let err = err.to_compile_error();
+            TokenStream::from(quote! {
+                #item_struct
+                #err
+            })
+        }
+    }
+}
+
+/// Generate the packing constants and methods of a `#[udon_sync_blob]` struct
+fn generate_sync_blob(item_struct: &syn::ItemStruct) -> syn::Result<proc_macro2::TokenStream> {
+    let Fields::Named(fields) = &item_struct.fields else {
+        return Err(syn::Error::new_spanned(&item_struct.ident, "udon_sync_blob requires a struct with named fields"));
+    };
+    if !item_struct.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&item_struct.generics, "udon_sync_blob does not support generic structs"));
+    }
+
+    let mut sizes = Vec::new();
+    let mut packs = Vec::new();
+    let mut unpacks = Vec::new();
+    let mut names = Vec::new();
+    for field in &fields.named {
+        let name = field.ident.clone().expect("named field");
+        let ty = &field.ty;
+        let type_name = match ty {
+            Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident().map(|ident| ident.to_string()),
+            _ => None,
+        };
+        let size: usize = match type_name.as_deref() {
+            Some("bool" | "i8" | "u8") => 1,
+            Some("i16" | "u16") => 2,
+            Some("i32" | "u32" | "f32") => 4,
+            Some("i64" | "u64" | "f64") => 8,
+            _ => return Err(syn::Error::new_spanned(
+                ty,
+                "udon_sync_blob fields must be bool, an integer, f32 or f64",
+            )),
+        };
+
+        sizes.push(size);
+        if type_name.as_deref() == Some("bool") {
+            packs.push(quote! { bytes.push(self.#name as u8); });
+            unpacks.push(quote! { let #name = bytes[offset] != 0; });
+        } else {
+            packs.push(quote! { bytes.extend_from_slice(&self.#name.to_le_bytes()); });
+            unpacks.push(quote! {
+                let #name = <#ty>::from_le_bytes(bytes[offset..offset + #size].try_into().ok()?);
+            });
+        }
+        names.push(name);
+    }
+
+    let struct_name = &item_struct.ident;
+    let total: usize = sizes.iter().sum();
+    Ok(quote! {
+        impl #struct_name {
+            /// Number of bytes the struct packs into
+            pub const UDON_BLOB_SIZE: usize = #total;
+
+            /// Pack the fields in declaration order, little-endian
+            pub fn to_udon_bytes(&self) -> ::std::vec::Vec<u8> {
+                let mut bytes = ::std::vec::Vec::with_capacity(Self::UDON_BLOB_SIZE);
+                #(#packs)*
+                bytes
+            }
+
+            /// Unpack a blob produced by `to_udon_bytes`; `None` if its length is wrong
+            pub fn from_udon_bytes(bytes: &[u8]) -> ::core::option::Option<Self> {
+                if bytes.len() != Self::UDON_BLOB_SIZE {
+                    return ::core::option::Option::None;
+                }
+                let mut offset = 0usize;
+                #(
+                    #unpacks
+                    offset += #sizes;
+                )*
+                let _ = offset;
+                ::core::option::Option::Some(Self { #(#names),* })
+            }
+        }
+    })
+}
+
 /// Attribute macro for marking functions as UdonSharp tests
 /// 
 /// This macro transforms regular Rust test functions into UdonSharp-compatible
@@ -183,6 +467,13 @@ pub fn udon_behaviour(args: TokenStream, input: TokenStream) -> TokenStream {
     });
     
     let events_str = behaviour_config.events.join(",");
+    // Custom events are allowed, but listed in the docs so they stand out from built-ins
+    let custom_events_doc = if behaviour_config.custom_events.is_empty() {
+        quote! {}
+    } else {
+        let note = format!("Custom events (not Udon built-ins): {}", behaviour_config.custom_events.join(", "));
+        quote! { #[doc = ""] #[doc = #note] }
+    };
     let deps_str = behaviour_config.dependencies.join(",");
     let auto_sync = behaviour_config.auto_sync;
     
@@ -190,6 +481,7 @@ pub fn udon_behaviour(args: TokenStream, input: TokenStream) -> TokenStream {
     let expanded = quote! {
         #(#fn_attrs)*
         #[doc = concat!("UdonBehaviour entry point: ", #behaviour_name)]
+        #custom_events_doc
         #[allow(non_snake_case)]
         #fn_vis fn #fn_name(#fn_inputs) #fn_output {
             #fn_block
@@ -199,8 +491,9 @@ pub fn udon_behaviour(args: TokenStream, input: TokenStream) -> TokenStream {
         #[doc(hidden)]
         #[allow(non_upper_case_globals)]
         const _: () = {
-            // This metadata will be extracted by the WASM analyzer
-            #[export_name = concat!("__udon_behaviour_", stringify!(#fn_name))]
+            // This metadata will be extracted by the WASM analyzer. The module path keeps
+            // same-named entry points in different modules from clashing at link time.
+            #[export_name = concat!("__udon_behaviour_", module_path!(), "::", stringify!(#fn_name))]
             static UDON_BEHAVIOUR_METADATA: &str = concat!(
                 "name:", #behaviour_name, ";",
                 "events:", #events_str, ";",
@@ -213,6 +506,148 @@ pub fn udon_behaviour(args: TokenStream, input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Attribute macro that generates an enum-based dispatcher for a trait
+///
+/// Udon has no vtables, so `Box<dyn Trait>` cannot be compiled. For a bounded set of
+/// implementors this macro generates `{Trait}Dispatch`, an enum with one variant per
+/// implementor, and implements the trait on it by matching on the variant.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[udon_enum_dispatch(Patrol, Chase)]
+/// pub trait EnemyState {
+///     fn tick(&mut self, delta: f32) -> bool;
+/// }
+///
+/// let mut state: EnemyStateDispatch = Patrol::default().into();
+/// state.tick(0.1); // routes to Patrol::tick
+/// ```
+#[proc_macro_attribute]
+pub fn udon_enum_dispatch(args: TokenStream, input: TokenStream) -> TokenStream {
+    use syn::parse::Parser;
+
+    let item_trait = parse_macro_input!(input as syn::ItemTrait);
+    let implementors = match syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated.parse(args) {
+        Ok(implementors) => implementors,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    match generate_enum_dispatch(&item_trait, &implementors) {
+        Ok(dispatch) => TokenStream::from(quote! {
+            #item_trait
+            #dispatch
+        }),
+        Err(err) => {
+            let err = err.to_compile_error();
+            TokenStream::from(quote! {
+                #item_trait
+                #err
+            })
+        }
+    }
+}
+
+/// Generate the dispatch enum, its `From` conversions and the trait implementation
+fn generate_enum_dispatch(
+    item_trait: &syn::ItemTrait,
+    implementors: &syn::punctuated::Punctuated<syn::Path, syn::Token![,]>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if implementors.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item_trait.ident,
+            "udon_enum_dispatch requires at least one implementor, e.g. #[udon_enum_dispatch(Patrol, Chase)]",
+        ));
+    }
+    if !item_trait.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item_trait.generics,
+            "udon_enum_dispatch does not support generic traits",
+        ));
+    }
+
+    let trait_name = &item_trait.ident;
+    let vis = &item_trait.vis;
+    let enum_name = syn::Ident::new(&format!("{}Dispatch", trait_name), trait_name.span());
+
+    let mut variants = Vec::new();
+    for path in implementors {
+        let variant = path.segments.last()
+            .map(|segment| segment.ident.clone())
+            .ok_or_else(|| syn::Error::new_spanned(path, "expected an implementor type"))?;
+        variants.push((variant, path));
+    }
+
+    let enum_variants = variants.iter().map(|(variant, path)| quote! { #variant(#path) });
+    let from_impls = variants.iter().map(|(variant, path)| quote! {
+        impl ::core::convert::From<#path> for #enum_name {
+            fn from(value: #path) -> Self {
+                #enum_name::#variant(value)
+            }
+        }
+    });
+
+    let mut methods = Vec::new();
+    for item in &item_trait.items {
+        match item {
+            syn::TraitItem::Fn(trait_fn) => {
+                if trait_fn.sig.receiver().is_none() {
+                    if trait_fn.default.is_some() {
+                        continue;
+                    }
+                    return Err(syn::Error::new_spanned(
+                        &trait_fn.sig,
+                        "udon_enum_dispatch can only dispatch methods that take self",
+                    ));
+                }
+
+                // Rename parameters so patterns in the trait signature do not matter
+                let mut sig = trait_fn.sig.clone();
+                let mut arg_names = Vec::new();
+                for (index, input) in sig.inputs.iter_mut().enumerate() {
+                    if let syn::FnArg::Typed(pat_type) = input {
+                        let name = syn::Ident::new(&format!("arg{}", index), proc_macro2::Span::call_site());
+                        *pat_type.pat = syn::parse_quote!(#name);
+                        arg_names.push(name);
+                    }
+                }
+
+                let method = &sig.ident;
+                let arms = variants.iter().map(|(variant, path)| quote! {
+                    #enum_name::#variant(inner) => <#path as #trait_name>::#method(inner #(, #arg_names)*)
+                });
+                methods.push(quote! {
+                    #sig {
+                        match self {
+                            #(#arms,)*
+                        }
+                    }
+                });
+            }
+            syn::TraitItem::Type(trait_type) => {
+                return Err(syn::Error::new_spanned(
+                    trait_type,
+                    "udon_enum_dispatch does not support associated types",
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(quote! {
+        #[doc = concat!("Enum dispatcher over the implementors of `", stringify!(#trait_name), "`")]
+        #vis enum #enum_name {
+            #(#enum_variants,)*
+        }
+
+        #(#from_impls)*
+
+        impl #trait_name for #enum_name {
+            #(#methods)*
+        }
+    })
+}
+
 fn extract_sync_mode(attrs: &[Attribute]) -> proc_macro2::TokenStream {
     for attr in attrs {
         if attr.path().is_ident("udon_sync_mode") {
@@ -237,17 +672,20 @@ fn process_fields(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>) -
         let field_type = &field.ty;
         
         let mut is_public = false;
+        let mut is_serialize_field = false;
         let mut is_sync = false;
         let mut sync_mode = quote! { udonsharp_core::types::UdonSyncMode::None };
         let mut header_text = None;
         let mut tooltip_text = None;
-        let _range_info: Option<(f32, f32)> = None;
+        let mut range = None;
         let _text_area_info: Option<(u32, u32)> = None;
         
         // Process field attributes
         for attr in &field.attrs {
             if attr.path().is_ident("udon_public") {
                 is_public = true;
+            } else if attr.path().is_ident("udon_serialize_field") {
+                is_serialize_field = true;
             } else if attr.path().is_ident("udon_sync") {
                 is_sync = true;
                 // Extract sync mode if specified - simplified for syn 2.0
@@ -260,30 +698,14 @@ fn process_fields(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>) -
                         sync_mode = quote! { udonsharp_core::types::UdonSyncMode::Continuous };
                     }
                 }
-            } else if attr.path().is_ident("udon_header") {
-                if let Meta::List(meta_list) = &attr.meta {
-                    let tokens_str = meta_list.tokens.to_string();
-                    // Extract string literal from tokens
-                    if let Some(start) = tokens_str.find('"') {
-                        if let Some(end) = tokens_str.rfind('"') {
-                            if start < end {
-                                header_text = Some(tokens_str[start + 1..end].to_string());
-                            }
-                        }
-                    }
-                }
-            } else if attr.path().is_ident("udon_tooltip") {
-                if let Meta::List(meta_list) = &attr.meta {
-                    let tokens_str = meta_list.tokens.to_string();
-                    // Extract string literal from tokens
-                    if let Some(start) = tokens_str.find('"') {
-                        if let Some(end) = tokens_str.rfind('"') {
-                            if start < end {
-                                tooltip_text = Some(tokens_str[start + 1..end].to_string());
-                            }
-                        }
-                    }
-                }
+            } else if is_inspector_attribute(attr, "header") {
+                header_text = attribute_text(attr);
+            } else if is_inspector_attribute(attr, "tooltip") {
+                tooltip_text = attribute_text(attr);
+            } else if is_inspector_attribute(attr, "range") {
+                range = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+                    .ok()
+                    .filter(|bounds| bounds.len() == 2);
             }
         }
         
@@ -295,16 +717,25 @@ fn process_fields(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>) -
             Some(text) => quote! { Some(#text.to_string()) },
             None => quote! { None },
         };
+        let range_opt = match range {
+            Some(bounds) => {
+                let (min, max) = (&bounds[0], &bounds[1]);
+                quote! { Some((#min as f32, #max as f32)) }
+            }
+            None => quote! { None },
+        };
         
         field_info.push(quote! {
             udonsharp_core::types::UdonFieldInfo {
                 name: stringify!(#field_name),
                 type_name: stringify!(#field_type),
                 is_public: #is_public,
+                is_serialize_field: #is_serialize_field,
                 is_sync: #is_sync,
                 sync_mode: #sync_mode,
                 header_text: #header_text_opt,
                 tooltip_text: #tooltip_text_opt,
+                range: #range_opt,
             }
         });
     }
@@ -318,11 +749,25 @@ fn process_fields(fields: &syn::punctuated::Punctuated<Field, syn::Token![,]>) -
     }
 }
 
+/// Check for an inspector attribute in either its shorthand (`#[header]`) or
+/// `udon_`-prefixed (`#[udon_header]`) spelling
+fn is_inspector_attribute(attr: &Attribute, name: &str) -> bool {
+    let path = attr.path();
+    path.is_ident(name) || path.get_ident().is_some_and(|ident| ident.to_string().strip_prefix("udon_") == Some(name))
+}
+
+/// Get the string literal argument of an attribute like `#[header("text")]`
+fn attribute_text(attr: &Attribute) -> Option<String> {
+    attr.parse_args::<syn::LitStr>().ok().map(|text| text.value())
+}
+
 /// Configuration for udon_behaviour attribute
 #[derive(Debug, Default)]
 struct UdonBehaviourConfig {
     name: Option<String>,
     events: Vec<String>,
+    /// Declared events that are not Udon built-ins
+    custom_events: Vec<String>,
     dependencies: Vec<String>,
     auto_sync: bool,
 }
@@ -348,7 +793,7 @@ fn parse_udon_behaviour_args_simple(args_str: &str) -> Result<UdonBehaviourConfi
         }
     }
     
-    // Extract events
+    // Extract events, either as `events = "Start,Update"` or `events = ["Start", "Update"]`
     if let Some(start) = args_clean.find("events=\"") {
         let start = start + 8; // length of "events=\""
         if let Some(end) = args_clean[start..].find("\"") {
@@ -359,7 +804,17 @@ fn parse_udon_behaviour_args_simple(args_str: &str) -> Result<UdonBehaviourConfi
                 .filter(|s| !s.is_empty())
                 .collect();
         }
+    } else if let Some(start) = args_clean.find("events=[") {
+        let start = start + 8; // length of "events=["
+        if let Some(end) = args_clean[start..].find(']') {
+            config.events = args_clean[start..start + end]
+                .split(',')
+                .map(|s| s.trim().trim_matches('"').to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
     }
+    config.custom_events = validate_event_names(&config.events)?;
     
     // Extract dependencies
     if let Some(start) = args_clean.find("dependencies=\"") {
@@ -387,6 +842,79 @@ fn parse_udon_behaviour_args_simple(args_str: &str) -> Result<UdonBehaviourConfi
     Ok(config)
 }
 
+/// Events Udon invokes on a behavior by name
+const KNOWN_UDON_EVENTS: &[&str] = &[
+    // Unity lifecycle events
+    "Awake", "Start", "Update", "LateUpdate", "FixedUpdate", "PostLateUpdate",
+    "OnEnable", "OnDisable", "OnDestroy",
+    // Unity collision events
+    "OnTriggerEnter", "OnTriggerExit", "OnTriggerStay",
+    "OnCollisionEnter", "OnCollisionExit", "OnCollisionStay",
+    // Unity UI events
+    "OnPointerClick", "OnPointerDown", "OnPointerUp", "OnPointerEnter", "OnPointerExit",
+    "OnDrag", "OnBeginDrag", "OnEndDrag", "OnDrop",
+    // VRChat events
+    "Interact", "OnPlayerJoined", "OnPlayerLeft", "OnPlayerRespawn",
+    "OnPlayerTriggerEnter", "OnPlayerTriggerExit", "OnPlayerTriggerStay",
+    "OnStationEntered", "OnStationExited",
+    "OnOwnershipTransferred", "OnDeserialization",
+    "OnPreSerialization", "OnPostSerialization",
+    "OnPickup", "OnPickupUseDown", "OnPickupUseUp",
+    "OnVideoStart", "OnVideoEnd", "OnVideoError", "OnVideoReady",
+    "OnVideoPlay", "OnVideoPause",
+];
+
+/// Check declared event names, returning the ones that are custom events
+///
+/// A name within a couple of edits of a built-in event is almost always a typo
+/// that would leave the behavior without a working handler, so it is rejected
+/// with the closest built-in as a suggestion rather than accepted as custom.
+fn validate_event_names(events: &[String]) -> Result<Vec<String>, String> {
+    let mut custom_events = Vec::new();
+
+    for event in events {
+        if KNOWN_UDON_EVENTS.contains(&event.as_str()) {
+            continue;
+        }
+
+        let closest = KNOWN_UDON_EVENTS.iter()
+            .map(|known| (edit_distance(&event.to_lowercase(), &known.to_lowercase()), *known))
+            .min();
+        if let Some((distance, known)) = closest {
+            if distance <= 2 && distance * 2 < event.len() {
+                return Err(format!("Unknown Udon event '{}'; did you mean '{}'?", event, known));
+            }
+        }
+
+        let mut chars = event.chars();
+        let is_identifier = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier {
+            return Err(format!("Event '{}' is not a valid C# method name", event));
+        }
+        custom_events.push(event.clone());
+    }
+
+    Ok(custom_events)
+}
+
+/// Number of single-character insertions, deletions and substitutions between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 /// Validate that a function is suitable for udon_behaviour attribute
 fn validate_udon_behaviour_function(func: &ItemFn) -> Result<(), String> {
     // Check function visibility
@@ -445,4 +973,27 @@ fn snake_to_pascal_case(snake_str: &str) -> String {
             }
         })
         .collect()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typo_in_reserved_event_suggests_closest_match() {
+        let error = parse_udon_behaviour_args_simple(r#"events = ["Start", "Updat"]"#).unwrap_err();
+        assert_eq!(error, "Unknown Udon event 'Updat'; did you mean 'Update'?");
+
+        let error = parse_udon_behaviour_args_simple(r#"events = "Start,onplayerjoined""#).unwrap_err();
+        assert!(error.contains("did you mean 'OnPlayerJoined'?"), "{}", error);
+    }
+
+    #[test]
+    fn test_custom_event_is_allowed_and_noted() {
+        let config = parse_udon_behaviour_args_simple(r#"events = "Start,FixedUpdate,ScoreChanged""#).unwrap();
+        assert_eq!(config.events, vec!["Start", "FixedUpdate", "ScoreChanged"]);
+        assert_eq!(config.custom_events, vec!["ScoreChanged"]);
+
+        assert!(parse_udon_behaviour_args_simple(r#"events = "Start,2Fast""#).is_err());
+    }
+}
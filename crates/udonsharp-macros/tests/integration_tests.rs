@@ -1,4 +1,4 @@
-use udonsharp_macros::udon_behaviour;
+use udonsharp_macros::{udon_behaviour, udon_enum_dispatch, udon_network_event, udon_sync_blob, UdonBehaviour};
 
 #[udon_behaviour]
 pub fn simple_behaviour() {
@@ -20,6 +20,11 @@ pub fn network_manager() {
     // Network manager with dependencies and auto sync
 }
 
+#[udon_behaviour(name = "Scoreboard", events = ["Start", "OnPlayerJoined", "ScoreChanged"])]
+pub fn scoreboard() {
+    // Built-in events plus a custom event
+}
+
 #[test]
 fn test_udon_behaviour_compilation() {
     // If this compiles, the macro is working
@@ -27,4 +32,139 @@ fn test_udon_behaviour_compilation() {
     custom_named_behaviour();
     game_manager();
     network_manager();
-}
\ No newline at end of file
+    scoreboard();
+}
+
+#[udon_enum_dispatch(Patrol, Chase)]
+pub trait EnemyState {
+    fn speed(&self) -> f32;
+    fn tick(&mut self, delta: f32) -> u32;
+    fn describe(&self) -> &'static str {
+        "enemy"
+    }
+}
+
+#[derive(Default)]
+pub struct Patrol {
+    ticks: u32,
+}
+
+impl EnemyState for Patrol {
+    fn speed(&self) -> f32 {
+        1.5
+    }
+
+    fn tick(&mut self, _delta: f32) -> u32 {
+        self.ticks += 1;
+        self.ticks
+    }
+}
+
+#[derive(Default)]
+pub struct Chase {
+    distance: f32,
+}
+
+impl EnemyState for Chase {
+    fn speed(&self) -> f32 {
+        4.0
+    }
+
+    fn tick(&mut self, delta: f32) -> u32 {
+        self.distance += self.speed() * delta;
+        self.distance as u32
+    }
+
+    fn describe(&self) -> &'static str {
+        "chasing"
+    }
+}
+
+#[test]
+fn test_enum_dispatch_routes_to_implementor() {
+    let mut states: Vec<EnemyStateDispatch> = vec![Patrol::default().into(), Chase::default().into()];
+
+    assert_eq!(states[0].speed(), 1.5);
+    assert_eq!(states[1].speed(), 4.0);
+
+    assert_eq!(states[0].tick(0.5), 1);
+    assert_eq!(states[0].tick(0.5), 2);
+    assert_eq!(states[1].tick(2.0), 8);
+
+    assert_eq!(states[0].describe(), "enemy");
+    assert_eq!(states[1].describe(), "chasing");
+    assert!(matches!(states.remove(1), EnemyStateDispatch::Chase(_)));
+}
+
+pub struct Cannon {
+    shots: u32,
+}
+
+impl Cannon {
+    #[udon_network_event(target = "Owner")]
+    pub fn fire_cannon(&mut self) {
+        self.shots += 1;
+    }
+
+    #[udon_network_event(name = "ReloadCannon")]
+    pub fn reload(&mut self) {
+        self.shots = 0;
+    }
+}
+
+#[test]
+fn test_udon_network_event_metadata() {
+    let mut cannon = Cannon { shots: 0 };
+    cannon.fire_cannon();
+    assert_eq!(cannon.shots, 1);
+    cannon.reload();
+    assert_eq!(cannon.shots, 0);
+
+    assert_eq!(Cannon::FIRE_CANNON_NETWORK_EVENT_NAME, "FireCannon");
+    assert_eq!(Cannon::FIRE_CANNON_NETWORK_EVENT_TARGET, "Owner");
+    assert_eq!(Cannon::RELOAD_NETWORK_EVENT_NAME, "ReloadCannon");
+    assert_eq!(Cannon::RELOAD_NETWORK_EVENT_TARGET, "All");
+}
+
+#[derive(UdonBehaviour)]
+pub struct ShorthandSettings {
+    #[header("Round Settings")]
+    #[tooltip("How long each round lasts in seconds")]
+    pub round_length: f32,
+}
+
+#[derive(UdonBehaviour)]
+pub struct PrefixedSettings {
+    #[udon_header("Round Settings")]
+    #[udon_tooltip("How long each round lasts in seconds")]
+    pub round_length: f32,
+}
+
+#[test]
+fn test_header_and_tooltip_spellings_produce_same_field_info() {
+    let shorthand = &ShorthandSettings::get_udon_field_info()[0];
+    let prefixed = &PrefixedSettings::get_udon_field_info()[0];
+
+    assert_eq!(shorthand.header_text.as_deref(), Some("Round Settings"));
+    assert_eq!(shorthand.tooltip_text.as_deref(), Some("How long each round lasts in seconds"));
+    assert_eq!(shorthand.header_text, prefixed.header_text);
+    assert_eq!(shorthand.tooltip_text, prefixed.tooltip_text);
+}
+
+#[udon_sync_blob]
+#[derive(Debug, PartialEq)]
+pub struct PlayerState {
+    health: i32,
+    alive: bool,
+}
+
+#[test]
+fn test_sync_blob_packs_and_unpacks_identically() {
+    let state = PlayerState { health: -75, alive: true };
+    let bytes = state.to_udon_bytes();
+
+    assert_eq!(PlayerState::UDON_BLOB_SIZE, 5);
+    assert_eq!(bytes, vec![0xB5, 0xFF, 0xFF, 0xFF, 1]);
+    assert_eq!(PlayerState::from_udon_bytes(&bytes), Some(state));
+    assert_eq!(PlayerState::from_udon_bytes(&bytes[..4]), None);
+}
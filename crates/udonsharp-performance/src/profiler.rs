@@ -219,6 +219,27 @@ impl CompilationProfiler {
         }
     }
 
+    /// Record the phases of a compilation pipeline run as completed operations
+    pub fn record_phase_log(&mut self, phase_log: &udonsharp_compiler::PhaseLog) {
+        for record in phase_log.records() {
+            let error_message = match &record.status {
+                udonsharp_compiler::PhaseStatus::Completed => None,
+                udonsharp_compiler::PhaseStatus::Failed(error) => Some(error.clone()),
+            };
+
+            self.completed_operations.push(CompletedOperation {
+                name: record.phase.name().to_string(),
+                duration: record.duration,
+                memory_used: 0,
+                memory_peak: 0,
+                success: record.succeeded(),
+                error_message,
+                sub_operations: Vec::new(),
+                metadata: HashMap::new(),
+            });
+        }
+    }
+
     /// Get profiling results for a specific operation
     pub fn get_operation_results(&self, name: &str) -> Option<&CompletedOperation> {
         self.completed_operations.iter().find(|op| op.name == name)
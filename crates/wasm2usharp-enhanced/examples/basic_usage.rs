@@ -1,7 +1,7 @@
 use wasm2usharp_enhanced::{
-    EnhancedWasm2USharpPipeline, ConversionConfig, 
+    EnhancedWasm2USharpPipeline, ConversionConfig, ConversionTarget,
     FileSplitter, SplittingStrategy,
-    CSharpFileGenerator, FileGenerationConfig
+    CSharpFileGenerator, FileGenerationConfig, OutputLayout
 };
 use std::collections::HashMap;
 
@@ -13,6 +13,8 @@ fn main() -> anyhow::Result<()> {
         generate_separate_files: true,
         udonsharp_attributes: true,
         inheritance_support: true,
+        output_layout: OutputLayout::PerBehavior,
+        target: ConversionTarget::UdonSharp,
     };
     
     // Create the enhanced pipeline
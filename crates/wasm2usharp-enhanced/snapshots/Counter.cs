@@ -0,0 +1,16 @@
+using UnityEngine;
+using VRC.SDKBase;
+using VRC.Udon;
+using UdonSharp;
+
+[UdonBehaviourSyncMode(BehaviourSyncMode.Manual)]
+public class Counter : UdonSharpBehaviour
+{
+    private int count;
+    [UdonSharpMethod]
+    public void Increment()
+    {
+        // Method implementation
+    }
+
+}
@@ -0,0 +1,13 @@
+using UnityEngine;
+using VRC.SDKBase;
+using VRC.Udon;
+using UdonSharp;
+
+[UdonBehaviourSyncMode(BehaviourSyncMode.Manual)]
+public class HelloWorld : UdonSharpBehaviour
+{
+    public void Start()
+    {
+        // Generated from WASM
+    }
+}
@@ -10,6 +10,9 @@ use wasmparser::{
 };
 use udonsharp_core::attributes::{UdonBehaviourMarker, AttributeParser};
 
+/// Prefix of the export symbols `#[udon_behaviour]` emits for its metadata
+pub const BEHAVIOUR_SYMBOL_PREFIX: &str = "__udon_behaviour_";
+
 /// Analyzer for detecting OOP patterns in WASM
 pub struct OopBehaviorAnalyzer {
     /// Function signatures indexed by function index
@@ -24,8 +27,15 @@ pub struct OopBehaviorAnalyzer {
     patterns: PatternDatabase,
     /// Mapping from Rust function names to WASM exports
     function_name_mapping: HashMap<String, u32>,
-    /// UdonBehaviour attributes extracted from custom sections
+    /// UdonBehaviour attributes extracted from custom sections, keyed by the
+    /// full module path of the behavior function
     udon_behaviour_attributes: HashMap<String, UdonBehaviourMarker>,
+    /// Module path and source location of the behavior generating each class name
+    behaviour_sources: HashMap<String, (String, String)>,
+    /// Constant `i32` value of each global, `None` for imported or computed globals
+    global_values: Vec<Option<u32>>,
+    /// Active data segments of memory 0 as `(offset, bytes)`
+    data_segments: Vec<(u32, Vec<u8>)>,
 }
 
 impl OopBehaviorAnalyzer {
@@ -39,6 +49,9 @@ impl OopBehaviorAnalyzer {
             patterns: PatternDatabase::new(),
             function_name_mapping: HashMap::new(),
             udon_behaviour_attributes: HashMap::new(),
+            behaviour_sources: HashMap::new(),
+            global_values: Vec::new(),
+            data_segments: Vec::new(),
         }
     }
     
@@ -74,10 +87,17 @@ impl OopBehaviorAnalyzer {
                 Payload::CustomSection(reader) => {
                     self.parse_custom_section(reader)?;
                 }
+                Payload::GlobalSection(reader) => {
+                    self.parse_global_section(reader)?;
+                }
+                Payload::DataSection(reader) => {
+                    self.parse_data_section(reader)?;
+                }
                 _ => {} // Ignore other sections for now
             }
         }
         
+        self.read_behaviour_symbols()?;
         Ok(())
     }
     
@@ -94,8 +114,14 @@ impl OopBehaviorAnalyzer {
     }
     
     /// Parse import section
-    fn parse_import_section(&mut self, _reader: wasmparser::ImportSectionReader) -> Result<()> {
-        // Simplified implementation - in a real version this would parse imports
+    fn parse_import_section(&mut self, reader: wasmparser::ImportSectionReader) -> Result<()> {
+        // Simplified implementation - in a real version this would parse imports.
+        // Imported globals still take up global indices, so reserve a slot for each.
+        for import in reader {
+            if let TypeRef::Global(_) = import?.ty {
+                self.global_values.push(None);
+            }
+        }
         Ok(())
     }
     
@@ -131,6 +157,99 @@ impl OopBehaviorAnalyzer {
         Ok(())
     }
     
+    /// Parse global section, recording globals initialized to an `i32` constant
+    fn parse_global_section(&mut self, reader: wasmparser::GlobalSectionReader) -> Result<()> {
+        for global in reader {
+            let global = global?;
+            let value = match global.init_expr.get_operators_reader().read()? {
+                Operator::I32Const { value } => Some(value as u32),
+                _ => None,
+            };
+            self.global_values.push(value);
+        }
+        Ok(())
+    }
+    
+    /// Parse data section, keeping the active segments placed at a constant offset
+    fn parse_data_section(&mut self, reader: wasmparser::DataSectionReader) -> Result<()> {
+        for data in reader {
+            let data = data?;
+            if let wasmparser::DataKind::Active { memory_index: 0, offset_expr } = data.kind {
+                if let Operator::I32Const { value } = offset_expr.get_operators_reader().read()? {
+                    self.data_segments.push((value as u32, data.data.to_vec()));
+                }
+            }
+        }
+        Ok(())
+    }
+    
+    /// Read `#[udon_behaviour]` metadata from its export symbols
+    ///
+    /// The macro exports a `&str` static named `__udon_behaviour_{module path}::{function}`.
+    /// Export names are flat, so the metadata is found the same way whether the
+    /// behavior lives in a submodule or is re-exported with `pub use`.
+    fn read_behaviour_symbols(&mut self) -> Result<()> {
+        let symbols: Vec<(String, u32)> = self.exports.iter()
+            .filter(|export| export.kind == ExportKind::Global && export.name.starts_with(BEHAVIOUR_SYMBOL_PREFIX))
+            .map(|export| (export.name.clone(), export.index))
+            .collect();
+        
+        for (symbol, global_index) in symbols {
+            let Some(metadata) = self.read_behaviour_metadata(global_index) else {
+                log::warn!("Could not read UdonBehaviour metadata of export '{}'", symbol);
+                continue;
+            };
+            let marker = AttributeParser::parse_udon_behaviour_from_metadata(&metadata)
+                .map_err(|e| anyhow::anyhow!("Invalid UdonBehaviour metadata in export '{}': {}", symbol, e))?;
+            
+            let path = &symbol[BEHAVIOUR_SYMBOL_PREFIX.len()..];
+            self.register_behaviour(path.to_string(), marker, path.to_string())?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Record the `#[udon_behaviour]` metadata of the function at module `path`,
+    /// found at `location`
+    ///
+    /// Two behaviors generating the same class name would overwrite each other's
+    /// C# file, so that is reported as an error naming both source locations.
+    /// A symbol and an attributes-section entry with the same module path
+    /// describe one behavior.
+    fn register_behaviour(&mut self, path: String, marker: UdonBehaviourMarker, location: String) -> Result<()> {
+        let class_name = marker.name.clone()
+            .unwrap_or_else(|| self.infer_behavior_name_from_function(behaviour_function_name(&path)));
+        
+        if let Some((previous_path, previous_location)) = self.behaviour_sources.get(&class_name) {
+            if *previous_path != path {
+                anyhow::bail!(
+                    "Behaviors '{}' and '{}' both generate the UdonBehaviour class '{}'; give one of them a distinct #[udon_behaviour(name = \"...\")]",
+                    previous_location, location, class_name
+                );
+            }
+        }
+        self.behaviour_sources.insert(class_name, (path.clone(), location));
+        self.udon_behaviour_attributes.insert(path, marker);
+        Ok(())
+    }
+    
+    /// Read the `&str` a metadata global points to: a pointer and length stored in memory
+    fn read_behaviour_metadata(&self, global_index: u32) -> Option<String> {
+        let address = (*self.global_values.get(global_index as usize)?)?;
+        let fat_pointer = self.read_memory(address, 8)?;
+        let pointer = u32::from_le_bytes(fat_pointer[..4].try_into().ok()?);
+        let length = u32::from_le_bytes(fat_pointer[4..].try_into().ok()?);
+        String::from_utf8(self.read_memory(pointer, length)?).ok()
+    }
+    
+    /// Read initialized memory from the data segments
+    fn read_memory(&self, address: u32, length: u32) -> Option<Vec<u8>> {
+        self.data_segments.iter().find_map(|(offset, bytes)| {
+            let start = address.checked_sub(*offset)? as usize;
+            bytes.get(start..start.checked_add(length as usize)?).map(|slice| slice.to_vec())
+        })
+    }
+    
     /// Parse function body
     fn parse_function_body(&mut self, body: wasmparser::FunctionBody) -> Result<()> {
         let func_index = self.function_bodies.len() as u32;
@@ -168,21 +287,22 @@ impl OopBehaviorAnalyzer {
             .map_err(|e| anyhow::anyhow!("Invalid UTF-8 in attributes section: {}", e))?;
         
         // Parse JSON-like format: {"function_name": "attribute_metadata", ...}
-        for line in attributes_str.lines() {
+        for (line_index, line) in attributes_str.lines().enumerate() {
             let line = line.trim();
             if line.is_empty() || line.starts_with('#') {
                 continue; // Skip empty lines and comments
             }
             
-            // Simple parsing: "function_name:attribute_metadata"
-            if let Some((func_name, metadata)) = line.split_once(':') {
+            // Simple parsing: "function_path:attribute_metadata"
+            if let Some((func_name, metadata)) = split_attribute_entry(line) {
                 let func_name = func_name.trim().to_string();
                 let metadata = metadata.trim();
                 
                 // Parse the UdonBehaviour attribute metadata
                 match AttributeParser::parse_udon_behaviour_from_metadata(metadata) {
                     Ok(marker) => {
-                        self.udon_behaviour_attributes.insert(func_name, marker);
+                        let location = format!("{} (udonsharp.attributes line {})", func_name, line_index + 1);
+                        self.register_behaviour(func_name, marker, location)?;
                     }
                     Err(e) => {
                         log::warn!("Failed to parse UdonBehaviour attribute for function '{}': {}", func_name, e);
@@ -487,9 +607,10 @@ impl OopBehaviorAnalyzer {
     pub fn get_udon_behaviour_functions(&self) -> HashMap<String, (u32, UdonBehaviourMarker)> {
         let mut result = HashMap::new();
         
-        for (func_name, marker) in &self.udon_behaviour_attributes {
+        for (path, marker) in &self.udon_behaviour_attributes {
+            let func_name = behaviour_function_name(path);
             if let Some(&func_index) = self.function_name_mapping.get(func_name) {
-                result.insert(func_name.clone(), (func_index, marker.clone()));
+                result.insert(func_name.to_string(), (func_index, marker.clone()));
             }
         }
         
@@ -511,14 +632,20 @@ impl OopBehaviorAnalyzer {
         self.function_name_mapping.clone()
     }
     
-    /// Check if a function has UdonBehaviour attribute
+    /// Check if a function, given by its exported name or full module path,
+    /// has UdonBehaviour attribute
     pub fn has_udon_behaviour_attribute(&self, func_name: &str) -> bool {
-        self.udon_behaviour_attributes.contains_key(func_name)
+        self.get_udon_behaviour_attribute(func_name).is_some()
     }
     
-    /// Get UdonBehaviour attribute for a function
+    /// Get UdonBehaviour attribute for a function, given by its exported name
+    /// or full module path
     pub fn get_udon_behaviour_attribute(&self, func_name: &str) -> Option<&UdonBehaviourMarker> {
-        self.udon_behaviour_attributes.get(func_name)
+        self.udon_behaviour_attributes.get(func_name).or_else(|| {
+            self.udon_behaviour_attributes.iter()
+                .find(|(path, _)| behaviour_function_name(path) == func_name)
+                .map(|(_, marker)| marker)
+        })
     }
     
     /// Identify and create behavior units from UdonBehaviour functions
@@ -629,7 +756,7 @@ impl OopBehaviorAnalyzer {
                 let func_name = &export.name;
                 
                 // Skip if this function has its own UdonBehaviour attribute
-                if self.udon_behaviour_attributes.contains_key(func_name) && func_name != entry_func {
+                if self.has_udon_behaviour_attribute(func_name) && func_name != entry_func {
                     continue;
                 }
                 
@@ -1109,6 +1236,26 @@ impl OopBehaviorAnalyzer {
     }
 }
 
+/// Name a behavior function is exported under: the last segment of its module path
+fn behaviour_function_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Split an attributes-section line into the function path and its metadata
+/// at the first `:` that is not part of a `::` path separator
+fn split_attribute_entry(line: &str) -> Option<(&str, &str)> {
+    let mut start = 0;
+    while let Some(offset) = line[start..].find(':') {
+        let colon = start + offset;
+        if line[colon + 1..].starts_with(':') {
+            start = colon + 2;
+            continue;
+        }
+        return Some((&line[..colon], &line[colon + 1..]));
+    }
+    None
+}
+
 /// Database of detected OOP patterns
 #[derive(Debug, Default)]
 struct PatternDatabase {
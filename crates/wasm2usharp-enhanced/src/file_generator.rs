@@ -33,6 +33,8 @@ pub struct MultiBehaviorConfig {
     pub include_debug_info: bool,
     /// Custom templates for behavior classes
     pub custom_templates: HashMap<String, String>,
+    /// How the generated classes are laid out in files
+    pub output_layout: OutputLayout,
 }
 
 impl Default for MultiBehaviorConfig {
@@ -43,10 +45,106 @@ impl Default for MultiBehaviorConfig {
             naming_convention: BehaviorNamingConvention::PascalCase,
             include_debug_info: true,
             custom_templates: HashMap::new(),
+            output_layout: OutputLayout::PerBehavior,
         }
     }
 }
 
+/// Layout of the generated C# files
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OutputLayout {
+    /// One `.cs` file per behavior, plus `SharedRuntime.cs`
+    #[default]
+    PerBehavior,
+    /// A single file with the given name containing the code of every behavior and
+    /// the SharedRuntime.
+    ///
+    /// Unity maps a script file only to the class named after it, so the bundle
+    /// declares its classes `partial` and each behavior keeps a stub file declaring
+    /// its class, from which UdonSharp creates the behavior's own program asset.
+    Bundled(String),
+}
+
+/// Bundle several C# sources into one file.
+///
+/// `using` directives are hoisted to the top and deduplicated; the remaining
+/// content of each source is appended in order, with its classes declared
+/// `partial` so stub files can declare them too.
+pub fn bundle_csharp_sources(file_name: &str, sources: &[&str]) -> String {
+    let mut usings = Vec::new();
+    let mut bodies = Vec::new();
+
+    for source in sources {
+        let mut body = Vec::new();
+        let mut in_header = true;
+        for line in source.lines() {
+            let trimmed = line.trim();
+            if is_using_directive(trimmed) {
+                if !usings.iter().any(|u: &String| u == trimmed) {
+                    usings.push(trimmed.to_string());
+                }
+                continue;
+            }
+            // Drop each source's own header comment and the blank lines around its usings
+            if in_header && (trimmed.is_empty() || trimmed.starts_with("//")) {
+                continue;
+            }
+            in_header = false;
+            body.push(partial_class_declaration(line).unwrap_or_else(|| line.to_string()));
+        }
+        while body.last().is_some_and(|line| line.trim().is_empty()) {
+            body.pop();
+        }
+        bodies.push(body.join("\n"));
+    }
+
+    usings.sort();
+    let mut content = format!("//\n// Generated C# bundle: {}\n// This file was automatically generated by the Rust to UdonSharp compiler.\n//\n\n", file_name);
+    for using in &usings {
+        content.push_str(using);
+        content.push('\n');
+    }
+    if !usings.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&bodies.join("\n\n"));
+    content.push('\n');
+    content
+}
+
+/// Stub script of a behavior whose code is in the bundle `bundle_name`, giving
+/// Unity a file named after the class to create the program asset from
+pub fn program_stub_source(bundle_name: &str, class_name: &str, namespace: Option<&str>) -> String {
+    let mut content = format!(
+        "//\n// Program stub: {}.cs\n// The code of {} is generated into {}.\n//\n\nusing UdonSharp;\n\n",
+        class_name, class_name, bundle_name
+    );
+    let indent = if namespace.is_some() { "    " } else { "" };
+    if let Some(namespace) = namespace {
+        content.push_str(&format!("namespace {}\n{{\n", namespace));
+    }
+    content.push_str(&format!("{0}public partial class {1} : UdonSharpBehaviour\n{0}{{\n{0}}}\n", indent, class_name));
+    if namespace.is_some() {
+        content.push_str("}\n");
+    }
+    content
+}
+
+/// Declare a class declaration line `partial`; `None` for any other line
+fn partial_class_declaration(line: &str) -> Option<String> {
+    let (modifiers, rest) = line.split_once("class ")?;
+    let is_declaration = modifiers.split_whitespace()
+        .all(|word| matches!(word, "public" | "internal" | "static" | "sealed" | "abstract"));
+    is_declaration.then(|| format!("{}partial class {}", modifiers, rest))
+}
+
+/// Check whether a trimmed line is a `using Namespace;` directive
+fn is_using_directive(line: &str) -> bool {
+    line.strip_prefix("using ")
+        .and_then(|rest| rest.strip_suffix(';'))
+        .is_some_and(|name| !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '.' || c == '_' || c == ' ' || c == '='))
+}
+
 /// Naming convention for behavior classes
 #[derive(Debug, Clone)]
 pub enum BehaviorNamingConvention {
@@ -1838,6 +1936,56 @@ impl MultiBehaviorFileGenerator {
         shared_runtime_generator.generate_shared_runtime(behavior_units, &additional_functions)
     }
     
+    /// Combine generated files into a single bundled file
+    fn bundle_files(&self, file_name: &str, files: &[&GeneratedCSharpFile]) -> GeneratedCSharpFile {
+        let sources: Vec<&str> = files.iter().map(|file| file.content.as_str()).collect();
+        let content = bundle_csharp_sources(file_name, &sources);
+
+        let mut using_statements = Vec::new();
+        let mut dependencies = HashSet::new();
+        for file in files {
+            for using in &file.using_statements {
+                if !using_statements.contains(using) {
+                    using_statements.push(using.clone());
+                }
+            }
+            dependencies.extend(file.dependencies.iter().cloned());
+        }
+
+        GeneratedCSharpFile {
+            name: file_name.to_string(),
+            using_statements,
+            namespace: self.behavior_config.namespace.clone(),
+            dependencies,
+            metadata: FileMetadata {
+                generated_at: chrono::Utc::now(),
+                generator_version: env!("CARGO_PKG_VERSION").to_string(),
+                line_count: content.lines().count(),
+                character_count: content.len(),
+            },
+            content,
+        }
+    }
+    
+    /// Generate the stub file of a behavior whose code is in the bundle `bundle_name`
+    fn program_stub_file(&self, bundle_name: &str, class_name: &str) -> GeneratedCSharpFile {
+        let content = program_stub_source(bundle_name, class_name, self.behavior_config.namespace.as_deref());
+
+        GeneratedCSharpFile {
+            name: format!("{}.cs", class_name),
+            using_statements: vec!["UdonSharp".to_string()],
+            namespace: self.behavior_config.namespace.clone(),
+            dependencies: HashSet::from([bundle_name.to_string()]),
+            metadata: FileMetadata {
+                generated_at: chrono::Utc::now(),
+                generator_version: env!("CARGO_PKG_VERSION").to_string(),
+                line_count: content.lines().count(),
+                character_count: content.len(),
+            },
+            content,
+        }
+    }
+    
     /// Generate all files for a multi-behavior project
     pub fn generate_all_files(&self, behavior_units: &[BehaviorUnit], shared_functions: &[String]) -> Result<MultiBehaviorGenerationResult> {
         let mut behavior_files = HashMap::new();
@@ -1869,9 +2017,30 @@ impl MultiBehaviorFileGenerator {
         
         generation_metadata.total_files = behavior_files.len() + if shared_runtime_file.is_some() { 1 } else { 0 };
         
+        // Bundle everything into a single file if requested
+        let bundled_file = match &self.behavior_config.output_layout {
+            OutputLayout::PerBehavior => None,
+            OutputLayout::Bundled(file_name) => {
+                let mut sources: Vec<&GeneratedCSharpFile> = behavior_units.iter()
+                    .filter_map(|unit| behavior_files.get(&unit.name))
+                    .collect();
+                sources.extend(shared_runtime_file.as_ref());
+                let bundle = self.bundle_files(file_name, &sources);
+                
+                // Each behavior keeps a stub file so it still gets its own program asset
+                for (behavior_name, file) in behavior_files.iter_mut() {
+                    *file = self.program_stub_file(file_name, &self.generate_behavior_class_name(behavior_name));
+                }
+                shared_runtime_file = shared_runtime_file.map(|file| self.program_stub_file(file_name, file.name.trim_end_matches(".cs")));
+                generation_metadata.total_files += 1;
+                Some(bundle)
+            }
+        };
+        
         Ok(MultiBehaviorGenerationResult {
             behavior_files,
             shared_runtime_file,
+            bundled_file,
             metadata: generation_metadata,
         })
     }
@@ -1880,10 +2049,13 @@ impl MultiBehaviorFileGenerator {
 /// Result of multi-behavior file generation
 #[derive(Debug)]
 pub struct MultiBehaviorGenerationResult {
-    /// Generated behavior class files (behavior_name -> generated_file)
+    /// Generated behavior class files (behavior_name -> generated_file); the
+    /// program stubs of the behaviors with the bundled layout
     pub behavior_files: HashMap<String, GeneratedCSharpFile>,
-    /// Generated SharedRuntime file (if enabled)
+    /// Generated SharedRuntime file (if enabled); its program stub with the bundled layout
     pub shared_runtime_file: Option<GeneratedCSharpFile>,
+    /// Single file containing all behaviors and the SharedRuntime (bundled layout only)
+    pub bundled_file: Option<GeneratedCSharpFile>,
     /// Generation metadata
     pub metadata: MultiBehaviorGenerationMetadata,
 }
@@ -3306,4 +3478,85 @@ pub enum MappingErrorType {
     UnsupportedEvent,
     MissingFunction,
     InvalidSignature,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use udonsharp_core::attributes::UdonBehaviourMarker;
+
+    fn behavior_unit(name: &str) -> BehaviorUnit {
+        BehaviorUnit {
+            name: name.to_string(),
+            entry_function: format!("{}_start", name),
+            entry_function_index: 0,
+            unity_events: vec!["Start".to_string()],
+            local_functions: [format!("{}_start", name)].into_iter().collect(),
+            shared_dependencies: HashSet::new(),
+            inter_behavior_calls: Vec::new(),
+            attribute_config: UdonBehaviourMarker {
+                name: Some(name.to_string()),
+                events: vec!["Start".to_string()],
+                dependencies: Vec::new(),
+                auto_sync: false,
+            },
+        }
+    }
+
+    fn declared_classes(content: &str) -> HashSet<String> {
+        content.lines()
+            .filter_map(|line| {
+                let words: Vec<&str> = line.split_whitespace().collect();
+                let class = words.iter().position(|word| *word == "class")?;
+                if words.first() != Some(&"public") {
+                    return None;
+                }
+                words.get(class + 1).map(|name| name.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bundled_layout_matches_per_behavior_layout() {
+        let units = vec![behavior_unit("game_manager"), behavior_unit("ui_controller")];
+        let shared_functions = vec!["clamp_score".to_string()];
+
+        let per_behavior = MultiBehaviorFileGenerator::with_config(MultiBehaviorConfig::default())
+            .generate_all_files(&units, &shared_functions)
+            .unwrap();
+        assert!(per_behavior.bundled_file.is_none());
+
+        let mut per_behavior_classes = HashSet::new();
+        for file in per_behavior.behavior_files.values().chain(per_behavior.shared_runtime_file.iter()) {
+            per_behavior_classes.extend(declared_classes(&file.content));
+        }
+        assert!(per_behavior_classes.contains("GameManager"));
+        assert!(per_behavior_classes.contains("SharedRuntime"));
+
+        let bundled_config = MultiBehaviorConfig {
+            output_layout: OutputLayout::Bundled("World.cs".to_string()),
+            ..MultiBehaviorConfig::default()
+        };
+        let bundled = MultiBehaviorFileGenerator::with_config(bundled_config)
+            .generate_all_files(&units, &shared_functions)
+            .unwrap();
+        let bundle = bundled.bundled_file.expect("bundled layout produces a bundle");
+
+        assert_eq!(bundle.name, "World.cs");
+        assert_eq!(declared_classes(&bundle.content), per_behavior_classes);
+        assert!(bundle.content.contains("public partial class GameManager : UdonSharpBehaviour"));
+        assert_eq!(bundle.content.matches("using UdonSharp;").count(), 1);
+
+        // Every behavior keeps a stub named after its class for its program asset
+        let mut behaviors: Vec<&String> = bundled.behavior_files.keys().collect();
+        behaviors.sort();
+        assert_eq!(behaviors, ["game_manager", "ui_controller"]);
+        let stub = &bundled.behavior_files["ui_controller"];
+        assert_eq!(stub.name, "UiController.cs");
+        assert!(stub.content.contains("public partial class UiController : UdonSharpBehaviour"));
+        assert_eq!(declared_classes(&stub.content), HashSet::from(["UiController".to_string()]));
+        let shared_runtime_stub = bundled.shared_runtime_file.expect("SharedRuntime keeps a stub");
+        assert_eq!(shared_runtime_stub.name, "SharedRuntime.cs");
+        assert!(shared_runtime_stub.content.contains("public partial class SharedRuntime : UdonSharpBehaviour"));
+        assert_eq!(bundled.metadata.total_files, 4);
+    }
+}
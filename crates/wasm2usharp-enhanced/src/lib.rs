@@ -8,15 +8,19 @@ pub mod transformer;
 pub mod splitter;
 pub mod file_generator;
 pub mod dependency_analyzer;
+pub mod udon_assembly;
+pub mod snapshot;
 
 #[cfg(test)]
 mod tests;
 
 pub use analyzer::*;
-pub use transformer::{EnhancedWasm2USharp, ConversionConfig, ConversionResult};
+pub use transformer::{EnhancedWasm2USharp, ConversionConfig, ConversionResult, ConversionTarget};
 pub use splitter::*;
 pub use file_generator::*;
 pub use dependency_analyzer::*;
+pub use udon_assembly::*;
+pub use snapshot::{compare_snapshot, normalize_csharp, SnapshotMismatch};
 
 use anyhow::Result;
 
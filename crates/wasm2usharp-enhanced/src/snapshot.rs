@@ -0,0 +1,96 @@
+//! Golden snapshot comparison of generated C#
+//!
+//! Codegen regressions are easiest to catch by comparing the C# of each
+//! behavior against a stored copy that a maintainer has reviewed. Snapshots
+//! are compared after normalizing whitespace, so indentation and blank-line
+//! churn does not fail a comparison, and a mismatch reports the first line
+//! that differs rather than the whole file.
+
+use std::fmt;
+
+/// Difference between generated C# and its golden snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotMismatch {
+    /// The conversion produced no source for the behavior
+    MissingBehavior { behavior: String },
+    /// The sources differ at a normalized line (1-based); `None` means the side ended
+    LineDiffers {
+        behavior: String,
+        line: usize,
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotMismatch::MissingBehavior { behavior } => {
+                write!(f, "No generated source for behavior '{}'", behavior)
+            }
+            SnapshotMismatch::LineDiffers { behavior, line, expected, actual } => {
+                let show = |side: &Option<String>| match side {
+                    Some(text) => format!("`{}`", text),
+                    None => "<end of file>".to_string(),
+                };
+                write!(
+                    f,
+                    "Snapshot of '{}' differs at line {}: expected {}, got {}",
+                    behavior, line, show(expected), show(actual)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotMismatch {}
+
+/// Normalize C# for comparison: trim lines, collapse inner whitespace and drop blank lines
+pub fn normalize_csharp(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Compare generated C# against a golden snapshot, reporting the first differing line
+pub fn compare_snapshot(behavior: &str, expected: &str, actual: &str) -> Result<(), SnapshotMismatch> {
+    let expected = normalize_csharp(expected);
+    let actual = normalize_csharp(actual);
+
+    for index in 0..expected.len().max(actual.len()) {
+        let (expected_line, actual_line) = (expected.get(index), actual.get(index));
+        if expected_line != actual_line {
+            return Err(SnapshotMismatch::LineDiffers {
+                behavior: behavior.to_string(),
+                line: index + 1,
+                expected: expected_line.cloned(),
+                actual: actual_line.cloned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_snapshot_ignores_whitespace_and_reports_first_difference() {
+        let expected = "public class A\n{\n    int x;\n\n    int y;\n}\n";
+        assert!(compare_snapshot("A", expected, "public  class A\n{\n\tint x;\n    int y;   \n}").is_ok());
+
+        let mismatch = compare_snapshot("A", expected, "public class A\n{\n    int x;\n    int z;\n}\n").unwrap_err();
+        assert_eq!(mismatch, SnapshotMismatch::LineDiffers {
+            behavior: "A".to_string(),
+            line: 4,
+            expected: Some("int y;".to_string()),
+            actual: Some("int z;".to_string()),
+        });
+
+        let truncated = compare_snapshot("A", expected, "public class A\n{\n").unwrap_err();
+        assert!(truncated.to_string().contains("line 3: expected `int x;`, got <end of file>"));
+    }
+}
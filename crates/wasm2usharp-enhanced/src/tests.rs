@@ -1,6 +1,10 @@
 //! Tests for WASM analysis and behavior identification
 
-use crate::analyzer::OopBehaviorAnalyzer;
+use crate::analyzer::{ClassInfo, FieldInfo, MethodInfo, OopAnalysisResult, OopBehaviorAnalyzer};
+use crate::snapshot::SnapshotMismatch;
+use crate::file_generator::OutputLayout;
+use crate::transformer::{ConversionConfig, EnhancedWasm2USharp};
+use crate::EnhancedWasm2USharpPipeline;
 
 #[cfg(test)]
 mod tests {
@@ -93,4 +97,201 @@ mod tests {
         assert!(!analyzer.has_udon_behaviour_attribute("non_existent_function"));
         assert!(analyzer.get_udon_behaviour_attribute("non_existent_function").is_none());
     }
-}
\ No newline at end of file
+
+    /// Smallest valid WASM module: magic number and version
+    const EMPTY_WASM: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+    const HELLO_WORLD_SNAPSHOT: &str = include_str!("../snapshots/HelloWorld.cs");
+    const COUNTER_SNAPSHOT: &str = include_str!("../snapshots/Counter.cs");
+
+    fn counter_analysis() -> OopAnalysisResult {
+        OopAnalysisResult {
+            classes: vec![ClassInfo {
+                name: "Counter".to_string(),
+                methods: vec!["Increment".to_string()],
+                fields: vec![FieldInfo {
+                    name: "count".to_string(),
+                    field_type: "int".to_string(),
+                    offset: 0,
+                    is_public: false,
+                }],
+                constructor_function: None,
+            }],
+            methods: vec![MethodInfo {
+                name: "Increment".to_string(),
+                class: Some("Counter".to_string()),
+                function_index: 0,
+                parameters: Vec::new(),
+                return_type: None,
+                is_static: false,
+                is_virtual: false,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hello_world_matches_snapshot() {
+        let config = ConversionConfig { class_name: "HelloWorld".to_string(), ..Default::default() };
+        let result = EnhancedWasm2USharpPipeline::with_config(config).convert(&EMPTY_WASM).unwrap();
+
+        if let Err(mismatch) = result.compare_snapshot("HelloWorld", HELLO_WORLD_SNAPSHOT) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    #[test]
+    fn test_counter_matches_snapshot() {
+        let result = EnhancedWasm2USharp::new().convert_with_oop(&EMPTY_WASM, &counter_analysis()).unwrap();
+
+        if let Err(mismatch) = result.compare_snapshot("Counter", COUNTER_SNAPSHOT) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    #[test]
+    fn test_bundled_layout_keeps_a_stub_per_class() {
+        let config = ConversionConfig {
+            output_layout: OutputLayout::Bundled("World.cs".to_string()),
+            ..Default::default()
+        };
+        let result = EnhancedWasm2USharp::with_config(config).convert_with_oop(&EMPTY_WASM, &counter_analysis()).unwrap();
+
+        assert!(result.main_class.contains("public partial class Counter : UdonSharpBehaviour"));
+        let mut stubs: Vec<&String> = result.additional_files.keys().collect();
+        stubs.sort();
+        assert_eq!(stubs, ["Counter.cs", "GeneratedUdonSharp.cs"]);
+        assert!(result.additional_files["Counter.cs"].contains("The code of Counter is generated into World.cs."));
+        assert_eq!(result.behavior_source("Counter"), Some(result.main_class.as_str()));
+    }
+
+    #[test]
+    fn test_snapshot_mismatch_reports_first_differing_line() {
+        let result = EnhancedWasm2USharp::new().convert_with_oop(&EMPTY_WASM, &counter_analysis()).unwrap();
+
+        let edited = COUNTER_SNAPSHOT.replace("private int count;", "private int total;");
+        match result.compare_snapshot("Counter", &edited) {
+            Err(SnapshotMismatch::LineDiffers { line, expected, actual, .. }) => {
+                assert_eq!(expected.as_deref(), Some("private int total;"));
+                assert_eq!(actual.as_deref(), Some("private int count;"));
+                assert!(line > 1);
+            }
+            other => panic!("expected a line mismatch, got {:?}", other),
+        }
+        assert!(matches!(
+            result.compare_snapshot("Missing", COUNTER_SNAPSHOT),
+            Err(SnapshotMismatch::MissingBehavior { .. })
+        ));
+    }
+
+    /// Build a module exporting `#[udon_behaviour]` metadata statics the way rustc lays them out
+    fn behaviour_symbols_module(symbols: &[(&str, &str)]) -> Vec<u8> {
+        behaviour_module(symbols, None)
+    }
+
+    /// Build a module exporting `#[udon_behaviour]` metadata statics, optionally
+    /// with a legacy `udonsharp.attributes` section
+    fn behaviour_module(symbols: &[(&str, &str)], attributes: Option<&str>) -> Vec<u8> {
+        use wasm_encoder::{
+            ConstExpr, CustomSection, DataSection, ExportKind, ExportSection, GlobalSection, GlobalType,
+            MemorySection, MemoryType, Module, ValType,
+        };
+
+        let mut data = Vec::new();
+        let mut strings = Vec::new();
+        for (_, metadata) in symbols {
+            strings.push((data.len() as u32, metadata.len() as u32));
+            data.extend_from_slice(metadata.as_bytes());
+        }
+
+        let mut globals = GlobalSection::new();
+        let mut exports = ExportSection::new();
+        for (index, ((symbol, _), (pointer, length))) in symbols.iter().zip(strings).enumerate() {
+            let address = data.len() as i32;
+            data.extend_from_slice(&pointer.to_le_bytes());
+            data.extend_from_slice(&length.to_le_bytes());
+            globals.global(GlobalType { val_type: ValType::I32, mutable: false }, &ConstExpr::i32_const(address));
+            exports.export(symbol, ExportKind::Global, index as u32);
+        }
+
+        let mut memories = MemorySection::new();
+        memories.memory(MemoryType { minimum: 1, maximum: None, memory64: false, shared: false });
+        let mut data_section = DataSection::new();
+        data_section.active(0, &ConstExpr::i32_const(0), data);
+
+        let mut module = Module::new();
+        module.section(&memories).section(&globals).section(&exports).section(&data_section);
+        if let Some(attributes) = attributes {
+            module.section(&CustomSection { name: "udonsharp.attributes".into(), data: attributes.as_bytes().into() });
+        }
+        module.finish()
+    }
+
+    #[test]
+    fn test_behaviour_symbols_are_read_from_any_module_path() {
+        let wasm = behaviour_symbols_module(&[
+            ("__udon_behaviour_game::lobby::lobby_manager", "name:LobbyManager;events:Start;dependencies:;auto_sync:false;"),
+            ("__udon_behaviour_game::arena::scoring::score_board", "name:ScoreBoard;events:Start,Update;dependencies:LobbyManager;auto_sync:true;"),
+        ]);
+        let mut analyzer = OopBehaviorAnalyzer::new();
+        analyzer.analyze(&wasm).unwrap();
+
+        let lobby = analyzer.get_udon_behaviour_attribute("lobby_manager").unwrap();
+        assert_eq!(lobby.name.as_deref(), Some("LobbyManager"));
+        let score = analyzer.get_udon_behaviour_attribute("score_board").unwrap();
+        assert_eq!(score.events, vec!["Start".to_string(), "Update".to_string()]);
+        assert_eq!(score.dependencies, vec!["LobbyManager".to_string()]);
+        assert!(score.auto_sync);
+    }
+
+    #[test]
+    fn test_same_named_behaviours_in_two_modules_collide() {
+        let wasm = behaviour_symbols_module(&[
+            ("__udon_behaviour_game::lobby::manager", "name:Manager;events:Start;dependencies:;auto_sync:false;"),
+            ("__udon_behaviour_game::arena::manager", "name:Manager;events:Start;dependencies:;auto_sync:false;"),
+        ]);
+        let error = OopBehaviorAnalyzer::new().analyze(&wasm).unwrap_err().to_string();
+        assert!(
+            error.contains("'game::lobby::manager' and 'game::arena::manager' both generate the UdonBehaviour class 'Manager'"),
+            "{}", error
+        );
+    }
+
+    #[test]
+    fn test_attributes_section_and_symbol_resolving_to_one_class_collide() {
+        let wasm = behaviour_module(
+            &[("__udon_behaviour_hud::ui_controller", "name:UIController;events:Start;dependencies:;auto_sync:false;")],
+            Some("# legacy metadata\nui_panel:name:UIController;events:Update;dependencies:;auto_sync:false;"),
+        );
+        let error = OopBehaviorAnalyzer::new().analyze(&wasm).unwrap_err().to_string();
+        assert!(
+            error.contains(
+                "'ui_panel (udonsharp.attributes line 2)' and 'hud::ui_controller' both generate the UdonBehaviour class 'UIController'"
+            ),
+            "{}", error
+        );
+
+        // A bare function name in the attributes section is a different path
+        let wasm = behaviour_module(
+            &[("__udon_behaviour_hud::ui_controller", "name:UIController;events:Start;dependencies:;auto_sync:false;")],
+            Some("ui_controller:name:UIController;events:Start;dependencies:;auto_sync:false;"),
+        );
+        let error = OopBehaviorAnalyzer::new().analyze(&wasm).unwrap_err().to_string();
+        assert!(
+            error.contains(
+                "'ui_controller (udonsharp.attributes line 1)' and 'hud::ui_controller' both generate the UdonBehaviour class 'UIController'"
+            ),
+            "{}", error
+        );
+
+        // One module path described by both is a single behavior
+        let wasm = behaviour_module(
+            &[("__udon_behaviour_hud::ui_controller", "name:UIController;events:Start;dependencies:;auto_sync:false;")],
+            Some("hud::ui_controller:name:UIController;events:Start;dependencies:;auto_sync:false;"),
+        );
+        let mut analyzer = OopBehaviorAnalyzer::new();
+        analyzer.analyze(&wasm).unwrap();
+        assert!(analyzer.has_udon_behaviour_attribute("ui_controller"));
+        assert!(analyzer.has_udon_behaviour_attribute("hud::ui_controller"));
+    }
+}
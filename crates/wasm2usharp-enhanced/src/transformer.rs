@@ -4,6 +4,8 @@
 //! to apply object-oriented programming patterns.
 
 use crate::analyzer::{OopAnalysisResult, ClassInfo, MethodInfo, FieldInfo};
+use crate::file_generator::{OutputLayout, bundle_csharp_sources, program_stub_source};
+use crate::snapshot::{compare_snapshot, SnapshotMismatch};
 use anyhow::{Context, Result};
 use std::collections::{HashMap, HashSet};
 use std::io::Write;
@@ -42,6 +44,11 @@ impl EnhancedWasm2USharp {
     
     /// Convert WASM to UdonSharp with OOP transformations
     pub fn convert_with_oop(&self, wasm_bytes: &[u8], analysis: &OopAnalysisResult) -> Result<ConversionResult> {
+        // Udon assembly is lowered from the Rust source; WASM function bodies are not translated yet
+        if self.config.target == ConversionTarget::UdonAssembly {
+            anyhow::bail!("The Udon assembly target is not supported for WASM input; compile the behaviors from Rust source instead");
+        }
+
         // First, use the original wasm2usharp to generate base C# code
         let base_code = self.generate_base_code(wasm_bytes)?;
         
@@ -49,7 +56,34 @@ impl EnhancedWasm2USharp {
         let transformed_code = self.apply_oop_transformations(&base_code, analysis)?;
         
         // Generate multiple files if needed
-        let files = self.organize_into_files(&transformed_code, analysis)?;
+        let mut files = self.organize_into_files(&transformed_code, analysis)?;
+        
+        // Fold every class into the main file for the bundled layout, leaving each
+        // class a stub file so it still gets its own program asset
+        if let OutputLayout::Bundled(file_name) = &self.config.output_layout {
+            let mut class_files: Vec<_> = files.iter()
+                .filter(|(name, _)| name.as_str() != "main")
+                .collect();
+            class_files.sort_by(|a, b| a.0.cmp(b.0));
+            
+            let mut sources = vec![files.get("main").map(|s| s.as_str()).unwrap_or_default()];
+            sources.extend(class_files.iter().map(|(_, code)| code.as_str()));
+            let bundled = bundle_csharp_sources(file_name, &sources);
+            
+            let mut class_names: Vec<String> = class_files.iter()
+                .map(|(name, _)| name.trim_end_matches(".cs").to_string())
+                .collect();
+            class_names.push(self.config.class_name.clone());
+            let stubs: HashMap<String, String> = class_names.into_iter()
+                .map(|class_name| {
+                    let stub = program_stub_source(file_name, &class_name, self.config.namespace.as_deref());
+                    (format!("{}.cs", class_name), stub)
+                })
+                .collect();
+            
+            files = stubs;
+            files.insert("main".to_string(), bundled);
+        }
         
         Ok(ConversionResult {
             main_class: files.get("main").cloned().unwrap_or_default(),
@@ -213,6 +247,34 @@ pub struct ConversionConfig {
     pub generate_separate_files: bool,
     pub udonsharp_attributes: bool,
     pub inheritance_support: bool,
+    /// Whether classes are emitted as separate files or bundled into the main file
+    /// next to a stub file per class
+    pub output_layout: OutputLayout,
+    /// Language the behaviors are emitted in
+    pub target: ConversionTarget,
+}
+
+/// Output language of a conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConversionTarget {
+    /// UdonSharp C# source, compiled to Udon by the UdonSharp compiler in Unity
+    #[default]
+    UdonSharp,
+    /// Raw Udon assembly (`.uasm`), for projects that do not use UdonSharp.
+    ///
+    /// Only a subset of behaviors can be expressed: field reads and writes,
+    /// arithmetic and comparisons, branches and event sends.
+    UdonAssembly,
+}
+
+impl ConversionTarget {
+    /// File extension of the generated behavior files
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            ConversionTarget::UdonSharp => "cs",
+            ConversionTarget::UdonAssembly => "uasm",
+        }
+    }
 }
 
 impl Default for ConversionConfig {
@@ -223,6 +285,8 @@ impl Default for ConversionConfig {
             generate_separate_files: true,
             udonsharp_attributes: true,
             inheritance_support: true,
+            output_layout: OutputLayout::PerBehavior,
+            target: ConversionTarget::UdonSharp,
         }
     }
 }
@@ -235,6 +299,28 @@ pub struct ConversionResult {
     pub analysis_result: OopAnalysisResult,
 }
 
+impl ConversionResult {
+    /// Get the generated source of a behavior, from its own file or the main file
+    pub fn behavior_source(&self, behavior: &str) -> Option<&str> {
+        // A bundled main file declares every class partial, next to stub files
+        if self.main_class.contains(&format!("partial class {} ", behavior)) {
+            return Some(&self.main_class);
+        }
+        if let Some(code) = self.additional_files.get(&format!("{}.cs", behavior)) {
+            return Some(code);
+        }
+        let declaration = format!("class {} ", behavior);
+        self.main_class.contains(&declaration).then_some(self.main_class.as_str())
+    }
+
+    /// Compare a behavior's generated source against a golden snapshot
+    pub fn compare_snapshot(&self, behavior: &str, expected: &str) -> std::result::Result<(), SnapshotMismatch> {
+        let actual = self.behavior_source(behavior)
+            .ok_or_else(|| SnapshotMismatch::MissingBehavior { behavior: behavior.to_string() })?;
+        compare_snapshot(behavior, expected, actual)
+    }
+}
+
 /// Transformation pipeline for applying OOP patterns to C# code
 pub struct OopTransformationPipeline {
     transformations: Vec<Box<dyn OopTransformation>>,
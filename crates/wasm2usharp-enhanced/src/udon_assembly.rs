@@ -0,0 +1,256 @@
+//! Udon assembly (`.uasm`) program writer
+//!
+//! Udon assembly is the textual form of an Udon program: a data section
+//! declaring the heap variables and a code section of stack instructions
+//! grouped under exported event labels. This module holds the program model
+//! and renders it, resolving jump labels to the byte addresses the Udon VM
+//! expects.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Address that ends the current event when jumped to
+const HALT_ADDRESS: u32 = 0xFFFF_FFFC;
+
+/// A variable in the data section of an Udon program
+#[derive(Debug, Clone, PartialEq)]
+pub struct UdonVariable {
+    /// Name of the heap variable
+    pub name: String,
+    /// Udon type name, e.g. `SystemInt32`
+    pub udon_type: String,
+    /// Initial value: `null`, `this` or a literal
+    pub value: String,
+    /// Whether the variable is visible to other behaviors and the inspector
+    pub exported: bool,
+    /// Whether the variable is synced over the network
+    pub synced: bool,
+}
+
+impl UdonVariable {
+    /// Create a private variable without an initial value
+    pub fn new(name: impl Into<String>, udon_type: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            udon_type: udon_type.into(),
+            value: "null".to_string(),
+            exported: false,
+            synced: false,
+        }
+    }
+
+    /// Set the initial value
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Mark the variable as exported
+    pub fn exported(mut self) -> Self {
+        self.exported = true;
+        self
+    }
+
+    /// Mark the variable as synced
+    pub fn synced(mut self) -> Self {
+        self.synced = true;
+        self
+    }
+}
+
+/// An instruction in the code section of an Udon program
+#[derive(Debug, Clone, PartialEq)]
+pub enum UdonInstruction {
+    /// Do nothing
+    Nop,
+    /// Push the address of a heap variable
+    Push(String),
+    /// Discard the top of the stack
+    Pop,
+    /// Copy the second-to-top variable into the top variable
+    Copy,
+    /// Call an extern with the pushed arguments
+    Extern(String),
+    /// Jump to a label
+    Jump(String),
+    /// Jump to a label if the popped boolean is false
+    JumpIfFalse(String),
+    /// End the current event
+    Return,
+    /// Mark a jump target; emits no code
+    Label(String),
+}
+
+impl UdonInstruction {
+    /// Size of the instruction in bytes
+    fn size(&self) -> u32 {
+        match self {
+            UdonInstruction::Label(_) => 0,
+            UdonInstruction::Nop | UdonInstruction::Pop | UdonInstruction::Copy => 4,
+            _ => 8,
+        }
+    }
+}
+
+/// Errors that can occur while rendering an Udon program
+#[derive(Debug, Clone, PartialEq)]
+pub enum UdonAssemblyError {
+    /// A jump refers to a label that is never defined
+    UndefinedLabel { label: String },
+    /// An instruction pushes a variable missing from the data section
+    UndefinedVariable { name: String },
+}
+
+impl std::fmt::Display for UdonAssemblyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UdonAssemblyError::UndefinedLabel { label } => write!(f, "Jump to undefined label '{}'", label),
+            UdonAssemblyError::UndefinedVariable { name } => write!(f, "Push of undeclared variable '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for UdonAssemblyError {}
+
+/// An Udon program made of heap variables and exported events
+#[derive(Debug, Clone, Default)]
+pub struct UdonAssemblyProgram {
+    variables: Vec<UdonVariable>,
+    events: Vec<(String, Vec<UdonInstruction>)>,
+}
+
+impl UdonAssemblyProgram {
+    /// Create an empty program
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a variable to the data section
+    pub fn add_variable(&mut self, variable: UdonVariable) {
+        self.variables.push(variable);
+    }
+
+    /// Add an exported event; a trailing return is appended if missing
+    pub fn add_event(&mut self, name: impl Into<String>, mut instructions: Vec<UdonInstruction>) {
+        if instructions.last() != Some(&UdonInstruction::Return) {
+            instructions.push(UdonInstruction::Return);
+        }
+        self.events.push((name.into(), instructions));
+    }
+
+    /// Get the variables of the data section
+    pub fn variables(&self) -> &[UdonVariable] {
+        &self.variables
+    }
+
+    /// Check if the data section declares a variable
+    pub fn has_variable(&self, name: &str) -> bool {
+        self.variables.iter().any(|v| v.name == name)
+    }
+
+    /// Render the program as Udon assembly
+    pub fn render(&self) -> Result<String, UdonAssemblyError> {
+        let labels = self.label_addresses();
+        let mut out = String::new();
+
+        out.push_str(".data_start\n");
+        for variable in &self.variables {
+            if variable.exported {
+                let _ = writeln!(out, "    .export {}", variable.name);
+            }
+            if variable.synced {
+                let _ = writeln!(out, "    .sync {}, none", variable.name);
+            }
+        }
+        for variable in &self.variables {
+            let _ = writeln!(out, "    {}: %{}, {}", variable.name, variable.udon_type, variable.value);
+        }
+        out.push_str(".data_end\n\n.code_start\n");
+
+        for (name, instructions) in &self.events {
+            let _ = writeln!(out, "    .export {}", name);
+            let _ = writeln!(out, "    {}:", name);
+            for instruction in instructions {
+                let line = match instruction {
+                    UdonInstruction::Label(_) => continue,
+                    UdonInstruction::Nop => "NOP".to_string(),
+                    UdonInstruction::Push(variable) => {
+                        if !self.has_variable(variable) {
+                            return Err(UdonAssemblyError::UndefinedVariable { name: variable.clone() });
+                        }
+                        format!("PUSH, {}", variable)
+                    }
+                    UdonInstruction::Pop => "POP".to_string(),
+                    UdonInstruction::Copy => "COPY".to_string(),
+                    UdonInstruction::Extern(signature) => format!("EXTERN, \"{}\"", signature),
+                    UdonInstruction::Jump(label) => format!("JUMP, 0x{:08X}", resolve(&labels, label)?),
+                    UdonInstruction::JumpIfFalse(label) => {
+                        format!("JUMP_IF_FALSE, 0x{:08X}", resolve(&labels, label)?)
+                    }
+                    UdonInstruction::Return => format!("JUMP, 0x{:08X}", HALT_ADDRESS),
+                };
+                let _ = writeln!(out, "        {}", line);
+            }
+            out.push('\n');
+        }
+        out.push_str(".code_end\n");
+
+        Ok(out)
+    }
+
+    /// Compute the byte address of every label in the code section
+    fn label_addresses(&self) -> HashMap<String, u32> {
+        let mut labels = HashMap::new();
+        let mut address = 0;
+        for (_, instructions) in &self.events {
+            for instruction in instructions {
+                if let UdonInstruction::Label(label) = instruction {
+                    labels.insert(label.clone(), address);
+                }
+                address += instruction.size();
+            }
+        }
+        labels
+    }
+}
+
+fn resolve(labels: &HashMap<String, u32>, label: &str) -> Result<u32, UdonAssemblyError> {
+    labels.get(label).copied().ok_or_else(|| UdonAssemblyError::UndefinedLabel { label: label.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_resolves_labels_to_byte_addresses() {
+        let mut program = UdonAssemblyProgram::new();
+        program.add_variable(UdonVariable::new("enabled", "SystemBoolean").with_value("true").exported());
+        program.add_variable(UdonVariable::new("count", "SystemInt32").with_value("0").synced());
+        program.add_event("_update", vec![
+            UdonInstruction::Push("enabled".to_string()),
+            UdonInstruction::JumpIfFalse("end".to_string()),
+            UdonInstruction::Nop,
+            UdonInstruction::Label("end".to_string()),
+        ]);
+
+        let uasm = program.render().unwrap();
+        assert!(uasm.contains("    .export enabled\n"));
+        assert!(uasm.contains("    .sync count, none\n"));
+        assert!(uasm.contains("    count: %SystemInt32, 0\n"));
+        assert!(uasm.contains("    .export _update\n    _update:\n"));
+        // PUSH (8) + JUMP_IF_FALSE (8) + NOP (4)
+        assert!(uasm.contains("JUMP_IF_FALSE, 0x00000014"));
+        assert!(uasm.contains("JUMP, 0xFFFFFFFC"));
+    }
+
+    #[test]
+    fn test_render_rejects_undeclared_variables() {
+        let mut program = UdonAssemblyProgram::new();
+        program.add_event("_start", vec![UdonInstruction::Push("missing".to_string())]);
+        assert_eq!(
+            program.render(),
+            Err(UdonAssemblyError::UndefinedVariable { name: "missing".to_string() })
+        );
+    }
+}
@@ -24,7 +24,7 @@ pub struct Counter {
     display: Option<GameObject>,
 }
 
-impl Counter {
+impl UdonBehaviour for Counter {
     fn start(&mut self) {
         // Initialize the counter
         self.current_count = 0;
@@ -36,7 +36,7 @@ impl Counter {
         // Update the display initially
         self.update_display();
         
-        log_message("Counter initialized");
+        log_info("Counter initialized");
     }
 }
 
@@ -46,9 +46,9 @@ impl Counter {
         if self.current_count < self.max_count {
             self.current_count += 1;
             self.update_display();
-            log_message(&format!("Counter incremented to {}", self.current_count));
+            log_info(&format!("Counter incremented to {}", self.current_count));
         } else {
-            log_message("Counter is at maximum value");
+            log_info("Counter is at maximum value");
         }
     }
     
@@ -57,9 +57,9 @@ impl Counter {
         if self.current_count > 0 {
             self.current_count -= 1;
             self.update_display();
-            log_message(&format!("Counter decremented to {}", self.current_count));
+            log_info(&format!("Counter decremented to {}", self.current_count));
         } else {
-            log_message("Counter is at minimum value");
+            log_info("Counter is at minimum value");
         }
     }
     
@@ -68,7 +68,7 @@ impl Counter {
     pub fn reset(&mut self) {
         self.current_count = 0;
         self.update_display();
-        log_message("Counter reset to 0");
+        log_info("Counter reset to 0");
     }
     
     /// Get the current count value
@@ -99,7 +99,7 @@ pub struct Display {
     counter: Option<GameObject>,
 }
 
-impl Display {
+impl UdonBehaviour for Display {
     fn start(&mut self) {
         // Find the counter behavior
         self.counter = GameObject::find("Counter");
@@ -107,7 +107,7 @@ impl Display {
         // Initial display update
         self.update_display();
         
-        log_message("Display initialized");
+        log_info("Display initialized");
     }
 }
 
@@ -193,7 +193,7 @@ pub fn format_number(number: i32) -> String {
 }
 
 /// Log a message (shared logging utility)
-pub fn log_message(message: &str) {
+pub fn log_info(message: &str) {
     // In real implementation, this would use UdonSharp's Debug.Log
-    println!("[LOG] {}", message);
+    println!("[INFO] {}", message);
 }
\ No newline at end of file
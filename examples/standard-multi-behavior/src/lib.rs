@@ -30,15 +30,15 @@ pub struct GameManager {
     
     /// Current game state synchronized across all clients
     #[udon_sync]
-    current_game_state: i32, // 0 = Lobby, 1 = Playing, 2 = Finished
+    pub current_game_state: i32, // 0 = Lobby, 1 = Playing, 2 = Finished
     
     /// Current round number
     #[udon_sync]
-    current_round: i32,
+    pub current_round: i32,
     
     /// Time remaining in current round
     #[udon_sync]
-    time_remaining: f32,
+    pub time_remaining: f32,
     
     /// Reference to the UI controller for updates
     ui_controller: Option<unity::GameObject>,
@@ -212,7 +212,7 @@ pub struct PlayerTracker {
     
     /// Current number of active players
     #[udon_sync]
-    active_player_count: i32,
+    pub active_player_count: i32,
     
     /// Reference to game manager
     game_manager: Option<unity::GameObject>,
@@ -484,15 +484,15 @@ pub struct ScoreSystem {
     
     /// Current high score across all players
     #[udon_sync]
-    high_score: i32,
+    pub high_score: i32,
     
     /// Current round high score
     #[udon_sync]
-    round_high_score: i32,
+    pub round_high_score: i32,
     
     /// Total games played
     #[udon_sync]
-    total_games_played: i32,
+    pub total_games_played: i32,
     
     /// Reference to UI controller for updates
     ui_controller: Option<unity::GameObject>,
@@ -649,4 +649,26 @@ pub fn log_warning(message: &str) {
 pub fn log_error(message: &str) {
     // In real implementation, this would use UdonSharp's Debug.LogError
     println!("[ERROR] {}", message);
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_time_pads_seconds_and_clamps_negatives() {
+        assert_eq!(format_time(0.0), "0:00");
+        assert_eq!(format_time(65.9), "1:05");
+        assert_eq!(format_time(300.0), "5:00");
+        assert_eq!(format_time(-3.0), "0:00");
+    }
+
+    #[test]
+    fn test_calculate_distance_matches_native_vector_math() {
+        let spawn = Vector3::new(1.0, 2.0, 3.0);
+        let goal = Vector3::new(4.0, 6.0, 3.0);
+        assert_eq!(calculate_distance(spawn, goal), 5.0);
+        assert_eq!(calculate_distance(spawn, goal), Vector3::distance(spawn, goal));
+        assert_eq!(calculate_distance(goal, goal), 0.0);
+    }
+}